@@ -0,0 +1,126 @@
+//! Bearer token authentication of the write, read and admin endpoints.
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request, State},
+    http::header,
+    middleware::Next,
+    response::Response,
+};
+
+use crate::error::AppError;
+
+/// The bearer tokens accepted by a group of endpoints.
+#[derive(Clone)]
+pub struct BearerTokens(Arc<[Box<str>]>);
+
+impl BearerTokens {
+    /// Creates the set of accepted tokens, `None` if there are none, i.e. if the endpoints are
+    /// not guarded.
+    pub fn new<T>(tokens: impl IntoIterator<Item = T>) -> Option<Self>
+    where
+        Box<str>: From<T>,
+    {
+        let tokens: Arc<[Box<str>]> = tokens.into_iter().map(Box::<str>::from).collect();
+
+        (!tokens.is_empty()).then_some(Self(tokens))
+    }
+
+    /// Returns whether `token` is one of the accepted tokens.
+    ///
+    /// Every accepted token is compared in constant time, so the response time doesn't leak how
+    /// much of a guessed token is right, nor which token it is closest to.
+    fn accepts(&self, token: &str) -> bool {
+        self.0.iter().fold(false, |accepted, accepted_token| {
+            accepted | constant_time_eq(token.as_bytes(), accepted_token.as_bytes())
+        })
+    }
+}
+
+/// Middleware rejecting requests with `401 Unauthorized` unless they carry one of the accepted
+/// tokens in an `Authorization: Bearer <token>` header.
+pub async fn require_bearer_token(
+    State(tokens): State<BearerTokens>,
+    request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let is_authorized = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|token| tokens.accepts(token));
+
+    if !is_authorized {
+        return Err(AppError::Unauthorized);
+    }
+
+    Ok(next.run(request).await)
+}
+
+fn constant_time_eq(lhs: &[u8], rhs: &[u8]) -> bool {
+    lhs.len() == rhs.len() && lhs.iter().zip(rhs).fold(0, |diff, (l, r)| diff | (l ^ r)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{
+        Router,
+        body::Body,
+        extract::Request,
+        http::{StatusCode, header},
+        middleware, routing,
+    };
+    use tower::ServiceExt;
+
+    use super::BearerTokens;
+
+    fn router() -> Router {
+        let tokens = BearerTokens::new(["s3cr3t", "0th3r"]).unwrap();
+
+        Router::new()
+            .route("/", routing::get(|| async {}))
+            .route_layer(middleware::from_fn_with_state(tokens, super::require_bearer_token))
+    }
+
+    #[test]
+    fn no_tokens_guard_nothing() {
+        assert!(BearerTokens::new(Vec::<String>::new()).is_none());
+    }
+
+    #[tokio::test]
+    async fn request_with_any_accepted_token_is_let_through() {
+        for token in ["s3cr3t", "0th3r"] {
+            // Arrange
+            let request = Request::get("/")
+                .header(header::AUTHORIZATION, format!("Bearer {token}"))
+                .body(Body::empty())
+                .unwrap();
+
+            // Act
+            let response = router().oneshot(request).await.unwrap();
+
+            // Assert
+            assert_eq!(response.status(), StatusCode::OK, "{token}");
+        }
+    }
+
+    #[tokio::test]
+    async fn request_without_or_with_wrong_token_is_unauthorized() {
+        for authorization in [None, Some("Bearer s3cr3"), Some("Bearer s3cr3t4"), Some("s3cr3t")] {
+            // Arrange
+            let mut request = Request::get("/");
+
+            if let Some(authorization) = authorization {
+                request = request.header(header::AUTHORIZATION, authorization);
+            }
+
+            // Act
+            let response = router().oneshot(request.body(Body::empty()).unwrap()).await.unwrap();
+
+            // Assert
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED, "{authorization:?}");
+        }
+    }
+}
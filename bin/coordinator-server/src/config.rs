@@ -4,9 +4,13 @@
 //! and environment variables. Environment variables override the base configuration
 //! and use the prefix `MIDENMULTISIG_`.
 
-use core::{num::NonZeroUsize, time::Duration};
+use core::{
+    num::{NonZeroU32, NonZeroUsize},
+    time::Duration,
+};
 
 use config::{ConfigError, Environment, File, FileFormat};
+use miden_multisig_coordinator_store::{PoolTlsConfig, TlsMode};
 use serde::Deserialize;
 
 /// Loads the application configuration from base config and environment variables.
@@ -54,6 +58,68 @@ pub struct AppConfig {
     /// CORS allowed origins (e.g., ["http://localhost:3000", "https://example.com"])
     /// Use ["*"] to allow all origins
     pub cors_allowed_origins: Vec<String>,
+
+    /// CORS allowed methods when specific origins are configured (e.g., ["GET", "POST"])
+    pub cors_allowed_methods: Vec<String>,
+
+    /// CORS allowed request headers when specific origins are configured
+    /// (e.g., ["content-type", "authorization"])
+    pub cors_allowed_headers: Vec<String>,
+
+    /// Whether credentialed CORS requests are allowed when specific origins are configured
+    pub cors_allow_credentials: bool,
+
+    /// How long browsers may cache CORS preflight responses (e.g., "10m")
+    /// Use "0s" to disable preflight caching
+    #[serde(with = "humantime_serde")]
+    pub cors_max_age: Duration,
+
+    /// Maximum size of a request body in bytes, larger requests are rejected with `413`
+    pub request_body_limit: usize,
+
+    /// Maximum number of write requests a client IP can burst before being rate limited
+    pub write_rate_limit_burst: NonZeroU32,
+
+    /// Number of write requests per minute a client IP is allowed in the long run
+    pub write_rate_limit_per_minute: NonZeroU32,
+
+    /// Maximum number of proposals a multisig account can burst before being rate limited
+    pub propose_rate_limit_burst: NonZeroU32,
+
+    /// Number of proposals per minute a multisig account is allowed in the long run
+    pub propose_rate_limit_per_minute: NonZeroU32,
+
+    /// Maximum number of signatures an approver of a multisig account can burst before being rate
+    /// limited
+    pub sign_rate_limit_burst: NonZeroU32,
+
+    /// Number of signatures per minute an approver of a multisig account is allowed in the long run
+    pub sign_rate_limit_per_minute: NonZeroU32,
+
+    /// Whether transactions are processed as soon as their signature threshold is met
+    /// If `false`, they must be executed through `/api/v1/multisig-tx/execute`
+    pub auto_process: bool,
+
+    /// Whether transaction summaries supplied along with proposals are stored without dry-running
+    /// the transaction request, instead of being checked against the dry-run summary
+    pub trust_tx_summary: bool,
+
+    /// The most pending transactions a multisig account may have at once, unlimited if unset
+    pub max_pending_proposals: Option<NonZeroU32>,
+
+    /// The most multisig accounts retrieved at once when listing all of them, 10000 if unset
+    pub max_all_multisig_accounts: Option<NonZeroU32>,
+
+    /// The bearer tokens accepted by the write endpoints, which are public if empty
+    #[serde(default)]
+    pub api_tokens: Vec<String>,
+
+    /// Whether the read endpoints require one of the `api_tokens` as well
+    #[serde(default)]
+    pub api_tokens_guard_reads: bool,
+
+    /// The bearer token required by the admin endpoints, which are disabled if unset
+    pub admin_token: Option<String>,
 }
 
 /// Database configuration settings.
@@ -64,6 +130,55 @@ pub struct DbConfig {
 
     /// Maximum number of database connections in the pool
     pub max_conn: NonZeroUsize,
+
+    /// Maximum duration of a single database statement before it is canceled
+    #[serde(with = "humantime_serde")]
+    pub statement_timeout: Duration,
+
+    /// Duration above which database queries are logged as slow
+    #[serde(with = "humantime_serde")]
+    pub slow_query_threshold: Duration,
+
+    /// The connection URL of a read replica serving read-only queries, if any
+    pub read_replica_url: Option<String>,
+
+    /// Whether the serialized transaction requests and summaries are stored zstd compressed
+    pub compress_tx_blobs: bool,
+
+    /// TLS settings of the database connections, the `sslmode` of the connection URLs applies if
+    /// unset
+    pub tls: Option<DbTlsConfig>,
+}
+
+/// TLS settings of the database connections.
+#[derive(Deserialize)]
+pub struct DbTlsConfig {
+    /// Whether connections use TLS: "disable", "prefer" or "require", the `sslmode` of the
+    /// connection URLs applies if unset
+    pub mode: Option<DbTlsMode>,
+
+    /// Path to a PEM file of CA certificates trusted in addition to the native root certificates
+    pub ca_cert_path: Option<String>,
+
+    /// Path to a PEM file of the client certificate chain
+    pub client_cert: Option<String>,
+
+    /// Path to a PEM file of the client private key
+    pub client_key: Option<String>,
+}
+
+/// Whether database connections use TLS, mirroring the PostgreSQL `sslmode`.
+#[derive(Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DbTlsMode {
+    /// Connections do not use TLS
+    Disable,
+
+    /// Connections use TLS if the database supports it
+    Prefer,
+
+    /// Connections fail unless the database supports TLS
+    Require,
 }
 
 /// Node and multisig client runtime configuration settings.
@@ -81,8 +196,53 @@ pub struct MidenConfig {
     /// Request timeout duration
     #[serde(with = "humantime_serde")]
     pub timeout: Duration,
+
+    /// How long the multisig client runtime keeps handling already queued operations on shutdown
+    #[serde(with = "humantime_serde")]
+    pub shutdown_drain_timeout: Duration,
+
+    /// The number of multisig client runtime worker threads multisig accounts are sharded across
+    pub runtime_workers: NonZeroUsize,
+
+    /// The most unknown input notes imported before dry-running a proposal, none if unset
+    pub note_import_limit: Option<NonZeroUsize>,
+
+    /// How often the multisig client runtime syncs with the node while idle, never if unset or zero
+    #[serde(default, with = "humantime_serde")]
+    pub sync_interval: Option<Duration>,
+
+    /// The most operations queued for each multisig client runtime worker
+    pub runtime_queue_capacity: NonZeroUsize,
+
+    /// How long an operation waits for room in a full runtime worker queue before failing
+    #[serde(with = "humantime_serde")]
+    pub runtime_enqueue_timeout: Duration,
 }
 
 impl Config {
     const CONFIG_ENV_PREFIX: &str = "MIDENMULTISIG";
 }
+
+impl From<DbTlsConfig> for PoolTlsConfig {
+    fn from(tls_config: DbTlsConfig) -> Self {
+        let DbTlsConfig {
+            mode,
+            ca_cert_path,
+            client_cert,
+            client_key,
+        } = tls_config;
+
+        let mode = mode.map(|mode| match mode {
+            DbTlsMode::Disable => TlsMode::Disable,
+            DbTlsMode::Prefer => TlsMode::Prefer,
+            DbTlsMode::Require => TlsMode::Require,
+        });
+
+        PoolTlsConfig::builder()
+            .maybe_mode(mode)
+            .maybe_ca_cert_path(ca_cert_path)
+            .maybe_client_cert(client_cert)
+            .maybe_client_key(client_key)
+            .build()
+    }
+}
@@ -0,0 +1,172 @@
+//! Per-request correlation ids, tying together the logs of a request across the server, the
+//! multisig engine and the multisig client runtime threads.
+
+use axum::{extract::Request, http::HeaderValue, middleware::Next, response::Response};
+use tracing::Instrument;
+use uuid::Uuid;
+
+/// The header a correlation id is read from on requests, and returned in on responses.
+pub const CORRELATION_ID_HEADER: &str = "x-correlation-id";
+
+/// The maximum length of a correlation id supplied by a client.
+const MAX_CORRELATION_ID_LEN: usize = 64;
+
+/// Middleware running the request within a span carrying its correlation id.
+///
+/// The correlation id is taken from the [`CORRELATION_ID_HEADER`] of the request if it is
+/// well-formed, i.e. at most [`MAX_CORRELATION_ID_LEN`] ASCII alphanumeric characters, `-` or `_`,
+/// otherwise a new one is generated. It is returned in the same header of the response.
+///
+/// The multisig engine captures the current span in every msg it sends to the multisig client
+/// runtime, which re-enters it while handling the msg, so every log of the request carries the
+/// `correlation_id` field, whichever thread emitted it.
+pub async fn correlate(request: Request, next: Next) -> Response {
+    let correlation_id = request
+        .headers()
+        .get(CORRELATION_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|correlation_id| is_well_formed(correlation_id))
+        .map(ToOwned::to_owned)
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+    let span = tracing::info_span!(
+        "request",
+        correlation_id = %correlation_id,
+        method = %request.method(),
+        path = request.uri().path(),
+    );
+
+    let mut response = next.run(request).instrument(span).await;
+
+    if let Ok(value) = HeaderValue::from_str(&correlation_id) {
+        response.headers_mut().insert(CORRELATION_ID_HEADER, value);
+    }
+
+    response
+}
+
+fn is_well_formed(correlation_id: &str) -> bool {
+    (1..=MAX_CORRELATION_ID_LEN).contains(&correlation_id.len())
+        && correlation_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use core::fmt;
+
+    use std::sync::{Arc, Mutex};
+
+    use axum::{Router, body::Body, extract::Request, middleware, routing};
+    use tower::ServiceExt;
+    use tracing::{
+        Event, Instrument, Span, Subscriber,
+        field::{Field, Visit},
+        span::{Attributes, Id},
+    };
+    use tracing_subscriber::{
+        Layer,
+        layer::{Context, SubscriberExt},
+        registry::LookupSpan,
+        util::SubscriberInitExt,
+    };
+    use uuid::Uuid;
+
+    use super::CORRELATION_ID_HEADER;
+
+    /// Captures the correlation ids of the spans in scope of every event.
+    #[derive(Clone, Default)]
+    struct CorrelationIdCapture(Arc<Mutex<Vec<String>>>);
+
+    /// The correlation id recorded on a span, stored in the span extensions.
+    struct CorrelationId(Option<String>);
+
+    impl Visit for CorrelationId {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            if field.name() == "correlation_id" {
+                self.0 = Some(format!("{value:?}"));
+            }
+        }
+    }
+
+    impl<S> Layer<S> for CorrelationIdCapture
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+            let mut correlation_id = CorrelationId(None);
+            attrs.record(&mut correlation_id);
+
+            if correlation_id.0.is_none() {
+                return;
+            }
+
+            if let Some(span) = ctx.span(id) {
+                span.extensions_mut().insert(correlation_id);
+            }
+        }
+
+        fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+            let correlation_ids = ctx.event_scope(event).into_iter().flatten().filter_map(|span| {
+                span.extensions().get::<CorrelationId>().and_then(|id| id.0.clone())
+            });
+
+            self.0.lock().unwrap().extend(correlation_ids);
+        }
+    }
+
+    /// Logs from a spawned task, the way the engine hands work over to the multisig client
+    /// runtime along with the current span.
+    async fn handler() {
+        tokio::spawn(async { tracing::info!("handled") }.instrument(Span::current()))
+            .await
+            .unwrap();
+    }
+
+    fn router() -> Router {
+        Router::new()
+            .route("/", routing::get(handler))
+            .layer(middleware::from_fn(super::correlate))
+    }
+
+    #[tokio::test]
+    async fn supplied_correlation_id_is_returned_and_carried_by_spawned_task_logs() {
+        // Arrange
+        let capture = CorrelationIdCapture::default();
+        let _guard = tracing_subscriber::registry().with(capture.clone()).set_default();
+
+        let request = Request::get("/")
+            .header(CORRELATION_ID_HEADER, "proposal-42")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router().oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.headers()[CORRELATION_ID_HEADER], "proposal-42");
+        assert_eq!(*capture.0.lock().unwrap(), ["proposal-42"]);
+    }
+
+    #[tokio::test]
+    async fn malformed_correlation_id_is_replaced_with_generated_one() {
+        // Arrange
+        let capture = CorrelationIdCapture::default();
+        let _guard = tracing_subscriber::registry().with(capture.clone()).set_default();
+
+        let request = Request::get("/")
+            .header(CORRELATION_ID_HEADER, "not well formed")
+            .body(Body::empty())
+            .unwrap();
+
+        // Act
+        let response = router().oneshot(request).await.unwrap();
+
+        // Assert
+        let correlation_id = response.headers()[CORRELATION_ID_HEADER].to_str().unwrap();
+
+        assert!(Uuid::parse_str(correlation_id).is_ok());
+        assert_eq!(*capture.0.lock().unwrap(), [correlation_id]);
+    }
+}
@@ -1,39 +1,173 @@
+use core::num::{NonZeroU32, NonZeroUsize};
+
 use std::borrow::Cow;
 
 use axum::{
+    Json,
+    extract::rejection::{JsonRejection, PathRejection, QueryRejection},
     http::StatusCode,
     response::{IntoResponse, Response},
 };
+use miden_client::account::{AccountId, NetworkId};
+use miden_multisig_coordinator_domain::tx::MultisigTxStatus;
 use miden_multisig_coordinator_engine::{MultisigEngineError, request::RequestError};
 use miden_multisig_coordinator_utils::AccountIdAddressError;
+use serde::Serialize;
 use tokio::task::JoinError;
+use uuid::Uuid;
 
 #[derive(Debug, thiserror::Error)]
 pub(crate) enum AppError {
     #[error("multisig engine error: {0}")]
     MultisigEngine(Box<MultisigEngineError>),
 
-    #[error("invalid network id error")]
-    InvalidNetworkId,
+    #[error(
+        "invalid network id error: expected `{}`, provided `{}`",
+        expected.as_str(),
+        provided.as_str()
+    )]
+    InvalidNetworkId { expected: NetworkId, provided: NetworkId },
 
     #[error("invalid account id address: {0}")]
     InvalidAccountIdAddress(Cow<'static, str>),
 
-    #[error("invalid pub key commit error")]
-    InvalidPubKeyCommit,
+    #[error("invalid base64 error: `{field}` is not valid base64")]
+    InvalidBase64 { field: Cow<'static, str> },
+
+    #[error("invalid hex error: `{field}` is not valid hex")]
+    InvalidHex { field: Cow<'static, str> },
+
+    #[error("invalid pub key commit error: the {format} decoded bytes are not a commitment")]
+    InvalidPubKeyCommit { format: &'static str },
 
     #[error("invalid transaction request error")]
     InvalidTransactionRequest,
 
-    #[error("invalid signature error")]
-    InvalidSignature,
+    #[error("invalid transaction summary error")]
+    InvalidTransactionSummary,
+
+    #[error("invalid transaction summary commit error")]
+    InvalidTransactionSummaryCommit,
+
+    #[error("invalid signature error: the {format} decoded bytes are not a signature")]
+    InvalidSignature { format: &'static str },
+
+    #[error("invalid signature format error")]
+    InvalidSignatureFormat,
+
+    #[error("signature not verified error: the signature does not verify against the approver key")]
+    SignatureNotVerified,
+
+    #[error("invalid key scheme error")]
+    InvalidKeyScheme,
 
     #[error("invalid multisig tx status error")]
     InvalidMultisigTxStatus,
 
+    #[error("invalid tx sort error")]
+    InvalidTxSort,
+
+    #[error("invalid trend granularity error")]
+    InvalidTrendGranularity,
+
+    #[error("invalid json body error: {message}")]
+    InvalidJsonBody { status: StatusCode, message: String },
+
+    #[error("invalid path error: {0}")]
+    InvalidPath(String),
+
+    #[error("invalid query error: {0}")]
+    InvalidQuery(String),
+
+    #[error("unauthorized error: missing or invalid bearer token")]
+    Unauthorized,
+
     #[error("multisig account not found error")]
     MultisigAccountNotFound,
 
+    #[error("multisig tx not found error")]
+    MultisigTxNotFound,
+
+    #[error("multisig tx not pending error: the tx is `{status}`")]
+    MultisigTxNotPending { status: MultisigTxStatus },
+
+    #[error("multisig account already exists error: `{}`", account_id.to_hex())]
+    MultisigAccountAlreadyExists { account_id: AccountId },
+
+    #[error(
+        "not multisig account error: `{}` is not authenticated by the multisig component",
+        account_id.to_hex()
+    )]
+    NotMultisigAccount { account_id: AccountId },
+
+    #[error(
+        "approver count mismatch error: `{provided}` approvers provided, `{on_chain}` on chain"
+    )]
+    ApproverCountMismatch { on_chain: usize, provided: usize },
+
+    #[error("approver key mismatch error: key `{index}` mismatches its on-chain commitment")]
+    ApproverKeyMismatch { index: usize },
+
+    #[error("tx summary mismatch error: the supplied tx summary doesn't match the tx request")]
+    TxSummaryMismatch,
+
+    #[error("invalid multisig config error: {0}")]
+    InvalidMultisigConfig(String),
+
+    #[error("invalid note selection error: {0}")]
+    InvalidNoteSelection(String),
+
+    #[error("invalid payment error: {0}")]
+    InvalidPayment(String),
+
+    #[error(
+        "too many unknown input notes error: `{unknown}` unknown, at most `{note_import_limit}` \
+         imported"
+    )]
+    TooManyUnknownInputNotes {
+        unknown: usize,
+        note_import_limit: NonZeroUsize,
+    },
+
+    #[error("approver not authorized error: `{approver}` is not an approver of tx `{tx_id}`")]
+    ApproverNotAuthorized { approver: String, tx_id: Uuid },
+
+    #[error(
+        "insufficient balance error: `{required}` of faucet `{}` required, `{available}` available",
+        faucet_id.to_hex()
+    )]
+    InsufficientBalance {
+        faucet_id: AccountId,
+        required: u64,
+        available: u64,
+    },
+
+    #[error(
+        "unreachable threshold error: `{threshold}` exceeds remaining weight `{remaining_weight}`"
+    )]
+    UnreachableThreshold { threshold: u64, remaining_weight: u64 },
+
+    #[error("threshold raised error: `{threshold}` exceeds current threshold `{current}`")]
+    ThresholdRaised { threshold: u64, current: u64 },
+
+    #[error("multisig account has pending txs error: its txs must be executed first")]
+    MultisigAccountHasPendingTxs,
+
+    #[error("multisig tx commitment altered error: the tx holds signatures over its commitment")]
+    MultisigTxCommitmentAltered,
+
+    #[error("too many pending proposals error: `{max_pending_proposals}` txs already pending")]
+    TooManyPendingProposals { max_pending_proposals: NonZeroU32 },
+
+    #[error("too many requests error")]
+    TooManyRequests,
+
+    #[error("overloaded error: the server is handling too many operations, retry later")]
+    Overloaded,
+
+    #[error("timeout error: the operation did not complete in time, retry later")]
+    Timeout,
+
     #[error("join error: {0}")]
     JoinError(#[from] JoinError),
 
@@ -53,43 +187,740 @@ impl AppError {
     {
         Self::Other(err.into())
     }
+
+    /// Returns the HTTP status code and the stable machine-readable code of the error.
+    ///
+    /// The codes are part of the API contract, clients are expected to match on them rather
+    /// than on the human-readable message.
+    pub(crate) fn status_and_code(&self) -> (StatusCode, &'static str) {
+        match self {
+            AppError::InvalidNetworkId { .. } => (StatusCode::BAD_REQUEST, "INVALID_NETWORK_ID"),
+            AppError::InvalidAccountIdAddress(_) => {
+                (StatusCode::BAD_REQUEST, "INVALID_ACCOUNT_ID_ADDRESS")
+            },
+            AppError::InvalidBase64 { .. } => (StatusCode::BAD_REQUEST, "INVALID_BASE64"),
+            AppError::InvalidHex { .. } => (StatusCode::BAD_REQUEST, "INVALID_HEX"),
+            AppError::InvalidPubKeyCommit { .. } => {
+                (StatusCode::BAD_REQUEST, "INVALID_PUB_KEY_COMMIT")
+            },
+            AppError::InvalidTransactionRequest => {
+                (StatusCode::BAD_REQUEST, "INVALID_TRANSACTION_REQUEST")
+            },
+            AppError::InvalidTransactionSummary => {
+                (StatusCode::BAD_REQUEST, "INVALID_TRANSACTION_SUMMARY")
+            },
+            AppError::InvalidTransactionSummaryCommit => {
+                (StatusCode::BAD_REQUEST, "INVALID_TRANSACTION_SUMMARY_COMMIT")
+            },
+            AppError::InvalidSignature { .. } => (StatusCode::BAD_REQUEST, "INVALID_SIGNATURE"),
+            AppError::InvalidSignatureFormat => {
+                (StatusCode::BAD_REQUEST, "INVALID_SIGNATURE_FORMAT")
+            },
+            AppError::SignatureNotVerified => {
+                (StatusCode::UNPROCESSABLE_ENTITY, "SIGNATURE_NOT_VERIFIED")
+            },
+            AppError::InvalidKeyScheme => (StatusCode::BAD_REQUEST, "INVALID_KEY_SCHEME"),
+            AppError::InvalidMultisigTxStatus => {
+                (StatusCode::BAD_REQUEST, "INVALID_MULTISIG_TX_STATUS")
+            },
+            AppError::InvalidTxSort => (StatusCode::BAD_REQUEST, "INVALID_TX_SORT"),
+            AppError::InvalidTrendGranularity => {
+                (StatusCode::BAD_REQUEST, "INVALID_TREND_GRANULARITY")
+            },
+            // e.g. 415 for a missing content type, or 422 for a body not matching the payload
+            AppError::InvalidJsonBody { status, .. } => (*status, "INVALID_JSON_BODY"),
+            AppError::InvalidPath(_) => (StatusCode::BAD_REQUEST, "INVALID_PATH"),
+            AppError::InvalidQuery(_) => (StatusCode::BAD_REQUEST, "INVALID_QUERY"),
+            AppError::RequestError(_) => (StatusCode::BAD_REQUEST, "INVALID_REQUEST"),
+            AppError::TxSummaryMismatch => (StatusCode::BAD_REQUEST, "TX_SUMMARY_MISMATCH"),
+            AppError::Unauthorized => (StatusCode::UNAUTHORIZED, "UNAUTHORIZED"),
+            AppError::MultisigAccountNotFound => {
+                (StatusCode::NOT_FOUND, "MULTISIG_ACCOUNT_NOT_FOUND")
+            },
+            AppError::MultisigTxNotFound => (StatusCode::NOT_FOUND, "MULTISIG_TX_NOT_FOUND"),
+            AppError::ApproverNotAuthorized { .. } => {
+                (StatusCode::FORBIDDEN, "APPROVER_NOT_AUTHORIZED")
+            },
+            AppError::MultisigTxNotPending { .. } => {
+                (StatusCode::CONFLICT, "MULTISIG_TX_NOT_PENDING")
+            },
+            AppError::MultisigAccountAlreadyExists { .. } => {
+                (StatusCode::CONFLICT, "MULTISIG_ACCOUNT_ALREADY_EXISTS")
+            },
+            AppError::TooManyPendingProposals { .. } => {
+                (StatusCode::CONFLICT, "TOO_MANY_PENDING_PROPOSALS")
+            },
+            AppError::MultisigAccountHasPendingTxs => {
+                (StatusCode::CONFLICT, "MULTISIG_ACCOUNT_HAS_PENDING_TXS")
+            },
+            AppError::MultisigTxCommitmentAltered => {
+                (StatusCode::CONFLICT, "MULTISIG_TX_COMMITMENT_ALTERED")
+            },
+            AppError::UnreachableThreshold { .. } => {
+                (StatusCode::UNPROCESSABLE_ENTITY, "UNREACHABLE_THRESHOLD")
+            },
+            AppError::ThresholdRaised { .. } => {
+                (StatusCode::UNPROCESSABLE_ENTITY, "THRESHOLD_RAISED")
+            },
+            AppError::InsufficientBalance { .. } => {
+                (StatusCode::UNPROCESSABLE_ENTITY, "INSUFFICIENT_BALANCE")
+            },
+            AppError::NotMultisigAccount { .. } => {
+                (StatusCode::UNPROCESSABLE_ENTITY, "NOT_MULTISIG_ACCOUNT")
+            },
+            AppError::ApproverCountMismatch { .. } => {
+                (StatusCode::UNPROCESSABLE_ENTITY, "APPROVER_COUNT_MISMATCH")
+            },
+            AppError::ApproverKeyMismatch { .. } => {
+                (StatusCode::UNPROCESSABLE_ENTITY, "APPROVER_KEY_MISMATCH")
+            },
+            AppError::InvalidMultisigConfig(_) => {
+                (StatusCode::UNPROCESSABLE_ENTITY, "INVALID_MULTISIG_CONFIG")
+            },
+            AppError::InvalidNoteSelection(_) => {
+                (StatusCode::UNPROCESSABLE_ENTITY, "INVALID_NOTE_SELECTION")
+            },
+            AppError::InvalidPayment(_) => (StatusCode::UNPROCESSABLE_ENTITY, "INVALID_PAYMENT"),
+            AppError::TooManyUnknownInputNotes { .. } => {
+                (StatusCode::UNPROCESSABLE_ENTITY, "TOO_MANY_UNKNOWN_INPUT_NOTES")
+            },
+            AppError::TooManyRequests => (StatusCode::TOO_MANY_REQUESTS, "TOO_MANY_REQUESTS"),
+            AppError::Overloaded => (StatusCode::SERVICE_UNAVAILABLE, "OVERLOADED"),
+            AppError::Timeout => (StatusCode::GATEWAY_TIMEOUT, "TIMEOUT"),
+            AppError::MultisigEngine(_) => (StatusCode::INTERNAL_SERVER_ERROR, "ENGINE_ERROR"),
+            AppError::JoinError(_) => (StatusCode::INTERNAL_SERVER_ERROR, "TASK_JOIN_ERROR"),
+            AppError::Other(_) => (StatusCode::INTERNAL_SERVER_ERROR, "INTERNAL_ERROR"),
+        }
+    }
+
+    /// Returns the variant specific details of the error, if any.
+    fn details(&self) -> Option<ErrorDetails> {
+        match self {
+            AppError::InvalidNetworkId { expected, provided } => Some(ErrorDetails::NetworkId {
+                expected: expected.as_str().to_owned(),
+                provided: provided.as_str().to_owned(),
+            }),
+            AppError::InvalidBase64 { field } | AppError::InvalidHex { field } => {
+                Some(ErrorDetails::Field { field: field.clone().into_owned() })
+            },
+            AppError::InvalidPubKeyCommit { format } | AppError::InvalidSignature { format } => {
+                Some(ErrorDetails::Format { format: *format })
+            },
+            AppError::ApproverNotAuthorized { approver, tx_id } => {
+                Some(ErrorDetails::ApproverNotAuthorized {
+                    approver: approver.clone(),
+                    tx_id: *tx_id,
+                })
+            },
+            AppError::MultisigTxNotPending { status } => {
+                Some(ErrorDetails::TxStatus { status: (*status).into() })
+            },
+            AppError::InsufficientBalance { faucet_id, required, available } => {
+                Some(ErrorDetails::InsufficientBalance {
+                    faucet_id: faucet_id.to_hex(),
+                    required: *required,
+                    available: *available,
+                })
+            },
+            AppError::ApproverCountMismatch { on_chain, provided } => {
+                Some(ErrorDetails::ApproverCount { on_chain: *on_chain, provided: *provided })
+            },
+            AppError::ApproverKeyMismatch { index } => Some(ErrorDetails::Field {
+                field: format!("pub_key_commits[{index}]"),
+            }),
+            AppError::TooManyPendingProposals { max_pending_proposals } => {
+                Some(ErrorDetails::MaxPendingProposals {
+                    max_pending_proposals: max_pending_proposals.get(),
+                })
+            },
+            AppError::UnreachableThreshold { threshold, remaining_weight } => {
+                Some(ErrorDetails::RemainingWeight {
+                    threshold: *threshold,
+                    remaining_weight: *remaining_weight,
+                })
+            },
+            AppError::ThresholdRaised { threshold, current } => {
+                Some(ErrorDetails::Threshold { threshold: *threshold, current: *current })
+            },
+            AppError::TooManyUnknownInputNotes { unknown, note_import_limit } => {
+                Some(ErrorDetails::UnknownInputNotes {
+                    unknown: *unknown,
+                    note_import_limit: note_import_limit.get(),
+                })
+            },
+            _ => None,
+        }
+    }
+
+    fn body(&self) -> ErrorBody {
+        let (_, code) = self.status_and_code();
+
+        ErrorBody {
+            code,
+            message: self.to_string(),
+            details: self.details(),
+        }
+    }
 }
 
 impl From<MultisigEngineError> for AppError {
     fn from(err: MultisigEngineError) -> Self {
+        if let Some((approver, tx_id)) = err.approver_not_authorized() {
+            return Self::ApproverNotAuthorized {
+                approver: approver.to_owned(),
+                tx_id: tx_id.into(),
+            };
+        }
+
+        if err.multisig_account_not_found().is_some() {
+            return Self::MultisigAccountNotFound;
+        }
+
+        if err.multisig_tx_not_found().is_some() {
+            return Self::MultisigTxNotFound;
+        }
+
+        if let Some(status) = err.multisig_tx_not_pending() {
+            return Self::MultisigTxNotPending { status };
+        }
+
+        if let Some((faucet_id, required, available)) = err.insufficient_balance() {
+            return Self::InsufficientBalance { faucet_id, required, available };
+        }
+
+        if let Some(max_pending_proposals) = err.too_many_pending_proposals() {
+            return Self::TooManyPendingProposals { max_pending_proposals };
+        }
+
+        if err.has_pending_txs() {
+            return Self::MultisigAccountHasPendingTxs;
+        }
+
+        if err.is_commitment_altered() {
+            return Self::MultisigTxCommitmentAltered;
+        }
+
+        if err.is_invalid_signature() {
+            return Self::SignatureNotVerified;
+        }
+
+        if let Some((threshold, remaining_weight)) = err.unreachable_threshold() {
+            return Self::UnreachableThreshold { threshold, remaining_weight };
+        }
+
+        if let Some((threshold, current)) = err.threshold_raised() {
+            return Self::ThresholdRaised { threshold, current };
+        }
+
+        if let Some(account_id) = err.multisig_account_already_exists() {
+            return Self::MultisigAccountAlreadyExists { account_id };
+        }
+
+        if let Some(account_id) = err.not_multisig_account() {
+            return Self::NotMultisigAccount { account_id };
+        }
+
+        if let Some((on_chain, provided)) = err.approver_count_mismatch() {
+            return Self::ApproverCountMismatch { on_chain, provided };
+        }
+
+        if let Some(index) = err.approver_key_mismatch() {
+            return Self::ApproverKeyMismatch { index };
+        }
+
+        if let Some(reason) = err.invalid_multisig_config() {
+            return Self::InvalidMultisigConfig(reason.to_owned());
+        }
+
+        if let Some(reason) = err.invalid_note_selection() {
+            return Self::InvalidNoteSelection(reason.to_owned());
+        }
+
+        if let Some((unknown, note_import_limit)) = err.too_many_unknown_input_notes() {
+            return Self::TooManyUnknownInputNotes { unknown, note_import_limit };
+        }
+
+        if let Some(reason) = err.invalid_payment() {
+            return Self::InvalidPayment(reason);
+        }
+
+        if err.is_tx_summary_mismatch() {
+            return Self::TxSummaryMismatch;
+        }
+
+        if err.is_overloaded() {
+            return Self::Overloaded;
+        }
+
+        if err.is_timeout() {
+            return Self::Timeout;
+        }
+
         Self::MultisigEngine(err.into())
     }
 }
 
 impl From<AccountIdAddressError> for AppError {
     fn from(err: AccountIdAddressError) -> Self {
-        Self::InvalidAccountIdAddress(err.to_string().into())
+        match err {
+            AccountIdAddressError::NetworkIdMismatch { expected, provided } => {
+                Self::InvalidNetworkId { expected, provided }
+            },
+            err => Self::InvalidAccountIdAddress(err.to_string().into()),
+        }
+    }
+}
+
+impl From<JsonRejection> for AppError {
+    fn from(rejection: JsonRejection) -> Self {
+        Self::InvalidJsonBody {
+            status: rejection.status(),
+            message: rejection.body_text(),
+        }
+    }
+}
+
+impl From<PathRejection> for AppError {
+    fn from(rejection: PathRejection) -> Self {
+        Self::InvalidPath(rejection.body_text())
+    }
+}
+
+impl From<QueryRejection> for AppError {
+    fn from(rejection: QueryRejection) -> Self {
+        Self::InvalidQuery(rejection.body_text())
     }
 }
 
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
-        let code = match self {
-            AppError::InvalidNetworkId
-            | AppError::InvalidAccountIdAddress(_)
-            | AppError::InvalidPubKeyCommit
-            | AppError::InvalidTransactionRequest
-            | AppError::InvalidSignature
-            | AppError::InvalidMultisigTxStatus
-            | AppError::RequestError(_) => {
-                tracing::warn!("client error: {}", self);
-                StatusCode::BAD_REQUEST
-            },
-            AppError::MultisigAccountNotFound => {
-                tracing::info!("multisig account not found");
-                StatusCode::NOT_FOUND
-            },
-            AppError::MultisigEngine(_) | AppError::JoinError(_) | AppError::Other(_) => {
-                tracing::error!("server error: {}", self);
-                StatusCode::INTERNAL_SERVER_ERROR
-            },
+        let (status, _) = self.status_and_code();
+
+        if status == StatusCode::NOT_FOUND {
+            tracing::info!("client error: {}", self);
+        } else if status.is_client_error() {
+            tracing::warn!("client error: {}", self);
+        } else {
+            tracing::error!("server error: {}", self);
+        }
+
+        (status, Json(self.body())).into_response()
+    }
+}
+
+/// The JSON envelope every error response is returned in.
+#[derive(Debug, Serialize)]
+struct ErrorBody {
+    /// The stable machine-readable code of the error.
+    code: &'static str,
+
+    /// The human-readable description of the error.
+    message: String,
+
+    /// The variant specific details of the error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    details: Option<ErrorDetails>,
+}
+
+/// The variant specific details of an error, serialized as a plain JSON object.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum ErrorDetails {
+    NetworkId {
+        expected: String,
+        provided: String,
+    },
+    Field {
+        field: String,
+    },
+    Format {
+        format: &'static str,
+    },
+    ApproverNotAuthorized {
+        approver: String,
+        tx_id: Uuid,
+    },
+    TxStatus {
+        status: &'static str,
+    },
+    InsufficientBalance {
+        faucet_id: String,
+        required: u64,
+        available: u64,
+    },
+    ApproverCount {
+        on_chain: usize,
+        provided: usize,
+    },
+    MaxPendingProposals {
+        max_pending_proposals: u32,
+    },
+    RemainingWeight {
+        threshold: u64,
+        remaining_weight: u64,
+    },
+    Threshold {
+        threshold: u64,
+        current: u64,
+    },
+    UnknownInputNotes {
+        unknown: usize,
+        note_import_limit: usize,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use core::num::{NonZeroU32, NonZeroUsize};
+
+    use axum::http::StatusCode;
+    use miden_client::account::{AccountId, NetworkId};
+    use miden_multisig_coordinator_domain::tx::MultisigTxStatus;
+    use miden_objects::testing::account_id::ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use super::AppError;
+
+    #[test]
+    fn invalid_network_id_error_message_contains_both_hrps() {
+        // Arrange
+        let err = AppError::InvalidNetworkId {
+            expected: NetworkId::Testnet,
+            provided: NetworkId::Mainnet,
+        };
+
+        // Act
+        let msg = err.to_string();
+
+        // Assert
+        assert!(msg.contains(NetworkId::Testnet.as_str()));
+        assert!(msg.contains(NetworkId::Mainnet.as_str()));
+    }
+
+    #[test]
+    fn not_found_error_is_returned_in_json_envelope() {
+        // Arrange
+        let err = AppError::MultisigAccountNotFound;
+
+        // Act
+        let (status, _) = err.status_and_code();
+        let body = serde_json::to_value(err.body()).unwrap();
+
+        // Assert
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(
+            body,
+            json!({
+                "code": "MULTISIG_ACCOUNT_NOT_FOUND",
+                "message": "multisig account not found error",
+            }),
+        );
+    }
+
+    #[test]
+    fn bad_request_error_is_returned_in_json_envelope_with_details() {
+        // Arrange
+        let err = AppError::InvalidNetworkId {
+            expected: NetworkId::Testnet,
+            provided: NetworkId::Mainnet,
+        };
+
+        // Act
+        let (status, _) = err.status_and_code();
+        let body = serde_json::to_value(err.body()).unwrap();
+
+        // Assert
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(
+            body,
+            json!({
+                "code": "INVALID_NETWORK_ID",
+                "message": err.to_string(),
+                "details": {
+                    "expected": NetworkId::Testnet.as_str(),
+                    "provided": NetworkId::Mainnet.as_str(),
+                },
+            }),
+        );
+    }
+
+    #[test]
+    fn approver_not_authorized_error_is_forbidden_with_approver_and_tx_id_details() {
+        // Arrange
+        let tx_id = Uuid::new_v4();
+
+        let err = AppError::ApproverNotAuthorized { approver: "mtst1abc".to_owned(), tx_id };
+
+        // Act
+        let (status, _) = err.status_and_code();
+        let body = serde_json::to_value(err.body()).unwrap();
+
+        // Assert
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert_eq!(
+            body,
+            json!({
+                "code": "APPROVER_NOT_AUTHORIZED",
+                "message": err.to_string(),
+                "details": {
+                    "approver": "mtst1abc",
+                    "tx_id": tx_id.to_string(),
+                },
+            }),
+        );
+    }
+
+    #[test]
+    fn multisig_tx_not_pending_error_is_conflict_with_status_details() {
+        // Arrange
+        let err = AppError::MultisigTxNotPending { status: MultisigTxStatus::Success };
+
+        // Act
+        let (status, _) = err.status_and_code();
+        let body = serde_json::to_value(err.body()).unwrap();
+
+        // Assert
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(
+            body,
+            json!({
+                "code": "MULTISIG_TX_NOT_PENDING",
+                "message": err.to_string(),
+                "details": { "status": "success" },
+            }),
+        );
+    }
+
+    #[test]
+    fn insufficient_balance_error_is_unprocessable_with_amount_details() {
+        // Arrange
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET).unwrap();
+
+        let err = AppError::InsufficientBalance { faucet_id, required: 150, available: 100 };
+
+        // Act
+        let (status, _) = err.status_and_code();
+        let body = serde_json::to_value(err.body()).unwrap();
+
+        // Assert
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            body,
+            json!({
+                "code": "INSUFFICIENT_BALANCE",
+                "message": err.to_string(),
+                "details": {
+                    "faucet_id": faucet_id.to_hex(),
+                    "required": 150,
+                    "available": 100,
+                },
+            }),
+        );
+    }
+
+    #[test]
+    fn approver_count_mismatch_error_is_unprocessable_with_count_details() {
+        // Arrange
+        let err = AppError::ApproverCountMismatch { on_chain: 3, provided: 2 };
+
+        // Act
+        let (status, _) = err.status_and_code();
+        let body = serde_json::to_value(err.body()).unwrap();
+
+        // Assert
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            body,
+            json!({
+                "code": "APPROVER_COUNT_MISMATCH",
+                "message": err.to_string(),
+                "details": { "on_chain": 3, "provided": 2 },
+            }),
+        );
+    }
+
+    #[test]
+    fn approver_key_mismatch_error_is_unprocessable_with_field_details() {
+        // Arrange
+        let err = AppError::ApproverKeyMismatch { index: 1 };
+
+        // Act
+        let (status, _) = err.status_and_code();
+        let body = serde_json::to_value(err.body()).unwrap();
+
+        // Assert
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            body,
+            json!({
+                "code": "APPROVER_KEY_MISMATCH",
+                "message": err.to_string(),
+                "details": { "field": "pub_key_commits[1]" },
+            }),
+        );
+    }
+
+    #[test]
+    fn too_many_pending_proposals_error_is_conflict_with_limit_details() {
+        // Arrange
+        let max_pending_proposals = NonZeroU32::new(16).unwrap();
+        let err = AppError::TooManyPendingProposals { max_pending_proposals };
+
+        // Act
+        let (status, _) = err.status_and_code();
+        let body = serde_json::to_value(err.body()).unwrap();
+
+        // Assert
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(
+            body,
+            json!({
+                "code": "TOO_MANY_PENDING_PROPOSALS",
+                "message": err.to_string(),
+                "details": { "max_pending_proposals": 16 },
+            }),
+        );
+    }
+
+    #[test]
+    fn unreachable_threshold_error_is_unprocessable_with_weight_details() {
+        // Arrange
+        let err = AppError::UnreachableThreshold { threshold: 3, remaining_weight: 2 };
+
+        // Act
+        let (status, _) = err.status_and_code();
+        let body = serde_json::to_value(err.body()).unwrap();
+
+        // Assert
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            body,
+            json!({
+                "code": "UNREACHABLE_THRESHOLD",
+                "message": err.to_string(),
+                "details": { "threshold": 3, "remaining_weight": 2 },
+            }),
+        );
+    }
+
+    #[test]
+    fn multisig_account_has_pending_txs_error_is_conflict() {
+        // Arrange
+        let err = AppError::MultisigAccountHasPendingTxs;
+
+        // Act
+        let (status, _) = err.status_and_code();
+        let body = serde_json::to_value(err.body()).unwrap();
+
+        // Assert
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(
+            body,
+            json!({ "code": "MULTISIG_ACCOUNT_HAS_PENDING_TXS", "message": err.to_string() }),
+        );
+    }
+
+    #[test]
+    fn multisig_tx_commitment_altered_error_is_conflict() {
+        // Arrange
+        let err = AppError::MultisigTxCommitmentAltered;
+
+        // Act
+        let (status, _) = err.status_and_code();
+        let body = serde_json::to_value(err.body()).unwrap();
+
+        // Assert
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(
+            body,
+            json!({ "code": "MULTISIG_TX_COMMITMENT_ALTERED", "message": err.to_string() }),
+        );
+    }
+
+    #[test]
+    fn tx_summary_mismatch_error_is_bad_request() {
+        // Arrange
+        let err = AppError::TxSummaryMismatch;
+
+        // Act
+        let (status, _) = err.status_and_code();
+        let body = serde_json::to_value(err.body()).unwrap();
+
+        // Assert
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body, json!({ "code": "TX_SUMMARY_MISMATCH", "message": err.to_string() }));
+    }
+
+    #[test]
+    fn invalid_note_selection_error_is_unprocessable_with_reason_in_message() {
+        // Arrange
+        let err = AppError::InvalidNoteSelection("no consumable note selected".to_owned());
+
+        // Act
+        let (status, _) = err.status_and_code();
+        let body = serde_json::to_value(err.body()).unwrap();
+
+        // Assert
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            body,
+            json!({
+                "code": "INVALID_NOTE_SELECTION",
+                "message": "invalid note selection error: no consumable note selected",
+            }),
+        );
+    }
+
+    #[test]
+    fn too_many_unknown_input_notes_error_is_unprocessable_with_limit_details() {
+        // Arrange
+        let err = AppError::TooManyUnknownInputNotes {
+            unknown: 5,
+            note_import_limit: NonZeroUsize::new(4).unwrap(),
         };
 
-        (code, self.to_string()).into_response()
+        // Act
+        let (status, _) = err.status_and_code();
+        let body = serde_json::to_value(err.body()).unwrap();
+
+        // Assert
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            body,
+            json!({
+                "code": "TOO_MANY_UNKNOWN_INPUT_NOTES",
+                "message": err.to_string(),
+                "details": { "unknown": 5, "note_import_limit": 4 },
+            }),
+        );
+    }
+
+    #[test]
+    fn overloaded_error_is_service_unavailable() {
+        // Arrange
+        let err = AppError::Overloaded;
+
+        // Act
+        let (status, _) = err.status_and_code();
+        let body = serde_json::to_value(err.body()).unwrap();
+
+        // Assert
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body, json!({ "code": "OVERLOADED", "message": err.to_string() }));
+    }
+
+    #[test]
+    fn timeout_error_is_gateway_timeout() {
+        // Arrange
+        let err = AppError::Timeout;
+
+        // Act
+        let (status, _) = err.status_and_code();
+        let body = serde_json::to_value(err.body()).unwrap();
+
+        // Assert
+        assert_eq!(status, StatusCode::GATEWAY_TIMEOUT);
+        assert_eq!(body, json!({ "code": "TIMEOUT", "message": err.to_string() }));
     }
 }
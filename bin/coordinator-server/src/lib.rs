@@ -5,36 +5,173 @@
 
 pub mod config;
 
+mod auth;
+mod correlation;
 mod error;
+mod extract;
 mod payload;
+mod rate_limit;
 mod routes;
 
+#[cfg(test)]
+mod testing;
+
+pub use self::{auth::BearerTokens, rate_limit::RateLimiter};
+
 use std::sync::Arc;
 
-use axum::{Router, routing};
-use bon::Builder;
+use axum::{Router, middleware, routing};
 use dissolve_derive::Dissolve;
-use miden_multisig_coordinator_engine::{MultisigEngine, Started};
+use miden_client::account::AccountId;
+use miden_multisig_coordinator_engine::{EventSink, MultisigEngine, Started};
 
 /// Creates and configures the main application router with all API endpoints.
 ///
 /// # Endpoints
 ///
+/// ## Errors
+///
+/// Errors are returned as a JSON envelope with a stable machine-readable `code`, clients are
+/// expected to match on the `code` rather than on the human-readable `message`. The `details`
+/// object is only present for errors carrying structured details.
+///
+/// ```json
+/// {
+///   "code": "INVALID_NETWORK_ID",
+///   "message": "invalid network id error: expected `mtst`, provided `mm`",
+///   "details": {
+///     "expected": "mtst",
+///     "provided": "mm"
+///   }
+/// }
+/// ```
+///
+/// | code | status | meaning |
+/// |------|--------|---------|
+/// | `INVALID_NETWORK_ID` | 400 | the address belongs to another network, `details` holds the `expected` and `provided` network ids |
+/// | `INVALID_ACCOUNT_ID_ADDRESS` | 400 | the address is not a valid bech32 account address |
+/// | `INVALID_BASE64` | 400 | a binary field is not valid base64, `details` holds the `field`, e.g. `tx_request` or `signatures[1].signature` |
+/// | `INVALID_HEX` | 400 | a hex encoded signature or public key commitment is not valid hex, `details` holds the `field` |
+/// | `INVALID_PUB_KEY_COMMIT` | 400 | a public key commitment is well-encoded but not a valid commitment, `details` holds the `format` it was decoded from |
+/// | `INVALID_TRANSACTION_REQUEST` | 400 | the transaction request is well-encoded but cannot be deserialized |
+/// | `INVALID_TRANSACTION_SUMMARY` | 400 | the supplied transaction summary is well-encoded but cannot be deserialized |
+/// | `INVALID_TRANSACTION_SUMMARY_COMMIT` | 400 | the supplied transaction summary commitment is well-encoded but not a valid commitment |
+/// | `INVALID_SIGNATURE` | 400 | a signature is well-encoded but cannot be deserialized, `details` holds the `format` it was decoded from |
+/// | `INVALID_SIGNATURE_FORMAT` | 400 | the signature format is neither `base64` nor `hex` |
+/// | `INVALID_KEY_SCHEME` | 400 | the key scheme is unknown |
+/// | `INVALID_MULTISIG_TX_STATUS` | 400 | the transaction status filter is unknown |
+/// | `INVALID_TX_SORT` | 400 | the transaction sort field or direction is unknown |
+/// | `INVALID_TREND_GRANULARITY` | 400 | the trend granularity is neither `day` nor `week` |
+/// | `INVALID_JSON_BODY` | 400, 413, 415 or 422 | the body is not JSON, is too large, lacks the `application/json` content type, or doesn't match the expected payload |
+/// | `INVALID_PATH` | 400 | a path parameter is malformed, e.g. a transaction id that is not a UUID |
+/// | `INVALID_QUERY` | 400 | the query string is missing a parameter or holds a malformed one |
+/// | `INVALID_REQUEST` | 400 | the request failed validation |
+/// | `TX_SUMMARY_MISMATCH` | 400 | the supplied transaction summary applies to another account or mismatches the one computed from the transaction request |
+/// | `UNAUTHORIZED` | 401 | the bearer token of a guarded endpoint is missing or wrong |
+/// | `APPROVER_NOT_AUTHORIZED` | 403 | the signing address is not an approver of the transaction's account, `details` holds the `approver` address and the `tx_id` |
+/// | `MULTISIG_ACCOUNT_NOT_FOUND` | 404 | the multisig account does not exist |
+/// | `MULTISIG_TX_NOT_FOUND` | 404 | the transaction does not exist |
+/// | `MULTISIG_TX_NOT_PENDING` | 409 | the transaction is no longer pending, `details` holds its `status` |
+/// | `MULTISIG_ACCOUNT_ALREADY_EXISTS` | 409 | the imported multisig account is already managed by the coordinator |
+/// | `TOO_MANY_PENDING_PROPOSALS` | 409 | the multisig account already has the maximum number of pending transactions, `details` holds the `max_pending_proposals` |
+/// | `MULTISIG_TX_COMMITMENT_ALTERED` | 409 | the summary commitment of the transaction would change although it holds signatures over it |
+/// | `INSUFFICIENT_BALANCE` | 422 | the multisig account holds less of an asset than the transaction pays out, `details` holds the `faucet_id` and the `required` and `available` amounts |
+/// | `NOT_MULTISIG_ACCOUNT` | 422 | the imported account is not authenticated by the multisig auth component |
+/// | `APPROVER_COUNT_MISMATCH` | 422 | the number of approvers of the imported account differs from its on-chain approver count, `details` holds the `on_chain` and `provided` counts |
+/// | `APPROVER_KEY_MISMATCH` | 422 | an approver key of the imported account doesn't match its on-chain commitment, `details` holds the `field` of the key |
+/// | `INVALID_MULTISIG_CONFIG` | 422 | the multisig account configuration cannot be met, e.g. its threshold exceeds the total weight of its approvers |
+/// | `INVALID_NOTE_SELECTION` | 422 | the consumable notes of the multisig account cannot satisfy the note selection, e.g. a selected note is not consumable by it |
+/// | `INVALID_PAYMENT` | 422 | the payment cannot be built into a transaction request, e.g. its note cannot carry the asset |
+/// | `TOO_MANY_UNKNOWN_INPUT_NOTES` | 422 | the transaction consumes more input notes unknown to the coordinator than it may import, `details` holds the number of `unknown` notes and the `note_import_limit` |
+/// | `SIGNATURE_NOT_VERIFIED` | 422 | the signature does not verify against the approver's key over the transaction summary commitment, e.g. it was made with another key scheme |
+/// | `TOO_MANY_REQUESTS` | 429 | the client exceeded the write rate limit, the multisig account exceeded the proposal rate limit, or an approver exceeded the signature rate limit of the account |
+/// | `ENGINE_ERROR` | 500 | the multisig engine failed to process the request |
+/// | `TASK_JOIN_ERROR` | 500 | a background task failed |
+/// | `INTERNAL_ERROR` | 500 | any other server error |
+/// | `OVERLOADED` | 503 | the multisig client runtime queue stayed full, the request may be retried later |
+/// | `TIMEOUT` | 504 | a database statement exceeded the statement timeout or the multisig client runtime didn't answer in time, the request may be retried later |
+///
+/// ---
+///
+/// ## Authentication
+///
+/// If API tokens are configured, the write endpoints, and optionally the read endpoints, are only
+/// served to requests carrying one of them in an `Authorization: Bearer <token>` header, other
+/// requests are rejected with `UNAUTHORIZED`. The health endpoints are always public.
+///
+/// ---
+///
+/// ## Correlation Ids
+///
+/// Every request is assigned a correlation id, returned in the `x-correlation-id` response header
+/// and attached as `correlation_id` to every log emitted while handling the request, including
+/// the logs of the multisig client runtime threads. A client may supply its own correlation id in
+/// the `x-correlation-id` request header, e.g. to follow a transaction from its proposal to its
+/// execution, as long as it is at most 64 ASCII alphanumeric characters, `-` or `_`. Otherwise a
+/// new UUID is generated.
+///
+/// ---
+///
 /// ## Health Check
 ///
-/// **`GET /health`** - Check if the server is running.
+/// **`GET /health/live`** - Check if the server is running. `GET /health` is an alias kept for
+/// backwards compatibility.
 ///
 /// ```bash
-/// curl -X GET http://localhost:59059/health
+/// curl -X GET http://localhost:59059/health/live
 /// ```
 ///
 /// Response: `200 OK`
 ///
+/// **`GET /health/ready`** - Check if the server is ready to serve requests, i.e. the database
+/// answers a `SELECT 1` and the multisig client runtime answers a ping.
+///
+/// ```bash
+/// curl -X GET http://localhost:59059/health/ready
+/// ```
+///
+/// Response: `200 OK` if ready, `503 Service Unavailable` otherwise
+///
+/// ---
+///
+/// ## Validate Address
+///
+/// **`POST /api/v1/address/validate`** - Checks whether a bech32 account address is well-formed
+/// and belongs to the network the server runs on, without creating anything.
+///
+/// ```bash
+/// curl -X POST http://localhost:59059/api/v1/address/validate \
+///   -H "Content-Type: application/json" \
+///   -d '{
+///     "address": "mtst1abc..."
+///   }'
+/// ```
+///
+/// Response:
+/// ```json
+/// {
+///   "is_valid": true,
+///   "network_id": "mtst",
+///   "matches_network": true
+/// }
+/// ```
+///
+/// Note: `network_id` is omitted if the address is malformed, in which case `error` describes
+/// why.
+///
 /// ---
 ///
 /// ## Create Multisig Account
 ///
 /// **`POST /api/v1/multisig-account/create`** - Creates a new multisig account with specified approvers and threshold.
+/// The optional `weights` give each approver a vote weight, the threshold being the total weight
+/// required; every approver weighs 1 if omitted, which is currently the only supported weight.
+/// The optional `labels` give approvers human-readable labels (up to 64 characters, `null` for an
+/// unlabeled approver), purely cosmetic coordinator metadata returned along with the approvers;
+/// an approver already labeled by another account keeps its label unless given a new one.
+/// Public key commitments are given in hex, with or without `0x` prefix, or in base64, the
+/// encoding being detected. The optional `address_interface`, `basic_wallet` or `unspecified`,
+/// is the interface encoded in the address of the account, `basic_wallet` if omitted.
 ///
 /// ```bash
 /// curl -X POST http://localhost:59059/api/v1/multisig-account/create \
@@ -50,6 +187,41 @@ use miden_multisig_coordinator_engine::{MultisigEngine, Started};
 ///       "<base64_encoded_public_key_1>",
 ///       "<base64_encoded_public_key_2>",
 ///       "<base64_encoded_public_key_3>"
+///     ],
+///     "weights": [1, 1, 1],
+///     "labels": ["Alice - CFO", null, null],
+///     "address_interface": "basic_wallet"
+///   }'
+/// ```
+///
+/// Response:
+/// ```json
+/// {
+///   "address": "mtst1xyz...",
+///   "created_at": "2025-10-19T12:00:00Z",
+///   "updated_at": "2025-10-19T12:00:00Z"
+/// }
+/// ```
+///
+/// ---
+///
+/// ## Import Multisig Account
+///
+/// **`POST /api/v1/multisig-account/import`** - Imports a multisig account created elsewhere, e.g.
+/// with the CLI, so that the coordinator manages it. The account must be public and on chain, its
+/// threshold and approver public keys are read from its storage. The storage doesn't hold the
+/// approver addresses, so `approvers` lists them in approver index order, one per on-chain public
+/// key. The account keeps the interface encoded in `multisig_account_address`.
+///
+/// ```bash
+/// curl -X POST http://localhost:59059/api/v1/multisig-account/import \
+///   -H "Content-Type: application/json" \
+///   -d '{
+///     "multisig_account_address": "mtst1xyz...",
+///     "approvers": [
+///       "mtst1abc...",
+///       "mtst1def...",
+///       "mtst1ghi..."
 ///     ]
 ///   }'
 /// ```
@@ -58,6 +230,7 @@ use miden_multisig_coordinator_engine::{MultisigEngine, Started};
 /// ```json
 /// {
 ///   "address": "mtst1xyz...",
+///   "threshold": 2,
 ///   "created_at": "2025-10-19T12:00:00Z",
 ///   "updated_at": "2025-10-19T12:00:00Z"
 /// }
@@ -68,12 +241,109 @@ use miden_multisig_coordinator_engine::{MultisigEngine, Started};
 /// ## Propose Transaction
 ///
 /// **`POST /api/v1/multisig-tx/propose`** - Proposes a new transaction for a multisig account.
+/// The optional `title` (up to 128 characters) and `memo` (up to 1024 characters) give approvers
+/// human-readable context; control characters are stripped.
 ///
 /// ```bash
 /// curl -X POST http://localhost:59059/api/v1/multisig-tx/propose \
 ///   -H "Content-Type: application/json" \
 ///   -d '{
 ///     "multisig_account_address": "mtst1xyz...",
+///     "tx_request": "<base64_encoded_transaction_request>",
+///     "tx_summary": "<optional_base64_encoded_transaction_summary>",
+///     "title": "Payroll October",
+///     "memo": "Monthly salaries for the core team",
+///     "include_tx_effect": true
+///   }'
+/// ```
+///
+/// Response:
+/// ```json
+/// {
+///   "tx_id": "550e8400-e29b-41d4-a716-446655440000",
+///   "tx_summary": "<base64_encoded_transaction_summary>",
+///   "tx_effect": {
+///     "assets_in": [],
+///     "assets_out": [
+///       {
+///         "faucet_id": "0x1a2b3c...",
+///         "amount": 100
+///       }
+///     ],
+///     "input_note_ids": [],
+///     "output_notes": [
+///       {
+///         "note_id": "0xdef456...",
+///         "counterparty": "mtst1charlie...",
+///         "assets": [
+///           {
+///             "faucet_id": "0x1a2b3c...",
+///             "amount": 100
+///           }
+///         ]
+///       }
+///     ]
+///   }
+/// }
+/// ```
+///
+/// `tx_effect` is only present if `include_tx_effect` is `true`. It is a human-readable preview
+/// decoded from `tx_summary`, listing the fungible assets added to or removed from the vault, the
+/// consumed unauthenticated input notes and the created output notes. `counterparty` is only set
+/// for public P2ID and P2IDE output notes. The raw `tx_summary` is always returned, so clients can
+/// verify the preview independently.
+///
+/// Integrators that already dry-run transactions against their own node may supply the resulting
+/// `tx_summary`. It is rejected if its commitment differs from the one of the summary computed by
+/// the coordinator, unless the server is configured with `trust_tx_summary`, in which case it is
+/// stored without dry-running the transaction request.
+///
+/// ---
+///
+/// ## Dry-run Transaction
+///
+/// **`POST /api/v1/multisig-tx/dry-run`** - Computes the summary and effect of a transaction for a
+/// multisig account exactly as proposing it would, without persisting anything. This lets
+/// proposers preview and iterate on a transaction before publishing it for signatures.
+///
+/// ```bash
+/// curl -X POST http://localhost:59059/api/v1/multisig-tx/dry-run \
+///   -H "Content-Type: application/json" \
+///   -d '{
+///     "multisig_account_address": "mtst1xyz...",
+///     "tx_request": "<base64_encoded_transaction_request>"
+///   }'
+/// ```
+///
+/// Response:
+/// ```json
+/// {
+///   "tx_summary": "<base64_encoded_transaction_summary>",
+///   "tx_effect": {
+///     "assets_in": [],
+///     "assets_out": [],
+///     "input_note_ids": [],
+///     "output_notes": []
+///   }
+/// }
+/// ```
+///
+/// `tx_effect` has the same shape as in the propose response.
+///
+/// ---
+///
+/// ## Validate Transaction
+///
+/// **`POST /api/v1/multisig-tx/validate`** - Checks whether a transaction for a multisig account
+/// is executable, e.g. that the account holds enough of the assets it pays out and that the notes
+/// it consumes are valid, by running the same balance check as proposing it would, then the same
+/// dry-run as the dry-run endpoint, without persisting anything.
+///
+/// ```bash
+/// curl -X POST http://localhost:59059/api/v1/multisig-tx/validate \
+///   -H "Content-Type: application/json" \
+///   -d '{
+///     "multisig_account_address": "mtst1xyz...",
 ///     "tx_request": "<base64_encoded_transaction_request>"
 ///   }'
 /// ```
@@ -81,17 +351,53 @@ use miden_multisig_coordinator_engine::{MultisigEngine, Started};
 /// Response:
 /// ```json
 /// {
+///   "executable": true,
+///   "tx_summary": "<base64_encoded_transaction_summary>",
+///   "reason": null
+/// }
+/// ```
+///
+/// Note: a transaction that is not executable is not an error, the response then has
+/// `executable` set to `false`, `tx_summary` set to `null` and `reason` describing why. Failing to
+/// reach the node is an error rather than a verdict, returned with a `5xx` status.
+///
+/// ---
+///
+/// ## Re-propose Transaction
+///
+/// **`POST /api/v1/multisig-tx/repropose`** - Re-proposes a transaction with status `failure`, e.g.
+/// one that failed due to stale chain state. The stored transaction request is dry-run again to
+/// compute a fresh transaction summary and the transaction goes back to `pending`. Since the
+/// summary commitment changes, all previously collected signatures are invalidated and deleted;
+/// approvers must sign the new `tx_summary`.
+///
+/// ```bash
+/// curl -X POST http://localhost:59059/api/v1/multisig-tx/repropose \
+///   -H "Content-Type: application/json" \
+///   -d '{
+///     "tx_id": "550e8400-e29b-41d4-a716-446655440000"
+///   }'
+/// ```
+///
+/// Response:
+/// ```json
+/// {
 ///   "tx_id": "550e8400-e29b-41d4-a716-446655440000",
-///   "tx_summary": "<base64_encoded_transaction_summary>"
+///   "tx_summary": "<base64_encoded_new_transaction_summary>"
 /// }
 /// ```
 ///
+/// Re-proposing a transaction with any other status is rejected with a `409` with the
+/// `MULTISIG_TX_NOT_PENDING` code, an unknown transaction with a `404` with the
+/// `MULTISIG_TX_NOT_FOUND` code.
+///
 /// ---
 ///
 /// ## Add Signature
 ///
 /// **`POST /api/v1/signature/add`** - Submits an approver's signature for a pending transaction.
-/// If the signature threshold is met, the transaction is automatically processed.
+/// If the signature threshold is met, the transaction is automatically processed, unless
+/// `auto_process` is disabled in which case it moves to `ready_to_execute`.
 ///
 /// ```bash
 /// curl -X POST http://localhost:59059/api/v1/signature/add \
@@ -99,6 +405,7 @@ use miden_multisig_coordinator_engine::{MultisigEngine, Started};
 ///   -d '{
 ///     "tx_id": "550e8400-e29b-41d4-a716-446655440000",
 ///     "approver": "mtst1abc...",
+///     "scheme": "falcon",
 ///     "signature": "<base64_encoded_signature>"
 ///   }'
 /// ```
@@ -110,14 +417,84 @@ use miden_multisig_coordinator_engine::{MultisigEngine, Started};
 /// }
 /// ```
 ///
-/// Note: `tx_result` is `null` if threshold is not yet met, or contains the base64-encoded
-/// transaction result if the transaction was executed.
+/// Note: `tx_result` is `null` if threshold is not yet met or automatic processing is disabled, or
+/// contains the base64-encoded transaction result if the transaction was executed.
+///
+/// `scheme` is the approver key scheme the signature was made with, either `falcon` (default if
+/// omitted) or `ecdsa`. ECDSA signatures are 65 byte `r || s || v` secp256k1 signatures over the
+/// transaction summary commitment, they are validated on submission but not yet verified on chain.
+///
+/// `signature_format` is the encoding of `signature`, either `base64` (default if omitted) or
+/// `hex`, with or without `0x` prefix.
+///
+/// A signature of an account that is not an approver of the transaction's multisig account is
+/// rejected with `403 Forbidden` (`APPROVER_NOT_AUTHORIZED`), and a signature for an unknown
+/// transaction with `404 Not Found` (`MULTISIG_TX_NOT_FOUND`).
+///
+/// ---
+///
+/// ## Add Signatures (Batch)
+///
+/// **`POST /api/v1/signature/add-batch`** - Submits signatures from several approvers for a pending
+/// transaction at once. The batch is all-or-nothing: it is rejected as a whole if any approver is
+/// not authorized or any signature cannot be decoded, otherwise all signatures are persisted in a
+/// single database transaction. Each signature accepts the same optional `scheme` and
+/// `signature_format` as above.
+///
+/// ```bash
+/// curl -X POST http://localhost:59059/api/v1/signature/add-batch \
+///   -H "Content-Type: application/json" \
+///   -d '{
+///     "tx_id": "550e8400-e29b-41d4-a716-446655440000",
+///     "signatures": [
+///       { "approver": "mtst1abc...", "signature": "<base64_encoded_signature>" },
+///       { "approver": "mtst1def...", "signature": "<base64_encoded_signature>" }
+///     ]
+///   }'
+/// ```
+///
+/// Response:
+/// ```json
+/// {
+///   "accepted": 2,
+///   "threshold_met": true,
+///   "tx_result": "<base64_encoded_transaction_result_if_threshold_met>"
+/// }
+/// ```
+///
+/// ---
+///
+/// ## Execute Transaction
+///
+/// **`POST /api/v1/multisig-tx/execute`** - Processes and submits a transaction with status
+/// `ready_to_execute`, i.e. one that met its signature threshold while `auto_process` is disabled.
+/// The transaction moves to `executing` while it is processed, so that concurrent requests execute
+/// it once. Executing a transaction with any other status is rejected with `409`.
+///
+/// ```bash
+/// curl -X POST http://localhost:59059/api/v1/multisig-tx/execute \
+///   -H "Content-Type: application/json" \
+///   -d '{
+///     "tx_id": "550e8400-e29b-41d4-a716-446655440000"
+///   }'
+/// ```
+///
+/// Response:
+/// ```json
+/// {
+///   "tx_result": "<base64_encoded_transaction_result>"
+/// }
+/// ```
 ///
 /// ---
 ///
 /// ## List Consumable Notes
 ///
 /// **`POST /api/v1/consumable-notes/list`** - Retrieves consumable notes' note-ids for an account.
+/// The optional `note_tag` (the tag's `u32` representation) narrows the notes to those carrying it.
+/// The notes are listed as of `block_num`, the block the coordinator is synced to. `stale` is
+/// `true` if the coordinator failed to sync with the node, so some notes may have been consumed
+/// since, in which case the request can be retried later.
 ///
 /// ```bash
 /// # Get consumable notes for a specific account
@@ -133,6 +510,14 @@ use miden_multisig_coordinator_engine::{MultisigEngine, Started};
 ///   -d '{
 ///     "address": null
 ///   }'
+///
+/// # Get consumable notes carrying a specific note tag
+/// curl -X POST http://localhost:59059/api/v1/consumable-notes/list \
+///   -H "Content-Type: application/json" \
+///   -d '{
+///     "address": "mtst1xyz...",
+///     "note_tag": 3221225472
+///   }'
 /// ```
 ///
 /// Response:
@@ -151,7 +536,9 @@ use miden_multisig_coordinator_engine::{MultisigEngine, Started};
 ///       "note_id": "0x789ghi...",
 ///       "note_id_file_bytes": "<base64_encoded_note_file>"
 ///     }
-///   ]
+///   ],
+///   "block_num": 123456,
+///   "stale": false
 /// }
 /// ```
 ///
@@ -159,7 +546,8 @@ use miden_multisig_coordinator_engine::{MultisigEngine, Started};
 ///
 /// ## Get Multisig Account Details
 ///
-/// **`POST /api/v1/multisig-account/details`** - Retrieves details of a multisig account.
+/// **`POST /api/v1/multisig-account/details`** - Retrieves details of a multisig account along with
+/// its approvers, ordered by approver index.
 ///
 /// ```bash
 /// curl -X POST http://localhost:59059/api/v1/multisig-account/details \
@@ -178,22 +566,252 @@ use miden_multisig_coordinator_engine::{MultisigEngine, Started};
 ///     "threshold": 2,
 ///     "created_at": "2025-10-19T12:00:00Z",
 ///     "updated_at": "2025-10-19T12:00:00Z"
+///   },
+///   "approvers": [
+///     {
+///       "address": "mtst1abc...",
+///       "scheme": "falcon",
+///       "pub_key_commit": "<base64_encoded_public_key_1>",
+///       "label": "Alice - CFO"
+///     },
+///     {
+///       "address": "mtst1def...",
+///       "scheme": "falcon",
+///       "pub_key_commit": "<base64_encoded_public_key_2>",
+///       "label": null
+///     }
+///   ]
+/// }
+/// ```
+///
+/// ---
+///
+/// ## Get Multisig Account Summary
+///
+/// **`GET /api/v1/multisig-account/summary`** - Retrieves only the threshold and the number of
+/// approvers of a multisig account, e.g. to render "2 of 3" in list views.
+///
+/// ```bash
+/// curl -X GET "http://localhost:59059/api/v1/multisig-account/summary?multisig_account_address=mtst1xyz..."
+/// ```
+///
+/// Response:
+/// ```json
+/// {
+///   "threshold": 2,
+///   "approver_count": 3
+/// }
+/// ```
+///
+/// ---
+///
+/// ## Get Multisig Approver Order
+///
+/// **`GET /api/v1/multisig-account/approver-order`** - Retrieves the approvers of a multisig
+/// account along with their index, in index order. The index is the position of the approver's
+/// public key in the on-chain account storage, which clients need to order signatures.
+///
+/// ```bash
+/// curl -X GET "http://localhost:59059/api/v1/multisig-account/approver-order?multisig_account_address=mtst1xyz..."
+/// ```
+///
+/// Response:
+/// ```json
+/// {
+///   "approvers": [
+///     {
+///       "index": 0,
+///       "address": "mtst1abc...",
+///       "scheme": "falcon",
+///       "pub_key_commit": "<base64_encoded_public_key_1>",
+///       "label": "Alice - CFO"
+///     },
+///     {
+///       "index": 1,
+///       "address": "mtst1def...",
+///       "scheme": "falcon",
+///       "pub_key_commit": "<base64_encoded_public_key_2>",
+///       "label": null
+///     }
+///   ]
+/// }
+/// ```
+///
+/// ---
+///
+/// ## Reconcile Multisig Account
+///
+/// **`POST /api/v1/admin/multisig-account/reconcile`** - Compares the stored view of a multisig
+/// account against its live on-chain threshold and approver public keys and reports every
+/// difference. An empty `mismatches` list means the store is in sync with the chain. A `null`
+/// `stored` or `on_chain` public key means there is no approver at that index on the respective
+/// side.
+///
+/// Note: this is an admin endpoint, only served if an admin token is configured and only to
+/// requests carrying it as a bearer token.
+///
+/// ```bash
+/// curl -X POST http://localhost:59059/api/v1/admin/multisig-account/reconcile \
+///   -H "Authorization: Bearer $ADMIN_TOKEN" \
+///   -H "Content-Type: application/json" \
+///   -d '{
+///     "multisig_account_address": "mtst1xyz..."
+///   }'
+/// ```
+///
+/// Response:
+/// ```json
+/// {
+///   "on_chain_threshold": 3,
+///   "on_chain_pub_key_commits": [
+///     "<base64_encoded_public_key_1>",
+///     "<base64_encoded_public_key_2>",
+///     "<base64_encoded_public_key_4>"
+///   ],
+///   "mismatches": [
+///     { "field": "threshold", "stored": 2, "on_chain": 3 },
+///     {
+///       "field": "pub_key_commit",
+///       "approver_index": 2,
+///       "stored": "<base64_encoded_public_key_3>",
+///       "on_chain": "<base64_encoded_public_key_4>"
+///     }
+///   ]
+/// }
+/// ```
+///
+/// ---
+///
+/// ## Reconcile Transactions
+///
+/// **`POST /api/v1/admin/multisig-tx/reconcile`** - Recovers transactions of a multisig account
+/// stuck with status `pending`, `ready_to_execute` or `executing` although they were executed on
+/// chain, e.g. when the coordinator stopped between submitting a transaction and recording its
+/// status. Every such transaction matching a transaction committed on chain is marked as
+/// `success`. The ids of the transactions whose stored data cannot be deserialized, which are left
+/// out of the reconciliation, are listed in `corrupt_tx_ids` if there are any.
+///
+/// Note: this is an admin endpoint, only served if an admin token is configured and only to
+/// requests carrying it as a bearer token.
+///
+/// ```bash
+/// curl -X POST http://localhost:59059/api/v1/admin/multisig-tx/reconcile \
+///   -H "Authorization: Bearer $ADMIN_TOKEN" \
+///   -H "Content-Type: application/json" \
+///   -d '{
+///     "multisig_account_address": "mtst1xyz..."
+///   }'
+/// ```
+///
+/// Response:
+/// ```json
+/// {
+///   "reconciled_tx_ids": ["550e8400-e29b-41d4-a716-446655440000"]
+/// }
+/// ```
+///
+/// ---
+///
+/// ## Archive Multisig Account
+///
+/// **`POST /api/v1/multisig-account/archive`** - Archives a multisig account, or unarchives it
+/// when `archived` is `false`. Archived accounts keep their approvers and transactions and are
+/// still retrieved by address, they are only hidden from default account listings.
+///
+/// ```bash
+/// curl -X POST http://localhost:59059/api/v1/multisig-account/archive \
+///   -H "Content-Type: application/json" \
+///   -d '{
+///     "multisig_account_address": "mtst1xyz...",
+///     "archived": true
+///   }'
+/// ```
+///
+/// Response:
+/// ```json
+/// {
+///   "multisig_account": {
+///     "address": "mtst1xyz...",
+///     "kind": "public",
+///     "threshold": 2,
+///     "created_at": "2025-10-19T12:00:00Z",
+///     "updated_at": "2025-10-19T12:00:00Z",
+///     "archived_at": "2025-11-07T12:00:00Z"
 ///   }
 /// }
 /// ```
 ///
 /// ---
 ///
+/// ## List Accounts
+///
+/// **`GET /api/v1/admin/accounts`** - Lists the multisig accounts in creation order, each with
+/// its approver count and the number of its transactions not executed yet, i.e. pending or ready
+/// to execute. Accounts are listed in pages of up to `limit` accounts, 100 if not given and at
+/// most 1000, the `next_cursor` of a page being passed back as `after` to list the next one.
+/// Archived accounts are only listed if `include_archived` is `true`.
+///
+/// Note: this is an admin endpoint, only served if an admin token is configured and only to
+/// requests carrying it as a bearer token.
+///
+/// ```bash
+/// curl -X GET "http://localhost:59059/api/v1/admin/accounts?after=AAZBgbTKMABtdHN0MXh5ei4uLg&limit=50" \
+///   -H "Authorization: Bearer $ADMIN_TOKEN"
+/// ```
+///
+/// Response:
+/// ```json
+/// {
+///   "accounts": [
+///     {
+///       "address": "mtst1xyz...",
+///       "kind": "public",
+///       "threshold": 2,
+///       "created_at": "2025-10-19T12:00:00Z",
+///       "updated_at": "2025-10-19T12:00:00Z",
+///       "approver_count": 3,
+///       "pending_tx_count": 1
+///     }
+///   ],
+///   "next_cursor": "AAZBgbTKMABtdHN0MXh5ei4uLg"
+/// }
+/// ```
+///
+/// ---
+///
 /// ## List Approvers
 ///
 /// **`POST /api/v1/multisig-account/approver/list`** - Lists all approvers for a specific multisig account.
 ///
+/// Approvers are listed in approver index order. Large approver sets can be listed in pages of up
+/// to `limit` approvers, the `next_cursor` of a page being passed back as `after` to list the
+/// next one. Approvers can be filtered by a text their label contains with `label_contains`,
+/// ignoring case.
+///
 /// ```bash
+/// # List all approvers
 /// curl -X POST http://localhost:59059/api/v1/multisig-account/approver/list \
 ///   -H "Content-Type: application/json" \
 ///   -d '{
 ///     "multisig_account_address": "mtst1xyz..."
 ///   }'
+///
+/// # List the page of 50 approvers following the previous one
+/// curl -X POST http://localhost:59059/api/v1/multisig-account/approver/list \
+///   -H "Content-Type: application/json" \
+///   -d '{
+///     "multisig_account_address": "mtst1xyz...",
+///     "after": "AAAAAAAAADFtdHN0MWFiYy4uLg",
+///     "limit": 50
+///   }'
+///
+/// # List the approvers labeled as CFO
+/// curl -X POST http://localhost:59059/api/v1/multisig-account/approver/list \
+///   -H "Content-Type: application/json" \
+///   -d '{
+///     "multisig_account_address": "mtst1xyz...",
+///     "label_contains": "cfo"
+///   }'
 /// ```
 ///
 /// Response:
@@ -202,15 +820,21 @@ use miden_multisig_coordinator_engine::{MultisigEngine, Started};
 ///   "approvers": [
 ///     {
 ///       "address": "mtst1abc...",
-///       "pub_key_commit": "<base64_encoded_public_key_1>"
+///       "scheme": "falcon",
+///       "pub_key_commit": "<base64_encoded_public_key_1>",
+///       "label": "Alice - CFO"
 ///     },
 ///     {
 ///       "address": "mtst1def...",
-///       "pub_key_commit": "<base64_encoded_public_key_2>"
+///       "scheme": "falcon",
+///       "pub_key_commit": "<base64_encoded_public_key_2>",
+///       "label": null
 ///     },
 ///     {
 ///       "address": "mtst1ghi...",
-///       "pub_key_commit": "<base64_encoded_public_key_3>"
+///       "scheme": "falcon",
+///       "pub_key_commit": "<base64_encoded_public_key_3>",
+///       "label": null
 ///     }
 ///   ]
 /// }
@@ -221,12 +845,20 @@ use miden_multisig_coordinator_engine::{MultisigEngine, Started};
 /// ## Get Transaction Statistics
 ///
 /// **`POST /api/v1/multisig-tx/stats`** - Retrieves transaction statistics for a multisig account.
+/// `approvers` lists, in approver index order, how many of the account's transactions each
+/// approver signed. `avg_time_to_threshold_secs` and `p95_time_to_threshold_secs` are the average
+/// and 95th percentile, in seconds, of the time executed transactions took from their proposal to
+/// meeting the threshold, both are omitted until a transaction is executed. The optional
+/// `trend_granularity`, `day` or `week`, adds a `trend` of how many transactions were proposed in
+/// each day or week, starting at midnight UTC, on Mondays for weeks, oldest first, leaving out
+/// those without transactions.
 ///
 /// ```bash
 /// curl -X POST http://localhost:59059/api/v1/multisig-tx/stats \
 ///   -H "Content-Type: application/json" \
 ///   -d '{
-///     "multisig_account_address": "mtst1xyz..."
+///     "multisig_account_address": "mtst1xyz...",
+///     "trend_granularity": "day"
 ///   }'
 /// ```
 ///
@@ -236,17 +868,99 @@ use miden_multisig_coordinator_engine::{MultisigEngine, Started};
 ///   "tx_stats": {
 ///     "total": 42,
 ///     "last_month": 15,
-///     "total_success": 38
+///     "total_success": 38,
+///     "approvers": [
+///       { "address": "mtst1abc...", "signed_count": 42 },
+///       { "address": "mtst1def...", "signed_count": 17 },
+///       { "address": "mtst1ghi...", "signed_count": 0 }
+///     ],
+///     "avg_time_to_threshold_secs": 5400.5,
+///     "p95_time_to_threshold_secs": 86400.0
+///   },
+///   "trend": [
+///     { "start": "2025-11-03T00:00:00Z", "count": 4 },
+///     { "start": "2025-11-05T00:00:00Z", "count": 1 }
+///   ]
+/// }
+/// ```
+///
+/// ---
+///
+/// ## Get Transaction by Summary Commitment
+///
+/// **`POST /api/v1/multisig-tx/by-summary-commit`** - Retrieves a transaction by the commitment
+/// of its summary, i.e. the message its approvers sign, so that an approver signing offline can
+/// confirm it is signing the right proposal before submitting its signature. If identical
+/// proposals share the commitment, the most recent one is returned.
+///
+/// ```bash
+/// curl -X POST http://localhost:59059/api/v1/multisig-tx/by-summary-commit \
+///   -H "Content-Type: application/json" \
+///   -d '{
+///     "tx_summary_commit": "<base64_encoded_transaction_summary_commitment>"
+///   }'
+/// ```
+///
+/// Response:
+/// ```json
+/// {
+///   "tx": {
+///     "id": "550e8400-e29b-41d4-a716-446655440000",
+///     "multisig_account_address": "mtst1xyz...",
+///     "status": "pending",
+///     "tx_request": "<base64_encoded_transaction_request>",
+///     "tx_summary": "<base64_encoded_transaction_summary>",
+///     "tx_summary_commit": "<base64_encoded_transaction_summary_commitment>",
+///     "input_note_ids": [],
+///     "signature_count": 1,
+///     "created_at": "2025-10-19T12:00:00Z",
+///     "updated_at": "2025-10-19T12:00:00Z"
 ///   }
 /// }
 /// ```
 ///
+/// Note: `tx` has the same shape as in the list response. A `404` with the
+/// `MULTISIG_TX_NOT_FOUND` code is returned if no transaction has this commitment.
+///
+/// ---
+///
+/// ## Get Transaction Summary
+///
+/// **`GET /api/v1/multisig-tx/{tx_id}/summary`** - Retrieves the summary of a pending transaction
+/// and its commitment, i.e. the message its approvers sign, so that an approver who lost the
+/// summary can fetch again what it signs without fetching the whole transaction.
+///
+/// ```bash
+/// curl -X GET http://localhost:59059/api/v1/multisig-tx/550e8400-e29b-41d4-a716-446655440000/summary
+/// ```
+///
+/// Response:
+/// ```json
+/// {
+///   "tx_summary": "<base64_encoded_transaction_summary>",
+///   "tx_summary_commit": "<base64_encoded_transaction_summary_commitment>"
+/// }
+/// ```
+///
+/// Note: A `404` with the `MULTISIG_TX_NOT_FOUND` code is returned for an unknown transaction,
+/// and a `409` with the `MULTISIG_TX_NOT_PENDING` code for a transaction which is no longer
+/// pending, `details` holding its `status`.
+///
 /// ---
 ///
 /// ## List Transactions
 ///
-/// **`POST /api/v1/multisig-tx/list`** - Lists all transactions for a multisig account,
-/// optionally filtered by status.
+/// **`POST /api/v1/multisig-tx/list`** - Lists all transactions for a multisig account, most
+/// recent first, optionally filtered by status.
+///
+/// The transactions can be sorted by `created_at`, `updated_at` or `signature_count`, in `asc`
+/// or `desc` direction, the direction defaulting to `desc`. Transactions sorting equal are
+/// ordered by id.
+///
+/// Large histories can be listed in pages of up to `limit` transactions: the response to a
+/// request with a `limit` carries a `next_cursor` while more transactions remain, to be passed
+/// back as `after`, along with the same `sort`, to list the next page. The cursor is an opaque
+/// URL-safe string, to be passed back as is.
 ///
 /// ```bash
 /// # List all transactions
@@ -257,13 +971,30 @@ use miden_multisig_coordinator_engine::{MultisigEngine, Started};
 ///     "tx_status_filter": null
 ///   }'
 ///
-/// # Filter by status (pending/success/failure)
+/// # Filter by status (pending/ready_to_execute/executing/success/failure)
 /// curl -X POST http://localhost:59059/api/v1/multisig-tx/list \
 ///   -H "Content-Type: application/json" \
 ///   -d '{
 ///     "multisig_account_address": "mtst1xyz...",
 ///     "tx_status_filter": "pending"
 ///   }'
+///
+/// # Sort by signature count, least signed first
+/// curl -X POST http://localhost:59059/api/v1/multisig-tx/list \
+///   -H "Content-Type: application/json" \
+///   -d '{
+///     "multisig_account_address": "mtst1xyz...",
+///     "sort": { "field": "signature_count", "direction": "asc" }
+///   }'
+///
+/// # List the page after a cursor
+/// curl -X POST http://localhost:59059/api/v1/multisig-tx/list \
+///   -H "Content-Type: application/json" \
+///   -d '{
+///     "multisig_account_address": "mtst1xyz...",
+///     "after": "AAZBgbTKMABVDoQA4ptB1KcWRGZVRAAA",
+///     "limit": 50
+///   }'
 /// ```
 ///
 /// Response:
@@ -283,44 +1014,331 @@ use miden_multisig_coordinator_engine::{MultisigEngine, Started};
 ///           "note_id_file_bytes": "<base64_encoded_note_file>"
 ///         }
 ///       ],
+///       "title": "Payroll October",
+///       "memo": "Monthly salaries for the core team",
 ///       "signature_count": 1,
+///       "tx_effect": {
+///         "assets_in": [],
+///         "assets_out": [
+///           {
+///             "faucet_id": "0x1a2b3c...",
+///             "amount": 100
+///           }
+///         ],
+///         "input_note_ids": [],
+///         "output_notes": [
+///           {
+///             "note_id": "0xdef456...",
+///             "counterparty": "mtst1charlie...",
+///             "assets": [
+///               {
+///                 "faucet_id": "0x1a2b3c...",
+///                 "amount": 100
+///               }
+///             ]
+///           }
+///         ]
+///       },
 ///       "created_at": "2025-10-19T12:00:00Z",
 ///       "updated_at": "2025-10-19T12:00:00Z"
 ///     }
-///   ]
+///   ],
+///   "next_cursor": "AAZBgbTKMABVDoQA4ptB1KcWRGZVRAAA"
+/// }
+/// ```
+///
+/// Note: `signature_count` is omitted if zero, `title` and `memo` are omitted if not set.
+/// `failure_reason` is only present for transactions with status `failure`. `chain_tx_id`, the hex
+/// id of the executed transaction to look up in a block explorer, and `submission_block_num`, the
+/// block it was executed against, are only present for transactions with status `success`, their
+/// absence means the transaction was not executed yet. `tx_effect` has the same shape as in the
+/// propose response, it is decoded once when the transaction is proposed and stored along with it.
+/// `next_cursor` is omitted on the last page, and always without a `limit`. Transactions whose
+/// stored data cannot be deserialized are left out of `txs` rather than failing the whole listing,
+/// their ids are then listed in `corrupt_tx_ids`, which is omitted otherwise.
+///
+/// ---
+///
+/// ## Export Transactions
+///
+/// **`GET /api/v1/multisig-tx/export`** - Exports all transactions of a multisig account, most
+/// recent first, as JSON Lines, one transaction per line. The transactions are fetched page by
+/// page while the response is written, so an export holds a bounded number of transactions in
+/// memory however long the account history is.
+///
+/// ```bash
+/// curl -X GET "http://localhost:59059/api/v1/multisig-tx/export?multisig_account_address=mtst1xyz..." -o txs.ndjson
+/// ```
+///
+/// Response, with `Content-Type: application/x-ndjson` and a
+/// `Content-Disposition: attachment; filename="mtst1xyz...-txs.ndjson"` header:
+/// ```text
+/// {"id":"550e8400-e29b-41d4-a716-446655440000","multisig_account_address":"mtst1xyz...",...}
+/// {"id":"6ba7b810-9dad-11d1-80b4-00c04fd430c8","multisig_account_address":"mtst1xyz...",...}
+/// ```
+///
+/// Note: each line has the same shape as a transaction of the list response. Transactions whose
+/// stored data cannot be deserialized are left out. An unknown account is reported with a
+/// `MULTISIG_ACCOUNT_NOT_FOUND` error, a failure once the export started aborts the response.
+///
+/// ---
+///
+/// ## List Transaction Statuses
+///
+/// **`GET /api/v1/multisig-tx/statuses`** - Lists every transaction status, in the order a
+/// transaction progresses through them, e.g. to build the status filter of the list endpoint.
+///
+/// ```bash
+/// curl -X GET http://localhost:59059/api/v1/multisig-tx/statuses
+/// ```
+///
+/// Response:
+/// ```json
+/// {
+///   "statuses": ["pending", "ready_to_execute", "executing", "success", "failure"]
+/// }
+/// ```
+///
+/// ---
+///
+/// ## Check Approver Signature
+///
+/// **`GET /api/v1/multisig-tx/{tx_id}/signed-by/{approver}`** - Checks whether an approver has
+/// already signed a transaction, e.g. to hide the sign button from approvers who already signed.
+///
+/// ```bash
+/// curl -X GET http://localhost:59059/api/v1/multisig-tx/550e8400-e29b-41d4-a716-446655440000/signed-by/mtst1abc...
+/// ```
+///
+/// Response:
+/// ```json
+/// {
+///   "signed": true
+/// }
+/// ```
+///
+/// Note: `signed` is `false` for an unknown transaction or an address that is not an approver.
+///
+/// ---
+///
+/// ## List Approver Pending Transactions
+///
+/// **`POST /api/v1/approver/pending-txs`** - Lists the pending transactions of every multisig
+/// account an approver belongs to, most recent first, each with whether the approver has already
+/// signed it, e.g. to build a feed of the proposals awaiting an approver. Transactions of archived
+/// accounts are left out.
+///
+/// The transactions are listed in pages of up to `limit` transactions, 100 if not given and at
+/// most 1000, as for List Transactions, by passing the `next_cursor` of a page back as `after`.
+///
+/// ```bash
+/// curl -X POST http://localhost:59059/api/v1/approver/pending-txs \
+///   -H "Content-Type: application/json" \
+///   -d '{
+///     "approver_address": "mtst1abc...",
+///     "limit": 50
+///   }'
+/// ```
+///
+/// Response:
+/// ```json
+/// {
+///   "txs": [
+///     {
+///       "id": "550e8400-e29b-41d4-a716-446655440000",
+///       "multisig_account_address": "mtst1xyz...",
+///       "status": "pending",
+///       ...
+///       "signed": false
+///     }
+///   ],
+///   "next_cursor": "AAZBgbTKMABVDoQA4ptB1KcWRGZVRAAA"
 /// }
 /// ```
 ///
-/// Note: `signature_count` is omitted if zero.
+/// Note: the transactions have the same shape as in List Transactions along with the `signed`
+/// flag, an address that is not an approver of any account has no pending transactions.
 pub fn create_router(app: App) -> Router {
-    Router::new()
-        .route("/health", routing::get(routes::health))
+    let write_routes = Router::new()
         .route(
             "/api/v1/multisig-account/create",
             routing::post(routes::create_multisig_account),
         )
+        .route(
+            "/api/v1/multisig-account/import",
+            routing::post(routes::import_multisig_account),
+        )
         .route("/api/v1/multisig-tx/propose", routing::post(routes::propose_multisig_tx))
+        .route("/api/v1/multisig-tx/dry-run", routing::post(routes::dry_run_multisig_tx))
+        .route("/api/v1/multisig-tx/validate", routing::post(routes::validate_multisig_tx))
+        .route("/api/v1/multisig-tx/repropose", routing::post(routes::repropose_multisig_tx))
+        .route("/api/v1/multisig-tx/execute", routing::post(routes::execute_multisig_tx))
         .route("/api/v1/signature/add", routing::post(routes::add_signature))
+        .route("/api/v1/signature/add-batch", routing::post(routes::add_signatures))
+        .route(
+            "/api/v1/multisig-account/archive",
+            routing::post(routes::archive_multisig_account),
+        );
+
+    // the rate limit is layered last, so that it also applies to requests failing authentication
+    let write_routes = require_bearer_token(write_routes, app.api_tokens.clone()).route_layer(
+        middleware::from_fn_with_state(app.write_rate_limiter.clone(), rate_limit::rate_limit),
+    );
+
+    let read_routes = Router::new()
+        .route("/api/v1/address/validate", routing::post(routes::validate_address))
         .route("/api/v1/consumable-notes/list", routing::post(routes::list_consumable_notes))
         .route(
             "/api/v1/multisig-account/details",
             routing::post(routes::get_multisig_account_details),
         )
+        .route(
+            "/api/v1/multisig-account/summary",
+            routing::get(routes::get_multisig_account_summary),
+        )
+        .route(
+            "/api/v1/multisig-account/approver-order",
+            routing::get(routes::get_multisig_approver_order),
+        )
         .route(
             "/api/v1/multisig-account/approver/list",
             routing::post(routes::list_multisig_approvers),
         )
         .route("/api/v1/multisig-tx/stats", routing::post(routes::get_multisig_tx_stats))
+        .route(
+            "/api/v1/multisig-tx/by-summary-commit",
+            routing::post(routes::get_multisig_tx_by_summary_commit),
+        )
         .route("/api/v1/multisig-tx/list", routing::post(routes::list_multisig_tx))
+        .route("/api/v1/multisig-tx/export", routing::get(routes::export_multisig_tx))
+        .route("/api/v1/multisig-tx/statuses", routing::get(routes::list_multisig_tx_statuses))
+        .route(
+            "/api/v1/multisig-tx/{tx_id}/summary",
+            routing::get(routes::get_multisig_tx_summary),
+        )
+        .route(
+            "/api/v1/multisig-tx/{tx_id}/signed-by/{approver}",
+            routing::get(routes::has_approver_signed),
+        )
+        .route("/api/v1/approver/pending-txs", routing::post(routes::list_approver_pending_txs));
+
+    let read_routes = if app.api_tokens_guard_reads {
+        require_bearer_token(read_routes, app.api_tokens.clone())
+    } else {
+        read_routes
+    };
+
+    // the admin endpoints are not routed at all unless an admin token is configured
+    let admin_routes = app.admin_token.clone().map(|admin_token| {
+        let admin_routes = Router::new()
+            .route("/api/v1/admin/accounts", routing::get(routes::list_multisig_accounts))
+            .route(
+                "/api/v1/admin/multisig-account/reconcile",
+                routing::post(routes::reconcile_multisig_account),
+            )
+            .route(
+                "/api/v1/admin/multisig-tx/reconcile",
+                routing::post(routes::reconcile_multisig_txs),
+            );
+
+        require_bearer_token(admin_routes, Some(admin_token))
+    });
+
+    Router::new()
+        .route("/health", routing::get(routes::health))
+        .route("/health/live", routing::get(routes::health))
+        .route("/health/ready", routing::get(routes::health_ready))
+        .merge(write_routes)
+        .merge(read_routes)
+        .merge(admin_routes.unwrap_or_default())
+        .layer(middleware::from_fn(correlation::correlate))
         .with_state(app)
 }
 
+/// Guards the routes with the bearer `tokens`, leaving them public if there are none.
+fn require_bearer_token(routes: Router<App>, tokens: Option<BearerTokens>) -> Router<App> {
+    match tokens {
+        Some(tokens) => {
+            routes.route_layer(middleware::from_fn_with_state(tokens, auth::require_bearer_token))
+        },
+        None => routes,
+    }
+}
+
 /// The main application state containing the multisig engine.
 ///
 /// This struct is passed to all route handlers and provides access to the
 /// core multisig functionality through the engine.
-#[derive(Clone, Builder, Dissolve)]
+#[derive(Clone, Dissolve)]
 pub struct App {
     /// The multisig engine instance that handles all multisig operations
     engine: Arc<MultisigEngine<Started>>,
+
+    /// The per-client-IP rate limiter guarding the write endpoints
+    write_rate_limiter: Arc<RateLimiter>,
+
+    /// The per-multisig-account rate limiter guarding transaction proposals and re-proposals
+    propose_rate_limiter: Arc<RateLimiter<AccountId>>,
+
+    /// The per-multisig-account and approver rate limiter guarding signature submissions, each
+    /// signature consuming a token of its approver
+    sign_rate_limiter: Arc<RateLimiter<(AccountId, AccountId)>>,
+
+    /// The bearer tokens guarding the write endpoints, which are public if unset
+    api_tokens: Option<BearerTokens>,
+
+    /// Whether the read endpoints are guarded by the `api_tokens` as well
+    api_tokens_guard_reads: bool,
+
+    /// The bearer token guarding the admin endpoints, which are disabled if unset
+    admin_token: Option<BearerTokens>,
+}
+
+#[bon::bon]
+impl App {
+    /// Creates the application state around a started engine.
+    ///
+    /// # Parameters
+    ///
+    /// * `engine` - The started multisig engine
+    /// * `event_sink` - Optional sink the engine emits its
+    ///   [`CoordinatorEvent`](miden_multisig_coordinator_engine::CoordinatorEvent)s to, e.g. to
+    ///   forward them to a message broker, the events are discarded if not given
+    /// * `write_rate_limiter`, `propose_rate_limiter`, `sign_rate_limiter` - The rate limiters
+    ///   guarding the write endpoints, transaction proposals and signature submissions
+    /// * `api_tokens` - Optional bearer tokens guarding the write endpoints
+    /// * `api_tokens_guard_reads` - Whether the `api_tokens` guard the read endpoints as well,
+    ///   `false` if not given
+    /// * `admin_token` - Optional bearer token guarding the admin endpoints
+    #[builder]
+    pub fn new(
+        engine: MultisigEngine<Started>,
+        event_sink: Option<Arc<dyn EventSink>>,
+        write_rate_limiter: Arc<RateLimiter>,
+        propose_rate_limiter: Arc<RateLimiter<AccountId>>,
+        sign_rate_limiter: Arc<RateLimiter<(AccountId, AccountId)>>,
+        api_tokens: Option<BearerTokens>,
+        #[builder(default)] api_tokens_guard_reads: bool,
+        admin_token: Option<BearerTokens>,
+    ) -> Self {
+        let engine = match event_sink {
+            Some(event_sink) => engine.with_event_sink(event_sink),
+            None => engine,
+        };
+
+        Self {
+            engine: Arc::new(engine),
+            write_rate_limiter,
+            propose_rate_limiter,
+            sign_rate_limiter,
+            api_tokens,
+            api_tokens_guard_reads,
+            admin_token,
+        }
+    }
+
+    /// Returns the multisig engine, e.g. to stop its runtime once the server shut down.
+    pub fn engine(&self) -> &Arc<MultisigEngine<Started>> {
+        &self.engine
+    }
 }
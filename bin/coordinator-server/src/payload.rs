@@ -7,14 +7,26 @@ use bon::Builder;
 use chrono::{DateTime, Utc};
 use miden_client::{
     Word,
-    account::Address,
+    account::{AccountIdAddress, AddressInterface, NetworkId},
     note::{NoteFile, NoteId},
     utils::Serializable,
 };
 use miden_multisig_coordinator_domain::{
-    account::{MultisigAccount, MultisigApprover, MultisigApproverDissolved},
-    tx::{MultisigTx, MultisigTxDissolved, MultisigTxStatus},
+    account::{
+        MultisigAccount, MultisigApprover, MultisigApproverDissolved, WithApprovers,
+        WithPubKeyCommits,
+    },
+    key::{ApproverKey, ApproverKeyScheme},
+    tx::{
+        MultisigTx, MultisigTxDissolved, MultisigTxStats, MultisigTxStatsDissolved,
+        MultisigTxStatus, TxAssetAmount, TxEffect, TxEffectDissolved, TxOutputNote,
+    },
 };
+use miden_multisig_coordinator_engine::response::{
+    MultisigAccountMismatch, MultisigAccountOverview, MultisigAccountOverviewDissolved,
+};
+use miden_multisig_coordinator_utils::to_bech32;
+use miden_objects::{block::BlockNumber, transaction::TransactionId};
 use serde::Serialize;
 use serde_with::{DisplayFromStr, base64::Base64};
 use uuid::Uuid;
@@ -26,6 +38,72 @@ pub struct MultisigAccountPayload {
     threshold: NonZeroU32,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    archived_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Builder, Serialize)]
+pub struct MultisigAccountOverviewPayload {
+    #[serde(flatten)]
+    account: MultisigAccountPayload,
+    approver_count: u32,
+    pending_tx_count: u64,
+}
+
+#[derive(Debug, Builder, Serialize)]
+pub struct ApproverPendingTxPayload {
+    #[serde(flatten)]
+    tx: MultisigTxPayload,
+    signed: bool,
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Builder, Serialize)]
+pub struct MultisigAccountApproverPayload {
+    address: String,
+
+    #[serde_as(as = "DisplayFromStr")]
+    scheme: ApproverKeyScheme,
+
+    #[serde_as(as = "Base64")]
+    pub_key_commit: Vec<u8>,
+
+    label: Option<String>,
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Builder, Serialize)]
+pub struct IndexedMultisigApproverPayload {
+    index: u32,
+    address: String,
+
+    #[serde_as(as = "DisplayFromStr")]
+    scheme: ApproverKeyScheme,
+
+    #[serde_as(as = "Base64")]
+    pub_key_commit: Vec<u8>,
+
+    label: Option<String>,
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Serialize)]
+#[serde(tag = "field", rename_all = "snake_case")]
+pub enum MultisigAccountMismatchPayload {
+    Threshold {
+        stored: u32,
+        on_chain: u32,
+    },
+    PubKeyCommit {
+        approver_index: usize,
+
+        #[serde_as(as = "Option<Base64>")]
+        stored: Option<Vec<u8>>,
+
+        #[serde_as(as = "Option<Base64>")]
+        on_chain: Option<Vec<u8>>,
+    },
 }
 
 #[serde_with::serde_as]
@@ -33,9 +111,14 @@ pub struct MultisigAccountPayload {
 pub struct MultisigApproverPayload {
     address: String,
 
+    #[serde_as(as = "DisplayFromStr")]
+    scheme: ApproverKeyScheme,
+
     #[serde_as(as = "Base64")]
     pub_key_commit: Vec<u8>,
 
+    label: Option<String>,
+
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -61,13 +144,74 @@ pub struct MultisigTxPayload {
     // TODO: remove this when `getInputNoteIds` avaialabe for `TransactionRequest` in web-sdk
     input_note_ids: Vec<NoteIdPayload>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    memo: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    failure_reason: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chain_tx_id: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    submission_block_num: Option<u32>,
+
     #[serde(skip_serializing_if = "Option::is_none")]
     signature_count: Option<NonZeroU32>,
 
+    tx_effect: TxEffectPayload,
+
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
 
+#[derive(Debug, Builder, Serialize)]
+pub struct TxEffectPayload {
+    assets_in: Vec<TxAssetAmountPayload>,
+    assets_out: Vec<TxAssetAmountPayload>,
+    input_note_ids: Vec<String>,
+    output_notes: Vec<TxOutputNotePayload>,
+}
+
+#[derive(Debug, Builder, Serialize)]
+pub struct TxAssetAmountPayload {
+    faucet_id: String,
+    amount: u64,
+}
+
+#[derive(Debug, Builder, Serialize)]
+pub struct TxOutputNotePayload {
+    note_id: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    counterparty: Option<String>,
+
+    assets: Vec<TxAssetAmountPayload>,
+}
+
+#[derive(Debug, Builder, Serialize)]
+pub struct MultisigTxStatsPayload {
+    total: u64,
+    last_month: u64,
+    total_success: u64,
+    approvers: Vec<ApproverSignedCountPayload>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    avg_time_to_threshold_secs: Option<f64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    p95_time_to_threshold_secs: Option<f64>,
+}
+
+#[derive(Debug, Builder, Serialize)]
+pub struct ApproverSignedCountPayload {
+    address: String,
+    signed_count: u64,
+}
+
 #[serde_with::serde_as]
 #[derive(Debug, Builder, Serialize)]
 pub struct NoteIdPayload {
@@ -80,58 +224,235 @@ pub struct NoteIdPayload {
 impl From<MultisigAccount> for MultisigAccountPayload {
     fn from(account: MultisigAccount) -> Self {
         Self::builder()
-            .address(Address::AccountId(account.address()).to_bech32(account.network_id()))
+            .address(account.address_bech32())
             .kind(account.kind().to_string())
             .threshold(account.threshold())
             .created_at(account.aux().created_at())
             .updated_at(account.aux().updated_at())
+            .maybe_archived_at(account.archived_at())
             .build()
     }
 }
 
+impl From<MultisigAccountOverview> for MultisigAccountOverviewPayload {
+    fn from(overview: MultisigAccountOverview) -> Self {
+        let MultisigAccountOverviewDissolved {
+            multisig_account,
+            approver_count,
+            pending_tx_count,
+        } = overview.dissolve();
+
+        Self::builder()
+            .account(multisig_account.into())
+            .approver_count(approver_count)
+            .pending_tx_count(pending_tx_count)
+            .build()
+    }
+}
+
+impl MultisigAccountApproverPayload {
+    pub fn from_multisig_account(
+        account: &MultisigAccount<WithApprovers, WithPubKeyCommits>,
+    ) -> Vec<Self> {
+        account
+            .approvers()
+            .iter()
+            .zip(account.pub_key_commits())
+            .zip(account.approver_labels())
+            .map(|((&address, pub_key_commit), label)| {
+                Self::builder()
+                    .address(to_bech32(account.network_id(), address))
+                    .scheme(pub_key_commit.scheme())
+                    .pub_key_commit(pub_key_commit.to_bytes())
+                    .maybe_label(label.clone())
+                    .build()
+            })
+            .collect()
+    }
+}
+
+impl From<(u32, MultisigApprover)> for IndexedMultisigApproverPayload {
+    fn from((index, approver): (u32, MultisigApprover)) -> Self {
+        let MultisigApproverDissolved {
+            address,
+            network_id,
+            pub_key_commit,
+            label,
+            ..
+        } = approver.dissolve();
+
+        Self::builder()
+            .index(index)
+            .address(to_bech32(network_id, address))
+            .scheme(pub_key_commit.scheme())
+            .pub_key_commit(pub_key_commit.to_bytes())
+            .maybe_label(label)
+            .build()
+    }
+}
+
+impl From<MultisigAccountMismatch> for MultisigAccountMismatchPayload {
+    fn from(mismatch: MultisigAccountMismatch) -> Self {
+        match mismatch {
+            MultisigAccountMismatch::Threshold { stored, on_chain } => {
+                Self::Threshold { stored, on_chain }
+            },
+            MultisigAccountMismatch::PubKeyCommit { approver_index, stored, on_chain } => {
+                Self::PubKeyCommit {
+                    approver_index,
+                    stored: stored.as_ref().map(ApproverKey::to_bytes),
+                    on_chain: on_chain.map(|pub_key_commit| Word::from(pub_key_commit).to_bytes()),
+                }
+            },
+        }
+    }
+}
+
 impl From<MultisigApprover> for MultisigApproverPayload {
     fn from(approver: MultisigApprover) -> Self {
-        let MultisigApproverDissolved { address, network_id, pub_key_commit, aux } =
-            approver.dissolve();
+        let MultisigApproverDissolved {
+            address,
+            network_id,
+            pub_key_commit,
+            label,
+            aux,
+        } = approver.dissolve();
 
         Self::builder()
-            .address(Address::AccountId(address).to_bech32(network_id))
-            .pub_key_commit(Word::from(pub_key_commit).to_bytes())
+            .address(to_bech32(network_id, address))
+            .scheme(pub_key_commit.scheme())
+            .pub_key_commit(pub_key_commit.to_bytes())
+            .maybe_label(label)
             .created_at(aux.created_at())
             .updated_at(aux.updated_at())
             .build()
     }
 }
 
-impl From<MultisigTx> for MultisigTxPayload {
-    fn from(tx: MultisigTx) -> Self {
+impl MultisigTxPayload {
+    pub fn from_multisig_tx(tx: MultisigTx) -> Self {
+        let multisig_account_address = tx.address_bech32();
+
         let MultisigTxDissolved {
             id,
-            address,
+            address: _,
             network_id,
             status,
             tx_request,
             tx_summary,
             tx_summary_commit,
+            tx_effect,
+            input_note_ids,
+            title,
+            memo,
+            failure_reason,
+            chain_tx_id,
+            submission_block_num,
             signature_count,
+            threshold: _,
             aux,
         } = tx.dissolve();
 
         Self::builder()
             .id(id.into())
-            .multisig_account_address(Address::AccountId(address).to_bech32(network_id))
+            .multisig_account_address(multisig_account_address)
             .status(status)
             .tx_request(tx_request.to_bytes())
             .tx_summary(tx_summary.to_bytes())
             .tx_summary_commit(tx_summary_commit.to_bytes())
-            .input_note_ids(tx_request.get_input_note_ids().into_iter().map(From::from).collect())
+            .input_note_ids(input_note_ids.into_iter().map(From::from).collect())
+            .maybe_title(title)
+            .maybe_memo(memo)
+            .maybe_failure_reason(failure_reason)
+            .maybe_chain_tx_id(chain_tx_id.as_ref().map(TransactionId::to_hex))
+            .maybe_submission_block_num(submission_block_num.as_ref().map(BlockNumber::as_u32))
             .maybe_signature_count(signature_count)
+            .tx_effect(TxEffectPayload::from_tx_effect(tx_effect, network_id))
             .created_at(aux.created_at())
             .updated_at(aux.updated_at())
             .build()
     }
 }
 
+impl TxEffectPayload {
+    pub fn from_tx_effect(tx_effect: TxEffect, network_id: NetworkId) -> Self {
+        let TxEffectDissolved {
+            assets_in,
+            assets_out,
+            input_note_ids,
+            output_notes,
+        } = tx_effect.dissolve();
+
+        let output_notes = output_notes
+            .iter()
+            .map(|output_note| TxOutputNotePayload::from_tx_output_note(output_note, network_id))
+            .collect();
+
+        Self::builder()
+            .assets_in(assets_in.iter().map(From::from).collect())
+            .assets_out(assets_out.iter().map(From::from).collect())
+            .input_note_ids(input_note_ids.iter().map(NoteId::to_hex).collect())
+            .output_notes(output_notes)
+            .build()
+    }
+}
+
+impl From<&TxAssetAmount> for TxAssetAmountPayload {
+    fn from(asset_amount: &TxAssetAmount) -> Self {
+        Self::builder()
+            .faucet_id(asset_amount.faucet_id().to_hex())
+            .amount(asset_amount.amount())
+            .build()
+    }
+}
+
+impl TxOutputNotePayload {
+    pub fn from_tx_output_note(output_note: &TxOutputNote, network_id: NetworkId) -> Self {
+        let counterparty = output_note.counterparty().map(|account_id| {
+            let address = AccountIdAddress::new(account_id, AddressInterface::BasicWallet);
+            to_bech32(network_id, address)
+        });
+
+        Self::builder()
+            .note_id(output_note.note_id().to_hex())
+            .maybe_counterparty(counterparty)
+            .assets(output_note.assets().iter().map(From::from).collect())
+            .build()
+    }
+}
+
+impl MultisigTxStatsPayload {
+    pub fn from_multisig_tx_stats(tx_stats: MultisigTxStats, network_id: NetworkId) -> Self {
+        let MultisigTxStatsDissolved {
+            total,
+            last_month,
+            total_success,
+            approver_signed_counts,
+            avg_time_to_threshold,
+            p95_time_to_threshold,
+        } = tx_stats.dissolve();
+
+        let approvers = approver_signed_counts
+            .into_iter()
+            .map(|(address, signed_count)| {
+                ApproverSignedCountPayload::builder()
+                    .address(to_bech32(network_id, address))
+                    .signed_count(signed_count)
+                    .build()
+            })
+            .collect();
+
+        Self::builder()
+            .total(total)
+            .last_month(last_month)
+            .total_success(total_success)
+            .approvers(approvers)
+            .maybe_avg_time_to_threshold_secs(avg_time_to_threshold.map(|d| d.as_secs_f64()))
+            .maybe_p95_time_to_threshold_secs(p95_time_to_threshold.map(|d| d.as_secs_f64()))
+            .build()
+    }
+}
+
 impl From<NoteId> for NoteIdPayload {
     fn from(note_id: NoteId) -> Self {
         Self::builder()
@@ -1,42 +1,109 @@
 use core::num::NonZeroU32;
 
 use dissolve_derive::Dissolve;
+use miden_multisig_coordinator_domain::cursor::Cursor;
+use miden_objects::address::AddressInterface;
 use serde::Deserialize;
-use serde_with::base64::Base64;
 use uuid::Uuid;
 
-#[serde_with::serde_as]
 #[derive(Debug, Dissolve, Deserialize)]
 pub struct CreateMultisigAccountRequestPayload {
     threshold: NonZeroU32,
     approvers: Vec<String>,
 
-    #[serde_as(as = "Vec<Base64>")]
-    pub_key_commits: Vec<Vec<u8>>,
+    pub_key_commits: Vec<String>,
+    schemes: Option<Vec<String>>,
+
+    weights: Option<Vec<NonZeroU32>>,
+    labels: Option<Vec<Option<String>>>,
+    address_interface: Option<AddressInterfacePayload>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AddressInterfacePayload {
+    Unspecified,
+    BasicWallet,
+}
+
+#[derive(Debug, Dissolve, Deserialize)]
+pub struct ImportMultisigAccountRequestPayload {
+    multisig_account_address: String,
+    approvers: Vec<String>,
+
+    pub_key_commits: Option<Vec<String>>,
+    schemes: Option<Vec<String>>,
 }
 
-#[serde_with::serde_as]
 #[derive(Debug, Dissolve, Deserialize)]
 pub struct ProposeMultisigTxRequestPayload {
     multisig_account_address: String,
 
-    #[serde_as(as = "Base64")]
-    tx_request: Vec<u8>,
+    tx_request: String,
+
+    tx_summary: Option<String>,
+
+    title: Option<String>,
+    memo: Option<String>,
+    include_tx_effect: Option<bool>,
+}
+
+#[derive(Debug, Dissolve, Deserialize)]
+pub struct DryRunMultisigTxRequestPayload {
+    multisig_account_address: String,
+
+    tx_request: String,
+}
+
+#[derive(Debug, Dissolve, Deserialize)]
+pub struct ValidateMultisigTxRequestPayload {
+    multisig_account_address: String,
+
+    tx_request: String,
+}
+
+#[derive(Debug, Dissolve, Deserialize)]
+pub struct ReproposeMultisigTxRequestPayload {
+    tx_id: Uuid,
+}
+
+#[derive(Debug, Dissolve, Deserialize)]
+pub struct ExecuteMultisigTxRequestPayload {
+    tx_id: Uuid,
 }
 
-#[serde_with::serde_as]
 #[derive(Debug, Dissolve, Deserialize)]
 pub struct AddSignatureRequestPayload {
     tx_id: Uuid,
     approver: String,
+    scheme: Option<String>,
+    signature: String,
+    signature_format: Option<String>,
+}
 
-    #[serde_as(as = "Base64")]
-    signature: Vec<u8>,
+#[derive(Debug, Dissolve, Deserialize)]
+pub struct AddSignaturesRequestPayload {
+    tx_id: Uuid,
+    signatures: Vec<ApproverSignaturePayload>,
+}
+
+#[derive(Debug, Dissolve, Deserialize)]
+pub struct ApproverSignaturePayload {
+    approver: String,
+    scheme: Option<String>,
+    signature: String,
+    signature_format: Option<String>,
+}
+
+#[derive(Debug, Dissolve, Deserialize)]
+pub struct ValidateAddressRequestPayload {
+    address: String,
 }
 
 #[derive(Debug, Dissolve, Deserialize)]
 pub struct ListConsumableNotesRequestPayload {
     address: Option<String>,
+    note_tag: Option<u32>,
 }
 
 #[derive(Debug, Dissolve, Deserialize)]
@@ -44,18 +111,102 @@ pub struct GetMultisigAccountDetailsRequestPayload {
     multisig_account_address: String,
 }
 
+#[derive(Debug, Dissolve, Deserialize)]
+pub struct HasApproverSignedRequestPayload {
+    tx_id: Uuid,
+    approver: String,
+}
+
+#[derive(Debug, Dissolve, Deserialize)]
+pub struct GetMultisigAccountSummaryRequestPayload {
+    multisig_account_address: String,
+}
+
+#[derive(Debug, Dissolve, Deserialize)]
+pub struct GetMultisigApproverOrderRequestPayload {
+    multisig_account_address: String,
+}
+
+#[derive(Debug, Dissolve, Deserialize)]
+pub struct ArchiveMultisigAccountRequestPayload {
+    multisig_account_address: String,
+    archived: bool,
+}
+
+#[derive(Debug, Dissolve, Deserialize)]
+pub struct ReconcileMultisigAccountRequestPayload {
+    multisig_account_address: String,
+}
+
+#[derive(Debug, Dissolve, Deserialize)]
+pub struct ReconcileMultisigTxsRequestPayload {
+    multisig_account_address: String,
+}
+
+#[derive(Debug, Dissolve, Deserialize)]
+pub struct ListMultisigAccountsRequestPayload {
+    #[serde(default)]
+    include_archived: bool,
+    after: Option<Cursor<String>>,
+    limit: Option<NonZeroU32>,
+}
+
 #[derive(Debug, Dissolve, Deserialize)]
 pub struct ListMultisigApproverRequestPayload {
     multisig_account_address: String,
+    label_contains: Option<String>,
+    after: Option<Cursor<String>>,
+    limit: Option<NonZeroU32>,
+}
+
+#[derive(Debug, Dissolve, Deserialize)]
+pub struct GetMultisigTxBySummaryCommitRequestPayload {
+    tx_summary_commit: String,
+}
+
+#[derive(Debug, Dissolve, Deserialize)]
+pub struct GetMultisigTxSummaryRequestPayload {
+    tx_id: Uuid,
 }
 
 #[derive(Debug, Dissolve, Deserialize)]
 pub struct GetMultisigTxStatsRequestPayload {
     multisig_account_address: String,
+    trend_granularity: Option<String>,
 }
 
 #[derive(Debug, Dissolve, Deserialize)]
 pub struct ListMultisigTxRequestPayload {
     multisig_account_address: String,
     tx_status_filter: Option<String>,
+    sort: Option<TxSortPayload>,
+    after: Option<Cursor>,
+    limit: Option<NonZeroU32>,
+}
+
+#[derive(Debug, Dissolve, Deserialize)]
+pub struct ListApproverPendingTxsRequestPayload {
+    approver_address: String,
+    after: Option<Cursor>,
+    limit: Option<NonZeroU32>,
+}
+
+#[derive(Debug, Dissolve, Deserialize)]
+pub struct TxSortPayload {
+    field: String,
+    direction: Option<String>,
+}
+
+#[derive(Debug, Dissolve, Deserialize)]
+pub struct ExportMultisigTxRequestPayload {
+    multisig_account_address: String,
+}
+
+impl From<AddressInterfacePayload> for AddressInterface {
+    fn from(address_interface: AddressInterfacePayload) -> Self {
+        match address_interface {
+            AddressInterfacePayload::Unspecified => Self::Unspecified,
+            AddressInterfacePayload::BasicWallet => Self::BasicWallet,
+        }
+    }
 }
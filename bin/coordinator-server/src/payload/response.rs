@@ -1,14 +1,32 @@
+use core::num::NonZeroU32;
+
 use bon::Builder;
 use chrono::{DateTime, Utc};
-use miden_multisig_coordinator_domain::tx::MultisigTxStats;
+use miden_multisig_coordinator_domain::{cursor::Cursor, tx::TxTrendBucket};
 use serde::Serialize;
 use serde_with::base64::Base64;
 use uuid::Uuid;
 
 use crate::payload::{
-    MultisigAccountPayload, MultisigApproverPayload, MultisigTxPayload, NoteIdPayload,
+    ApproverPendingTxPayload, IndexedMultisigApproverPayload, MultisigAccountApproverPayload,
+    MultisigAccountMismatchPayload, MultisigAccountOverviewPayload, MultisigAccountPayload,
+    MultisigApproverPayload, MultisigTxPayload, MultisigTxStatsPayload, NoteIdPayload,
+    TxEffectPayload,
 };
 
+#[derive(Debug, Builder, Serialize)]
+pub struct ValidateAddressResponsePayload {
+    is_valid: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    network_id: Option<String>,
+
+    matches_network: bool,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
 #[derive(Debug, Builder, Serialize)]
 pub struct CreateMultisigAccountResponsePayload {
     address: String,
@@ -16,6 +34,14 @@ pub struct CreateMultisigAccountResponsePayload {
     updated_at: DateTime<Utc>,
 }
 
+#[derive(Debug, Builder, Serialize)]
+pub struct ImportMultisigAccountResponsePayload {
+    address: String,
+    threshold: NonZeroU32,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
 #[serde_with::serde_as]
 #[derive(Debug, Builder, Serialize)]
 pub struct ProposeMultisigTxResponsePayload {
@@ -23,6 +49,45 @@ pub struct ProposeMultisigTxResponsePayload {
 
     #[serde_as(as = "Base64")]
     tx_summary: Vec<u8>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tx_effect: Option<TxEffectPayload>,
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Builder, Serialize)]
+pub struct DryRunMultisigTxResponsePayload {
+    #[serde_as(as = "Base64")]
+    tx_summary: Vec<u8>,
+
+    tx_effect: TxEffectPayload,
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Builder, Serialize)]
+pub struct ValidateMultisigTxResponsePayload {
+    executable: bool,
+
+    #[serde_as(as = "Option<Base64>")]
+    tx_summary: Option<Vec<u8>>,
+
+    reason: Option<String>,
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Builder, Serialize)]
+pub struct ReproposeMultisigTxResponsePayload {
+    tx_id: Uuid,
+
+    #[serde_as(as = "Base64")]
+    tx_summary: Vec<u8>,
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Builder, Serialize)]
+pub struct ExecuteMultisigTxResponsePayload {
+    #[serde_as(as = "Base64")]
+    tx_result: Vec<u8>,
 }
 
 #[serde_with::serde_as]
@@ -32,27 +97,131 @@ pub struct AddSignatureResponsePayload {
     tx_result: Option<Vec<u8>>,
 }
 
+#[serde_with::serde_as]
+#[derive(Debug, Builder, Serialize)]
+pub struct AddSignaturesResponsePayload {
+    accepted: usize,
+    threshold_met: bool,
+
+    #[serde_as(as = "Option<Base64>")]
+    tx_result: Option<Vec<u8>>,
+}
+
 #[derive(Debug, Builder, Serialize)]
 pub struct ListConsumableNotesResponsePayload {
     note_ids: Vec<NoteIdPayload>,
+    block_num: u32,
+    stale: bool,
 }
 
 #[derive(Debug, Builder, Serialize)]
 pub struct GetMultisigAccountDetailsResponsePayload {
     multisig_account: MultisigAccountPayload,
+    approvers: Vec<MultisigAccountApproverPayload>,
+}
+
+#[derive(Debug, Builder, Serialize)]
+pub struct ArchiveMultisigAccountResponsePayload {
+    multisig_account: MultisigAccountPayload,
+}
+
+#[derive(Debug, Builder, Serialize)]
+pub struct HasApproverSignedResponsePayload {
+    signed: bool,
+}
+
+#[derive(Debug, Builder, Serialize)]
+pub struct GetMultisigAccountSummaryResponsePayload {
+    threshold: NonZeroU32,
+    approver_count: u32,
+}
+
+#[derive(Debug, Builder, Serialize)]
+pub struct GetMultisigApproverOrderResponsePayload {
+    approvers: Vec<IndexedMultisigApproverPayload>,
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Builder, Serialize)]
+pub struct ReconcileMultisigAccountResponsePayload {
+    on_chain_threshold: u32,
+
+    #[serde_as(as = "Vec<Base64>")]
+    on_chain_pub_key_commits: Vec<Vec<u8>>,
+
+    mismatches: Vec<MultisigAccountMismatchPayload>,
+}
+
+#[derive(Debug, Builder, Serialize)]
+pub struct ReconcileMultisigTxsResponsePayload {
+    reconciled_tx_ids: Vec<Uuid>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    corrupt_tx_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Builder, Serialize)]
+pub struct ListMultisigAccountsResponsePayload {
+    accounts: Vec<MultisigAccountOverviewPayload>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_cursor: Option<Cursor<String>>,
 }
 
 #[derive(Debug, Builder, Serialize)]
 pub struct ListMultisigApproverResponsePayload {
     approvers: Vec<MultisigApproverPayload>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_cursor: Option<Cursor<String>>,
 }
 
 #[derive(Debug, Builder, Serialize)]
 pub struct GetMultisigTxStatsResponsePayload {
-    tx_stats: MultisigTxStats,
+    tx_stats: MultisigTxStatsPayload,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trend: Option<Vec<TxTrendBucket>>,
+}
+
+#[derive(Debug, Builder, Serialize)]
+pub struct GetMultisigTxBySummaryCommitResponsePayload {
+    tx: MultisigTxPayload,
+}
+
+#[serde_with::serde_as]
+#[derive(Debug, Builder, Serialize)]
+pub struct GetMultisigTxSummaryResponsePayload {
+    #[serde_as(as = "Base64")]
+    tx_summary: Vec<u8>,
+
+    #[serde_as(as = "Base64")]
+    tx_summary_commit: Vec<u8>,
 }
 
 #[derive(Debug, Builder, Serialize)]
 pub struct ListMultisigTxResponsePayload {
     txs: Vec<MultisigTxPayload>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    corrupt_tx_ids: Vec<Uuid>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_cursor: Option<Cursor>,
+}
+
+#[derive(Debug, Builder, Serialize)]
+pub struct ListApproverPendingTxsResponsePayload {
+    txs: Vec<ApproverPendingTxPayload>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    corrupt_tx_ids: Vec<Uuid>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    next_cursor: Option<Cursor>,
+}
+
+#[derive(Debug, Builder, Serialize)]
+pub struct ListMultisigTxStatusesResponsePayload {
+    statuses: Vec<&'static str>,
 }
@@ -0,0 +1,251 @@
+//! Token bucket rate limiting, of the write endpoints per client IP, of the proposals per
+//! multisig account, and of the signatures per multisig account and approver.
+
+use core::{
+    net::{IpAddr, SocketAddr},
+    num::NonZeroU32,
+};
+
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    sync::{Arc, Mutex, PoisonError},
+    time::Instant,
+};
+
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    middleware::Next,
+    response::Response,
+};
+use hashlink::LruCache;
+
+use crate::error::AppError;
+
+/// A token bucket rate limiter keyed by client IP address by default, or by any other key, e.g.
+/// the multisig account a request acts on.
+///
+/// Every key starts with `burst` tokens, each request consumes one token, and tokens are refilled
+/// at a constant rate of `per_minute` tokens per minute, up to `burst`.
+///
+/// At most [`MAX_TRACKED_KEYS`](Self::MAX_TRACKED_KEYS) keys are tracked, the least recently used
+/// one being forgotten to make room for a new one, so that requests with ever new keys cannot
+/// grow the limiter without bound.
+#[derive(Debug)]
+pub struct RateLimiter<K = IpAddr> {
+    capacity: f64,
+    refill_per_sec: f64,
+    buckets: Mutex<LruCache<K, TokenBucket>>,
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl<K: Eq + Hash + Clone> RateLimiter<K> {
+    /// The number of tracked keys above which the least recently used one is forgotten.
+    const MAX_TRACKED_KEYS: usize = 10_000;
+
+    /// Creates a new rate limiter allowing bursts of `burst` requests per key, refilled at
+    /// `per_minute` requests per minute.
+    pub fn new(burst: NonZeroU32, per_minute: NonZeroU32) -> Self {
+        Self {
+            capacity: burst.get().into(),
+            refill_per_sec: f64::from(per_minute.get()) / 60.0,
+            buckets: Mutex::new(LruCache::new(Self::MAX_TRACKED_KEYS)),
+        }
+    }
+
+    /// Consumes a token of `key`, failing with [`AppError::TooManyRequests`] if it ran out of
+    /// tokens.
+    pub(crate) fn acquire(&self, key: K) -> Result<(), AppError> {
+        self.acquire_all([key])
+    }
+
+    /// Consumes a token of each of `keys` at once, a key listed several times consuming as many
+    /// tokens, failing with [`AppError::TooManyRequests`] without consuming any token if any of
+    /// them ran out of tokens.
+    pub(crate) fn acquire_all(&self, keys: impl IntoIterator<Item = K>) -> Result<(), AppError> {
+        if !self.try_acquire_all(keys, Instant::now()) {
+            return Err(AppError::TooManyRequests);
+        }
+
+        Ok(())
+    }
+
+    fn try_acquire_all(&self, keys: impl IntoIterator<Item = K>, now: Instant) -> bool {
+        let mut demands = HashMap::<K, f64>::new();
+
+        for key in keys {
+            *demands.entry(key).or_default() += 1.0;
+        }
+
+        let mut buckets = self.buckets.lock().unwrap_or_else(PoisonError::into_inner);
+
+        // all keys are checked before any bucket is inserted, as inserting the bucket of a new key
+        // could otherwise forget a key of the same batch whose token would then never be consumed
+        let mut remaining_tokens = Vec::with_capacity(demands.len());
+
+        for (key, demand) in demands {
+            let tokens = buckets.get(&key).map_or(self.capacity, |bucket| {
+                let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+                (bucket.tokens + elapsed * self.refill_per_sec).min(self.capacity)
+            });
+
+            if tokens < demand {
+                return false;
+            }
+
+            remaining_tokens.push((key, tokens - demand));
+        }
+
+        for (key, tokens) in remaining_tokens {
+            buckets.insert(key, TokenBucket { tokens, last_refill: now });
+        }
+
+        true
+    }
+}
+
+/// Middleware rejecting requests with `429 Too Many Requests` once the client IP ran out of tokens.
+pub async fn rate_limit(
+    State(limiter): State<Arc<RateLimiter>>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    limiter.acquire(addr.ip())?;
+
+    Ok(next.run(request).await)
+}
+
+#[cfg(test)]
+mod tests {
+    use core::{
+        net::{IpAddr, Ipv4Addr},
+        num::NonZeroU32,
+        time::Duration,
+    };
+
+    use std::time::Instant;
+
+    use miden_objects::{
+        account::AccountId,
+        testing::account_id::{
+            ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
+            ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE,
+        },
+    };
+
+    use crate::error::AppError;
+
+    use super::RateLimiter;
+
+    #[test]
+    fn rate_limiter_rejects_requests_beyond_burst_until_refilled() {
+        // Arrange
+        let limiter = RateLimiter::new(NonZeroU32::new(2).unwrap(), NonZeroU32::new(60).unwrap());
+        let alice = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+        let bob = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2));
+        let now = Instant::now();
+
+        // Act & Assert
+        assert!(limiter.try_acquire_all([alice], now));
+        assert!(limiter.try_acquire_all([alice], now));
+        assert!(!limiter.try_acquire_all([alice], now));
+
+        assert!(limiter.try_acquire_all([bob], now));
+
+        assert!(limiter.try_acquire_all([alice], now + Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn rate_limiter_consumes_tokens_of_all_keys_or_none() {
+        // Arrange
+        let limiter = RateLimiter::new(NonZeroU32::new(2).unwrap(), NonZeroU32::new(1).unwrap());
+        let alice = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+        let bob = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2));
+        let now = Instant::now();
+
+        assert!(limiter.try_acquire_all([alice], now));
+
+        // Act
+        let batch_beyond_alice_tokens = limiter.try_acquire_all([alice, bob, alice], now);
+        let batch_within_tokens = limiter.try_acquire_all([alice, bob, bob], now);
+
+        // Assert
+        assert!(!batch_beyond_alice_tokens);
+        assert!(batch_within_tokens);
+        assert!(!limiter.try_acquire_all([bob], now));
+    }
+
+    #[test]
+    fn rate_limiter_forgets_least_recently_used_key_beyond_max_tracked_keys() {
+        // Arrange
+        let limiter = RateLimiter::new(NonZeroU32::MIN, NonZeroU32::MIN);
+        let max_tracked_keys = RateLimiter::<IpAddr>::MAX_TRACKED_KEYS;
+        let keys: Vec<_> = (0..=max_tracked_keys)
+            .map(|i| IpAddr::V4(Ipv4Addr::from(u32::try_from(i).unwrap())))
+            .collect();
+        let now = Instant::now();
+
+        // Act
+        for &key in &keys {
+            assert!(limiter.try_acquire_all([key], now));
+        }
+
+        // Assert
+        let buckets = limiter.buckets.lock().unwrap();
+
+        assert_eq!(buckets.len(), max_tracked_keys);
+        assert!(!buckets.contains_key(&keys[0]));
+        assert!(buckets.contains_key(&keys[max_tracked_keys]));
+    }
+
+    #[test]
+    fn rate_limiter_rejects_batch_with_key_out_of_tokens_once_max_tracked_keys_are_tracked() {
+        // Arrange
+        let limiter = RateLimiter::new(NonZeroU32::MIN, NonZeroU32::MIN);
+        let max_tracked_keys = RateLimiter::<IpAddr>::MAX_TRACKED_KEYS;
+        let keys: Vec<_> = (0..=max_tracked_keys)
+            .map(|i| IpAddr::V4(Ipv4Addr::from(u32::try_from(i).unwrap())))
+            .collect();
+        let now = Instant::now();
+
+        for &key in &keys[..max_tracked_keys] {
+            assert!(limiter.try_acquire_all([key], now));
+        }
+
+        // Act
+        let batch_with_exhausted_key =
+            limiter.try_acquire_all([keys[max_tracked_keys], keys[0]], now);
+
+        // Assert
+        assert!(!batch_with_exhausted_key);
+        assert!(!limiter.try_acquire_all([keys[0]], now));
+        assert!(limiter.try_acquire_all([keys[max_tracked_keys]], now));
+        assert_eq!(limiter.buckets.lock().unwrap().len(), max_tracked_keys);
+    }
+
+    #[test]
+    fn account_rate_limiter_throttles_rapid_proposals_of_one_account_only() {
+        // Arrange
+        let burst = NonZeroU32::new(3).unwrap();
+        let limiter = RateLimiter::new(burst, NonZeroU32::new(1).unwrap());
+        let alice = AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+        let bob = AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE).unwrap();
+
+        // Act
+        let alice_proposals: Vec<_> = (0..=burst.get()).map(|_| limiter.acquire(alice)).collect();
+        let bob_proposal = limiter.acquire(bob);
+
+        // Assert
+        let (last, allowed) = alice_proposals.split_last().unwrap();
+
+        assert!(allowed.iter().all(Result::is_ok));
+        assert!(matches!(last, Err(AppError::TooManyRequests)));
+        assert!(bob_proposal.is_ok());
+    }
+}
@@ -1,48 +1,121 @@
-use axum::{Json, extract::State, http::StatusCode};
+use core::{hash::Hash, num::NonZeroU32};
+
+use std::borrow::Cow;
+
+use axum::{
+    BoxError,
+    body::Body,
+    extract::State,
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use base64::{Engine, prelude::BASE64_STANDARD};
+use futures::stream;
 use itertools::Itertools;
 use miden_client::{
     Word,
-    account::Address,
+    account::AccountId,
+    note::NoteTag,
+    transaction::TransactionRequest,
     utils::{Deserializable, Serializable},
 };
+use miden_multisig_coordinator_domain::{
+    account::MultisigAccount,
+    key::{ApproverKey, ApproverKeyScheme, ApproverSignature},
+    tx::{
+        MultisigTx, MultisigTxDissolved, MultisigTxId, MultisigTxStatus, SortDirection,
+        TrendGranularity, TxEffect, TxSort, TxSortField,
+    },
+};
 use miden_multisig_coordinator_engine::{
+    MultisigEngine, Started,
     request::{
-        AddSignatureRequest, CreateMultisigAccountRequest, GetConsumableNotesRequest,
-        GetMultisigAccountRequest, GetMultisigTxStatsRequest, ListMultisigApproverRequest,
-        ListMultisigTxRequest, ProposeMultisigTxRequest, RequestError,
+        AddSignatureRequest, AddSignaturesRequest, ArchiveMultisigAccountRequest,
+        CreateMultisigAccountRequest, DryRunMultisigTxRequest, ExecuteReadyTxRequest,
+        GetConsumableNotesRequest, GetMultisigAccountRequest, GetMultisigTxAccountRequest,
+        GetMultisigTxBySummaryCommitRequest, GetMultisigTxRequest, GetMultisigTxStatsRequest,
+        GetMultisigTxTrendRequest, HasApproverSignedRequest, ImportMultisigAccountRequest,
+        ListApproverPendingTxsRequest, ListMultisigAccountsRequest, ListMultisigApproverRequest,
+        ListMultisigTxRequest, ProposeMultisigTxRequest, ReconcileMultisigAccountRequest,
+        ReconcileMultisigTxsRequest, ReproposeMultisigTxRequest, RequestError,
+        ValidateMultisigTxRequest,
     },
     response::{
+        AddSignaturesResponseDissolved, ArchiveMultisigAccountResponseDissolved,
         CreateMultisigAccountResponse, CreateMultisigAccountResponseDissolved,
-        GetMultisigAccountResponseDissolved, GetMultisigTxStatsResponseDissolved,
+        DryRunMultisigTxResponseDissolved, GetConsumableNotesResponseDissolved,
+        GetMultisigAccountFullResponseDissolved, GetMultisigAccountSummaryResponse,
+        GetMultisigApproverOrderResponseDissolved, GetMultisigTxAccountResponse,
+        GetMultisigTxBySummaryCommitResponseDissolved, GetMultisigTxResponse,
+        GetMultisigTxStatsResponseDissolved, HasApproverSignedResponse,
+        ImportMultisigAccountResponseDissolved, ListApproverPendingTxsResponse,
+        ListApproverPendingTxsResponseDissolved, ListMultisigAccountsResponseDissolved,
         ListMultisigApproverResponseDissolved, ListMultisigTxResponse,
         ListMultisigTxResponseDissolved, ProposeMultisigTxResponseDissolved,
+        ReconcileMultisigAccountResponseDissolved, ReconcileMultisigTxsResponseDissolved,
+        ReproposeMultisigTxResponseDissolved, ValidateMultisigTxResponseDissolved,
     },
 };
-use miden_objects::crypto::dsa::rpo_falcon512::PublicKey;
+use miden_multisig_coordinator_utils::{decode_account_id_address, to_bech32};
 use tokio::task;
 
 use crate::{
     App, AppDissolved,
     error::AppError,
+    extract::{Json, Path, Query},
     payload::{
+        ApproverPendingTxPayload, MultisigAccountApproverPayload, MultisigTxPayload,
+        MultisigTxStatsPayload, TxEffectPayload,
         request::{
             AddSignatureRequestPayload, AddSignatureRequestPayloadDissolved,
+            AddSignaturesRequestPayload, AddSignaturesRequestPayloadDissolved,
+            ApproverSignaturePayload, ApproverSignaturePayloadDissolved,
+            ArchiveMultisigAccountRequestPayload, ArchiveMultisigAccountRequestPayloadDissolved,
             CreateMultisigAccountRequestPayload, CreateMultisigAccountRequestPayloadDissolved,
+            DryRunMultisigTxRequestPayload, DryRunMultisigTxRequestPayloadDissolved,
+            ExecuteMultisigTxRequestPayload, ExecuteMultisigTxRequestPayloadDissolved,
+            ExportMultisigTxRequestPayload, ExportMultisigTxRequestPayloadDissolved,
             GetMultisigAccountDetailsRequestPayload,
-            GetMultisigAccountDetailsRequestPayloadDissolved, GetMultisigTxStatsRequestPayload,
-            GetMultisigTxStatsRequestPayloadDissolved, ListConsumableNotesRequestPayload,
-            ListConsumableNotesRequestPayloadDissolved, ListMultisigApproverRequestPayload,
+            GetMultisigAccountDetailsRequestPayloadDissolved,
+            GetMultisigAccountSummaryRequestPayload,
+            GetMultisigAccountSummaryRequestPayloadDissolved,
+            GetMultisigApproverOrderRequestPayload,
+            GetMultisigApproverOrderRequestPayloadDissolved,
+            GetMultisigTxBySummaryCommitRequestPayload,
+            GetMultisigTxBySummaryCommitRequestPayloadDissolved, GetMultisigTxStatsRequestPayload,
+            GetMultisigTxStatsRequestPayloadDissolved, GetMultisigTxSummaryRequestPayload,
+            GetMultisigTxSummaryRequestPayloadDissolved, HasApproverSignedRequestPayload,
+            HasApproverSignedRequestPayloadDissolved, ImportMultisigAccountRequestPayload,
+            ImportMultisigAccountRequestPayloadDissolved, ListApproverPendingTxsRequestPayload,
+            ListApproverPendingTxsRequestPayloadDissolved, ListConsumableNotesRequestPayload,
+            ListConsumableNotesRequestPayloadDissolved, ListMultisigAccountsRequestPayload,
+            ListMultisigAccountsRequestPayloadDissolved, ListMultisigApproverRequestPayload,
             ListMultisigApproverRequestPayloadDissolved, ListMultisigTxRequestPayload,
             ListMultisigTxRequestPayloadDissolved, ProposeMultisigTxRequestPayload,
-            ProposeMultisigTxRequestPayloadDissolved,
+            ProposeMultisigTxRequestPayloadDissolved, ReconcileMultisigAccountRequestPayload,
+            ReconcileMultisigAccountRequestPayloadDissolved, ReconcileMultisigTxsRequestPayload,
+            ReconcileMultisigTxsRequestPayloadDissolved, ReproposeMultisigTxRequestPayload,
+            ReproposeMultisigTxRequestPayloadDissolved, TxSortPayload, TxSortPayloadDissolved,
+            ValidateAddressRequestPayload, ValidateAddressRequestPayloadDissolved,
+            ValidateMultisigTxRequestPayload, ValidateMultisigTxRequestPayloadDissolved,
         },
         response::{
-            AddSignatureResponsePayload, CreateMultisigAccountResponsePayload,
-            GetMultisigAccountDetailsResponsePayload, GetMultisigTxStatsResponsePayload,
-            ListConsumableNotesResponsePayload, ListMultisigApproverResponsePayload,
-            ListMultisigTxResponsePayload, ProposeMultisigTxResponsePayload,
+            AddSignatureResponsePayload, AddSignaturesResponsePayload,
+            ArchiveMultisigAccountResponsePayload, CreateMultisigAccountResponsePayload,
+            DryRunMultisigTxResponsePayload, ExecuteMultisigTxResponsePayload,
+            GetMultisigAccountDetailsResponsePayload, GetMultisigAccountSummaryResponsePayload,
+            GetMultisigApproverOrderResponsePayload, GetMultisigTxBySummaryCommitResponsePayload,
+            GetMultisigTxStatsResponsePayload, GetMultisigTxSummaryResponsePayload,
+            HasApproverSignedResponsePayload, ImportMultisigAccountResponsePayload,
+            ListApproverPendingTxsResponsePayload, ListConsumableNotesResponsePayload,
+            ListMultisigAccountsResponsePayload, ListMultisigApproverResponsePayload,
+            ListMultisigTxResponsePayload, ListMultisigTxStatusesResponsePayload,
+            ProposeMultisigTxResponsePayload, ReconcileMultisigAccountResponsePayload,
+            ReconcileMultisigTxsResponsePayload, ReproposeMultisigTxResponsePayload,
+            ValidateAddressResponsePayload, ValidateMultisigTxResponsePayload,
         },
     },
+    rate_limit::RateLimiter,
 };
 
 #[tracing::instrument]
@@ -50,45 +123,88 @@ pub async fn health() -> StatusCode {
     StatusCode::OK
 }
 
+#[tracing::instrument(skip_all)]
+pub async fn health_ready(State(app): State<App>) -> StatusCode {
+    let AppDissolved { engine, .. } = app.dissolve();
+
+    match engine.check_readiness().await {
+        Ok(()) => StatusCode::OK,
+        Err(e) => {
+            tracing::warn!("not ready: {e}");
+            StatusCode::SERVICE_UNAVAILABLE
+        },
+    }
+}
+
+#[tracing::instrument(skip_all)]
+pub async fn validate_address(
+    State(app): State<App>,
+    Json(payload): Json<ValidateAddressRequestPayload>,
+) -> Json<ValidateAddressResponsePayload> {
+    let AppDissolved { engine, .. } = app.dissolve();
+
+    let ValidateAddressRequestPayloadDissolved { address } = payload.dissolve();
+
+    let expected = engine.network_id();
+
+    // runs the exact decoding the other endpoints run, so that both always agree
+    let response = match decode_account_id_address(expected, &address).map_err(AppError::from) {
+        Ok(_) => ValidateAddressResponsePayload::builder()
+            .is_valid(true)
+            .network_id(expected.as_str().to_owned())
+            .matches_network(true)
+            .build(),
+        Err(AppError::InvalidNetworkId { provided, .. }) => {
+            ValidateAddressResponsePayload::builder()
+                .is_valid(true)
+                .network_id(provided.as_str().to_owned())
+                .matches_network(false)
+                .build()
+        },
+        Err(err) => ValidateAddressResponsePayload::builder()
+            .is_valid(false)
+            .matches_network(false)
+            .error(err.to_string())
+            .build(),
+    };
+
+    Json(response)
+}
+
 #[tracing::instrument(skip_all)]
 pub async fn create_multisig_account(
     State(app): State<App>,
     Json(payload): Json<CreateMultisigAccountRequestPayload>,
 ) -> Result<Json<CreateMultisigAccountResponsePayload>, AppError> {
-    let AppDissolved { engine } = app.dissolve();
+    let AppDissolved { engine, .. } = app.dissolve();
 
-    let CreateMultisigAccountRequestPayloadDissolved { threshold, approvers, pub_key_commits } =
-        payload.dissolve();
+    let CreateMultisigAccountRequestPayloadDissolved {
+        threshold,
+        approvers,
+        pub_key_commits,
+        schemes,
+        weights,
+        labels,
+        address_interface,
+    } = payload.dissolve();
 
     let engine_network_id = engine.network_id();
     let CreateMultisigAccountResponseDissolved { multisig_account, .. } =
         task::spawn_blocking(move || {
             let approvers = approvers
                 .iter()
-                .map(AsRef::as_ref)
-                .map(miden_multisig_coordinator_utils::extract_network_id_account_id_address_pair)
-                .map(|res| res.map_err(From::from))
-                .map_ok(|(network_id, account_id_address)| {
-                    engine_network_id
-                        .eq(&network_id)
-                        .then_some(account_id_address)
-                        .ok_or(AppError::InvalidNetworkId)
-                })
-                .map(Result::flatten)
+                .map(|approver| decode_account_id_address(engine_network_id, approver))
                 .try_collect()?;
 
-            let pub_key_commits = pub_key_commits
-                .iter()
-                .map(AsRef::as_ref)
-                .map(Word::read_from_bytes)
-                .map_ok(PublicKey::new)
-                .try_collect()
-                .map_err(|_| AppError::InvalidPubKeyCommit)?;
+            let pub_key_commits = decode_pub_key_commits(&pub_key_commits, schemes)?;
 
             CreateMultisigAccountRequest::builder()
                 .threshold(threshold)
                 .approvers(approvers)
                 .pub_key_commits(pub_key_commits)
+                .maybe_weights(weights)
+                .maybe_labels(labels)
+                .maybe_address_interface(address_interface.map(From::from))
                 .build()
                 .map_err(RequestError::from)
                 .map_err(AppError::from)
@@ -99,9 +215,53 @@ pub async fn create_multisig_account(
         .map(CreateMultisigAccountResponse::dissolve)?;
 
     let response = CreateMultisigAccountResponsePayload::builder()
-        .address(
-            Address::AccountId(multisig_account.address()).to_bech32(multisig_account.network_id()),
-        )
+        .address(multisig_account.address_bech32())
+        .created_at(multisig_account.aux().created_at())
+        .updated_at(multisig_account.aux().updated_at())
+        .build();
+
+    Ok(Json(response))
+}
+
+#[tracing::instrument(skip_all)]
+pub async fn import_multisig_account(
+    State(app): State<App>,
+    Json(payload): Json<ImportMultisigAccountRequestPayload>,
+) -> Result<Json<ImportMultisigAccountResponsePayload>, AppError> {
+    let AppDissolved { engine, .. } = app.dissolve();
+
+    let ImportMultisigAccountRequestPayloadDissolved {
+        multisig_account_address,
+        approvers,
+        pub_key_commits,
+        schemes,
+    } = payload.dissolve();
+
+    let account_id_address =
+        decode_account_id_address(engine.network_id(), &multisig_account_address)?;
+
+    let approvers = approvers
+        .iter()
+        .map(|approver| decode_account_id_address(engine.network_id(), approver))
+        .try_collect()?;
+
+    let pub_key_commits = pub_key_commits
+        .map(|pub_key_commits| decode_pub_key_commits(&pub_key_commits, schemes))
+        .transpose()?;
+
+    let request = ImportMultisigAccountRequest::builder()
+        .account_id(account_id_address.id())
+        .approvers(approvers)
+        .maybe_pub_key_commits(pub_key_commits)
+        .address_interface(account_id_address.interface())
+        .build();
+
+    let ImportMultisigAccountResponseDissolved { multisig_account, .. } =
+        engine.import_multisig_account(request).await?.dissolve();
+
+    let response = ImportMultisigAccountResponsePayload::builder()
+        .address(multisig_account.address_bech32())
+        .threshold(multisig_account.threshold())
         .created_at(multisig_account.aux().created_at())
         .updated_at(multisig_account.aux().updated_at())
         .build();
@@ -114,38 +274,195 @@ pub async fn propose_multisig_tx(
     State(app): State<App>,
     Json(payload): Json<ProposeMultisigTxRequestPayload>,
 ) -> Result<Json<ProposeMultisigTxResponsePayload>, AppError> {
-    let AppDissolved { engine } = app.dissolve();
+    let AppDissolved { engine, propose_rate_limiter, .. } = app.dissolve();
 
     let ProposeMultisigTxRequestPayloadDissolved {
         multisig_account_address: address,
         tx_request,
+        tx_summary,
+        title,
+        memo,
+        include_tx_effect,
     } = payload.dissolve();
 
+    let account_id_address = decode_account_id_address(engine.network_id(), &address)?;
+
     let request = {
-        let account_id_address =
-            miden_multisig_coordinator_utils::extract_network_id_account_id_address_pair(&address)
-                .map(|(network_id, address)| {
-                    engine.network_id().eq(&network_id).then_some(address)
-                })?
-                .ok_or(AppError::InvalidNetworkId)?;
+        let tx_request = decode_tx_request(&tx_request)?;
 
-        let tx_request = Deserializable::read_from_bytes(&tx_request)
-            .map_err(|_| AppError::InvalidTransactionRequest)?;
+        let tx_summary = tx_summary
+            .map(|tx_summary| {
+                Deserializable::read_from_bytes(&decode_base64("tx_summary", &tx_summary)?)
+                    .map_err(|_| AppError::InvalidTransactionSummary)
+            })
+            .transpose()?;
 
         ProposeMultisigTxRequest::builder()
             .address(account_id_address)
             .tx_request(tx_request)
+            .maybe_tx_summary(tx_summary)
+            .maybe_title(title)
+            .maybe_memo(memo)
             .build()
+            .map_err(RequestError::from)?
     };
 
+    // malformed requests are rejected before they can use up the tokens of the account
+    propose_rate_limiter.acquire(account_id_address.id())?;
+
+    let network_id = engine.network_id();
+
     let ProposeMultisigTxResponseDissolved { tx_id, tx_summary } =
         engine.propose_multisig_tx(request).await?.dissolve();
 
+    let tx_effect = include_tx_effect
+        .unwrap_or_default()
+        .then(|| TxEffectPayload::from_tx_effect(TxEffect::from(&tx_summary), network_id));
+
     let response = ProposeMultisigTxResponsePayload::builder()
         .tx_id(tx_id.into())
         .tx_summary(tx_summary.to_bytes())
+        .maybe_tx_effect(tx_effect)
+        .build();
+
+    Ok(Json(response))
+}
+
+#[tracing::instrument(skip_all)]
+pub async fn dry_run_multisig_tx(
+    State(app): State<App>,
+    Json(payload): Json<DryRunMultisigTxRequestPayload>,
+) -> Result<Json<DryRunMultisigTxResponsePayload>, AppError> {
+    let AppDissolved { engine, .. } = app.dissolve();
+
+    let DryRunMultisigTxRequestPayloadDissolved {
+        multisig_account_address: address,
+        tx_request,
+    } = payload.dissolve();
+
+    let request = {
+        let account_id_address = decode_account_id_address(engine.network_id(), &address)?;
+
+        let tx_request = decode_tx_request(&tx_request)?;
+
+        DryRunMultisigTxRequest::builder()
+            .address(account_id_address)
+            .tx_request(tx_request)
+            .build()
+    };
+
+    let network_id = engine.network_id();
+
+    let DryRunMultisigTxResponseDissolved { tx_summary } =
+        engine.dry_run_multisig_tx(request).await?.dissolve();
+
+    let response = DryRunMultisigTxResponsePayload::builder()
+        .tx_effect(TxEffectPayload::from_tx_effect(TxEffect::from(&tx_summary), network_id))
+        .tx_summary(tx_summary.to_bytes())
+        .build();
+
+    Ok(Json(response))
+}
+
+#[tracing::instrument(skip_all)]
+pub async fn validate_multisig_tx(
+    State(app): State<App>,
+    Json(payload): Json<ValidateMultisigTxRequestPayload>,
+) -> Result<Json<ValidateMultisigTxResponsePayload>, AppError> {
+    let AppDissolved { engine, .. } = app.dissolve();
+
+    let ValidateMultisigTxRequestPayloadDissolved {
+        multisig_account_address: address,
+        tx_request,
+    } = payload.dissolve();
+
+    let request = {
+        let account_id_address = decode_account_id_address(engine.network_id(), &address)?;
+
+        let tx_request = decode_tx_request(&tx_request)?;
+
+        ValidateMultisigTxRequest::builder()
+            .address(account_id_address)
+            .tx_request(tx_request)
+            .build()
+    };
+
+    let ValidateMultisigTxResponseDissolved { tx_summary, reason } =
+        engine.validate_multisig_tx(request).await?.dissolve();
+
+    let response = ValidateMultisigTxResponsePayload::builder()
+        .executable(tx_summary.is_some())
+        .maybe_tx_summary(tx_summary.as_ref().map(Serializable::to_bytes))
+        .maybe_reason(reason)
+        .build();
+
+    Ok(Json(response))
+}
+
+#[tracing::instrument(skip_all)]
+pub async fn repropose_multisig_tx(
+    State(app): State<App>,
+    Json(payload): Json<ReproposeMultisigTxRequestPayload>,
+) -> Result<Json<ReproposeMultisigTxResponsePayload>, AppError> {
+    let AppDissolved { engine, propose_rate_limiter, .. } = app.dissolve();
+
+    let ReproposeMultisigTxRequestPayloadDissolved { tx_id } = payload.dissolve();
+
+    acquire_by_tx_account(&engine, &propose_rate_limiter, tx_id.into(), |account_id| [account_id])
+        .await?;
+
+    let request = ReproposeMultisigTxRequest::builder().tx_id(tx_id.into()).build();
+
+    let ReproposeMultisigTxResponseDissolved { tx_id, tx_summary } =
+        engine.repropose_multisig_tx(request).await?.dissolve();
+
+    let response = ReproposeMultisigTxResponsePayload::builder()
+        .tx_id(tx_id.into())
+        .tx_summary(tx_summary.to_bytes())
+        .build();
+
+    Ok(Json(response))
+}
+
+#[tracing::instrument(skip_all)]
+pub async fn has_approver_signed(
+    State(app): State<App>,
+    Path(payload): Path<HasApproverSignedRequestPayload>,
+) -> Result<Json<HasApproverSignedResponsePayload>, AppError> {
+    let AppDissolved { engine, .. } = app.dissolve();
+
+    let HasApproverSignedRequestPayloadDissolved { tx_id, approver } = payload.dissolve();
+
+    let request = HasApproverSignedRequest::builder()
+        .tx_id(tx_id.into())
+        .approver(decode_account_id_address(engine.network_id(), &approver)?)
         .build();
 
+    let signed = engine
+        .has_approver_signed(request)
+        .await
+        .map(HasApproverSignedResponse::dissolve)?;
+
+    let response = HasApproverSignedResponsePayload::builder().signed(signed).build();
+
+    Ok(Json(response))
+}
+
+#[tracing::instrument(skip_all)]
+pub async fn execute_multisig_tx(
+    State(app): State<App>,
+    Json(payload): Json<ExecuteMultisigTxRequestPayload>,
+) -> Result<Json<ExecuteMultisigTxResponsePayload>, AppError> {
+    let AppDissolved { engine, .. } = app.dissolve();
+
+    let ExecuteMultisigTxRequestPayloadDissolved { tx_id } = payload.dissolve();
+
+    let request = ExecuteReadyTxRequest::builder().tx_id(tx_id.into()).build();
+
+    let tx_result = engine.execute_ready_tx(request).await?.to_bytes();
+
+    let response = ExecuteMultisigTxResponsePayload::builder().tx_result(tx_result).build();
+
     Ok(Json(response))
 }
 
@@ -154,31 +471,101 @@ pub async fn add_signature(
     State(app): State<App>,
     Json(payload): Json<AddSignatureRequestPayload>,
 ) -> Result<Json<AddSignatureResponsePayload>, AppError> {
-    let AppDissolved { engine } = app.dissolve();
+    let AppDissolved { engine, sign_rate_limiter, .. } = app.dissolve();
+
+    let AddSignatureRequestPayloadDissolved {
+        tx_id,
+        approver,
+        scheme,
+        signature,
+        signature_format,
+    } = payload.dissolve();
+
+    let approver = decode_account_id_address(engine.network_id(), &approver)?;
+
+    let signature = decode_approver_signature(
+        "signature",
+        scheme.as_deref(),
+        signature_format.as_deref(),
+        &signature,
+    )?;
+
+    // malformed signatures are rejected before they can use up the tokens of the approver
+    acquire_by_tx_account(&engine, &sign_rate_limiter, tx_id.into(), |account_id| {
+        [(account_id, approver.id())]
+    })
+    .await?;
+
+    let request = AddSignatureRequest::builder()
+        .tx_id(tx_id.into())
+        .approver(approver)
+        .signature(signature)
+        .build();
+
+    let tx_result = engine.add_signature(request).await?.as_ref().map(Serializable::to_bytes);
+
+    let response = AddSignatureResponsePayload::builder().maybe_tx_result(tx_result).build();
+
+    Ok(Json(response))
+}
+
+#[tracing::instrument(skip_all)]
+pub async fn add_signatures(
+    State(app): State<App>,
+    Json(payload): Json<AddSignaturesRequestPayload>,
+) -> Result<Json<AddSignaturesResponsePayload>, AppError> {
+    let AppDissolved { engine, sign_rate_limiter, .. } = app.dissolve();
 
-    let AddSignatureRequestPayloadDissolved { tx_id, approver, signature } = payload.dissolve();
+    let AddSignaturesRequestPayloadDissolved { tx_id, signatures } = payload.dissolve();
 
     let request = {
-        let approver =
-            miden_multisig_coordinator_utils::extract_network_id_account_id_address_pair(&approver)
-                .map(|(network_id, address)| {
-                    engine.network_id().eq(&network_id).then_some(address)
-                })?
-                .ok_or(AppError::InvalidNetworkId)?;
+        let signatures: Vec<_> = signatures
+            .into_iter()
+            .map(ApproverSignaturePayload::dissolve)
+            .enumerate()
+            .map(|(i, approver_signature)| {
+                let ApproverSignaturePayloadDissolved {
+                    approver,
+                    scheme,
+                    signature,
+                    signature_format,
+                } = approver_signature;
 
-        let signature =
-            Deserializable::read_from_bytes(&signature).map_err(|_| AppError::InvalidSignature)?;
+                let approver = decode_account_id_address(engine.network_id(), &approver)?;
+
+                let signature = decode_approver_signature(
+                    format!("signatures[{i}].signature"),
+                    scheme.as_deref(),
+                    signature_format.as_deref(),
+                    &signature,
+                )?;
+
+                Ok((approver, signature))
+            })
+            .collect::<Result<_, AppError>>()?;
+
+        // malformed signatures are rejected before they can use up the tokens of the approvers,
+        // each signature of the batch consuming a token of its approver
+        acquire_by_tx_account(&engine, &sign_rate_limiter, tx_id.into(), |account_id| {
+            signatures.iter().map(move |(approver, _)| (account_id, approver.id()))
+        })
+        .await?;
 
-        AddSignatureRequest::builder()
+        AddSignaturesRequest::builder()
             .tx_id(tx_id.into())
-            .approver(approver)
-            .signature(signature)
+            .signatures(signatures)
             .build()
+            .map_err(RequestError::from)?
     };
 
-    let tx_result = engine.add_signature(request).await?.as_ref().map(Serializable::to_bytes);
+    let AddSignaturesResponseDissolved { accepted, threshold_met, tx_result } =
+        engine.add_signatures(request).await?.dissolve();
 
-    let response = AddSignatureResponsePayload::builder().maybe_tx_result(tx_result).build();
+    let response = AddSignaturesResponsePayload::builder()
+        .accepted(accepted)
+        .threshold_met(threshold_met)
+        .maybe_tx_result(tx_result.as_ref().map(Serializable::to_bytes))
+        .build();
 
     Ok(Json(response))
 }
@@ -188,33 +575,33 @@ pub async fn list_consumable_notes(
     State(app): State<App>,
     Json(payload): Json<ListConsumableNotesRequestPayload>,
 ) -> Result<Json<ListConsumableNotesResponsePayload>, AppError> {
-    let AppDissolved { engine } = app.dissolve();
+    let AppDissolved { engine, .. } = app.dissolve();
 
-    let ListConsumableNotesRequestPayloadDissolved { address } = payload.dissolve();
+    let ListConsumableNotesRequestPayloadDissolved { address, note_tag } = payload.dissolve();
 
     let account_id_address = address
         .as_deref()
-        .map(miden_multisig_coordinator_utils::extract_network_id_account_id_address_pair)
-        .transpose()?
-        .map(|(network_id, address)| {
-            engine
-                .network_id()
-                .eq(&network_id)
-                .then_some(address)
-                .ok_or(AppError::InvalidNetworkId)
-        })
+        .map(|address| decode_account_id_address(engine.network_id(), address))
         .transpose()?;
 
-    let request = GetConsumableNotesRequest::builder().maybe_address(account_id_address).build();
+    let request = GetConsumableNotesRequest::builder()
+        .maybe_address(account_id_address)
+        .maybe_note_tag(note_tag.map(NoteTag::from))
+        .build();
 
-    let note_ids = engine
-        .get_consumable_notes(request)
-        .await?
+    let GetConsumableNotesResponseDissolved { notes, block_num, stale } =
+        engine.get_consumable_notes(request).await?.dissolve();
+
+    let note_ids = notes
         .into_iter()
         .map(|(input_note_record, _)| input_note_record.id().into())
         .collect();
 
-    let response = ListConsumableNotesResponsePayload::builder().note_ids(note_ids).build();
+    let response = ListConsumableNotesResponsePayload::builder()
+        .note_ids(note_ids)
+        .block_num(block_num.as_u32())
+        .stale(stale)
+        .build();
 
     Ok(Json(response))
 }
@@ -224,128 +611,1323 @@ pub async fn get_multisig_account_details(
     State(app): State<App>,
     Json(payload): Json<GetMultisigAccountDetailsRequestPayload>,
 ) -> Result<Json<GetMultisigAccountDetailsResponsePayload>, AppError> {
-    let AppDissolved { engine } = app.dissolve();
+    let AppDissolved { engine, .. } = app.dissolve();
 
     let GetMultisigAccountDetailsRequestPayloadDissolved { multisig_account_address } =
         payload.dissolve();
 
     let multisig_account_id_address =
-        miden_multisig_coordinator_utils::extract_network_id_account_id_address_pair(
-            &multisig_account_address,
-        )
-        .map(|(network_id, address)| engine.network_id().eq(&network_id).then_some(address))?
-        .ok_or(AppError::InvalidNetworkId)?;
+        decode_account_id_address(engine.network_id(), &multisig_account_address)?;
 
     let request = GetMultisigAccountRequest::builder()
         .multisig_account_id_address(multisig_account_id_address)
         .build();
 
-    let GetMultisigAccountResponseDissolved { multisig_account } =
-        engine.get_multisig_account(request).await?.dissolve();
+    let GetMultisigAccountFullResponseDissolved { multisig_account } =
+        engine.get_multisig_account_full(request).await?.dissolve();
 
     let multisig_account = multisig_account.ok_or(AppError::MultisigAccountNotFound)?;
 
     let response = GetMultisigAccountDetailsResponsePayload::builder()
-        .multisig_account(multisig_account.into())
+        .approvers(MultisigAccountApproverPayload::from_multisig_account(&multisig_account))
+        .multisig_account(<MultisigAccount>::from(multisig_account).into())
         .build();
 
     Ok(Json(response))
 }
 
 #[tracing::instrument(skip_all)]
-pub async fn list_multisig_approvers(
+pub async fn get_multisig_account_summary(
     State(app): State<App>,
-    Json(payload): Json<ListMultisigApproverRequestPayload>,
-) -> Result<Json<ListMultisigApproverResponsePayload>, AppError> {
-    let AppDissolved { engine } = app.dissolve();
+    Query(payload): Query<GetMultisigAccountSummaryRequestPayload>,
+) -> Result<Json<GetMultisigAccountSummaryResponsePayload>, AppError> {
+    let AppDissolved { engine, .. } = app.dissolve();
 
-    let ListMultisigApproverRequestPayloadDissolved { multisig_account_address } =
+    let GetMultisigAccountSummaryRequestPayloadDissolved { multisig_account_address } =
         payload.dissolve();
 
     let multisig_account_id_address =
-        miden_multisig_coordinator_utils::extract_network_id_account_id_address_pair(
-            &multisig_account_address,
-        )
-        .map(|(network_id, address)| engine.network_id().eq(&network_id).then_some(address))?
-        .ok_or(AppError::InvalidNetworkId)?;
+        decode_account_id_address(engine.network_id(), &multisig_account_address)?;
 
-    let request = ListMultisigApproverRequest::builder()
+    let request = GetMultisigAccountRequest::builder()
         .multisig_account_id_address(multisig_account_id_address)
         .build();
 
-    let ListMultisigApproverResponseDissolved { approvers } =
-        engine.list_multisig_approvers(request).await?.dissolve();
+    let (threshold, approver_count) = engine
+        .get_multisig_account_summary(request)
+        .await
+        .map(GetMultisigAccountSummaryResponse::dissolve)?
+        .ok_or(AppError::MultisigAccountNotFound)?;
 
-    let response = ListMultisigApproverResponsePayload::builder()
-        .approvers(approvers.into_iter().map(From::from).collect())
+    let response = GetMultisigAccountSummaryResponsePayload::builder()
+        .threshold(threshold)
+        .approver_count(approver_count)
         .build();
 
     Ok(Json(response))
 }
 
-pub async fn get_multisig_tx_stats(
+#[tracing::instrument(skip_all)]
+pub async fn get_multisig_approver_order(
     State(app): State<App>,
-    Json(payload): Json<GetMultisigTxStatsRequestPayload>,
-) -> Result<Json<GetMultisigTxStatsResponsePayload>, AppError> {
-    let AppDissolved { engine } = app.dissolve();
+    Query(payload): Query<GetMultisigApproverOrderRequestPayload>,
+) -> Result<Json<GetMultisigApproverOrderResponsePayload>, AppError> {
+    let AppDissolved { engine, .. } = app.dissolve();
 
-    let GetMultisigTxStatsRequestPayloadDissolved { multisig_account_address } = payload.dissolve();
+    let GetMultisigApproverOrderRequestPayloadDissolved { multisig_account_address } =
+        payload.dissolve();
 
     let multisig_account_id_address =
-        miden_multisig_coordinator_utils::extract_network_id_account_id_address_pair(
-            &multisig_account_address,
-        )
-        .map(|(network_id, address)| engine.network_id().eq(&network_id).then_some(address))?
-        .ok_or(AppError::InvalidNetworkId)?;
+        decode_account_id_address(engine.network_id(), &multisig_account_address)?;
 
-    let request = GetMultisigTxStatsRequest::builder()
+    let request = GetMultisigAccountRequest::builder()
         .multisig_account_id_address(multisig_account_id_address)
         .build();
 
-    let GetMultisigTxStatsResponseDissolved { tx_stats } =
-        engine.get_multisig_tx_stats(request).await?.dissolve();
+    let GetMultisigApproverOrderResponseDissolved { approvers } =
+        engine.get_multisig_approver_order(request).await?.dissolve();
 
-    let response = GetMultisigTxStatsResponsePayload::builder().tx_stats(tx_stats).build();
+    let approvers = approvers.ok_or(AppError::MultisigAccountNotFound)?;
+
+    let response = GetMultisigApproverOrderResponsePayload::builder()
+        .approvers(approvers.into_iter().map(From::from).collect())
+        .build();
 
     Ok(Json(response))
 }
 
 #[tracing::instrument(skip_all)]
-pub async fn list_multisig_tx(
+pub async fn reconcile_multisig_account(
     State(app): State<App>,
-    Json(payload): Json<ListMultisigTxRequestPayload>,
-) -> Result<Json<ListMultisigTxResponsePayload>, AppError> {
-    let AppDissolved { engine } = app.dissolve();
+    Json(payload): Json<ReconcileMultisigAccountRequestPayload>,
+) -> Result<Json<ReconcileMultisigAccountResponsePayload>, AppError> {
+    let AppDissolved { engine, .. } = app.dissolve();
 
-    let ListMultisigTxRequestPayloadDissolved {
-        multisig_account_address,
-        tx_status_filter,
-    } = payload.dissolve();
+    let ReconcileMultisigAccountRequestPayloadDissolved { multisig_account_address } =
+        payload.dissolve();
 
     let multisig_account_id_address =
-        miden_multisig_coordinator_utils::extract_network_id_account_id_address_pair(
-            &multisig_account_address,
+        decode_account_id_address(engine.network_id(), &multisig_account_address)?;
+
+    let request = ReconcileMultisigAccountRequest::builder()
+        .multisig_account_id_address(multisig_account_id_address)
+        .build();
+
+    let ReconcileMultisigAccountResponseDissolved {
+        on_chain_threshold,
+        on_chain_pub_key_commits,
+        mismatches,
+    } = engine.reconcile_multisig_account(request).await?.dissolve();
+
+    let response = ReconcileMultisigAccountResponsePayload::builder()
+        .on_chain_threshold(on_chain_threshold)
+        .on_chain_pub_key_commits(
+            on_chain_pub_key_commits
+                .into_iter()
+                .map(|pk| Word::from(pk).to_bytes())
+                .collect(),
         )
-        .map(|(network_id, address)| engine.network_id().eq(&network_id).then_some(address))?
-        .ok_or(AppError::InvalidNetworkId)?;
+        .mismatches(mismatches.into_iter().map(From::from).collect())
+        .build();
 
-    let tx_status_filter = tx_status_filter
-        .as_deref()
-        .map(TryFrom::try_from)
-        .transpose()
-        .map_err(|_| AppError::InvalidMultisigTxStatus)?;
+    Ok(Json(response))
+}
 
-    let request = ListMultisigTxRequest::builder()
+#[tracing::instrument(skip_all)]
+pub async fn reconcile_multisig_txs(
+    State(app): State<App>,
+    Json(payload): Json<ReconcileMultisigTxsRequestPayload>,
+) -> Result<Json<ReconcileMultisigTxsResponsePayload>, AppError> {
+    let AppDissolved { engine, .. } = app.dissolve();
+
+    let ReconcileMultisigTxsRequestPayloadDissolved { multisig_account_address } =
+        payload.dissolve();
+
+    let multisig_account_id_address =
+        decode_account_id_address(engine.network_id(), &multisig_account_address)?;
+
+    let request = ReconcileMultisigTxsRequest::builder()
         .multisig_account_id_address(multisig_account_id_address)
-        .maybe_tx_status_filter(tx_status_filter)
         .build();
 
-    let ListMultisigTxResponseDissolved { txs } =
-        engine.list_multisig_tx(request).await.map(ListMultisigTxResponse::dissolve)?;
+    let ReconcileMultisigTxsResponseDissolved { reconciled_tx_ids, corrupt_tx_ids } =
+        engine.reconcile_multisig_txs(request).await?.dissolve();
 
-    let response = ListMultisigTxResponsePayload::builder()
-        .txs(txs.into_iter().map(From::from).collect())
+    let response = ReconcileMultisigTxsResponsePayload::builder()
+        .reconciled_tx_ids(reconciled_tx_ids.into_iter().map(From::from).collect())
+        .corrupt_tx_ids(corrupt_tx_ids.into_iter().map(From::from).collect())
+        .build();
+
+    Ok(Json(response))
+}
+
+#[tracing::instrument(skip_all)]
+pub async fn archive_multisig_account(
+    State(app): State<App>,
+    Json(payload): Json<ArchiveMultisigAccountRequestPayload>,
+) -> Result<Json<ArchiveMultisigAccountResponsePayload>, AppError> {
+    let AppDissolved { engine, .. } = app.dissolve();
+
+    let ArchiveMultisigAccountRequestPayloadDissolved { multisig_account_address, archived } =
+        payload.dissolve();
+
+    let multisig_account_id_address =
+        decode_account_id_address(engine.network_id(), &multisig_account_address)?;
+
+    let request = ArchiveMultisigAccountRequest::builder()
+        .multisig_account_id_address(multisig_account_id_address)
+        .archived(archived)
+        .build();
+
+    let ArchiveMultisigAccountResponseDissolved { multisig_account } =
+        engine.archive_multisig_account(request).await?.dissolve();
+
+    let multisig_account = multisig_account.ok_or(AppError::MultisigAccountNotFound)?;
+
+    let response = ArchiveMultisigAccountResponsePayload::builder()
+        .multisig_account(multisig_account.into())
+        .build();
+
+    Ok(Json(response))
+}
+
+#[tracing::instrument(skip_all)]
+pub async fn list_multisig_accounts(
+    State(app): State<App>,
+    Query(payload): Query<ListMultisigAccountsRequestPayload>,
+) -> Result<Json<ListMultisigAccountsResponsePayload>, AppError> {
+    let AppDissolved { engine, .. } = app.dissolve();
+
+    let ListMultisigAccountsRequestPayloadDissolved { include_archived, after, limit } =
+        payload.dissolve();
+
+    let request = ListMultisigAccountsRequest::builder()
+        .include_archived(include_archived)
+        .maybe_after(after)
+        .maybe_limit(limit)
+        .build();
+
+    let ListMultisigAccountsResponseDissolved { accounts, next_cursor } =
+        engine.list_multisig_accounts(request).await?.dissolve();
+
+    let response = ListMultisigAccountsResponsePayload::builder()
+        .accounts(accounts.into_iter().map(From::from).collect())
+        .maybe_next_cursor(next_cursor)
         .build();
 
     Ok(Json(response))
 }
+
+pub async fn list_multisig_approvers(
+    State(app): State<App>,
+    Json(payload): Json<ListMultisigApproverRequestPayload>,
+) -> Result<Json<ListMultisigApproverResponsePayload>, AppError> {
+    let AppDissolved { engine, .. } = app.dissolve();
+
+    let ListMultisigApproverRequestPayloadDissolved {
+        multisig_account_address,
+        label_contains,
+        after,
+        limit,
+    } = payload.dissolve();
+
+    let multisig_account_id_address =
+        decode_account_id_address(engine.network_id(), &multisig_account_address)?;
+
+    let request = ListMultisigApproverRequest::builder()
+        .multisig_account_id_address(multisig_account_id_address)
+        .maybe_label_contains(label_contains)
+        .maybe_after(after)
+        .maybe_limit(limit)
+        .build();
+
+    let ListMultisigApproverResponseDissolved { approvers, next_cursor } =
+        engine.list_multisig_approvers(request).await?.dissolve();
+
+    let response = ListMultisigApproverResponsePayload::builder()
+        .approvers(approvers.into_iter().map(From::from).collect())
+        .maybe_next_cursor(next_cursor)
+        .build();
+
+    Ok(Json(response))
+}
+
+pub async fn get_multisig_tx_stats(
+    State(app): State<App>,
+    Json(payload): Json<GetMultisigTxStatsRequestPayload>,
+) -> Result<Json<GetMultisigTxStatsResponsePayload>, AppError> {
+    let AppDissolved { engine, .. } = app.dissolve();
+
+    let GetMultisigTxStatsRequestPayloadDissolved {
+        multisig_account_address,
+        trend_granularity,
+    } = payload.dissolve();
+
+    let multisig_account_id_address =
+        decode_account_id_address(engine.network_id(), &multisig_account_address)?;
+
+    let trend_granularity = trend_granularity
+        .as_deref()
+        .map(str::parse::<TrendGranularity>)
+        .transpose()
+        .map_err(|_| AppError::InvalidTrendGranularity)?;
+
+    let request = GetMultisigTxStatsRequest::builder()
+        .multisig_account_id_address(multisig_account_id_address)
+        .build();
+
+    let GetMultisigTxStatsResponseDissolved { tx_stats } =
+        engine.get_multisig_tx_stats(request).await?.dissolve();
+
+    let trend = match trend_granularity {
+        Some(granularity) => {
+            let request = GetMultisigTxTrendRequest::builder()
+                .multisig_account_id_address(multisig_account_id_address)
+                .granularity(granularity)
+                .build();
+
+            Some(engine.get_tx_trend(request).await?.dissolve())
+        },
+        None => None,
+    };
+
+    let response = GetMultisigTxStatsResponsePayload::builder()
+        .tx_stats(MultisigTxStatsPayload::from_multisig_tx_stats(tx_stats, engine.network_id()))
+        .maybe_trend(trend)
+        .build();
+
+    Ok(Json(response))
+}
+
+#[tracing::instrument(skip_all)]
+pub async fn get_multisig_tx_by_summary_commit(
+    State(app): State<App>,
+    Json(payload): Json<GetMultisigTxBySummaryCommitRequestPayload>,
+) -> Result<Json<GetMultisigTxBySummaryCommitResponsePayload>, AppError> {
+    let AppDissolved { engine, .. } = app.dissolve();
+
+    let GetMultisigTxBySummaryCommitRequestPayloadDissolved { tx_summary_commit } =
+        payload.dissolve();
+
+    let tx_summary_commit =
+        Word::read_from_bytes(&decode_base64("tx_summary_commit", &tx_summary_commit)?)
+            .map_err(|_| AppError::InvalidTransactionSummaryCommit)?;
+
+    let request = GetMultisigTxBySummaryCommitRequest::builder()
+        .tx_summary_commit(tx_summary_commit)
+        .build();
+
+    let GetMultisigTxBySummaryCommitResponseDissolved { multisig_tx } =
+        engine.get_multisig_tx_by_summary_commit(request).await?.dissolve();
+
+    let multisig_tx = multisig_tx.ok_or(AppError::MultisigTxNotFound)?;
+
+    let response = GetMultisigTxBySummaryCommitResponsePayload::builder()
+        .tx(MultisigTxPayload::from_multisig_tx(multisig_tx))
+        .build();
+
+    Ok(Json(response))
+}
+
+#[tracing::instrument(skip_all)]
+pub async fn get_multisig_tx_summary(
+    State(app): State<App>,
+    Path(payload): Path<GetMultisigTxSummaryRequestPayload>,
+) -> Result<Json<GetMultisigTxSummaryResponsePayload>, AppError> {
+    let AppDissolved { engine, .. } = app.dissolve();
+
+    let GetMultisigTxSummaryRequestPayloadDissolved { tx_id } = payload.dissolve();
+
+    let request = GetMultisigTxRequest::builder().tx_id(tx_id.into()).build();
+
+    let multisig_tx = engine
+        .get_multisig_tx(request)
+        .await
+        .map(GetMultisigTxResponse::dissolve)?
+        .ok_or(AppError::MultisigTxNotFound)?;
+
+    let MultisigTxDissolved {
+        status, tx_summary, tx_summary_commit, ..
+    } = multisig_tx.dissolve();
+
+    if status != MultisigTxStatus::Pending {
+        return Err(AppError::MultisigTxNotPending { status });
+    }
+
+    let response = GetMultisigTxSummaryResponsePayload::builder()
+        .tx_summary(tx_summary.to_bytes())
+        .tx_summary_commit(tx_summary_commit.to_bytes())
+        .build();
+
+    Ok(Json(response))
+}
+
+#[tracing::instrument(skip_all)]
+pub async fn list_multisig_tx(
+    State(app): State<App>,
+    Json(payload): Json<ListMultisigTxRequestPayload>,
+) -> Result<Json<ListMultisigTxResponsePayload>, AppError> {
+    let AppDissolved { engine, .. } = app.dissolve();
+
+    let ListMultisigTxRequestPayloadDissolved {
+        multisig_account_address,
+        tx_status_filter,
+        sort,
+        after,
+        limit,
+    } = payload.dissolve();
+
+    let multisig_account_id_address =
+        decode_account_id_address(engine.network_id(), &multisig_account_address)?;
+
+    let tx_status_filter = tx_status_filter
+        .as_deref()
+        .map(str::parse::<MultisigTxStatus>)
+        .transpose()
+        .map_err(|_| AppError::InvalidMultisigTxStatus)?;
+
+    let sort = sort.map(TxSortPayload::dissolve).map(decode_tx_sort).transpose()?;
+
+    let request = ListMultisigTxRequest::builder()
+        .multisig_account_id_address(multisig_account_id_address)
+        .maybe_tx_status_filter(tx_status_filter)
+        .maybe_sort(sort)
+        .maybe_after(after)
+        .maybe_limit(limit)
+        .build();
+
+    let ListMultisigTxResponseDissolved { txs, corrupt_tx_ids, next_cursor } =
+        engine.list_multisig_tx(request).await.map(ListMultisigTxResponse::dissolve)?;
+
+    let txs = to_multisig_tx_payloads(txs).await?;
+
+    let response = ListMultisigTxResponsePayload::builder()
+        .txs(txs)
+        .corrupt_tx_ids(corrupt_tx_ids.into_iter().map(From::from).collect())
+        .maybe_next_cursor(next_cursor)
+        .build();
+
+    Ok(Json(response))
+}
+
+#[tracing::instrument(skip_all)]
+pub async fn list_approver_pending_txs(
+    State(app): State<App>,
+    Json(payload): Json<ListApproverPendingTxsRequestPayload>,
+) -> Result<Json<ListApproverPendingTxsResponsePayload>, AppError> {
+    let AppDissolved { engine, .. } = app.dissolve();
+
+    let ListApproverPendingTxsRequestPayloadDissolved { approver_address, after, limit } =
+        payload.dissolve();
+
+    let request = ListApproverPendingTxsRequest::builder()
+        .approver(decode_account_id_address(engine.network_id(), &approver_address)?)
+        .maybe_after(after)
+        .maybe_limit(limit)
+        .build();
+
+    let ListApproverPendingTxsResponseDissolved { txs, corrupt_tx_ids, next_cursor } = engine
+        .list_approver_pending_txs(request)
+        .await
+        .map(ListApproverPendingTxsResponse::dissolve)?;
+
+    let (txs, signed): (Vec<_>, Vec<_>) = txs.into_iter().unzip();
+
+    let txs = to_multisig_tx_payloads(txs)
+        .await?
+        .into_iter()
+        .zip(signed)
+        .map(|(tx, signed)| ApproverPendingTxPayload::builder().tx(tx).signed(signed).build())
+        .collect();
+
+    let response = ListApproverPendingTxsResponsePayload::builder()
+        .txs(txs)
+        .corrupt_tx_ids(corrupt_tx_ids.into_iter().map(From::from).collect())
+        .maybe_next_cursor(next_cursor)
+        .build();
+
+    Ok(Json(response))
+}
+
+/// The number of transactions fetched at once while exporting, which bounds the memory an export
+/// holds regardless of the size of the account history.
+const EXPORT_PAGE_SIZE: NonZeroU32 = NonZeroU32::new(100).unwrap();
+
+#[tracing::instrument(skip_all)]
+pub async fn export_multisig_tx(
+    State(app): State<App>,
+    Query(payload): Query<ExportMultisigTxRequestPayload>,
+) -> Result<Response, AppError> {
+    let AppDissolved { engine, .. } = app.dissolve();
+
+    let ExportMultisigTxRequestPayloadDissolved { multisig_account_address } = payload.dissolve();
+
+    let multisig_account_id_address =
+        decode_account_id_address(engine.network_id(), &multisig_account_address)?;
+
+    let request = GetMultisigAccountRequest::builder()
+        .multisig_account_id_address(multisig_account_id_address)
+        .build();
+
+    // errors past this check can only abort the response body, so an unknown account is reported
+    // before the response starts
+    engine
+        .get_multisig_account_summary(request)
+        .await?
+        .ok_or(AppError::MultisigAccountNotFound)?;
+
+    let file_name = to_bech32(engine.network_id(), multisig_account_id_address);
+
+    // the state is the cursor of the next page to fetch, `None` once the last page was fetched
+    let lines = stream::try_unfold(Some(None), move |after| {
+        let engine = engine.clone();
+
+        async move {
+            let Some(after) = after else {
+                return Ok(None);
+            };
+
+            let request = ListMultisigTxRequest::builder()
+                .multisig_account_id_address(multisig_account_id_address)
+                .maybe_after(after)
+                .limit(EXPORT_PAGE_SIZE)
+                .build();
+
+            let ListMultisigTxResponseDissolved { txs, corrupt_tx_ids, next_cursor } =
+                engine.list_multisig_tx(request).await.map(ListMultisigTxResponse::dissolve)?;
+
+            if !corrupt_tx_ids.is_empty() {
+                tracing::warn!(?corrupt_tx_ids, "leaving undeserializable txs out of export");
+            }
+
+            // a whole page is serialized at once, off the async runtime
+            let lines = task::spawn_blocking(move || {
+                let mut lines = Vec::new();
+                for tx in txs {
+                    serde_json::to_writer(&mut lines, &MultisigTxPayload::from_multisig_tx(tx))?;
+                    lines.push(b'\n');
+                }
+
+                Ok::<_, serde_json::Error>(lines)
+            })
+            .await??;
+
+            Ok::<_, BoxError>(Some((lines, next_cursor.map(Some))))
+        }
+    });
+
+    let headers = [
+        (header::CONTENT_TYPE, "application/x-ndjson".to_owned()),
+        (
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"{file_name}-txs.ndjson\""),
+        ),
+    ];
+
+    Ok((headers, Body::from_stream(lines)).into_response())
+}
+
+#[tracing::instrument]
+pub async fn list_multisig_tx_statuses() -> Json<ListMultisigTxStatusesResponsePayload> {
+    let statuses = MultisigTxStatus::all().iter().copied().map(<&str>::from).collect();
+
+    Json(ListMultisigTxStatusesResponsePayload::builder().statuses(statuses).build())
+}
+
+/// The number of transactions above which a page is converted into payloads on a blocking
+/// thread, as serializing their requests and summaries would otherwise hold up the other requests
+/// handled by the async runtime.
+const BLOCKING_CONVERSION_THRESHOLD: usize = 32;
+
+/// Converts transactions into their payloads, on a blocking thread if there are more than
+/// [`BLOCKING_CONVERSION_THRESHOLD`] of them.
+async fn to_multisig_tx_payloads(txs: Vec<MultisigTx>) -> Result<Vec<MultisigTxPayload>, AppError> {
+    if txs.len() <= BLOCKING_CONVERSION_THRESHOLD {
+        return Ok(txs.into_iter().map(MultisigTxPayload::from_multisig_tx).collect());
+    }
+
+    task::spawn_blocking(move || txs.into_iter().map(MultisigTxPayload::from_multisig_tx).collect())
+        .await
+        .map_err(From::from)
+}
+
+/// Consumes a token of each of the keys `keys` derives from the multisig account of the
+/// transaction `tx_id` from the `limiter`, none if any of them ran out of tokens.
+///
+/// Unknown transactions are let through, the engine reports them as not found.
+async fn acquire_by_tx_account<K, I>(
+    engine: &MultisigEngine<Started>,
+    limiter: &RateLimiter<K>,
+    tx_id: MultisigTxId,
+    keys: impl FnOnce(AccountId) -> I,
+) -> Result<(), AppError>
+where
+    K: Eq + Hash + Clone,
+    I: IntoIterator<Item = K>,
+{
+    let request = GetMultisigTxAccountRequest::builder().tx_id(tx_id).build();
+
+    let address = engine
+        .get_multisig_tx_account(request)
+        .await
+        .map(GetMultisigTxAccountResponse::dissolve)?;
+
+    address.map_or(Ok(()), |address| limiter.acquire_all(keys(address.id())))
+}
+
+/// Decodes a transaction sort order, the direction defaulting to descending.
+fn decode_tx_sort(
+    TxSortPayloadDissolved { field, direction }: TxSortPayloadDissolved,
+) -> Result<TxSort, AppError> {
+    let field = field.parse::<TxSortField>().map_err(|_| AppError::InvalidTxSort)?;
+
+    let direction = direction
+        .as_deref()
+        .map(str::parse::<SortDirection>)
+        .transpose()
+        .map_err(|_| AppError::InvalidTxSort)?
+        .unwrap_or_default();
+
+    Ok(TxSort::builder().field(field).direction(direction).build())
+}
+
+/// Decodes a base64 encoded field of a request payload, `field` naming it in the error.
+///
+/// Malformed base64 is reported as [`AppError::InvalidBase64`], so that clients can tell it
+/// apart from well-encoded bytes which fail to deserialize.
+fn decode_base64(field: impl Into<Cow<'static, str>>, encoded: &str) -> Result<Vec<u8>, AppError> {
+    BASE64_STANDARD
+        .decode(encoded)
+        .map_err(|_| AppError::InvalidBase64 { field: field.into() })
+}
+
+/// Decodes a hex encoded field of a request payload, with or without `0x` prefix, `field` naming
+/// it in the error.
+fn decode_hex(field: impl Into<Cow<'static, str>>, encoded: &str) -> Result<Vec<u8>, AppError> {
+    hex::decode(encoded.strip_prefix("0x").unwrap_or(encoded))
+        .map_err(|_| AppError::InvalidHex { field: field.into() })
+}
+
+/// Decodes a base64 encoded transaction request.
+fn decode_tx_request(encoded: &str) -> Result<TransactionRequest, AppError> {
+    TransactionRequest::read_from_bytes(&decode_base64("tx_request", encoded)?)
+        .map_err(|_| AppError::InvalidTransactionRequest)
+}
+
+/// Decodes a signature of the given approver key scheme, defaulting to Falcon, encoded in the
+/// given format, `base64` or `hex`, defaulting to `base64`.
+///
+/// This is the single decode path of the signatures of every request payload.
+fn decode_approver_signature(
+    field: impl Into<Cow<'static, str>>,
+    scheme: Option<&str>,
+    format: Option<&str>,
+    signature: &str,
+) -> Result<ApproverSignature, AppError> {
+    let scheme = scheme
+        .map(ApproverKeyScheme::try_from)
+        .transpose()
+        .map_err(|_| AppError::InvalidKeyScheme)?
+        .unwrap_or(ApproverKeyScheme::Falcon);
+
+    let (format, signature) = match format {
+        None | Some("base64") => ("base64", decode_base64(field, signature)?),
+        Some("hex") => ("hex", decode_hex(field, signature)?),
+        Some(_) => return Err(AppError::InvalidSignatureFormat),
+    };
+
+    ApproverSignature::from_bytes(scheme, &signature).ok_or(AppError::InvalidSignature { format })
+}
+
+/// Decodes an approver public key of the given scheme, i.e. a Falcon public key commitment or a
+/// compressed secp256k1 public key, encoded in hex, with or without `0x` prefix, or in base64,
+/// `field` naming it in the error.
+///
+/// The encoding is detected rather than given: a `0x` prefixed or hex digits only string is hex,
+/// anything else is base64. The base64 encoding of a commitment always ends with padding, so it
+/// is never mistaken for hex. Errors name the detected encoding, i.e. [`AppError::InvalidHex`]
+/// or [`AppError::InvalidBase64`] when the string is malformed, and the `format` of
+/// [`AppError::InvalidPubKeyCommit`] when the decoded bytes are not a key of the scheme.
+///
+/// This is the single decode path of the public key commitments of every request payload.
+fn decode_pub_key_commit(
+    field: impl Into<Cow<'static, str>>,
+    scheme: ApproverKeyScheme,
+    encoded: &str,
+) -> Result<ApproverKey, AppError> {
+    let is_hex = encoded.starts_with("0x")
+        || (!encoded.is_empty() && encoded.chars().all(|c| c.is_ascii_hexdigit()));
+
+    let (format, pub_key_commit) = if is_hex {
+        ("hex", decode_hex(field, encoded)?)
+    } else {
+        ("base64", decode_base64(field, encoded)?)
+    };
+
+    ApproverKey::from_bytes(scheme, &pub_key_commit).ok_or(AppError::InvalidPubKeyCommit { format })
+}
+
+/// Decodes the public key commitments of a request payload, each of the scheme at the same index
+/// of `schemes`, see [`decode_pub_key_commit`].
+///
+/// Every public key is a Falcon public key commitment unless schemes are given, in which case
+/// there must be exactly one per public key.
+fn decode_pub_key_commits(
+    pub_key_commits: &[String],
+    schemes: Option<Vec<String>>,
+) -> Result<Vec<ApproverKey>, AppError> {
+    let schemes: Vec<_> = match schemes {
+        Some(schemes) if schemes.len() != pub_key_commits.len() => {
+            return Err(AppError::InvalidKeyScheme);
+        },
+        Some(schemes) => schemes
+            .iter()
+            .map(|scheme| ApproverKeyScheme::try_from(scheme.as_str()))
+            .try_collect()
+            .map_err(|_| AppError::InvalidKeyScheme)?,
+        None => vec![ApproverKeyScheme::Falcon; pub_key_commits.len()],
+    };
+
+    pub_key_commits
+        .iter()
+        .zip(schemes)
+        .enumerate()
+        .map(|(i, (pub_key_commit, scheme))| {
+            decode_pub_key_commit(format!("pub_key_commits[{i}]"), scheme, pub_key_commit)
+        })
+        .try_collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use core::num::NonZeroU32;
+
+    use axum::{
+        body::Body,
+        extract::DefaultBodyLimit,
+        http::{Request, StatusCode, header},
+    };
+    use base64::{Engine, prelude::BASE64_STANDARD};
+    use chrono::Utc;
+    use miden_client::{
+        Word,
+        account::{AccountIdAddress, AccountStorageMode, AddressInterface, NetworkId},
+        utils::Serializable,
+    };
+    use miden_multisig_coordinator_domain::{
+        Timestamps,
+        account::{MultisigAccount, MultisigApprover},
+        key::{ApproverKey, ApproverKeyScheme, ApproverSignature, EcdsaPubKey},
+        tx::{MultisigTx, MultisigTxStatus, TxEffect},
+    };
+    use miden_multisig_coordinator_store::MultisigStoreBackend;
+    use miden_multisig_coordinator_utils::to_bech32;
+    use miden_multisig_test_utils::{
+        InMemoryMultisigStore, account_id_address, empty_tx_summary, pay_to_id_tx_request,
+    };
+    use miden_objects::{
+        crypto::dsa::rpo_falcon512::{PublicKey, SecretKey},
+        testing::account_id::{
+            ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET, ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
+            ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE, ACCOUNT_ID_SENDER,
+        },
+    };
+    use serde_json::json;
+    use tempfile::TempDir;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    use crate::{
+        auth::BearerTokens,
+        error::AppError,
+        payload::{MultisigTxPayload, response::ListMultisigApproverResponsePayload},
+        testing::{send, start_offline_app},
+    };
+
+    fn code(err: AppError) -> &'static str {
+        err.status_and_code().1
+    }
+
+    #[test]
+    fn tx_request_of_malformed_base64_and_of_undeserializable_bytes_fail_with_distinct_codes() {
+        // Arrange
+        let not_base64 = "not base64!";
+        let garbage = BASE64_STANDARD.encode(b"not a transaction request");
+
+        // Act
+        let not_base64_err = super::decode_tx_request(not_base64).unwrap_err();
+        let garbage_err = super::decode_tx_request(&garbage).unwrap_err();
+
+        // Assert
+        assert!(
+            matches!(&not_base64_err, AppError::InvalidBase64 { field } if field == "tx_request")
+        );
+        assert_eq!(code(not_base64_err), "INVALID_BASE64");
+        assert_eq!(code(garbage_err), "INVALID_TRANSACTION_REQUEST");
+    }
+
+    #[test]
+    fn signature_of_malformed_base64_and_of_undeserializable_bytes_fail_with_distinct_codes() {
+        // Arrange
+        let not_base64 = "not base64!";
+        let garbage = BASE64_STANDARD.encode(b"not a signature");
+
+        // Act
+        let not_base64_err =
+            super::decode_approver_signature("signature", None, None, not_base64).unwrap_err();
+        let garbage_err =
+            super::decode_approver_signature("signature", None, None, &garbage).unwrap_err();
+
+        // Assert
+        assert!(
+            matches!(&not_base64_err, AppError::InvalidBase64 { field } if field == "signature")
+        );
+        assert_eq!(code(not_base64_err), "INVALID_BASE64");
+        assert_eq!(code(garbage_err), "INVALID_SIGNATURE");
+    }
+
+    #[test]
+    fn signature_decodes_the_same_from_base64_and_hex() {
+        // Arrange
+        let signature = ApproverSignature::from(SecretKey::new().sign(Word::default())).to_bytes();
+
+        let base64 = BASE64_STANDARD.encode(&signature);
+        let hex = hex::encode(&signature);
+
+        // Act
+        let from_base64 =
+            super::decode_approver_signature("signature", None, Some("base64"), &base64).unwrap();
+        let from_hex =
+            super::decode_approver_signature("signature", None, Some("hex"), &hex).unwrap();
+        let from_prefixed_hex =
+            super::decode_approver_signature("signature", None, Some("hex"), &format!("0x{hex}"))
+                .unwrap();
+
+        // Assert
+        assert_eq!(from_base64.to_bytes(), signature);
+        assert_eq!(from_hex.to_bytes(), signature);
+        assert_eq!(from_prefixed_hex.to_bytes(), signature);
+    }
+
+    #[test]
+    fn signature_of_malformed_hex_and_of_undeserializable_bytes_fail_with_format() {
+        // Arrange
+        let not_hex = "not hex";
+        let garbage = hex::encode(b"not a signature");
+
+        // Act
+        let not_hex_err =
+            super::decode_approver_signature("signature", None, Some("hex"), not_hex).unwrap_err();
+        let garbage_err =
+            super::decode_approver_signature("signature", None, Some("hex"), &garbage).unwrap_err();
+        let unknown_format_err =
+            super::decode_approver_signature("signature", None, Some("base58"), &garbage)
+                .unwrap_err();
+
+        // Assert
+        assert!(matches!(&not_hex_err, AppError::InvalidHex { field } if field == "signature"));
+        assert!(matches!(garbage_err, AppError::InvalidSignature { format: "hex" }));
+        assert!(matches!(unknown_format_err, AppError::InvalidSignatureFormat));
+    }
+
+    #[test]
+    fn pub_key_commit_decodes_the_same_from_hex_and_base64() {
+        // Arrange
+        let pub_key_commit = Word::from(SecretKey::new().public_key());
+        let bytes = pub_key_commit.to_bytes();
+
+        let hex = hex::encode(&bytes);
+        let base64 = BASE64_STANDARD.encode(&bytes);
+
+        let decode = |encoded: &str| {
+            super::decode_pub_key_commit("pub_key_commits[0]", ApproverKeyScheme::Falcon, encoded)
+        };
+
+        // Act
+        let from_hex = decode(&hex).unwrap();
+        let from_prefixed_hex = decode(&format!("0x{hex}")).unwrap();
+        let from_base64 = decode(&base64).unwrap();
+
+        // Assert
+        assert_eq!(from_hex.commitment(), pub_key_commit);
+        assert_eq!(from_prefixed_hex.commitment(), pub_key_commit);
+        assert_eq!(from_base64.commitment(), pub_key_commit);
+    }
+
+    #[test]
+    fn pub_key_commit_decodes_as_the_given_scheme() {
+        // Arrange
+        let ecdsa_pub_key = [0x02; EcdsaPubKey::SERIALIZED_SIZE];
+        let falcon_pub_key_commit = Word::from(SecretKey::new().public_key()).to_bytes();
+
+        // Act
+        let ecdsa = super::decode_pub_key_commit(
+            "pub_key_commits[0]",
+            ApproverKeyScheme::Ecdsa,
+            &hex::encode(ecdsa_pub_key),
+        )
+        .unwrap();
+
+        let falcon_as_ecdsa_err = super::decode_pub_key_commit(
+            "pub_key_commits[0]",
+            ApproverKeyScheme::Ecdsa,
+            &hex::encode(falcon_pub_key_commit),
+        )
+        .unwrap_err();
+
+        // Assert
+        assert_eq!(ecdsa, ApproverKey::Ecdsa(EcdsaPubKey::from_bytes(&ecdsa_pub_key).unwrap()));
+        assert!(matches!(falcon_as_ecdsa_err, AppError::InvalidPubKeyCommit { format: "hex" }));
+    }
+
+    #[test]
+    fn invalid_pub_key_commit_error_names_the_detected_encoding() {
+        // Arrange
+        let malformed_hex = "0xnot hex";
+        let malformed_base64 = "not base64!";
+        let short_hex = hex::encode(b"not a commitment");
+        let short_base64 = BASE64_STANDARD.encode(b"not a commitment");
+
+        let decode = |encoded: &str| {
+            super::decode_pub_key_commit("pk", ApproverKeyScheme::Falcon, encoded).unwrap_err()
+        };
+
+        // Act
+        let malformed_hex_err = decode(malformed_hex);
+        let malformed_base64_err = decode(malformed_base64);
+        let short_hex_err = decode(&short_hex);
+        let short_base64_err = decode(&short_base64);
+
+        // Assert
+        assert!(matches!(&malformed_hex_err, AppError::InvalidHex { field } if field == "pk"));
+        assert!(
+            matches!(&malformed_base64_err, AppError::InvalidBase64 { field } if field == "pk")
+        );
+        assert!(matches!(short_hex_err, AppError::InvalidPubKeyCommit { format: "hex" }));
+        assert!(matches!(short_base64_err, AppError::InvalidPubKeyCommit { format: "base64" }));
+    }
+
+    #[test]
+    fn listed_approvers_carry_their_labels() {
+        // Arrange
+        let now = Utc::now();
+
+        let approvers = [
+            (ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE, Some("Alice - CFO")),
+            (ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE, None),
+        ]
+        .map(|(account_id, label)| {
+            let account_id = account_id.try_into().unwrap();
+            let address = AccountIdAddress::new(account_id, AddressInterface::BasicWallet);
+
+            MultisigApprover::builder()
+                .address(address)
+                .network_id(NetworkId::Testnet)
+                .pub_key_commit(ApproverKey::Falcon(PublicKey::new(Word::default())))
+                .maybe_label(label.map(ToOwned::to_owned))
+                .aux(Timestamps::builder().created_at(now).updated_at(now).build())
+                .build()
+        });
+
+        // Act
+        let response = ListMultisigApproverResponsePayload::builder()
+            .approvers(approvers.into_iter().map(From::from).collect())
+            .build();
+
+        // Assert
+        let response = serde_json::to_value(response).unwrap();
+
+        assert_eq!(response["approvers"][0]["label"], json!("Alice - CFO"));
+        assert_eq!(response["approvers"][1]["label"], json!(null));
+    }
+
+    #[tokio::test]
+    async fn approver_order_route_lists_approvers_by_index_and_rejects_unknown_accounts() {
+        // Arrange
+        let temp_dir = TempDir::new().expect("failed to create temporary directory");
+
+        let store = InMemoryMultisigStore::new();
+
+        let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+        let unknown_addr = account_id_address(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET);
+        let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+        let bob_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE);
+
+        let multisig_account = MultisigAccount::builder()
+            .address(multisig_addr)
+            .network_id(NetworkId::Testnet)
+            .kind(AccountStorageMode::Public)
+            .threshold(NonZeroU32::new(2).unwrap())
+            .aux(())
+            .build()
+            .with_approvers(vec![alice_addr, bob_addr])
+            .unwrap()
+            .with_pub_key_commits(vec![
+                ApproverKey::Falcon(SecretKey::new().public_key()),
+                ApproverKey::Falcon(SecretKey::new().public_key()),
+            ])
+            .unwrap();
+
+        store.create_multisig_account(multisig_account).await.unwrap();
+
+        let router = crate::create_router(start_offline_app(temp_dir.path(), store).await);
+
+        let request = |address| {
+            let uri = format!(
+                "/api/v1/multisig-account/approver-order?multisig_account_address={}",
+                to_bech32(NetworkId::Testnet, address),
+            );
+
+            Request::get(uri).body(Body::empty()).unwrap()
+        };
+
+        // Act
+        let (known_status, known) = send(router.clone(), request(multisig_addr)).await;
+        let (unknown_status, unknown) = send(router, request(unknown_addr)).await;
+
+        // Assert
+        assert_eq!(known_status, StatusCode::OK);
+
+        let approvers = known["approvers"].as_array().unwrap();
+
+        assert_eq!(approvers.len(), 2);
+
+        for (index, (approver, address)) in approvers.iter().zip([alice_addr, bob_addr]).enumerate()
+        {
+            assert_eq!(approver["index"], json!(index));
+            assert_eq!(approver["address"], json!(to_bech32(NetworkId::Testnet, address)));
+        }
+
+        assert_eq!(unknown_status, StatusCode::NOT_FOUND);
+        assert_eq!(unknown["code"], json!("MULTISIG_ACCOUNT_NOT_FOUND"));
+    }
+
+    #[tokio::test]
+    async fn validate_address_route_tells_malformed_addresses_from_addresses_of_other_networks() {
+        // Arrange
+        let temp_dir = TempDir::new().expect("failed to create temporary directory");
+
+        let app = start_offline_app(temp_dir.path(), InMemoryMultisigStore::new()).await;
+        let router = crate::create_router(app);
+
+        let address = AccountIdAddress::new(
+            ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE.try_into().unwrap(),
+            AddressInterface::BasicWallet,
+        );
+
+        let request = |address: &str| {
+            Request::post("/api/v1/address/validate")
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(json!({ "address": address }).to_string()))
+                .unwrap()
+        };
+
+        let testnet_address = to_bech32(NetworkId::Testnet, address);
+        let mainnet_address = to_bech32(NetworkId::Mainnet, address);
+
+        // Act
+        let (testnet_status, testnet) = send(router.clone(), request(&testnet_address)).await;
+        let (mainnet_status, mainnet) = send(router.clone(), request(&mainnet_address)).await;
+        let (malformed_status, malformed) = send(router, request("mtst1notanaddress")).await;
+
+        // Assert
+        assert_eq!(testnet_status, StatusCode::OK);
+        assert_eq!(
+            testnet,
+            json!({ "is_valid": true, "network_id": "mtst", "matches_network": true }),
+        );
+
+        assert_eq!(mainnet_status, StatusCode::OK);
+        assert_eq!(
+            mainnet,
+            json!({ "is_valid": true, "network_id": "mm", "matches_network": false }),
+        );
+
+        // a malformed address is a valid request, answered rather than rejected
+        assert_eq!(malformed_status, StatusCode::OK);
+        assert_eq!(malformed["is_valid"], json!(false));
+        assert_eq!(malformed["matches_network"], json!(false));
+        assert!(malformed.get("network_id").is_none());
+        assert!(malformed["error"].is_string());
+    }
+
+    #[tokio::test]
+    async fn export_route_streams_every_page_of_txs_as_json_lines() {
+        // Arrange
+        let temp_dir = TempDir::new().expect("failed to create temporary directory");
+
+        let store = InMemoryMultisigStore::new();
+
+        let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+        let unknown_addr = account_id_address(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET);
+        let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+
+        let multisig_account = MultisigAccount::builder()
+            .address(multisig_addr)
+            .network_id(NetworkId::Testnet)
+            .kind(AccountStorageMode::Public)
+            .threshold(NonZeroU32::MIN)
+            .aux(())
+            .build()
+            .with_approvers(vec![alice_addr])
+            .unwrap()
+            .with_pub_key_commits(vec![ApproverKey::Falcon(SecretKey::new().public_key())])
+            .unwrap();
+
+        store.create_multisig_account(multisig_account).await.unwrap();
+
+        let tx_request = pay_to_id_tx_request(multisig_addr.id(), alice_addr.id());
+
+        let tx_summary = empty_tx_summary(multisig_addr.id());
+
+        // one tx more than fits in a page, so that the export spans two pages
+        let mut tx_ids = Vec::new();
+        for _ in 0..=super::EXPORT_PAGE_SIZE.get() {
+            let tx_id = store
+                .create_multisig_tx(
+                    NetworkId::Testnet,
+                    multisig_addr,
+                    &tx_request,
+                    &tx_summary,
+                    None,
+                    None,
+                    None,
+                )
+                .await
+                .unwrap();
+
+            tx_ids.push(Uuid::from(tx_id));
+        }
+
+        let router = crate::create_router(start_offline_app(temp_dir.path(), store).await);
+
+        let request = |address| {
+            let uri = format!(
+                "/api/v1/multisig-tx/export?multisig_account_address={}",
+                to_bech32(NetworkId::Testnet, address),
+            );
+
+            Request::get(uri).body(Body::empty()).unwrap()
+        };
+
+        // Act
+        let response = router.clone().oneshot(request(multisig_addr)).await.unwrap();
+        let (unknown_status, unknown) = send(router, request(unknown_addr)).await;
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/x-ndjson");
+        assert_eq!(
+            response.headers()[header::CONTENT_DISPOSITION],
+            format!(
+                "attachment; filename=\"{}-txs.ndjson\"",
+                to_bech32(NetworkId::Testnet, multisig_addr),
+            ),
+        );
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+        let mut exported_tx_ids: Vec<_> = body
+            .split(|b| *b == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let tx: serde_json::Value = serde_json::from_slice(line).unwrap();
+
+                tx["id"].as_str().unwrap().parse::<Uuid>().unwrap()
+            })
+            .collect();
+
+        assert!(body.ends_with(b"\n"));
+
+        exported_tx_ids.sort();
+        tx_ids.sort();
+
+        assert_eq!(exported_tx_ids, tx_ids);
+
+        assert_eq!(unknown_status, StatusCode::NOT_FOUND);
+        assert_eq!(unknown["code"], json!("MULTISIG_ACCOUNT_NOT_FOUND"));
+    }
+
+    #[tokio::test]
+    async fn reconcile_txs_route_is_only_served_to_admins() {
+        // Arrange
+        let temp_dir = TempDir::new().expect("failed to create temporary directory");
+
+        let app = start_offline_app(temp_dir.path(), InMemoryMultisigStore::new()).await;
+
+        let admin_app = crate::App {
+            admin_token: BearerTokens::new(["s3cr3t"]),
+            ..app.clone()
+        };
+
+        let multisig_addr = AccountIdAddress::new(
+            ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE.try_into().unwrap(),
+            AddressInterface::BasicWallet,
+        );
+
+        let request = |uri, authorization: Option<&str>| {
+            let body = json!({
+                "multisig_account_address": to_bech32(NetworkId::Testnet, multisig_addr),
+            });
+
+            let mut request = Request::post(uri).header(header::CONTENT_TYPE, "application/json");
+
+            if let Some(authorization) = authorization {
+                request = request.header(header::AUTHORIZATION, authorization);
+            }
+
+            request.body(Body::from(body.to_string())).unwrap()
+        };
+
+        let reconcile = "/api/v1/admin/multisig-tx/reconcile";
+
+        // Act
+        let public = crate::create_router(app.clone())
+            .oneshot(request("/api/v1/multisig-tx/reconcile", None))
+            .await
+            .unwrap();
+
+        let without_admin_token =
+            crate::create_router(app).oneshot(request(reconcile, None)).await.unwrap();
+
+        let (anonymous_status, anonymous) =
+            send(crate::create_router(admin_app.clone()), request(reconcile, None)).await;
+
+        let (admin_status, admin) =
+            send(crate::create_router(admin_app), request(reconcile, Some("Bearer s3cr3t"))).await;
+
+        // Assert
+        assert_eq!(public.status(), StatusCode::NOT_FOUND);
+        assert_eq!(without_admin_token.status(), StatusCode::NOT_FOUND);
+
+        assert_eq!(anonymous_status, StatusCode::UNAUTHORIZED);
+        assert_eq!(anonymous["code"], json!("UNAUTHORIZED"));
+
+        // the admin is let through, to an account the store doesn't know
+        assert_eq!(admin_status, StatusCode::NOT_FOUND);
+        assert_eq!(admin["code"], json!("MULTISIG_ACCOUNT_NOT_FOUND"));
+    }
+
+    #[tokio::test]
+    async fn rejected_json_bodies_paths_and_queries_are_answered_in_the_error_envelope() {
+        // Arrange
+        let temp_dir = TempDir::new().expect("failed to create temporary directory");
+
+        let app = start_offline_app(temp_dir.path(), InMemoryMultisigStore::new()).await;
+
+        // limited like the server limits request bodies
+        let router = crate::create_router(app).layer(DefaultBodyLimit::max(1024));
+
+        let details = "/api/v1/multisig-account/details";
+
+        let cases = [
+            (
+                Request::post(details)
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(Body::from("{not json"))
+                    .unwrap(),
+                StatusCode::BAD_REQUEST,
+                "INVALID_JSON_BODY",
+            ),
+            (
+                Request::post(details).body(Body::from("{}")).unwrap(),
+                StatusCode::UNSUPPORTED_MEDIA_TYPE,
+                "INVALID_JSON_BODY",
+            ),
+            (
+                Request::post(details)
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(Body::from("{}"))
+                    .unwrap(),
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "INVALID_JSON_BODY",
+            ),
+            (
+                Request::post(details)
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(Body::from(format!("{{{}}}", " ".repeat(2048))))
+                    .unwrap(),
+                StatusCode::PAYLOAD_TOO_LARGE,
+                "INVALID_JSON_BODY",
+            ),
+            (
+                Request::get("/api/v1/multisig-tx/not-a-uuid/summary")
+                    .body(Body::empty())
+                    .unwrap(),
+                StatusCode::BAD_REQUEST,
+                "INVALID_PATH",
+            ),
+            (
+                Request::get("/api/v1/multisig-account/summary").body(Body::empty()).unwrap(),
+                StatusCode::BAD_REQUEST,
+                "INVALID_QUERY",
+            ),
+        ];
+
+        for (request, expected_status, expected_code) in cases {
+            // Act
+            let (status, body) = send(router.clone(), request).await;
+
+            // Assert
+            assert_eq!(status, expected_status);
+            assert_eq!(body["code"], json!(expected_code));
+            assert!(body["message"].is_string());
+        }
+    }
+
+    #[tokio::test]
+    async fn large_page_of_txs_converted_off_thread_keeps_the_bytes_of_inline_conversion() {
+        // Arrange
+        let multisig_addr = AccountIdAddress::new(
+            ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE.try_into().unwrap(),
+            AddressInterface::BasicWallet,
+        );
+        let recipient_id = ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE.try_into().unwrap();
+
+        let tx_request = pay_to_id_tx_request(multisig_addr.id(), recipient_id);
+
+        let tx_summary = empty_tx_summary(multisig_addr.id());
+
+        let now = Utc::now();
+
+        // one tx more than converted inline
+        let txs: Vec<_> = (0..=super::BLOCKING_CONVERSION_THRESHOLD)
+            .map(|_| {
+                MultisigTx::builder()
+                    .id(Uuid::new_v4().into())
+                    .address(multisig_addr)
+                    .network_id(NetworkId::Testnet)
+                    .status(MultisigTxStatus::Pending)
+                    .tx_request(tx_request.clone())
+                    .tx_summary(tx_summary.clone())
+                    .tx_summary_commit(tx_summary.to_commitment())
+                    .tx_effect(TxEffect::from(&tx_summary))
+                    .input_note_ids(Vec::new())
+                    .threshold(NonZeroU32::MIN)
+                    .aux(Timestamps::builder().created_at(now).updated_at(now).build())
+                    .build()
+            })
+            .collect();
+
+        let inline_payloads: Vec<_> =
+            txs.iter().cloned().map(MultisigTxPayload::from_multisig_tx).collect();
+
+        // Act
+        let payloads = super::to_multisig_tx_payloads(txs).await.unwrap();
+
+        // Assert
+        let payloads = serde_json::to_value(payloads).unwrap();
+
+        assert_eq!(payloads, serde_json::to_value(inline_payloads).unwrap());
+
+        for payload in payloads.as_array().unwrap() {
+            let tx_request_bytes =
+                BASE64_STANDARD.decode(payload["tx_request"].as_str().unwrap()).unwrap();
+            let tx_summary_bytes =
+                BASE64_STANDARD.decode(payload["tx_summary"].as_str().unwrap()).unwrap();
+
+            assert_eq!(tx_request_bytes, tx_request.to_bytes());
+            assert_eq!(tx_summary_bytes, tx_summary.to_bytes());
+        }
+    }
+}
@@ -0,0 +1,62 @@
+//! Helpers exercising the coordinator server router in tests, offline and without a database.
+
+use core::{num::NonZeroU32, time::Duration};
+
+use std::{path::Path, sync::Arc};
+
+use axum::{
+    Router,
+    body::{self, Body},
+    http::{Request, StatusCode},
+};
+use miden_client::account::NetworkId;
+use miden_multisig_coordinator_engine::{
+    MultisigClientRuntimeConfig, MultisigEngine, SharedNodeRpcClient,
+};
+use miden_multisig_coordinator_store::MultisigStoreBackend;
+use miden_multisig_test_utils::create_mock_rpc_api;
+use serde_json::Value;
+use tower::ServiceExt;
+
+use crate::{App, RateLimiter};
+
+/// Starts an [`App`] on the given store, its runtime storing its state in `temp_dir` and serving
+/// node requests with a mock node.
+pub(crate) async fn start_offline_app(
+    temp_dir: &Path,
+    store: impl MultisigStoreBackend + 'static,
+) -> App {
+    // the node url is never connected to, the mock node serving every request instead
+    let config = MultisigClientRuntimeConfig::builder()
+        .node_url("http://localhost".parse().unwrap())
+        .store_path(temp_dir.join("store"))
+        .keystore_path(temp_dir.join("keystore"))
+        .timeout(Duration::from_secs(10))
+        .shutdown_drain_timeout(Duration::from_secs(10))
+        .rpc_client(SharedNodeRpcClient::new(create_mock_rpc_api().await))
+        .build();
+
+    let engine = MultisigEngine::new(NetworkId::Testnet, store)
+        .start_multisig_client_runtime(config)
+        .await
+        .unwrap();
+
+    let unlimited = || Arc::new(RateLimiter::new(NonZeroU32::MAX, NonZeroU32::MAX));
+
+    App::builder()
+        .engine(engine)
+        .write_rate_limiter(unlimited())
+        .propose_rate_limiter(unlimited())
+        .sign_rate_limiter(unlimited())
+        .build()
+}
+
+/// Sends the request through the router, returning the status and JSON body of the response.
+pub(crate) async fn send(router: Router, request: Request<Body>) -> (StatusCode, Value) {
+    let response = router.oneshot(request).await.unwrap();
+    let status = response.status();
+
+    let body = body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+    (status, serde_json::from_slice(&body).unwrap())
+}
@@ -2,17 +2,18 @@
 
 use core::num::NonZeroU32;
 
-use alloc::vec::Vec;
+use alloc::{string::String, vec, vec::Vec};
 
 use bon::Builder;
+use chrono::{DateTime, Utc};
 use dissolve_derive::Dissolve;
 use miden_client::account::{AccountIdAddress, AccountStorageMode, NetworkId};
-use miden_objects::crypto::dsa::rpo_falcon512::PublicKey;
+use miden_objects::address::Address;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::Timestamps;
+use crate::{Timestamps, key::ApproverKey};
 
 #[cfg(feature = "serde")]
 use crate::with_serde;
@@ -20,7 +21,7 @@ use crate::with_serde;
 /// An approver authorized to sign multisig transactions.
 ///
 /// Each approver is identified by their account address and has an associated
-/// public key commitment used for signature verification.
+/// public key commitment used for signature verification, of any supported signature scheme.
 ///
 /// # Type Parameters
 ///
@@ -37,8 +38,14 @@ pub struct MultisigApprover<AUX = Timestamps> {
     network_id: NetworkId,
 
     /// The public key commitment used for signature verification.
-    #[cfg_attr(feature = "serde", serde(with = "with_serde::pub_key_commit"))]
-    pub_key_commit: PublicKey,
+    pub_key_commit: ApproverKey,
+
+    /// The optional human-readable label of the approver, e.g. "Alice - CFO".
+    ///
+    /// Labels are coordinator metadata only, they are not part of the on-chain account. They are
+    /// given per multisig account, an approver shared by several accounts may be labeled apart
+    /// in each of them.
+    label: Option<String>,
 
     /// Auxiliary metadata associated with this approver.
     aux: AUX,
@@ -89,9 +96,24 @@ pub struct MultisigAccount<APPR = WithoutApprovers, PKC = WithoutPubKeyCommits,
     #[cfg_attr(feature = "serde", serde(with = "with_serde::account_storage_mode"))]
     kind: AccountStorageMode,
 
-    /// The minimum number of signatures required to execute transactions.
+    /// The minimum total weight of the signing approvers required to execute transactions.
+    ///
+    /// Every approver weighs 1 unless set otherwise, i.e. the threshold is a number of signatures.
     threshold: NonZeroU32,
 
+    /// The on-chain nonce of the account as last seen by the coordinator, i.e. when the account
+    /// was created or the last multisig transaction was executed.
+    ///
+    /// `None` if unknown, i.e. for accounts stored before the coordinator tracked their nonce.
+    #[cfg_attr(feature = "serde", serde(default))]
+    nonce: Option<u64>,
+
+    /// When the account was archived, `None` if it is not archived.
+    ///
+    /// Archived accounts are hidden from the default listings, their history is kept.
+    #[cfg_attr(feature = "serde", serde(default))]
+    archived_at: Option<DateTime<Utc>>,
+
     /// The list of approvers (type-state: present or absent).
     approvers: APPR,
 
@@ -104,13 +126,15 @@ pub struct MultisigAccount<APPR = WithoutApprovers, PKC = WithoutPubKeyCommits,
 
 /// Type-state marker indicating that approvers have been set.
 ///
-/// This type wraps a vector of approver addresses and is used as a type parameter
-/// in [`MultisigAccount`] to enforce compile-time checks.
+/// This type wraps a vector of approver addresses along with their vote weights and optional
+/// labels, and is used as a type parameter in [`MultisigAccount`] to enforce compile-time checks.
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct WithApprovers(
     #[cfg_attr(feature = "serde", serde(with = "with_serde::vec_account_id_address"))]
     Vec<AccountIdAddress>,
+    Vec<NonZeroU32>,
+    Vec<Option<String>>,
 );
 
 /// Type-state marker indicating that approvers have not been set.
@@ -126,9 +150,7 @@ pub struct WithoutApprovers;
 /// in [`MultisigAccount`] to enforce compile-time checks.
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-pub struct WithPubKeyCommits(
-    #[cfg_attr(feature = "serde", serde(with = "with_serde::vec_pub_key_commits"))] Vec<PublicKey>,
-);
+pub struct WithPubKeyCommits(Vec<ApproverKey>);
 
 /// Type-state marker indicating that public key commitments have not been set.
 ///
@@ -150,6 +172,8 @@ impl<AUX> MultisigAccount<WithoutApprovers, WithoutPubKeyCommits, AUX> {
         network_id: NetworkId,
         kind: AccountStorageMode,
         threshold: NonZeroU32,
+        nonce: Option<u64>,
+        archived_at: Option<DateTime<Utc>>,
         aux: AUX,
     ) -> Self {
         Self {
@@ -157,6 +181,8 @@ impl<AUX> MultisigAccount<WithoutApprovers, WithoutPubKeyCommits, AUX> {
             network_id,
             kind,
             threshold,
+            nonce,
+            archived_at,
             approvers: WithoutApprovers,
             pub_key_commits: WithoutPubKeyCommits,
             aux,
@@ -179,6 +205,8 @@ impl<APPR, PKC, AUX1> MultisigAccount<APPR, PKC, AUX1> {
             network_id: self.network_id,
             kind: self.kind,
             threshold: self.threshold,
+            nonce: self.nonce,
+            archived_at: self.archived_at,
             approvers: self.approvers,
             pub_key_commits: self.pub_key_commits,
             aux,
@@ -189,7 +217,7 @@ impl<APPR, PKC, AUX1> MultisigAccount<APPR, PKC, AUX1> {
 }
 
 impl<AUX> MultisigAccount<WithoutApprovers, WithoutPubKeyCommits, AUX> {
-    /// Adds approvers to the account.
+    /// Adds approvers, each weighing 1, to the account.
     ///
     /// This transitions the account from [`WithoutApprovers`] to [`WithApprovers`] state when
     /// the threshold does not exceed the approver count.
@@ -202,13 +230,40 @@ impl<AUX> MultisigAccount<WithoutApprovers, WithoutPubKeyCommits, AUX> {
         self,
         approver_addresses: Vec<AccountIdAddress>,
     ) -> Option<MultisigAccount<WithApprovers, WithoutPubKeyCommits, AUX>> {
-        // TODO: ascertain whether casting u32 to usize will always be safe
-        (approver_addresses.len() >= self.threshold.get() as usize).then(|| MultisigAccount {
+        let weights = vec![NonZeroU32::MIN; approver_addresses.len()];
+        self.with_weighted_approvers(approver_addresses, weights)
+    }
+
+    /// Adds approvers with their vote weights, in approver order, to the account.
+    ///
+    /// The threshold is met when the weights of the signing approvers sum up to it, so
+    /// [`with_approvers`](Self::with_approvers) is the special case of every approver weighing 1.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(account)` if there is one weight per approver and the total weight meets or
+    ///   exceeds the threshold
+    /// * `None` otherwise
+    pub fn with_weighted_approvers(
+        self,
+        approver_addresses: Vec<AccountIdAddress>,
+        weights: Vec<NonZeroU32>,
+    ) -> Option<MultisigAccount<WithApprovers, WithoutPubKeyCommits, AUX>> {
+        let total_weight: u64 = weights.iter().map(|weight| u64::from(weight.get())).sum();
+
+        let is_valid = approver_addresses.len() == weights.len()
+            && total_weight >= u64::from(self.threshold.get());
+
+        let labels = vec![None; approver_addresses.len()];
+
+        is_valid.then(|| MultisigAccount {
             address: self.address,
             network_id: self.network_id,
             kind: self.kind,
             threshold: self.threshold,
-            approvers: WithApprovers(approver_addresses),
+            nonce: self.nonce,
+            archived_at: self.archived_at,
+            approvers: WithApprovers(approver_addresses, weights, labels),
             pub_key_commits: WithoutPubKeyCommits,
             aux: self.aux,
         })
@@ -225,7 +280,7 @@ impl<AUX> MultisigAccount<WithoutApprovers, WithoutPubKeyCommits, AUX> {
     /// * `None` if there are fewer public keys than the threshold
     pub fn with_pub_key_commits(
         self,
-        pub_key_commits: Vec<PublicKey>,
+        pub_key_commits: Vec<ApproverKey>,
     ) -> Option<MultisigAccount<WithoutApprovers, WithPubKeyCommits, AUX>> {
         // TODO: ascertain whether casting u32 to usize will always be safe
         (pub_key_commits.len() >= self.threshold.get() as usize).then(|| MultisigAccount {
@@ -233,6 +288,8 @@ impl<AUX> MultisigAccount<WithoutApprovers, WithoutPubKeyCommits, AUX> {
             network_id: self.network_id,
             kind: self.kind,
             threshold: self.threshold,
+            nonce: self.nonce,
+            archived_at: self.archived_at,
             approvers: WithoutApprovers,
             pub_key_commits: WithPubKeyCommits(pub_key_commits),
             aux: self.aux,
@@ -250,13 +307,15 @@ impl<AUX> MultisigAccount<WithApprovers, WithoutPubKeyCommits, AUX> {
     /// * `None` if the counts don't match
     pub fn with_pub_key_commits(
         self,
-        pub_key_commits: Vec<PublicKey>,
+        pub_key_commits: Vec<ApproverKey>,
     ) -> Option<MultisigAccount<WithApprovers, WithPubKeyCommits, AUX>> {
         (self.approvers.get().len() == pub_key_commits.len()).then(|| MultisigAccount {
             address: self.address,
             network_id: self.network_id,
             kind: self.kind,
             threshold: self.threshold,
+            nonce: self.nonce,
+            archived_at: self.archived_at,
             approvers: self.approvers,
             pub_key_commits: WithPubKeyCommits(pub_key_commits),
             aux: self.aux,
@@ -281,7 +340,9 @@ impl<AUX> MultisigAccount<WithoutApprovers, WithPubKeyCommits, AUX> {
             network_id: self.network_id,
             kind: self.kind,
             threshold: self.threshold,
-            approvers: WithApprovers(approver_addresses),
+            nonce: self.nonce,
+            archived_at: self.archived_at,
+            approvers: WithApprovers::new(approver_addresses),
             pub_key_commits: self.pub_key_commits,
             aux: self.aux,
         })
@@ -299,6 +360,11 @@ impl<APPR, PKC, AUX> MultisigAccount<APPR, PKC, AUX> {
         self.network_id
     }
 
+    /// Returns the canonical bech32 string of the account's address on its network.
+    pub fn address_bech32(&self) -> String {
+        Address::AccountId(self.address).to_bech32(self.network_id)
+    }
+
     /// Returns the account kind.
     pub fn kind(&self) -> AccountStorageMode {
         self.kind
@@ -309,6 +375,17 @@ impl<APPR, PKC, AUX> MultisigAccount<APPR, PKC, AUX> {
         self.threshold
     }
 
+    /// Returns the on-chain nonce of the account as last seen by the coordinator, or `None` if it
+    /// is unknown.
+    pub fn nonce(&self) -> Option<u64> {
+        self.nonce
+    }
+
+    /// Returns when the account was archived, or `None` if it is not archived.
+    pub fn archived_at(&self) -> Option<DateTime<Utc>> {
+        self.archived_at
+    }
+
     /// Returns a reference to the auxiliary metadata.
     pub fn aux(&self) -> &AUX {
         &self.aux
@@ -320,11 +397,34 @@ impl<PKC, AUX> MultisigAccount<WithApprovers, PKC, AUX> {
     pub fn approvers(&self) -> &[AccountIdAddress] {
         self.approvers.get()
     }
+
+    /// Returns the vote weights of the approvers, in approver order.
+    pub fn approver_weights(&self) -> &[NonZeroU32] {
+        self.approvers.weights()
+    }
+
+    /// Returns the optional labels of the approvers, in approver order.
+    pub fn approver_labels(&self) -> &[Option<String>] {
+        self.approvers.labels()
+    }
+
+    /// Labels the approvers, in approver order, approvers without a label being `None`.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(account)` if there is one label per approver
+    /// * `None` otherwise
+    pub fn with_approver_labels(mut self, labels: Vec<Option<String>>) -> Option<Self> {
+        (self.approvers.get().len() == labels.len()).then(|| {
+            self.approvers.2 = labels;
+            self
+        })
+    }
 }
 
 impl<APPR, AUX> MultisigAccount<APPR, WithPubKeyCommits, AUX> {
     /// Returns the list of public key commitments.
-    pub fn pub_key_commits(&self) -> &[PublicKey] {
+    pub fn pub_key_commits(&self) -> &[ApproverKey] {
         self.pub_key_commits.get()
     }
 }
@@ -358,6 +458,8 @@ impl<AUX> MultisigAccount<WithApprovers, WithoutPubKeyCommits, AUX> {
             network_id: self.network_id,
             kind: self.kind,
             threshold: self.threshold,
+            nonce: self.nonce,
+            archived_at: self.archived_at,
             approvers: WithoutApprovers,
             pub_key_commits: WithoutPubKeyCommits,
             aux: (),
@@ -376,12 +478,18 @@ impl<AUX> MultisigAccount<WithoutApprovers, WithPubKeyCommits, AUX> {
     /// 3. The original auxiliary data
     pub fn dissolve(
         self,
-    ) -> (MultisigAccount<WithoutApprovers, WithoutPubKeyCommits, ()>, Vec<PublicKey>, AUX) {
+    ) -> (
+        MultisigAccount<WithoutApprovers, WithoutPubKeyCommits, ()>,
+        Vec<ApproverKey>,
+        AUX,
+    ) {
         let multisig_account = MultisigAccount {
             address: self.address,
             network_id: self.network_id,
             kind: self.kind,
             threshold: self.threshold,
+            nonce: self.nonce,
+            archived_at: self.archived_at,
             approvers: WithoutApprovers,
             pub_key_commits: WithoutPubKeyCommits,
             aux: (),
@@ -404,7 +512,7 @@ impl<AUX> MultisigAccount<WithApprovers, WithPubKeyCommits, AUX> {
     ) -> (
         MultisigAccount<WithoutApprovers, WithoutPubKeyCommits, ()>,
         Vec<AccountIdAddress>,
-        Vec<PublicKey>,
+        Vec<ApproverKey>,
         AUX,
     ) {
         let multisig_account = MultisigAccount {
@@ -412,6 +520,8 @@ impl<AUX> MultisigAccount<WithApprovers, WithPubKeyCommits, AUX> {
             network_id: self.network_id,
             kind: self.kind,
             threshold: self.threshold,
+            nonce: self.nonce,
+            archived_at: self.archived_at,
             approvers: WithoutApprovers,
             pub_key_commits: WithoutPubKeyCommits,
             aux: (),
@@ -427,21 +537,35 @@ impl<AUX> MultisigAccount<WithApprovers, WithPubKeyCommits, AUX> {
 }
 
 impl WithApprovers {
+    fn new(approver_addresses: Vec<AccountIdAddress>) -> Self {
+        let weights = vec![NonZeroU32::MIN; approver_addresses.len()];
+        let labels = vec![None; approver_addresses.len()];
+        Self(approver_addresses, weights, labels)
+    }
+
     fn get(&self) -> &[AccountIdAddress] {
         &self.0
     }
 
+    fn weights(&self) -> &[NonZeroU32] {
+        &self.1
+    }
+
+    fn labels(&self) -> &[Option<String>] {
+        &self.2
+    }
+
     fn into_inner(self) -> Vec<AccountIdAddress> {
         self.0
     }
 }
 
 impl WithPubKeyCommits {
-    fn get(&self) -> &[PublicKey] {
+    fn get(&self) -> &[ApproverKey] {
         &self.0
     }
 
-    fn into_inner(self) -> Vec<PublicKey> {
+    fn into_inner(self) -> Vec<ApproverKey> {
         self.0
     }
 }
@@ -486,6 +610,8 @@ impl<AUX> From<MultisigAccount<WithApprovers, WithPubKeyCommits, AUX>>
             network_id,
             kind,
             threshold,
+            nonce,
+            archived_at,
             approvers,
             aux,
             ..
@@ -496,6 +622,8 @@ impl<AUX> From<MultisigAccount<WithApprovers, WithPubKeyCommits, AUX>>
             network_id,
             kind,
             threshold,
+            nonce,
+            archived_at,
             approvers,
             pub_key_commits: WithoutPubKeyCommits,
             aux,
@@ -513,6 +641,8 @@ impl<AUX> From<MultisigAccount<WithApprovers, WithPubKeyCommits, AUX>>
             network_id,
             kind,
             threshold,
+            nonce,
+            archived_at,
             pub_key_commits,
             aux,
             ..
@@ -523,9 +653,103 @@ impl<AUX> From<MultisigAccount<WithApprovers, WithPubKeyCommits, AUX>>
             network_id,
             kind,
             threshold,
+            nonce,
+            archived_at,
             approvers: WithoutApprovers,
             pub_key_commits,
             aux,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use core::num::NonZeroU32;
+
+    use alloc::{vec, vec::Vec};
+
+    use miden_client::account::{AccountIdAddress, AccountStorageMode, NetworkId};
+    use miden_objects::{
+        address::{Address, AddressInterface},
+        testing::account_id::{
+            ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
+            ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE, ACCOUNT_ID_SENDER,
+        },
+    };
+
+    use super::MultisigAccount;
+
+    #[test]
+    fn address_bech32_decodes_into_stored_network_id_and_address() {
+        // Arrange
+        let address = AccountIdAddress::new(
+            ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE.try_into().unwrap(),
+            AddressInterface::BasicWallet,
+        );
+
+        for network_id in [NetworkId::Mainnet, NetworkId::Testnet, NetworkId::Devnet] {
+            let account = MultisigAccount::builder()
+                .address(address)
+                .network_id(network_id)
+                .kind(AccountStorageMode::Public)
+                .threshold(NonZeroU32::MIN)
+                .aux(())
+                .build();
+
+            // Act
+            let bech32 = account.address_bech32();
+
+            // Assert
+            let (decoded_network_id, decoded_address) = Address::from_bech32(&bech32).unwrap();
+
+            assert_eq!(decoded_network_id, network_id);
+            assert_eq!(decoded_address, Address::AccountId(address));
+        }
+    }
+
+    #[test]
+    fn weighted_approvers_are_accepted_once_their_total_weight_meets_threshold() {
+        // Arrange
+        let account_id_address =
+            |id: u128| AccountIdAddress::new(id.try_into().unwrap(), AddressInterface::BasicWallet);
+
+        let approvers = vec![
+            account_id_address(ACCOUNT_ID_SENDER),
+            account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE),
+        ];
+
+        let account = |threshold| {
+            MultisigAccount::builder()
+                .address(account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE))
+                .network_id(NetworkId::Testnet)
+                .kind(AccountStorageMode::Public)
+                .threshold(NonZeroU32::new(threshold).unwrap())
+                .aux(())
+                .build()
+        };
+
+        let weights = |weights: &[u32]| {
+            weights
+                .iter()
+                .map(|&weight| NonZeroU32::new(weight).unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        // Act
+        let met = account(4).with_weighted_approvers(approvers.clone(), weights(&[1, 3]));
+        let short = account(5).with_weighted_approvers(approvers.clone(), weights(&[1, 3]));
+        let mismatched = account(1).with_weighted_approvers(approvers.clone(), weights(&[1]));
+        let unweighted = account(2).with_approvers(approvers.clone());
+
+        // Assert
+        let met = met.expect("a total weight meeting the threshold must be accepted");
+
+        assert_eq!(met.approvers(), approvers);
+        assert_eq!(met.approver_weights(), weights(&[1, 3]));
+
+        assert!(short.is_none());
+        assert!(mismatched.is_none());
+
+        assert_eq!(unweighted.unwrap().approver_weights(), [NonZeroU32::MIN; 2]);
+    }
+}
@@ -0,0 +1,134 @@
+//! Audit log domain models, a durable record of notable accepted and rejected operations.
+
+use alloc::string::String;
+
+use bon::Builder;
+use chrono::{DateTime, Utc};
+use dissolve_derive::Dissolve;
+use miden_client::account::{AccountIdAddress, NetworkId};
+use strum::{Display, EnumString, IntoStaticStr};
+use uuid::Uuid;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::tx::MultisigTxId;
+
+#[cfg(feature = "serde")]
+use crate::with_serde;
+
+/// An operation on a multisig account recorded in the audit log.
+///
+/// The canonical string form is the snake case variant name, e.g. `add_signature`, as stored in
+/// the database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoStaticStr, EnumString, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditAction {
+    /// A transaction was proposed.
+    ProposeTx,
+    /// A failed transaction was re-proposed.
+    ReproposeTx,
+    /// An approver signed a transaction.
+    AddSignature,
+    /// A transaction was executed and submitted to the node.
+    ExecuteTx,
+}
+
+/// Whether an operation recorded in the audit log was carried out.
+///
+/// The canonical string form is the snake case variant name, e.g. `rejected`, as stored in the
+/// database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoStaticStr, EnumString, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditOutcome {
+    /// The operation was carried out.
+    Accepted,
+    /// The operation was refused, e.g. a signature of a non-approver.
+    Rejected,
+}
+
+/// What an operation recorded in the audit log applied to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuditSubject {
+    /// A multisig account, e.g. for a proposal refused before any transaction was stored.
+    Account(AccountIdAddress),
+    /// A multisig transaction, the event is recorded in the audit log of its account.
+    Tx(MultisigTxId),
+}
+
+/// An entry of the audit log of a multisig account.
+///
+/// Unlike tracing logs, audit events are persisted along the account, so that accepted and
+/// rejected operations can be reviewed long after they happened.
+#[derive(Debug, Clone, Builder, Dissolve)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde_with::serde_as)]
+pub struct AuditEvent {
+    /// The unique identifier of the event.
+    #[cfg_attr(feature = "serde", serde(with = "with_serde::hyphenated_uuid"))]
+    id: Uuid,
+
+    /// The multisig account address the operation applied to.
+    #[cfg_attr(feature = "serde", serde(with = "with_serde::account_id_address"))]
+    address: AccountIdAddress,
+
+    /// The network the multisig account is associated with.
+    #[cfg_attr(feature = "serde", serde(with = "with_serde::network_id"))]
+    network_id: NetworkId,
+
+    /// The transaction the operation applied to, if any.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    tx_id: Option<MultisigTxId>,
+
+    /// The approver who carried out the operation, if known.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "with_serde::option_account_id_address"
+        )
+    )]
+    actor: Option<AccountIdAddress>,
+
+    /// The operation.
+    #[cfg_attr(feature = "serde", serde_as(as = "DisplayFromStr"))]
+    action: AuditAction,
+
+    /// Whether the operation was carried out.
+    #[cfg_attr(feature = "serde", serde_as(as = "DisplayFromStr"))]
+    outcome: AuditOutcome,
+
+    /// A human-readable detail of the outcome, e.g. why the operation was rejected.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    detail: Option<String>,
+
+    /// When the event was recorded.
+    created_at: DateTime<Utc>,
+}
+
+impl AuditEvent {
+    /// Returns the unique identifier of the event.
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    /// Returns the multisig account address the operation applied to.
+    pub fn address(&self) -> AccountIdAddress {
+        self.address
+    }
+
+    /// Returns the operation.
+    pub fn action(&self) -> AuditAction {
+        self.action
+    }
+
+    /// Returns whether the operation was carried out.
+    pub fn outcome(&self) -> AuditOutcome {
+        self.outcome
+    }
+
+    /// Returns when the event was recorded.
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
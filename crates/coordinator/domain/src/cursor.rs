@@ -0,0 +1,261 @@
+//! Opaque cursors of keyset paginated listings.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt, str::FromStr};
+
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de::Error};
+
+/// The number of bytes the sort key of a cursor is encoded with, in big endian, ahead of the
+/// bytes of the id.
+const SORT_KEY_LEN: usize = 8;
+
+/// The position of an entity in a listing ordered by a sort key then id, from which the next
+/// page of the listing starts.
+///
+/// The sort key is the value the entity was sorted by when the page was listed, e.g. its creation
+/// timestamp, so that the next page starts right after it even if the entity changed or was
+/// deleted since. A cursor is thus only valid for the sort order it was returned for. The id
+/// breaks ties between entities with equal sort keys, it is the [`Uuid`] of transactions and
+/// audit events, and the address of multisig accounts and approvers.
+///
+/// The canonical string form is the URL-safe unpadded base64 encoding of the sort key, i.e. a
+/// timestamp at microsecond precision as stored in the database or a count, and of the id. It is
+/// opaque to clients, produced by [`Display`](fmt::Display), accepted by [`FromStr`] and used by
+/// serde.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cursor<I = Uuid> {
+    sort_key: i64,
+    id: I,
+}
+
+/// An id breaking ties between entities with equal sort keys in a [`Cursor`].
+pub trait CursorId: Sized {
+    /// Returns the bytes the id is encoded with in the cursor.
+    fn to_cursor_bytes(&self) -> Vec<u8>;
+
+    /// Decodes the id from the bytes it is encoded with, `None` if they don't encode an id.
+    fn from_cursor_bytes(bytes: &[u8]) -> Option<Self>;
+}
+
+impl CursorId for Uuid {
+    fn to_cursor_bytes(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+
+    fn from_cursor_bytes(bytes: &[u8]) -> Option<Self> {
+        Uuid::from_slice(bytes).ok()
+    }
+}
+
+impl CursorId for String {
+    fn to_cursor_bytes(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+
+    fn from_cursor_bytes(bytes: &[u8]) -> Option<Self> {
+        core::str::from_utf8(bytes)
+            .ok()
+            .filter(|id| !id.is_empty())
+            .map(ToString::to_string)
+    }
+}
+
+impl<I> Cursor<I> {
+    /// Returns the cursor of the entity with the given id, sorted by the timestamp `sort_key`,
+    /// e.g. its creation timestamp.
+    pub fn new(sort_key: DateTime<Utc>, id: I) -> Self {
+        Self {
+            sort_key: sort_key.timestamp_micros(),
+            id,
+        }
+    }
+
+    /// Returns the cursor of the entity with the given id, sorted by the count `sort_key`, e.g.
+    /// its number of signatures.
+    pub fn with_count(sort_key: u64, id: I) -> Self {
+        // no count stored in the database exceeds a bigint
+        Self {
+            sort_key: i64::try_from(sort_key).unwrap_or(i64::MAX),
+            id,
+        }
+    }
+
+    /// Returns the sort key of the entity as a timestamp.
+    ///
+    /// A sort key beyond the range of timestamps, which no cursor returned by a timestamp sorted
+    /// listing holds, is clamped to that range, so that it still orders the same way.
+    pub fn timestamp(&self) -> DateTime<Utc> {
+        DateTime::from_timestamp_micros(self.sort_key).unwrap_or(if self.sort_key < 0 {
+            DateTime::<Utc>::MIN_UTC
+        } else {
+            DateTime::<Utc>::MAX_UTC
+        })
+    }
+
+    /// Returns the sort key of the entity as a count.
+    pub fn count(&self) -> i64 {
+        self.sort_key
+    }
+
+    /// Returns the id of the entity.
+    pub fn id(&self) -> &I {
+        &self.id
+    }
+}
+
+impl<I: CursorId> fmt::Display for Cursor<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut bytes = self.sort_key.to_be_bytes().to_vec();
+        bytes.extend(self.id.to_cursor_bytes());
+
+        f.write_str(&URL_SAFE_NO_PAD.encode(bytes))
+    }
+}
+
+impl<I: CursorId> FromStr for Cursor<I> {
+    type Err = InvalidCursorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = URL_SAFE_NO_PAD.decode(s).map_err(|_| InvalidCursorError)?;
+
+        if bytes.len() < SORT_KEY_LEN {
+            return Err(InvalidCursorError);
+        }
+
+        let (sort_key, id) = bytes.split_at(SORT_KEY_LEN);
+        let sort_key = i64::from_be_bytes(sort_key.try_into().expect("sort key is 8 bytes"));
+
+        let id = I::from_cursor_bytes(id).ok_or(InvalidCursorError)?;
+
+        Ok(Self { sort_key, id })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<I: CursorId> Serialize for Cursor<I> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, I: CursorId> Deserialize<'de> for Cursor<I> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // owned rather than borrowed, as not every deserializer can lend its input
+        String::deserialize(deserializer)
+            .map(|cursor| cursor.parse())?
+            .map_err(D::Error::custom)
+    }
+}
+
+/// Error returned when a string is not the canonical form of a [`Cursor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidCursorError;
+
+impl fmt::Display for InvalidCursorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid cursor")
+    }
+}
+
+impl core::error::Error for InvalidCursorError {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+
+    use chrono::{DateTime, Utc};
+    use uuid::Uuid;
+
+    use super::{Cursor, InvalidCursorError};
+
+    #[test]
+    fn cursor_round_trips_through_its_string_form() {
+        // Arrange
+        let created_at = DateTime::<Utc>::from_timestamp_micros(1_731_672_000_123_456).unwrap();
+        let id = Uuid::from_u128(0x550e_8400_e29b_41d4_a716_4466_5544_0000);
+        let cursor = Cursor::new(created_at, id);
+
+        // Act
+        let encoded = cursor.to_string();
+        let decoded = encoded.parse::<Cursor>().unwrap();
+
+        // Assert
+        assert!(encoded.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+        assert_eq!(decoded, cursor);
+    }
+
+    #[test]
+    fn count_sorted_cursor_round_trips_through_its_string_form() {
+        // Arrange
+        let cursor = Cursor::with_count(3, Uuid::max());
+
+        // Act
+        let decoded = cursor.to_string().parse::<Cursor>().unwrap();
+
+        // Assert
+        assert_eq!(decoded, cursor);
+        assert_eq!(decoded.count(), 3);
+        assert_eq!(Cursor::with_count(u64::MAX, Uuid::nil()).count(), i64::MAX);
+    }
+
+    #[test]
+    fn sort_key_beyond_timestamps_is_clamped() {
+        // Act
+        let (min, max) =
+            (Cursor::with_count(0, Uuid::nil()), Cursor::with_count(u64::MAX, Uuid::nil()));
+
+        // Assert
+        assert_eq!(min.timestamp(), DateTime::<Utc>::UNIX_EPOCH);
+        assert_eq!(max.timestamp(), DateTime::<Utc>::MAX_UTC);
+    }
+
+    #[test]
+    fn malformed_cursor_is_rejected() {
+        // Arrange
+        let cursor = Cursor::new(DateTime::<Utc>::UNIX_EPOCH, Uuid::nil()).to_string();
+        let truncated = &cursor[..cursor.len() - 2];
+        let extended = cursor.clone() + "AA";
+
+        for malformed in ["", "not a cursor", truncated, &extended] {
+            // Act
+            let result = malformed.parse::<Cursor>();
+
+            // Assert
+            assert_eq!(result, Err(InvalidCursorError), "{malformed}");
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn cursor_deserializes_from_owned_string() {
+        use serde::{
+            Deserialize,
+            de::value::{Error, StringDeserializer},
+        };
+
+        // Arrange
+        let cursor = Cursor::new(DateTime::<Utc>::UNIX_EPOCH, Uuid::max());
+        let deserializer = StringDeserializer::<Error>::new(cursor.to_string());
+
+        // Act
+        let deserialized = Cursor::deserialize(deserializer);
+
+        // Assert
+        assert_eq!(deserialized, Ok(cursor));
+    }
+}
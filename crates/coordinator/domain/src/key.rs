@@ -0,0 +1,206 @@
+//! Approver key and signature domain models for the supported signature schemes.
+
+use alloc::vec::Vec;
+
+use miden_client::{
+    Word,
+    utils::{Deserializable, Serializable},
+};
+use miden_objects::crypto::{
+    dsa::rpo_falcon512::{PublicKey, Signature},
+    hash::rpo::Rpo256,
+};
+use strum::{Display, EnumString, IntoStaticStr};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "serde")]
+use crate::with_serde;
+
+/// The signature scheme of an approver key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoStaticStr, EnumString, Display)]
+#[strum(serialize_all = "snake_case")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ApproverKeyScheme {
+    /// RPO Falcon512, natively verified by the multisig account.
+    Falcon,
+    /// ECDSA over secp256k1.
+    Ecdsa,
+}
+
+/// The public key an approver signs multisig transactions with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ApproverKey {
+    /// An RPO Falcon512 public key commitment.
+    Falcon(#[cfg_attr(feature = "serde", serde(with = "with_serde::pub_key_commit"))] PublicKey),
+    /// A compressed secp256k1 public key.
+    Ecdsa(EcdsaPubKey),
+}
+
+/// A compressed SEC1 encoded secp256k1 public key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EcdsaPubKey(
+    #[cfg_attr(feature = "serde", serde(with = "with_serde::byte_array"))]
+    [u8; EcdsaPubKey::SERIALIZED_SIZE],
+);
+
+/// A signature made by an approver over a transaction summary commitment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApproverSignature {
+    /// An RPO Falcon512 signature.
+    Falcon(Signature),
+    /// A recoverable secp256k1 ECDSA signature.
+    Ecdsa(EcdsaSignature),
+}
+
+/// A recoverable secp256k1 ECDSA signature, encoded as `r || s || v`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EcdsaSignature([u8; EcdsaSignature::SERIALIZED_SIZE]);
+
+impl ApproverKey {
+    /// Decodes an approver key of the given scheme from its byte representation.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(key)` if the bytes are a valid key of the given scheme
+    /// * `None` otherwise
+    pub fn from_bytes(scheme: ApproverKeyScheme, bz: &[u8]) -> Option<Self> {
+        match scheme {
+            ApproverKeyScheme::Falcon => {
+                Word::read_from_bytes(bz).map(PublicKey::new).map(Self::Falcon).ok()
+            },
+            ApproverKeyScheme::Ecdsa => EcdsaPubKey::from_bytes(bz).map(Self::Ecdsa),
+        }
+    }
+
+    /// Returns the signature scheme of the key.
+    pub fn scheme(&self) -> ApproverKeyScheme {
+        match self {
+            Self::Falcon(_) => ApproverKeyScheme::Falcon,
+            Self::Ecdsa(_) => ApproverKeyScheme::Ecdsa,
+        }
+    }
+
+    /// Returns the byte representation of the key, without the scheme.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            &Self::Falcon(pub_key_commit) => Word::from(pub_key_commit).as_bytes().to_vec(),
+            Self::Ecdsa(pub_key) => pub_key.as_bytes().to_vec(),
+        }
+    }
+
+    /// Returns the commitment the multisig account holds on chain for the approver's key.
+    ///
+    /// A Falcon public key commitment is its own commitment, a secp256k1 public key is committed
+    /// to by the RPO hash of its compressed encoding.
+    pub fn commitment(&self) -> Word {
+        match self {
+            &Self::Falcon(pub_key_commit) => pub_key_commit.into(),
+            Self::Ecdsa(pub_key) => Rpo256::hash(pub_key.as_bytes()),
+        }
+    }
+
+    /// Returns the Falcon public key commitment, if this is a Falcon key.
+    pub fn as_falcon(&self) -> Option<PublicKey> {
+        match self {
+            &Self::Falcon(pub_key_commit) => Some(pub_key_commit),
+            Self::Ecdsa(_) => None,
+        }
+    }
+}
+
+impl EcdsaPubKey {
+    /// The size of a compressed SEC1 encoded secp256k1 public key.
+    pub const SERIALIZED_SIZE: usize = 33;
+
+    /// Decodes a compressed SEC1 encoded public key.
+    ///
+    /// Only the length and the compression tag are checked, whether the key is a point on the
+    /// curve is checked when verifying signatures.
+    pub fn from_bytes(bz: &[u8]) -> Option<Self> {
+        let bz = <[u8; Self::SERIALIZED_SIZE]>::try_from(bz).ok()?;
+        matches!(bz[0], 0x02 | 0x03).then_some(Self(bz))
+    }
+
+    /// Returns the compressed SEC1 encoding of the public key.
+    pub fn as_bytes(&self) -> &[u8; Self::SERIALIZED_SIZE] {
+        &self.0
+    }
+}
+
+impl ApproverSignature {
+    /// Decodes a signature of the given scheme from its byte representation.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(signature)` if the bytes are a valid signature of the given scheme
+    /// * `None` otherwise
+    pub fn from_bytes(scheme: ApproverKeyScheme, bz: &[u8]) -> Option<Self> {
+        match scheme {
+            ApproverKeyScheme::Falcon => Signature::read_from_bytes(bz).map(Self::Falcon).ok(),
+            ApproverKeyScheme::Ecdsa => EcdsaSignature::from_bytes(bz).map(Self::Ecdsa),
+        }
+    }
+
+    /// Returns the signature scheme of the signature.
+    pub fn scheme(&self) -> ApproverKeyScheme {
+        match self {
+            Self::Falcon(_) => ApproverKeyScheme::Falcon,
+            Self::Ecdsa(_) => ApproverKeyScheme::Ecdsa,
+        }
+    }
+
+    /// Returns the byte representation of the signature, without the scheme.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            Self::Falcon(signature) => signature.to_bytes(),
+            Self::Ecdsa(signature) => signature.as_bytes().to_vec(),
+        }
+    }
+}
+
+impl EcdsaSignature {
+    /// The size of a recoverable secp256k1 ECDSA signature.
+    pub const SERIALIZED_SIZE: usize = 65;
+
+    /// Decodes a recoverable signature encoded as `r || s || v`.
+    pub fn from_bytes(bz: &[u8]) -> Option<Self> {
+        <[u8; Self::SERIALIZED_SIZE]>::try_from(bz).ok().map(Self)
+    }
+
+    /// Returns the `r || s || v` encoding of the signature.
+    pub fn as_bytes(&self) -> &[u8; Self::SERIALIZED_SIZE] {
+        &self.0
+    }
+}
+
+impl From<PublicKey> for ApproverKey {
+    /// Wraps a Falcon public key commitment into an approver key.
+    fn from(pub_key_commit: PublicKey) -> Self {
+        Self::Falcon(pub_key_commit)
+    }
+}
+
+impl From<EcdsaPubKey> for ApproverKey {
+    /// Wraps a secp256k1 public key into an approver key.
+    fn from(pub_key: EcdsaPubKey) -> Self {
+        Self::Ecdsa(pub_key)
+    }
+}
+
+impl From<Signature> for ApproverSignature {
+    /// Wraps a Falcon signature into an approver signature.
+    fn from(signature: Signature) -> Self {
+        Self::Falcon(signature)
+    }
+}
+
+impl From<EcdsaSignature> for ApproverSignature {
+    /// Wraps a secp256k1 signature into an approver signature.
+    fn from(signature: EcdsaSignature) -> Self {
+        Self::Ecdsa(signature)
+    }
+}
@@ -9,6 +9,9 @@
 extern crate alloc;
 
 pub mod account;
+pub mod audit;
+pub mod cursor;
+pub mod key;
 pub mod tx;
 
 #[cfg(feature = "serde")]
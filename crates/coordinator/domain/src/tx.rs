@@ -1,16 +1,28 @@
 //! Multisig transaction domain models and status tracking.
 
-use core::{fmt, num::NonZeroU32};
+mod effect;
+
+pub use self::effect::{TxAssetAmount, TxEffect, TxOutputNote};
+
+use core::{fmt, num::NonZeroU32, str::FromStr, time::Duration};
+
+use alloc::{string::String, vec::Vec};
 
 use bon::Builder;
+use chrono::{DateTime, Utc};
 use dissolve_derive::Dissolve;
 use miden_client::{
     Word,
     account::{AccountIdAddress, NetworkId},
+    note::NoteId,
     transaction::TransactionRequest,
 };
-use miden_objects::transaction::TransactionSummary;
-use strum::{Display, EnumString, IntoStaticStr};
+use miden_objects::{
+    address::Address,
+    block::BlockNumber,
+    transaction::{TransactionId, TransactionSummary},
+};
+use strum::{Display, EnumString, IntoStaticStr, VariantArray};
 use uuid::Uuid;
 
 #[cfg(feature = "serde")]
@@ -25,26 +37,99 @@ use crate::with_serde;
 ///
 /// This is a wrapper around a UUID that provides type safety and
 /// seamless conversion to/from UUID values.
-#[derive(Debug, Clone)]
+///
+/// The canonical string form is the lowercase hyphenated UUID, e.g.
+/// `550e8400-e29b-41d4-a716-446655440000`, as stored in the database. It is produced by
+/// [`Display`](fmt::Display), accepted by [`FromStr`] and used by serde.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(transparent))]
-pub struct MultisigTxId(Uuid);
+pub struct MultisigTxId(
+    #[cfg_attr(feature = "serde", serde(with = "with_serde::hyphenated_uuid"))] Uuid,
+);
 
 /// The execution status of a multisig transaction.
 ///
 /// A transaction progresses through these states as signatures are collected
 /// and the transaction is executed.
-#[derive(Debug, Clone, IntoStaticStr, EnumString, Display)]
+///
+/// The canonical string form is the snake case variant name, e.g. `ready_to_execute`, as stored
+/// in the database. It is produced by [`Display`](fmt::Display), accepted by [`FromStr`], and
+/// every status listed by [`MultisigTxStatus::all`] round-trips through it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoStaticStr, EnumString, Display, VariantArray)]
 #[strum(serialize_all = "snake_case")]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum MultisigTxStatus {
     /// The transaction is awaiting sufficient signatures to meet the threshold.
     Pending,
+    /// The transaction met its threshold and awaits a manual execution, as automatic processing
+    /// is disabled.
+    ReadyToExecute,
+    /// The transaction met its threshold and is being executed, having been claimed by a manual
+    /// execution, so that it can't be executed twice.
+    Executing,
     /// The transaction has been successfully submitted on-chain.
     Success,
     /// The transaction execution failed.
     Failure,
 }
 
+/// The field multisig transactions are sorted by when listed.
+///
+/// The canonical string form is the snake case variant name, e.g. `signature_count`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, IntoStaticStr, EnumString, Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum TxSortField {
+    /// When the transaction was proposed.
+    #[default]
+    CreatedAt,
+    /// When the transaction last changed, i.e. was signed or changed status.
+    UpdatedAt,
+    /// The number of signatures collected.
+    SignatureCount,
+}
+
+/// The direction multisig transactions are sorted in when listed.
+///
+/// The canonical string form is the lowercase variant name, i.e. `asc` or `desc`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, IntoStaticStr, EnumString, Display)]
+#[strum(serialize_all = "lowercase")]
+pub enum SortDirection {
+    /// Smallest first.
+    Asc,
+    /// Largest first.
+    #[default]
+    Desc,
+}
+
+/// The order multisig transactions are listed in.
+///
+/// Transactions sorting equal on the field are ordered by id in the same direction, so that the
+/// order is total and stable across pages. Defaults to the most recently proposed first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Builder)]
+pub struct TxSort {
+    /// The field to sort by.
+    #[builder(default)]
+    field: TxSortField,
+
+    /// The direction to sort in.
+    #[builder(default)]
+    direction: SortDirection,
+}
+
+/// The width of the time buckets multisig transactions are counted in for a trend.
+///
+/// Buckets start at midnight UTC, on Mondays for weeks. The canonical string form is the
+/// lowercase variant name, i.e. `day` or `week`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, IntoStaticStr, EnumString, Display)]
+#[strum(serialize_all = "lowercase")]
+pub enum TrendGranularity {
+    /// One bucket per day.
+    #[default]
+    Day,
+    /// One bucket per ISO week.
+    Week,
+}
+
 /// A multisig transaction tracking signatures and execution state.
 ///
 /// This represents a transaction that requires multiple signatures before
@@ -84,10 +169,56 @@ pub struct MultisigTx<AUX = Timestamps> {
     #[cfg_attr(feature = "serde", serde(with = "with_serde::word"))]
     tx_summary_commit: Word,
 
+    /// The effect of the transaction, decoded from its summary when it was proposed.
+    tx_effect: TxEffect,
+
+    /// The ids of the input notes consumed by the transaction request, in request order.
+    #[cfg_attr(feature = "serde", serde(with = "with_serde::vec_note_id"))]
+    input_note_ids: Vec<NoteId>,
+
+    /// An optional short human-readable title of the proposal.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    title: Option<String>,
+
+    /// An optional human-readable memo describing the proposal.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    memo: Option<String>,
+
+    /// The reason the transaction failed to be processed, set only for failed transactions.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    failure_reason: Option<String>,
+
+    /// The id of the executed transaction on chain, set only for successful transactions.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "with_serde::option_transaction_id"
+        )
+    )]
+    chain_tx_id: Option<TransactionId>,
+
+    /// The number of the block the transaction was executed against when it was submitted, set
+    /// only for successful transactions.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "with_serde::option_block_number"
+        )
+    )]
+    submission_block_num: Option<BlockNumber>,
+
     /// The number of signatures currently collected (if any).
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     signature_count: Option<NonZeroU32>,
 
+    /// The threshold of the multisig account the transaction applies to, i.e. the number of
+    /// signatures required to process it.
+    threshold: NonZeroU32,
+
     /// Auxiliary metadata associated with this transaction.
     aux: AUX,
 }
@@ -104,6 +235,70 @@ pub struct MultisigTxStats {
 
     /// The total number of successfully executed transactions.
     total_success: u64,
+
+    /// The number of the account's transactions signed by each approver, in approver index order.
+    #[cfg_attr(feature = "serde", serde(with = "with_serde::vec_account_id_address_with_count"))]
+    approver_signed_counts: Vec<(AccountIdAddress, u64)>,
+
+    /// The average time the executed transactions took from their proposal to meeting the
+    /// threshold, `None` if there is no such transaction.
+    #[cfg_attr(feature = "serde", serde(default))]
+    avg_time_to_threshold: Option<Duration>,
+
+    /// The 95th percentile of the time the executed transactions took from their proposal to
+    /// meeting the threshold, `None` if there is no such transaction.
+    #[cfg_attr(feature = "serde", serde(default))]
+    p95_time_to_threshold: Option<Duration>,
+}
+
+/// The number of multisig transactions proposed within a time bucket of a trend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Builder, Dissolve)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TxTrendBucket {
+    /// When the bucket starts, see [`TrendGranularity`].
+    start: DateTime<Utc>,
+
+    /// The number of transactions proposed within the bucket.
+    count: u64,
+}
+
+impl MultisigTxStatus {
+    /// Returns every status, in the order a transaction progresses through them.
+    pub fn all() -> &'static [Self] {
+        Self::VARIANTS
+    }
+}
+
+impl TxSort {
+    /// Returns the field to sort by.
+    pub fn field(&self) -> TxSortField {
+        self.field
+    }
+
+    /// Returns the direction to sort in.
+    pub fn direction(&self) -> SortDirection {
+        self.direction
+    }
+}
+
+impl<AUX> MultisigTx<AUX> {
+    /// Returns the canonical bech32 string of the multisig account address on the network of the
+    /// transaction.
+    pub fn address_bech32(&self) -> String {
+        Address::AccountId(self.address).to_bech32(self.network_id)
+    }
+
+    /// Returns whether the collected signatures meet the threshold of the multisig account.
+    pub fn is_threshold_met(&self) -> bool {
+        self.remaining_signatures() == 0
+    }
+
+    /// Returns the number of signatures still required to meet the threshold of the multisig
+    /// account, zero once it is met.
+    pub fn remaining_signatures(&self) -> u32 {
+        let signature_count = self.signature_count.map_or(0, NonZeroU32::get);
+        self.threshold.get().saturating_sub(signature_count)
+    }
 }
 
 impl From<Uuid> for MultisigTxId {
@@ -128,8 +323,91 @@ impl From<&MultisigTxId> for Uuid {
 }
 
 impl fmt::Display for MultisigTxId {
-    /// Formats the `MultisigTxId` as its underlying UUID string representation.
+    /// Formats the `MultisigTxId` as its lowercase hyphenated UUID representation.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        write!(f, "{}", self.0.hyphenated())
+    }
+}
+
+impl FromStr for MultisigTxId {
+    type Err = uuid::Error;
+
+    /// Parses a `MultisigTxId` from a UUID string.
+    ///
+    /// Besides the canonical hyphenated form, the other UUID forms (simple, braced and URN) are
+    /// accepted as well.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Uuid::try_parse(s).map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use uuid::Uuid;
+
+    use super::{MultisigTxId, MultisigTxStatus};
+
+    #[test]
+    fn multisig_tx_id_round_trips_through_its_string_form() {
+        // Arrange
+        let mut state = 0x9e37_79b9_7f4a_7c15_f39c_c060_5ced_c834_u128;
+
+        let ids = (0..1_000).map(|_| {
+            // xorshift over the full 128 bits, so every nibble of the uuid gets exercised
+            state ^= state << 35;
+            state ^= state >> 59;
+            state ^= state << 21;
+            MultisigTxId::from(Uuid::from_u128(state))
+        });
+
+        // Act & Assert
+        for id in ids.chain([Uuid::nil(), Uuid::max()].map(MultisigTxId::from)) {
+            assert_eq!(id, id.to_string().parse::<MultisigTxId>().unwrap());
+        }
+    }
+
+    #[test]
+    fn multisig_tx_id_string_form_is_lowercase_hyphenated_uuid() {
+        // Arrange
+        let id: MultisigTxId = "{550E8400-E29B-41D4-A716-446655440000}".parse().unwrap();
+
+        // Act
+        let s = id.to_string();
+
+        // Assert
+        assert_eq!(s, "550e8400-e29b-41d4-a716-446655440000");
+        assert!("550e8400-e29b-41d4-a716".parse::<MultisigTxId>().is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn multisig_tx_id_deserializes_from_owned_string() {
+        use serde::{
+            Deserialize,
+            de::value::{Error, StringDeserializer},
+        };
+
+        // Arrange
+        let id = MultisigTxId::from(Uuid::from_u128(0x550e_8400_e29b_41d4_a716_4466_5544_0000));
+        let deserializer = StringDeserializer::<Error>::new(id.to_string());
+
+        // Act
+        let deserialized = MultisigTxId::deserialize(deserializer);
+
+        // Assert
+        assert_eq!(deserialized, Ok(id));
+    }
+
+    #[test]
+    fn every_multisig_tx_status_round_trips_through_its_string_form() {
+        // Act & Assert
+        for &status in MultisigTxStatus::all() {
+            assert_eq!(status, status.to_string().parse::<MultisigTxStatus>().unwrap());
+        }
+
+        assert_eq!(MultisigTxStatus::ReadyToExecute.to_string(), "ready_to_execute");
+        assert!("ReadyToExecute".parse::<MultisigTxStatus>().is_err());
     }
 }
@@ -0,0 +1,407 @@
+//! Human-readable previews of multisig transactions.
+
+use alloc::vec::Vec;
+
+use dissolve_derive::Dissolve;
+use miden_client::{
+    account::AccountId,
+    asset::Asset,
+    note::{NoteId, WellKnownNote},
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
+use miden_objects::transaction::{OutputNote, TransactionSummary};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "serde")]
+use crate::with_serde;
+
+/// The effect of a multisig transaction, decoded from its [`TransactionSummary`].
+///
+/// This gives approvers a structured preview of what they are authorizing (e.g. "sends 100 MID
+/// to charlie") without having to deserialize the transaction summary themselves. Only fungible
+/// assets are reported.
+///
+/// The effect is decoded once when the transaction is proposed and persisted along with it, in
+/// its [`Serializable`] byte representation.
+#[derive(Debug, Clone, PartialEq, Eq, Dissolve)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TxEffect {
+    /// The fungible assets added to the multisig account vault.
+    assets_in: Vec<TxAssetAmount>,
+
+    /// The fungible assets removed from the multisig account vault.
+    assets_out: Vec<TxAssetAmount>,
+
+    /// The ids of the input notes consumed by the transaction whose headers are part of the
+    /// summary, i.e. unauthenticated input notes.
+    #[cfg_attr(feature = "serde", serde(with = "with_serde::vec_note_id"))]
+    input_note_ids: Vec<NoteId>,
+
+    /// The notes created by the transaction.
+    output_notes: Vec<TxOutputNote>,
+}
+
+/// An amount of a fungible asset issued by a faucet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Dissolve)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TxAssetAmount {
+    /// The id of the faucet issuing the asset.
+    #[cfg_attr(feature = "serde", serde(with = "with_serde::account_id"))]
+    faucet_id: AccountId,
+
+    /// The amount of the asset.
+    amount: u64,
+}
+
+/// A note created by a multisig transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Dissolve)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TxOutputNote {
+    /// The id of the note.
+    #[cfg_attr(feature = "serde", serde(with = "with_serde::note_id"))]
+    note_id: NoteId,
+
+    /// The account the note is targeted at, if the note is a public P2ID or P2IDE note.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default,
+            skip_serializing_if = "Option::is_none",
+            with = "with_serde::option_account_id"
+        )
+    )]
+    counterparty: Option<AccountId>,
+
+    /// The fungible assets carried by the note, empty if the note details are not public.
+    assets: Vec<TxAssetAmount>,
+}
+
+impl TxEffect {
+    /// Returns the fungible assets added to the multisig account vault.
+    pub fn assets_in(&self) -> &[TxAssetAmount] {
+        &self.assets_in
+    }
+
+    /// Returns the fungible assets removed from the multisig account vault.
+    pub fn assets_out(&self) -> &[TxAssetAmount] {
+        &self.assets_out
+    }
+
+    /// Returns the ids of the unauthenticated input notes consumed by the transaction.
+    pub fn input_note_ids(&self) -> &[NoteId] {
+        &self.input_note_ids
+    }
+
+    /// Returns the notes created by the transaction.
+    pub fn output_notes(&self) -> &[TxOutputNote] {
+        &self.output_notes
+    }
+
+    /// Returns the distinct accounts targeted by the output notes, in output note order.
+    pub fn counterparties(&self) -> Vec<AccountId> {
+        let mut counterparties = Vec::new();
+
+        for counterparty in self.output_notes.iter().filter_map(TxOutputNote::counterparty) {
+            if !counterparties.contains(&counterparty) {
+                counterparties.push(counterparty);
+            }
+        }
+
+        counterparties
+    }
+}
+
+impl TxAssetAmount {
+    /// Returns the id of the faucet issuing the asset.
+    pub fn faucet_id(&self) -> AccountId {
+        self.faucet_id
+    }
+
+    /// Returns the amount of the asset.
+    pub fn amount(&self) -> u64 {
+        self.amount
+    }
+}
+
+impl TxOutputNote {
+    /// Returns the id of the note.
+    pub fn note_id(&self) -> NoteId {
+        self.note_id
+    }
+
+    /// Returns the account the note is targeted at, if known.
+    pub fn counterparty(&self) -> Option<AccountId> {
+        self.counterparty
+    }
+
+    /// Returns the fungible assets carried by the note.
+    pub fn assets(&self) -> &[TxAssetAmount] {
+        &self.assets
+    }
+}
+
+impl From<&TransactionSummary> for TxEffect {
+    /// Decodes the effect of a transaction from its summary.
+    fn from(tx_summary: &TransactionSummary) -> Self {
+        let mut assets_in = Vec::new();
+        let mut assets_out = Vec::new();
+
+        for (&faucet_id, &amount) in tx_summary.account_delta().vault().fungible().iter() {
+            let asset_amount = TxAssetAmount { faucet_id, amount: amount.unsigned_abs() };
+
+            if amount > 0 {
+                assets_in.push(asset_amount);
+            } else if amount < 0 {
+                assets_out.push(asset_amount);
+            }
+        }
+
+        let input_note_ids = tx_summary
+            .input_notes()
+            .iter()
+            .filter_map(|input_note| input_note.header())
+            .map(|header| header.id())
+            .collect();
+
+        let output_notes = tx_summary.output_notes().iter().map(TxOutputNote::from).collect();
+
+        Self {
+            assets_in,
+            assets_out,
+            input_note_ids,
+            output_notes,
+        }
+    }
+}
+
+impl From<&OutputNote> for TxOutputNote {
+    /// Decodes an output note, recognizing the target account of public P2ID and P2IDE notes.
+    fn from(output_note: &OutputNote) -> Self {
+        let counterparty = output_note.recipient().and_then(|recipient| {
+            let script_root = recipient.script().root();
+
+            let is_p2id = script_root == WellKnownNote::P2ID.script_root()
+                || script_root == WellKnownNote::P2IDE.script_root();
+
+            // both P2ID and P2IDE notes start their inputs with the target account id suffix and
+            // prefix
+            match recipient.inputs().values() {
+                &[suffix, prefix, ..] if is_p2id => AccountId::try_from([prefix, suffix]).ok(),
+                _ => None,
+            }
+        });
+
+        let assets = output_note
+            .assets()
+            .into_iter()
+            .flat_map(|assets| assets.iter())
+            .filter_map(|asset| match asset {
+                Asset::Fungible(fungible_asset) => Some(TxAssetAmount {
+                    faucet_id: fungible_asset.faucet_id(),
+                    amount: fungible_asset.amount(),
+                }),
+                Asset::NonFungible(_) => None,
+            })
+            .collect();
+
+        Self {
+            note_id: output_note.id(),
+            counterparty,
+            assets,
+        }
+    }
+}
+
+impl Serializable for TxEffect {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.assets_in.write_into(target);
+        self.assets_out.write_into(target);
+        self.input_note_ids.write_into(target);
+        self.output_notes.write_into(target);
+    }
+}
+
+impl Deserializable for TxEffect {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            assets_in: Vec::read_from(source)?,
+            assets_out: Vec::read_from(source)?,
+            input_note_ids: Vec::read_from(source)?,
+            output_notes: Vec::read_from(source)?,
+        })
+    }
+}
+
+impl Serializable for TxAssetAmount {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.faucet_id.write_into(target);
+        target.write_u64(self.amount);
+    }
+}
+
+impl Deserializable for TxAssetAmount {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            faucet_id: AccountId::read_from(source)?,
+            amount: source.read_u64()?,
+        })
+    }
+}
+
+impl Serializable for TxOutputNote {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.note_id.write_into(target);
+        self.counterparty.write_into(target);
+        self.assets.write_into(target);
+    }
+}
+
+impl Deserializable for TxOutputNote {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            note_id: NoteId::read_from(source)?,
+            counterparty: Option::read_from(source)?,
+            assets: Vec::read_from(source)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use miden_client::{
+        Felt, ONE, Word,
+        account::AccountId,
+        asset::FungibleAsset,
+        crypto::RpoRandomCoin,
+        note::{NoteType, create_p2id_note},
+        utils::{Deserializable, Serializable},
+    };
+    use miden_objects::{
+        account::{AccountDelta, AccountStorageDelta, AccountVaultDelta},
+        testing::account_id::{
+            ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET, ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_1,
+            ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
+            ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE, ACCOUNT_ID_SENDER,
+        },
+        transaction::{InputNotes, OutputNote, OutputNotes, TransactionSummary},
+    };
+
+    use super::{TxAssetAmount, TxEffect};
+
+    #[test]
+    fn effect_splits_vault_delta_and_recognizes_counterparties_of_public_p2id_notes() {
+        // Arrange
+        let multisig =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE).unwrap();
+        let alice = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let bob = AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+
+        let faucet_in = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET).unwrap();
+        let faucet_out = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_1).unwrap();
+
+        let asset_in = FungibleAsset::new(faucet_in, 70).unwrap();
+        let asset_out = FungibleAsset::new(faucet_out, 30).unwrap();
+
+        let mut vault_delta = AccountVaultDelta::default();
+        vault_delta.add_asset(asset_in.into()).unwrap();
+        vault_delta.remove_asset(asset_out.into()).unwrap();
+
+        let mut rng = RpoRandomCoin::new(Word::default());
+
+        let mut p2id_note = |target, note_type| {
+            create_p2id_note(
+                multisig,
+                target,
+                vec![FungibleAsset::new(faucet_out, 10).unwrap().into()],
+                note_type,
+                Felt::new(0),
+                &mut rng,
+            )
+            .unwrap()
+        };
+
+        let to_alice = p2id_note(alice, NoteType::Public);
+        let again_to_alice = p2id_note(alice, NoteType::Public);
+        let to_bob = p2id_note(bob, NoteType::Private);
+
+        let tx_summary = TransactionSummary::new(
+            AccountDelta::new(multisig, AccountStorageDelta::default(), vault_delta, ONE).unwrap(),
+            InputNotes::new(vec![]).unwrap(),
+            OutputNotes::new(vec![
+                OutputNote::Full(to_alice.clone()),
+                OutputNote::Full(again_to_alice.clone()),
+                // only the header of a private note is known
+                OutputNote::Header(*to_bob.header()),
+            ])
+            .unwrap(),
+            Word::default(),
+        );
+
+        // Act
+        let effect = TxEffect::from(&tx_summary);
+
+        // Assert
+        assert_eq!(effect.assets_in(), [TxAssetAmount { faucet_id: faucet_in, amount: 70 }]);
+        assert_eq!(effect.assets_out(), [TxAssetAmount { faucet_id: faucet_out, amount: 30 }]);
+        assert!(effect.input_note_ids().is_empty());
+
+        let [alice_note, again_alice_note, bob_note] = effect.output_notes() else {
+            panic!("every output note must be decoded");
+        };
+
+        assert_eq!(alice_note.note_id(), to_alice.id());
+        assert_eq!(alice_note.counterparty(), Some(alice));
+        assert_eq!(alice_note.assets(), [TxAssetAmount { faucet_id: faucet_out, amount: 10 }]);
+        assert_eq!(again_alice_note.note_id(), again_to_alice.id());
+
+        assert_eq!(bob_note.note_id(), to_bob.id());
+        assert_eq!(bob_note.counterparty(), None);
+        assert!(bob_note.assets().is_empty());
+
+        assert_eq!(effect.counterparties(), [alice]);
+    }
+
+    #[test]
+    fn effect_round_trips_through_its_bytes() {
+        // Arrange
+        let multisig =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE).unwrap();
+        let alice = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let faucet = AccountId::try_from(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET).unwrap();
+
+        let note = create_p2id_note(
+            multisig,
+            alice,
+            vec![FungibleAsset::new(faucet, 10).unwrap().into()],
+            NoteType::Public,
+            Felt::new(0),
+            &mut RpoRandomCoin::new(Word::default()),
+        )
+        .unwrap();
+
+        let mut vault_delta = AccountVaultDelta::default();
+        vault_delta
+            .remove_asset(FungibleAsset::new(faucet, 10).unwrap().into())
+            .unwrap();
+
+        let tx_summary = TransactionSummary::new(
+            AccountDelta::new(multisig, AccountStorageDelta::default(), vault_delta, ONE).unwrap(),
+            InputNotes::new(vec![]).unwrap(),
+            OutputNotes::new(vec![OutputNote::Full(note)]).unwrap(),
+            Word::default(),
+        );
+
+        let effect = TxEffect::from(&tx_summary);
+
+        // Act
+        let bz = effect.to_bytes();
+
+        // Assert
+        assert_eq!(TxEffect::read_from_bytes(&bz).unwrap(), effect);
+        assert!(TxEffect::read_from_bytes(&bz[..bz.len() - 1]).is_err());
+    }
+}
@@ -30,6 +30,30 @@ pub mod account_id_address {
     }
 }
 
+pub mod account_id {
+    use miden_client::{
+        account::AccountId,
+        utils::{Deserializable, Serializable},
+    };
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    pub fn serialize<S>(account_id: &AccountId, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(&account_id.to_bytes())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<AccountId, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <&[u8]>::deserialize(deserializer)
+            .map(Deserializable::read_from_bytes)?
+            .map_err(D::Error::custom)
+    }
+}
+
 pub mod account_storage_mode {
     use core::str::FromStr;
 
@@ -62,6 +86,50 @@ pub mod account_storage_mode {
     }
 }
 
+pub mod byte_array {
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    pub fn serialize<S, const N: usize>(bz: &[u8; N], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(bz)
+    }
+
+    pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[u8; N], D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <&[u8]>::deserialize(deserializer)
+            .map(TryFrom::try_from)?
+            .map_err(D::Error::custom)
+    }
+}
+
+pub mod hyphenated_uuid {
+    use alloc::string::String;
+
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+    use uuid::Uuid;
+
+    pub fn serialize<S>(uuid: &Uuid, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(uuid.hyphenated().encode_lower(&mut Uuid::encode_buffer()))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Uuid, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // owned rather than borrowed, as not every deserializer can lend its input
+        String::deserialize(deserializer)
+            .map(|uuid| Uuid::try_parse(&uuid))?
+            .map_err(D::Error::custom)
+    }
+}
+
 pub mod network_id {
     use core::str::FromStr;
 
@@ -85,6 +153,119 @@ pub mod network_id {
     }
 }
 
+pub mod note_id {
+    use miden_client::{Word, note::NoteId};
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    pub fn serialize<S>(&note_id: &NoteId, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(&Word::from(note_id).as_bytes())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<NoteId, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <[u8; Word::SERIALIZED_SIZE]>::deserialize(deserializer)
+            .map(Word::try_from)?
+            .map(NoteId::from)
+            .map_err(D::Error::custom)
+    }
+}
+
+pub mod option_account_id {
+    use miden_client::account::AccountId;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    struct Wrapper(#[serde(with = "super::account_id")] AccountId);
+
+    pub fn serialize<S>(&account_id: &Option<AccountId>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        account_id.map(Wrapper).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<AccountId>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<Wrapper>::deserialize(deserializer)
+            .map(|wrapper| wrapper.map(|Wrapper(account_id)| account_id))
+    }
+}
+
+pub mod option_account_id_address {
+    use miden_client::account::AccountIdAddress;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    struct Wrapper(#[serde(with = "super::account_id_address")] AccountIdAddress);
+
+    pub fn serialize<S>(
+        &account_id_address: &Option<AccountIdAddress>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        account_id_address.map(Wrapper).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<AccountIdAddress>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<Wrapper>::deserialize(deserializer)
+            .map(|wrapper| wrapper.map(|Wrapper(account_id_address)| account_id_address))
+    }
+}
+
+pub mod option_block_number {
+    use miden_objects::block::BlockNumber;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S>(&block_num: &Option<BlockNumber>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        block_num.map(|block_num| block_num.as_u32()).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<BlockNumber>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<u32>::deserialize(deserializer).map(|block_num| block_num.map(BlockNumber::from))
+    }
+}
+
+pub mod option_transaction_id {
+    use miden_objects::transaction::TransactionId;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    struct Wrapper(#[serde(with = "super::transaction_id")] TransactionId);
+
+    pub fn serialize<S>(&tx_id: &Option<TransactionId>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        tx_id.map(Wrapper).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<TransactionId>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<Wrapper>::deserialize(deserializer)
+            .map(|wrapper| wrapper.map(|Wrapper(tx_id)| tx_id))
+    }
+}
+
 pub mod pub_key_commit {
     use miden_client::Word;
     use miden_objects::crypto::dsa::rpo_falcon512::PublicKey;
@@ -108,6 +289,28 @@ pub mod pub_key_commit {
     }
 }
 
+pub mod transaction_id {
+    use miden_client::utils::{Deserializable, Serializable};
+    use miden_objects::transaction::TransactionId;
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    pub fn serialize<S>(tx_id: &TransactionId, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bytes(&tx_id.to_bytes())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<TransactionId, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <&[u8]>::deserialize(deserializer)
+            .map(Deserializable::read_from_bytes)?
+            .map_err(D::Error::custom)
+    }
+}
+
 pub mod transaction_request {
     use miden_client::{
         transaction::TransactionRequest,
@@ -216,63 +419,129 @@ pub mod vec_account_id_address {
     }
 }
 
-pub mod vec_pub_key_commits {
+pub mod vec_account_id_address_with_count {
     use alloc::{
         fmt::{self, Formatter},
         vec::Vec,
     };
 
-    use miden_client::Word;
-    use miden_objects::crypto::dsa::rpo_falcon512::PublicKey;
+    use miden_client::account::AccountIdAddress;
+    use serde::{
+        Deserializer, Serializer,
+        de::{self, SeqAccess, Visitor},
+        ser::SerializeSeq,
+    };
+
+    pub fn serialize<S>(
+        account_id_addresses_with_count: &Vec<(AccountIdAddress, u64)>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(account_id_addresses_with_count.len().into())?;
+
+        for (account_id_address, count) in account_id_addresses_with_count {
+            seq.serialize_element(&(
+                super::serialize_account_id_address(account_id_address),
+                count,
+            ))?;
+        }
+
+        seq.end()
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<(AccountIdAddress, u64)>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct AccountIdAddressWithCountVecVisitor;
+
+        impl<'de> Visitor<'de> for AccountIdAddressWithCountVecVisitor {
+            type Value = Vec<(AccountIdAddress, u64)>;
+
+            fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+                formatter.write_str("a sequence of account ids with counts")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut account_id_addresses_with_count =
+                    Vec::with_capacity(seq.size_hint().unwrap_or(0));
+
+                while let Some((bz, count)) =
+                    seq.next_element::<([u8; AccountIdAddress::SERIALIZED_SIZE], u64)>()?
+                {
+                    let account_id_address = bz.try_into().map_err(de::Error::custom)?;
+                    account_id_addresses_with_count.push((account_id_address, count));
+                }
+
+                Ok(account_id_addresses_with_count)
+            }
+        }
+
+        deserializer.deserialize_seq(AccountIdAddressWithCountVecVisitor)
+    }
+}
+
+pub mod vec_note_id {
+    use alloc::{
+        fmt::{self, Formatter},
+        vec::Vec,
+    };
+
+    use miden_client::{Word, note::NoteId};
     use serde::{
         Deserializer, Serializer,
         de::{self, SeqAccess, Visitor},
         ser::SerializeSeq,
     };
 
-    pub fn serialize<S>(pub_key_commits: &Vec<PublicKey>, serializer: S) -> Result<S::Ok, S::Error>
+    pub fn serialize<S>(note_ids: &Vec<NoteId>, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let mut seq = serializer.serialize_seq(pub_key_commits.len().into())?;
+        let mut seq = serializer.serialize_seq(note_ids.len().into())?;
 
-        for &pub_key_commit in pub_key_commits {
-            seq.serialize_element(&Word::from(pub_key_commit).as_bytes())?;
+        for &note_id in note_ids {
+            seq.serialize_element(&Word::from(note_id).as_bytes())?;
         }
 
         seq.end()
     }
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<PublicKey>, D::Error>
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<NoteId>, D::Error>
     where
         D: Deserializer<'de>,
     {
-        struct PubKeyCommitVecVisitor;
+        struct NoteIdVecVisitor;
 
-        impl<'de> Visitor<'de> for PubKeyCommitVecVisitor {
-            type Value = Vec<PublicKey>;
+        impl<'de> Visitor<'de> for NoteIdVecVisitor {
+            type Value = Vec<NoteId>;
 
             fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
-                formatter.write_str("a sequence of public key commitments")
+                formatter.write_str("a sequence of note ids")
             }
 
             fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
             where
                 A: SeqAccess<'de>,
             {
-                let mut pub_key_commits = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                let mut note_ids = Vec::with_capacity(seq.size_hint().unwrap_or(0));
 
                 while let Some(bz) = seq.next_element::<[u8; Word::SERIALIZED_SIZE]>()? {
-                    let pub_key_commit =
-                        Word::try_from(bz).map(PublicKey::new).map_err(de::Error::custom)?;
-                    pub_key_commits.push(pub_key_commit);
+                    let note_id =
+                        Word::try_from(bz).map(NoteId::from).map_err(de::Error::custom)?;
+                    note_ids.push(note_id);
                 }
 
-                Ok(pub_key_commits)
+                Ok(note_ids)
             }
         }
 
-        deserializer.deserialize_seq(PubKeyCommitVecVisitor)
+        deserializer.deserialize_seq(NoteIdVecVisitor)
     }
 }
 
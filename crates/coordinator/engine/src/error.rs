@@ -1,11 +1,19 @@
+use core::{
+    num::{NonZeroU32, NonZeroUsize},
+    time::Duration,
+};
+
 use std::borrow::Cow;
 
+use miden_client::{account::AccountId, transaction::TransactionRequestError};
+use miden_multisig_coordinator_domain::tx::{MultisigTxId, MultisigTxStatus};
 use miden_multisig_coordinator_store::MultisigStoreError;
-use tokio::sync::oneshot;
+use miden_objects::block::BlockNumber;
+use tokio::{sync::oneshot, time::error::Elapsed};
 
 use crate::multisig_client_runtime::{
     MultisigClientRuntimeError,
-    msg::{ProcessMultisigTxError, ProposeMultisigTxError},
+    msg::{ImportMultisigAccountError, ProcessMultisigTxError, ProposeMultisigTxError},
 };
 
 /// The main error type for multisig engine operations.
@@ -13,6 +21,234 @@ use crate::multisig_client_runtime::{
 #[error("multisig engine error: {0}")]
 pub struct MultisigEngineError(#[from] MultisigEngineErrorKind);
 
+impl MultisigEngineError {
+    /// Returns the bech32 address of the approver and the id of the transaction, if the error is
+    /// caused by an approver not authorized to sign the transaction.
+    pub fn approver_not_authorized(&self) -> Option<(&str, &MultisigTxId)> {
+        match &self.0 {
+            MultisigEngineErrorKind::ApproverNotAuthorized { approver, tx_id } => {
+                Some((approver, tx_id))
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the id of the faucet, the required and the available amounts of its asset, if the
+    /// error is caused by the multisig account holding less of the asset than a transaction pays
+    /// out.
+    pub fn insufficient_balance(&self) -> Option<(AccountId, u64, u64)> {
+        match self.0 {
+            MultisigEngineErrorKind::InsufficientBalance { faucet_id, required, available } => {
+                Some((faucet_id, required, available))
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the id of the account, if the error is caused by the multisig account not being
+    /// managed by the engine.
+    pub fn multisig_account_not_found(&self) -> Option<AccountId> {
+        match self.0 {
+            MultisigEngineErrorKind::MultisigAccountNotFound(account_id) => Some(account_id),
+            _ => None,
+        }
+    }
+
+    /// Returns the id of the transaction, if the error is caused by the transaction not existing.
+    pub fn multisig_tx_not_found(&self) -> Option<&MultisigTxId> {
+        match &self.0 {
+            MultisigEngineErrorKind::MultisigTxNotFound(tx_id) => Some(tx_id),
+            _ => None,
+        }
+    }
+
+    /// Returns the status of the transaction, if the error is caused by the transaction not having
+    /// the status the operation requires, e.g. executing a transaction which is not ready to
+    /// execute.
+    pub fn multisig_tx_not_pending(&self) -> Option<MultisigTxStatus> {
+        match self.0 {
+            MultisigEngineErrorKind::MultisigTxNotPending { status, .. } => Some(status),
+            _ => None,
+        }
+    }
+
+    /// Returns the id of the account, if the error is caused by importing an account that is
+    /// already persisted.
+    pub fn multisig_account_already_exists(&self) -> Option<AccountId> {
+        match self.0 {
+            MultisigEngineErrorKind::MultisigAccountAlreadyExists(account_id) => Some(account_id),
+            _ => None,
+        }
+    }
+
+    /// Returns the id of the account, if the error is caused by importing an account that is not
+    /// authenticated by the multisig auth component.
+    pub fn not_multisig_account(&self) -> Option<AccountId> {
+        match self.0 {
+            MultisigEngineErrorKind::NotMultisigAccount(account_id) => Some(account_id),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of on-chain approver public keys and the number of provided approvers,
+    /// if the error is caused by importing an account with a mismatching number of approvers.
+    pub fn approver_count_mismatch(&self) -> Option<(usize, usize)> {
+        match self.0 {
+            MultisigEngineErrorKind::ApproverCountMismatch { on_chain, provided } => {
+                Some((on_chain, provided))
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns the index of the approver key, if the error is caused by importing an account with
+    /// an approver key whose commitment differs from the on-chain one.
+    pub fn approver_key_mismatch(&self) -> Option<usize> {
+        match self.0 {
+            MultisigEngineErrorKind::ApproverKeyMismatch { index } => Some(index),
+            _ => None,
+        }
+    }
+
+    /// Returns the threshold and the total weight of the remaining approvers, if the error is
+    /// caused by removing an approver whose removal would leave the account unable to meet its
+    /// threshold.
+    pub fn unreachable_threshold(&self) -> Option<(u64, u64)> {
+        match self.0 {
+            MultisigEngineErrorKind::MultisigStore(MultisigStoreError::UnreachableThreshold {
+                threshold,
+                remaining_weight,
+            }) => Some((threshold, remaining_weight)),
+            _ => None,
+        }
+    }
+
+    /// Returns the requested and the current threshold, if the error is caused by removing an
+    /// approver along with raising the threshold of the account.
+    pub fn threshold_raised(&self) -> Option<(u64, u64)> {
+        match self.0 {
+            MultisigEngineErrorKind::MultisigStore(MultisigStoreError::ThresholdRaised {
+                threshold,
+                current,
+            }) => Some((threshold, current)),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the error is caused by removing an approver from a multisig account with
+    /// transactions not executed yet.
+    pub fn has_pending_txs(&self) -> bool {
+        matches!(self.0, MultisigEngineErrorKind::MultisigStore(MultisigStoreError::PendingTxs))
+    }
+
+    /// Returns `true` if the error is caused by changing the summary commitment of a transaction
+    /// that holds signatures over it.
+    pub fn is_commitment_altered(&self) -> bool {
+        matches!(
+            self.0,
+            MultisigEngineErrorKind::MultisigStore(MultisigStoreError::CommitmentAltered)
+        )
+    }
+
+    /// Returns `true` if the error is caused by adding a signature that doesn't verify against the
+    /// key of the approver adding it.
+    pub fn is_invalid_signature(&self) -> bool {
+        matches!(
+            self.0,
+            MultisigEngineErrorKind::MultisigStore(MultisigStoreError::InvalidSignature)
+        )
+    }
+
+    /// Returns the maximum number of pending transactions of a multisig account, if the error is
+    /// caused by proposing or re-proposing a transaction for an account already having as many.
+    pub fn too_many_pending_proposals(&self) -> Option<NonZeroU32> {
+        match self.0 {
+            MultisigEngineErrorKind::MultisigStore(MultisigStoreError::TooManyPendingTxs {
+                max,
+            }) => Some(max),
+            _ => None,
+        }
+    }
+
+    /// Returns the reason, if the error is caused by a multisig account configuration that cannot
+    /// be met, e.g. a threshold exceeding the total weight of the approvers.
+    pub fn invalid_multisig_config(&self) -> Option<&str> {
+        match &self.0 {
+            MultisigEngineErrorKind::InvalidMultisigConfig(reason) => Some(reason),
+            _ => None,
+        }
+    }
+
+    /// Returns the reason, if the error is caused by a note selection that the consumable notes of
+    /// the multisig account cannot satisfy.
+    pub fn invalid_note_selection(&self) -> Option<&str> {
+        match &self.0 {
+            MultisigEngineErrorKind::InvalidNoteSelection(reason) => Some(reason),
+            _ => None,
+        }
+    }
+
+    /// Returns the reason, if the error is caused by a payment whose transaction request cannot be
+    /// built, e.g. because its note cannot carry the asset.
+    pub fn invalid_payment(&self) -> Option<String> {
+        match &self.0 {
+            MultisigEngineErrorKind::InvalidPayment(err) => Some(err.to_string()),
+            _ => None,
+        }
+    }
+
+    /// Returns the block the multisig client is synced to, if the error is caused by proposing to
+    /// consume notes listed while the client could not sync with the node.
+    pub fn stale_consumable_notes(&self) -> Option<BlockNumber> {
+        match self.0 {
+            MultisigEngineErrorKind::StaleConsumableNotes(block_num) => Some(block_num),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of unknown input notes and the note import limit, if the error is caused
+    /// by proposing a transaction consuming more unknown input notes than the runtime may import.
+    pub fn too_many_unknown_input_notes(&self) -> Option<(usize, NonZeroUsize)> {
+        match &self.0 {
+            MultisigEngineErrorKind::ProposeMultisigTx(err) => err.too_many_unknown_input_notes(),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the error is caused by a supplied transaction summary not matching the
+    /// proposed transaction, i.e. applying to another account or mismatching the computed one.
+    pub fn is_tx_summary_mismatch(&self) -> bool {
+        matches!(self.0, MultisigEngineErrorKind::TxSummaryMismatch(_))
+    }
+
+    /// Returns `true` if the error is caused by an operation timing out, i.e. a database statement
+    /// exceeding the statement timeout or the multisig client runtime not answering in time.
+    pub fn is_timeout(&self) -> bool {
+        matches!(
+            self.0,
+            MultisigEngineErrorKind::Timeout(_)
+                | MultisigEngineErrorKind::MultisigStore(MultisigStoreError::Timeout)
+        )
+    }
+
+    /// Returns `true` if the error is caused by a multisig client runtime queue staying full,
+    /// i.e. the runtime falling behind the requested operations.
+    pub fn is_overloaded(&self) -> bool {
+        matches!(self.0, MultisigEngineErrorKind::Overloaded(_))
+    }
+
+    /// Returns `true` if the error is caused by the transaction itself not being executable, e.g.
+    /// the multisig account holding too little of an asset or an input note being invalid, rather
+    /// than by the engine failing to process it.
+    pub(crate) fn is_tx_not_executable(&self) -> bool {
+        match &self.0 {
+            MultisigEngineErrorKind::InsufficientBalance { .. } => true,
+            MultisigEngineErrorKind::ProposeMultisigTx(err) => err.is_tx_not_executable(),
+            _ => false,
+        }
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 pub(crate) enum MultisigEngineErrorKind {
     #[error("multisig client runtime error: {0}")]
@@ -24,12 +260,81 @@ pub(crate) enum MultisigEngineErrorKind {
     #[error("mpsc sender error: {0}")]
     MpscSender(Cow<'static, str>),
 
-    #[error("oneshot receive error: {0}")]
-    OneshotReceive(#[from] oneshot::error::RecvError),
+    #[error("overloaded error: the multisig client runtime queue stayed full for {0:?}")]
+    Overloaded(Duration),
+
+    #[error("oneshot receive error: no response to {operation}: {source}")]
+    OneshotReceive {
+        operation: &'static str,
+        source: oneshot::error::RecvError,
+    },
+
+    #[error("timeout error: {0}")]
+    Timeout(#[from] Elapsed),
 
     #[error("not found error: {0}")]
     NotFound(Cow<'static, str>),
 
+    #[error("multisig account not found error: `{}`", .0.to_hex())]
+    MultisigAccountNotFound(AccountId),
+
+    #[error("multisig tx not found error: `{0}`")]
+    MultisigTxNotFound(MultisigTxId),
+
+    #[error("multisig tx not pending error: tx `{tx_id}` is `{status}`")]
+    MultisigTxNotPending {
+        tx_id: MultisigTxId,
+        status: MultisigTxStatus,
+    },
+
+    #[error("approver not authorized error: `{approver}` is not an approver of tx `{tx_id}`")]
+    ApproverNotAuthorized { approver: String, tx_id: MultisigTxId },
+
+    #[error(
+        "insufficient balance error: `{required}` of faucet `{}` required, `{available}` available",
+        faucet_id.to_hex()
+    )]
+    InsufficientBalance {
+        faucet_id: AccountId,
+        required: u64,
+        available: u64,
+    },
+
+    #[error("multisig account already exists error: `{}`", .0.to_hex())]
+    MultisigAccountAlreadyExists(AccountId),
+
+    #[error(
+        "not multisig account error: `{}` is not authenticated by the multisig component",
+        .0.to_hex()
+    )]
+    NotMultisigAccount(AccountId),
+
+    #[error(
+        "approver count mismatch error: `{provided}` approvers provided, `{on_chain}` on chain"
+    )]
+    ApproverCountMismatch { on_chain: usize, provided: usize },
+
+    #[error("approver key mismatch error: key `{index}` mismatches its on-chain commitment")]
+    ApproverKeyMismatch { index: usize },
+
+    #[error("tx summary mismatch error: {0}")]
+    TxSummaryMismatch(&'static str),
+
+    #[error("invalid multisig config error: {0}")]
+    InvalidMultisigConfig(&'static str),
+
+    #[error("invalid note selection error: {0}")]
+    InvalidNoteSelection(Cow<'static, str>),
+
+    #[error("invalid payment error: {0}")]
+    InvalidPayment(TransactionRequestError),
+
+    #[error("stale consumable notes error: the notes are as of block `{0}`, behind the chain tip")]
+    StaleConsumableNotes(BlockNumber),
+
+    #[error("import multisig account error: {0}")]
+    ImportMultisigAccount(#[from] ImportMultisigAccountError),
+
     #[error("propose multisig tx error: {0}")]
     ProposeMultisigTx(#[from] ProposeMultisigTxError),
 
@@ -48,6 +353,12 @@ impl MultisigEngineErrorKind {
         Self::MpscSender(err.into())
     }
 
+    pub fn oneshot_receive(
+        operation: &'static str,
+    ) -> impl FnOnce(oneshot::error::RecvError) -> Self {
+        move |source| Self::OneshotReceive { operation, source }
+    }
+
     pub fn not_found<E>(err: E) -> Self
     where
         Cow<'static, str>: From<E>,
@@ -0,0 +1,66 @@
+//! Domain events emitted by the multisig engine.
+//!
+//! The engine reports the changes it persisted to an [`EventSink`], so that integrations (e.g.
+//! publishing to Kafka or NATS, or logging) can be bolted on without the engine knowing about
+//! them. Events are emitted once the change they report is persisted, in the order the engine
+//! performed the changes.
+
+use miden_client::{account::AccountIdAddress, transaction::TransactionId};
+use miden_multisig_coordinator_domain::tx::MultisigTxId;
+
+/// A change persisted by the [`MultisigEngine`](crate::MultisigEngine).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CoordinatorEvent {
+    /// A multisig account was created on chain and persisted.
+    AccountCreated { address: AccountIdAddress },
+
+    /// A multisig account created elsewhere was imported from the node and persisted.
+    AccountImported { address: AccountIdAddress },
+
+    /// An approver was removed from a multisig account, possibly along with a threshold change.
+    ApproverRemoved {
+        address: AccountIdAddress,
+        approver: AccountIdAddress,
+    },
+
+    /// A multisig transaction was proposed, or a failed one re-proposed.
+    TxProposed {
+        tx_id: MultisigTxId,
+        address: AccountIdAddress,
+    },
+
+    /// The signature of an approver was added to a multisig transaction.
+    SignatureAdded {
+        tx_id: MultisigTxId,
+        approver: AccountIdAddress,
+    },
+
+    /// A multisig transaction was executed and submitted to the node.
+    TxExecuted {
+        tx_id: MultisigTxId,
+        chain_tx_id: TransactionId,
+    },
+
+    /// A multisig transaction failed to be executed or submitted.
+    TxFailed { tx_id: MultisigTxId, reason: String },
+}
+
+/// A destination of the [`CoordinatorEvent`]s emitted by the engine.
+///
+/// The engine awaits [`emit`](EventSink::emit) before completing the operation that caused the
+/// event, so implementations should hand slow work (e.g. network publishing) off to a background
+/// task. Emitting cannot fail, implementations handle their own errors, e.g. by logging them.
+#[async_trait::async_trait]
+pub trait EventSink: Send + Sync {
+    /// Emits an event.
+    async fn emit(&self, event: CoordinatorEvent);
+}
+
+/// An [`EventSink`] discarding every event, used unless another sink is configured.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopEventSink;
+
+#[async_trait::async_trait]
+impl EventSink for NoopEventSink {
+    async fn emit(&self, _event: CoordinatorEvent) {}
+}
@@ -8,7 +8,7 @@
 //! The [`MultisigEngine`] orchestrates between two key components:
 //!
 //! 1. `MultisigClientRuntime`: Manages the blockchain client
-//!    in a dedicated thread (see [`MultisigClientRuntimeConfig`])
+//!    in one or more dedicated worker threads (see [`MultisigClientRuntimeConfig`])
 //! 2. [`MultisigStore`]: Provides persistent storage for multisig account and transaction data
 //!
 //! ```text
@@ -23,9 +23,9 @@
 //!            ▼                               ▼
 //! ┌──────────────────────┐       ┌─────────────────────┐
 //! │ MultisigStore        │       │ MultisigClient      │
-//! │ (PostgreSQL)         │       │ Runtime Thread      │
+//! │ (PostgreSQL)         │       │ Runtime Threads     │
 //! │                      │       │                     │
-//! │  - Accounts          │       │ - dedicated thread  │
+//! │  - Accounts          │       │ - dedicated threads │
 //! │  - Approvers         │       │ - LocalSet          │              
 //! │  - Transactions      │       │ - !Send + !Sync     │
 //! │  - Signatures        │       │ - mpsc channels     │
@@ -60,20 +60,36 @@
 //! dedicated thread using tokio's [`LocalSet`], which allows running `!Send` futures on a single
 //! thread. Communication happens via:
 //!
-//! - **Command channel**: `mpsc::UnboundedSender<MultisigClientRuntimeMsg>` (to send requests)
+//! - **Command channel**: `mpsc::Sender<MultisigClientRuntimeMsg>` (to send requests), bounded
+//!   so that a burst of operations cannot queue unboundedly while the runtime falls behind
 //! - **Response channels**: `oneshot::Sender<T>` (to receive responses)
 //!
 //! ### Result: `MultisigEngine` becomes `Sync`
 //!
 //! The [`MultisigEngine<Started>`] type contains:
-//! - `mpsc::UnboundedSender` which is `Send + Sync`
-//! - `MultisigStore` which is `Send + Sync` (uses [diesel-async](https://docs.rs/diesel-async))
-//! - `JoinHandle` which is `Send + Sync`
+//! - `mpsc::Sender`s which are `Send + Sync`
+//! - a [`MultisigStoreBackend`] which is `Send + Sync`, e.g. `MultisigStore` (uses
+//!   [diesel-async](https://docs.rs/diesel-async))
+//! - `JoinHandle`s which are `Send + Sync`
 //!
 //! Therefore `MultisigEngine<Started>` is `Send + Sync`, and `Arc<MultisigEngine<Started>>`
 //! is both `Send + Sync` and `Clone`, making it usable in web frameworks like
 //! [axum](https://docs.rs/axum).
 //!
+//! ## Runtime Workers
+//!
+//! A single runtime thread handles msgs one at a time, which serializes the node interaction of
+//! all multisig accounts. The runtime can therefore be spread over several worker threads (see
+//! [`MultisigClientRuntimeConfig`]), each tracking a shard of the multisig accounts, chosen by
+//! account id. The engine holds one channel per worker and routes every msg to the worker owning
+//! the account it operates on, which gives the following ordering guarantee:
+//!
+//! - Operations on a given account reach the node in the order they were requested
+//! - Operations on accounts owned by different workers run in parallel, in no particular order
+//!
+//! Operations that don't target a single account, e.g. listing the consumable notes of all
+//! accounts or pinging the runtime, are sent to every worker.
+//!
 //! ## State Machine
 //!
 //! The [`MultisigEngine`] uses a type-state pattern for lifecycle management:
@@ -87,15 +103,38 @@
 //! MultisigEngine<Started>
 //!    │
 //!    │ - create_multisig_account()
+//!    │ - import_multisig_account()
 //!    │ - propose_multisig_tx()
+//!    │ - propose_consume_notes()
+//!    │ - propose_payment()
+//!    │ - dry_run_multisig_tx()
+//!    │ - validate_multisig_tx()
+//!    │ - repropose_multisig_tx()
 //!    │ - add_signature()
+//!    │ - add_signatures()
+//!    │ - execute_ready_tx()
+//!    │ - reconcile_multisig_txs()
+//!    │ - has_approver_signed()
 //!    │ - get_multisig_account()
+//!    │ - get_multisig_account_summary()
+//!    │ - get_multisig_approver_order()
+//!    │ - list_multisig_accounts()
+//!    │ - archive_multisig_account()
+//!    │ - remove_multisig_approver()
+//!    │ - reconcile_multisig_account()
+//!    │ - verify_account_consistency()
+//!    │ - get_multisig_tx_by_summary_commit()
 //!    │ - list_multisig_tx()
+//!    │ - list_approver_pending_txs()
+//!    │ - list_audit_events()
 //!    │ - get_consumable_notes()
+//!    │ - check_readiness()
+//!    │ - ping_runtime()
+//!    │ - runtime_worker_of()
 //!    │
 //!    │
 //!    │
-//!    │ .stop_multisig_client_runtime()
+//!    │ .stop_multisig_client_runtime()  (drains queued msgs up to a timeout)
 //!    │
 //!    ▼
 //! MultisigEngine<Stopped>
@@ -108,81 +147,189 @@
 //! - **Account Management**:
 //!   - [`create_multisig_account`](MultisigEngine::create_multisig_account) - Create a new
 //!     multisig account
+//!   - [`import_multisig_account`](MultisigEngine::import_multisig_account) - Import a multisig
+//!     account created elsewhere, e.g. with the CLI
 //!   - [`get_multisig_account`](MultisigEngine::get_multisig_account) - Retrieve account details
+//!   - [`get_multisig_account_full`](MultisigEngine::get_multisig_account_full) - Retrieve account
+//!     details along with its approvers and public key commitments
+//!   - [`get_multisig_account_summary`](MultisigEngine::get_multisig_account_summary) - Retrieve
+//!     the account threshold and approver count
+//!   - [`get_multisig_approver_order`](MultisigEngine::get_multisig_approver_order) - Retrieve
+//!     the approvers along with the index of their signature slot
+//!   - [`list_multisig_accounts`](MultisigEngine::list_multisig_accounts) - List the accounts
+//!     along with their approver and pending transaction counts
+//!   - [`reconcile_multisig_account`](MultisigEngine::reconcile_multisig_account) - Diff the stored
+//!     account against its on-chain threshold and approver public keys
+//!   - [`verify_account_consistency`](MultisigEngine::verify_account_consistency) - Report the
+//!     drift of the stored account nonce, threshold and approver public keys from the chain
+//!   - [`archive_multisig_account`](MultisigEngine::archive_multisig_account) - Archive or
+//!     unarchive an account, hiding it from or listing it again in the default account listing
+//!   - [`remove_multisig_approver`](MultisigEngine::remove_multisig_approver) - Remove an
+//!     approver, optionally lowering the threshold, as long as the threshold stays reachable
 //!
 //! - **Transaction Management**:
 //!   - [`propose_multisig_tx`](MultisigEngine::propose_multisig_tx) - Propose a new transaction
+//!   - [`propose_consume_notes`](MultisigEngine::propose_consume_notes) - Propose consuming all,
+//!     given, or just enough consumable notes to cover an amount
+//!   - [`propose_payment`](MultisigEngine::propose_payment) - Propose paying an asset to another
+//!     account
+//!   - [`dry_run_multisig_tx`](MultisigEngine::dry_run_multisig_tx) - Preview the summary of a
+//!     transaction without proposing it
+//!   - [`validate_multisig_tx`](MultisigEngine::validate_multisig_tx) - Check whether a
+//!     transaction is executable without proposing it
+//!   - [`repropose_multisig_tx`](MultisigEngine::repropose_multisig_tx) - Re-propose a failed
+//!     transaction with a fresh summary, invalidating its signatures
 //!   - [`add_signature`](MultisigEngine::add_signature) - Add an approver's signature
+//!   - [`add_signatures`](MultisigEngine::add_signatures) - Add several approvers' signatures at
+//!     once
+//!   - [`has_approver_signed`](MultisigEngine::has_approver_signed) - Check whether an approver
+//!     signed a transaction
+//!   - [`execute_ready_tx`](MultisigEngine::execute_ready_tx) - Execute a transaction that met its
+//!     threshold while automatic processing is disabled (see
+//!     [`with_auto_process`](MultisigEngine::with_auto_process))
+//!   - [`reconcile_multisig_txs`](MultisigEngine::reconcile_multisig_txs) - Recover the status of
+//!     transactions executed on chain whose status update was lost, e.g. in a crash
+//!   - [`get_multisig_tx_by_summary_commit`](MultisigEngine::get_multisig_tx_by_summary_commit) -
+//!     Get a transaction by the commitment its approvers sign
 //!   - [`list_multisig_tx`](MultisigEngine::list_multisig_tx) - List transactions for an account
+//!   - [`list_approver_pending_txs`](MultisigEngine::list_approver_pending_txs) - List the
+//!     proposals awaiting an approver across all of their accounts
+//!   - [`list_audit_events`](MultisigEngine::list_audit_events) - List the accepted and rejected
+//!     proposals, signatures and executions recorded for an account
 //!
 //! - **Notes**:
 //!   - [`get_consumable_notes`](MultisigEngine::get_consumable_notes) - Get consumable notes
 //!
+//! - **Health**:
+//!   - [`check_readiness`](MultisigEngine::check_readiness) - Check that the database and the
+//!     runtime threads are reachable
+//!   - [`ping_runtime`](MultisigEngine::ping_runtime) - Check that the runtime threads are alive
+//!   - [`runtime_worker_of`](MultisigEngine::runtime_worker_of) - Tell which runtime worker
+//!     handles the operations on an account
+//!
+//! ## Events
+//!
+//! The engine emits a [`CoordinatorEvent`] to its [`EventSink`] (see
+//! [`with_event_sink`](MultisigEngine::with_event_sink)) whenever it persisted an account
+//! creation or import, an approver removal, a transaction proposal, a signature, or the execution
+//! or failure of a transaction. Events are discarded by default.
+//!
 //! [`MultisigClient`]: miden_multisig_client::MultisigClient
 //! [`MultisigStore`]: miden_multisig_coordinator_store::MultisigStore
+//! [`MultisigStoreBackend`]: miden_multisig_coordinator_store::MultisigStoreBackend
 //! [`LocalSet`]: tokio::task::LocalSet
 
 mod error;
+mod event;
 mod multisig_client_runtime;
 mod types;
 
 use crate::types::{
     request::{
-        GetMultisigTxStatsRequest, GetMultisigTxStatsRequestDissolved, ListMultisigApproverRequest,
+        GetMultisigTxStatsRequest, GetMultisigTxStatsRequestDissolved, GetMultisigTxTrendRequest,
+        GetMultisigTxTrendRequestDissolved, ListMultisigAccountsRequest,
+        ListMultisigAccountsRequestDissolved, ListMultisigApproverRequest,
         ListMultisigApproverRequestDissolved,
     },
-    response::{GetMultisigTxStatsResponse, ListMultisigApproverResponse},
+    response::{
+        GetMultisigTxStatsResponse, GetMultisigTxTrendResponse, ListMultisigAccountsResponse,
+        ListMultisigApproverResponse, MultisigAccountOverview,
+    },
 };
 
 pub use self::{
     error::MultisigEngineError,
-    multisig_client_runtime::MultisigClientRuntimeConfig,
+    event::{CoordinatorEvent, EventSink, NoopEventSink},
+    multisig_client_runtime::{
+        MultisigClientRuntimeConfig, MultisigClientRuntimeDrainSummary, SharedNodeRpcClient,
+    },
     types::{request, response},
 };
 
-use std::thread::JoinHandle;
+use core::{cmp::Reverse, num::NonZeroU32, time::Duration};
 
+use std::{collections::BTreeMap, sync::Arc, thread::JoinHandle};
+
+use futures::TryStreamExt;
 use miden_client::{
-    account::{AccountIdAddress, AccountStorageMode, AddressInterface, NetworkId},
-    note::NoteConsumability,
+    Word,
+    account::{Account, AccountId, AccountIdAddress, AccountStorageMode, NetworkId},
+    asset::Asset,
+    note::{NoteConsumability, NoteId},
     store::InputNoteRecord,
-    transaction::TransactionResult,
+    transaction::{
+        PaymentNoteDescription, TransactionRecord, TransactionRequest, TransactionRequestBuilder,
+        TransactionResult,
+    },
 };
+use miden_multisig_client::MultisigConfig;
 use miden_multisig_coordinator_domain::{
-    account::MultisigAccount,
-    tx::{MultisigTxDissolved, MultisigTxStatus},
+    account::{MultisigAccount, WithApprovers, WithPubKeyCommits},
+    audit::{AuditAction, AuditOutcome, AuditSubject},
+    key::{ApproverKey, ApproverSignature},
+    tx::{MultisigTxDissolved, MultisigTxId, MultisigTxStatus, TxSort, TxTrendBucket},
 };
-use miden_multisig_coordinator_store::MultisigStore;
-use tokio::{
-    runtime::Runtime,
-    sync::{
-        mpsc::{self, error::SendError},
-        oneshot,
-    },
-    task,
+use miden_multisig_coordinator_store::{MultisigStoreBackend, MultisigStoreError};
+use miden_multisig_coordinator_utils::to_bech32;
+use miden_objects::{crypto::dsa::rpo_falcon512::PublicKey, transaction::TransactionSummary};
+use tokio::sync::{
+    mpsc::{self, error::SendTimeoutError},
+    oneshot,
 };
+use tracing::Span;
 
 use self::{
     error::MultisigEngineErrorKind,
     multisig_client_runtime::{
         MultisigClientRuntimeError,
         msg::{
-            CreateMultisigAccount, GetConsumableNotes, MultisigClientRuntimeMsg, ProcessMultisigTx,
-            ProposeMultisigTx,
+            BuildPaymentTxRequest, ConsumableNotesDissolved, CreateMultisigAccount,
+            ExportMultisigAccount, FetchMultisigAccount, GetAccount, GetCommittedTxs,
+            GetConsumableNotes, ImportMultisigAccountError, MultisigClientRuntimeMsg, Ping,
+            ProcessMultisigTx, ProposeMultisigTx, TrackMultisigAccount,
         },
     },
     types::{
         request::{
-            AddSignatureRequest, AddSignatureRequestDissolved, CreateMultisigAccountRequest,
-            CreateMultisigAccountRequestDissolved, GetConsumableNotesRequest,
+            AddSignatureRequest, AddSignatureRequestDissolved, AddSignaturesRequest,
+            AddSignaturesRequestDissolved, ArchiveMultisigAccountRequest,
+            ArchiveMultisigAccountRequestDissolved, CreateMultisigAccountRequest,
+            CreateMultisigAccountRequestDissolved, DryRunMultisigTxRequest,
+            DryRunMultisigTxRequestDissolved, ExecuteReadyTxRequest,
+            ExecuteReadyTxRequestDissolved, GetConsumableNotesRequest,
             GetConsumableNotesRequestDissolved, GetMultisigAccountRequest,
-            GetMultisigAccountRequestDissolved, ListMultisigTxRequest,
-            ListMultisigTxRequestDissolved, ProposeMultisigTxRequest,
-            ProposeMultisigTxRequestDissolved,
+            GetMultisigAccountRequestDissolved, GetMultisigTxAccountRequest,
+            GetMultisigTxAccountRequestDissolved, GetMultisigTxBySummaryCommitRequest,
+            GetMultisigTxBySummaryCommitRequestDissolved, GetMultisigTxRequest,
+            GetMultisigTxRequestDissolved, HasApproverSignedRequest,
+            HasApproverSignedRequestDissolved, ImportMultisigAccountRequest,
+            ImportMultisigAccountRequestDissolved, ListApproverPendingTxsRequest,
+            ListApproverPendingTxsRequestDissolved, ListAuditEventsRequest,
+            ListAuditEventsRequestDissolved, ListMultisigTxRequest, ListMultisigTxRequestDissolved,
+            NoteSelector, ProposeConsumeNotesRequest, ProposeConsumeNotesRequestDissolved,
+            ProposeMultisigTxRequest, ProposeMultisigTxRequestDissolved, ProposePaymentRequest,
+            ProposePaymentRequestDissolved, ReconcileMultisigAccountRequest,
+            ReconcileMultisigAccountRequestDissolved, ReconcileMultisigTxsRequest,
+            ReconcileMultisigTxsRequestDissolved, RemoveMultisigApproverRequest,
+            RemoveMultisigApproverRequestDissolved, ReproposeMultisigTxRequest,
+            ReproposeMultisigTxRequestDissolved, ValidateMultisigTxRequest,
+            ValidateMultisigTxRequestDissolved, VerifyAccountConsistencyRequest,
+            VerifyAccountConsistencyRequestDissolved,
         },
         response::{
-            CreateMultisigAccountResponse, GetMultisigAccountResponse, ListMultisigTxResponse,
-            ProposeMultisigTxResponse,
+            AddSignaturesResponse, ArchiveMultisigAccountResponse, CreateMultisigAccountResponse,
+            Drift, DriftField, DryRunMultisigTxResponse, GetConsumableNotesResponse,
+            GetConsumableNotesResponseDissolved, GetMultisigAccountFullResponse,
+            GetMultisigAccountResponse, GetMultisigAccountSummaryResponse,
+            GetMultisigApproverOrderResponse, GetMultisigTxAccountResponse,
+            GetMultisigTxBySummaryCommitResponse, GetMultisigTxResponse, HasApproverSignedResponse,
+            ImportMultisigAccountResponse, ListApproverPendingTxsResponse, ListAuditEventsResponse,
+            ListMultisigTxResponse, MultisigAccountMismatch, ProposeConsumeNotesResponse,
+            ProposeMultisigTxResponse, ProposeMultisigTxResponseDissolved, ProposePaymentResponse,
+            ReconcileMultisigAccountResponse, ReconcileMultisigTxsResponse,
+            RemoveMultisigApproverResponse, ReproposeMultisigTxResponse,
+            StopMultisigClientRuntimeResponse, ValidateMultisigTxResponse,
+            VerifyAccountConsistencyResponse,
         },
     },
 };
@@ -199,7 +346,11 @@ use self::{
 /// * `R` - The multisig client runtime state, either [`Stopped`] or [`Started`]
 pub struct MultisigEngine<R> {
     network_id: NetworkId,
-    store: MultisigStore,
+    auto_process: bool,
+    trust_tx_summary: bool,
+    max_pending_proposals: Option<NonZeroU32>,
+    store: Arc<dyn MultisigStoreBackend>,
+    event_sink: Arc<dyn EventSink>,
     runtime: R,
 }
 
@@ -211,78 +362,191 @@ pub struct Stopped;
 /// Marker type indicating the [`MultisigEngine`] is in the started state.
 ///
 /// In this state:
-/// - The multisig client runtime thread is running
+/// - The multisig client runtime worker threads are running
 /// - All blockchain operations are available
-/// - Communication happens via message passing channels
+/// - Communication happens via message passing channels, one per worker
 pub struct Started {
-    sender: mpsc::UnboundedSender<MultisigClientRuntimeMsg>,
-    handle: JoinHandle<Result<(), MultisigClientRuntimeError>>,
+    senders: Vec<mpsc::Sender<MultisigClientRuntimeMsg>>,
+    handles: Vec<JoinHandle<Result<MultisigClientRuntimeDrainSummary, MultisigClientRuntimeError>>>,
+    enqueue_timeout: Duration,
 }
 
+/// The number of stored multisig accounts retrieved at once while starting the runtime, which
+/// keeps startup from being refused by the cap on retrieving all accounts at once.
+const TRACKED_ACCOUNTS_PAGE_SIZE: NonZeroU32 = NonZeroU32::new(1_000).unwrap();
+
 impl<R> MultisigEngine<R> {
     /// Returns the network ID this engine is configured for.
     pub fn network_id(&self) -> NetworkId {
         self.network_id
     }
+
+    /// Sets the sink the [`CoordinatorEvent`]s are emitted to.
+    ///
+    /// Events are discarded by default, see [`NoopEventSink`]. The sink can be set whether the
+    /// runtime is started or not, as the events are emitted by the engine itself.
+    pub fn with_event_sink(self, event_sink: Arc<dyn EventSink>) -> Self {
+        Self { event_sink, ..self }
+    }
 }
 
 impl MultisigEngine<Stopped> {
     /// Creates a new [`MultisigEngine<Stopped>`].
-    pub fn new(network_id: NetworkId, store: MultisigStore) -> Self {
-        Self { network_id, store, runtime: Stopped }
+    ///
+    /// The store is usually a
+    /// [`MultisigStore`](miden_multisig_coordinator_store::MultisigStore), any other
+    /// [`MultisigStoreBackend`] can be used instead, e.g. an in-memory one to run the engine in
+    /// tests without a database.
+    pub fn new(network_id: NetworkId, store: impl MultisigStoreBackend + 'static) -> Self {
+        Self {
+            network_id,
+            auto_process: true,
+            trust_tx_summary: false,
+            max_pending_proposals: None,
+            store: Arc::new(store),
+            event_sink: Arc::new(NoopEventSink),
+            runtime: Stopped,
+        }
+    }
+
+    /// Sets whether transactions are processed as soon as their signature threshold is met.
+    ///
+    /// Enabled by default. When disabled, transactions meeting their threshold are marked as
+    /// [`MultisigTxStatus::ReadyToExecute`] instead, and are only processed once
+    /// [`execute_ready_tx`](MultisigEngine::execute_ready_tx) is called.
+    pub fn with_auto_process(self, auto_process: bool) -> Self {
+        Self { auto_process, ..self }
+    }
+
+    /// Sets whether transaction summaries supplied along with proposals are trusted as is.
+    ///
+    /// Disabled by default, in which case a supplied summary is only accepted if its commitment
+    /// matches the one of the summary computed by dry-running the transaction request. When
+    /// enabled, a supplied summary is persisted without dry-running the transaction request.
+    pub fn with_trust_tx_summary(self, trust_tx_summary: bool) -> Self {
+        Self { trust_tx_summary, ..self }
+    }
+
+    /// Sets the maximum number of pending transactions a multisig account may have at once.
+    ///
+    /// Unlimited by default. When set, proposing or re-proposing a transaction for an account
+    /// already having as many pending transactions is rejected, until some of them meet their
+    /// signature threshold. The pending transactions are counted within the database transaction
+    /// storing the new one, so that concurrent proposals can't exceed the maximum.
+    pub fn with_max_pending_proposals(self, max_pending_proposals: Option<NonZeroU32>) -> Self {
+        Self { max_pending_proposals, ..self }
     }
 
-    /// Starts the multisig client runtime thread and transitions to the [`Started`] state.
+    /// Starts the multisig client runtime threads and transitions to the [`Started`] state.
+    ///
+    /// This spawns as many dedicated threads as configured workers, each running its own
+    /// [`MultisigClient`](miden_multisig_client::MultisigClient) and tracking the stored multisig
+    /// accounts it owns.
+    ///
+    /// This only returns once every worker initialized its client, i.e. built it and synced it
+    /// with the node, so a misconfigured or unreachable node is reported right away rather than
+    /// by every later operation timing out.
+    ///
+    /// An account a worker couldn't import from the node, e.g. because it isn't on chain yet and
+    /// the number of workers changed since it was created, is exported from the worker tracking
+    /// it and handed over to its owning worker.
     ///
-    /// This spawns a dedicated thread that runs the [`MultisigClient`](miden_multisig_client::MultisigClient).
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The stored multisig accounts cannot be retrieved
+    /// - A worker fails to initialize its client, e.g. because the node is unreachable
+    /// - A worker stops while accounts are handed over
     #[tracing::instrument(skip_all)]
     pub async fn start_multisig_client_runtime(
         self,
-        rt: Runtime,
         multisig_client_runtime_config: MultisigClientRuntimeConfig,
     ) -> Result<MultisigEngine<Started>, MultisigEngineError> {
-        let (sender, receiver) = mpsc::unbounded_channel();
+        let workers = multisig_client_runtime_config.workers().get();
+        let queue_capacity = multisig_client_runtime_config.queue_capacity().get();
+        let enqueue_timeout = multisig_client_runtime_config.enqueue_timeout();
+
+        // only the addresses of the accounts are held, a page of accounts at a time
+        let mut tracking_multisig_accounts = vec![Vec::new(); workers];
+        {
+            let mut multisig_accounts =
+                self.store.stream_multisig_accounts_paged(true, TRACKED_ACCOUNTS_PAGE_SIZE);
+
+            while let Some(multisig_account) =
+                multisig_accounts.try_next().await.map_err(MultisigEngineErrorKind::from)?
+            {
+                let address = multisig_account.address();
+                tracking_multisig_accounts
+                    [multisig_client_runtime::worker_of(address.id(), workers)]
+                .push(address);
+            }
+        }
 
-        let multisig_accounts = self
-            .store
-            .get_all_multisig_accounts()
-            .await
-            .map_err(MultisigEngineErrorKind::from)?;
+        let mut senders = Vec::with_capacity(workers);
+        let mut handles = Vec::with_capacity(workers);
+        let mut startup_receivers = Vec::with_capacity(workers);
 
-        let addresses: Vec<_> = task::spawn_blocking(move || {
-            multisig_accounts.iter().map(MultisigAccount::address).collect()
-        })
-        .await
-        .map_err(|e| MultisigEngineErrorKind::other(e.to_string()))?;
-
-        let handle = multisig_client_runtime::spawn_new(
-            rt,
-            receiver,
-            addresses.into_iter(),
-            multisig_client_runtime_config,
-        );
+        for (worker, addresses) in tracking_multisig_accounts.into_iter().enumerate() {
+            let (sender, receiver) = mpsc::channel(queue_capacity);
+
+            let (handle, startup_receiver) = multisig_client_runtime::spawn_new(
+                receiver,
+                addresses.into_iter(),
+                multisig_client_runtime_config.for_worker(worker),
+            );
+
+            senders.push(sender);
+            handles.push(handle);
+            startup_receivers.push(startup_receiver);
+        }
+
+        // on failure, the senders are dropped, which closes the msg channels of the workers that
+        // did initialize and makes them exit
+        let mut untracked_account_ids = Vec::new();
+        for startup_receiver in startup_receivers {
+            let worker_untracked_account_ids = startup_receiver
+                .await
+                .map_err(MultisigEngineErrorKind::oneshot_receive("runtime startup"))?
+                .map_err(MultisigEngineErrorKind::from)?;
+
+            untracked_account_ids.extend(worker_untracked_account_ids);
+        }
 
         let engine = MultisigEngine {
             network_id: self.network_id(),
+            auto_process: self.auto_process,
+            trust_tx_summary: self.trust_tx_summary,
+            max_pending_proposals: self.max_pending_proposals,
             store: self.store,
-            runtime: Started { sender, handle },
+            event_sink: self.event_sink,
+            runtime: Started { senders, handles, enqueue_timeout },
         };
 
+        for account_id in untracked_account_ids {
+            engine.hand_over_multisig_account(account_id).await?;
+        }
+
         Ok(engine)
     }
 }
 
 impl MultisigEngine<Started> {
+    /// How long [`ping_runtime`](Self::ping_runtime) waits for the runtime threads to answer.
+    pub const RUNTIME_PING_TIMEOUT: Duration = Duration::from_secs(5);
+
     /// Creates a new multisig account on the blockchain and persists it in the database.
     ///
     /// This operation:
-    /// 1. Sends a request to the runtime thread to create the account on-chain
+    /// 1. Sends a request to the first runtime worker to build the account, without tracking it,
+    ///    then hands the account over to its owning worker, which tracks it until it is on chain
     /// 2. Stores the account metadata in the persistent store
     /// 3. Returns the blockchain account and the coordinator's view of the persisted multisig account
     ///
     /// # Errors
     ///
     /// This function will return an error if:
+    /// - The threshold exceeds the total approver weight, see
+    ///   [`MultisigEngineError::invalid_multisig_config`]
     /// - Communication with the runtime thread fails
     /// - The blockchain account creation fails
     /// - Database storage fails
@@ -291,43 +555,89 @@ impl MultisigEngine<Started> {
         &self,
         request: CreateMultisigAccountRequest,
     ) -> Result<CreateMultisigAccountResponse, MultisigEngineError> {
-        let CreateMultisigAccountRequestDissolved { threshold, approvers, pub_key_commits } =
-            request.dissolve();
+        let CreateMultisigAccountRequestDissolved {
+            threshold,
+            approvers,
+            pub_key_commits,
+            weights,
+            labels,
+            address_interface,
+        } = request.dissolve();
 
         let (msg, receiver) = {
             let (sender, receiver) = oneshot::channel();
 
+            // the multisig auth component holds the commitment of every approver key on chain
+            let on_chain_pub_key_commits = pub_key_commits
+                .iter()
+                .map(|pub_key_commit| PublicKey::new(pub_key_commit.commitment()))
+                .collect();
+
             let msg = CreateMultisigAccount::builder()
                 .threshold(threshold)
-                .approvers(pub_key_commits.clone())
+                .approvers(on_chain_pub_key_commits)
                 .sender(sender)
                 .build();
 
             (MultisigClientRuntimeMsg::CreateMultisigAccount(msg), receiver)
         };
 
-        self.send_to_multisig_client_runtime(msg).map_err(|_| {
-            MultisigEngineErrorKind::mpsc_sender("failed to send create multisig account")
-        })?;
+        self.send_to_multisig_client_runtime(msg, "failed to send create multisig account")
+            .await?;
+
+        let (miden_account, seed) = receiver
+            .await
+            .map_err(MultisigEngineErrorKind::oneshot_receive("create multisig account"))?;
+
+        // accounts are built by the first worker, only their owning worker tracks them
+        let (msg, receiver) = {
+            let (sender, receiver) = oneshot::channel();
+
+            let msg = TrackMultisigAccount::builder()
+                .account(miden_account.clone())
+                .seed(seed)
+                .sender(sender)
+                .build();
+
+            (MultisigClientRuntimeMsg::TrackMultisigAccount(msg), receiver)
+        };
+
+        self.send_to_multisig_client_runtime(msg, "failed to send track multisig account")
+            .await?;
+
+        receiver
+            .await
+            .map_err(MultisigEngineErrorKind::oneshot_receive("track multisig account"))?;
 
-        let miden_account = receiver.await.map_err(MultisigEngineErrorKind::from)?;
+        let address = AccountIdAddress::new(miden_account.id(), address_interface);
 
         let multisig_account = MultisigAccount::builder()
-            .address(AccountIdAddress::new(miden_account.id(), AddressInterface::BasicWallet))
+            .address(address)
             .network_id(self.network_id())
             .kind(AccountStorageMode::Public) // TODO: add support for private multisig accounts
             .threshold(threshold)
+            .nonce(miden_account.nonce().as_int())
             .aux(())
             .build()
-            .with_approvers(approvers)
-            .ok_or(MultisigEngineErrorKind::other("threshold exceeds approvers length"))?
+            .with_weighted_approvers(approvers, weights)
+            .ok_or(MultisigEngineErrorKind::InvalidMultisigConfig(
+                "threshold exceeds total approver weight",
+            ))?
+            .with_approver_labels(labels)
+            .ok_or(MultisigEngineErrorKind::InvalidMultisigConfig(
+                "approvers length mismatches labels",
+            ))?
             .with_pub_key_commits(pub_key_commits)
-            .ok_or(MultisigEngineErrorKind::other("approvers length mismatches pub key commits"))
+            .ok_or(MultisigEngineErrorKind::InvalidMultisigConfig(
+                "approvers length mismatches pub key commits",
+            ))
             .map(|multisig_account| self.store.create_multisig_account(multisig_account))?
             .await
             .map(From::from)
             .map_err(MultisigEngineErrorKind::from)?;
 
+        self.event_sink.emit(CoordinatorEvent::AccountCreated { address }).await;
+
         let response = CreateMultisigAccountResponse::builder()
             .miden_account(miden_account)
             .multisig_account(multisig_account)
@@ -336,30 +646,248 @@ impl MultisigEngine<Started> {
         Ok(response)
     }
 
+    /// Imports a multisig account created elsewhere, e.g. with the CLI, and persists it in the
+    /// database.
+    ///
+    /// This operation:
+    /// 1. Sends a request to the runtime worker owning the account to fetch it from the node,
+    ///    without tracking it, rejecting it unless its auth component is the multisig auth
+    ///    component
+    /// 2. Reads the threshold and the approver public key commitments from the account storage,
+    ///    pairing them with the given approver addresses in approver index order
+    /// 3. Stores the account metadata in the persistent store
+    /// 4. Has the runtime worker owning the account track it
+    /// 5. Returns the on-chain account and the coordinator's view of the persisted multisig account
+    ///
+    /// The account is only tracked once persisted, so a rejected import leaves no trace in the
+    /// runtime. Approver keys are Falcon public keys unless given, in which case their commitments
+    /// must match the on-chain ones.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The multisig account is already persisted, whatever the interface of its address
+    /// - Communication with the runtime thread fails
+    /// - The account is not a public account known to the node
+    /// - The auth component of the account is not the multisig auth component, or its storage
+    ///   doesn't hold a valid multisig configuration
+    /// - The number of approvers differs from the number of on-chain approver public keys
+    /// - A given approver key doesn't match the on-chain commitment at its index
+    /// - Database storage fails
+    #[tracing::instrument(skip_all)]
+    pub async fn import_multisig_account(
+        &self,
+        request: ImportMultisigAccountRequest,
+    ) -> Result<ImportMultisigAccountResponse, MultisigEngineError> {
+        let ImportMultisigAccountRequestDissolved {
+            account_id,
+            approvers,
+            pub_key_commits,
+            address_interface,
+        } = request.dissolve();
+
+        let address = AccountIdAddress::new(account_id, address_interface);
+
+        let (msg, receiver) = {
+            let (sender, receiver) = oneshot::channel();
+
+            let msg = FetchMultisigAccount::builder().account_id(account_id).sender(sender).build();
+
+            (MultisigClientRuntimeMsg::FetchMultisigAccount(msg), receiver)
+        };
+
+        self.send_to_multisig_client_runtime(msg, "failed to send fetch multisig account")
+            .await?;
+
+        let miden_account = receiver
+            .await
+            .map_err(MultisigEngineErrorKind::oneshot_receive("fetch multisig account"))?
+            .map_err(|err| {
+                if err.is_not_multisig_account() {
+                    MultisigEngineErrorKind::NotMultisigAccount(account_id)
+                } else {
+                    MultisigEngineErrorKind::from(err)
+                }
+            })?;
+
+        let MultisigConfig { threshold, approver_pub_keys, .. } =
+            MultisigConfig::try_from(&miden_account)
+                .map_err(ImportMultisigAccountError::from)
+                .map_err(MultisigEngineErrorKind::from)?;
+
+        if approvers.len() != approver_pub_keys.len() {
+            return Err(MultisigEngineErrorKind::ApproverCountMismatch {
+                on_chain: approver_pub_keys.len(),
+                provided: approvers.len(),
+            }
+            .into());
+        }
+
+        // the storage of the account holds the commitment of every approver key, which is the
+        // Falcon public key itself
+        let pub_key_commits = match pub_key_commits {
+            Some(pub_key_commits) if pub_key_commits.len() != approver_pub_keys.len() => {
+                return Err(MultisigEngineErrorKind::ApproverCountMismatch {
+                    on_chain: approver_pub_keys.len(),
+                    provided: pub_key_commits.len(),
+                }
+                .into());
+            },
+            Some(pub_key_commits) => {
+                let mismatch = pub_key_commits.iter().zip(&approver_pub_keys).position(
+                    |(pub_key_commit, &on_chain_pub_key)| {
+                        pub_key_commit.commitment() != Word::from(on_chain_pub_key)
+                    },
+                );
+
+                if let Some(index) = mismatch {
+                    return Err(MultisigEngineErrorKind::ApproverKeyMismatch { index }.into());
+                }
+
+                pub_key_commits
+            },
+            None => approver_pub_keys.into_iter().map(ApproverKey::Falcon).collect(),
+        };
+
+        let threshold = NonZeroU32::new(threshold).ok_or(
+            MultisigEngineErrorKind::InvalidMultisigConfig("zero on-chain multisig threshold"),
+        )?;
+
+        let multisig_account = MultisigAccount::builder()
+            .address(address)
+            .network_id(self.network_id())
+            .kind(AccountStorageMode::Public)
+            .threshold(threshold)
+            .nonce(miden_account.nonce().as_int())
+            .aux(())
+            .build()
+            .with_approvers(approvers)
+            .ok_or(MultisigEngineErrorKind::InvalidMultisigConfig(
+                "threshold exceeds approver count",
+            ))?
+            .with_pub_key_commits(pub_key_commits)
+            .ok_or(MultisigEngineErrorKind::InvalidMultisigConfig(
+                "approvers length mismatches pub key commits",
+            ))
+            .map(|multisig_account| self.store.create_multisig_account(multisig_account))?
+            .await
+            .map(From::from)
+            .map_err(|err| match err {
+                MultisigStoreError::MultisigAccountAlreadyExists => {
+                    MultisigEngineErrorKind::MultisigAccountAlreadyExists(account_id)
+                },
+                err => MultisigEngineErrorKind::from(err),
+            })?;
+
+        let (msg, receiver) = {
+            let (sender, receiver) = oneshot::channel();
+
+            let msg = TrackMultisigAccount::builder()
+                .account(miden_account.clone())
+                .sender(sender)
+                .build();
+
+            (MultisigClientRuntimeMsg::TrackMultisigAccount(msg), receiver)
+        };
+
+        // the account is persisted, so even if tracking fails now it is tracked on the next start
+        self.send_to_multisig_client_runtime(msg, "failed to send track multisig account")
+            .await?;
+
+        receiver
+            .await
+            .map_err(MultisigEngineErrorKind::oneshot_receive("track multisig account"))?;
+
+        self.event_sink.emit(CoordinatorEvent::AccountImported { address }).await;
+
+        let response = ImportMultisigAccountResponse::builder()
+            .miden_account(miden_account)
+            .multisig_account(multisig_account)
+            .build();
+
+        Ok(response)
+    }
+
     /// Retrieves consumable notes for a multisig account.
+    ///
+    /// If no account is given, the consumable notes of all multisig accounts are gathered from
+    /// every runtime worker. If a note tag is given, only the notes carrying that tag are returned.
+    ///
+    /// # Freshness
+    ///
+    /// The notes are listed as of the block the multisig client is synced to, which is returned
+    /// along with them. Each listing syncs the client first, if the sync fails the notes of the
+    /// last synced block are still returned but flagged as stale, as some may have been consumed
+    /// since. When several runtime workers are involved, the oldest of their blocks is returned
+    /// and the notes are stale if any worker's are.
     #[tracing::instrument(skip_all)]
     pub async fn get_consumable_notes(
         &self,
         request: GetConsumableNotesRequest,
-    ) -> Result<Vec<(InputNoteRecord, Vec<NoteConsumability>)>, MultisigEngineError> {
-        let GetConsumableNotesRequestDissolved { address } = request.dissolve();
+    ) -> Result<GetConsumableNotesResponse, MultisigEngineError> {
+        let GetConsumableNotesRequestDissolved { address, note_tag } = request.dissolve();
 
-        let (msg, receiver) = {
+        let account_id = address.as_ref().map(AccountIdAddress::id);
+
+        let workers = match account_id {
+            Some(account_id) => {
+                let worker = self.runtime_worker_of(account_id);
+                worker..worker + 1
+            },
+            None => 0..self.runtime.senders.len(),
+        };
+
+        let mut receivers = Vec::with_capacity(workers.len());
+
+        for worker in workers {
             let (sender, receiver) = oneshot::channel();
 
             let msg = GetConsumableNotes::builder()
-                .maybe_account_id(address.as_ref().map(AccountIdAddress::id))
+                .maybe_account_id(account_id)
+                .maybe_note_tag(note_tag)
                 .sender(sender)
                 .build();
 
-            (MultisigClientRuntimeMsg::GetConsumableNotes(msg), receiver)
-        };
+            let msg = MultisigClientRuntimeMsg::GetConsumableNotes(msg);
+            self.runtime.send(worker, msg, "failed to send get consumable notes").await?;
+
+            receivers.push(receiver);
+        }
+
+        let mut notes: BTreeMap<NoteId, (InputNoteRecord, Vec<NoteConsumability>)> =
+            BTreeMap::new();
+        let mut oldest_block_num = None;
+        let mut any_stale = false;
+
+        for receiver in receivers {
+            // a note consumable by accounts owned by different workers is reported by each one
+            let ConsumableNotesDissolved { notes: worker_notes, block_num, stale } = receiver
+                .await
+                .map_err(MultisigEngineErrorKind::oneshot_receive("get consumable notes"))?
+                .dissolve();
+
+            oldest_block_num =
+                Some(oldest_block_num.map_or(block_num, |oldest| block_num.min(oldest)));
+            any_stale |= stale;
+
+            for (note, consumability) in worker_notes {
+                notes
+                    .entry(note.id())
+                    .or_insert_with(|| (note, Vec::new()))
+                    .1
+                    .extend(consumability);
+            }
+        }
 
-        self.send_to_multisig_client_runtime(msg).map_err(|_| {
-            MultisigEngineErrorKind::mpsc_sender("failed to send get consmable notes")
-        })?;
+        let block_num = oldest_block_num.expect("at least one runtime worker is queried");
+
+        let response = GetConsumableNotesResponse::builder()
+            .notes(notes.into_values().collect())
+            .block_num(block_num)
+            .stale(any_stale)
+            .build();
 
-        receiver.await.map_err(MultisigEngineErrorKind::from).map_err(From::from)
+        Ok(response)
     }
 
     /// Proposes a new multisig transaction.
@@ -378,6 +906,20 @@ impl MultisigEngine<Started> {
     ///                              Status: Success or Failure
     /// ```
     ///
+    /// # Pre-computed Summaries
+    ///
+    /// The request may carry a pre-computed transaction summary, e.g. from an integrator running
+    /// its own node. Unless the engine trusts supplied summaries (see
+    /// [`with_trust_tx_summary`](MultisigEngine::with_trust_tx_summary)), the transaction request
+    /// is still dry-run and the supplied summary is rejected if its commitment differs from the
+    /// computed one. Trusted summaries are persisted without any round trip to the runtime thread.
+    ///
+    /// # Balance Check
+    ///
+    /// Before dry-running the transaction request, the fungible assets its output notes pay out
+    /// are checked against the multisig account vault, asset by asset, so that an under-funded
+    /// proposal is rejected before any approver signs it.
+    ///
     /// # Returns
     ///
     /// Returns the transaction ID in the database and the transaction summary.
@@ -385,49 +927,53 @@ impl MultisigEngine<Started> {
     /// # Errors
     ///
     /// This function will return an error if:
-    /// - The multisig account doesn't exist
+    /// - The multisig account doesn't exist, see
+    ///   [`MultisigEngineError::multisig_account_not_found`]
     /// - Communication with the runtime thread fails
     /// - Transaction validation fails
+    /// - The multisig account holds less of an asset than the output notes pay out
+    /// - The supplied transaction summary mismatches the multisig account or the computed summary,
+    ///   see [`MultisigEngineError::is_tx_summary_mismatch`]
+    /// - The multisig account already has the maximum number of pending transactions, see
+    ///   [`with_max_pending_proposals`](MultisigEngine::with_max_pending_proposals)
     /// - Database storage fails
-    #[tracing::instrument(skip_all)]
+    #[tracing::instrument(
+        skip_all,
+        fields(address = tracing::field::Empty, tx_id = tracing::field::Empty),
+    )]
     pub async fn propose_multisig_tx(
         &self,
         request: ProposeMultisigTxRequest,
     ) -> Result<ProposeMultisigTxResponse, MultisigEngineError> {
-        let ProposeMultisigTxRequestDissolved { address, tx_request } = request.dissolve();
+        let ProposeMultisigTxRequestDissolved {
+            address,
+            tx_request,
+            tx_summary,
+            title,
+            memo,
+        } = request.dissolve();
 
-        let (msg, receiver) = {
-            let (sender, receiver) = oneshot::channel();
+        Span::current().record("address", tracing::field::display(address.id().to_hex()));
 
-            let msg = ProposeMultisigTx::builder()
-                .account_id(address.id())
-                .tx_request(tx_request.clone())
-                .sender(sender)
-                .build();
+        let proposal =
+            self.try_propose_multisig_tx(address, tx_request, tx_summary, title, memo).await;
 
-            (MultisigClientRuntimeMsg::ProposeMultisigTx(msg), receiver)
+        let (tx_id, tx_summary) = match proposal {
+            Ok(proposal) => proposal,
+            Err(err) => {
+                let subject = AuditSubject::Account(address);
+                self.audit(subject, None, AuditAction::ProposeTx, Err(&err)).await;
+                return Err(err);
+            },
         };
 
-        self.send_to_multisig_client_runtime(msg).map_err(|_| {
-            MultisigEngineErrorKind::mpsc_sender("failed to send propose multisig tx")
-        })?;
-
-        self.store
-            .get_multisig_account(self.network_id(), address)
-            .await
-            .map_err(MultisigEngineErrorKind::from)?
-            .ok_or(MultisigEngineErrorKind::not_found("account not found"))?;
+        Span::current().record("tx_id", tracing::field::display(&tx_id));
 
-        let tx_summary = receiver
-            .await
-            .map_err(MultisigEngineErrorKind::from)?
-            .map_err(MultisigEngineErrorKind::from)?;
+        let subject = AuditSubject::Tx(tx_id.clone());
+        self.audit(subject, None, AuditAction::ProposeTx, Ok(())).await;
 
-        let tx_id = self
-            .store
-            .create_multisig_tx(self.network_id(), address, &tx_request, &tx_summary)
-            .await
-            .map_err(MultisigEngineErrorKind::from)?;
+        let event = CoordinatorEvent::TxProposed { tx_id: tx_id.clone(), address };
+        self.event_sink.emit(event).await;
 
         let response =
             ProposeMultisigTxResponse::builder().tx_id(tx_id).tx_summary(tx_summary).build();
@@ -435,229 +981,2494 @@ impl MultisigEngine<Started> {
         Ok(response)
     }
 
-    /// Adds an approver's signature to a pending multisig transaction.
-    ///
-    /// When the signature threshold is met, the transaction is automatically processed
-    /// and submitted to the blockchain.
-    ///
-    /// # Returns
+    /// Proposes a transaction consuming a selection of the consumable notes of a multisig
+    /// account.
     ///
-    /// * `Ok(Some(TransactionResult))` - Threshold met, transaction processed successfully
-    /// * `Ok(None)` - Signature added, waiting for more signatures
+    /// The notes are resolved from [`get_consumable_notes`](Self::get_consumable_notes) according
+    /// to the [`NoteSelector`](request::NoteSelector), then the transaction consuming them is
+    /// proposed like any other through [`propose_multisig_tx`](Self::propose_multisig_tx).
     ///
     /// # Errors
     ///
     /// This function will return an error if:
-    /// - The approver is not authorized for this transaction
-    /// - The signature is invalid
-    /// - Database operations fail
-    #[tracing::instrument(skip_all)]
-    pub async fn add_signature(
+    /// - The consumable notes are stale because syncing with the node failed, as some of them may
+    ///   have been consumed since, see [`MultisigEngineError::stale_consumable_notes`]
+    /// - No note is selected, a selected note is not consumable by the account, or the
+    ///   consumable notes carry less than the selected amount, see
+    ///   [`MultisigEngineError::invalid_note_selection`]
+    /// - Proposing the transaction fails, see [`propose_multisig_tx`](Self::propose_multisig_tx)
+    #[tracing::instrument(skip_all, fields(address = tracing::field::Empty))]
+    pub async fn propose_consume_notes(
         &self,
-        request: AddSignatureRequest,
-    ) -> Result<Option<TransactionResult>, MultisigEngineError> {
-        let AddSignatureRequestDissolved { tx_id, approver, signature } = request.dissolve();
+        request: ProposeConsumeNotesRequest,
+    ) -> Result<ProposeConsumeNotesResponse, MultisigEngineError> {
+        let ProposeConsumeNotesRequestDissolved { address, selector } = request.dissolve();
 
-        let threshold_met = self
-            .store
-            .add_multisig_tx_signature(&tx_id, self.network_id(), approver, &signature)
-            .await
-            .map_err(MultisigEngineErrorKind::from)?
-            .ok_or(MultisigEngineErrorKind::other(
-                "approver not permitted to add signature for tx",
-            ))?;
+        Span::current().record("address", tracing::field::display(address.id().to_hex()));
 
-        // TODO: make transaction processing async
-        if threshold_met {
-            let (signatures, multisig_tx) = self
-                .store
-                .get_signatures_of_all_approvers_with_multisig_tx_by_tx_id(&tx_id)
-                .await
-                .map_err(MultisigEngineErrorKind::from)?;
+        let get_consumable_notes_request =
+            GetConsumableNotesRequest::builder().address(address).build();
 
-            let (msg, receiver) = {
-                let (sender, receiver) = oneshot::channel();
+        let GetConsumableNotesResponseDissolved { notes, block_num, stale } =
+            self.get_consumable_notes(get_consumable_notes_request).await?.dissolve();
 
-                let MultisigTxDissolved { address, tx_request, tx_summary, .. } =
-                    multisig_tx.dissolve();
+        // the runtime already tried to sync before listing, so there is no point in retrying here
+        if stale {
+            return Err(MultisigEngineErrorKind::StaleConsumableNotes(block_num).into());
+        }
 
-                let msg = ProcessMultisigTx::builder()
-                    .account_id(address.id())
-                    .tx_request(tx_request)
-                    .tx_summary(tx_summary)
-                    .signatures(signatures)
-                    .sender(sender)
-                    .build();
+        let notes: Vec<_> = notes.into_iter().map(|(note, _)| note).collect();
 
-                (MultisigClientRuntimeMsg::ProcessMultisigTx(msg), receiver)
-            };
+        let note_ids = select_notes(&notes, selector)?;
 
-            self.send_to_multisig_client_runtime(msg).map_err(|_| {
-                MultisigEngineErrorKind::mpsc_sender("failed to send process multisig tx")
-            })?;
+        let tx_request = TransactionRequestBuilder::new()
+            .build_consume_notes(note_ids.clone())
+            .map_err(|e| MultisigEngineErrorKind::other(e.to_string()))?;
 
-            match receiver.await.map_err(MultisigEngineErrorKind::from)? {
-                Ok(tx_result) => {
-                    self.store
-                        .update_multisig_tx_status_by_id(&tx_id, MultisigTxStatus::Success)
-                        .await
-                        .map_err(MultisigEngineErrorKind::from)?;
+        let propose_request = ProposeMultisigTxRequest::builder()
+            .address(address)
+            .tx_request(tx_request)
+            .build()
+            .map_err(|e| MultisigEngineErrorKind::other(e.to_string()))?;
 
-                    return Ok(Some(tx_result));
-                },
-                Err(e) => {
-                    // TODO: ascertain the scenarios this can occur
-                    self.store
-                        .update_multisig_tx_status_by_id(&tx_id, MultisigTxStatus::Failure)
-                        .await
-                        .map_err(MultisigEngineErrorKind::from)?;
+        let ProposeMultisigTxResponseDissolved { tx_id, tx_summary } =
+            self.propose_multisig_tx(propose_request).await?.dissolve();
 
-                    return Err(MultisigEngineErrorKind::from(e).into());
-                },
-            }
-        }
+        let response = ProposeConsumeNotesResponse::builder()
+            .tx_id(tx_id)
+            .tx_summary(tx_summary)
+            .note_ids(note_ids)
+            .build();
 
-        Ok(None)
+        Ok(response)
     }
 
-    /// Retrieves a multisig account by its address.
+    /// Proposes a transaction paying an asset from a multisig account to another account.
     ///
-    /// Queries the persistent store for multisig account metadata, including threshold,
-    /// approvers, and public key commitments.
-    #[tracing::instrument(skip_all)]
-    pub async fn get_multisig_account(
+    /// The transaction request creating the P2ID note carrying the asset is built by the runtime
+    /// thread, then proposed like any other through
+    /// [`propose_multisig_tx`](Self::propose_multisig_tx), so callers need not build and serialize
+    /// the transaction request themselves.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The multisig account doesn't exist, see
+    ///   [`MultisigEngineError::multisig_account_not_found`]
+    /// - Communication with the runtime thread fails
+    /// - The transaction request cannot be built, e.g. the payment note cannot carry the asset,
+    ///   see [`MultisigEngineError::invalid_payment`]
+    /// - Proposing the transaction fails, see [`propose_multisig_tx`](Self::propose_multisig_tx)
+    #[tracing::instrument(skip_all, fields(address = tracing::field::Empty))]
+    pub async fn propose_payment(
         &self,
-        request: GetMultisigAccountRequest,
-    ) -> Result<GetMultisigAccountResponse, MultisigEngineError> {
-        let GetMultisigAccountRequestDissolved { multisig_account_id_address } = request.dissolve();
+        request: ProposePaymentRequest,
+    ) -> Result<ProposePaymentResponse, MultisigEngineError> {
+        let ProposePaymentRequestDissolved { address, recipient, asset, note_type } =
+            request.dissolve();
 
-        let multisig_account = self
-            .store
-            .get_multisig_account(self.network_id(), multisig_account_id_address)
+        Span::current().record("address", tracing::field::display(address.id().to_hex()));
+
+        // checked before the runtime thread builds anything for the account
+        self.ensure_multisig_account_exists(address).await?;
+
+        let (msg, receiver) = {
+            let (sender, receiver) = oneshot::channel();
+
+            let msg = BuildPaymentTxRequest::builder()
+                .account_id(address.id())
+                .payment(PaymentNoteDescription::new(vec![asset], address.id(), recipient.id()))
+                .note_type(note_type)
+                .sender(sender)
+                .build();
+
+            (MultisigClientRuntimeMsg::BuildPaymentTxRequest(msg), receiver)
+        };
+
+        self.send_to_multisig_client_runtime(msg, "failed to send build payment tx request")
+            .await?;
+
+        let tx_request = receiver
             .await
-            .map_err(MultisigEngineErrorKind::from)?;
+            .map_err(MultisigEngineErrorKind::oneshot_receive("build payment tx request"))?
+            .map_err(MultisigEngineErrorKind::InvalidPayment)?;
 
-        let response = GetMultisigAccountResponse::builder()
-            .maybe_multisig_account(multisig_account)
-            .build();
+        let propose_request = ProposeMultisigTxRequest::builder()
+            .address(address)
+            .tx_request(tx_request)
+            .build()
+            .map_err(|e| MultisigEngineErrorKind::other(e.to_string()))?;
+
+        let ProposeMultisigTxResponseDissolved { tx_id, tx_summary } =
+            self.propose_multisig_tx(propose_request).await?.dissolve();
+
+        let response =
+            ProposePaymentResponse::builder().tx_id(tx_id).tx_summary(tx_summary).build();
 
         Ok(response)
     }
 
-    /// Retrieves transaction statistics for a specific multisig account.
+    /// Dry-runs a multisig transaction without proposing it.
     ///
-    /// Returns aggregated statistics including total transactions, transactions since one month ago,
-    /// and the total number of successful transactions for the given multisig account.
-    pub async fn get_multisig_tx_stats(
-        &self,
-        request: GetMultisigTxStatsRequest,
-    ) -> Result<GetMultisigTxStatsResponse, MultisigEngineError> {
-        let GetMultisigTxStatsRequestDissolved { multisig_account_id_address } = request.dissolve();
+    /// The transaction summary is computed exactly as in
+    /// [`propose_multisig_tx`](Self::propose_multisig_tx), but nothing is persisted, so proposers
+    /// can preview a transaction and iterate on it before publishing it for signatures.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The multisig account doesn't exist, see
+    ///   [`MultisigEngineError::multisig_account_not_found`]
+    /// - Communication with the runtime thread fails
+    /// - Transaction validation fails
+    #[tracing::instrument(skip_all, fields(address = tracing::field::Empty))]
+    pub async fn dry_run_multisig_tx(
+        &self,
+        request: DryRunMultisigTxRequest,
+    ) -> Result<DryRunMultisigTxResponse, MultisigEngineError> {
+        let DryRunMultisigTxRequestDissolved { address, tx_request } = request.dissolve();
 
-        let tx_stats = self
+        Span::current().record("address", tracing::field::display(address.id().to_hex()));
+
+        self.ensure_multisig_account_exists(address).await?;
+
+        let tx_summary = self.compute_tx_summary(address, tx_request).await?;
+
+        Ok(DryRunMultisigTxResponse::builder().tx_summary(tx_summary).build())
+    }
+
+    /// Validates that a multisig transaction is executable without proposing it.
+    ///
+    /// The transaction goes through the same balance check as in
+    /// [`propose_multisig_tx`](Self::propose_multisig_tx), then through
+    /// [`dry_run_multisig_tx`](Self::dry_run_multisig_tx), but nothing is persisted. Unlike the
+    /// dry-run, a transaction that is not executable, e.g. paying out more than the account holds
+    /// or consuming invalid notes, is not an error but reported in the response along with the
+    /// reason.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The multisig account doesn't exist, see
+    ///   [`MultisigEngineError::multisig_account_not_found`]
+    /// - Communication with the runtime thread fails
+    /// - The node cannot be reached, as the transaction may be executable nonetheless
+    #[tracing::instrument(skip_all, fields(address = tracing::field::Empty))]
+    pub async fn validate_multisig_tx(
+        &self,
+        request: ValidateMultisigTxRequest,
+    ) -> Result<ValidateMultisigTxResponse, MultisigEngineError> {
+        let ValidateMultisigTxRequestDissolved { address, tx_request } = request.dissolve();
+
+        Span::current().record("address", tracing::field::display(address.id().to_hex()));
+
+        self.ensure_multisig_account_exists(address).await?;
+
+        let tx_summary = match self.ensure_sufficient_balance(address, &tx_request).await {
+            Ok(()) => self.compute_tx_summary(address, tx_request).await,
+            Err(err) => Err(err),
+        };
+
+        let response = match tx_summary {
+            Ok(tx_summary) => ValidateMultisigTxResponse::builder().tx_summary(tx_summary).build(),
+            Err(err) if err.is_tx_not_executable() => {
+                ValidateMultisigTxResponse::builder().reason(err.to_string()).build()
+            },
+            Err(err) => return Err(err),
+        };
+
+        Ok(response)
+    }
+
+    /// Re-proposes a failed multisig transaction, e.g. one that failed due to stale chain state.
+    ///
+    /// The stored transaction request is dry-run again against the synced node to compute a fresh
+    /// transaction summary, which replaces the stored one, and the transaction goes back to
+    /// `Pending`. Since the summary commitment changes, **all previously collected signatures are
+    /// invalidated and deleted**; approvers must sign the new summary.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The transaction doesn't exist, see [`MultisigEngineError::multisig_tx_not_found`]
+    /// - The transaction is not failed, see [`MultisigEngineError::multisig_tx_not_pending`]
+    /// - Communication with the runtime thread fails
+    /// - Transaction validation fails
+    /// - The multisig account already has the maximum number of pending transactions, see
+    ///   [`with_max_pending_proposals`](MultisigEngine::with_max_pending_proposals)
+    /// - Database storage fails
+    #[tracing::instrument(skip_all, fields(tx_id = tracing::field::Empty))]
+    pub async fn repropose_multisig_tx(
+        &self,
+        request: ReproposeMultisigTxRequest,
+    ) -> Result<ReproposeMultisigTxResponse, MultisigEngineError> {
+        let ReproposeMultisigTxRequestDissolved { tx_id } = request.dissolve();
+
+        Span::current().record("tx_id", tracing::field::display(&tx_id));
+
+        let subject = AuditSubject::Tx(tx_id.clone());
+
+        let (address, tx_summary) = match self.try_repropose_multisig_tx(&tx_id).await {
+            Ok(reproposal) => reproposal,
+            Err(err) => {
+                self.audit(subject, None, AuditAction::ReproposeTx, Err(&err)).await;
+                return Err(err);
+            },
+        };
+
+        self.audit(subject, None, AuditAction::ReproposeTx, Ok(())).await;
+
+        let event = CoordinatorEvent::TxProposed { tx_id: tx_id.clone(), address };
+        self.event_sink.emit(event).await;
+
+        let response = ReproposeMultisigTxResponse::builder()
+            .tx_id(tx_id)
+            .tx_summary(tx_summary)
+            .build();
+
+        Ok(response)
+    }
+
+    /// Adds an approver's signature to a pending multisig transaction.
+    ///
+    /// When the signature threshold is met, the transaction is automatically processed
+    /// and submitted to the blockchain, unless automatic processing is disabled, in which case
+    /// it is marked as ready to execute.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(TransactionResult))` - Threshold met, transaction processed successfully
+    /// * `Ok(None)` - Signature added, waiting for more signatures or for a manual execution
+    ///
+    /// The approver is checked before the signature is added, so a signature of a non-approver
+    /// is rejected without opening a write transaction.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The transaction doesn't exist, see [`MultisigEngineError::multisig_tx_not_found`]
+    /// - The approver is not authorized for this transaction, see
+    ///   [`MultisigEngineError::approver_not_authorized`]
+    /// - The signature is invalid
+    /// - Database operations fail
+    #[tracing::instrument(skip_all, fields(tx_id = tracing::field::Empty))]
+    pub async fn add_signature(
+        &self,
+        request: AddSignatureRequest,
+    ) -> Result<Option<TransactionResult>, MultisigEngineError> {
+        let AddSignatureRequestDissolved { tx_id, approver, signature } = request.dissolve();
+
+        Span::current().record("tx_id", tracing::field::display(&tx_id));
+
+        let subject = AuditSubject::Tx(tx_id.clone());
+
+        let threshold_met = match self.try_add_signature(&tx_id, approver, &signature).await {
+            Ok(threshold_met) => threshold_met,
+            Err(err) => {
+                self.audit(subject, Some(approver), AuditAction::AddSignature, Err(&err)).await;
+                return Err(err);
+            },
+        };
+
+        self.audit(subject, Some(approver), AuditAction::AddSignature, Ok(())).await;
+
+        let event = CoordinatorEvent::SignatureAdded { tx_id: tx_id.clone(), approver };
+        self.event_sink.emit(event).await;
+
+        // TODO: make transaction processing async
+        if threshold_met {
+            return self.on_threshold_met(&tx_id).await;
+        }
+
+        Ok(None)
+    }
+
+    /// Adds several approvers' signatures to a pending multisig transaction at once.
+    ///
+    /// All signatures are validated and stored in a single database transaction; if any approver
+    /// is not authorized or any signature cannot be stored, none of them are. When the batch
+    /// crosses the signature threshold, the transaction is processed exactly once, after all
+    /// signatures have been persisted, or marked as ready to execute if automatic processing is
+    /// disabled.
+    ///
+    /// # Returns
+    ///
+    /// The number of accepted signatures, along with the [`TransactionResult`] if the
+    /// transaction was processed.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The transaction doesn't exist, see [`MultisigEngineError::multisig_tx_not_found`]
+    /// - Any approver is not authorized for this transaction, see
+    ///   [`MultisigEngineError::approver_not_authorized`]
+    /// - Any signature is invalid or was already added
+    /// - Database operations fail
+    #[tracing::instrument(skip_all, fields(tx_id = tracing::field::Empty))]
+    pub async fn add_signatures(
+        &self,
+        request: AddSignaturesRequest,
+    ) -> Result<AddSignaturesResponse, MultisigEngineError> {
+        let AddSignaturesRequestDissolved { tx_id, signatures } = request.dissolve();
+
+        Span::current().record("tx_id", tracing::field::display(&tx_id));
+
+        let subject = AuditSubject::Tx(tx_id.clone());
+
+        let added = match self
             .store
-            .get_multisig_tx_stats_by_multisig_account_address(
-                self.network_id(),
-                multisig_account_id_address,
+            .add_multisig_tx_signatures(&tx_id, self.network_id(), &signatures)
+            .await
+        {
+            Ok(Some(threshold_met)) => Ok(threshold_met),
+            Ok(None) => Err(self.unauthorized_approver_error(&tx_id, &signatures).await),
+            Err(err) => Err(MultisigEngineErrorKind::from(err)),
+        };
+
+        let threshold_met = match added {
+            Ok(threshold_met) => threshold_met,
+            Err(err) => {
+                // the batch is rejected as a whole, so is the signature of every approver in it
+                for &(approver, _) in &signatures {
+                    let (subject, action) = (subject.clone(), AuditAction::AddSignature);
+                    self.audit(subject, Some(approver), action, Err(&err)).await;
+                }
+
+                return Err(err.into());
+            },
+        };
+
+        for &(approver, _) in &signatures {
+            self.audit(subject.clone(), Some(approver), AuditAction::AddSignature, Ok(()))
+                .await;
+
+            let event = CoordinatorEvent::SignatureAdded { tx_id: tx_id.clone(), approver };
+            self.event_sink.emit(event).await;
+        }
+
+        // TODO: make transaction processing async
+        let tx_result = if threshold_met {
+            self.on_threshold_met(&tx_id).await?
+        } else {
+            None
+        };
+
+        let response = AddSignaturesResponse::builder()
+            .accepted(signatures.len())
+            .threshold_met(threshold_met)
+            .maybe_tx_result(tx_result)
+            .build();
+
+        Ok(response)
+    }
+
+    /// Executes a multisig transaction that met its signature threshold while automatic
+    /// processing was disabled.
+    ///
+    /// The transaction is processed and submitted to the blockchain exactly as if it had been
+    /// processed when its threshold was met. It is first claimed by atomically moving it to
+    /// [`MultisigTxStatus::Executing`], so that concurrent executions of the same transaction
+    /// submit it once. It moves back to [`MultisigTxStatus::ReadyToExecute`] if it couldn't be
+    /// processed, as opposed to its processing failing.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The transaction doesn't exist, see [`MultisigEngineError::multisig_tx_not_found`]
+    /// - The transaction is not ready to execute, e.g. because it is being executed already, see
+    ///   [`MultisigEngineError::multisig_tx_not_pending`]
+    /// - The transaction processing fails
+    /// - Database operations fail
+    #[tracing::instrument(skip_all, fields(tx_id = tracing::field::Empty))]
+    pub async fn execute_ready_tx(
+        &self,
+        request: ExecuteReadyTxRequest,
+    ) -> Result<TransactionResult, MultisigEngineError> {
+        let ExecuteReadyTxRequestDissolved { tx_id } = request.dissolve();
+
+        Span::current().record("tx_id", tracing::field::display(&tx_id));
+
+        let claimed = self
+            .store
+            .transition_multisig_tx_status_by_id(
+                &tx_id,
+                MultisigTxStatus::ReadyToExecute,
+                MultisigTxStatus::Executing,
             )
             .await
             .map_err(MultisigEngineErrorKind::from)?;
 
-        let response = GetMultisigTxStatsResponse::builder().tx_stats(tx_stats).build();
+        if !claimed {
+            return Err(self.multisig_tx_status_error(tx_id).await);
+        }
+
+        let tx_result = self.process_multisig_tx(&tx_id).await;
+
+        if tx_result.is_err() {
+            // a failed processing already moved the tx to failure, this only releases the claim
+            // of a tx which couldn't be processed at all
+            self.store
+                .transition_multisig_tx_status_by_id(
+                    &tx_id,
+                    MultisigTxStatus::Executing,
+                    MultisigTxStatus::ReadyToExecute,
+                )
+                .await
+                .map_err(MultisigEngineErrorKind::from)?;
+        }
+
+        tx_result
+    }
+
+    /// Checks whether an approver has signed a multisig transaction.
+    ///
+    /// The signature is looked up in the persistent store without loading it, so this is cheap
+    /// enough to e.g. tell an approver whether they can still sign.
+    #[tracing::instrument(skip_all)]
+    pub async fn has_approver_signed(
+        &self,
+        request: HasApproverSignedRequest,
+    ) -> Result<HasApproverSignedResponse, MultisigEngineError> {
+        let HasApproverSignedRequestDissolved { tx_id, approver } = request.dissolve();
+
+        let signed = self
+            .store
+            .has_approver_signed(&tx_id, self.network_id(), approver)
+            .await
+            .map_err(MultisigEngineErrorKind::from)?;
+
+        Ok(HasApproverSignedResponse::builder().signed(signed).build())
+    }
+
+    /// Retrieves a multisig account by its address.
+    ///
+    /// Queries the persistent store for multisig account metadata, including threshold,
+    /// approvers, and public key commitments.
+    #[tracing::instrument(skip_all)]
+    pub async fn get_multisig_account(
+        &self,
+        request: GetMultisigAccountRequest,
+    ) -> Result<GetMultisigAccountResponse, MultisigEngineError> {
+        let GetMultisigAccountRequestDissolved { multisig_account_id_address } = request.dissolve();
+
+        let multisig_account = self
+            .store
+            .get_multisig_account(self.network_id(), multisig_account_id_address)
+            .await
+            .map_err(MultisigEngineErrorKind::from)?;
+
+        let response = GetMultisigAccountResponse::builder()
+            .maybe_multisig_account(multisig_account)
+            .build();
 
         Ok(response)
     }
 
-    /// Lists all approvers for a specific multisig account.
+    /// Retrieves a multisig account by its address together with its approvers and their
+    /// public key commitments.
     ///
-    /// Retrieves the list of approvers associated with the given multisig account address,
-    /// including their addresses and public key commitments.
+    /// Everything is loaded from the persistent store in a single query, so the approvers are
+    /// guaranteed to be consistent with the returned account.
     #[tracing::instrument(skip_all)]
-    pub async fn list_multisig_approvers(
+    pub async fn get_multisig_account_full(
         &self,
-        request: ListMultisigApproverRequest,
-    ) -> Result<ListMultisigApproverResponse, MultisigEngineError> {
-        let ListMultisigApproverRequestDissolved { multisig_account_id_address } =
-            request.dissolve();
+        request: GetMultisigAccountRequest,
+    ) -> Result<GetMultisigAccountFullResponse, MultisigEngineError> {
+        let GetMultisigAccountRequestDissolved { multisig_account_id_address } = request.dissolve();
 
-        self.store
-            .get_approvers_by_multisig_account_address(
-                self.network_id(),
-                multisig_account_id_address,
-            )
+        let multisig_account = self
+            .store
+            .try_get_multisig_account(self.network_id(), multisig_account_id_address)
             .await
-            .map(|approvers| ListMultisigApproverResponse::builder().approvers(approvers).build())
-            .map_err(MultisigEngineErrorKind::from)
-            .map_err(From::from)
+            .map_err(MultisigEngineErrorKind::from)?;
+
+        let response = GetMultisigAccountFullResponse::builder()
+            .maybe_multisig_account(multisig_account)
+            .build();
+
+        Ok(response)
     }
 
-    /// Lists multisig transactions for a specific multisig account.
+    /// Retrieves the threshold and the number of approvers of a multisig account.
     ///
-    /// Returns transactions associated with the given account address, optionally
-    /// filtered by status (Pending, Success, Failure).
+    /// This is a lightweight alternative to
+    /// [`get_multisig_account_full`](Self::get_multisig_account_full) for callers that don't need
+    /// the approvers themselves.
     #[tracing::instrument(skip_all)]
-    pub async fn list_multisig_tx(
+    pub async fn get_multisig_account_summary(
         &self,
-        request: ListMultisigTxRequest, // TODO: add pagination support
-    ) -> Result<ListMultisigTxResponse, MultisigEngineError> {
-        let ListMultisigTxRequestDissolved {
-            multisig_account_id_address,
-            tx_status_filter,
-        } = request.dissolve();
+        request: GetMultisigAccountRequest,
+    ) -> Result<GetMultisigAccountSummaryResponse, MultisigEngineError> {
+        let GetMultisigAccountRequestDissolved { multisig_account_id_address } = request.dissolve();
 
-        self.store
-            .get_txs_by_multisig_account_address_with_status_filter(
+        let summary = self
+            .store
+            .get_account_summary(self.network_id(), multisig_account_id_address)
+            .await
+            .map_err(MultisigEngineErrorKind::from)?;
+
+        let response = GetMultisigAccountSummaryResponse::builder().maybe_summary(summary).build();
+
+        Ok(response)
+    }
+
+    /// Retrieves the approvers of a multisig account along with their approver index.
+    ///
+    /// Transactions are executed with one signature slot per approver, in approver index order,
+    /// so a signer assembling signatures offline places each approver's signature at its index.
+    #[tracing::instrument(skip_all)]
+    pub async fn get_multisig_approver_order(
+        &self,
+        request: GetMultisigAccountRequest,
+    ) -> Result<GetMultisigApproverOrderResponse, MultisigEngineError> {
+        let GetMultisigAccountRequestDissolved { multisig_account_id_address } = request.dissolve();
+
+        let approvers = self
+            .store
+            .get_indexed_approvers_by_multisig_account_address(
                 self.network_id(),
                 multisig_account_id_address,
-                tx_status_filter,
             )
             .await
-            .map(|txs| ListMultisigTxResponse::builder().txs(txs).build())
-            .map_err(MultisigEngineErrorKind::from)
-            .map_err(From::from)
+            .map_err(MultisigEngineErrorKind::from)?;
+
+        let response =
+            GetMultisigApproverOrderResponse::builder().maybe_approvers(approvers).build();
+
+        Ok(response)
     }
 
-    /// Stops the multisig client runtime thread and transitions to [`Stopped`] state.
+    /// Archives or unarchives a multisig account.
     ///
-    /// This sends a shutdown message to the runtime thread and waits for it to
-    /// terminate gracefully. Once stopped, the engine can no longer perform
-    /// blockchain operations.
+    /// Archived accounts are hidden from the default account listing but keep their history and
+    /// are still retrieved by address. The runtime keeps tracking them, so they can be
+    /// unarchived at any time.
+    ///
+    /// The response holds no account if the account doesn't exist.
     ///
     /// # Errors
     ///
     /// This function will return an error if:
-    /// - The shutdown message cannot be sent
-    /// - The runtime thread panicked or misbehaved
-    /// - The thread join operation fails
+    /// - The database update fails
+    /// - Stored account data cannot be deserialized
     #[tracing::instrument(skip_all)]
-    pub async fn stop_multisig_client_runtime(
-        self,
-    ) -> Result<MultisigEngine<Stopped>, MultisigEngineError> {
-        self.send_to_multisig_client_runtime(MultisigClientRuntimeMsg::Shutdown)
-            .map_err(|_| MultisigEngineErrorKind::mpsc_sender("failed to send shutdown msg"))?;
-
-        self.runtime
-            .handle
-            .join()
-            .map_err(|_| {
-                MultisigEngineErrorKind::other("multisig client runtime thread misbehavior")
-            })?
-            .map_err(MultisigEngineErrorKind::from)?;
+    pub async fn archive_multisig_account(
+        &self,
+        request: ArchiveMultisigAccountRequest,
+    ) -> Result<ArchiveMultisigAccountResponse, MultisigEngineError> {
+        let ArchiveMultisigAccountRequestDissolved { multisig_account_id_address, archived } =
+            request.dissolve();
 
-        let engine = MultisigEngine {
-            network_id: self.network_id,
-            store: self.store,
-            runtime: Stopped,
+        let network_id = self.network_id();
+
+        let multisig_account = if archived {
+            self.store
+                .archive_multisig_account(network_id, multisig_account_id_address)
+                .await
+                .map_err(MultisigEngineErrorKind::from)?
+        } else {
+            self.store
+                .unarchive_multisig_account(network_id, multisig_account_id_address)
+                .await
+                .map_err(MultisigEngineErrorKind::from)?
         };
 
-        Ok(engine)
+        let response = ArchiveMultisigAccountResponse::builder()
+            .maybe_multisig_account(multisig_account)
+            .build();
+
+        Ok(response)
     }
 
-    #[allow(clippy::result_large_err)]
-    fn send_to_multisig_client_runtime(
+    /// Removes an approver from a multisig account, optionally lowering its threshold in the
+    /// same database transaction.
+    ///
+    /// The removal is refused if the total weight of the remaining approvers would fall below the
+    /// threshold, the new one if given, as the account could never process a transaction again.
+    /// An account whose threshold equals its approver count thus needs its threshold lowered
+    /// along with the removal.
+    ///
+    /// The removal is refused as well while the account has transactions not executed yet. The
+    /// approvers keep their index, so that transactions keep being executed against the on-chain
+    /// configuration, which is expected to be updated to the same approvers and threshold, see
+    /// [`verify_account_consistency`](Self::verify_account_consistency).
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The account has transactions not executed yet, see
+    ///   [`MultisigEngineError::has_pending_txs`]
+    /// - The remaining approver weight would be below the threshold, see
+    ///   [`MultisigEngineError::unreachable_threshold`]
+    /// - The given threshold is above the current one, see
+    ///   [`MultisigEngineError::threshold_raised`]
+    /// - Database operations fail
+    #[tracing::instrument(skip_all)]
+    pub async fn remove_multisig_approver(
         &self,
-        msg: MultisigClientRuntimeMsg,
-    ) -> Result<(), SendError<MultisigClientRuntimeMsg>> {
-        self.runtime.sender.send(msg)
+        request: RemoveMultisigApproverRequest,
+    ) -> Result<RemoveMultisigApproverResponse, MultisigEngineError> {
+        let RemoveMultisigApproverRequestDissolved {
+            multisig_account_id_address,
+            approver,
+            threshold,
+        } = request.dissolve();
+
+        let network_id = self.network_id();
+
+        let multisig_account = match threshold {
+            Some(threshold) => self
+                .store
+                .remove_multisig_account_approver_and_lower_threshold(
+                    network_id,
+                    multisig_account_id_address,
+                    approver,
+                    threshold,
+                )
+                .await
+                .map_err(MultisigEngineErrorKind::from)?,
+            None => self
+                .store
+                .remove_multisig_account_approver(network_id, multisig_account_id_address, approver)
+                .await
+                .map_err(MultisigEngineErrorKind::from)?,
+        };
+
+        if multisig_account.is_some() {
+            let address = multisig_account_id_address;
+            self.event_sink
+                .emit(CoordinatorEvent::ApproverRemoved { address, approver })
+                .await;
+        }
+
+        let response = RemoveMultisigApproverResponse::builder()
+            .maybe_multisig_account(multisig_account)
+            .build();
+
+        Ok(response)
+    }
+
+    /// Compares the stored view of a multisig account against its live on-chain state.
+    ///
+    /// The account is fetched through the runtime thread and its threshold and approver public
+    /// keys are read from its storage, then diffed against the persisted threshold and approver
+    /// public key commitments. An empty list of mismatches means the store is in sync with the
+    /// chain.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The multisig account doesn't exist in the store, see
+    ///   [`MultisigEngineError::multisig_account_not_found`], or on chain
+    /// - Communication with the runtime thread fails
+    /// - The on-chain account storage doesn't hold a multisig configuration
+    #[tracing::instrument(skip_all)]
+    pub async fn reconcile_multisig_account(
+        &self,
+        request: ReconcileMultisigAccountRequest,
+    ) -> Result<ReconcileMultisigAccountResponse, MultisigEngineError> {
+        let ReconcileMultisigAccountRequestDissolved { multisig_account_id_address } =
+            request.dissolve();
+
+        let (multisig_account, account) = self
+            .get_stored_and_on_chain_multisig_account(multisig_account_id_address)
+            .await?;
+
+        let MultisigConfig {
+            threshold: on_chain_threshold,
+            approver_pub_keys: on_chain_pub_key_commits,
+            ..
+        } = MultisigConfig::try_from(&account)
+            .map_err(|e| MultisigEngineErrorKind::other(e.to_string()))?;
+
+        let mut mismatches = Vec::new();
+
+        let stored_threshold = multisig_account.threshold().get();
+
+        if stored_threshold != on_chain_threshold {
+            mismatches.push(MultisigAccountMismatch::Threshold {
+                stored: stored_threshold,
+                on_chain: on_chain_threshold,
+            });
+        }
+
+        let stored_pub_key_commits = multisig_account.pub_key_commits();
+        let num_approvers = stored_pub_key_commits.len().max(on_chain_pub_key_commits.len());
+
+        for approver_index in 0..num_approvers {
+            let stored = stored_pub_key_commits.get(approver_index).copied();
+            let on_chain = on_chain_pub_key_commits.get(approver_index).copied();
+
+            if stored.map(|stored| stored.commitment()) != on_chain.map(Word::from) {
+                mismatches.push(MultisigAccountMismatch::PubKeyCommit {
+                    approver_index,
+                    stored,
+                    on_chain,
+                });
+            }
+        }
+
+        let response = ReconcileMultisigAccountResponse::builder()
+            .on_chain_threshold(on_chain_threshold)
+            .on_chain_pub_key_commits(on_chain_pub_key_commits)
+            .mismatches(mismatches)
+            .build();
+
+        Ok(response)
+    }
+
+    /// Verifies that the stored view of a multisig account is consistent with its live on-chain
+    /// state, reporting every field that drifted.
+    ///
+    /// Unlike [`reconcile_multisig_account`](Self::reconcile_multisig_account), the nonce is
+    /// compared too: the coordinator records the nonce of the account when creating it, after
+    /// executing each of its transactions and when reconciling them, so a nonce drift reveals that
+    /// the account was changed on chain by other means, e.g. its threshold or approvers were
+    /// updated out of band. A nonce the store doesn't know is reported as drifted. The values of
+    /// the drifted fields are reported as strings, public key commitments hex-encoded. An empty
+    /// report means the store is in sync with the chain.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The multisig account doesn't exist in the store, see
+    ///   [`MultisigEngineError::multisig_account_not_found`], or on chain
+    /// - Communication with the runtime thread fails
+    /// - The on-chain account storage doesn't hold a multisig configuration
+    #[tracing::instrument(skip_all)]
+    pub async fn verify_account_consistency(
+        &self,
+        request: VerifyAccountConsistencyRequest,
+    ) -> Result<VerifyAccountConsistencyResponse, MultisigEngineError> {
+        let VerifyAccountConsistencyRequestDissolved { multisig_account_id_address } =
+            request.dissolve();
+
+        let (multisig_account, account) = self
+            .get_stored_and_on_chain_multisig_account(multisig_account_id_address)
+            .await?;
+
+        let MultisigConfig {
+            threshold: on_chain_threshold,
+            approver_pub_keys: on_chain_pub_key_commits,
+            ..
+        } = MultisigConfig::try_from(&account)
+            .map_err(|e| MultisigEngineErrorKind::other(e.to_string()))?;
+
+        let mut drifts = Vec::new();
+
+        let stored_nonce = multisig_account.nonce();
+        let on_chain_nonce = account.nonce().as_int();
+
+        if stored_nonce != Some(on_chain_nonce) {
+            drifts.push(
+                Drift::builder()
+                    .field(DriftField::Nonce)
+                    .maybe_db_value(stored_nonce.as_ref().map(u64::to_string))
+                    .chain_value(on_chain_nonce.to_string())
+                    .build(),
+            );
+        }
+
+        let stored_threshold = multisig_account.threshold().get();
+
+        if stored_threshold != on_chain_threshold {
+            drifts.push(
+                Drift::builder()
+                    .field(DriftField::Threshold)
+                    .db_value(stored_threshold.to_string())
+                    .chain_value(on_chain_threshold.to_string())
+                    .build(),
+            );
+        }
+
+        let stored_pub_key_commits = multisig_account.pub_key_commits();
+        let num_approvers = stored_pub_key_commits.len().max(on_chain_pub_key_commits.len());
+
+        for approver_index in 0..num_approvers {
+            let stored = stored_pub_key_commits.get(approver_index).copied();
+            let on_chain =
+                on_chain_pub_key_commits.get(approver_index).copied().map(ApproverKey::Falcon);
+
+            if stored.map(|stored| stored.commitment())
+                != on_chain.map(|on_chain| on_chain.commitment())
+            {
+                drifts.push(
+                    Drift::builder()
+                        .field(DriftField::PubKeyCommit { approver_index })
+                        .maybe_db_value(stored.as_ref().map(approver_key_to_hex))
+                        .maybe_chain_value(on_chain.as_ref().map(approver_key_to_hex))
+                        .build(),
+                );
+            }
+        }
+
+        let response = VerifyAccountConsistencyResponse::builder().drifts(drifts).build();
+
+        Ok(response)
+    }
+
+    /// Recovers the status of the transactions of a multisig account that were executed on chain
+    /// without their status being updated, e.g. because the coordinator crashed in between.
+    ///
+    /// Every pending, ready to execute or executing transaction is matched against the
+    /// transactions of the account the runtime thread saw committed on chain, a committed
+    /// transaction being its execution if it consumed the same input notes and created the same
+    /// output notes. A matching transaction is marked as successful, along with the id of the
+    /// committed transaction and the block it was executed against, and the on-chain nonce of the
+    /// account is recorded. A committed transaction is matched at most once, and never if it is
+    /// already recorded as the execution of another transaction.
+    ///
+    /// Only the transactions submitted by this coordinator's runtime are known to it, so a
+    /// transaction executed by other means is left untouched.
+    ///
+    /// Transactions whose stored data cannot be deserialized are left out of the reconciliation
+    /// and logged rather than failing it, their ids are reported in the response.
+    ///
+    /// # Returns
+    ///
+    /// The ids of the transactions marked as successful, along with the ids of the undeserializable
+    /// transactions.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The multisig account doesn't exist in the store, see
+    ///   [`MultisigEngineError::multisig_account_not_found`], or on chain
+    /// - Communication with the runtime thread fails
+    /// - Database operations fail
+    #[tracing::instrument(skip_all)]
+    pub async fn reconcile_multisig_txs(
+        &self,
+        request: ReconcileMultisigTxsRequest,
+    ) -> Result<ReconcileMultisigTxsResponse, MultisigEngineError> {
+        let ReconcileMultisigTxsRequestDissolved { multisig_account_id_address } =
+            request.dissolve();
+
+        let (msg, receiver) = {
+            let (sender, receiver) = oneshot::channel();
+
+            let msg = GetCommittedTxs::builder()
+                .account_id(multisig_account_id_address.id())
+                .sender(sender)
+                .build();
+
+            (MultisigClientRuntimeMsg::GetCommittedTxs(msg), receiver)
+        };
+
+        self.send_to_multisig_client_runtime(msg, "failed to send get committed txs")
+            .await?;
+
+        // the account as of the same sync as the committed txs, to record its nonce along them
+        let (msg, account_receiver) = {
+            let (sender, receiver) = oneshot::channel();
+
+            let msg = GetAccount::builder()
+                .account_id(multisig_account_id_address.id())
+                .sender(sender)
+                .build();
+
+            (MultisigClientRuntimeMsg::GetAccount(msg), receiver)
+        };
+
+        self.send_to_multisig_client_runtime(msg, "failed to send get account").await?;
+
+        // the statuses read here gate the updates below, so they must not lag behind
+        let store = self.store.primary();
+
+        store
+            .try_get_multisig_account(self.network_id(), multisig_account_id_address)
+            .await
+            .map_err(MultisigEngineErrorKind::from)?
+            .ok_or(MultisigEngineErrorKind::MultisigAccountNotFound(
+                multisig_account_id_address.id(),
+            ))?;
+
+        let (txs, _) = store
+            .get_txs_by_multisig_account_address_with_status_filter(
+                self.network_id(),
+                multisig_account_id_address,
+                None::<MultisigTxStatus>,
+                TxSort::default(),
+                None,
+                None,
+            )
+            .await
+            .map_err(MultisigEngineErrorKind::from)?;
+
+        let mut corrupt_tx_ids = Vec::new();
+
+        let txs: Vec<_> = txs
+            .into_iter()
+            .filter_map(|(tx_id, tx)| match tx {
+                Ok(tx) => Some(tx.dissolve()),
+                Err(e) => {
+                    tracing::warn!("skipping undeserializable multisig tx {tx_id}: {e}");
+                    corrupt_tx_ids.push(tx_id);
+                    None
+                },
+            })
+            .collect();
+
+        let mut committed_txs = receiver
+            .await
+            .map_err(MultisigEngineErrorKind::oneshot_receive("get committed txs"))?;
+
+        let on_chain_nonce = account_receiver
+            .await
+            .map_err(MultisigEngineErrorKind::oneshot_receive("get account"))?
+            .ok_or(MultisigEngineErrorKind::not_found("multisig account not found on chain"))?
+            .nonce()
+            .as_int();
+
+        committed_txs
+            .retain(|committed_tx| !txs.iter().any(|tx| tx.chain_tx_id == Some(committed_tx.id)));
+
+        let mut reconciled_tx_ids = Vec::new();
+
+        for MultisigTxDissolved { id, status, tx_summary, .. } in txs {
+            if !matches!(
+                status,
+                MultisigTxStatus::Pending
+                    | MultisigTxStatus::ReadyToExecute
+                    | MultisigTxStatus::Executing
+            ) {
+                continue;
+            }
+
+            let Some(position) = committed_txs
+                .iter()
+                .position(|committed_tx| is_execution_of(committed_tx, &tx_summary))
+            else {
+                continue;
+            };
+
+            let committed_tx = committed_txs.swap_remove(position);
+
+            store
+                .update_multisig_tx_status_to_success_by_id(
+                    &id,
+                    committed_tx.id,
+                    committed_tx.details.block_num,
+                    on_chain_nonce,
+                )
+                .await
+                .map_err(MultisigEngineErrorKind::from)?;
+
+            tracing::info!(
+                tx_id = %id,
+                chain_tx_id = committed_tx.id.to_hex(),
+                "reconciled multisig tx",
+            );
+
+            reconciled_tx_ids.push(id);
+        }
+
+        let response = ReconcileMultisigTxsResponse::builder()
+            .reconciled_tx_ids(reconciled_tx_ids)
+            .corrupt_tx_ids(corrupt_tx_ids)
+            .build();
+
+        Ok(response)
+    }
+
+    /// Retrieves transaction statistics for a specific multisig account.
+    ///
+    /// Returns aggregated statistics including total transactions, transactions since one month ago,
+    /// and the total number of successful transactions for the given multisig account, along with
+    /// the average and 95th percentile of the time its executed transactions took to gather enough
+    /// signatures once proposed.
+    pub async fn get_multisig_tx_stats(
+        &self,
+        request: GetMultisigTxStatsRequest,
+    ) -> Result<GetMultisigTxStatsResponse, MultisigEngineError> {
+        let GetMultisigTxStatsRequestDissolved { multisig_account_id_address } = request.dissolve();
+
+        let tx_stats = self
+            .store
+            .get_multisig_tx_stats_by_multisig_account_address(
+                self.network_id(),
+                multisig_account_id_address,
+            )
+            .await
+            .map_err(MultisigEngineErrorKind::from)?;
+
+        let response = GetMultisigTxStatsResponse::builder().tx_stats(tx_stats).build();
+
+        Ok(response)
+    }
+
+    /// Retrieves the number of transactions of a multisig account proposed in each day or week,
+    /// e.g. to chart the activity of the account next to its
+    /// [statistics](Self::get_multisig_tx_stats).
+    ///
+    /// Buckets start at midnight UTC, on Mondays for weeks, and those without transactions are
+    /// left out.
+    #[tracing::instrument(skip_all)]
+    pub async fn get_tx_trend(
+        &self,
+        request: GetMultisigTxTrendRequest,
+    ) -> Result<GetMultisigTxTrendResponse, MultisigEngineError> {
+        let GetMultisigTxTrendRequestDissolved { multisig_account_id_address, granularity } =
+            request.dissolve();
+
+        let buckets = self
+            .store
+            .get_tx_trend_by_multisig_account_address(
+                self.network_id(),
+                multisig_account_id_address,
+                granularity,
+            )
+            .await
+            .map_err(MultisigEngineErrorKind::from)?
+            .into_iter()
+            .map(|(start, count)| TxTrendBucket::builder().start(start).count(count).build())
+            .collect();
+
+        let response = GetMultisigTxTrendResponse::builder().buckets(buckets).build();
+
+        Ok(response)
+    }
+
+    /// Lists the multisig accounts in creation order, along with their approver count and the
+    /// number of their transactions not executed yet, e.g. for operational dashboards.
+    ///
+    /// Accounts are listed in pages of [`ListMultisigAccountsRequest::DEFAULT_LIMIT`] accounts
+    /// unless a `limit` is given, which is lowered to [`ListMultisigAccountsRequest::MAX_LIMIT`],
+    /// each page starting `after` the `next_cursor` returned along with the previous one.
+    /// Archived accounts are only listed if `include_archived` is set.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - Database query fails
+    #[tracing::instrument(skip_all)]
+    pub async fn list_multisig_accounts(
+        &self,
+        request: ListMultisigAccountsRequest,
+    ) -> Result<ListMultisigAccountsResponse, MultisigEngineError> {
+        let ListMultisigAccountsRequestDissolved { include_archived, after, limit } =
+            request.dissolve();
+
+        let limit = limit
+            .unwrap_or(ListMultisigAccountsRequest::DEFAULT_LIMIT)
+            .min(ListMultisigAccountsRequest::MAX_LIMIT);
+
+        let (accounts, next_cursor) = self
+            .store
+            .get_multisig_accounts_with_counts(include_archived, after, Some(limit))
+            .await
+            .map_err(MultisigEngineErrorKind::from)?;
+
+        let accounts = accounts
+            .into_iter()
+            .map(|(multisig_account, approver_count, pending_tx_count)| {
+                MultisigAccountOverview::builder()
+                    .multisig_account(multisig_account)
+                    .approver_count(approver_count)
+                    .pending_tx_count(pending_tx_count)
+                    .build()
+            })
+            .collect();
+
+        let response = ListMultisigAccountsResponse::builder()
+            .accounts(accounts)
+            .maybe_next_cursor(next_cursor)
+            .build();
+
+        Ok(response)
+    }
+
+    /// Lists the approvers for a specific multisig account.
+    ///
+    /// Retrieves the list of approvers associated with the given multisig account address,
+    /// including their addresses and public key commitments, in approver index order.
+    ///
+    /// Large approver sets can be listed in pages of at most `limit` approvers, each page starting
+    /// `after` the `next_cursor` returned along with the previous one. All approvers are listed if
+    /// no `limit` is given. Approvers can be filtered by a text their label contains, in which
+    /// case pages are made of the matching approvers.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_multisig_approvers(
+        &self,
+        request: ListMultisigApproverRequest,
+    ) -> Result<ListMultisigApproverResponse, MultisigEngineError> {
+        let ListMultisigApproverRequestDissolved {
+            multisig_account_id_address,
+            label_contains,
+            after,
+            limit,
+        } = request.dissolve();
+
+        self.store
+            .get_approvers_by_multisig_account_address(
+                self.network_id(),
+                multisig_account_id_address,
+                label_contains.as_deref(),
+                after,
+                limit,
+            )
+            .await
+            .map(|(approvers, next_cursor)| {
+                ListMultisigApproverResponse::builder()
+                    .approvers(approvers)
+                    .maybe_next_cursor(next_cursor)
+                    .build()
+            })
+            .map_err(MultisigEngineErrorKind::from)
+            .map_err(From::from)
+    }
+
+    /// Retrieves a multisig transaction by its ID.
+    ///
+    /// The transaction is loaded from the persistent store along with its summary, e.g. for an
+    /// approver who lost the summary of a pending proposal to fetch again what it signs.
+    #[tracing::instrument(skip_all)]
+    pub async fn get_multisig_tx(
+        &self,
+        request: GetMultisigTxRequest,
+    ) -> Result<GetMultisigTxResponse, MultisigEngineError> {
+        let GetMultisigTxRequestDissolved { tx_id } = request.dissolve();
+
+        let multisig_tx = self
+            .store
+            .get_multisig_tx_by_id(&tx_id)
+            .await
+            .map_err(MultisigEngineErrorKind::from)?;
+
+        Ok(GetMultisigTxResponse::builder().maybe_multisig_tx(multisig_tx).build())
+    }
+
+    /// Retrieves the address of the multisig account a transaction belongs to.
+    ///
+    /// Only the address is loaded from the persistent store, so this is cheap enough to e.g. rate
+    /// limit the signatures of a transaction per account. It is looked up on the primary, so that
+    /// a tx proposed right before is not reported as missing by a lagging read replica.
+    #[tracing::instrument(skip_all)]
+    pub async fn get_multisig_tx_account(
+        &self,
+        request: GetMultisigTxAccountRequest,
+    ) -> Result<GetMultisigTxAccountResponse, MultisigEngineError> {
+        let GetMultisigTxAccountRequestDissolved { tx_id } = request.dissolve();
+
+        let address = self
+            .store
+            .primary()
+            .get_multisig_account_address_by_tx_id(&tx_id)
+            .await
+            .map_err(MultisigEngineErrorKind::from)?;
+
+        Ok(GetMultisigTxAccountResponse::builder().maybe_address(address).build())
+    }
+
+    /// Retrieves a multisig transaction by the commitment of its summary.
+    ///
+    /// Approvers signing offline know the transaction they sign by its summary commitment rather
+    /// than by its id, this lets them confirm they are signing the right proposal before
+    /// submitting their signature. If identical proposals share the commitment, the most recent
+    /// one is returned.
+    #[tracing::instrument(skip_all)]
+    pub async fn get_multisig_tx_by_summary_commit(
+        &self,
+        request: GetMultisigTxBySummaryCommitRequest,
+    ) -> Result<GetMultisigTxBySummaryCommitResponse, MultisigEngineError> {
+        let GetMultisigTxBySummaryCommitRequestDissolved { tx_summary_commit } = request.dissolve();
+
+        let multisig_tx = self
+            .store
+            .get_multisig_tx_by_summary_commit(tx_summary_commit)
+            .await
+            .map_err(MultisigEngineErrorKind::from)?;
+
+        let response = GetMultisigTxBySummaryCommitResponse::builder()
+            .maybe_multisig_tx(multisig_tx)
+            .build();
+
+        Ok(response)
+    }
+
+    /// Lists multisig transactions for a specific multisig account.
+    ///
+    /// Returns transactions associated with the given account address in the requested `sort`
+    /// order, most recent first by default, optionally filtered by status (Pending, Success,
+    /// Failure).
+    ///
+    /// When a `limit` is given, the response carries the cursor of the next page, to be passed
+    /// back as `after` along with the same `sort` to list the following transactions.
+    ///
+    /// Transactions whose stored data cannot be deserialized are skipped and logged rather than
+    /// failing the whole listing, their ids are reported in the response.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_multisig_tx(
+        &self,
+        request: ListMultisigTxRequest,
+    ) -> Result<ListMultisigTxResponse, MultisigEngineError> {
+        let ListMultisigTxRequestDissolved {
+            multisig_account_id_address,
+            tx_status_filter,
+            sort,
+            after,
+            limit,
+        } = request.dissolve();
+
+        let (listed_txs, next_cursor) = self
+            .store
+            .get_txs_by_multisig_account_address_with_status_filter(
+                self.network_id(),
+                multisig_account_id_address,
+                tx_status_filter,
+                sort.unwrap_or_default(),
+                after,
+                limit,
+            )
+            .await
+            .map_err(MultisigEngineErrorKind::from)?;
+
+        let mut txs = Vec::with_capacity(listed_txs.len());
+        let mut corrupt_tx_ids = Vec::new();
+
+        for (tx_id, tx) in listed_txs {
+            match tx {
+                Ok(tx) => txs.push(tx),
+                Err(e) => {
+                    tracing::warn!("skipping undeserializable multisig tx {tx_id}: {e}");
+                    corrupt_tx_ids.push(tx_id);
+                },
+            }
+        }
+
+        let response = ListMultisigTxResponse::builder()
+            .txs(txs)
+            .corrupt_tx_ids(corrupt_tx_ids)
+            .maybe_next_cursor(next_cursor)
+            .build();
+
+        Ok(response)
+    }
+
+    /// Lists the pending transactions of every multisig account an approver belongs to, most
+    /// recent first, along with whether the approver has already signed each.
+    ///
+    /// This is the feed of the proposals awaiting the approver across all of their accounts not
+    /// archived. The transactions are listed a page at a time, of
+    /// [`ListApproverPendingTxsRequest::DEFAULT_LIMIT`] transactions unless a `limit` is given,
+    /// which is lowered to [`ListApproverPendingTxsRequest::MAX_LIMIT`]. The response carries the
+    /// cursor of the next page, to be passed back as `after` to list the following transactions.
+    ///
+    /// Transactions whose stored data cannot be deserialized are skipped and logged rather than
+    /// failing the whole listing, their ids are reported in the response.
+    #[tracing::instrument(skip_all)]
+    pub async fn list_approver_pending_txs(
+        &self,
+        request: ListApproverPendingTxsRequest,
+    ) -> Result<ListApproverPendingTxsResponse, MultisigEngineError> {
+        let ListApproverPendingTxsRequestDissolved { approver, after, limit } = request.dissolve();
+
+        let limit = limit
+            .unwrap_or(ListApproverPendingTxsRequest::DEFAULT_LIMIT)
+            .min(ListApproverPendingTxsRequest::MAX_LIMIT);
+
+        let (listed_txs, next_cursor) = self
+            .store
+            .get_pending_txs_for_approver(self.network_id(), approver, after, Some(limit))
+            .await
+            .map_err(MultisigEngineErrorKind::from)?;
+
+        let mut txs = Vec::with_capacity(listed_txs.len());
+        let mut corrupt_tx_ids = Vec::new();
+
+        for (tx_id, tx, signed) in listed_txs {
+            match tx {
+                Ok(tx) => txs.push((tx, signed)),
+                Err(e) => {
+                    tracing::warn!("skipping undeserializable multisig tx {tx_id}: {e}");
+                    corrupt_tx_ids.push(tx_id);
+                },
+            }
+        }
+
+        let response = ListApproverPendingTxsResponse::builder()
+            .txs(txs)
+            .corrupt_tx_ids(corrupt_tx_ids)
+            .maybe_next_cursor(next_cursor)
+            .build();
+
+        Ok(response)
+    }
+
+    /// Lists the audit log of a multisig account, most recent events first.
+    ///
+    /// The audit log records notable accepted and rejected operations, e.g. proposals, signatures
+    /// and executions. The events are listed a page at a time, of
+    /// [`ListAuditEventsRequest::DEFAULT_LIMIT`] events unless a `limit` is given, which is
+    /// lowered to [`ListAuditEventsRequest::MAX_LIMIT`]. The response carries the cursor of the
+    /// next page, to be passed back as `after` to list the following events.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - Database query fails
+    #[tracing::instrument(skip_all)]
+    pub async fn list_audit_events(
+        &self,
+        request: ListAuditEventsRequest,
+    ) -> Result<ListAuditEventsResponse, MultisigEngineError> {
+        let ListAuditEventsRequestDissolved {
+            multisig_account_id_address,
+            after,
+            limit,
+        } = request.dissolve();
+
+        let limit = limit
+            .unwrap_or(ListAuditEventsRequest::DEFAULT_LIMIT)
+            .min(ListAuditEventsRequest::MAX_LIMIT);
+
+        let (audit_events, next_cursor) = self
+            .store
+            .get_audit_events_by_multisig_account_address(
+                self.network_id(),
+                multisig_account_id_address,
+                after,
+                Some(limit),
+            )
+            .await
+            .map_err(MultisigEngineErrorKind::from)?;
+
+        let response = ListAuditEventsResponse::builder()
+            .audit_events(audit_events)
+            .maybe_next_cursor(next_cursor)
+            .build();
+
+        Ok(response)
+    }
+
+    /// Checks whether the engine is ready to serve requests.
+    ///
+    /// The engine is ready if the database answers a trivial query and every multisig client
+    /// runtime thread answers a ping, see [`ping_runtime`](Self::ping_runtime).
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The database is unreachable
+    /// - A multisig client runtime thread is not running or doesn't answer in time
+    #[tracing::instrument(skip_all)]
+    pub async fn check_readiness(&self) -> Result<(), MultisigEngineError> {
+        self.store.ping().await.map_err(MultisigEngineErrorKind::from)?;
+
+        self.ping_runtime().await
+    }
+
+    /// Checks that every multisig client runtime worker thread is alive and its client
+    /// initialized.
+    ///
+    /// A worker only starts handling msgs once its client is initialized, and exits if the
+    /// initialization fails, so an answered ping means the client is usable. Since a worker
+    /// handles msgs one at a time, the ping is only answered once the msgs queued before it were
+    /// handled.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - A multisig client runtime worker thread is not running anymore
+    /// - The pings are not all answered within [`Self::RUNTIME_PING_TIMEOUT`]
+    #[tracing::instrument(skip_all)]
+    pub async fn ping_runtime(&self) -> Result<(), MultisigEngineError> {
+        let mut receivers = Vec::with_capacity(self.runtime.senders.len());
+
+        for worker in 0..self.runtime.senders.len() {
+            let (sender, receiver) = oneshot::channel();
+
+            let msg = Ping::builder().sender(sender).build();
+
+            self.runtime
+                .send(worker, MultisigClientRuntimeMsg::Ping(msg), "failed to send ping")
+                .await?;
+
+            receivers.push(receiver);
+        }
+
+        let pongs = async {
+            for receiver in receivers {
+                receiver.await.map_err(MultisigEngineErrorKind::oneshot_receive("ping"))?;
+            }
+
+            Ok::<_, MultisigEngineErrorKind>(())
+        };
+
+        tokio::time::timeout(Self::RUNTIME_PING_TIMEOUT, pongs)
+            .await
+            .map_err(MultisigEngineErrorKind::from)?
+            .map_err(From::from)
+    }
+
+    /// Returns the index of the multisig client runtime worker owning the given account.
+    ///
+    /// All the operations on an account are handled by its owning worker, in the order they were
+    /// requested.
+    pub fn runtime_worker_of(&self, account_id: AccountId) -> usize {
+        multisig_client_runtime::worker_of(account_id, self.runtime.senders.len())
+    }
+
+    /// Stops the multisig client runtime threads and transitions to [`Stopped`] state.
+    ///
+    /// This sends a shutdown message to every runtime worker thread and waits for them to
+    /// terminate gracefully. Once stopped, the engine can no longer perform
+    /// blockchain operations.
+    ///
+    /// Messages queued before the shutdown message are still handled until the configured
+    /// drain timeout elapses, so e.g. a multisig transaction whose threshold was just met still
+    /// gets submitted. The response carries the stopped engine along with a
+    /// [`MultisigClientRuntimeDrainSummary`] telling how many queued messages were drained and how
+    /// many were abandoned, summed over all workers.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The shutdown message cannot be sent
+    /// - A runtime thread panicked or misbehaved
+    /// - A thread join operation fails
+    #[tracing::instrument(skip_all)]
+    pub async fn stop_multisig_client_runtime(
+        self,
+    ) -> Result<StopMultisigClientRuntimeResponse, MultisigEngineError> {
+        for sender in &self.runtime.senders {
+            sender
+                .send(MultisigClientRuntimeMsg::Shutdown)
+                .await
+                .map_err(|_| MultisigEngineErrorKind::mpsc_sender("failed to send shutdown msg"))?;
+        }
+
+        let mut drain_summary = MultisigClientRuntimeDrainSummary::default();
+
+        for handle in self.runtime.handles {
+            let worker_drain_summary = handle
+                .join()
+                .map_err(|_| {
+                    MultisigEngineErrorKind::other("multisig client runtime thread misbehavior")
+                })?
+                .map_err(MultisigEngineErrorKind::from)?;
+
+            drain_summary = drain_summary + worker_drain_summary;
+        }
+
+        let engine = MultisigEngine {
+            network_id: self.network_id,
+            auto_process: self.auto_process,
+            trust_tx_summary: self.trust_tx_summary,
+            max_pending_proposals: self.max_pending_proposals,
+            store: self.store,
+            event_sink: self.event_sink,
+            runtime: Stopped,
+        };
+
+        let response = StopMultisigClientRuntimeResponse::builder()
+            .engine(engine)
+            .drain_summary(drain_summary)
+            .build();
+
+        Ok(response)
+    }
+
+    /// Validates and persists a proposed transaction, see
+    /// [`propose_multisig_tx`](Self::propose_multisig_tx), which records the outcome in the audit
+    /// log.
+    async fn try_propose_multisig_tx(
+        &self,
+        address: AccountIdAddress,
+        tx_request: TransactionRequest,
+        tx_summary: Option<TransactionSummary>,
+        title: Option<String>,
+        memo: Option<String>,
+    ) -> Result<(MultisigTxId, TransactionSummary), MultisigEngineError> {
+        self.ensure_multisig_account_exists(address).await?;
+
+        let tx_summary = match tx_summary {
+            Some(tx_summary) if self.trust_tx_summary => {
+                if tx_summary.account_delta().id() != address.id() {
+                    let err = MultisigEngineErrorKind::TxSummaryMismatch(
+                        "supplied tx summary applies to another account",
+                    );
+                    return Err(err.into());
+                }
+
+                tx_summary
+            },
+            supplied_tx_summary => {
+                self.ensure_sufficient_balance(address, &tx_request).await?;
+
+                let tx_summary = self.compute_tx_summary(address, tx_request.clone()).await?;
+
+                if supplied_tx_summary.is_some_and(|supplied_tx_summary| {
+                    supplied_tx_summary.to_commitment() != tx_summary.to_commitment()
+                }) {
+                    let err = MultisigEngineErrorKind::TxSummaryMismatch(
+                        "supplied tx summary commitment mismatches the computed one",
+                    );
+                    return Err(err.into());
+                }
+
+                tx_summary
+            },
+        };
+
+        let tx_id = self
+            .store
+            .create_multisig_tx(
+                self.network_id(),
+                address,
+                &tx_request,
+                &tx_summary,
+                title.as_deref(),
+                memo.as_deref(),
+                self.max_pending_proposals,
+            )
+            .await
+            .map_err(MultisigEngineErrorKind::from)?;
+
+        Ok((tx_id, tx_summary))
+    }
+
+    /// Dry-runs a failed transaction again and stores its fresh summary, see
+    /// [`repropose_multisig_tx`](Self::repropose_multisig_tx), which records the outcome in the
+    /// audit log.
+    async fn try_repropose_multisig_tx(
+        &self,
+        tx_id: &MultisigTxId,
+    ) -> Result<(AccountIdAddress, TransactionSummary), MultisigEngineError> {
+        let MultisigTxDissolved { address, status, tx_request, .. } = self
+            .store
+            .primary()
+            .get_multisig_tx_by_id(tx_id)
+            .await
+            .map_err(MultisigEngineErrorKind::from)?
+            .ok_or_else(|| MultisigEngineErrorKind::MultisigTxNotFound(tx_id.clone()))?
+            .dissolve();
+
+        if !matches!(status, MultisigTxStatus::Failure) {
+            let err =
+                MultisigEngineErrorKind::MultisigTxNotPending { tx_id: tx_id.clone(), status };
+            return Err(err.into());
+        }
+
+        let (msg, receiver) = {
+            let (sender, receiver) = oneshot::channel();
+
+            let msg = ProposeMultisigTx::builder()
+                .account_id(address.id())
+                .tx_request(tx_request)
+                .sender(sender)
+                .build();
+
+            (MultisigClientRuntimeMsg::ProposeMultisigTx(msg), receiver)
+        };
+
+        self.send_to_multisig_client_runtime(msg, "failed to send propose multisig tx")
+            .await?;
+
+        let tx_summary = receiver
+            .await
+            .map_err(MultisigEngineErrorKind::oneshot_receive("propose multisig tx"))?
+            .map_err(MultisigEngineErrorKind::from)?;
+
+        let reproposal = self
+            .store
+            .repropose_failed_multisig_tx(tx_id, &tx_summary, self.max_pending_proposals)
+            .await;
+
+        match reproposal {
+            Ok(()) => Ok((address, tx_summary)),
+            // the tx left the failure status or was deleted while being dry-run
+            Err(MultisigStoreError::NotFound(_)) => {
+                Err(self.multisig_tx_status_error(tx_id.clone()).await)
+            },
+            Err(err) => Err(MultisigEngineErrorKind::from(err).into()),
+        }
+    }
+
+    /// Returns the error of an operation refused because the transaction doesn't have the status
+    /// it requires, telling apart a transaction with another status from a missing one.
+    async fn multisig_tx_status_error(&self, tx_id: MultisigTxId) -> MultisigEngineError {
+        // the status is read back from the primary, which the refused write was made against
+        let multisig_tx = match self.store.primary().get_multisig_tx_by_id(&tx_id).await {
+            Ok(multisig_tx) => multisig_tx,
+            Err(err) => return MultisigEngineErrorKind::from(err).into(),
+        };
+
+        let err = match multisig_tx {
+            Some(multisig_tx) => MultisigEngineErrorKind::MultisigTxNotPending {
+                status: multisig_tx.dissolve().status,
+                tx_id,
+            },
+            None => MultisigEngineErrorKind::MultisigTxNotFound(tx_id),
+        };
+
+        err.into()
+    }
+
+    /// Checks the approver and stores their signature, see [`add_signature`](Self::add_signature),
+    /// which records the outcome in the audit log. Returns whether the threshold is now met.
+    async fn try_add_signature(
+        &self,
+        tx_id: &MultisigTxId,
+        approver: AccountIdAddress,
+        signature: &ApproverSignature,
+    ) -> Result<bool, MultisigEngineError> {
+        let approver_not_authorized = || MultisigEngineErrorKind::ApproverNotAuthorized {
+            approver: to_bech32(self.network_id(), approver),
+            tx_id: tx_id.clone(),
+        };
+
+        // reject strangers before opening the write transaction, on the primary so that a tx
+        // proposed right before is not reported as missing by a lagging read replica
+        let is_approver = self
+            .store
+            .primary()
+            .is_approver_of_tx(tx_id, self.network_id(), approver)
+            .await
+            .map_err(MultisigEngineErrorKind::from)?
+            .ok_or_else(|| MultisigEngineErrorKind::MultisigTxNotFound(tx_id.clone()))?;
+
+        if !is_approver {
+            return Err(approver_not_authorized().into());
+        }
+
+        self.store
+            .add_multisig_tx_signature(tx_id, self.network_id(), approver, signature)
+            .await
+            .map_err(MultisigEngineErrorKind::from)?
+            .ok_or_else(|| approver_not_authorized().into())
+    }
+
+    /// Returns the error of a batch of signatures refused for holding the signature of an approver
+    /// not authorized to sign the transaction, naming the first such approver, or the error of
+    /// the transaction not existing.
+    async fn unauthorized_approver_error(
+        &self,
+        tx_id: &MultisigTxId,
+        signatures: &[(AccountIdAddress, ApproverSignature)],
+    ) -> MultisigEngineErrorKind {
+        let approver_not_authorized = |approver| MultisigEngineErrorKind::ApproverNotAuthorized {
+            approver: to_bech32(self.network_id(), approver),
+            tx_id: tx_id.clone(),
+        };
+
+        for &(approver, _) in signatures {
+            let is_approver =
+                self.store.primary().is_approver_of_tx(tx_id, self.network_id(), approver).await;
+
+            match is_approver {
+                Ok(Some(true)) => {},
+                Ok(Some(false)) => return approver_not_authorized(approver),
+                Ok(None) => return MultisigEngineErrorKind::MultisigTxNotFound(tx_id.clone()),
+                Err(err) => return MultisigEngineErrorKind::from(err),
+            }
+        }
+
+        // the approvers changed since the batch was refused, it is blamed on its first approver
+        match signatures.first() {
+            Some(&(approver, _)) => approver_not_authorized(approver),
+            None => MultisigEngineErrorKind::MultisigTxNotFound(tx_id.clone()),
+        }
+    }
+
+    /// Ensures the multisig account vault holds enough of every fungible asset paid out by the
+    /// output notes of a transaction request, each asset being checked independently.
+    ///
+    /// Transactions consuming notes are left to the dry-run, as the consumed notes may fund the
+    /// output notes.
+    async fn ensure_sufficient_balance(
+        &self,
+        address: AccountIdAddress,
+        tx_request: &TransactionRequest,
+    ) -> Result<(), MultisigEngineError> {
+        if !tx_request.get_input_note_ids().is_empty() {
+            return Ok(());
+        }
+
+        let mut required_amounts = BTreeMap::<AccountId, u64>::new();
+
+        for note in tx_request.expected_output_own_notes() {
+            for asset in note.assets().iter() {
+                if let Asset::Fungible(fungible_asset) = asset {
+                    let required = required_amounts.entry(fungible_asset.faucet_id()).or_default();
+                    *required = required.saturating_add(fungible_asset.amount());
+                }
+            }
+        }
+
+        if required_amounts.is_empty() {
+            return Ok(());
+        }
+
+        let (msg, receiver) = {
+            let (sender, receiver) = oneshot::channel();
+
+            let msg = GetAccount::builder().account_id(address.id()).sender(sender).build();
+
+            (MultisigClientRuntimeMsg::GetAccount(msg), receiver)
+        };
+
+        self.send_to_multisig_client_runtime(msg, "failed to send get account").await?;
+
+        let account = receiver
+            .await
+            .map_err(MultisigEngineErrorKind::oneshot_receive("get account"))?
+            .ok_or(MultisigEngineErrorKind::not_found("multisig account not found on chain"))?;
+
+        for (faucet_id, required) in required_amounts {
+            let available = account
+                .vault()
+                .get_balance(faucet_id)
+                .map_err(|e| MultisigEngineErrorKind::other(e.to_string()))?;
+
+            if required > available {
+                let err =
+                    MultisigEngineErrorKind::InsufficientBalance { faucet_id, required, available };
+                return Err(err.into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fails with [`MultisigEngineErrorKind::MultisigAccountNotFound`] unless the multisig account
+    /// is stored, so that requests for unknown accounts never reach the runtime thread.
+    async fn ensure_multisig_account_exists(
+        &self,
+        address: AccountIdAddress,
+    ) -> Result<(), MultisigEngineError> {
+        // on the primary so that an account created right before is not reported as missing by a
+        // lagging read replica
+        self.store
+            .primary()
+            .get_multisig_account(self.network_id(), address)
+            .await
+            .map_err(MultisigEngineErrorKind::from)?
+            .ok_or(MultisigEngineErrorKind::MultisigAccountNotFound(address.id()))?;
+
+        Ok(())
+    }
+
+    /// Computes the summary of a transaction request against a stored multisig account by
+    /// dry-running it on the runtime thread, see
+    /// [`ensure_multisig_account_exists`](Self::ensure_multisig_account_exists) for checking the
+    /// account is stored first.
+    async fn compute_tx_summary(
+        &self,
+        address: AccountIdAddress,
+        tx_request: TransactionRequest,
+    ) -> Result<TransactionSummary, MultisigEngineError> {
+        let (msg, receiver) = {
+            let (sender, receiver) = oneshot::channel();
+
+            let msg = ProposeMultisigTx::builder()
+                .account_id(address.id())
+                .tx_request(tx_request)
+                .sender(sender)
+                .build();
+
+            (MultisigClientRuntimeMsg::ProposeMultisigTx(msg), receiver)
+        };
+
+        self.send_to_multisig_client_runtime(msg, "failed to send propose multisig tx")
+            .await?;
+
+        receiver
+            .await
+            .map_err(MultisigEngineErrorKind::oneshot_receive("propose multisig tx"))?
+            .map_err(MultisigEngineErrorKind::from)
+            .map_err(From::from)
+    }
+
+    /// Processes a multisig transaction that just met its threshold, or marks it as ready to
+    /// execute if automatic processing is disabled.
+    async fn on_threshold_met(
+        &self,
+        tx_id: &MultisigTxId,
+    ) -> Result<Option<TransactionResult>, MultisigEngineError> {
+        if self.auto_process {
+            return self.process_multisig_tx(tx_id).await.map(Some);
+        }
+
+        self.store
+            .update_multisig_tx_status_by_id(tx_id, MultisigTxStatus::ReadyToExecute)
+            .await
+            .map_err(MultisigEngineErrorKind::from)?;
+
+        Ok(None)
+    }
+
+    /// Collects the signatures of a multisig transaction that met its threshold, has it executed
+    /// and submitted by the runtime thread, then updates its status accordingly, recording the
+    /// on-chain id of the executed transaction on success.
+    ///
+    /// The stored approver public keys, if any, are handed over too, in case the runtime can't
+    /// read them from the account storage.
+    async fn process_multisig_tx(
+        &self,
+        tx_id: &MultisigTxId,
+    ) -> Result<TransactionResult, MultisigEngineError> {
+        let (signatures, multisig_tx) = self
+            .store
+            .get_signatures_of_all_approvers_with_multisig_tx_by_tx_id(tx_id)
+            .await
+            .map_err(MultisigEngineErrorKind::from)?;
+
+        let MultisigTxDissolved {
+            address,
+            tx_request,
+            tx_summary,
+            threshold,
+            ..
+        } = multisig_tx.dissolve();
+
+        let approver_pub_keys = self
+            .store
+            .get_approver_pub_keys_in_order(self.network_id(), address)
+            .await
+            .map_err(MultisigEngineErrorKind::from)?;
+
+        let (msg, receiver) = {
+            let (sender, receiver) = oneshot::channel();
+
+            let msg = ProcessMultisigTx::builder()
+                .account_id(address.id())
+                .tx_request(tx_request)
+                .tx_summary(tx_summary)
+                .signatures(signatures.into_iter().map(|s| s.map(|(sig, _)| sig)).collect())
+                .maybe_approver_pub_keys(approver_pub_keys)
+                .threshold(threshold)
+                .sender(sender)
+                .build();
+
+            (MultisigClientRuntimeMsg::ProcessMultisigTx(msg), receiver)
+        };
+
+        self.send_to_multisig_client_runtime(msg, "failed to send process multisig tx")
+            .await?;
+
+        let tx_result = receiver
+            .await
+            .map_err(MultisigEngineErrorKind::oneshot_receive("process multisig tx"))?;
+
+        match tx_result {
+            Ok(tx_result) => {
+                let executed_tx = tx_result.executed_transaction();
+
+                self.store
+                    .update_multisig_tx_status_to_success_by_id(
+                        tx_id,
+                        executed_tx.id(),
+                        executed_tx.block_header().block_num(),
+                        executed_tx.final_account().nonce().as_int(),
+                    )
+                    .await
+                    .map_err(MultisigEngineErrorKind::from)?;
+
+                let subject = AuditSubject::Tx(tx_id.clone());
+                self.audit(subject, None, AuditAction::ExecuteTx, Ok(())).await;
+
+                let event = CoordinatorEvent::TxExecuted {
+                    tx_id: tx_id.clone(),
+                    chain_tx_id: executed_tx.id(),
+                };
+                self.event_sink.emit(event).await;
+
+                Ok(tx_result)
+            },
+            Err(e) => {
+                let err = MultisigEngineError::from(MultisigEngineErrorKind::from(e));
+                let reason = err.to_string();
+
+                self.store
+                    .update_multisig_tx_status_to_failure_by_id(tx_id, &reason)
+                    .await
+                    .map_err(MultisigEngineErrorKind::from)?;
+
+                let subject = AuditSubject::Tx(tx_id.clone());
+                self.audit(subject, None, AuditAction::ExecuteTx, Err(&reason)).await;
+
+                let event = CoordinatorEvent::TxFailed { tx_id: tx_id.clone(), reason };
+                self.event_sink.emit(event).await;
+
+                Err(err)
+            },
+        }
+    }
+
+    /// Records an operation in the audit log, `Err` carrying why it was rejected.
+    ///
+    /// Recording is best effort: a failure is logged and otherwise ignored, so that the audit log
+    /// never blocks the operation it records.
+    async fn audit(
+        &self,
+        subject: AuditSubject,
+        actor: Option<AccountIdAddress>,
+        action: AuditAction,
+        result: Result<(), &dyn core::fmt::Display>,
+    ) {
+        let (outcome, detail) = match result {
+            Ok(()) => (AuditOutcome::Accepted, None),
+            Err(reason) => (AuditOutcome::Rejected, Some(reason.to_string())),
+        };
+
+        if let Err(e) = self
+            .store
+            .record_audit_event(
+                self.network_id(),
+                &subject,
+                actor,
+                action,
+                outcome,
+                detail.as_deref(),
+            )
+            .await
+        {
+            tracing::warn!("failed to record {outcome} {action} in the audit log: {e}");
+        }
+    }
+
+    /// Fetches the stored view of a multisig account, along with its on-chain state through the
+    /// runtime thread.
+    async fn get_stored_and_on_chain_multisig_account(
+        &self,
+        multisig_account_id_address: AccountIdAddress,
+    ) -> Result<(MultisigAccount<WithApprovers, WithPubKeyCommits>, Account), MultisigEngineErrorKind>
+    {
+        let (msg, receiver) = {
+            let (sender, receiver) = oneshot::channel();
+
+            let msg = GetAccount::builder()
+                .account_id(multisig_account_id_address.id())
+                .sender(sender)
+                .build();
+
+            (MultisigClientRuntimeMsg::GetAccount(msg), receiver)
+        };
+
+        self.send_to_multisig_client_runtime(msg, "failed to send get account").await?;
+
+        let multisig_account = self
+            .store
+            .try_get_multisig_account(self.network_id(), multisig_account_id_address)
+            .await
+            .map_err(MultisigEngineErrorKind::from)?
+            .ok_or(MultisigEngineErrorKind::MultisigAccountNotFound(
+                multisig_account_id_address.id(),
+            ))?;
+
+        let account = receiver
+            .await
+            .map_err(MultisigEngineErrorKind::oneshot_receive("get account"))?
+            .ok_or(MultisigEngineErrorKind::not_found("multisig account not found on chain"))?;
+
+        Ok((multisig_account, account))
+    }
+
+    /// Hands an account its owning runtime worker couldn't import from the node over to it, from
+    /// the first other worker tracking it. An account no worker tracks is left untracked, as
+    /// there is nothing to hand over.
+    async fn hand_over_multisig_account(
+        &self,
+        account_id: AccountId,
+    ) -> Result<(), MultisigEngineErrorKind> {
+        let owner = self.runtime_worker_of(account_id);
+
+        for worker in (0..self.runtime.senders.len()).filter(|&worker| worker != owner) {
+            let (sender, receiver) = oneshot::channel();
+
+            let msg =
+                ExportMultisigAccount::builder().account_id(account_id).sender(sender).build();
+            let msg = MultisigClientRuntimeMsg::ExportMultisigAccount(msg);
+            self.runtime.send(worker, msg, "failed to send export multisig account").await?;
+
+            let exported = receiver
+                .await
+                .map_err(MultisigEngineErrorKind::oneshot_receive("export multisig account"))?;
+
+            let Some((account, seed)) = exported else {
+                continue;
+            };
+
+            let (sender, receiver) = oneshot::channel();
+
+            let msg = TrackMultisigAccount::builder()
+                .account(account)
+                .maybe_seed(seed)
+                .sender(sender)
+                .build();
+
+            let msg = MultisigClientRuntimeMsg::TrackMultisigAccount(msg);
+            self.runtime.send(owner, msg, "failed to send track multisig account").await?;
+
+            receiver
+                .await
+                .map_err(MultisigEngineErrorKind::oneshot_receive("track multisig account"))?;
+
+            tracing::info!(%account_id, from = worker, to = owner, "handed over multisig account");
+            return Ok(());
+        }
+
+        tracing::error!(%account_id, "multisig account is tracked by no runtime worker");
+        Ok(())
+    }
+
+    /// Sends a msg to the runtime worker owning the account it operates on, or to the first
+    /// worker if it doesn't operate on any account.
+    ///
+    /// `err` describes the failure if the worker is not running anymore.
+    async fn send_to_multisig_client_runtime(
+        &self,
+        msg: MultisigClientRuntimeMsg,
+        err: &'static str,
+    ) -> Result<(), MultisigEngineErrorKind> {
+        let worker = msg.account_id().map_or(0, |account_id| self.runtime_worker_of(account_id));
+
+        self.runtime.send(worker, msg, err).await
+    }
+}
+
+/// Selects the ids of the notes to consume among the consumable notes of an account.
+///
+/// The [`NoteSelector::UpToAmount`] selector picks the notes carrying the largest amounts first,
+/// which reaches the amount with the fewest notes.
+///
+/// # Errors
+///
+/// - When no note is selected, or a selected note is not consumable
+/// - When the consumable notes carry less than the selected amount
+fn select_notes(
+    notes: &[InputNoteRecord],
+    selector: NoteSelector,
+) -> Result<Vec<NoteId>, MultisigEngineErrorKind> {
+    let note_ids = match selector {
+        NoteSelector::All => notes.iter().map(InputNoteRecord::id).collect(),
+        NoteSelector::NoteIds(note_ids) => {
+            let unknown_note_id =
+                note_ids.iter().find(|&&note_id| notes.iter().all(|note| note.id() != note_id));
+
+            if let Some(note_id) = unknown_note_id {
+                let err = format!("note {note_id} is not consumable by the account");
+                return Err(MultisigEngineErrorKind::InvalidNoteSelection(err.into()));
+            }
+
+            note_ids
+        },
+        NoteSelector::UpToAmount { faucet_id, amount } => {
+            let mut note_amounts: Vec<_> = notes
+                .iter()
+                .map(|note| {
+                    let note_amount = note
+                        .assets()
+                        .iter()
+                        .filter_map(|asset| match asset {
+                            Asset::Fungible(asset) if asset.faucet_id() == faucet_id => {
+                                Some(asset.amount())
+                            },
+                            _ => None,
+                        })
+                        .sum::<u64>();
+
+                    (note.id(), note_amount)
+                })
+                .filter(|&(_, note_amount)| note_amount > 0)
+                .collect();
+
+            note_amounts.sort_by_key(|&(_, note_amount)| Reverse(note_amount));
+
+            let mut total = 0_u64;
+            let mut note_ids = Vec::new();
+
+            for (note_id, note_amount) in note_amounts {
+                if total >= amount {
+                    break;
+                }
+
+                total = total.saturating_add(note_amount);
+                note_ids.push(note_id);
+            }
+
+            if total < amount {
+                return Err(MultisigEngineErrorKind::InvalidNoteSelection(
+                    "consumable notes carry less than the selected amount".into(),
+                ));
+            }
+
+            note_ids
+        },
+    };
+
+    if note_ids.is_empty() {
+        return Err(MultisigEngineErrorKind::InvalidNoteSelection(
+            "no consumable note selected".into(),
+        ));
+    }
+
+    Ok(note_ids)
+}
+
+/// Tells whether a committed transaction is the execution of the transaction with the given
+/// summary, i.e. whether it consumed the same input notes and created the same output notes.
+///
+/// A transaction without any input or output note can't be told apart from another one by its
+/// notes, so it never matches.
+fn is_execution_of(committed_tx: &TransactionRecord, tx_summary: &TransactionSummary) -> bool {
+    let input_notes = tx_summary.input_notes();
+    let output_notes = tx_summary.output_notes();
+
+    if input_notes.num_notes() == 0 && output_notes.num_notes() == 0 {
+        return false;
+    }
+
+    let nullifiers = input_notes.iter().map(|input_note| input_note.nullifier().as_word());
+
+    committed_tx.details.input_note_nullifiers.iter().copied().eq(nullifiers)
+        && committed_tx.details.output_notes.commitment() == output_notes.commitment()
+}
+
+impl Started {
+    /// Queues a msg for the given runtime worker.
+    ///
+    /// If the queue of the worker is full, this waits for room for up to the configured enqueue
+    /// timeout, after which it fails as overloaded. `err` describes the failure if the worker is
+    /// not running anymore.
+    async fn send(
+        &self,
+        worker: usize,
+        msg: MultisigClientRuntimeMsg,
+        err: &'static str,
+    ) -> Result<(), MultisigEngineErrorKind> {
+        self.senders[worker]
+            .send_timeout(msg, self.enqueue_timeout)
+            .await
+            .map_err(|e| match e {
+                SendTimeoutError::Timeout(_) => {
+                    MultisigEngineErrorKind::Overloaded(self.enqueue_timeout)
+                },
+                SendTimeoutError::Closed(_) => MultisigEngineErrorKind::mpsc_sender(err),
+            })
+    }
+}
+
+/// Hex-encodes the byte representation of an approver key, as reported in a [`Drift`].
+fn approver_key_to_hex(approver_key: &ApproverKey) -> String {
+    format!("0x{}", hex::encode(approver_key.to_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use miden_client::{
+        Felt, Word,
+        account::{AccountId, AccountIdAddress, AccountStorageMode, AddressInterface, NetworkId},
+        asset::FungibleAsset,
+        crypto::RpoRandomCoin,
+        note::{NoteType, create_p2id_note},
+        store::InputNoteRecord,
+    };
+    use miden_multisig_coordinator_domain::{
+        account::MultisigAccount, key::ApproverKey, tx::TxSort,
+    };
+    use miden_multisig_test_utils::{
+        InMemoryMultisigStore, account_id_address, empty_tx_summary, pay_to_id_tx_request,
+    };
+    use miden_objects::{
+        block::BlockNumber,
+        crypto::dsa::rpo_falcon512::SecretKey,
+        testing::account_id::{
+            ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET, ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_1,
+            ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
+            ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE, ACCOUNT_ID_SENDER,
+        },
+    };
+    use tokio::sync::{mpsc, oneshot};
+
+    use super::{
+        DryRunMultisigTxRequest, Duration, MultisigClientRuntimeMsg, MultisigEngine,
+        MultisigEngineErrorKind, MultisigStoreBackend, NonZeroU32, NoopEventSink, NoteSelector,
+        Ping, ProposeConsumeNotesRequest, Started, multisig_client_runtime::msg::ConsumableNotes,
+    };
+
+    fn make_fungible_note(
+        rng: &mut RpoRandomCoin,
+        faucet_id: u128,
+        amount: u64,
+    ) -> InputNoteRecord {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let target = AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+        let asset = FungibleAsset::new(faucet_id.try_into().unwrap(), amount).unwrap();
+
+        create_p2id_note(sender, target, vec![asset.into()], NoteType::Public, Felt::new(0), rng)
+            .unwrap()
+            .into()
+    }
+
+    /// Makes an engine whose runtime is a single worker receiving msgs from `sender`, so that
+    /// tests can stand in for the runtime.
+    fn engine_on_runtime(
+        sender: mpsc::Sender<MultisigClientRuntimeMsg>,
+    ) -> MultisigEngine<Started> {
+        MultisigEngine {
+            network_id: NetworkId::Testnet,
+            auto_process: true,
+            trust_tx_summary: false,
+            max_pending_proposals: None,
+            store: Arc::new(InMemoryMultisigStore::new()),
+            event_sink: Arc::new(NoopEventSink),
+            runtime: Started {
+                senders: vec![sender],
+                handles: Vec::new(),
+                enqueue_timeout: Duration::from_millis(10),
+            },
+        }
+    }
+
+    #[test]
+    fn selecting_notes_up_to_amount_picks_fewest_notes_covering_amount() {
+        // Arrange
+        let mut rng = RpoRandomCoin::new(Word::default());
+
+        let notes = vec![
+            make_fungible_note(&mut rng, ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET, 30),
+            make_fungible_note(&mut rng, ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET, 50),
+            make_fungible_note(&mut rng, ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET_1, 500),
+            make_fungible_note(&mut rng, ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET, 10),
+            make_fungible_note(&mut rng, ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET, 40),
+        ];
+
+        let up_to = |amount| NoteSelector::UpToAmount {
+            faucet_id: ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET.try_into().unwrap(),
+            amount,
+        };
+
+        // Act
+        let single = super::select_notes(&notes, up_to(45)).unwrap();
+        let pair = super::select_notes(&notes, up_to(90)).unwrap();
+        let insufficient = super::select_notes(&notes, up_to(131));
+
+        // Assert
+        assert_eq!(single, [notes[1].id()]);
+        assert_eq!(pair, [notes[1].id(), notes[4].id()]);
+        assert!(matches!(insufficient, Err(MultisigEngineErrorKind::InvalidNoteSelection(_))));
+    }
+
+    #[tokio::test]
+    async fn sending_to_full_runtime_queue_fails_as_overloaded_after_enqueue_timeout() {
+        // Arrange
+        let (sender, _receiver) = mpsc::channel(1);
+
+        let runtime = Started {
+            senders: vec![sender],
+            handles: Vec::new(),
+            enqueue_timeout: Duration::from_millis(10),
+        };
+
+        runtime
+            .send(0, MultisigClientRuntimeMsg::Shutdown, "failed to send")
+            .await
+            .unwrap();
+
+        // Act
+        let overloaded =
+            runtime.send(0, MultisigClientRuntimeMsg::Shutdown, "failed to send").await;
+
+        // Assert
+        assert!(matches!(overloaded, Err(MultisigEngineErrorKind::Overloaded(_))));
+    }
+
+    #[tokio::test]
+    async fn sending_to_stopped_runtime_fails_as_mpsc_sender_error() {
+        // Arrange
+        let (sender, receiver) = mpsc::channel(1);
+        drop(receiver);
+
+        let runtime = Started {
+            senders: vec![sender],
+            handles: Vec::new(),
+            enqueue_timeout: Duration::from_millis(10),
+        };
+
+        // Act
+        let closed = runtime.send(0, MultisigClientRuntimeMsg::Shutdown, "failed to send").await;
+
+        // Assert
+        assert!(matches!(closed, Err(MultisigEngineErrorKind::MpscSender(_))));
+    }
+
+    #[tokio::test]
+    async fn runtime_dropping_msg_mid_flight_fails_with_error_naming_its_operation() {
+        // Arrange
+        let (sender, mut receiver) = mpsc::channel(1);
+
+        let runtime = Started {
+            senders: vec![sender],
+            handles: Vec::new(),
+            enqueue_timeout: Duration::from_millis(10),
+        };
+
+        // a runtime giving up on a msg drops it along with its response sender
+        tokio::spawn(async move { drop(receiver.recv().await) });
+
+        let (pong_sender, pong_receiver) = oneshot::channel();
+        let msg = MultisigClientRuntimeMsg::Ping(Ping::builder().sender(pong_sender).build());
+
+        runtime.send(0, msg, "failed to send ping").await.unwrap();
+
+        // Act
+        let err = pong_receiver
+            .await
+            .map_err(MultisigEngineErrorKind::oneshot_receive("ping"))
+            .unwrap_err();
+
+        // Assert
+        assert!(matches!(err, MultisigEngineErrorKind::OneshotReceive { operation: "ping", .. }));
+        assert!(err.to_string().contains("no response to ping"));
+    }
+
+    #[tokio::test]
+    async fn readiness_check_and_ping_fail_when_runtime_is_down() {
+        // Arrange
+        let (sender, receiver) = mpsc::channel(1);
+
+        // a runtime whose thread exited, e.g. after failing to initialize, closed its channel
+        drop(receiver);
+
+        let engine = engine_on_runtime(sender);
+
+        // Act
+        let readiness = engine.check_readiness().await;
+        let ping = engine.ping_runtime().await;
+
+        // Assert
+        assert!(readiness.is_err());
+        assert!(ping.is_err());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn ping_fails_when_runtime_does_not_answer_in_time() {
+        // Arrange
+        let (sender, mut receiver) = mpsc::channel(1);
+
+        // a runtime stuck on a long operation holds the ping without answering it
+        tokio::spawn(async move {
+            let _held = receiver.recv().await;
+            core::future::pending::<()>().await;
+        });
+
+        let engine = engine_on_runtime(sender);
+
+        // Act
+        let ping = engine.ping_runtime().await;
+
+        // Assert
+        assert!(ping.unwrap_err().is_timeout());
+    }
+
+    #[tokio::test]
+    async fn proposing_to_consume_notes_fails_when_sync_failed_and_listing_is_stale() {
+        // Arrange
+        let (sender, mut receiver) = mpsc::channel(1);
+
+        // a runtime failing to sync with the node lists the notes of its last synced block as stale
+        tokio::spawn(async move {
+            while let Some(msg) = receiver.recv().await {
+                if let MultisigClientRuntimeMsg::GetConsumableNotes(msg) = msg {
+                    let consumable_notes = ConsumableNotes::builder()
+                        .notes(Vec::new())
+                        .block_num(BlockNumber::from(7))
+                        .stale(true)
+                        .build();
+
+                    let _ = msg.dissolve().sender.send(consumable_notes);
+                }
+            }
+        });
+
+        let engine = engine_on_runtime(sender);
+
+        let address = AccountIdAddress::new(
+            ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE.try_into().unwrap(),
+            AddressInterface::BasicWallet,
+        );
+
+        let request = ProposeConsumeNotesRequest::builder()
+            .address(address)
+            .selector(NoteSelector::All)
+            .build();
+
+        // Act
+        let err = engine.propose_consume_notes(request).await.unwrap_err();
+
+        // Assert
+        assert_eq!(err.stale_consumable_notes(), Some(BlockNumber::from(7)));
+    }
+
+    #[tokio::test]
+    async fn dry_run_returns_summary_computed_by_runtime_without_persisting_tx() {
+        // Arrange
+        let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+        let unknown_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE);
+        let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+
+        let tx_summary = empty_tx_summary(multisig_addr.id());
+
+        let (sender, mut receiver) = mpsc::channel(1);
+
+        // a runtime dry-running every tx request into the same summary
+        let computed_tx_summary = tx_summary.clone();
+        tokio::spawn(async move {
+            while let Some(msg) = receiver.recv().await {
+                if let MultisigClientRuntimeMsg::ProposeMultisigTx(msg) = msg {
+                    let _ = msg.dissolve().sender.send(Ok(computed_tx_summary.clone()));
+                }
+            }
+        });
+
+        let engine = engine_on_runtime(sender);
+
+        let multisig_account = MultisigAccount::builder()
+            .address(multisig_addr)
+            .network_id(NetworkId::Testnet)
+            .kind(AccountStorageMode::Public)
+            .threshold(NonZeroU32::MIN)
+            .aux(())
+            .build()
+            .with_approvers(vec![alice_addr])
+            .unwrap()
+            .with_pub_key_commits(vec![ApproverKey::Falcon(SecretKey::new().public_key())])
+            .unwrap();
+
+        engine.store.create_multisig_account(multisig_account).await.unwrap();
+
+        let request = |address: AccountIdAddress| {
+            DryRunMultisigTxRequest::builder()
+                .address(address)
+                .tx_request(pay_to_id_tx_request(address.id(), alice_addr.id()))
+                .build()
+        };
+
+        // Act
+        let dry_run = engine.dry_run_multisig_tx(request(multisig_addr)).await.unwrap();
+        let unknown = engine.dry_run_multisig_tx(request(unknown_addr)).await.unwrap_err();
+
+        // Assert
+        assert_eq!(dry_run.dissolve().tx_summary.to_commitment(), tx_summary.to_commitment());
+        assert_eq!(unknown.multisig_account_not_found(), Some(unknown_addr.id()));
+
+        let (txs, _) = engine
+            .store
+            .get_txs_by_multisig_account_address_with_status_filter(
+                NetworkId::Testnet,
+                multisig_addr,
+                None,
+                TxSort::default(),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert!(txs.is_empty(), "a dry-run must not propose the tx");
     }
 }
@@ -14,28 +14,71 @@
 //! ┌───────────────────────┐         ┌───────────────────────────────┐
 //! │ MultisigEngine        │         │ MultisigClient (!Send + !Sync)│
 //! │                       │         │                               │
-//! │ mpsc::UnboundedSender ┼─────────│──> mpsc::UnboundedReceiver    │
+//! │ mpsc::Sender          ┼─────────│──> mpsc::Receiver             │
 //! │                       │         │                               │
 //! │ oneshot::Receiver <───┼─────────┤─── oneshot::Sender            │
 //! └───────────────────────┘         └───────────────────────────────┘
 //! ```
 //!
-//! 1. A [`MultisigClientRuntimeMsg`] is sent from an external thread using a
-//!    [`mpsc::UnboundedSender`].
-//! 2. The runtime thread receives the message through the [`mpsc::UnboundedReceiver`].
+//! 1. A [`MultisigClientRuntimeMsg`] is sent from an external thread using a bounded
+//!    [`mpsc::Sender`], waiting for room in the queue if it is full.
+//! 2. The runtime thread receives the message through the [`mpsc::Receiver`].
 //! 3. The runtime performs the blockchain operation using the [`MultisigClient`].
 //! 4. The runtime sends the result back via the [`oneshot::Sender`] that was sent in the
 //!    [`MultisigClientRuntimeMsg`].
 //!
+//! A msg whose [`oneshot::Receiver`] was dropped by the time it is handled, e.g. because the
+//! request that sent it was cancelled, is skipped rather than handled for nobody. Conversely, a
+//! msg dropped by the runtime without a response fails the engine with an error naming its
+//! operation.
+//!
+//! ## Workers
+//!
+//! The runtime may be spread over several worker threads, see
+//! [`MultisigClientRuntimeConfig`]. Each worker runs its own [`MultisigClient`] with its own
+//! store, and tracks a shard of the multisig accounts, see [`worker_of`]. A msg operating on an
+//! account is sent to the worker owning that account, so:
+//!
+//! - The msgs of a given account are handled one at a time, in the order they were sent
+//! - The msgs of accounts owned by different workers are handled in parallel, without any
+//!   ordering between them
+//!
+//! Multisig accounts are always built by the first worker, without being tracked there, and then
+//! handed over to their owning worker with a [`MultisigClientRuntimeMsg::TrackMultisigAccount`].
+//! Multisig accounts created elsewhere are fetched from the node by their owning worker with a
+//! [`MultisigClientRuntimeMsg::FetchMultisigAccount`], which doesn't track them, and only tracked
+//! once imported, with a [`MultisigClientRuntimeMsg::TrackMultisigAccount`].
+//!
+//! On startup, each worker imports from the node the accounts it owns and doesn't track yet. An
+//! account not on chain yet can't be imported, e.g. when the number of workers changed since it
+//! was created, so it is exported from the worker tracking it with a
+//! [`MultisigClientRuntimeMsg::ExportMultisigAccount`] and handed over to its owning worker. The
+//! former worker keeps its copy, which is no longer sent any msg.
+//!
+//! Each [`MultisigClientRuntimeMsg`] also carries the [`Span`] that was current when it was built.
+//! The runtime re-enters that span while handling the msg, so logs emitted on the runtime thread
+//! carry the same fields (e.g. `tx_id`, or the `correlation_id` the coordinator server attaches
+//! to every request) as the request that originated them.
+//!
+//! ## Shutdown
+//!
+//! On [`MultisigClientRuntimeMsg::Shutdown`] the runtime stops accepting new msgs but keeps
+//! handling the already queued ones until the configured drain timeout elapses, so that e.g. a
+//! multisig tx whose threshold was just met still gets submitted. Msgs still queued after the
+//! timeout are abandoned, which drops their [`oneshot::Sender`]. The thread returns a
+//! [`MultisigClientRuntimeDrainSummary`] counting both.
+//!
 //! ## Thread Safety
 //!
 //! The runtime ensures thread safety by:
-//! - Running the `!Send + !Sync` client on a single dedicated thread
+//! - Running each `!Send + !Sync` client on its own dedicated thread
 //! - Using [`LocalSet`] to prevent the tokio runtime from moving tasks across threads
 //! - Communicating only via thread-safe channels (`mpsc` and `oneshot`)
 //!
 //! [`MultisigClient`]: miden_multisig_client::MultisigClient
 //! [`LocalSet`]: tokio::task::LocalSet
+//! [`oneshot::Sender`]: tokio::sync::oneshot::Sender
+//! [`oneshot::Receiver`]: tokio::sync::oneshot::Receiver
 
 pub mod msg;
 
@@ -43,42 +86,69 @@ mod error;
 
 pub use self::error::MultisigClientRuntimeError;
 
-use core::time::Duration;
+use core::{fmt, future, num::NonZeroUsize, ops::Add, time::Duration};
 
 use std::{
-    path::PathBuf,
+    path::{Path, PathBuf},
     sync::Arc,
     thread::{self, JoinHandle},
+    time::Instant,
 };
 
 use bon::Builder;
 use miden_client::{
-    account::AccountIdAddress, auth::TransactionAuthenticator, builder::ClientBuilder,
+    account::{Account, AccountId, AccountIdAddress},
+    auth::TransactionAuthenticator,
+    builder::ClientBuilder,
     keystore::FilesystemKeyStore,
+    note::{NoteConsumability, NoteFile, NoteTag},
+    rpc::{NodeRpcClient, TonicRpcClient},
+    store::{InputNoteRecord, TransactionFilter},
+    transaction::{TransactionRequest, TransactionRequestBuilder, TransactionStatus},
 };
-use miden_multisig_client::MultisigClient;
-use tokio::{runtime::Runtime, sync::mpsc, task::LocalSet};
+use miden_multisig_client::{MultisigClient, MultisigClientError, MultisigConfig, SignatureFelts};
+use miden_multisig_coordinator_domain::key::ApproverSignature;
+use tokio::{
+    runtime::Builder as RuntimeBuilder,
+    sync::{mpsc, oneshot},
+    task::LocalSet,
+    time::{self, Interval, MissedTickBehavior},
+};
+use tracing::{Instrument, Span};
 use url::Url;
 
 use self::{
     error::Result,
     msg::{
-        CreateMultisigAccount, CreateMultisigAccountDissolved, GetConsumableNotes,
-        GetConsumableNotesDissolved, MultisigClientRuntimeMsg, ProcessMultisigTx,
-        ProcessMultisigTxDissolved, ProposeMultisigTx, ProposeMultisigTxDissolved,
+        BuildPaymentTxRequest, BuildPaymentTxRequestDissolved, ConsumableNotes,
+        CreateMultisigAccount, CreateMultisigAccountDissolved, ExportMultisigAccount,
+        ExportMultisigAccountDissolved, FetchMultisigAccount, FetchMultisigAccountDissolved,
+        GetAccount, GetAccountDissolved, GetCommittedTxs, GetCommittedTxsDissolved,
+        GetConsumableNotes, GetConsumableNotesDissolved, MultisigClientRuntimeMsg, PingDissolved,
+        ProcessMultisigTx, ProcessMultisigTxDissolved, ProposeMultisigTx,
+        ProposeMultisigTxDissolved, ProposeMultisigTxError, TrackMultisigAccount,
+        TrackMultisigAccountDissolved,
     },
 };
 
-/// Spawns a new multisig client runtime thread.
+/// Spawns a new multisig client runtime worker thread.
 ///
 /// This function creates a dedicated thread that runs the [`MultisigClient`] using a tokio
-/// [`LocalSet`]. The thread listens for messages on the provided channel and processes
-/// them using the [`MultisigClient`].
+/// [`LocalSet`] on its own current thread tokio runtime. The thread listens for messages on the
+/// provided channel and processes them using the [`MultisigClient`].
 ///
 /// # Returns
 ///
 /// A [`JoinHandle`] for the spawned thread, which can be used to wait for thread completion
-/// or detect panics.
+/// or detect panics. On completion the thread yields a summary of the msgs drained and abandoned
+/// during shutdown.
+///
+/// Along with it, a [`oneshot::Receiver`] resolving once the [`MultisigClient`] is initialized,
+/// i.e. built, synced with the node, and tracking the given multisig accounts. It yields the ids
+/// of the accounts that couldn't be imported from the node, e.g. because they are not on chain
+/// yet, or the initialization error if any, in which case the thread exits without handling any
+/// msg. It is closed without a value if the thread failed before even starting the
+/// initialization.
 ///
 /// # Thread Lifecycle
 ///
@@ -91,21 +161,50 @@ use self::{
 /// [`LocalSet`]: tokio::task::LocalSet
 #[tracing::instrument(skip_all, fields(?config))]
 pub fn spawn_new<A>(
-    rt: Runtime,
-    msg_receiver: mpsc::UnboundedReceiver<MultisigClientRuntimeMsg>,
+    msg_receiver: mpsc::Receiver<MultisigClientRuntimeMsg>,
     tracking_multisig_accounts: A,
     config: MultisigClientRuntimeConfig,
-) -> JoinHandle<Result<()>>
+) -> (
+    JoinHandle<Result<MultisigClientRuntimeDrainSummary>>,
+    oneshot::Receiver<Result<Vec<AccountId>>>,
+)
 where
     A: Iterator<Item = AccountIdAddress> + Send + 'static,
 {
-    thread::spawn(move || {
+    let (startup_sender, startup_receiver) = oneshot::channel();
+
+    let handle = thread::spawn(move || {
+        let rt = RuntimeBuilder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| MultisigClientRuntimeError::other(e.to_string()))?;
+
         let local = LocalSet::new();
-        let fut = run_multisig_client_runtime(msg_receiver, tracking_multisig_accounts, config);
+        let fut = run_multisig_client_runtime(
+            msg_receiver,
+            tracking_multisig_accounts,
+            config,
+            startup_sender,
+        );
         let local_runtime = local.run_until(fut);
         rt.block_on(local_runtime)
             .inspect_err(|e| tracing::error!("failed to run multisig client runtime: {e}"))
-    })
+    });
+
+    (handle, startup_receiver)
+}
+
+/// Returns the index of the worker owning the given account, out of `workers` workers.
+///
+/// The upper half of an account id prefix is derived from a hash, so accounts are spread evenly
+/// across workers, and a given account is always owned by the same worker for a given number of
+/// workers.
+///
+/// # Panics
+///
+/// When `workers` is zero.
+pub(crate) fn worker_of(account_id: AccountId, workers: usize) -> usize {
+    ((account_id.prefix().as_u64() >> 32) % workers as u64) as usize
 }
 
 /// Configuration for the multisig client runtime.
@@ -118,47 +217,271 @@ where
 /// * `store_path` - Path to the database for multisig client state
 /// * `keystore_path` - Path to the filesystem keystore for cryptographic keys
 /// * `timeout` - Network request timeout duration
-#[derive(Debug, Builder)]
+/// * `shutdown_drain_timeout` - How long to keep handling queued msgs after a shutdown msg
+/// * `workers` - The number of runtime worker threads, one by default
+/// * `note_import_limit` - The most input notes imported before dry-running a proposal, none if
+///   not set
+/// * `sync_interval` - How often each worker syncs its client with the node while idle, never if
+///   not set or zero
+/// * `queue_capacity` - The most msgs queued for each worker, 1024 by default
+/// * `enqueue_timeout` - How long sending a msg waits for room in a full queue, 5 seconds by
+///   default
+/// * `rpc_client` - The node RPC client shared by all workers, connecting to `node_url` if not
+///   set
+///
+/// The first worker stores its state at `store_path`, the other ones at `store_path` with the
+/// worker index appended to the file stem, e.g. `store-1.sqlite3`. All workers share the keystore.
+///
+/// A proposal may consume notes the multisig client never synced, e.g. notes sent to the
+/// multisig account before it was tracked, which fails the dry-run. If a `note_import_limit` is
+/// set, the input notes of a proposal unknown to the client are imported by id beforehand, and
+/// proposals referencing more unknown notes than the limit are rejected.
+///
+/// Operations sync the client when they need fresh state, so without a `sync_interval` notes
+/// received since the last operation of a worker only show up after its next operation. With a
+/// `sync_interval`, each worker also syncs periodically, skipping the sync while msgs are queued
+/// as handling them syncs anyway.
+///
+/// The msg queue of each worker holds up to `queue_capacity` msgs, so a burst of operations
+/// cannot grow it unboundedly while the worker falls behind. Sending a msg to a full queue waits
+/// for room for up to `enqueue_timeout`, after which the operation fails as overloaded.
+///
+/// Setting an `rpc_client` replaces the connection to `node_url`, e.g. with a mock node so that
+/// the runtime runs offline in tests.
+#[derive(Debug, Clone, Builder)]
 pub struct MultisigClientRuntimeConfig {
     node_url: Url,
     store_path: PathBuf,
     keystore_path: PathBuf,
     timeout: Duration,
+    shutdown_drain_timeout: Duration,
+
+    #[builder(default = NonZeroUsize::MIN)]
+    workers: NonZeroUsize,
+
+    note_import_limit: Option<NonZeroUsize>,
+    sync_interval: Option<Duration>,
+
+    #[builder(default = Self::DEFAULT_QUEUE_CAPACITY)]
+    queue_capacity: NonZeroUsize,
+
+    #[builder(default = Self::DEFAULT_ENQUEUE_TIMEOUT)]
+    enqueue_timeout: Duration,
+
+    rpc_client: Option<SharedNodeRpcClient>,
+}
+
+impl MultisigClientRuntimeConfig {
+    /// The default most msgs queued for each worker.
+    pub const DEFAULT_QUEUE_CAPACITY: NonZeroUsize = NonZeroUsize::new(1024).unwrap();
+
+    /// The default duration sending a msg waits for room in a full queue.
+    pub const DEFAULT_ENQUEUE_TIMEOUT: Duration = Duration::from_secs(5);
+
+    /// Returns the number of runtime worker threads.
+    pub fn workers(&self) -> NonZeroUsize {
+        self.workers
+    }
+
+    /// Returns the most msgs queued for each worker.
+    pub fn queue_capacity(&self) -> NonZeroUsize {
+        self.queue_capacity
+    }
+
+    /// Returns how long sending a msg waits for room in a full queue.
+    pub fn enqueue_timeout(&self) -> Duration {
+        self.enqueue_timeout
+    }
+
+    /// Returns the configuration of the given worker, which only differs by its store path.
+    pub(crate) fn for_worker(&self, worker: usize) -> Self {
+        Self {
+            store_path: worker_store_path(&self.store_path, worker),
+            ..self.clone()
+        }
+    }
+}
+
+/// A node RPC client shared by the multisig client runtime workers.
+#[derive(Clone)]
+pub struct SharedNodeRpcClient(Arc<dyn NodeRpcClient + Send + Sync>);
+
+impl SharedNodeRpcClient {
+    /// Wraps the given node RPC client so that the runtime workers share it.
+    pub fn new(rpc_client: impl NodeRpcClient + Send + Sync + 'static) -> Self {
+        Self(Arc::new(rpc_client))
+    }
+}
+
+impl fmt::Debug for SharedNodeRpcClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SharedNodeRpcClient").finish_non_exhaustive()
+    }
+}
+
+/// Returns the store path of the given worker, the first worker keeping the configured one.
+fn worker_store_path(store_path: &Path, worker: usize) -> PathBuf {
+    if worker == 0 {
+        return store_path.to_path_buf();
+    }
+
+    let mut file_name = store_path.file_stem().unwrap_or_default().to_os_string();
+    file_name.push(format!("-{worker}"));
+
+    if let Some(extension) = store_path.extension() {
+        file_name.push(".");
+        file_name.push(extension);
+    }
+
+    store_path.with_file_name(file_name)
+}
+
+/// Summary of the msgs that were still queued when the runtime received a shutdown msg.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MultisigClientRuntimeDrainSummary {
+    /// The number of queued msgs handled before the drain timeout elapsed
+    drained: usize,
+
+    /// The number of queued msgs dropped without being handled
+    abandoned: usize,
+}
+
+impl MultisigClientRuntimeDrainSummary {
+    /// Returns the number of queued msgs handled before the drain timeout elapsed.
+    pub fn drained(&self) -> usize {
+        self.drained
+    }
+
+    /// Returns the number of queued msgs dropped without being handled.
+    pub fn abandoned(&self) -> usize {
+        self.abandoned
+    }
+}
+
+impl Add for MultisigClientRuntimeDrainSummary {
+    type Output = Self;
+
+    /// Sums the summaries of several workers.
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            drained: self.drained + rhs.drained,
+            abandoned: self.abandoned + rhs.abandoned,
+        }
+    }
 }
 
 #[tracing::instrument(skip_all)]
 async fn run_multisig_client_runtime<A>(
-    mut msg_receiver: mpsc::UnboundedReceiver<MultisigClientRuntimeMsg>,
+    mut msg_receiver: mpsc::Receiver<MultisigClientRuntimeMsg>,
     tracking_multisig_accounts: A,
+    config: MultisigClientRuntimeConfig,
+    startup_sender: oneshot::Sender<Result<Vec<AccountId>>>,
+) -> Result<MultisigClientRuntimeDrainSummary>
+where
+    A: Iterator<Item = AccountIdAddress>,
+{
+    let shutdown_drain_timeout = config.shutdown_drain_timeout;
+    let note_import_limit = config.note_import_limit;
+
+    let mut sync_interval = make_sync_interval(config.sync_interval);
+
+    let mut client = match init_multisig_client(config).await {
+        Ok(client) => client,
+        Err(e) => {
+            tracing::error!("failed to initialize multisig client: {e}");
+
+            // the error is handed over to the engine, the thread itself exits cleanly
+            let _ = startup_sender.send(Err(e));
+            return Ok(MultisigClientRuntimeDrainSummary::default());
+        },
+    };
+
+    let untracked_account_ids =
+        track_multisig_accounts(&mut client, tracking_multisig_accounts).await;
+    let _ = startup_sender.send(Ok(untracked_account_ids));
+
+    let mut drain_summary = MultisigClientRuntimeDrainSummary::default();
+
+    loop {
+        let msg = tokio::select! {
+            biased;
+
+            msg = msg_receiver.recv() => msg,
+            () = next_sync_tick(&mut sync_interval) => {
+                periodic_sync(&mut client, &msg_receiver).await;
+                continue;
+            },
+        };
+
+        let Some(msg) = msg else {
+            break;
+        };
+
+        if let MultisigClientRuntimeMsg::Shutdown = msg {
+            tracing::info!("received shutdown msg, draining queued msgs");
+            drain_summary = drain_msgs(
+                &mut client,
+                &mut msg_receiver,
+                shutdown_drain_timeout,
+                note_import_limit,
+            )
+            .await;
+            break;
+        }
+
+        handle_msg(&mut client, msg, note_import_limit).await;
+    }
+
+    tracing::info!(
+        drained = drain_summary.drained,
+        abandoned = drain_summary.abandoned,
+        "shutting down multisig client runtime",
+    );
+
+    Ok(drain_summary)
+}
+
+/// Builds the [`MultisigClient`] and syncs it with the node.
+///
+/// This is the first time the node is reached, so an unreachable node fails the initialization.
+async fn init_multisig_client(
     MultisigClientRuntimeConfig {
         node_url,
         store_path,
         keystore_path,
         timeout,
+        rpc_client,
+        ..
     }: MultisigClientRuntimeConfig,
-) -> Result<()>
-where
-    A: Iterator<Item = AccountIdAddress>,
-{
+) -> Result<MultisigClient<impl TransactionAuthenticator + Sync + 'static>> {
     let keystore = FilesystemKeyStore::new(keystore_path)
         .map_err(|e| MultisigClientRuntimeError::other(e.to_string()))?;
 
-    let endpoint = node_url.as_str().trim_end_matches('/').try_into().map_err(|e| {
-        MultisigClientRuntimeError::other(format!("failed to parse node url {node_url}: {e}"))
-    })?;
-
     let store_path = store_path
         .to_str()
         .ok_or(MultisigClientRuntimeError::other("invalid store path"))?;
 
+    let rpc_api: Arc<dyn NodeRpcClient + Send> = match rpc_client {
+        Some(SharedNodeRpcClient(rpc_client)) => rpc_client,
+        None => {
+            let endpoint = node_url.as_str().trim_end_matches('/').try_into().map_err(|e| {
+                MultisigClientRuntimeError::other(format!(
+                    "failed to parse node url {node_url}: {e}"
+                ))
+            })?;
+
+            Arc::new(TonicRpcClient::new(&endpoint, timeout.as_millis() as u64))
+        },
+    };
+
     let mut client = ClientBuilder::new()
-        .tonic_rpc_client(&endpoint, Some(timeout.as_millis() as u64))
+        .rpc(rpc_api.clone())
         .authenticator(Arc::new(keystore))
         .sqlite_store(store_path)
         .build()
         .await
         .inspect_err(|e| tracing::error!("failed to build multisig client: {e}"))
-        .map(MultisigClient::new)?;
+        .map(|client| MultisigClient::new(client, rpc_api))?;
 
     client
         .ensure_genesis_in_place()
@@ -170,46 +493,207 @@ where
         .await
         .inspect_err(|e| tracing::error!("failed to sync state: {e}"))?;
 
-    for account_id in tracking_multisig_accounts.map(|address| address.id()) {
-        let _ = client
-            .import_account_by_id(account_id)
-            .await
-            .inspect_err(|e| tracing::error!("failed to track multisig account {account_id}: {e}"));
+    Ok(client)
+}
+
+/// Imports from the node the given multisig accounts the client doesn't track yet, returning the
+/// ids of the ones that couldn't be imported.
+async fn track_multisig_accounts<AUTH>(
+    client: &mut MultisigClient<AUTH>,
+    multisig_accounts: impl Iterator<Item = AccountIdAddress>,
+) -> Vec<AccountId>
+where
+    AUTH: TransactionAuthenticator + Sync + 'static,
+{
+    let mut untracked_account_ids = Vec::new();
+
+    for account_id in multisig_accounts.map(|address| address.id()) {
+        // kept from an earlier run, possibly before being on chain
+        if let Ok(Some(_)) = client.get_account(account_id).await {
+            continue;
+        }
+
+        if let Err(e) = client.import_account_by_id(account_id).await {
+            tracing::warn!("failed to import multisig account {account_id}: {e}");
+            untracked_account_ids.push(account_id);
+        }
     }
 
-    // TODO: convey the error in a better way to the caller
-    while let Some(msg) = msg_receiver.recv().await {
-        match msg {
-            MultisigClientRuntimeMsg::Shutdown => {
-                tracing::info!("received shutdown msg, stopping multisig client runtime");
-                break;
-            },
-            MultisigClientRuntimeMsg::GetConsumableNotes(msg) => {
-                let _ = handle_get_consumable_notes(&mut client, msg)
-                    .await
-                    .inspect_err(|e| tracing::error!("failed to handle get consumable notes: {e}"));
-            },
-            MultisigClientRuntimeMsg::CreateMultisigAccount(msg) => {
-                let _ = handle_create_multisig_account(&mut client, msg).await.inspect_err(|e| {
-                    tracing::error!("failed to handle create multisig account: {e}")
-                });
-            },
-            MultisigClientRuntimeMsg::ProposeMultisigTx(msg) => {
-                let _ = handle_propose_multisig_tx(&mut client, msg)
-                    .await
-                    .inspect_err(|e| tracing::error!("failed to handle propose multisig tx: {e}"));
-            },
-            MultisigClientRuntimeMsg::ProcessMultisigTx(msg) => {
-                let _ = handle_process_multisig_tx(&mut client, msg)
-                    .await
-                    .inspect_err(|e| tracing::error!("failed to handle process multisig tx: {e}"));
-            },
+    untracked_account_ids
+}
+
+/// Makes the interval periodic syncs run on, first ticking one `period` from now. Periodic syncs
+/// are disabled if no `period` is given or if it is zero, which an interval can't tick on.
+fn make_sync_interval(period: Option<Duration>) -> Option<Interval> {
+    period.filter(|period| !period.is_zero()).map(|period| {
+        let mut sync_interval = time::interval_at(time::Instant::now() + period, period);
+        sync_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        sync_interval
+    })
+}
+
+/// Waits for the next tick of the periodic sync interval, forever if periodic syncs are disabled.
+async fn next_sync_tick(sync_interval: &mut Option<Interval>) {
+    match sync_interval {
+        Some(sync_interval) => {
+            sync_interval.tick().await;
+        },
+        None => future::pending().await,
+    }
+}
+
+/// Syncs the client with the node so that e.g. consumable notes reflect recent chain activity,
+/// unless msgs are queued, whose handling syncs the client anyway.
+async fn periodic_sync<AUTH>(
+    client: &mut MultisigClient<AUTH>,
+    msg_receiver: &mpsc::Receiver<MultisigClientRuntimeMsg>,
+) where
+    AUTH: TransactionAuthenticator + Sync + 'static,
+{
+    if !msg_receiver.is_empty() {
+        tracing::debug!("msgs are queued, skipping periodic sync");
+        return;
+    }
+
+    let _ = client
+        .sync_state()
+        .await
+        .inspect_err(|e| tracing::warn!("failed to periodically sync state: {e}"));
+}
+
+/// Stops accepting new msgs and handles the already queued ones until `drain_timeout` elapses.
+///
+/// A msg whose handling started before the timeout is always run to completion, so a multisig tx
+/// is never interrupted mid-submit. The remaining msgs are dropped, which closes their oneshot
+/// channels.
+async fn drain_msgs<AUTH>(
+    client: &mut MultisigClient<AUTH>,
+    msg_receiver: &mut mpsc::Receiver<MultisigClientRuntimeMsg>,
+    drain_timeout: Duration,
+    note_import_limit: Option<NonZeroUsize>,
+) -> MultisigClientRuntimeDrainSummary
+where
+    AUTH: TransactionAuthenticator + Sync + 'static,
+{
+    msg_receiver.close();
+
+    let deadline = Instant::now() + drain_timeout;
+    let mut drain_summary = MultisigClientRuntimeDrainSummary::default();
+
+    while let Ok(msg) = msg_receiver.try_recv() {
+        if let MultisigClientRuntimeMsg::Shutdown = msg {
+            continue;
+        }
+
+        if Instant::now() < deadline {
+            handle_msg(client, msg, note_import_limit).await;
+            drain_summary.drained += 1;
+        } else {
+            drain_summary.abandoned += 1;
         }
     }
 
-    tracing::info!("shutting down multisig client runtime");
+    if drain_summary.abandoned > 0 {
+        tracing::warn!(
+            abandoned = drain_summary.abandoned,
+            "drain timeout elapsed, abandoned queued msgs",
+        );
+    }
 
-    Ok(())
+    drain_summary
+}
+
+// TODO: convey the error in a better way to the caller
+async fn handle_msg<AUTH>(
+    client: &mut MultisigClient<AUTH>,
+    msg: MultisigClientRuntimeMsg,
+    note_import_limit: Option<NonZeroUsize>,
+) where
+    AUTH: TransactionAuthenticator + Sync + 'static,
+{
+    // re-enter the span of the sender so that logs emitted on this thread can be correlated
+    // with the originating request
+    let span = msg.span().cloned().unwrap_or_else(Span::none);
+
+    // the engine dropped the receiver of the response, e.g. because the request was cancelled,
+    // so the msg is skipped rather than syncing, proving or submitting for nobody
+    if msg.is_abandoned() {
+        tracing::warn!(parent: &span, operation = msg.operation(), "skipping abandoned msg");
+        return;
+    }
+
+    match msg {
+        MultisigClientRuntimeMsg::Shutdown => {},
+        MultisigClientRuntimeMsg::GetConsumableNotes(msg) => {
+            let _ = handle_get_consumable_notes(client, msg)
+                .instrument(span)
+                .await
+                .inspect_err(|e| tracing::error!("failed to handle get consumable notes: {e}"));
+        },
+        MultisigClientRuntimeMsg::GetAccount(msg) => {
+            let _ = handle_get_account(client, msg)
+                .instrument(span)
+                .await
+                .inspect_err(|e| tracing::error!("failed to handle get account: {e}"));
+        },
+        MultisigClientRuntimeMsg::GetCommittedTxs(msg) => {
+            let _ = handle_get_committed_txs(client, msg)
+                .instrument(span)
+                .await
+                .inspect_err(|e| tracing::error!("failed to handle get committed txs: {e}"));
+        },
+        MultisigClientRuntimeMsg::BuildPaymentTxRequest(msg) => {
+            let _ = handle_build_payment_tx_request(client, msg)
+                .instrument(span)
+                .await
+                .inspect_err(|e| tracing::error!("failed to handle build payment tx request: {e}"));
+        },
+        MultisigClientRuntimeMsg::CreateMultisigAccount(msg) => {
+            let _ = handle_create_multisig_account(client, msg)
+                .instrument(span)
+                .await
+                .inspect_err(|e| tracing::error!("failed to handle create multisig account: {e}"));
+        },
+        MultisigClientRuntimeMsg::ProposeMultisigTx(msg) => {
+            let _ = handle_propose_multisig_tx(client, msg, note_import_limit)
+                .instrument(span)
+                .await
+                .inspect_err(|e| tracing::error!("failed to handle propose multisig tx: {e}"));
+        },
+        MultisigClientRuntimeMsg::ProcessMultisigTx(msg) => {
+            let _ = handle_process_multisig_tx(client, msg)
+                .instrument(span)
+                .await
+                .inspect_err(|e| tracing::error!("failed to handle process multisig tx: {e}"));
+        },
+        MultisigClientRuntimeMsg::TrackMultisigAccount(msg) => {
+            let _ = handle_track_multisig_account(client, msg)
+                .instrument(span)
+                .await
+                .inspect_err(|e| tracing::error!("failed to handle track multisig account: {e}"));
+        },
+        MultisigClientRuntimeMsg::FetchMultisigAccount(msg) => {
+            let _ = handle_fetch_multisig_account(client, msg)
+                .instrument(span)
+                .await
+                .inspect_err(|e| tracing::error!("failed to handle fetch multisig account: {e}"));
+        },
+        MultisigClientRuntimeMsg::ExportMultisigAccount(msg) => {
+            let _ = handle_export_multisig_account(client, msg)
+                .instrument(span)
+                .await
+                .inspect_err(|e| tracing::error!("failed to handle export multisig account: {e}"));
+        },
+        MultisigClientRuntimeMsg::Ping(msg) => {
+            // answered without touching the client, the round trip alone shows the runtime is
+            // handling msgs
+            let PingDissolved { sender, .. } = msg.dissolve();
+
+            let _ = sender
+                .send(())
+                .inspect_err(|_| tracing::error!("oneshot sender failed to send pong"));
+        },
+    }
 }
 
 #[tracing::instrument(skip_all)]
@@ -220,15 +704,81 @@ async fn handle_create_multisig_account<AUTH>(
 where
     AUTH: TransactionAuthenticator + Sync + 'static,
 {
-    client.sync_state().await?;
+    let CreateMultisigAccountDissolved { threshold, approvers, sender, .. } = msg.dissolve();
+
+    // not tracked here, the owning worker tracks it from its seed since it isn't on chain yet
+    let (account, seed) = client.build_account(approvers, threshold.get())?;
+
+    let _ = sender
+        .send((account, seed))
+        .inspect_err(|_| tracing::error!("oneshot sender failed to send new multisig account"));
 
-    let CreateMultisigAccountDissolved { threshold, approvers, sender } = msg.dissolve();
+    Ok(())
+}
 
-    let account = client.setup_account(approvers, threshold.get()).await;
+#[tracing::instrument(skip_all)]
+async fn handle_track_multisig_account<AUTH>(
+    client: &mut MultisigClient<AUTH>,
+    msg: TrackMultisigAccount,
+) -> Result<()>
+where
+    AUTH: TransactionAuthenticator + Sync + 'static,
+{
+    let TrackMultisigAccountDissolved { account, seed, sender, .. } = msg.dissolve();
+
+    // an account may still be tracked from an earlier import, in which case it is kept as is
+    if client.get_account(account.id()).await?.is_none() {
+        client.add_account(&account, seed, false).await?;
+    }
+
+    let _ = sender
+        .send(())
+        .inspect_err(|_| tracing::error!("oneshot sender failed to send tracked multisig account"));
+
+    Ok(())
+}
+
+/// Sends back a multisig account tracked by this worker along with its seed, without syncing it,
+/// or nothing if it isn't tracked here.
+#[tracing::instrument(skip_all)]
+async fn handle_export_multisig_account<AUTH>(
+    client: &mut MultisigClient<AUTH>,
+    msg: ExportMultisigAccount,
+) -> Result<()>
+where
+    AUTH: TransactionAuthenticator + Sync + 'static,
+{
+    let ExportMultisigAccountDissolved { account_id, sender, .. } = msg.dissolve();
+
+    let account = client.get_account(account_id).await?.map(|record| {
+        let seed = record.seed().copied();
+        (Account::from(record), seed)
+    });
+
+    let _ = sender.send(account).inspect_err(|_| {
+        tracing::error!("oneshot sender failed to send exported multisig account")
+    });
+
+    Ok(())
+}
+
+/// Fetches a multisig account created elsewhere from the node without tracking it, sending back
+/// the account or the reason it can't be imported.
+#[tracing::instrument(skip_all)]
+async fn handle_fetch_multisig_account<AUTH>(
+    client: &mut MultisigClient<AUTH>,
+    msg: FetchMultisigAccount,
+) -> Result<()>
+where
+    AUTH: TransactionAuthenticator + Sync + 'static,
+{
+    let FetchMultisigAccountDissolved { account_id, sender, .. } = msg.dissolve();
+
+    let account = client.fetch_multisig_account(account_id).await.map_err(From::from);
 
     let _ = sender
         .send(account)
-        .inspect_err(|_| tracing::error!("oneshot sender failed to send new multisig account"));
+        .inspect_err(|_| tracing::error!("oneshot sender failed to send fetched multisig account"));
 
     Ok(())
 }
@@ -241,40 +791,202 @@ async fn handle_get_consumable_notes<AUTH>(
 where
     AUTH: TransactionAuthenticator + Sync + 'static,
 {
-    client.sync_state().await?;
+    // the notes as of the last synced block are still listed if the node is unreachable, flagged
+    // as stale so that the caller can decide whether to retry
+    let chain_tip = client
+        .sync_state()
+        .await
+        .map(|sync_summary| sync_summary.block_num)
+        .inspect_err(|e| tracing::warn!("failed to sync state, listing stale notes: {e}"))
+        .ok();
 
-    let GetConsumableNotesDissolved { account_id, sender } = msg.dissolve();
+    let block_num = client.get_sync_height().await?;
 
-    let notes = client.get_consumable_notes(account_id).await?;
+    let GetConsumableNotesDissolved { account_id, note_tag, sender, .. } = msg.dissolve();
+
+    let mut notes = client.get_consumable_notes(account_id).await?;
+
+    if let Some(note_tag) = note_tag {
+        retain_notes_with_tag(&mut notes, note_tag);
+    }
+
+    let consumable_notes = ConsumableNotes::builder()
+        .notes(notes)
+        .block_num(block_num)
+        .stale(chain_tip.is_none_or(|chain_tip| block_num < chain_tip))
+        .build();
 
     let _ = sender
-        .send(notes)
+        .send(consumable_notes)
         .inspect_err(|_| tracing::error!("oneshot sender failed to send list of consumable notes"));
 
     Ok(())
 }
 
+/// Keeps only the notes whose metadata carries the given tag.
+///
+/// Notes without metadata (e.g. those only known by id) can't be matched and are dropped.
+fn retain_notes_with_tag(
+    notes: &mut Vec<(InputNoteRecord, Vec<NoteConsumability>)>,
+    note_tag: NoteTag,
+) {
+    notes.retain(|(note, _)| note.metadata().is_some_and(|metadata| metadata.tag() == note_tag));
+}
+
+#[tracing::instrument(skip_all)]
+async fn handle_get_account<AUTH>(client: &mut MultisigClient<AUTH>, msg: GetAccount) -> Result<()>
+where
+    AUTH: TransactionAuthenticator + Sync + 'static,
+{
+    client.sync_state().await?;
+
+    let GetAccountDissolved { account_id, sender, .. } = msg.dissolve();
+
+    let account = client.get_account(account_id).await?.map(Into::into);
+
+    let _ = sender
+        .send(account)
+        .inspect_err(|_| tracing::error!("oneshot sender failed to send account"));
+
+    Ok(())
+}
+
+/// Builds the request of a transaction paying the asset with a P2ID note, drawing the note serial
+/// number from the client's rng.
+#[tracing::instrument(skip_all)]
+async fn handle_build_payment_tx_request<AUTH>(
+    client: &mut MultisigClient<AUTH>,
+    msg: BuildPaymentTxRequest,
+) -> Result<()>
+where
+    AUTH: TransactionAuthenticator + Sync + 'static,
+{
+    let BuildPaymentTxRequestDissolved { payment, note_type, sender, .. } = msg.dissolve();
+
+    let tx_request =
+        TransactionRequestBuilder::new().build_pay_to_id(payment, note_type, client.rng());
+
+    let _ = sender
+        .send(tx_request)
+        .inspect_err(|_| tracing::error!("oneshot sender failed to send payment tx request"));
+
+    Ok(())
+}
+
+/// Sends back the transactions of the account that this client submitted and saw committed.
+#[tracing::instrument(skip_all)]
+async fn handle_get_committed_txs<AUTH>(
+    client: &mut MultisigClient<AUTH>,
+    msg: GetCommittedTxs,
+) -> Result<()>
+where
+    AUTH: TransactionAuthenticator + Sync + 'static,
+{
+    client.sync_state().await?;
+
+    let GetCommittedTxsDissolved { account_id, sender, .. } = msg.dissolve();
+
+    let committed_txs = client
+        .get_transactions(TransactionFilter::All)
+        .await?
+        .into_iter()
+        .filter(|tx| tx.details.account_id == account_id)
+        .filter(|tx| matches!(tx.status, TransactionStatus::Committed { .. }))
+        .collect();
+
+    let _ = sender
+        .send(committed_txs)
+        .inspect_err(|_| tracing::error!("oneshot sender failed to send committed txs"));
+
+    Ok(())
+}
+
 #[tracing::instrument(skip_all)]
 async fn handle_propose_multisig_tx<AUTH>(
     client: &mut MultisigClient<AUTH>,
     msg: ProposeMultisigTx,
+    note_import_limit: Option<NonZeroUsize>,
 ) -> Result<()>
 where
     AUTH: TransactionAuthenticator + Sync + 'static,
 {
     client.sync_state().await?;
 
-    let ProposeMultisigTxDissolved { account_id, tx_request, sender } = msg.dissolve();
+    let ProposeMultisigTxDissolved { account_id, tx_request, sender, .. } = msg.dissolve();
 
-    let tx_summary = client.propose_multisig_transaction(account_id, tx_request).await;
+    let imported = match note_import_limit {
+        Some(note_import_limit) => {
+            import_unknown_input_notes(client, &tx_request, note_import_limit).await
+        },
+        None => Ok(()),
+    };
+
+    let tx_summary = match imported {
+        Ok(()) => client
+            .propose_multisig_transaction(account_id, tx_request)
+            .await
+            .map_err(From::from),
+        Err(e) => Err(e),
+    };
 
     let _ = sender
-        .send(tx_summary.map_err(From::from))
+        .send(tx_summary)
         .inspect_err(|_| tracing::error!("oneshot sender failed to send tx summary"));
 
     Ok(())
 }
 
+/// Imports the input notes of the transaction request unknown to the client by id, then syncs
+/// the client so that the dry-run of the proposal finds them.
+///
+/// More unknown input notes than `note_import_limit` fail the proposal rather than having the
+/// runtime, which handles the msgs of its accounts one at a time, import arbitrarily many notes.
+async fn import_unknown_input_notes<AUTH>(
+    client: &mut MultisigClient<AUTH>,
+    tx_request: &TransactionRequest,
+    note_import_limit: NonZeroUsize,
+) -> core::result::Result<(), ProposeMultisigTxError>
+where
+    AUTH: TransactionAuthenticator + Sync + 'static,
+{
+    let mut unknown_note_ids = Vec::new();
+
+    for note_id in tx_request.get_input_note_ids() {
+        if client
+            .get_input_note(note_id)
+            .await
+            .map_err(MultisigClientError::tx_proposal)?
+            .is_none()
+        {
+            unknown_note_ids.push(note_id);
+        }
+    }
+
+    if unknown_note_ids.is_empty() {
+        return Ok(());
+    }
+
+    if unknown_note_ids.len() > note_import_limit.get() {
+        return Err(ProposeMultisigTxError::TooManyUnknownInputNotes {
+            unknown: unknown_note_ids.len(),
+            note_import_limit,
+        });
+    }
+
+    for note_id in unknown_note_ids {
+        client
+            .import_note(NoteFile::NoteId(note_id))
+            .await
+            .map_err(MultisigClientError::tx_proposal)?;
+
+        tracing::info!(note_id = note_id.to_hex(), "imported unknown input note");
+    }
+
+    client.sync_state().await.map_err(MultisigClientError::tx_proposal)?;
+
+    Ok(())
+}
+
 #[tracing::instrument(skip_all)]
 async fn handle_process_multisig_tx<AUTH>(
     client: &mut MultisigClient<AUTH>,
@@ -290,19 +1002,36 @@ where
         tx_request,
         tx_summary,
         signatures,
+        approver_pub_keys,
+        threshold,
         sender,
+        ..
     } = msg.dissolve();
 
-    let account_record = client.try_get_account(account_id).await?;
+    let account: Account = client.try_get_account(account_id).await?.into();
 
-    let signatures = signatures
-        .into_iter()
-        .map(|s| s.map(miden_multisig_coordinator_utils::rpo_falcon512_signature_into_felt_vec))
-        .collect();
+    let signatures = signatures.into_iter().map(|s| s.map(approver_signature_into_felts)).collect();
 
-    let tx_result = client
-        .new_multisig_transaction(account_record.into(), tx_request, tx_summary, signatures)
-        .await;
+    // the stored config stands in for an account storage the client can't read it from
+    let config = MultisigClient::<AUTH>::account_config(&account)
+        .map(|MultisigConfig { threshold, approver_pub_keys, .. }| (approver_pub_keys, threshold))
+        .or_else(|e| approver_pub_keys.map(|pub_keys| (pub_keys, threshold.get())).ok_or(e));
+
+    let tx_result = match config {
+        Ok((approver_pub_keys, threshold)) => {
+            client
+                .new_multisig_transaction_with_pub_keys(
+                    account_id,
+                    tx_request,
+                    tx_summary,
+                    signatures,
+                    approver_pub_keys,
+                    threshold,
+                )
+                .await
+        },
+        Err(e) => Err(e),
+    };
 
     if let Ok(tx_result) = &tx_result {
         client.submit_transaction(tx_result.clone()).await?;
@@ -314,3 +1043,216 @@ where
 
     Ok(())
 }
+
+/// Encodes an approver signature for the advice map according to its scheme.
+// TODO: ECDSA signatures fill the same advice map slots as Falcon ones, but are not yet verified
+// by the multisig account on chain.
+fn approver_signature_into_felts(signature: ApproverSignature) -> SignatureFelts {
+    match signature {
+        ApproverSignature::Falcon(signature) => SignatureFelts::from(signature),
+        ApproverSignature::Ecdsa(signature) => {
+            SignatureFelts::from_ecdsa_k256(signature.as_bytes())
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use std::path::Path;
+
+    use miden_client::{
+        Felt, Word,
+        account::AccountId,
+        crypto::RpoRandomCoin,
+        note::{Note, NoteExecutionHint, NoteMetadata, NoteTag, NoteType, create_p2id_note},
+        store::InputNoteRecord,
+    };
+    use miden_multisig_test_utils::create_mock_rpc_api;
+    use miden_objects::testing::account_id::{
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE, ACCOUNT_ID_SENDER,
+    };
+    use tempfile::TempDir;
+    use tokio::{
+        sync::{mpsc, oneshot},
+        time,
+    };
+
+    use super::{
+        MultisigClientRuntimeConfig, SharedNodeRpcClient,
+        msg::{MultisigClientRuntimeMsg, Ping},
+    };
+
+    fn ping() -> (MultisigClientRuntimeMsg, oneshot::Receiver<()>) {
+        let (sender, receiver) = oneshot::channel();
+
+        (MultisigClientRuntimeMsg::Ping(Ping::builder().sender(sender).build()), receiver)
+    }
+
+    fn make_tagged_note(rng: &mut RpoRandomCoin, tag: NoteTag) -> InputNoteRecord {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let target = AccountId::try_from(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE).unwrap();
+
+        let note =
+            create_p2id_note(sender, target, vec![], NoteType::Public, Felt::new(0), rng).unwrap();
+
+        let metadata = NoteMetadata::new(
+            sender,
+            NoteType::Public,
+            tag,
+            NoteExecutionHint::always(),
+            Felt::new(0),
+        )
+        .unwrap();
+
+        Note::new(note.assets().clone(), metadata, note.recipient().clone()).into()
+    }
+
+    #[test]
+    fn worker_store_paths_keep_first_worker_path_and_suffix_the_other_ones() {
+        // Arrange
+        let store_path = Path::new("./data/store.sqlite3");
+
+        // Act
+        let first = super::worker_store_path(store_path, 0);
+        let second = super::worker_store_path(store_path, 1);
+
+        // Assert
+        assert_eq!(first, store_path);
+        assert_eq!(second, Path::new("./data/store-1.sqlite3"));
+    }
+
+    #[test]
+    fn retaining_notes_with_tag_drops_notes_with_other_tags() {
+        // Arrange
+        let mut rng = RpoRandomCoin::new(Word::default());
+
+        let payroll_tag = NoteTag::from(0x1234_u32);
+        let other_tag = NoteTag::from(0x5678_u32);
+
+        let payroll_note = make_tagged_note(&mut rng, payroll_tag);
+        let other_note = make_tagged_note(&mut rng, other_tag);
+
+        let payroll_note_id = payroll_note.id();
+        let mut notes = vec![(payroll_note, vec![]), (other_note, vec![])];
+
+        // Act
+        super::retain_notes_with_tag(&mut notes, payroll_tag);
+
+        // Assert
+        let [(note, _)] = notes.try_into().expect("exactly one note must match the tag");
+        assert_eq!(note.id(), payroll_note_id);
+    }
+
+    #[test]
+    fn zero_or_missing_sync_interval_disables_periodic_syncs() {
+        // Act
+        let missing = super::make_sync_interval(None);
+        let zero = super::make_sync_interval(Some(Duration::ZERO));
+
+        // Assert
+        assert!(missing.is_none());
+        assert!(zero.is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn periodic_sync_ticks_once_per_sync_interval() {
+        // Arrange
+        let period = Duration::from_secs(30);
+        let mut sync_interval = super::make_sync_interval(Some(period));
+        let start = time::Instant::now();
+
+        // Act
+        super::next_sync_tick(&mut sync_interval).await;
+        let first_tick = start.elapsed();
+
+        super::next_sync_tick(&mut sync_interval).await;
+        let second_tick = start.elapsed();
+
+        // Assert
+        assert_eq!(first_tick, period);
+        assert_eq!(second_tick, 2 * period);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn disabled_periodic_sync_never_ticks() {
+        // Arrange
+        let mut sync_interval = super::make_sync_interval(None);
+
+        // Act
+        let tick =
+            time::timeout(Duration::from_secs(3600), super::next_sync_tick(&mut sync_interval))
+                .await;
+
+        // Assert
+        assert!(tick.is_err(), "a disabled periodic sync must never tick");
+    }
+
+    #[test]
+    fn msg_is_abandoned_once_its_response_receiver_is_dropped() {
+        // Arrange
+        let (sender, receiver) = oneshot::channel();
+        let msg = MultisigClientRuntimeMsg::Ping(Ping::builder().sender(sender).build());
+
+        let abandoned_before = msg.is_abandoned();
+
+        // Act
+        drop(receiver);
+
+        // Assert
+        assert!(!abandoned_before);
+        assert!(msg.is_abandoned());
+        assert_eq!(msg.operation(), "ping");
+    }
+
+    #[tokio::test]
+    async fn queued_msgs_are_drained_until_drain_timeout_and_abandoned_past_it() {
+        for (drain_timeout, expected_drained, expected_abandoned) in
+            [(Duration::from_secs(10), 2, 0), (Duration::ZERO, 0, 2)]
+        {
+            // Arrange
+            let temp_dir = TempDir::new().expect("failed to create temporary directory");
+
+            // the node url is never connected to, the mock node serving every request instead
+            let config = MultisigClientRuntimeConfig::builder()
+                .node_url("http://localhost".parse().unwrap())
+                .store_path(temp_dir.path().join("store"))
+                .keystore_path(temp_dir.path().join("keystore"))
+                .timeout(Duration::from_secs(10))
+                .shutdown_drain_timeout(drain_timeout)
+                .rpc_client(SharedNodeRpcClient::new(create_mock_rpc_api().await))
+                .build();
+
+            let mut client = super::init_multisig_client(config).await.unwrap();
+
+            let (msg_sender, mut msg_receiver) = mpsc::channel(8);
+
+            let mut ping_receivers = Vec::new();
+            for _ in 0..2 {
+                let (msg, receiver) = ping();
+                msg_sender.send(msg).await.unwrap();
+                ping_receivers.push(receiver);
+            }
+
+            // e.g. of a second stop, neither drained nor abandoned
+            msg_sender.send(MultisigClientRuntimeMsg::Shutdown).await.unwrap();
+
+            // Act
+            let drain_summary =
+                super::drain_msgs(&mut client, &mut msg_receiver, drain_timeout, None).await;
+
+            // Assert
+            assert_eq!(drain_summary.drained(), expected_drained);
+            assert_eq!(drain_summary.abandoned(), expected_abandoned);
+
+            for receiver in ping_receivers {
+                assert_eq!(receiver.await.is_ok(), expected_drained > 0);
+            }
+
+            let (late_msg, _) = ping();
+
+            assert!(msg_sender.send(late_msg).await.is_err(), "no msg is queued once draining");
+        }
+    }
+}
@@ -1,40 +1,182 @@
-use core::num::NonZeroU32;
+use core::num::{NonZeroU32, NonZeroUsize};
 
 use bon::Builder;
 use dissolve_derive::Dissolve;
 use miden_client::{
+    Word,
     account::{Account, AccountId},
-    note::NoteConsumability,
+    note::{NoteConsumability, NoteTag, NoteType},
     store::InputNoteRecord,
-    transaction::{TransactionRequest, TransactionResult},
+    transaction::{
+        PaymentNoteDescription, TransactionRecord, TransactionRequest, TransactionRequestError,
+        TransactionResult,
+    },
 };
 use miden_multisig_client::MultisigClientError;
+use miden_multisig_coordinator_domain::key::ApproverSignature;
 use miden_objects::{
-    crypto::dsa::rpo_falcon512::{PublicKey, Signature},
-    transaction::TransactionSummary,
+    block::BlockNumber, crypto::dsa::rpo_falcon512::PublicKey, transaction::TransactionSummary,
 };
 use tokio::sync::oneshot;
+use tracing::Span;
 
 #[allow(clippy::large_enum_variant)]
 pub enum MultisigClientRuntimeMsg {
     CreateMultisigAccount(CreateMultisigAccount),
+    BuildPaymentTxRequest(BuildPaymentTxRequest),
     GetConsumableNotes(GetConsumableNotes),
+    GetAccount(GetAccount),
+    GetCommittedTxs(GetCommittedTxs),
     ProposeMultisigTx(ProposeMultisigTx),
     ProcessMultisigTx(ProcessMultisigTx),
+    TrackMultisigAccount(TrackMultisigAccount),
+    FetchMultisigAccount(FetchMultisigAccount),
+    ExportMultisigAccount(ExportMultisigAccount),
+    Ping(Ping),
     Shutdown,
 }
 
+impl MultisigClientRuntimeMsg {
+    /// Returns the span of the caller that sent this msg, if any.
+    ///
+    /// The span is captured when the msg is built so that the work done on the runtime thread
+    /// can be correlated with the request that originated it.
+    pub fn span(&self) -> Option<&Span> {
+        match self {
+            Self::CreateMultisigAccount(msg) => Some(&msg.span),
+            Self::BuildPaymentTxRequest(msg) => Some(&msg.span),
+            Self::GetConsumableNotes(msg) => Some(&msg.span),
+            Self::GetAccount(msg) => Some(&msg.span),
+            Self::GetCommittedTxs(msg) => Some(&msg.span),
+            Self::ProposeMultisigTx(msg) => Some(&msg.span),
+            Self::ProcessMultisigTx(msg) => Some(&msg.span),
+            Self::TrackMultisigAccount(msg) => Some(&msg.span),
+            Self::FetchMultisigAccount(msg) => Some(&msg.span),
+            Self::ExportMultisigAccount(msg) => Some(&msg.span),
+            Self::Ping(msg) => Some(&msg.span),
+            Self::Shutdown => None,
+        }
+    }
+
+    /// Returns the name of the operation this msg requests, as named in the logs and in the
+    /// errors of the engine.
+    pub fn operation(&self) -> &'static str {
+        match self {
+            Self::CreateMultisigAccount(_) => "create multisig account",
+            Self::BuildPaymentTxRequest(_) => "build payment tx request",
+            Self::GetConsumableNotes(_) => "get consumable notes",
+            Self::GetAccount(_) => "get account",
+            Self::GetCommittedTxs(_) => "get committed txs",
+            Self::ProposeMultisigTx(_) => "propose multisig tx",
+            Self::ProcessMultisigTx(_) => "process multisig tx",
+            Self::TrackMultisigAccount(_) => "track multisig account",
+            Self::FetchMultisigAccount(_) => "fetch multisig account",
+            Self::ExportMultisigAccount(_) => "export multisig account",
+            Self::Ping(_) => "ping",
+            Self::Shutdown => "shutdown",
+        }
+    }
+
+    /// Returns whether the sender of this msg stopped waiting for its response, e.g. because the
+    /// request that originated it was cancelled, in which case handling it is wasted work.
+    pub fn is_abandoned(&self) -> bool {
+        match self {
+            Self::CreateMultisigAccount(msg) => msg.sender.is_closed(),
+            Self::BuildPaymentTxRequest(msg) => msg.sender.is_closed(),
+            Self::GetConsumableNotes(msg) => msg.sender.is_closed(),
+            Self::GetAccount(msg) => msg.sender.is_closed(),
+            Self::GetCommittedTxs(msg) => msg.sender.is_closed(),
+            Self::ProposeMultisigTx(msg) => msg.sender.is_closed(),
+            Self::ProcessMultisigTx(msg) => msg.sender.is_closed(),
+            Self::TrackMultisigAccount(msg) => msg.sender.is_closed(),
+            Self::FetchMultisigAccount(msg) => msg.sender.is_closed(),
+            Self::ExportMultisigAccount(msg) => msg.sender.is_closed(),
+            Self::Ping(msg) => msg.sender.is_closed(),
+            Self::Shutdown => false,
+        }
+    }
+
+    /// Returns the id of the account this msg operates on, if any.
+    ///
+    /// The msg is routed to the runtime worker owning that account, so that the msgs of a given
+    /// account are handled in the order they were sent. An account is exported from a worker that
+    /// doesn't own it, so that msg is sent to that worker explicitly instead.
+    pub fn account_id(&self) -> Option<AccountId> {
+        match self {
+            Self::BuildPaymentTxRequest(msg) => Some(msg.account_id),
+            Self::GetConsumableNotes(msg) => msg.account_id,
+            Self::GetAccount(msg) => Some(msg.account_id),
+            Self::GetCommittedTxs(msg) => Some(msg.account_id),
+            Self::ProposeMultisigTx(msg) => Some(msg.account_id),
+            Self::ProcessMultisigTx(msg) => Some(msg.account_id),
+            Self::TrackMultisigAccount(msg) => Some(msg.account.id()),
+            Self::FetchMultisigAccount(msg) => Some(msg.account_id),
+            Self::CreateMultisigAccount(_)
+            | Self::ExportMultisigAccount(_)
+            | Self::Ping(_)
+            | Self::Shutdown => None,
+        }
+    }
+}
+
 #[derive(Debug, Builder, Dissolve)]
 pub struct CreateMultisigAccount {
     threshold: NonZeroU32,
     approvers: Vec<PublicKey>,
-    sender: oneshot::Sender<Account>,
+    sender: oneshot::Sender<(Account, Word)>,
+
+    #[builder(default = Span::current())]
+    span: Span,
+}
+
+#[derive(Debug, Builder, Dissolve)]
+pub struct BuildPaymentTxRequest {
+    account_id: AccountId,
+    payment: PaymentNoteDescription,
+    note_type: NoteType,
+    sender: oneshot::Sender<Result<TransactionRequest, TransactionRequestError>>,
+
+    #[builder(default = Span::current())]
+    span: Span,
 }
 
 #[derive(Debug, Builder, Dissolve)]
 pub struct GetConsumableNotes {
     account_id: Option<AccountId>,
-    sender: oneshot::Sender<Vec<(InputNoteRecord, Vec<NoteConsumability>)>>,
+    note_tag: Option<NoteTag>,
+    sender: oneshot::Sender<ConsumableNotes>,
+
+    #[builder(default = Span::current())]
+    span: Span,
+}
+
+/// The consumable notes known to a runtime worker, as of the block its client is synced to.
+#[derive(Debug, Builder, Dissolve)]
+pub struct ConsumableNotes {
+    notes: Vec<(InputNoteRecord, Vec<NoteConsumability>)>,
+    block_num: BlockNumber,
+
+    /// Whether the client is synced to a block behind the chain tip, e.g. because the sync
+    /// preceding the listing failed.
+    stale: bool,
+}
+
+#[derive(Debug, Builder, Dissolve)]
+pub struct GetAccount {
+    account_id: AccountId,
+    sender: oneshot::Sender<Option<Account>>,
+
+    #[builder(default = Span::current())]
+    span: Span,
+}
+
+#[derive(Debug, Builder, Dissolve)]
+pub struct GetCommittedTxs {
+    account_id: AccountId,
+    sender: oneshot::Sender<Vec<TransactionRecord>>,
+
+    #[builder(default = Span::current())]
+    span: Span,
 }
 
 #[derive(Debug, Builder, Dissolve)]
@@ -42,6 +184,9 @@ pub struct ProposeMultisigTx {
     account_id: AccountId,
     tx_request: TransactionRequest,
     sender: oneshot::Sender<Result<TransactionSummary, ProposeMultisigTxError>>,
+
+    #[builder(default = Span::current())]
+    span: Span,
 }
 
 #[derive(Debug, Builder, Dissolve)]
@@ -49,16 +194,111 @@ pub struct ProcessMultisigTx {
     account_id: AccountId,
     tx_request: TransactionRequest,
     tx_summary: TransactionSummary,
-    signatures: Vec<Option<Signature>>,
+    signatures: Vec<Option<ApproverSignature>>,
+
+    /// The stored approver public keys, used if the account storage doesn't hold a valid
+    /// multisig configuration
+    approver_pub_keys: Option<Vec<PublicKey>>,
+
+    /// The stored threshold, used along the stored approver public keys
+    threshold: NonZeroU32,
+
     sender: oneshot::Sender<Result<TransactionResult, ProcessMultisigTxError>>,
+
+    #[builder(default = Span::current())]
+    span: Span,
+}
+
+#[derive(Debug, Builder, Dissolve)]
+pub struct TrackMultisigAccount {
+    account: Account,
+    seed: Option<Word>,
+    sender: oneshot::Sender<()>,
+
+    #[builder(default = Span::current())]
+    span: Span,
+}
+
+#[derive(Debug, Builder, Dissolve)]
+pub struct FetchMultisigAccount {
+    account_id: AccountId,
+    sender: oneshot::Sender<Result<Account, ImportMultisigAccountError>>,
+
+    #[builder(default = Span::current())]
+    span: Span,
+}
+
+/// Retrieves an account tracked by a worker along with its seed, if it isn't on chain yet, so that
+/// the worker owning it can track it too.
+#[derive(Debug, Builder, Dissolve)]
+pub struct ExportMultisigAccount {
+    account_id: AccountId,
+    sender: oneshot::Sender<Option<(Account, Option<Word>)>>,
+
+    #[builder(default = Span::current())]
+    span: Span,
+}
+
+#[derive(Debug, Builder, Dissolve)]
+pub struct Ping {
+    sender: oneshot::Sender<()>,
+
+    #[builder(default = Span::current())]
+    span: Span,
 }
 
 /// Error that occurs when proposing a multisig transaction.
 #[derive(Debug, thiserror::Error)]
-#[error("propose multisig tx error: {0}")]
-pub struct ProposeMultisigTxError(#[from] MultisigClientError);
+pub enum ProposeMultisigTxError {
+    #[error("propose multisig tx error: {0}")]
+    MultisigClient(#[from] MultisigClientError),
+
+    #[error(
+        "propose multisig tx error: `{unknown}` input notes are unknown, more than the \
+         `{note_import_limit}` that may be imported"
+    )]
+    TooManyUnknownInputNotes {
+        unknown: usize,
+        note_import_limit: NonZeroUsize,
+    },
+}
+
+impl ProposeMultisigTxError {
+    /// Returns `true` if the error is caused by the transaction itself, e.g. failing to execute,
+    /// rather than by the node being unreachable or the note import limit being exceeded.
+    pub fn is_tx_not_executable(&self) -> bool {
+        match self {
+            Self::MultisigClient(err) => !matches!(err, MultisigClientError::RpcError(_)),
+            Self::TooManyUnknownInputNotes { .. } => false,
+        }
+    }
+
+    /// Returns the number of unknown input notes and the note import limit, if the error is
+    /// caused by a transaction consuming more unknown input notes than may be imported.
+    pub fn too_many_unknown_input_notes(&self) -> Option<(usize, NonZeroUsize)> {
+        match *self {
+            Self::TooManyUnknownInputNotes { unknown, note_import_limit } => {
+                Some((unknown, note_import_limit))
+            },
+            Self::MultisigClient(_) => None,
+        }
+    }
+}
 
 /// Error that occurs when processing a multisig transaction.
 #[derive(Debug, thiserror::Error)]
 #[error("process multisig tx error: {0}")]
 pub struct ProcessMultisigTxError(#[from] MultisigClientError);
+
+/// Error that occurs when importing a multisig account created elsewhere.
+#[derive(Debug, thiserror::Error)]
+#[error("import multisig account error: {0}")]
+pub struct ImportMultisigAccountError(#[from] MultisigClientError);
+
+impl ImportMultisigAccountError {
+    /// Returns `true` if the error is caused by the account not being authenticated by the
+    /// multisig auth component.
+    pub fn is_not_multisig_account(&self) -> bool {
+        matches!(self.0, MultisigClientError::NotMultisigAccount(_))
+    }
+}
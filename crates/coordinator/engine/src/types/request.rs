@@ -2,15 +2,31 @@
 
 mod error;
 
-pub use self::error::{CreateMultisigAccountRequestError, RequestError};
+pub use self::error::{
+    AddSignaturesRequestError, CreateMultisigAccountRequestError, ProposeMultisigTxRequestError,
+    ProposePaymentRequestError, RequestError,
+};
 
-use core::num::NonZeroU32;
+use core::{hash::Hash, num::NonZeroU32};
+
+use std::collections::HashSet;
 
 use bon::Builder;
 use dissolve_derive::Dissolve;
-use miden_client::{account::AccountIdAddress, transaction::TransactionRequest};
-use miden_multisig_coordinator_domain::tx::{MultisigTxId, MultisigTxStatus};
-use miden_objects::crypto::dsa::rpo_falcon512::{PublicKey, Signature};
+use miden_client::{
+    Word,
+    account::{AccountId, AccountIdAddress, AddressInterface},
+    asset::Asset,
+    note::{NoteId, NoteTag, NoteType},
+    transaction::TransactionRequest,
+};
+use miden_multisig_client::MAX_APPROVERS;
+use miden_multisig_coordinator_domain::{
+    cursor::Cursor,
+    key::{ApproverKey, ApproverSignature},
+    tx::{MultisigTxId, MultisigTxStatus, TrendGranularity, TxSort},
+};
+use miden_objects::transaction::TransactionSummary;
 
 /// Request to create a new multisig account.
 ///
@@ -18,17 +34,54 @@ use miden_objects::crypto::dsa::rpo_falcon512::{PublicKey, Signature};
 ///
 /// The request validates that:
 /// - `approvers` and `pub_key_commits` are both non-empty have the same length
-/// - The threshold doesn't exceed the number of approvers
+/// - There are at most [`MAX_APPROVERS`] approvers, the most the multisig auth component supports
+/// - `weights`, if given, has one weight per approver, each of which is 1 as the multisig auth
+///   component does not support weighted approvers yet
+/// - The threshold doesn't exceed the total weight of the approvers
+/// - `labels`, if given, has one optional label per approver, each of which does not exceed
+///   [`Self::MAX_LABEL_LEN`] characters once control characters are stripped, blank labels being
+///   treated as absent
 #[derive(Debug, Dissolve)]
 pub struct CreateMultisigAccountRequest {
-    /// Minimum number of signatures required to execute transactions
+    /// Minimum total weight of the signing approvers required to execute transactions
     threshold: NonZeroU32,
 
     /// List of account addresses that can approve transactions
     approvers: Vec<AccountIdAddress>,
 
-    /// Corresponding public key commitments for each approver
-    pub_key_commits: Vec<PublicKey>,
+    /// Corresponding public keys for each approver, of any supported scheme
+    pub_key_commits: Vec<ApproverKey>,
+
+    /// Corresponding vote weights for each approver
+    weights: Vec<NonZeroU32>,
+
+    /// Corresponding optional human-readable labels for each approver
+    labels: Vec<Option<String>>,
+
+    /// The interface of the multisig account address
+    address_interface: AddressInterface,
+}
+
+/// Request to import a multisig account created elsewhere, e.g. with the CLI.
+///
+/// The threshold and the approver public keys are read from the on-chain storage of the account,
+/// which doesn't hold the approver addresses, so they are given in approver index order.
+#[derive(Debug, Builder, Dissolve)]
+pub struct ImportMultisigAccountRequest {
+    /// The ID of the public multisig account to import
+    account_id: AccountId,
+
+    /// List of approver account addresses, one per on-chain approver public key
+    approvers: Vec<AccountIdAddress>,
+
+    /// Optional list of approver keys, one per approver, whose commitments must match the
+    /// on-chain ones. The on-chain commitments are taken as Falcon public keys if not given, as
+    /// the commitment of any other key doesn't reveal the key.
+    pub_key_commits: Option<Vec<ApproverKey>>,
+
+    /// The interface of the multisig account address, the basic wallet one if not given
+    #[builder(default = AddressInterface::BasicWallet)]
+    address_interface: AddressInterface,
 }
 
 /// Request to query consumable notes.
@@ -36,16 +89,114 @@ pub struct CreateMultisigAccountRequest {
 pub struct GetConsumableNotesRequest {
     /// Optional account filter. If `None`, returns notes for all accounts.
     address: Option<AccountIdAddress>,
+
+    /// Optional note tag filter. If `None`, returns notes regardless of their tag.
+    note_tag: Option<NoteTag>,
 }
 
 /// Request to propose a new multisig transaction.
-#[derive(Debug, Builder, Dissolve)]
+///
+/// # Validation
+///
+/// The request strips control characters from `title` and `memo`, treats blank values as absent
+/// and validates that they do not exceed [`Self::MAX_TITLE_LEN`] and [`Self::MAX_MEMO_LEN`]
+/// characters respectively.
+#[derive(Debug, Dissolve)]
 pub struct ProposeMultisigTxRequest {
     /// The multisig account address to which the transaction applies
     address: AccountIdAddress,
 
     /// The transaction request
     tx_request: TransactionRequest,
+
+    /// Optional pre-computed summary of the transaction request
+    tx_summary: Option<TransactionSummary>,
+
+    /// Optional short human-readable title of the proposal
+    title: Option<String>,
+
+    /// Optional human-readable memo describing the proposal
+    memo: Option<String>,
+}
+
+/// Request to propose consuming a selection of the consumable notes of a multisig account.
+#[derive(Debug, Builder, Dissolve)]
+pub struct ProposeConsumeNotesRequest {
+    /// The multisig account address consuming the notes
+    address: AccountIdAddress,
+
+    /// Which of the consumable notes to consume
+    selector: NoteSelector,
+}
+
+/// Request to propose paying an asset from a multisig account to another account.
+///
+/// # Validation
+///
+/// The request validates that:
+/// - The recipient is not the multisig account itself
+/// - A fungible asset has a non-zero amount
+#[derive(Debug, Dissolve)]
+pub struct ProposePaymentRequest {
+    /// The multisig account address paying the asset
+    address: AccountIdAddress,
+
+    /// The address of the account the payment note is targeted at
+    recipient: AccountIdAddress,
+
+    /// The asset to pay
+    asset: Asset,
+
+    /// The type of the payment note
+    note_type: NoteType,
+}
+
+/// Selects the consumable notes of a multisig account to consume.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NoteSelector {
+    /// Every consumable note.
+    All,
+
+    /// The given notes, each of which must be consumable.
+    NoteIds(Vec<NoteId>),
+
+    /// The fewest notes carrying at least `amount` of the fungible asset issued by `faucet_id`.
+    UpToAmount { faucet_id: AccountId, amount: u64 },
+}
+
+/// Request to dry-run a multisig transaction without proposing it.
+#[derive(Debug, Builder, Dissolve)]
+pub struct DryRunMultisigTxRequest {
+    /// The multisig account address to which the transaction applies
+    address: AccountIdAddress,
+
+    /// The transaction request
+    tx_request: TransactionRequest,
+}
+
+/// Request to validate that a multisig transaction is executable without proposing it.
+#[derive(Debug, Builder, Dissolve)]
+pub struct ValidateMultisigTxRequest {
+    /// The multisig account address to which the transaction applies
+    address: AccountIdAddress,
+
+    /// The transaction request
+    tx_request: TransactionRequest,
+}
+
+/// Request to re-propose a failed transaction with a freshly computed summary.
+#[derive(Debug, Builder, Dissolve)]
+pub struct ReproposeMultisigTxRequest {
+    /// The failed transaction ID to re-propose
+    tx_id: MultisigTxId,
+}
+
+/// Request to execute a transaction that met its threshold while automatic processing is
+/// disabled.
+#[derive(Debug, Builder, Dissolve)]
+pub struct ExecuteReadyTxRequest {
+    /// The ready to execute transaction ID
+    tx_id: MultisigTxId,
 }
 
 /// Request to add an approver's signature to a pending transaction.
@@ -57,8 +208,33 @@ pub struct AddSignatureRequest {
     /// The accountaddress of the approver adding their signature
     approver: AccountIdAddress,
 
-    /// The cryptographic signature
-    signature: Signature,
+    /// The cryptographic signature, of the approver's key scheme
+    #[builder(into)]
+    signature: ApproverSignature,
+}
+
+/// Request to check whether an approver has signed a transaction.
+#[derive(Debug, Builder, Dissolve)]
+pub struct HasApproverSignedRequest {
+    /// The transaction ID to check
+    tx_id: MultisigTxId,
+
+    /// The account address of the approver to check
+    approver: AccountIdAddress,
+}
+
+/// Request to add several approvers' signatures to a pending transaction at once.
+///
+/// # Validation
+///
+/// The request validates that `signatures` is non-empty.
+#[derive(Debug, Dissolve)]
+pub struct AddSignaturesRequest {
+    /// The transaction ID to add the signatures to
+    tx_id: MultisigTxId,
+
+    /// The account addresses of the approvers paired with their cryptographic signatures
+    signatures: Vec<(AccountIdAddress, ApproverSignature)>,
 }
 
 /// Request to retrieve a multisig account by address.
@@ -68,11 +244,81 @@ pub struct GetMultisigAccountRequest {
     multisig_account_id_address: AccountIdAddress,
 }
 
+/// Request to reconcile a multisig account's stored view against its on-chain state.
+#[derive(Debug, Builder, Dissolve)]
+pub struct ReconcileMultisigAccountRequest {
+    /// The multisig account address to reconcile
+    multisig_account_id_address: AccountIdAddress,
+}
+
+/// Request to verify a multisig account's stored view is consistent with its on-chain state.
+#[derive(Debug, Builder, Dissolve)]
+pub struct VerifyAccountConsistencyRequest {
+    /// The multisig account address to verify
+    multisig_account_id_address: AccountIdAddress,
+}
+
+/// Request to recover the status of the transactions of a multisig account from the chain.
+#[derive(Debug, Builder, Dissolve)]
+pub struct ReconcileMultisigTxsRequest {
+    /// The multisig account address whose transactions to reconcile
+    multisig_account_id_address: AccountIdAddress,
+}
+
+/// Request to archive or unarchive a multisig account.
+#[derive(Debug, Builder, Dissolve)]
+pub struct ArchiveMultisigAccountRequest {
+    /// The multisig account address to archive or unarchive
+    multisig_account_id_address: AccountIdAddress,
+
+    /// Whether the account is archived, or unarchived if `false`
+    archived: bool,
+}
+
+/// Request to remove an approver from a multisig account, optionally lowering its threshold.
+#[derive(Debug, Builder, Dissolve)]
+pub struct RemoveMultisigApproverRequest {
+    /// The multisig account address to remove the approver from
+    multisig_account_id_address: AccountIdAddress,
+
+    /// The account address of the approver to remove
+    approver: AccountIdAddress,
+
+    /// Optional threshold lowered to along with the removal, the threshold is kept if not given
+    threshold: Option<NonZeroU32>,
+}
+
 /// Request to list approvers for a multisig account.
 #[derive(Debug, Builder, Dissolve)]
 pub struct ListMultisigApproverRequest {
     /// The multisig account address to query
     multisig_account_id_address: AccountIdAddress,
+
+    /// Optional text the label of the listed approvers contains, ignoring case, all approvers
+    /// being listed if not given
+    label_contains: Option<String>,
+
+    /// Optional cursor of the page to list, i.e. the `next_cursor` of the previous page listed
+    after: Option<Cursor<String>>,
+
+    /// Optional maximum number of approvers to list, all are listed if not given
+    limit: Option<NonZeroU32>,
+}
+
+/// Request to list the multisig accounts, along with their approver and pending transaction
+/// counts.
+#[derive(Debug, Builder, Dissolve)]
+pub struct ListMultisigAccountsRequest {
+    /// Whether archived accounts are listed too
+    #[builder(default)]
+    include_archived: bool,
+
+    /// Optional cursor of the page to list, i.e. the `next_cursor` of the previous page listed
+    after: Option<Cursor<String>>,
+
+    /// Optional maximum number of accounts to list, [`Self::DEFAULT_LIMIT`] if not given, lowered
+    /// to [`Self::MAX_LIMIT`] if above
+    limit: Option<NonZeroU32>,
 }
 
 /// Request to retrieve transaction statistics for a multisig account.
@@ -82,6 +328,38 @@ pub struct GetMultisigTxStatsRequest {
     multisig_account_id_address: AccountIdAddress,
 }
 
+/// Request to retrieve the number of transactions of a multisig account proposed over time.
+#[derive(Debug, Builder, Dissolve)]
+pub struct GetMultisigTxTrendRequest {
+    /// The multisig account address to query
+    multisig_account_id_address: AccountIdAddress,
+
+    /// The width of the buckets transactions are counted in, days if not given
+    #[builder(default)]
+    granularity: TrendGranularity,
+}
+
+/// Request to retrieve a multisig transaction by its ID.
+#[derive(Debug, Builder, Dissolve)]
+pub struct GetMultisigTxRequest {
+    /// The transaction ID to look up
+    tx_id: MultisigTxId,
+}
+
+/// Request to retrieve the multisig account a transaction belongs to.
+#[derive(Debug, Builder, Dissolve)]
+pub struct GetMultisigTxAccountRequest {
+    /// The transaction ID to look up
+    tx_id: MultisigTxId,
+}
+
+/// Request to retrieve a multisig transaction by the commitment of its summary.
+#[derive(Debug, Builder, Dissolve)]
+pub struct GetMultisigTxBySummaryCommitRequest {
+    /// The commitment of the transaction summary, i.e. the message signed by the approvers
+    tx_summary_commit: Word,
+}
+
 /// Request to list transactions for a multisig account.
 #[derive(Debug, Builder, Dissolve)]
 pub struct ListMultisigTxRequest {
@@ -90,6 +368,49 @@ pub struct ListMultisigTxRequest {
 
     /// Optional status filter (Pending, Success, Failure)
     tx_status_filter: Option<MultisigTxStatus>,
+
+    /// Optional order to list the transactions in, most recent first if not given
+    sort: Option<TxSort>,
+
+    /// Optional cursor of the page to list, i.e. the `next_cursor` of the previous page listed
+    /// in the same `sort` order
+    after: Option<Cursor>,
+
+    /// Optional maximum number of transactions to list, all are listed if not given
+    limit: Option<NonZeroU32>,
+}
+
+/// Request to list the audit log of a multisig account.
+#[derive(Debug, Builder, Dissolve)]
+pub struct ListAuditEventsRequest {
+    /// The multisig account address to query
+    multisig_account_id_address: AccountIdAddress,
+
+    /// Optional cursor of the page to list, i.e. the `next_cursor` of the previous page listed
+    after: Option<Cursor>,
+
+    /// Optional maximum number of events to list, [`Self::DEFAULT_LIMIT`] if not given, lowered
+    /// to [`Self::MAX_LIMIT`] if above
+    limit: Option<NonZeroU32>,
+}
+
+/// Request to list the pending transactions of every multisig account an approver belongs to.
+#[derive(Debug, Builder, Dissolve)]
+pub struct ListApproverPendingTxsRequest {
+    /// The account address of the approver to query
+    approver: AccountIdAddress,
+
+    /// Optional cursor of the page to list, i.e. the `next_cursor` of the previous page listed
+    after: Option<Cursor>,
+
+    /// Optional maximum number of transactions to list, [`Self::DEFAULT_LIMIT`] if not given,
+    /// lowered to [`Self::MAX_LIMIT`] if above
+    limit: Option<NonZeroU32>,
+}
+
+impl CreateMultisigAccountRequest {
+    /// The maximum number of characters allowed in an approver label.
+    pub const MAX_LABEL_LEN: usize = 64;
 }
 
 #[bon::bon]
@@ -98,16 +419,27 @@ impl CreateMultisigAccountRequest {
     ///
     /// # Parameters
     ///
-    /// * `threshold` - Number of signatures required (must not exceed the number of approvers)
-    /// * `approvers` - List of approver account addresses
-    /// * `pub_key_commits` - List of public key commitments (must match approver count)
+    /// * `threshold` - Total approver weight required (must not exceed the total weight of the
+    ///   approvers)
+    /// * `approvers` - List of distinct approver account addresses (at most [`MAX_APPROVERS`])
+    /// * `pub_key_commits` - List of distinct approver public keys, Falcon public key commitments
+    ///   or secp256k1 public keys (must match approver count)
+    /// * `weights` - Optional list of approver weights (must match approver count), every
+    ///   approver weighs 1 if not given
+    /// * `labels` - Optional list of optional approver labels (must match approver count, each at
+    ///   most [`Self::MAX_LABEL_LEN`] characters), no approver is labeled if not given
+    /// * `address_interface` - Optional interface of the multisig account address,
+    ///   [`AddressInterface::BasicWallet`] if not given
     ///
     /// Returns an error if validation fails.
     #[builder]
-    pub fn new(
+    pub fn new<K: Into<ApproverKey>>(
         threshold: NonZeroU32,
         approvers: Vec<AccountIdAddress>,
-        pub_key_commits: Vec<PublicKey>,
+        pub_key_commits: Vec<K>,
+        weights: Option<Vec<NonZeroU32>>,
+        labels: Option<Vec<Option<String>>>,
+        address_interface: Option<AddressInterface>,
     ) -> Result<Self, CreateMultisigAccountRequestError> {
         if approvers.is_empty() {
             return Err(CreateMultisigAccountRequestError::EmptyApprovers);
@@ -121,13 +453,341 @@ impl CreateMultisigAccountRequest {
             return Err(CreateMultisigAccountRequestError::ApproversPubKeyCommitsLengthMismatch);
         }
 
-        let threshold_usize = usize::try_from(threshold.get())
-            .map_err(|e| CreateMultisigAccountRequestError::other(e.to_string()))?;
+        if approvers.len() > MAX_APPROVERS {
+            let count = approvers.len();
+            return Err(CreateMultisigAccountRequestError::TooManyApprovers { count });
+        }
+
+        if has_duplicates(approvers.iter().map(AccountIdAddress::id)) {
+            return Err(CreateMultisigAccountRequestError::DuplicateApprover);
+        }
+
+        let pub_key_commits: Vec<ApproverKey> =
+            pub_key_commits.into_iter().map(Into::into).collect();
+
+        if has_duplicates(pub_key_commits.iter().map(ApproverKey::to_bytes)) {
+            return Err(CreateMultisigAccountRequestError::DuplicatePubKeyCommit);
+        }
+
+        let weights = weights.unwrap_or_else(|| vec![NonZeroU32::MIN; approvers.len()]);
+
+        if approvers.len() != weights.len() {
+            return Err(CreateMultisigAccountRequestError::ApproversWeightsLengthMismatch);
+        }
+
+        // TODO: lift once the multisig auth component supports weighted approvers
+        if weights.iter().any(|&weight| weight != NonZeroU32::MIN) {
+            return Err(CreateMultisigAccountRequestError::UnsupportedWeight);
+        }
 
-        if threshold_usize > approvers.len() {
+        let total_weight: u64 = weights.iter().map(|weight| u64::from(weight.get())).sum();
+
+        if u64::from(threshold.get()) > total_weight {
             return Err(CreateMultisigAccountRequestError::ExcessThreshold);
         }
 
-        Ok(Self { threshold, approvers, pub_key_commits })
+        let labels: Vec<_> = labels
+            .unwrap_or_else(|| vec![None; approvers.len()])
+            .into_iter()
+            .map(|label| label.and_then(sanitize_text))
+            .collect();
+
+        if approvers.len() != labels.len() {
+            return Err(CreateMultisigAccountRequestError::ApproversLabelsLengthMismatch);
+        }
+
+        if labels.iter().flatten().any(|label| label.chars().count() > Self::MAX_LABEL_LEN) {
+            return Err(CreateMultisigAccountRequestError::LabelTooLong {
+                max_len: Self::MAX_LABEL_LEN,
+            });
+        }
+
+        let address_interface = address_interface.unwrap_or(AddressInterface::BasicWallet);
+
+        Ok(Self {
+            threshold,
+            approvers,
+            pub_key_commits,
+            weights,
+            labels,
+            address_interface,
+        })
+    }
+}
+
+impl ListAuditEventsRequest {
+    /// The number of events listed when no limit is given.
+    pub const DEFAULT_LIMIT: NonZeroU32 = NonZeroU32::new(100).unwrap();
+
+    /// The maximum number of events listed at once.
+    pub const MAX_LIMIT: NonZeroU32 = NonZeroU32::new(1_000).unwrap();
+}
+
+impl ListMultisigAccountsRequest {
+    /// The number of accounts listed when no limit is given.
+    pub const DEFAULT_LIMIT: NonZeroU32 = NonZeroU32::new(100).unwrap();
+
+    /// The maximum number of accounts listed at once.
+    pub const MAX_LIMIT: NonZeroU32 = NonZeroU32::new(1_000).unwrap();
+}
+
+impl ListApproverPendingTxsRequest {
+    /// The number of transactions listed when no limit is given.
+    pub const DEFAULT_LIMIT: NonZeroU32 = NonZeroU32::new(100).unwrap();
+
+    /// The maximum number of transactions listed at once.
+    pub const MAX_LIMIT: NonZeroU32 = NonZeroU32::new(1_000).unwrap();
+}
+
+impl ProposeMultisigTxRequest {
+    /// The maximum number of characters allowed in a proposal title.
+    pub const MAX_TITLE_LEN: usize = 128;
+
+    /// The maximum number of characters allowed in a proposal memo.
+    pub const MAX_MEMO_LEN: usize = 1024;
+}
+
+#[bon::bon]
+impl ProposeMultisigTxRequest {
+    /// Creates a new multisig transaction proposal request with validation.
+    ///
+    /// # Parameters
+    ///
+    /// * `address` - The multisig account address to which the transaction applies
+    /// * `tx_request` - The transaction request
+    /// * `tx_summary` - Optional pre-computed transaction summary, see
+    ///   [`propose_multisig_tx`](crate::MultisigEngine::propose_multisig_tx)
+    /// * `title` - Optional title (at most [`Self::MAX_TITLE_LEN`] characters)
+    /// * `memo` - Optional memo (at most [`Self::MAX_MEMO_LEN`] characters)
+    ///
+    /// Returns an error if validation fails.
+    #[builder]
+    pub fn new(
+        address: AccountIdAddress,
+        tx_request: TransactionRequest,
+        tx_summary: Option<TransactionSummary>,
+        title: Option<String>,
+        memo: Option<String>,
+    ) -> Result<Self, ProposeMultisigTxRequestError> {
+        let title = title.and_then(sanitize_text);
+        if title.as_ref().is_some_and(|t| t.chars().count() > Self::MAX_TITLE_LEN) {
+            return Err(ProposeMultisigTxRequestError::TitleTooLong {
+                max_len: Self::MAX_TITLE_LEN,
+            });
+        }
+
+        let memo = memo.and_then(sanitize_text);
+        if memo.as_ref().is_some_and(|m| m.chars().count() > Self::MAX_MEMO_LEN) {
+            return Err(ProposeMultisigTxRequestError::MemoTooLong { max_len: Self::MAX_MEMO_LEN });
+        }
+
+        Ok(Self {
+            address,
+            tx_request,
+            tx_summary,
+            title,
+            memo,
+        })
+    }
+}
+
+#[bon::bon]
+impl ProposePaymentRequest {
+    /// Creates a new payment proposal request with validation.
+    ///
+    /// # Parameters
+    ///
+    /// * `address` - The multisig account address paying the asset
+    /// * `recipient` - The address of the account the payment note is targeted at
+    /// * `asset` - The asset to pay
+    /// * `note_type` - The type of the payment note
+    ///
+    /// Returns an error if validation fails.
+    #[builder]
+    pub fn new(
+        address: AccountIdAddress,
+        recipient: AccountIdAddress,
+        asset: Asset,
+        note_type: NoteType,
+    ) -> Result<Self, ProposePaymentRequestError> {
+        if recipient.id() == address.id() {
+            return Err(ProposePaymentRequestError::SelfPayment);
+        }
+
+        if matches!(asset, Asset::Fungible(fungible_asset) if fungible_asset.amount() == 0) {
+            return Err(ProposePaymentRequestError::ZeroAmount);
+        }
+
+        Ok(Self { address, recipient, asset, note_type })
+    }
+}
+
+#[bon::bon]
+impl AddSignaturesRequest {
+    /// Creates a new batch signature submission request with validation.
+    ///
+    /// # Parameters
+    ///
+    /// * `tx_id` - The transaction ID to add the signatures to
+    /// * `signatures` - List of approver account addresses paired with their signatures
+    ///
+    /// Returns an error if validation fails.
+    #[builder]
+    pub fn new(
+        tx_id: MultisigTxId,
+        signatures: Vec<(AccountIdAddress, ApproverSignature)>,
+    ) -> Result<Self, AddSignaturesRequestError> {
+        if signatures.is_empty() {
+            return Err(AddSignaturesRequestError::EmptySignatures);
+        }
+
+        Ok(Self { tx_id, signatures })
+    }
+}
+
+/// Strips control characters and surrounding whitespace, returning `None` if nothing remains.
+fn sanitize_text(text: String) -> Option<String> {
+    let text: String = text.chars().filter(|c| !c.is_control()).collect();
+    let text = text.trim();
+
+    (!text.is_empty()).then(|| text.to_owned())
+}
+
+/// Returns whether any item appears more than once.
+fn has_duplicates<T: Eq + Hash>(items: impl IntoIterator<Item = T>) -> bool {
+    let mut seen = HashSet::new();
+    items.into_iter().any(|item| !seen.insert(item))
+}
+
+#[cfg(test)]
+mod tests {
+    use core::num::NonZeroU32;
+
+    use miden_multisig_coordinator_domain::key::ApproverKey;
+    use miden_multisig_test_utils::{account_id_address, pay_to_id_tx_request};
+    use miden_objects::{
+        crypto::dsa::rpo_falcon512::SecretKey,
+        testing::account_id::{
+            ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
+            ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE, ACCOUNT_ID_SENDER,
+        },
+    };
+
+    use super::{
+        CreateMultisigAccountRequest, CreateMultisigAccountRequestError, ProposeMultisigTxRequest,
+    };
+
+    fn create_request(
+        threshold: u32,
+        weights: Option<Vec<u32>>,
+    ) -> Result<CreateMultisigAccountRequest, CreateMultisigAccountRequestError> {
+        let approvers = [ACCOUNT_ID_SENDER, ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE]
+            .map(account_id_address);
+
+        let weights = weights.map(|weights| {
+            weights.into_iter().map(|weight| NonZeroU32::new(weight).unwrap()).collect()
+        });
+
+        CreateMultisigAccountRequest::builder()
+            .threshold(NonZeroU32::new(threshold).unwrap())
+            .approvers(approvers.to_vec())
+            .pub_key_commits(vec![
+                ApproverKey::Falcon(SecretKey::new().public_key()),
+                ApproverKey::Falcon(SecretKey::new().public_key()),
+            ])
+            .maybe_weights(weights)
+            .build()
+    }
+
+    #[test]
+    fn approvers_weigh_one_unless_weighted_otherwise() {
+        // Act
+        let unweighted = create_request(2, None).unwrap();
+        let weighted = create_request(2, Some(vec![1, 1])).unwrap();
+
+        // Assert
+        assert_eq!(unweighted.weights, [NonZeroU32::MIN; 2]);
+        assert_eq!(weighted.weights, [NonZeroU32::MIN; 2]);
+    }
+
+    #[test]
+    fn weights_not_matching_approvers_other_than_one_or_short_of_threshold_are_rejected() {
+        // Act
+        let missing_weight = create_request(1, Some(vec![1]));
+        let extra_weight = create_request(1, Some(vec![1, 1, 1]));
+        let unsupported_weight = create_request(1, Some(vec![1, 2]));
+        let excess_threshold = create_request(3, Some(vec![1, 1]));
+
+        // Assert
+        assert!(matches!(
+            missing_weight,
+            Err(CreateMultisigAccountRequestError::ApproversWeightsLengthMismatch),
+        ));
+        assert!(matches!(
+            extra_weight,
+            Err(CreateMultisigAccountRequestError::ApproversWeightsLengthMismatch),
+        ));
+        assert!(matches!(
+            unsupported_weight,
+            Err(CreateMultisigAccountRequestError::UnsupportedWeight),
+        ));
+        assert!(matches!(
+            excess_threshold,
+            Err(CreateMultisigAccountRequestError::ExcessThreshold)
+        ));
+    }
+
+    fn propose_request(title: &str, memo: &str) -> ProposeMultisigTxRequest {
+        let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+        let recipient_addr = account_id_address(ACCOUNT_ID_SENDER);
+
+        ProposeMultisigTxRequest::builder()
+            .address(multisig_addr)
+            .tx_request(pay_to_id_tx_request(multisig_addr.id(), recipient_addr.id()))
+            .title(title.to_owned())
+            .memo(memo.to_owned())
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn title_and_memo_are_stripped_of_control_characters_and_trimmed() {
+        // Act
+        let request =
+            propose_request("Consume\u{7} notes", "  Sweep faucet\r notes into the multisig\n");
+
+        // Assert
+        assert_eq!(request.title.as_deref(), Some("Consume notes"));
+        assert_eq!(request.memo.as_deref(), Some("Sweep faucet notes into the multisig"));
+    }
+
+    #[test]
+    fn blank_title_and_memo_are_treated_as_absent() {
+        // Act
+        let request = propose_request(" \u{7} ", "\t\n");
+
+        // Assert
+        assert_eq!(request.title, None);
+        assert_eq!(request.memo, None);
+    }
+
+    #[test]
+    fn title_and_memo_are_measured_once_stripped_of_control_characters() {
+        // Arrange
+        let title = format!("{}\u{7}", "t".repeat(ProposeMultisigTxRequest::MAX_TITLE_LEN));
+        let memo = format!("{}\u{7}", "m".repeat(ProposeMultisigTxRequest::MAX_MEMO_LEN));
+
+        // Act
+        let request = propose_request(&title, &memo);
+
+        // Assert
+        assert_eq!(
+            request.title.map(|title| title.len()),
+            Some(ProposeMultisigTxRequest::MAX_TITLE_LEN)
+        );
+        assert_eq!(
+            request.memo.map(|memo| memo.len()),
+            Some(ProposeMultisigTxRequest::MAX_MEMO_LEN)
+        );
     }
 }
@@ -8,6 +8,18 @@ pub enum RequestError {
     /// Error creating a multisig account request.
     #[error("create multisig account error: {0}")]
     CreateMultisigAccount(#[from] CreateMultisigAccountRequestError),
+
+    /// Error creating a multisig transaction proposal request.
+    #[error("propose multisig tx error: {0}")]
+    ProposeMultisigTx(#[from] ProposeMultisigTxRequestError),
+
+    /// Error creating a payment proposal request.
+    #[error("propose payment error: {0}")]
+    ProposePayment(#[from] ProposePaymentRequestError),
+
+    /// Error creating a batch signature submission request.
+    #[error("add signatures error: {0}")]
+    AddSignatures(#[from] AddSignaturesRequestError),
 }
 
 /// Errors that can occur when validating a multisig account creation request.
@@ -25,10 +37,44 @@ pub enum CreateMultisigAccountRequestError {
     #[error("approvers and pub key commits length mismatch")]
     ApproversPubKeyCommitsLengthMismatch,
 
-    /// The threshold exceeds the number of approvers
-    #[error("excess threshold error: threshold exceeds number of approvers")]
+    /// There are more approvers than the multisig auth component supports, see
+    /// [`MAX_APPROVERS`](miden_multisig_client::MAX_APPROVERS)
+    #[error(
+        "too many approvers error: {count} approvers exceed the maximum of {}",
+        miden_multisig_client::MAX_APPROVERS
+    )]
+    TooManyApprovers { count: usize },
+
+    /// An approver is listed more than once, which would give it several approval slots
+    #[error("duplicate approver error")]
+    DuplicateApprover,
+
+    /// A public key commitment is listed more than once, which would let one key sign for several
+    /// approvers
+    #[error("duplicate pub key commit error")]
+    DuplicatePubKeyCommit,
+
+    /// The approvers and weights lists have different lengths
+    #[error("approvers and weights length mismatch")]
+    ApproversWeightsLengthMismatch,
+
+    /// An approver weight other than 1 was given, which the multisig auth component does not
+    /// support yet
+    #[error("unsupported weight error: approver weights other than 1 are not supported")]
+    UnsupportedWeight,
+
+    /// The threshold exceeds the total weight of the approvers
+    #[error("excess threshold error: threshold exceeds total approver weight")]
     ExcessThreshold,
 
+    /// The approvers and labels lists have different lengths
+    #[error("approvers and labels length mismatch")]
+    ApproversLabelsLengthMismatch,
+
+    /// An approver label exceeds the maximum allowed length
+    #[error("label too long error: label exceeds {max_len} characters")]
+    LabelTooLong { max_len: usize },
+
     /// Other validation error
     #[error("other error: {0}")]
     Other(Cow<'static, str>),
@@ -42,3 +88,35 @@ impl CreateMultisigAccountRequestError {
         Self::Other(err.into())
     }
 }
+
+/// Errors that can occur when validating a multisig transaction proposal request.
+#[derive(Debug, thiserror::Error)]
+pub enum ProposeMultisigTxRequestError {
+    /// The title exceeds the maximum allowed length
+    #[error("title too long error: title exceeds {max_len} characters")]
+    TitleTooLong { max_len: usize },
+
+    /// The memo exceeds the maximum allowed length
+    #[error("memo too long error: memo exceeds {max_len} characters")]
+    MemoTooLong { max_len: usize },
+}
+
+/// Errors that can occur when validating a payment proposal request.
+#[derive(Debug, thiserror::Error)]
+pub enum ProposePaymentRequestError {
+    /// The recipient is the paying multisig account itself
+    #[error("self payment error: recipient is the paying account")]
+    SelfPayment,
+
+    /// The fungible asset to pay has a zero amount
+    #[error("zero amount error: fungible asset amount is zero")]
+    ZeroAmount,
+}
+
+/// Errors that can occur when validating a batch signature submission request.
+#[derive(Debug, thiserror::Error)]
+pub enum AddSignaturesRequestError {
+    /// The signatures list is empty
+    #[error("empty signatures error")]
+    EmptySignatures,
+}
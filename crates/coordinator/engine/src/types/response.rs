@@ -1,12 +1,26 @@
 //! Response types for multisig engine operations.
 
+use core::num::NonZeroU32;
+
 use dissolve_derive::Dissolve;
-use miden_client::account::Account;
+use miden_client::{
+    account::{Account, AccountIdAddress},
+    note::{NoteConsumability, NoteId},
+    store::InputNoteRecord,
+    transaction::TransactionResult,
+};
 use miden_multisig_coordinator_domain::{
-    account::{MultisigAccount, MultisigApprover},
-    tx::{MultisigTx, MultisigTxId, MultisigTxStats},
+    account::{MultisigAccount, MultisigApprover, WithApprovers, WithPubKeyCommits},
+    audit::AuditEvent,
+    cursor::Cursor,
+    key::ApproverKey,
+    tx::{MultisigTx, MultisigTxId, MultisigTxStats, TxTrendBucket},
+};
+use miden_objects::{
+    block::BlockNumber, crypto::dsa::rpo_falcon512::PublicKey, transaction::TransactionSummary,
 };
-use miden_objects::transaction::TransactionSummary;
+
+use crate::{MultisigClientRuntimeDrainSummary, MultisigEngine, Stopped};
 
 /// Response from creating a multisig account.
 ///
@@ -20,6 +34,32 @@ pub struct CreateMultisigAccountResponse {
     multisig_account: MultisigAccount,
 }
 
+/// Response from importing a multisig account created elsewhere.
+///
+/// Contains both the on-chain account and the coordinator's view of the persisted multisig account.
+#[derive(Debug, Dissolve)]
+pub struct ImportMultisigAccountResponse {
+    /// The account object fetched from the node by the
+    /// [`MultisigClient`](miden_multisig_client::MultisigClient)
+    miden_account: Account,
+
+    /// The coordinator's view of the persisted multisig account
+    multisig_account: MultisigAccount,
+}
+
+/// Response from querying consumable notes.
+#[derive(Debug, Dissolve)]
+pub struct GetConsumableNotesResponse {
+    /// The consumable notes, along with the accounts able to consume them
+    notes: Vec<(InputNoteRecord, Vec<NoteConsumability>)>,
+
+    /// The block the multisig client is synced to, as of which the notes are consumable
+    block_num: BlockNumber,
+
+    /// Whether the block is behind the chain tip, i.e. some notes may have been consumed since
+    stale: bool,
+}
+
 /// Response from proposing a multisig transaction.
 #[derive(Debug, Dissolve)]
 pub struct ProposeMultisigTxResponse {
@@ -30,6 +70,167 @@ pub struct ProposeMultisigTxResponse {
     tx_summary: TransactionSummary,
 }
 
+/// Response from proposing to consume a selection of consumable notes.
+#[derive(Debug, Dissolve)]
+pub struct ProposeConsumeNotesResponse {
+    /// The unique identifier for the transaction in the coordinator's database
+    tx_id: MultisigTxId,
+
+    /// The transaction summary to be signed by approvers
+    tx_summary: TransactionSummary,
+
+    /// The ids of the selected notes consumed by the transaction
+    note_ids: Vec<NoteId>,
+}
+
+/// Response from proposing a payment.
+#[derive(Debug, Dissolve)]
+pub struct ProposePaymentResponse {
+    /// The unique identifier for the transaction in the coordinator's database
+    tx_id: MultisigTxId,
+
+    /// The transaction summary to be signed by approvers
+    tx_summary: TransactionSummary,
+}
+
+/// Response from dry-running a multisig transaction.
+#[derive(Debug, Dissolve)]
+pub struct DryRunMultisigTxResponse {
+    /// The transaction summary approvers would sign if the transaction was proposed
+    tx_summary: TransactionSummary,
+}
+
+/// Response from validating a multisig transaction.
+///
+/// Exactly one of `tx_summary` and `reason` is set, depending on whether the transaction is
+/// executable.
+#[derive(Debug, Dissolve)]
+pub struct ValidateMultisigTxResponse {
+    /// The transaction summary approvers would sign, if the transaction is executable
+    tx_summary: Option<TransactionSummary>,
+
+    /// Why the transaction is not executable, if it is not
+    reason: Option<String>,
+}
+
+/// Response from re-proposing a failed multisig transaction.
+#[derive(Debug, Dissolve)]
+pub struct ReproposeMultisigTxResponse {
+    /// The transaction ID, unchanged by the re-proposal
+    tx_id: MultisigTxId,
+
+    /// The freshly computed transaction summary to be signed by approvers
+    tx_summary: TransactionSummary,
+}
+
+/// Response from reconciling a multisig account against its on-chain state.
+#[derive(Debug, Dissolve)]
+pub struct ReconcileMultisigAccountResponse {
+    /// The threshold read from the on-chain account storage
+    on_chain_threshold: u32,
+
+    /// The approvers' public key commitments read from the on-chain account storage, in approver
+    /// index order
+    on_chain_pub_key_commits: Vec<PublicKey>,
+
+    /// The differences between the coordinator's stored view and the on-chain state
+    mismatches: Vec<MultisigAccountMismatch>,
+}
+
+/// Response from verifying a multisig account's stored view against its on-chain state.
+#[derive(Debug, Dissolve)]
+pub struct VerifyAccountConsistencyResponse {
+    /// The fields whose stored value drifted from the on-chain one, empty if the stored view is
+    /// consistent with the chain
+    drifts: Vec<Drift>,
+}
+
+/// Response from recovering the status of the transactions of a multisig account from the chain.
+#[derive(Debug, Dissolve)]
+pub struct ReconcileMultisigTxsResponse {
+    /// The IDs of the transactions found executed on chain, which are now marked as successful
+    reconciled_tx_ids: Vec<MultisigTxId>,
+
+    /// IDs of the transactions whose stored data cannot be deserialized, which are left out of
+    /// the reconciliation
+    corrupt_tx_ids: Vec<MultisigTxId>,
+}
+
+/// Response from stopping the multisig client runtime.
+#[derive(Dissolve)]
+pub struct StopMultisigClientRuntimeResponse {
+    /// The stopped engine, which can no longer perform blockchain operations
+    engine: MultisigEngine<Stopped>,
+
+    /// Summary of the msgs still queued when the runtime was stopped, summed over all workers
+    drain_summary: MultisigClientRuntimeDrainSummary,
+}
+
+/// A difference between the coordinator's stored view of a multisig account and its on-chain
+/// state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MultisigAccountMismatch {
+    /// The stored threshold differs from the on-chain one.
+    Threshold { stored: u32, on_chain: u32 },
+
+    /// The public key commitment at the approver index differs, `None` if there is no approver
+    /// at that index on the respective side. Stored keys are compared by the commitment the
+    /// multisig account holds on chain, see [`ApproverKey::commitment`].
+    PubKeyCommit {
+        approver_index: usize,
+        stored: Option<ApproverKey>,
+        on_chain: Option<PublicKey>,
+    },
+}
+
+/// A field of a multisig account whose stored value drifted from its on-chain value.
+#[derive(Debug, Clone, PartialEq, Eq, Dissolve)]
+pub struct Drift {
+    /// The drifted field
+    field: DriftField,
+
+    /// The stored value of the field, `None` if the field is missing from the store or unknown
+    db_value: Option<String>,
+
+    /// The on-chain value of the field, `None` if the field is missing from the chain
+    chain_value: Option<String>,
+}
+
+/// A field of a multisig account compared by
+/// [`verify_account_consistency`](crate::MultisigEngine::verify_account_consistency).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DriftField {
+    /// The nonce of the account.
+    Nonce,
+
+    /// The threshold of the account.
+    Threshold,
+
+    /// The public key commitment of the approver at the index.
+    PubKeyCommit { approver_index: usize },
+}
+
+/// Response from adding several approvers' signatures to a pending transaction at once.
+#[derive(Debug, Dissolve)]
+pub struct AddSignaturesResponse {
+    /// The number of signatures that were accepted
+    accepted: usize,
+
+    /// Whether the accepted signatures made the transaction cross its threshold
+    threshold_met: bool,
+
+    /// The transaction result if the threshold was crossed and the transaction was processed,
+    /// `None` otherwise
+    tx_result: Option<TransactionResult>,
+}
+
+/// Response from checking whether an approver has signed a transaction.
+#[derive(Debug, Dissolve)]
+pub struct HasApproverSignedResponse {
+    /// Whether the approver signed the transaction
+    signed: bool,
+}
+
 /// Response from retrieving a multisig account.
 #[derive(Debug, Dissolve)]
 pub struct GetMultisigAccountResponse {
@@ -37,11 +238,74 @@ pub struct GetMultisigAccountResponse {
     multisig_account: Option<MultisigAccount>,
 }
 
+/// Response from retrieving a multisig account together with its approvers.
+#[derive(Debug, Dissolve)]
+pub struct GetMultisigAccountFullResponse {
+    /// The account with its approvers and public key commitments if found, `None` otherwise
+    multisig_account: Option<MultisigAccount<WithApprovers, WithPubKeyCommits>>,
+}
+
+/// Response from retrieving the summary of a multisig account.
+#[derive(Debug, Dissolve)]
+pub struct GetMultisigAccountSummaryResponse {
+    /// The `(threshold, approver_count)` of the account if found, `None` otherwise
+    summary: Option<(NonZeroU32, u32)>,
+}
+
+/// Response from archiving or unarchiving a multisig account.
+#[derive(Debug, Dissolve)]
+pub struct ArchiveMultisigAccountResponse {
+    /// The archived or unarchived account if found, `None` otherwise
+    multisig_account: Option<MultisigAccount>,
+}
+
+/// Response from removing an approver from a multisig account.
+#[derive(Debug, Dissolve)]
+pub struct RemoveMultisigApproverResponse {
+    /// The account without the approver if the account and the approver were found, `None`
+    /// otherwise
+    multisig_account: Option<MultisigAccount<WithApprovers, WithPubKeyCommits>>,
+}
+
 /// Response from listing approvers for a multisig account.
 #[derive(Debug, Dissolve)]
 pub struct ListMultisigApproverResponse {
     /// List of approvers matching the query criteria
     approvers: Vec<MultisigApprover>,
+
+    /// Cursor of the next page, `None` if this is the last page
+    next_cursor: Option<Cursor<String>>,
+}
+
+/// Response from retrieving the approvers of a multisig account in approver index order.
+#[derive(Debug, Dissolve)]
+pub struct GetMultisigApproverOrderResponse {
+    /// The approvers of the account along with their approver index if found, `None` otherwise
+    approvers: Option<Vec<(u32, MultisigApprover)>>,
+}
+
+/// Response from listing the multisig accounts.
+#[derive(Debug, Dissolve)]
+pub struct ListMultisigAccountsResponse {
+    /// The listed accounts, in creation order
+    accounts: Vec<MultisigAccountOverview>,
+
+    /// Cursor of the next page, `None` if this is the last page
+    next_cursor: Option<Cursor<String>>,
+}
+
+/// A multisig account along with the counts of its approvers and pending transactions.
+#[derive(Debug, Dissolve)]
+pub struct MultisigAccountOverview {
+    /// The multisig account
+    multisig_account: MultisigAccount,
+
+    /// The number of approvers of the account
+    approver_count: u32,
+
+    /// The number of transactions of the account not executed yet, i.e. pending or ready to
+    /// execute
+    pending_tx_count: u64,
 }
 
 /// Response containing transaction statistics for a multisig account.
@@ -51,11 +315,72 @@ pub struct GetMultisigTxStatsResponse {
     tx_stats: MultisigTxStats,
 }
 
+/// Response from retrieving the number of transactions of a multisig account proposed over time.
+#[derive(Debug, Dissolve)]
+pub struct GetMultisigTxTrendResponse {
+    /// The buckets holding at least one transaction, oldest first
+    buckets: Vec<TxTrendBucket>,
+}
+
+/// Response from retrieving a multisig transaction by its ID.
+#[derive(Debug, Dissolve)]
+pub struct GetMultisigTxResponse {
+    /// The transaction if found, `None` otherwise
+    multisig_tx: Option<MultisigTx>,
+}
+
+/// Response from retrieving the multisig account a transaction belongs to.
+#[derive(Debug, Dissolve)]
+pub struct GetMultisigTxAccountResponse {
+    /// The address of the account if the transaction is found, `None` otherwise
+    address: Option<AccountIdAddress>,
+}
+
+/// Response from retrieving a multisig transaction by the commitment of its summary.
+#[derive(Debug, Dissolve)]
+pub struct GetMultisigTxBySummaryCommitResponse {
+    /// The transaction if found, `None` otherwise
+    multisig_tx: Option<MultisigTx>,
+}
+
 /// Response from listing multisig transactions.
 #[derive(Debug, Dissolve)]
 pub struct ListMultisigTxResponse {
     /// List of transactions matching the query criteria
     txs: Vec<MultisigTx>,
+
+    /// IDs of the transactions matching the query criteria whose stored data cannot be
+    /// deserialized, which are left out of `txs`
+    corrupt_tx_ids: Vec<MultisigTxId>,
+
+    /// Cursor of the next page, `None` if this is the last page
+    next_cursor: Option<Cursor>,
+}
+
+/// Response from listing the pending transactions of every multisig account an approver belongs
+/// to.
+#[derive(Debug, Dissolve)]
+pub struct ListApproverPendingTxsResponse {
+    /// The pending transactions, most recent first, each paired with whether the approver has
+    /// already signed it
+    txs: Vec<(MultisigTx, bool)>,
+
+    /// IDs of the pending transactions whose stored data cannot be deserialized, which are left
+    /// out of `txs`
+    corrupt_tx_ids: Vec<MultisigTxId>,
+
+    /// Cursor of the next page, `None` if this is the last page
+    next_cursor: Option<Cursor>,
+}
+
+/// Response from listing the audit log of a multisig account.
+#[derive(Debug, Dissolve)]
+pub struct ListAuditEventsResponse {
+    /// The audit events, most recent first
+    audit_events: Vec<AuditEvent>,
+
+    /// Cursor of the next page, `None` if this is the last page
+    next_cursor: Option<Cursor>,
 }
 
 #[bon::bon]
@@ -66,6 +391,26 @@ impl CreateMultisigAccountResponse {
     }
 }
 
+#[bon::bon]
+impl ImportMultisigAccountResponse {
+    #[builder]
+    pub(crate) fn new(miden_account: Account, multisig_account: MultisigAccount) -> Self {
+        Self { miden_account, multisig_account }
+    }
+}
+
+#[bon::bon]
+impl GetConsumableNotesResponse {
+    #[builder]
+    pub(crate) fn new(
+        notes: Vec<(InputNoteRecord, Vec<NoteConsumability>)>,
+        block_num: BlockNumber,
+        stale: bool,
+    ) -> Self {
+        Self { notes, block_num, stale }
+    }
+}
+
 #[bon::bon]
 impl ProposeMultisigTxResponse {
     #[builder]
@@ -74,6 +419,69 @@ impl ProposeMultisigTxResponse {
     }
 }
 
+#[bon::bon]
+impl ProposeConsumeNotesResponse {
+    #[builder]
+    pub(crate) fn new(
+        tx_id: MultisigTxId,
+        tx_summary: TransactionSummary,
+        note_ids: Vec<NoteId>,
+    ) -> Self {
+        Self { tx_id, tx_summary, note_ids }
+    }
+}
+
+#[bon::bon]
+impl ProposePaymentResponse {
+    #[builder]
+    pub(crate) fn new(tx_id: MultisigTxId, tx_summary: TransactionSummary) -> Self {
+        Self { tx_id, tx_summary }
+    }
+}
+
+#[bon::bon]
+impl DryRunMultisigTxResponse {
+    #[builder]
+    pub(crate) fn new(tx_summary: TransactionSummary) -> Self {
+        Self { tx_summary }
+    }
+}
+
+#[bon::bon]
+impl ValidateMultisigTxResponse {
+    #[builder]
+    pub(crate) fn new(tx_summary: Option<TransactionSummary>, reason: Option<String>) -> Self {
+        Self { tx_summary, reason }
+    }
+}
+
+impl ValidateMultisigTxResponse {
+    /// Returns whether the transaction is executable.
+    pub fn is_executable(&self) -> bool {
+        self.tx_summary.is_some()
+    }
+}
+
+#[bon::bon]
+impl ReproposeMultisigTxResponse {
+    #[builder]
+    pub(crate) fn new(tx_id: MultisigTxId, tx_summary: TransactionSummary) -> Self {
+        Self { tx_id, tx_summary }
+    }
+}
+
+#[bon::bon]
+impl AddSignaturesResponse {
+    #[builder]
+    pub(crate) fn new(
+        accepted: usize,
+        threshold_met: bool,
+        tx_result: Option<TransactionResult>,
+    ) -> Self {
+        Self { accepted, threshold_met, tx_result }
+    }
+}
+
 #[bon::bon]
 impl GetMultisigAccountResponse {
     #[builder]
@@ -83,13 +491,95 @@ impl GetMultisigAccountResponse {
 }
 
 #[bon::bon]
-impl ListMultisigApproverResponse {
+impl HasApproverSignedResponse {
+    #[builder]
+    pub(crate) fn new(signed: bool) -> Self {
+        Self { signed }
+    }
+}
+
+#[bon::bon]
+impl GetMultisigAccountSummaryResponse {
     #[builder]
-    pub(crate) fn new(approvers: Vec<MultisigApprover>) -> Self {
+    pub(crate) fn new(summary: Option<(NonZeroU32, u32)>) -> Self {
+        Self { summary }
+    }
+}
+
+#[bon::bon]
+impl GetMultisigAccountFullResponse {
+    #[builder]
+    pub(crate) fn new(
+        multisig_account: Option<MultisigAccount<WithApprovers, WithPubKeyCommits>>,
+    ) -> Self {
+        Self { multisig_account }
+    }
+}
+
+#[bon::bon]
+impl ArchiveMultisigAccountResponse {
+    #[builder]
+    pub(crate) fn new(multisig_account: Option<MultisigAccount>) -> Self {
+        Self { multisig_account }
+    }
+}
+
+#[bon::bon]
+impl RemoveMultisigApproverResponse {
+    #[builder]
+    pub(crate) fn new(
+        multisig_account: Option<MultisigAccount<WithApprovers, WithPubKeyCommits>>,
+    ) -> Self {
+        Self { multisig_account }
+    }
+}
+
+#[bon::bon]
+impl ListMultisigAccountsResponse {
+    #[builder]
+    pub(crate) fn new(
+        accounts: Vec<MultisigAccountOverview>,
+        next_cursor: Option<Cursor<String>>,
+    ) -> Self {
+        Self { accounts, next_cursor }
+    }
+}
+
+#[bon::bon]
+impl MultisigAccountOverview {
+    #[builder]
+    pub(crate) fn new(
+        multisig_account: MultisigAccount,
+        approver_count: u32,
+        pending_tx_count: u64,
+    ) -> Self {
+        Self {
+            multisig_account,
+            approver_count,
+            pending_tx_count,
+        }
+    }
+}
+
+#[bon::bon]
+impl GetMultisigApproverOrderResponse {
+    #[builder]
+    pub(crate) fn new(approvers: Option<Vec<(u32, MultisigApprover)>>) -> Self {
         Self { approvers }
     }
 }
 
+#[bon::bon]
+impl ListMultisigApproverResponse {
+    #[builder]
+    pub(crate) fn new(
+        approvers: Vec<MultisigApprover>,
+        next_cursor: Option<Cursor<String>>,
+    ) -> Self {
+        Self { approvers, next_cursor }
+    }
+}
+
 #[bon::bon]
 impl GetMultisigTxStatsResponse {
     #[builder]
@@ -98,10 +588,124 @@ impl GetMultisigTxStatsResponse {
     }
 }
 
+#[bon::bon]
+impl GetMultisigTxTrendResponse {
+    #[builder]
+    pub(crate) fn new(buckets: Vec<TxTrendBucket>) -> Self {
+        Self { buckets }
+    }
+}
+
+#[bon::bon]
+impl GetMultisigTxResponse {
+    #[builder]
+    pub(crate) fn new(multisig_tx: Option<MultisigTx>) -> Self {
+        Self { multisig_tx }
+    }
+}
+
+#[bon::bon]
+impl GetMultisigTxAccountResponse {
+    #[builder]
+    pub(crate) fn new(address: Option<AccountIdAddress>) -> Self {
+        Self { address }
+    }
+}
+
+#[bon::bon]
+impl GetMultisigTxBySummaryCommitResponse {
+    #[builder]
+    pub(crate) fn new(multisig_tx: Option<MultisigTx>) -> Self {
+        Self { multisig_tx }
+    }
+}
+
 #[bon::bon]
 impl ListMultisigTxResponse {
     #[builder]
-    pub(crate) fn new(txs: Vec<MultisigTx>) -> Self {
-        Self { txs }
+    pub(crate) fn new(
+        txs: Vec<MultisigTx>,
+        corrupt_tx_ids: Vec<MultisigTxId>,
+        next_cursor: Option<Cursor>,
+    ) -> Self {
+        Self { txs, corrupt_tx_ids, next_cursor }
+    }
+}
+
+#[bon::bon]
+impl ListApproverPendingTxsResponse {
+    #[builder]
+    pub(crate) fn new(
+        txs: Vec<(MultisigTx, bool)>,
+        corrupt_tx_ids: Vec<MultisigTxId>,
+        next_cursor: Option<Cursor>,
+    ) -> Self {
+        Self { txs, corrupt_tx_ids, next_cursor }
+    }
+}
+
+#[bon::bon]
+impl ListAuditEventsResponse {
+    #[builder]
+    pub(crate) fn new(audit_events: Vec<AuditEvent>, next_cursor: Option<Cursor>) -> Self {
+        Self { audit_events, next_cursor }
+    }
+}
+
+#[bon::bon]
+impl ReconcileMultisigAccountResponse {
+    #[builder]
+    pub(crate) fn new(
+        on_chain_threshold: u32,
+        on_chain_pub_key_commits: Vec<PublicKey>,
+        mismatches: Vec<MultisigAccountMismatch>,
+    ) -> Self {
+        Self {
+            on_chain_threshold,
+            on_chain_pub_key_commits,
+            mismatches,
+        }
+    }
+}
+
+#[bon::bon]
+impl VerifyAccountConsistencyResponse {
+    #[builder]
+    pub(crate) fn new(drifts: Vec<Drift>) -> Self {
+        Self { drifts }
+    }
+}
+
+#[bon::bon]
+impl Drift {
+    #[builder]
+    pub(crate) fn new(
+        field: DriftField,
+        db_value: Option<String>,
+        chain_value: Option<String>,
+    ) -> Self {
+        Self { field, db_value, chain_value }
+    }
+}
+
+#[bon::bon]
+impl ReconcileMultisigTxsResponse {
+    #[builder]
+    pub(crate) fn new(
+        reconciled_tx_ids: Vec<MultisigTxId>,
+        corrupt_tx_ids: Vec<MultisigTxId>,
+    ) -> Self {
+        Self { reconciled_tx_ids, corrupt_tx_ids }
+    }
+}
+
+#[bon::bon]
+impl StopMultisigClientRuntimeResponse {
+    #[builder]
+    pub(crate) fn new(
+        engine: MultisigEngine<Stopped>,
+        drain_summary: MultisigClientRuntimeDrainSummary,
+    ) -> Self {
+        Self { engine, drain_summary }
     }
 }
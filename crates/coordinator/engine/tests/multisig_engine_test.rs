@@ -7,13 +7,13 @@ use core::{
 
 use std::{
     path::Path,
-    sync::{Arc, LazyLock, Mutex},
+    sync::{Arc, Mutex},
+    time::Instant,
 };
 
 use diesel::{Connection, PgConnection, RunQueryDsl};
-use diesel_migrations::{EmbeddedMigrations, MigrationHarness};
 use miden_client::{
-    Client, DebugMode, Felt,
+    Client, DebugMode, Felt, ONE, Word,
     account::{
         Account, AccountBuilder, AccountIdAddress, AccountStorageMode, AccountType,
         AddressInterface, NetworkId,
@@ -24,41 +24,83 @@ use miden_client::{
     builder::ClientBuilder,
     crypto::SecretKey,
     keystore::FilesystemKeyStore,
-    note::NoteType,
-    rpc::{Endpoint, TonicRpcClient},
-    transaction::TransactionRequestBuilder,
+    note::{NoteId, NoteType},
+    rpc::{Endpoint, NodeRpcClient, TonicRpcClient, domain::account::FetchedAccount},
+    transaction::{PaymentNoteDescription, TransactionRequestBuilder, TransactionResult},
+};
+use miden_multisig_client::MAX_APPROVERS;
+use miden_multisig_coordinator_domain::{
+    account::{MultisigAccount, MultisigApproverDissolved},
+    audit::{AuditAction, AuditEvent, AuditOutcome},
+    key::{ApproverKey, ApproverSignature, EcdsaPubKey},
+    tx::{
+        MultisigTxDissolved, MultisigTxId, MultisigTxStatsDissolved, MultisigTxStatus, TxEffect,
+        TxSort,
+    },
 };
 use miden_multisig_coordinator_engine::{
-    MultisigClientRuntimeConfig, MultisigEngine, Started,
+    CoordinatorEvent, EventSink, MultisigClientRuntimeConfig, MultisigEngine, SharedNodeRpcClient,
+    Started, Stopped,
     request::{
-        AddSignatureRequest, CreateMultisigAccountRequest, GetConsumableNotesRequest,
-        ProposeMultisigTxRequest,
+        AddSignatureRequest, AddSignaturesRequest, CreateMultisigAccountRequest,
+        CreateMultisigAccountRequestDissolved, CreateMultisigAccountRequestError,
+        DryRunMultisigTxRequest, ExecuteReadyTxRequest, GetConsumableNotesRequest,
+        GetMultisigAccountRequest, GetMultisigTxBySummaryCommitRequest, GetMultisigTxStatsRequest,
+        HasApproverSignedRequest, ImportMultisigAccountRequest, ListAuditEventsRequest,
+        ListMultisigTxRequest, NoteSelector, ProposeConsumeNotesRequest, ProposeMultisigTxRequest,
+        ProposePaymentRequest, ProposePaymentRequestError, ReconcileMultisigAccountRequest,
+        ReconcileMultisigTxsRequest, ReproposeMultisigTxRequest, ValidateMultisigTxRequest,
+        VerifyAccountConsistencyRequest,
+    },
+    response::{
+        AddSignaturesResponseDissolved, CreateMultisigAccountResponseDissolved, DriftDissolved,
+        DriftField, DryRunMultisigTxResponseDissolved, GetConsumableNotesResponseDissolved,
+        GetMultisigAccountFullResponseDissolved, GetMultisigApproverOrderResponseDissolved,
+        GetMultisigTxBySummaryCommitResponseDissolved, GetMultisigTxStatsResponseDissolved,
+        ImportMultisigAccountResponseDissolved, ListAuditEventsResponseDissolved,
+        ListMultisigTxResponseDissolved, MultisigAccountMismatch,
+        ProposeConsumeNotesResponseDissolved, ProposeMultisigTxResponseDissolved,
+        ProposePaymentResponseDissolved, ReconcileMultisigAccountResponseDissolved,
+        ReconcileMultisigTxsResponseDissolved, ReproposeMultisigTxResponseDissolved,
+        ValidateMultisigTxResponseDissolved,
+    },
+};
+use miden_multisig_coordinator_store::{ConnConfig, MultisigStoreBackend};
+use miden_multisig_coordinator_utils::to_bech32;
+use miden_multisig_test_utils::{
+    InMemoryMultisigStore, account_id_address, create_mock_rpc_api, create_test_multisig_client,
+    empty_tx_summary, pay_to_id_tx_request, setup_multisig_store, setup_test_db,
+    setup_test_multisig_account, tx_summary_of,
+};
+use miden_objects::{
+    account::{AccountDelta, AccountId, AccountIdVersion, AccountStorageDelta, AccountVaultDelta},
+    testing::account_id::{
+        ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET, ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE, ACCOUNT_ID_SENDER,
     },
-    response::{CreateMultisigAccountResponseDissolved, ProposeMultisigTxResponseDissolved},
 };
-use miden_multisig_coordinator_store::MultisigStore;
 use rand::{RngCore, rngs::StdRng};
 use tempfile::TempDir;
-use testcontainers::{ContainerAsync, ImageExt, runners::AsyncRunner};
-use testcontainers_modules::postgres::Postgres;
-use tokio::{runtime::Runtime, sync::OnceCell};
 
-const MIGRATIONS: EmbeddedMigrations = diesel_migrations::embed_migrations!("../store/migrations");
+/// Makes `count` distinct approvers, along with a distinct Falcon public key for each.
+fn make_approvers(count: usize) -> (Vec<AccountIdAddress>, Vec<ApproverKey>) {
+    (0..count)
+        .map(|i| {
+            let mut bytes = [0; 15];
+            bytes[0] = u8::try_from(i).expect("approver count must fit in a byte");
 
-static POSTGRES_CONTAINER: OnceCell<ContainerAsync<Postgres>> = OnceCell::const_new();
+            let account_id = AccountId::dummy(
+                bytes,
+                AccountIdVersion::Version0,
+                AccountType::RegularAccountImmutableCode,
+                AccountStorageMode::Public,
+            );
 
-static DB_COUNTER: LazyLock<Mutex<u32>> = LazyLock::new(|| Mutex::new(0));
+            let approver = AccountIdAddress::new(account_id, AddressInterface::BasicWallet);
 
-async fn pg_container() -> &'static ContainerAsync<Postgres> {
-    POSTGRES_CONTAINER
-        .get_or_init(|| async {
-            Postgres::default()
-                .with_tag("18-alpine")
-                .start()
-                .await
-                .expect("failed to start postgres container")
+            (approver, ApproverKey::Falcon(SecretKey::new().public_key()))
         })
-        .await
+        .unzip()
 }
 
 #[tokio::test]
@@ -120,6 +162,8 @@ async fn single_note_consumption_works_using_multisig_engine_to_get_consumable_n
             .get_consumable_notes(GetConsumableNotesRequest::builder().build())
             .await
             .unwrap()
+            .dissolve()
+            .notes
             .into_iter()
             .map(|(nr, _)| nr.id())
             .collect();
@@ -130,7 +174,8 @@ async fn single_note_consumption_works_using_multisig_engine_to_get_consumable_n
     let propose_request = ProposeMultisigTxRequest::builder()
         .address(AccountIdAddress::new(multisig_account.id(), AddressInterface::BasicWallet))
         .tx_request(consume_notes_tx_request)
-        .build();
+        .build()
+        .unwrap();
 
     let ProposeMultisigTxResponseDissolved { tx_id, tx_summary } =
         engine.propose_multisig_tx(propose_request).await.unwrap().dissolve();
@@ -177,133 +222,3501 @@ async fn single_note_consumption_works_using_multisig_engine_to_get_consumable_n
     assert_eq!(asset_balance, asset.amount());
 }
 
-async fn setup_fungible_faucet_client(
-    temp_dir: &Path,
-    symbol: &str,
-    decimals: u8,
-    max_supply: u64,
-) -> (Client<FilesystemKeyStore<StdRng>>, Account) {
-    let (mut client, keystore) = setup_testnet_client(temp_dir).await;
+#[tokio::test]
+async fn consumable_notes_are_listed_as_of_the_synced_block() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
 
-    let mut init_seed = [0u8; 32];
-    client.rng().fill_bytes(&mut init_seed);
+    let (mut observer_client, _) = setup_testnet_client(&temp_dir.join("observer")).await;
 
-    let symbol = TokenSymbol::new(symbol).unwrap();
-    let max_supply = Felt::new(max_supply);
+    let engine = start_testnet_multisig_engine(&temp_dir.join("multisig")).await;
 
-    let sk = SecretKey::with_rng(client.rng());
+    // the engine syncs before listing, so it can't be synced to an older block than observed here
+    let observed_block_num = observer_client.sync_state().await.unwrap().block_num;
 
-    let (account, seed) = AccountBuilder::new(init_seed)
-        .account_type(AccountType::FungibleFaucet)
-        .storage_mode(miden_client::account::AccountStorageMode::Public)
-        .with_auth_component(AuthRpoFalcon512::new(sk.public_key()))
-        .with_component(BasicFungibleFaucet::new(symbol, decimals, max_supply).unwrap())
+    // Act
+    let GetConsumableNotesResponseDissolved { block_num, stale, .. } = engine
+        .get_consumable_notes(GetConsumableNotesRequest::builder().build())
+        .await
+        .unwrap()
+        .dissolve();
+
+    // Assert
+    assert!(block_num >= observed_block_num);
+    assert!(!stale);
+}
+
+#[tokio::test]
+async fn batch_signatures_meeting_threshold_process_multisig_tx_in_single_call() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let (mut ff_client, ff_account) =
+        setup_fungible_faucet_client(&temp_dir.join("ff"), "BAT", 8, 5_000_000).await;
+
+    let (_, alice_account, alice_sk) = setup_regular_account_client(&temp_dir.join("alice")).await;
+
+    let (_, bob_account, bob_sk) = setup_regular_account_client(&temp_dir.join("bob")).await;
+
+    let (_, charlie_account, charlie_sk) =
+        setup_regular_account_client(&temp_dir.join("charlie")).await;
+
+    let engine = start_testnet_multisig_engine(&temp_dir.join("multisig")).await;
+
+    let alice_addr = AccountIdAddress::new(alice_account.id(), AddressInterface::BasicWallet);
+    let bob_addr = AccountIdAddress::new(bob_account.id(), AddressInterface::BasicWallet);
+    let charlie_addr = AccountIdAddress::new(charlie_account.id(), AddressInterface::BasicWallet);
+
+    let create_account_request = CreateMultisigAccountRequest::builder()
+        .threshold(NonZeroU32::new(2).unwrap())
+        .approvers(vec![alice_addr, bob_addr, charlie_addr])
+        .pub_key_commits(vec![alice_sk.public_key(), bob_sk.public_key(), charlie_sk.public_key()])
         .build()
         .unwrap();
 
-    client.add_account(&account, Some(seed), false).await.unwrap();
-    keystore.add_key(&AuthSecretKey::RpoFalcon512(sk)).unwrap();
+    let CreateMultisigAccountResponseDissolved { miden_account: multisig_account, .. } =
+        engine.create_multisig_account(create_account_request).await.unwrap().dissolve();
 
-    (client, account)
-}
+    let asset = FungibleAsset::new(ff_account.id(), 420_000).unwrap();
 
-async fn setup_regular_account_client(
-    temp_dir: &Path,
-) -> (Client<FilesystemKeyStore<StdRng>>, Account, SecretKey) {
-    let (mut client, keystore) = setup_testnet_client(temp_dir).await;
+    let mint_request = TransactionRequestBuilder::new()
+        .build_mint_fungible_asset(asset, multisig_account.id(), NoteType::Public, ff_client.rng())
+        .unwrap();
 
-    let mut init_seed = [0u8; 32];
-    client.rng().fill_bytes(&mut init_seed);
+    ff_client.sync_state().await.unwrap();
+    let tx_result = ff_client.new_transaction(ff_account.id(), mint_request).await.unwrap();
 
-    let sk = SecretKey::with_rng(client.rng());
+    ff_client.submit_transaction(tx_result).await.unwrap();
 
-    let (account, seed) = AccountBuilder::new(init_seed)
-        .account_type(AccountType::RegularAccountUpdatableCode)
-        .storage_mode(AccountStorageMode::Public)
-        .with_auth_component(AuthRpoFalcon512::new(sk.public_key()))
-        .with_component(BasicWallet)
+    let note_ids = wait_for_consumable_note_ids(&engine, None).await;
+
+    let propose_request = ProposeMultisigTxRequest::builder()
+        .address(AccountIdAddress::new(multisig_account.id(), AddressInterface::BasicWallet))
+        .tx_request(TransactionRequestBuilder::new().build_consume_notes(note_ids).unwrap())
         .build()
         .unwrap();
 
-    client.add_account(&account, Some(seed), false).await.unwrap();
-    keystore.add_key(&AuthSecretKey::RpoFalcon512(sk.clone())).unwrap();
+    let ProposeMultisigTxResponseDissolved { tx_id, tx_summary } =
+        engine.propose_multisig_tx(propose_request).await.unwrap().dissolve();
 
-    (client, account, sk)
+    // Act
+    let tx_summary_commitment = tx_summary.to_commitment();
+
+    let add_sigs_request = AddSignaturesRequest::builder()
+        .tx_id(tx_id)
+        .signatures(vec![
+            (alice_addr, alice_sk.sign(tx_summary_commitment).into()),
+            (bob_addr, bob_sk.sign(tx_summary_commitment).into()),
+        ])
+        .build()
+        .unwrap();
+
+    let AddSignaturesResponseDissolved { accepted, threshold_met, tx_result } =
+        engine.add_signatures(add_sigs_request).await.unwrap().dissolve();
+
+    // Assert
+    assert_eq!(accepted, 2);
+    assert!(threshold_met);
+
+    let tx_result = tx_result.expect("tx must have been executed");
+
+    wait_for_tx_commit(&tx_result).await;
+
+    let list_tx_request = ListMultisigTxRequest::builder()
+        .multisig_account_id_address(AccountIdAddress::new(
+            multisig_account.id(),
+            AddressInterface::BasicWallet,
+        ))
+        .build();
+
+    let ListMultisigTxResponseDissolved { txs, .. } =
+        engine.list_multisig_tx(list_tx_request).await.unwrap().dissolve();
+
+    let [tx] = txs.try_into().expect("exactly one tx must have been proposed");
+    let MultisigTxDissolved {
+        status,
+        chain_tx_id,
+        submission_block_num,
+        ..
+    } = tx.dissolve();
+
+    assert!(matches!(status, MultisigTxStatus::Success));
+    assert_eq!(chain_tx_id, Some(tx_result.executed_transaction().id()));
+    assert!(submission_block_num.is_some());
+
+    let asset_balance = {
+        let (mut client, _) = setup_testnet_client(&temp_dir.join("external")).await;
+
+        client.import_account_by_id(multisig_account.id()).await.unwrap();
+        client.sync_state().await.unwrap();
+
+        let imported_multisig_account_record =
+            client.get_account(multisig_account.id()).await.unwrap().unwrap();
+
+        imported_multisig_account_record
+            .account()
+            .vault()
+            .get_balance(ff_account.id())
+            .unwrap()
+    };
+
+    assert_eq!(asset_balance, asset.amount());
 }
 
-async fn setup_testnet_client(
-    temp_dir: &Path,
-) -> (Client<FilesystemKeyStore<StdRng>>, FilesystemKeyStore<StdRng>) {
-    let keystore =
-        FilesystemKeyStore::new(temp_dir.join("keystore")).expect("failed to initialize keystore");
+#[tokio::test]
+async fn event_sink_collects_events_across_propose_sign_execute_flow() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
 
-    let client = ClientBuilder::new()
-        .rpc(Arc::new(TonicRpcClient::new(&Endpoint::testnet(), 10_000)))
-        .sqlite_store(temp_dir.join("store").as_os_str().to_str().unwrap())
-        .authenticator(keystore.clone().into())
-        .in_debug_mode(DebugMode::Enabled)
+    let (mut ff_client, ff_account) =
+        setup_fungible_faucet_client(&temp_dir.join("ff"), "EVT", 8, 5_000_000).await;
+
+    let (_, alice_account, alice_sk) = setup_regular_account_client(&temp_dir.join("alice")).await;
+
+    let (_, bob_account, bob_sk) = setup_regular_account_client(&temp_dir.join("bob")).await;
+
+    let event_sink = Arc::new(InMemoryEventSink::default());
+
+    let engine =
+        MultisigEngine::new(NetworkId::Testnet, setup_multisig_store(setup_test_db().await).await)
+            .with_event_sink(event_sink.clone());
+
+    let engine = start_multisig_engine(
+        &temp_dir.join("multisig"),
+        engine,
+        "https://rpc.testnet.miden.io:443",
+    )
+    .await;
+
+    let alice_addr = AccountIdAddress::new(alice_account.id(), AddressInterface::BasicWallet);
+    let bob_addr = AccountIdAddress::new(bob_account.id(), AddressInterface::BasicWallet);
+
+    let create_account_request = CreateMultisigAccountRequest::builder()
+        .threshold(NonZeroU32::new(2).unwrap())
+        .approvers(vec![alice_addr, bob_addr])
+        .pub_key_commits(vec![alice_sk.public_key(), bob_sk.public_key()])
         .build()
-        .await
-        .expect("failed to build miden client");
+        .unwrap();
 
-    (client, keystore)
-}
+    let CreateMultisigAccountResponseDissolved { miden_account: multisig_account, .. } =
+        engine.create_multisig_account(create_account_request).await.unwrap().dissolve();
 
-async fn start_testnet_multisig_engine(temp_dir: &Path) -> MultisigEngine<Started> {
-    let db_url = setup_test_db().await;
+    let multisig_addr = AccountIdAddress::new(multisig_account.id(), AddressInterface::BasicWallet);
 
-    let multisig_store =
-        miden_multisig_coordinator_store::establish_pool(db_url, NonZeroUsize::MIN)
-            .await
-            .map(MultisigStore::new)
-            .expect("failed to initialize multisig store");
+    let asset = FungibleAsset::new(ff_account.id(), 1_000).unwrap();
 
-    let engine = MultisigEngine::new(NetworkId::Testnet, multisig_store);
+    let mint_request = TransactionRequestBuilder::new()
+        .build_mint_fungible_asset(asset, multisig_account.id(), NoteType::Public, ff_client.rng())
+        .unwrap();
 
-    let config = MultisigClientRuntimeConfig::builder()
-        .node_url("https://rpc.testnet.miden.io:443".parse().unwrap())
-        .store_path(temp_dir.join("store"))
-        .keystore_path(temp_dir.join("keystore"))
-        .timeout(Duration::from_secs(10))
-        .build();
+    ff_client.sync_state().await.unwrap();
+    let tx_result = ff_client.new_transaction(ff_account.id(), mint_request).await.unwrap();
 
-    engine
-        .start_multisig_client_runtime(
-            Runtime::new().expect("failed to create tokio runtime"),
-            config,
+    ff_client.submit_transaction(tx_result).await.unwrap();
+
+    let note_ids = wait_for_consumable_note_ids(&engine, None).await;
+
+    // Act
+    let propose_request = ProposeMultisigTxRequest::builder()
+        .address(multisig_addr)
+        .tx_request(TransactionRequestBuilder::new().build_consume_notes(note_ids).unwrap())
+        .build()
+        .unwrap();
+
+    let ProposeMultisigTxResponseDissolved { tx_id, tx_summary } =
+        engine.propose_multisig_tx(propose_request).await.unwrap().dissolve();
+
+    for (approver, sk) in [(alice_addr, &alice_sk), (bob_addr, &bob_sk)] {
+        let add_sig_request = AddSignatureRequest::builder()
+            .tx_id(tx_id.clone())
+            .approver(approver)
+            .signature(sk.sign(tx_summary.to_commitment()))
+            .build();
+
+        engine.add_signature(add_sig_request).await.unwrap();
+    }
+
+    // Assert
+    let ListMultisigTxResponseDissolved { txs, .. } = engine
+        .list_multisig_tx(
+            ListMultisigTxRequest::builder()
+                .multisig_account_id_address(multisig_addr)
+                .build(),
         )
         .await
         .unwrap()
+        .dissolve();
+
+    let [tx] = txs.try_into().expect("exactly one tx must have been proposed");
+    let MultisigTxDissolved { chain_tx_id, .. } = tx.dissolve();
+    let chain_tx_id = chain_tx_id.expect("tx must have been executed");
+
+    assert_eq!(
+        *event_sink.0.lock().unwrap(),
+        [
+            CoordinatorEvent::AccountCreated { address: multisig_addr },
+            CoordinatorEvent::TxProposed {
+                tx_id: tx_id.clone(),
+                address: multisig_addr
+            },
+            CoordinatorEvent::SignatureAdded {
+                tx_id: tx_id.clone(),
+                approver: alice_addr
+            },
+            CoordinatorEvent::SignatureAdded { tx_id: tx_id.clone(), approver: bob_addr },
+            CoordinatorEvent::TxExecuted { tx_id, chain_tx_id },
+        ],
+    );
 }
 
-async fn setup_test_db() -> String {
-    let container = pg_container().await;
+#[tokio::test]
+async fn reconciling_recovers_executed_multisig_tx_whose_status_update_was_lost() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let (mut ff_client, ff_account) =
+        setup_fungible_faucet_client(&temp_dir.join("ff"), "REC", 8, 5_000_000).await;
+
+    let (_, alice_account, alice_sk) = setup_regular_account_client(&temp_dir.join("alice")).await;
 
-    let db_name = {
-        let mut counter = DB_COUNTER.lock().unwrap();
-        *counter += 1;
-        format!("test_db_{}", *counter)
-    };
+    let (_, bob_account, bob_sk) = setup_regular_account_client(&temp_dir.join("bob")).await;
 
-    let host = container.get_host().await.expect("failed to get host");
+    let db_url = setup_test_db().await;
+    let engine =
+        start_testnet_multisig_engine_with_db_url(&temp_dir.join("multisig"), db_url.clone()).await;
+
+    let alice_addr = AccountIdAddress::new(alice_account.id(), AddressInterface::BasicWallet);
+    let bob_addr = AccountIdAddress::new(bob_account.id(), AddressInterface::BasicWallet);
+
+    let create_account_request = CreateMultisigAccountRequest::builder()
+        .threshold(NonZeroU32::new(2).unwrap())
+        .approvers(vec![alice_addr, bob_addr])
+        .pub_key_commits(vec![alice_sk.public_key(), bob_sk.public_key()])
+        .build()
+        .unwrap();
+
+    let CreateMultisigAccountResponseDissolved { miden_account: multisig_account, .. } =
+        engine.create_multisig_account(create_account_request).await.unwrap().dissolve();
+
+    let multisig_addr = AccountIdAddress::new(multisig_account.id(), AddressInterface::BasicWallet);
+
+    let asset = FungibleAsset::new(ff_account.id(), 210_000).unwrap();
+
+    let mint_request = TransactionRequestBuilder::new()
+        .build_mint_fungible_asset(asset, multisig_account.id(), NoteType::Public, ff_client.rng())
+        .unwrap();
+
+    ff_client.sync_state().await.unwrap();
+    let tx_result = ff_client.new_transaction(ff_account.id(), mint_request).await.unwrap();
+
+    ff_client.submit_transaction(tx_result).await.unwrap();
+
+    let note_ids = wait_for_consumable_note_ids(&engine, None).await;
+
+    let propose_request = ProposeMultisigTxRequest::builder()
+        .address(multisig_addr)
+        .tx_request(TransactionRequestBuilder::new().build_consume_notes(note_ids).unwrap())
+        .build()
+        .unwrap();
+
+    let ProposeMultisigTxResponseDissolved { tx_id, tx_summary } =
+        engine.propose_multisig_tx(propose_request).await.unwrap().dissolve();
+
+    let tx_summary_commitment = tx_summary.to_commitment();
+
+    let add_sigs_request = AddSignaturesRequest::builder()
+        .tx_id(tx_id.clone())
+        .signatures(vec![
+            (alice_addr, alice_sk.sign(tx_summary_commitment).into()),
+            (bob_addr, bob_sk.sign(tx_summary_commitment).into()),
+        ])
+        .build()
+        .unwrap();
+
+    let AddSignaturesResponseDissolved { tx_result, .. } =
+        engine.add_signatures(add_sigs_request).await.unwrap().dissolve();
+
+    let tx_result = tx_result.expect("tx must have been executed");
+    let executed_chain_tx_id = tx_result.executed_transaction().id();
+
+    wait_for_tx_commit(&tx_result).await;
+
+    // the coordinator stopping right after submitting the tx leaves it pending in the store
+    diesel::sql_query(format!(
+        "UPDATE tx SET status = 'pending', chain_tx_id = NULL, submission_block_num = NULL \
+         WHERE id = '{tx_id}'"
+    ))
+    .execute(&mut PgConnection::establish(&db_url).expect("failed to connect to test database"))
+    .expect("failed to revert tx status");
+
+    diesel::sql_query(format!(
+        "UPDATE multisig_account SET nonce = NULL WHERE address = '{}'",
+        to_bech32(NetworkId::Testnet, multisig_addr),
+    ))
+    .execute(&mut PgConnection::establish(&db_url).expect("failed to connect to test database"))
+    .expect("failed to revert multisig account nonce");
+
+    let reconcile_request = ReconcileMultisigTxsRequest::builder()
+        .multisig_account_id_address(multisig_addr)
+        .build();
+
+    // Act
+    let ReconcileMultisigTxsResponseDissolved { reconciled_tx_ids, .. } =
+        engine.reconcile_multisig_txs(reconcile_request).await.unwrap().dissolve();
+
+    // Assert
+    assert_eq!(reconciled_tx_ids, [tx_id]);
+
+    let list_tx_request = ListMultisigTxRequest::builder()
+        .multisig_account_id_address(multisig_addr)
+        .build();
 
-    let port = container.get_host_port_ipv4(5432).await.expect("failed to get port");
+    let ListMultisigTxResponseDissolved { txs, .. } =
+        engine.list_multisig_tx(list_tx_request).await.unwrap().dissolve();
 
-    let admin_url = format!("postgres://postgres:postgres@{}:{}/postgres", host, port);
+    let [tx] = txs.try_into().expect("exactly one tx must have been proposed");
+    let MultisigTxDissolved {
+        status,
+        chain_tx_id,
+        submission_block_num,
+        ..
+    } = tx.dissolve();
 
-    let mut admin_conn =
-        PgConnection::establish(&admin_url).expect("failed to connect to postgres");
+    assert!(matches!(status, MultisigTxStatus::Success));
+    assert_eq!(chain_tx_id, Some(executed_chain_tx_id));
+    assert!(submission_block_num.is_some());
 
-    diesel::sql_query(format!("CREATE DATABASE {db_name}"))
-        .execute(&mut admin_conn)
-        .expect("failed to create test database");
+    // the on-chain nonce of the account is recorded along the reconciled tx
+    let verify_request = VerifyAccountConsistencyRequest::builder()
+        .multisig_account_id_address(multisig_addr)
+        .build();
+
+    let drifts = engine.verify_account_consistency(verify_request).await.unwrap().dissolve();
+
+    assert!(drifts.is_empty(), "reconciled tx must keep the account consistent: {drifts:?}");
+
+    // a second pass finds nothing left to reconcile
+    let reconcile_request = ReconcileMultisigTxsRequest::builder()
+        .multisig_account_id_address(multisig_addr)
+        .build();
+
+    let ReconcileMultisigTxsResponseDissolved { reconciled_tx_ids, .. } =
+        engine.reconcile_multisig_txs(reconcile_request).await.unwrap().dissolve();
+
+    assert!(reconciled_tx_ids.is_empty());
+}
 
-    let test_db_url = format!("postgres://postgres:postgres@{}:{}/{}", host, port, db_name);
+#[tokio::test]
+async fn verifying_account_consistency_detects_on_chain_nonce_drift() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let (mut ff_client, ff_account) =
+        setup_fungible_faucet_client(&temp_dir.join("ff"), "DRF", 8, 5_000_000).await;
+
+    let (_, alice_account, alice_sk) = setup_regular_account_client(&temp_dir.join("alice")).await;
 
-    PgConnection::establish(&test_db_url)
-        .expect("failed to connect to test database")
-        .run_pending_migrations(MIGRATIONS)
-        .expect("failed to run migrations");
+    let (_, bob_account, bob_sk) = setup_regular_account_client(&temp_dir.join("bob")).await;
+
+    let db_url = setup_test_db().await;
+    let engine =
+        start_testnet_multisig_engine_with_db_url(&temp_dir.join("multisig"), db_url.clone()).await;
+
+    let alice_addr = AccountIdAddress::new(alice_account.id(), AddressInterface::BasicWallet);
+    let bob_addr = AccountIdAddress::new(bob_account.id(), AddressInterface::BasicWallet);
+
+    let create_account_request = CreateMultisigAccountRequest::builder()
+        .threshold(NonZeroU32::new(2).unwrap())
+        .approvers(vec![alice_addr, bob_addr])
+        .pub_key_commits(vec![alice_sk.public_key(), bob_sk.public_key()])
+        .build()
+        .unwrap();
+
+    let CreateMultisigAccountResponseDissolved { miden_account: multisig_account, .. } =
+        engine.create_multisig_account(create_account_request).await.unwrap().dissolve();
+
+    let multisig_addr = AccountIdAddress::new(multisig_account.id(), AddressInterface::BasicWallet);
+
+    let verify_request = || {
+        VerifyAccountConsistencyRequest::builder()
+            .multisig_account_id_address(multisig_addr)
+            .build()
+    };
+
+    let drifts = engine.verify_account_consistency(verify_request()).await.unwrap().dissolve();
+
+    assert!(drifts.is_empty(), "freshly created account must be consistent: {drifts:?}");
+
+    let asset = FungibleAsset::new(ff_account.id(), 210_000).unwrap();
+
+    let mint_request = TransactionRequestBuilder::new()
+        .build_mint_fungible_asset(asset, multisig_account.id(), NoteType::Public, ff_client.rng())
+        .unwrap();
+
+    ff_client.sync_state().await.unwrap();
+    let tx_result = ff_client.new_transaction(ff_account.id(), mint_request).await.unwrap();
+
+    ff_client.submit_transaction(tx_result).await.unwrap();
+
+    let note_ids = wait_for_consumable_note_ids(&engine, None).await;
+
+    let propose_request = ProposeMultisigTxRequest::builder()
+        .address(multisig_addr)
+        .tx_request(TransactionRequestBuilder::new().build_consume_notes(note_ids).unwrap())
+        .build()
+        .unwrap();
+
+    let ProposeMultisigTxResponseDissolved { tx_id, tx_summary } =
+        engine.propose_multisig_tx(propose_request).await.unwrap().dissolve();
+
+    let tx_summary_commitment = tx_summary.to_commitment();
+
+    let add_sigs_request = AddSignaturesRequest::builder()
+        .tx_id(tx_id)
+        .signatures(vec![
+            (alice_addr, alice_sk.sign(tx_summary_commitment).into()),
+            (bob_addr, bob_sk.sign(tx_summary_commitment).into()),
+        ])
+        .build()
+        .unwrap();
+
+    let AddSignaturesResponseDissolved { tx_result, .. } =
+        engine.add_signatures(add_sigs_request).await.unwrap().dissolve();
+
+    let on_chain_nonce = tx_result
+        .expect("tx must have been executed")
+        .executed_transaction()
+        .final_account()
+        .nonce();
+
+    // the coordinator records the nonce of the account it executed the tx on
+    let drifts = engine.verify_account_consistency(verify_request()).await.unwrap().dissolve();
+
+    assert!(drifts.is_empty(), "executed tx must keep the account consistent: {drifts:?}");
+
+    // the account moving on chain without the coordinator seeing it leaves the stored nonce behind
+    diesel::sql_query(format!(
+        "UPDATE multisig_account SET nonce = 0 WHERE address = '{}'",
+        to_bech32(NetworkId::Testnet, multisig_addr),
+    ))
+    .execute(&mut PgConnection::establish(&db_url).expect("failed to connect to test database"))
+    .expect("failed to revert multisig account nonce");
+
+    // Act
+    let drifts = engine.verify_account_consistency(verify_request()).await.unwrap().dissolve();
+
+    // Assert
+    let [drift] = drifts.try_into().expect("only the nonce must have drifted");
+    let DriftDissolved { field, db_value, chain_value } = drift.dissolve();
+
+    assert_eq!(field, DriftField::Nonce);
+    assert_eq!(db_value.as_deref(), Some("0"));
+    assert_eq!(chain_value, Some(on_chain_nonce.as_int().to_string()));
+}
+
+#[tokio::test]
+async fn reconciling_multisig_account_reports_stored_threshold_differing_from_on_chain_one() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let (_, alice_account, alice_sk) = setup_regular_account_client(&temp_dir.join("alice")).await;
+
+    let (_, bob_account, bob_sk) = setup_regular_account_client(&temp_dir.join("bob")).await;
+
+    let db_url = setup_test_db().await;
+    let engine =
+        start_testnet_multisig_engine_with_db_url(&temp_dir.join("multisig"), db_url.clone()).await;
+
+    let alice_addr = AccountIdAddress::new(alice_account.id(), AddressInterface::BasicWallet);
+    let bob_addr = AccountIdAddress::new(bob_account.id(), AddressInterface::BasicWallet);
+
+    let pub_key_commits = vec![alice_sk.public_key(), bob_sk.public_key()];
+
+    let create_account_request = CreateMultisigAccountRequest::builder()
+        .threshold(NonZeroU32::new(2).unwrap())
+        .approvers(vec![alice_addr, bob_addr])
+        .pub_key_commits(pub_key_commits.clone())
+        .build()
+        .unwrap();
+
+    let CreateMultisigAccountResponseDissolved { miden_account: multisig_account, .. } =
+        engine.create_multisig_account(create_account_request).await.unwrap().dissolve();
+
+    let multisig_addr = AccountIdAddress::new(multisig_account.id(), AddressInterface::BasicWallet);
+
+    let reconcile_request = || {
+        ReconcileMultisigAccountRequest::builder()
+            .multisig_account_id_address(multisig_addr)
+            .build()
+    };
+
+    let ReconcileMultisigAccountResponseDissolved { mismatches, .. } =
+        engine.reconcile_multisig_account(reconcile_request()).await.unwrap().dissolve();
+
+    assert!(mismatches.is_empty(), "freshly created account must be in sync: {mismatches:?}");
+
+    diesel::sql_query(format!(
+        "UPDATE multisig_account SET threshold = 1 WHERE address = '{}'",
+        to_bech32(NetworkId::Testnet, multisig_addr),
+    ))
+    .execute(&mut PgConnection::establish(&db_url).expect("failed to connect to test database"))
+    .expect("failed to alter multisig account threshold");
+
+    // Act
+    let ReconcileMultisigAccountResponseDissolved {
+        on_chain_threshold,
+        on_chain_pub_key_commits,
+        mismatches,
+    } = engine.reconcile_multisig_account(reconcile_request()).await.unwrap().dissolve();
+
+    // Assert
+    assert_eq!(on_chain_threshold, 2);
+    assert_eq!(on_chain_pub_key_commits, pub_key_commits);
+    assert_eq!(mismatches, [MultisigAccountMismatch::Threshold { stored: 1, on_chain: 2 }]);
+}
+
+#[tokio::test]
+async fn importing_multisig_account_created_elsewhere_persists_its_on_chain_config() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let (mut ff_client, ff_account) =
+        setup_fungible_faucet_client(&temp_dir.join("ff"), "IMP", 8, 5_000_000).await;
+
+    let (_, alice_account, alice_sk) = setup_regular_account_client(&temp_dir.join("alice")).await;
+
+    let (_, bob_account, bob_sk) = setup_regular_account_client(&temp_dir.join("bob")).await;
+
+    // the account is created by another coordinator, standing in for e.g. the CLI
+    let creating_engine = start_testnet_multisig_engine(&temp_dir.join("creator")).await;
+
+    let alice_addr = AccountIdAddress::new(alice_account.id(), AddressInterface::BasicWallet);
+    let bob_addr = AccountIdAddress::new(bob_account.id(), AddressInterface::BasicWallet);
+
+    let create_account_request = CreateMultisigAccountRequest::builder()
+        .threshold(NonZeroU32::new(2).unwrap())
+        .approvers(vec![alice_addr, bob_addr])
+        .pub_key_commits(vec![alice_sk.public_key(), bob_sk.public_key()])
+        .build()
+        .unwrap();
+
+    let CreateMultisigAccountResponseDissolved { miden_account: multisig_account, .. } =
+        creating_engine
+            .create_multisig_account(create_account_request)
+            .await
+            .unwrap()
+            .dissolve();
+
+    let multisig_addr = AccountIdAddress::new(multisig_account.id(), AddressInterface::BasicWallet);
+
+    let asset = FungibleAsset::new(ff_account.id(), 210_000).unwrap();
+
+    let mint_request = TransactionRequestBuilder::new()
+        .build_mint_fungible_asset(asset, multisig_account.id(), NoteType::Public, ff_client.rng())
+        .unwrap();
+
+    ff_client.sync_state().await.unwrap();
+    let tx_result = ff_client.new_transaction(ff_account.id(), mint_request).await.unwrap();
+
+    ff_client.submit_transaction(tx_result).await.unwrap();
+
+    let note_ids = wait_for_consumable_note_ids(&creating_engine, None).await;
+
+    let propose_request = ProposeMultisigTxRequest::builder()
+        .address(multisig_addr)
+        .tx_request(TransactionRequestBuilder::new().build_consume_notes(note_ids).unwrap())
+        .build()
+        .unwrap();
+
+    let ProposeMultisigTxResponseDissolved { tx_id, tx_summary } =
+        creating_engine.propose_multisig_tx(propose_request).await.unwrap().dissolve();
+
+    let tx_summary_commitment = tx_summary.to_commitment();
+
+    let add_sigs_request = AddSignaturesRequest::builder()
+        .tx_id(tx_id)
+        .signatures(vec![
+            (alice_addr, alice_sk.sign(tx_summary_commitment).into()),
+            (bob_addr, bob_sk.sign(tx_summary_commitment).into()),
+        ])
+        .build()
+        .unwrap();
+
+    // executing a tx puts the account on chain, from where it can be imported
+    let AddSignaturesResponseDissolved { tx_result, .. } =
+        creating_engine.add_signatures(add_sigs_request).await.unwrap().dissolve();
+
+    let tx_result = tx_result.expect("tx must have been executed");
+    let on_chain_nonce = tx_result.executed_transaction().final_account().nonce();
+
+    wait_for_tx_commit(&tx_result).await;
+
+    let engine = start_testnet_multisig_engine(&temp_dir.join("importer")).await;
+
+    let import_request = |account_id, approvers| {
+        ImportMultisigAccountRequest::builder()
+            .account_id(account_id)
+            .approvers(approvers)
+            .build()
+    };
+
+    // keys swapped with respect to the approver index order
+    let swapped_keys_import_request = ImportMultisigAccountRequest::builder()
+        .account_id(multisig_addr.id())
+        .approvers(vec![alice_addr, bob_addr])
+        .pub_key_commits(vec![
+            ApproverKey::Falcon(bob_sk.public_key()),
+            ApproverKey::Falcon(alice_sk.public_key()),
+        ])
+        .build();
+
+    // Act
+    let swapped_keys_import_err =
+        engine.import_multisig_account(swapped_keys_import_request).await.unwrap_err();
+
+    let ImportMultisigAccountResponseDissolved { miden_account, multisig_account } = engine
+        .import_multisig_account(import_request(multisig_addr.id(), vec![alice_addr, bob_addr]))
+        .await
+        .unwrap()
+        .dissolve();
+
+    let reimport_err = engine
+        .import_multisig_account(import_request(multisig_addr.id(), vec![alice_addr, bob_addr]))
+        .await
+        .unwrap_err();
+
+    let faucet_import_err = engine
+        .import_multisig_account(import_request(ff_account.id(), vec![alice_addr]))
+        .await
+        .unwrap_err();
+
+    // Assert
+    assert_eq!(miden_account.id(), multisig_addr.id());
+    assert_eq!(multisig_account.address(), multisig_addr);
+    assert_eq!(multisig_account.threshold().get(), 2);
+    assert_eq!(multisig_account.nonce(), Some(on_chain_nonce.as_int()));
+    assert_eq!(multisig_account.approvers(), [alice_addr, bob_addr]);
+    assert_eq!(
+        multisig_account.pub_key_commits(),
+        [
+            ApproverKey::Falcon(alice_sk.public_key()),
+            ApproverKey::Falcon(bob_sk.public_key())
+        ],
+    );
+
+    let get_request = GetMultisigAccountRequest::builder()
+        .multisig_account_id_address(multisig_addr)
+        .build();
+
+    let GetMultisigAccountFullResponseDissolved {
+        multisig_account: stored_multisig_account,
+    } = engine.get_multisig_account_full(get_request).await.unwrap().dissolve();
+
+    let stored_multisig_account = stored_multisig_account.expect("account must be persisted");
+
+    assert_eq!(stored_multisig_account.approvers(), [alice_addr, bob_addr]);
+
+    assert_eq!(swapped_keys_import_err.approver_key_mismatch(), Some(0));
+    assert_eq!(reimport_err.multisig_account_already_exists(), Some(multisig_addr.id()));
+    assert_eq!(faucet_import_err.not_multisig_account(), Some(ff_account.id()));
+}
+
+#[tokio::test]
+async fn multisig_tx_meeting_threshold_waits_for_execution_when_auto_process_is_disabled() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let (mut ff_client, ff_account) =
+        setup_fungible_faucet_client(&temp_dir.join("ff"), "RTE", 8, 5_000_000).await;
+
+    let (_, alice_account, alice_sk) = setup_regular_account_client(&temp_dir.join("alice")).await;
+
+    let (_, bob_account, bob_sk) = setup_regular_account_client(&temp_dir.join("bob")).await;
+
+    let db_url = setup_test_db().await;
+
+    let engine = start_testnet_multisig_engine_with_auto_process(
+        &temp_dir.join("multisig"),
+        db_url.clone(),
+        false,
+    )
+    .await;
+
+    let store = setup_multisig_store(db_url).await;
+
+    let alice_addr = AccountIdAddress::new(alice_account.id(), AddressInterface::BasicWallet);
+    let bob_addr = AccountIdAddress::new(bob_account.id(), AddressInterface::BasicWallet);
+
+    let create_account_request = CreateMultisigAccountRequest::builder()
+        .threshold(NonZeroU32::new(2).unwrap())
+        .approvers(vec![alice_addr, bob_addr])
+        .pub_key_commits(vec![alice_sk.public_key(), bob_sk.public_key()])
+        .build()
+        .unwrap();
+
+    let CreateMultisigAccountResponseDissolved { miden_account: multisig_account, .. } =
+        engine.create_multisig_account(create_account_request).await.unwrap().dissolve();
+
+    let asset = FungibleAsset::new(ff_account.id(), 100_000).unwrap();
+
+    let mint_request = TransactionRequestBuilder::new()
+        .build_mint_fungible_asset(asset, multisig_account.id(), NoteType::Public, ff_client.rng())
+        .unwrap();
+
+    ff_client.sync_state().await.unwrap();
+    let tx_result = ff_client.new_transaction(ff_account.id(), mint_request).await.unwrap();
+
+    ff_client.submit_transaction(tx_result).await.unwrap();
+
+    let note_ids = wait_for_consumable_note_ids(&engine, None).await;
+
+    let propose_request = ProposeMultisigTxRequest::builder()
+        .address(AccountIdAddress::new(multisig_account.id(), AddressInterface::BasicWallet))
+        .tx_request(TransactionRequestBuilder::new().build_consume_notes(note_ids).unwrap())
+        .build()
+        .unwrap();
+
+    let ProposeMultisigTxResponseDissolved { tx_id, tx_summary } =
+        engine.propose_multisig_tx(propose_request).await.unwrap().dissolve();
+
+    let tx_summary_commitment = tx_summary.to_commitment();
+
+    let add_sigs_request = AddSignaturesRequest::builder()
+        .tx_id(tx_id.clone())
+        .signatures(vec![
+            (alice_addr, alice_sk.sign(tx_summary_commitment).into()),
+            (bob_addr, bob_sk.sign(tx_summary_commitment).into()),
+        ])
+        .build()
+        .unwrap();
+
+    // Act
+    let AddSignaturesResponseDissolved { threshold_met, tx_result, .. } =
+        engine.add_signatures(add_sigs_request).await.unwrap().dissolve();
+
+    let MultisigTxDissolved { status: status_before_execution, .. } =
+        store.get_multisig_tx_by_id(&tx_id).await.unwrap().unwrap().dissolve();
+
+    let execute_request = ExecuteReadyTxRequest::builder().tx_id(tx_id.clone()).build();
+    let execute_result = engine.execute_ready_tx(execute_request).await;
+
+    let MultisigTxDissolved { status: status_after_execution, .. } =
+        store.get_multisig_tx_by_id(&tx_id).await.unwrap().unwrap().dissolve();
+
+    let execute_again_request = ExecuteReadyTxRequest::builder().tx_id(tx_id).build();
+    let execute_again_result = engine.execute_ready_tx(execute_again_request).await;
+
+    // Assert
+    assert!(threshold_met);
+    assert!(tx_result.is_none());
+    assert!(matches!(status_before_execution, MultisigTxStatus::ReadyToExecute));
+
+    assert!(execute_result.is_ok());
+    assert!(matches!(status_after_execution, MultisigTxStatus::Success));
+
+    let err = execute_again_result.unwrap_err();
+    assert_eq!(err.multisig_tx_not_pending(), Some(MultisigTxStatus::Success));
+}
+
+#[tokio::test]
+async fn proposed_pay_to_id_tx_persists_effect_listing_recipient_and_amount() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let (mut ff_client, ff_account) =
+        setup_fungible_faucet_client(&temp_dir.join("ff"), "EFF", 8, 5_000_000).await;
+
+    let (_, alice_account, alice_sk) = setup_regular_account_client(&temp_dir.join("alice")).await;
+
+    let (_, charlie_account, _) = setup_regular_account_client(&temp_dir.join("charlie")).await;
+
+    let engine = start_testnet_multisig_engine(&temp_dir.join("multisig")).await;
+
+    let alice_addr = AccountIdAddress::new(alice_account.id(), AddressInterface::BasicWallet);
+
+    let create_account_request = CreateMultisigAccountRequest::builder()
+        .threshold(NonZeroU32::MIN)
+        .approvers(vec![alice_addr])
+        .pub_key_commits(vec![alice_sk.public_key()])
+        .build()
+        .unwrap();
+
+    let CreateMultisigAccountResponseDissolved { miden_account: multisig_account, .. } =
+        engine.create_multisig_account(create_account_request).await.unwrap().dissolve();
+
+    let multisig_addr = AccountIdAddress::new(multisig_account.id(), AddressInterface::BasicWallet);
+
+    let asset = FungibleAsset::new(ff_account.id(), 100_000).unwrap();
+
+    let mint_request = TransactionRequestBuilder::new()
+        .build_mint_fungible_asset(asset, multisig_account.id(), NoteType::Public, ff_client.rng())
+        .unwrap();
+
+    ff_client.sync_state().await.unwrap();
+    let tx_result = ff_client.new_transaction(ff_account.id(), mint_request).await.unwrap();
+
+    ff_client.submit_transaction(tx_result).await.unwrap();
+
+    let note_ids = wait_for_consumable_note_ids(&engine, None).await;
+
+    let propose_request = ProposeMultisigTxRequest::builder()
+        .address(multisig_addr)
+        .tx_request(TransactionRequestBuilder::new().build_consume_notes(note_ids).unwrap())
+        .build()
+        .unwrap();
+
+    let ProposeMultisigTxResponseDissolved { tx_id, tx_summary } =
+        engine.propose_multisig_tx(propose_request).await.unwrap().dissolve();
+
+    let add_sig_request = AddSignatureRequest::builder()
+        .tx_id(tx_id)
+        .approver(alice_addr)
+        .signature(alice_sk.sign(tx_summary.to_commitment()))
+        .build();
+
+    let tx_result = engine.add_signature(add_sig_request).await.unwrap();
+
+    wait_for_tx_commit(&tx_result.expect("tx must have been executed")).await;
+
+    let payment = FungibleAsset::new(ff_account.id(), 40_000).unwrap();
+
+    let pay_to_id_request = TransactionRequestBuilder::new()
+        .build_pay_to_id(
+            PaymentNoteDescription::new(
+                vec![payment.into()],
+                multisig_account.id(),
+                charlie_account.id(),
+            ),
+            NoteType::Public,
+            ff_client.rng(),
+        )
+        .unwrap();
+
+    // Act
+    let dry_run_request = DryRunMultisigTxRequest::builder()
+        .address(multisig_addr)
+        .tx_request(pay_to_id_request.clone())
+        .build();
+
+    let DryRunMultisigTxResponseDissolved { tx_summary: dry_run_tx_summary } =
+        engine.dry_run_multisig_tx(dry_run_request).await.unwrap().dissolve();
+
+    let propose_request = ProposeMultisigTxRequest::builder()
+        .address(multisig_addr)
+        .tx_request(pay_to_id_request)
+        .build()
+        .unwrap();
+
+    engine.propose_multisig_tx(propose_request).await.unwrap();
+
+    let list_tx_request = ListMultisigTxRequest::builder()
+        .multisig_account_id_address(multisig_addr)
+        .tx_status_filter(MultisigTxStatus::Pending)
+        .build();
+
+    let ListMultisigTxResponseDissolved { txs, .. } =
+        engine.list_multisig_tx(list_tx_request).await.unwrap().dissolve();
+
+    // Assert
+    let [tx] = txs.try_into().expect("exactly one pending tx must have been proposed");
+    let MultisigTxDissolved { tx_effect, .. } = tx.dissolve();
+
+    assert_eq!(TxEffect::from(&dry_run_tx_summary), tx_effect);
+
+    let [asset_out] = tx_effect.assets_out() else {
+        panic!("exactly one asset must leave the multisig account vault");
+    };
+
+    assert_eq!(asset_out.faucet_id(), ff_account.id());
+    assert_eq!(asset_out.amount(), payment.amount());
+
+    let [output_note] = tx_effect.output_notes() else {
+        panic!("exactly one output note must have been created");
+    };
+
+    assert_eq!(output_note.counterparty(), Some(charlie_account.id()));
+    assert_eq!(output_note.assets(), [*asset_out]);
+    assert_eq!(tx_effect.counterparties(), [charlie_account.id()]);
+}
+
+#[tokio::test]
+async fn proposed_payment_pays_asset_to_recipient() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let (mut ff_client, ff_account) =
+        setup_fungible_faucet_client(&temp_dir.join("ff"), "PAY", 8, 5_000_000).await;
+
+    let (_, alice_account, alice_sk) = setup_regular_account_client(&temp_dir.join("alice")).await;
+
+    let (_, charlie_account, _) = setup_regular_account_client(&temp_dir.join("charlie")).await;
+
+    let engine = start_testnet_multisig_engine(&temp_dir.join("multisig")).await;
+
+    let alice_addr = AccountIdAddress::new(alice_account.id(), AddressInterface::BasicWallet);
+
+    let create_account_request = CreateMultisigAccountRequest::builder()
+        .threshold(NonZeroU32::MIN)
+        .approvers(vec![alice_addr])
+        .pub_key_commits(vec![alice_sk.public_key()])
+        .build()
+        .unwrap();
+
+    let CreateMultisigAccountResponseDissolved { miden_account: multisig_account, .. } =
+        engine.create_multisig_account(create_account_request).await.unwrap().dissolve();
+
+    let multisig_addr = AccountIdAddress::new(multisig_account.id(), AddressInterface::BasicWallet);
+
+    let asset = FungibleAsset::new(ff_account.id(), 100_000).unwrap();
+
+    let mint_request = TransactionRequestBuilder::new()
+        .build_mint_fungible_asset(asset, multisig_account.id(), NoteType::Public, ff_client.rng())
+        .unwrap();
+
+    ff_client.sync_state().await.unwrap();
+    let tx_result = ff_client.new_transaction(ff_account.id(), mint_request).await.unwrap();
+
+    ff_client.submit_transaction(tx_result).await.unwrap();
+
+    wait_for_consumable_note_ids(&engine, Some(multisig_addr)).await;
+
+    let propose_request = ProposeConsumeNotesRequest::builder()
+        .address(multisig_addr)
+        .selector(NoteSelector::All)
+        .build();
+
+    let ProposeConsumeNotesResponseDissolved { tx_id, tx_summary, .. } =
+        engine.propose_consume_notes(propose_request).await.unwrap().dissolve();
+
+    let add_sig_request = AddSignatureRequest::builder()
+        .tx_id(tx_id)
+        .approver(alice_addr)
+        .signature(alice_sk.sign(tx_summary.to_commitment()))
+        .build();
+
+    let tx_result = engine.add_signature(add_sig_request).await.unwrap();
+
+    wait_for_tx_commit(&tx_result.expect("tx must have been executed")).await;
+
+    let payment = FungibleAsset::new(ff_account.id(), 40_000).unwrap();
+
+    let propose_request = ProposePaymentRequest::builder()
+        .address(multisig_addr)
+        .recipient(AccountIdAddress::new(charlie_account.id(), AddressInterface::BasicWallet))
+        .asset(payment.into())
+        .note_type(NoteType::Public)
+        .build()
+        .unwrap();
+
+    // Act
+    let ProposePaymentResponseDissolved { tx_summary, .. } =
+        engine.propose_payment(propose_request).await.unwrap().dissolve();
+
+    // Assert
+    let tx_effect = TxEffect::from(&tx_summary);
+
+    let [asset_out] = tx_effect.assets_out() else {
+        panic!("exactly one asset must leave the multisig account vault");
+    };
+
+    assert_eq!(asset_out.faucet_id(), ff_account.id());
+    assert_eq!(asset_out.amount(), payment.amount());
+
+    let [output_note] = tx_effect.output_notes() else {
+        panic!("exactly one output note must have been created");
+    };
+
+    assert_eq!(output_note.counterparty(), Some(charlie_account.id()));
+    assert_eq!(output_note.assets(), [*asset_out]);
+}
+
+#[tokio::test]
+async fn proposing_payment_exceeding_balance_fails_with_insufficient_balance() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let (_, ff_account) =
+        setup_fungible_faucet_client(&temp_dir.join("ff"), "OVR", 8, 5_000_000).await;
+
+    let (_, alice_account, alice_sk) = setup_regular_account_client(&temp_dir.join("alice")).await;
+
+    let (_, charlie_account, _) = setup_regular_account_client(&temp_dir.join("charlie")).await;
+
+    let engine = start_testnet_multisig_engine(&temp_dir.join("multisig")).await;
+
+    let alice_addr = AccountIdAddress::new(alice_account.id(), AddressInterface::BasicWallet);
+
+    let create_account_request = CreateMultisigAccountRequest::builder()
+        .threshold(NonZeroU32::MIN)
+        .approvers(vec![alice_addr])
+        .pub_key_commits(vec![alice_sk.public_key()])
+        .build()
+        .unwrap();
+
+    let CreateMultisigAccountResponseDissolved { miden_account: multisig_account, .. } =
+        engine.create_multisig_account(create_account_request).await.unwrap().dissolve();
+
+    let multisig_addr = AccountIdAddress::new(multisig_account.id(), AddressInterface::BasicWallet);
+
+    let propose_request = ProposePaymentRequest::builder()
+        .address(multisig_addr)
+        .recipient(AccountIdAddress::new(charlie_account.id(), AddressInterface::BasicWallet))
+        .asset(FungibleAsset::new(ff_account.id(), 1_000).unwrap().into())
+        .note_type(NoteType::Public)
+        .build()
+        .unwrap();
+
+    // Act
+    let err = engine.propose_payment(propose_request).await.unwrap_err();
+
+    // Assert
+    assert_eq!(err.insufficient_balance(), Some((ff_account.id(), 1_000, 0)));
+
+    let list_tx_request = ListMultisigTxRequest::builder()
+        .multisig_account_id_address(multisig_addr)
+        .build();
+
+    let ListMultisigTxResponseDissolved { txs, .. } =
+        engine.list_multisig_tx(list_tx_request).await.unwrap().dissolve();
+
+    assert!(txs.is_empty(), "no tx must be proposed");
+}
+
+#[tokio::test]
+async fn validating_multisig_tx_reports_executability_without_persisting_anything() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let (mut ff_client, ff_account) =
+        setup_fungible_faucet_client(&temp_dir.join("ff"), "VAL", 8, 5_000_000).await;
+
+    let (_, alice_account, alice_sk) = setup_regular_account_client(&temp_dir.join("alice")).await;
+
+    let (_, charlie_account, _) = setup_regular_account_client(&temp_dir.join("charlie")).await;
+
+    let engine = start_testnet_multisig_engine(&temp_dir.join("multisig")).await;
+
+    let alice_addr = AccountIdAddress::new(alice_account.id(), AddressInterface::BasicWallet);
+
+    let create_account_request = CreateMultisigAccountRequest::builder()
+        .threshold(NonZeroU32::MIN)
+        .approvers(vec![alice_addr])
+        .pub_key_commits(vec![alice_sk.public_key()])
+        .build()
+        .unwrap();
+
+    let CreateMultisigAccountResponseDissolved { miden_account: multisig_account, .. } =
+        engine.create_multisig_account(create_account_request).await.unwrap().dissolve();
+
+    let multisig_addr = AccountIdAddress::new(multisig_account.id(), AddressInterface::BasicWallet);
+
+    let asset = FungibleAsset::new(ff_account.id(), 1_000).unwrap();
+
+    let mint_request = TransactionRequestBuilder::new()
+        .build_mint_fungible_asset(asset, multisig_account.id(), NoteType::Public, ff_client.rng())
+        .unwrap();
+
+    ff_client.sync_state().await.unwrap();
+    let tx_result = ff_client.new_transaction(ff_account.id(), mint_request).await.unwrap();
+
+    ff_client.submit_transaction(tx_result).await.unwrap();
+
+    let note_ids = wait_for_consumable_note_ids(&engine, None).await;
+
+    let consume_notes_request = TransactionRequestBuilder::new().build_consume_notes(note_ids);
+
+    // the minted notes are not consumed yet, so the vault holds nothing to pay out
+    let payment = PaymentNoteDescription::new(
+        vec![asset.into()],
+        multisig_account.id(),
+        charlie_account.id(),
+    );
+
+    let overpaying_request = TransactionRequestBuilder::new().build_pay_to_id(
+        payment,
+        NoteType::Public,
+        ff_client.rng(),
+    );
+
+    // Act
+    let validate_request = ValidateMultisigTxRequest::builder()
+        .address(multisig_addr)
+        .tx_request(consume_notes_request.unwrap())
+        .build();
+
+    let executable = engine.validate_multisig_tx(validate_request).await.unwrap();
+    let is_executable = executable.is_executable();
+    let ValidateMultisigTxResponseDissolved { tx_summary, reason } = executable.dissolve();
+
+    let validate_request = ValidateMultisigTxRequest::builder()
+        .address(multisig_addr)
+        .tx_request(overpaying_request.unwrap())
+        .build();
+
+    let not_executable = engine.validate_multisig_tx(validate_request).await.unwrap();
+
+    // Assert
+    assert!(is_executable);
+    assert!(reason.is_none());
+
+    let tx_summary = tx_summary.expect("executable tx must be summarized");
+    assert_eq!(TxEffect::from(&tx_summary).assets_in().len(), 1);
+
+    assert!(!not_executable.is_executable());
+
+    let ValidateMultisigTxResponseDissolved { tx_summary, reason } = not_executable.dissolve();
+    assert!(tx_summary.is_none());
+    assert!(reason.is_some_and(|reason| reason.contains("insufficient balance")));
+
+    let list_tx_request = ListMultisigTxRequest::builder()
+        .multisig_account_id_address(multisig_addr)
+        .build();
+
+    let ListMultisigTxResponseDissolved { txs, .. } =
+        engine.list_multisig_tx(list_tx_request).await.unwrap().dissolve();
+
+    assert!(txs.is_empty(), "validating must not persist any tx");
+}
+
+#[test]
+fn propose_payment_request_rejects_paying_the_multisig_account_itself() {
+    // Arrange
+    let multisig_addr = AccountIdAddress::new(
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE.try_into().unwrap(),
+        AddressInterface::BasicWallet,
+    );
+
+    let payment =
+        FungibleAsset::new(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET.try_into().unwrap(), 100).unwrap();
+
+    // Act
+    let result = ProposePaymentRequest::builder()
+        .address(multisig_addr)
+        .recipient(multisig_addr)
+        .asset(payment.into())
+        .note_type(NoteType::Public)
+        .build();
+
+    // Assert
+    assert!(matches!(result, Err(ProposePaymentRequestError::SelfPayment)));
+}
+
+#[tokio::test]
+async fn failed_multisig_tx_persists_failure_reason() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let (mut ff_client, ff_account) =
+        setup_fungible_faucet_client(&temp_dir.join("ff"), "FLR", 8, 5_000_000).await;
+
+    let (_, alice_account, alice_sk) = setup_regular_account_client(&temp_dir.join("alice")).await;
+
+    let (_, bob_account, bob_sk) = setup_regular_account_client(&temp_dir.join("bob")).await;
+
+    let engine = start_testnet_multisig_engine(&temp_dir.join("multisig")).await;
+
+    let alice_addr = AccountIdAddress::new(alice_account.id(), AddressInterface::BasicWallet);
+    let bob_addr = AccountIdAddress::new(bob_account.id(), AddressInterface::BasicWallet);
+
+    let create_account_request = CreateMultisigAccountRequest::builder()
+        .threshold(NonZeroU32::new(2).unwrap())
+        .approvers(vec![alice_addr, bob_addr])
+        .pub_key_commits(vec![alice_sk.public_key(), bob_sk.public_key()])
+        .build()
+        .unwrap();
+
+    let CreateMultisigAccountResponseDissolved { miden_account: multisig_account, .. } =
+        engine.create_multisig_account(create_account_request).await.unwrap().dissolve();
+
+    let multisig_addr = AccountIdAddress::new(multisig_account.id(), AddressInterface::BasicWallet);
+
+    let asset = FungibleAsset::new(ff_account.id(), 100_000).unwrap();
+
+    let mint_request = TransactionRequestBuilder::new()
+        .build_mint_fungible_asset(asset, multisig_account.id(), NoteType::Public, ff_client.rng())
+        .unwrap();
+
+    ff_client.sync_state().await.unwrap();
+    let tx_result = ff_client.new_transaction(ff_account.id(), mint_request).await.unwrap();
+
+    ff_client.submit_transaction(tx_result).await.unwrap();
+
+    let note_ids = wait_for_consumable_note_ids(&engine, None).await;
+
+    // both proposals consume the same notes, so only the first one processed can succeed
+    let mut proposals = Vec::new();
+    for _ in 0..2 {
+        let tx_request =
+            TransactionRequestBuilder::new().build_consume_notes(note_ids.clone()).unwrap();
+
+        let propose_request = ProposeMultisigTxRequest::builder()
+            .address(multisig_addr)
+            .tx_request(tx_request)
+            .build()
+            .unwrap();
+
+        let ProposeMultisigTxResponseDissolved { tx_id, tx_summary } =
+            engine.propose_multisig_tx(propose_request).await.unwrap().dissolve();
+
+        proposals.push((tx_id, tx_summary.to_commitment()));
+    }
+
+    let [(first_tx_id, first_commitment), (second_tx_id, second_commitment)] =
+        proposals.try_into().unwrap();
+
+    let add_sigs_request = AddSignaturesRequest::builder()
+        .tx_id(first_tx_id)
+        .signatures(vec![
+            (alice_addr, alice_sk.sign(first_commitment).into()),
+            (bob_addr, bob_sk.sign(first_commitment).into()),
+        ])
+        .build()
+        .unwrap();
+
+    let AddSignaturesResponseDissolved { tx_result, .. } =
+        engine.add_signatures(add_sigs_request).await.unwrap().dissolve();
+
+    wait_for_tx_commit(&tx_result.expect("tx must have been executed")).await;
+
+    // Act
+    let add_sigs_request = AddSignaturesRequest::builder()
+        .tx_id(second_tx_id)
+        .signatures(vec![
+            (alice_addr, alice_sk.sign(second_commitment).into()),
+            (bob_addr, bob_sk.sign(second_commitment).into()),
+        ])
+        .build()
+        .unwrap();
+
+    let add_sigs_result = engine.add_signatures(add_sigs_request).await;
+
+    // Assert
+    assert!(add_sigs_result.is_err());
+
+    let list_tx_request = ListMultisigTxRequest::builder()
+        .multisig_account_id_address(multisig_addr)
+        .tx_status_filter(MultisigTxStatus::Failure)
+        .build();
+
+    let ListMultisigTxResponseDissolved { txs, .. } =
+        engine.list_multisig_tx(list_tx_request).await.unwrap().dissolve();
+
+    let [failed_tx] = txs.try_into().expect("exactly one tx must have failed");
+    let MultisigTxDissolved { failure_reason, .. } = failed_tx.dissolve();
+
+    assert!(failure_reason.is_some_and(|reason| !reason.is_empty()));
+}
+
+#[tokio::test]
+async fn reproposing_failed_multisig_tx_clears_signatures_and_stores_new_commitment() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let (mut ff_client, ff_account) =
+        setup_fungible_faucet_client(&temp_dir.join("ff"), "RPR", 8, 5_000_000).await;
+
+    let (_, alice_account, alice_sk) = setup_regular_account_client(&temp_dir.join("alice")).await;
+
+    let (_, bob_account, bob_sk) = setup_regular_account_client(&temp_dir.join("bob")).await;
+
+    let db_url = setup_test_db().await;
+
+    let engine =
+        start_testnet_multisig_engine_with_db_url(&temp_dir.join("multisig"), db_url.clone()).await;
+
+    let store = setup_multisig_store(db_url).await;
+
+    let alice_addr = AccountIdAddress::new(alice_account.id(), AddressInterface::BasicWallet);
+    let bob_addr = AccountIdAddress::new(bob_account.id(), AddressInterface::BasicWallet);
+
+    let create_account_request = CreateMultisigAccountRequest::builder()
+        .threshold(NonZeroU32::new(2).unwrap())
+        .approvers(vec![alice_addr, bob_addr])
+        .pub_key_commits(vec![alice_sk.public_key(), bob_sk.public_key()])
+        .build()
+        .unwrap();
+
+    let CreateMultisigAccountResponseDissolved { miden_account: multisig_account, .. } =
+        engine.create_multisig_account(create_account_request).await.unwrap().dissolve();
+
+    let asset = FungibleAsset::new(ff_account.id(), 100_000).unwrap();
+
+    let mint_request = TransactionRequestBuilder::new()
+        .build_mint_fungible_asset(asset, multisig_account.id(), NoteType::Public, ff_client.rng())
+        .unwrap();
+
+    ff_client.sync_state().await.unwrap();
+    let tx_result = ff_client.new_transaction(ff_account.id(), mint_request).await.unwrap();
+
+    ff_client.submit_transaction(tx_result).await.unwrap();
+
+    let note_ids = wait_for_consumable_note_ids(&engine, None).await;
+
+    let propose_request = ProposeMultisigTxRequest::builder()
+        .address(AccountIdAddress::new(multisig_account.id(), AddressInterface::BasicWallet))
+        .tx_request(TransactionRequestBuilder::new().build_consume_notes(note_ids).unwrap())
+        .build()
+        .unwrap();
+
+    let ProposeMultisigTxResponseDissolved { tx_id, tx_summary } =
+        engine.propose_multisig_tx(propose_request).await.unwrap().dissolve();
+
+    let add_sig_request = AddSignatureRequest::builder()
+        .tx_id(tx_id.clone())
+        .approver(alice_addr)
+        .signature(alice_sk.sign(tx_summary.to_commitment()))
+        .build();
+
+    engine.add_signature(add_sig_request).await.unwrap();
+
+    store
+        .update_multisig_tx_status_to_failure_by_id(&tx_id, "stale chain state")
+        .await
+        .unwrap();
+
+    // Act
+    let repropose_request = ReproposeMultisigTxRequest::builder().tx_id(tx_id.clone()).build();
+
+    let ReproposeMultisigTxResponseDissolved { tx_summary: new_tx_summary, .. } =
+        engine.repropose_multisig_tx(repropose_request).await.unwrap().dissolve();
+
+    // Assert
+    let MultisigTxDissolved {
+        status,
+        tx_summary_commit,
+        failure_reason,
+        signature_count,
+        ..
+    } = store.get_multisig_tx_by_id(&tx_id).await.unwrap().unwrap().dissolve();
+
+    assert!(matches!(status, MultisigTxStatus::Pending));
+    assert_eq!(tx_summary_commit, new_tx_summary.to_commitment());
+    assert!(failure_reason.is_none());
+    assert!(signature_count.is_none());
+
+    let (signatures, _) = store
+        .get_signatures_of_all_approvers_with_multisig_tx_by_tx_id(&tx_id)
+        .await
+        .unwrap();
+
+    assert!(signatures.iter().all(Option::is_none));
+}
+
+#[tokio::test]
+async fn multisig_tx_stats_count_signed_txs_per_approver() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let (mut ff_client, ff_account) =
+        setup_fungible_faucet_client(&temp_dir.join("ff"), "STA", 8, 5_000_000).await;
+
+    let (_, alice_account, alice_sk) = setup_regular_account_client(&temp_dir.join("alice")).await;
+
+    let (_, bob_account, bob_sk) = setup_regular_account_client(&temp_dir.join("bob")).await;
+
+    let (_, charlie_account, charlie_sk) =
+        setup_regular_account_client(&temp_dir.join("charlie")).await;
+
+    let engine = start_testnet_multisig_engine(&temp_dir.join("multisig")).await;
+
+    let alice_addr = AccountIdAddress::new(alice_account.id(), AddressInterface::BasicWallet);
+    let bob_addr = AccountIdAddress::new(bob_account.id(), AddressInterface::BasicWallet);
+    let charlie_addr = AccountIdAddress::new(charlie_account.id(), AddressInterface::BasicWallet);
+
+    // the threshold is never met, so all proposals stay pending
+    let create_account_request = CreateMultisigAccountRequest::builder()
+        .threshold(NonZeroU32::new(3).unwrap())
+        .approvers(vec![alice_addr, bob_addr, charlie_addr])
+        .pub_key_commits(vec![alice_sk.public_key(), bob_sk.public_key(), charlie_sk.public_key()])
+        .build()
+        .unwrap();
+
+    let CreateMultisigAccountResponseDissolved { miden_account: multisig_account, .. } =
+        engine.create_multisig_account(create_account_request).await.unwrap().dissolve();
+
+    let multisig_addr = AccountIdAddress::new(multisig_account.id(), AddressInterface::BasicWallet);
+
+    let asset = FungibleAsset::new(ff_account.id(), 100_000).unwrap();
+
+    let mint_request = TransactionRequestBuilder::new()
+        .build_mint_fungible_asset(asset, multisig_account.id(), NoteType::Public, ff_client.rng())
+        .unwrap();
+
+    ff_client.sync_state().await.unwrap();
+    let tx_result = ff_client.new_transaction(ff_account.id(), mint_request).await.unwrap();
+
+    ff_client.submit_transaction(tx_result).await.unwrap();
+
+    let note_ids = wait_for_consumable_note_ids(&engine, None).await;
+
+    // alice signs every proposal, bob only the first one and charlie none
+    for i in 0..3 {
+        let tx_request =
+            TransactionRequestBuilder::new().build_consume_notes(note_ids.clone()).unwrap();
+
+        let propose_request = ProposeMultisigTxRequest::builder()
+            .address(multisig_addr)
+            .tx_request(tx_request)
+            .build()
+            .unwrap();
+
+        let ProposeMultisigTxResponseDissolved { tx_id, tx_summary } =
+            engine.propose_multisig_tx(propose_request).await.unwrap().dissolve();
+
+        let tx_summary_commitment = tx_summary.to_commitment();
+
+        let mut signatures = vec![(alice_addr, alice_sk.sign(tx_summary_commitment).into())];
+        if i == 0 {
+            signatures.push((bob_addr, bob_sk.sign(tx_summary_commitment).into()));
+        }
+
+        let add_sigs_request = AddSignaturesRequest::builder()
+            .tx_id(tx_id)
+            .signatures(signatures)
+            .build()
+            .unwrap();
+
+        engine.add_signatures(add_sigs_request).await.unwrap();
+    }
+
+    // Act
+    let tx_stats_request = GetMultisigTxStatsRequest::builder()
+        .multisig_account_id_address(multisig_addr)
+        .build();
+
+    let GetMultisigTxStatsResponseDissolved { tx_stats } =
+        engine.get_multisig_tx_stats(tx_stats_request).await.unwrap().dissolve();
+
+    // Assert
+    let MultisigTxStatsDissolved { total, approver_signed_counts, .. } = tx_stats.dissolve();
+
+    assert_eq!(total, 3);
+    assert_eq!(approver_signed_counts, vec![(alice_addr, 3), (bob_addr, 1), (charlie_addr, 0)]);
+}
+
+#[tokio::test]
+async fn listing_multisig_txs_with_cursors_walks_history_without_duplicates_or_gaps() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let (mut ff_client, ff_account) =
+        setup_fungible_faucet_client(&temp_dir.join("ff"), "PAG", 8, 5_000_000).await;
+
+    let (_, alice_account, alice_sk) = setup_regular_account_client(&temp_dir.join("alice")).await;
+
+    let (_, bob_account, bob_sk) = setup_regular_account_client(&temp_dir.join("bob")).await;
+
+    let engine = start_testnet_multisig_engine(&temp_dir.join("multisig")).await;
+
+    let alice_addr = AccountIdAddress::new(alice_account.id(), AddressInterface::BasicWallet);
+    let bob_addr = AccountIdAddress::new(bob_account.id(), AddressInterface::BasicWallet);
+
+    let create_account_request = CreateMultisigAccountRequest::builder()
+        .threshold(NonZeroU32::new(2).unwrap())
+        .approvers(vec![alice_addr, bob_addr])
+        .pub_key_commits(vec![alice_sk.public_key(), bob_sk.public_key()])
+        .build()
+        .unwrap();
+
+    let CreateMultisigAccountResponseDissolved { miden_account: multisig_account, .. } =
+        engine.create_multisig_account(create_account_request).await.unwrap().dissolve();
+
+    let multisig_addr = AccountIdAddress::new(multisig_account.id(), AddressInterface::BasicWallet);
+
+    let asset = FungibleAsset::new(ff_account.id(), 100_000).unwrap();
+
+    let mint_request = TransactionRequestBuilder::new()
+        .build_mint_fungible_asset(asset, multisig_account.id(), NoteType::Public, ff_client.rng())
+        .unwrap();
+
+    ff_client.sync_state().await.unwrap();
+    let tx_result = ff_client.new_transaction(ff_account.id(), mint_request).await.unwrap();
+
+    ff_client.submit_transaction(tx_result).await.unwrap();
+
+    let note_ids = wait_for_consumable_note_ids(&engine, None).await;
+
+    // no signatures are added, so all proposals stay pending
+    let mut proposed_tx_ids = Vec::new();
+    for _ in 0..5 {
+        let tx_request =
+            TransactionRequestBuilder::new().build_consume_notes(note_ids.clone()).unwrap();
+
+        let propose_request = ProposeMultisigTxRequest::builder()
+            .address(multisig_addr)
+            .tx_request(tx_request)
+            .build()
+            .unwrap();
+
+        let ProposeMultisigTxResponseDissolved { tx_id, .. } =
+            engine.propose_multisig_tx(propose_request).await.unwrap().dissolve();
+
+        proposed_tx_ids.push(tx_id);
+    }
+
+    // Act
+    let mut listed_tx_ids = Vec::new();
+    let mut page_lens = Vec::new();
+    let mut cursor = None;
+
+    loop {
+        let list_tx_request = ListMultisigTxRequest::builder()
+            .multisig_account_id_address(multisig_addr)
+            .maybe_after(cursor)
+            .limit(NonZeroU32::new(2).unwrap())
+            .build();
+
+        let ListMultisigTxResponseDissolved { txs, next_cursor, .. } =
+            engine.list_multisig_tx(list_tx_request).await.unwrap().dissolve();
+
+        page_lens.push(txs.len());
+        listed_tx_ids.extend(txs.into_iter().map(|tx| tx.dissolve().id));
+
+        let Some(next_cursor) = next_cursor else { break };
+        cursor = Some(next_cursor);
+    }
+
+    // Assert
+    assert_eq!(page_lens, vec![2, 2, 1]);
+
+    let expected_tx_ids: Vec<_> = proposed_tx_ids.into_iter().rev().collect();
+    assert_eq!(listed_tx_ids, expected_tx_ids);
+}
+
+#[tokio::test]
+async fn proposing_with_trusted_tx_summary_skips_multisig_client_runtime() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::MIN)
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![ApproverKey::Falcon(SecretKey::new().public_key())])
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    let engine = MultisigEngine::new(NetworkId::Testnet, store).with_trust_tx_summary(true);
+
+    // the runtime doesn't track this account, so proposing fails if any msg is sent to it
+    let engine = start_offline_multisig_engine(temp_dir, engine).await;
+
+    let tx_request = pay_to_id_tx_request(multisig_addr.id(), alice_addr.id());
+
+    let account_delta = AccountDelta::new(
+        multisig_addr.id(),
+        AccountStorageDelta::default(),
+        AccountVaultDelta::default(),
+        ONE,
+    )
+    .unwrap();
+
+    let tx_summary = tx_summary_of(account_delta);
+
+    let propose_request = ProposeMultisigTxRequest::builder()
+        .address(multisig_addr)
+        .tx_request(tx_request)
+        .tx_summary(tx_summary.clone())
+        .build()
+        .unwrap();
+
+    // Act
+    let ProposeMultisigTxResponseDissolved { tx_summary: proposed_tx_summary, .. } =
+        engine.propose_multisig_tx(propose_request).await.unwrap().dissolve();
+
+    let list_tx_request = ListMultisigTxRequest::builder()
+        .multisig_account_id_address(multisig_addr)
+        .tx_status_filter(MultisigTxStatus::Pending)
+        .build();
+
+    let ListMultisigTxResponseDissolved { txs, .. } =
+        engine.list_multisig_tx(list_tx_request).await.unwrap().dissolve();
+
+    // Assert
+    assert_eq!(proposed_tx_summary.to_commitment(), tx_summary.to_commitment());
+
+    let [tx] = txs.try_into().expect("exactly one pending tx must have been proposed");
+    let MultisigTxDissolved { tx_summary: persisted_tx_summary, .. } = tx.dissolve();
+
+    assert_eq!(persisted_tx_summary.to_commitment(), tx_summary.to_commitment());
+}
+
+#[tokio::test]
+async fn proposed_multisig_tx_is_fetched_by_its_summary_commitment() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::MIN)
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![ApproverKey::Falcon(SecretKey::new().public_key())])
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    let engine = MultisigEngine::new(NetworkId::Testnet, store).with_trust_tx_summary(true);
+    let engine = start_offline_multisig_engine(temp_dir, engine).await;
+
+    let tx_request = pay_to_id_tx_request(multisig_addr.id(), alice_addr.id());
+
+    let account_delta = AccountDelta::new(
+        multisig_addr.id(),
+        AccountStorageDelta::default(),
+        AccountVaultDelta::default(),
+        ONE,
+    )
+    .unwrap();
+
+    let tx_summary = tx_summary_of(account_delta);
+
+    let propose_request = ProposeMultisigTxRequest::builder()
+        .address(multisig_addr)
+        .tx_request(tx_request)
+        .tx_summary(tx_summary.clone())
+        .build()
+        .unwrap();
+
+    let ProposeMultisigTxResponseDissolved { tx_id, .. } =
+        engine.propose_multisig_tx(propose_request).await.unwrap().dissolve();
+
+    let get_tx_by_summary_commit = async |tx_summary_commit: Word| {
+        let request = GetMultisigTxBySummaryCommitRequest::builder()
+            .tx_summary_commit(tx_summary_commit)
+            .build();
+
+        let GetMultisigTxBySummaryCommitResponseDissolved { multisig_tx } =
+            engine.get_multisig_tx_by_summary_commit(request).await.unwrap().dissolve();
+
+        multisig_tx
+    };
+
+    // Act
+    let fetched_tx = get_tx_by_summary_commit(tx_summary.to_commitment()).await;
+    let unknown_tx = get_tx_by_summary_commit(Word::default()).await;
+
+    // Assert
+    let MultisigTxDissolved { id, tx_summary_commit, .. } = fetched_tx
+        .expect("proposed tx must be found by its summary commitment")
+        .dissolve();
+
+    assert_eq!(id, tx_id);
+    assert_eq!(tx_summary_commit, tx_summary.to_commitment());
+
+    assert!(unknown_tx.is_none(), "no tx must be found by an unknown summary commitment");
+}
+
+#[tokio::test]
+async fn proposing_checks_account_on_primary_despite_lagging_read_replica() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let primary_db_url = setup_test_db().await;
+
+    // the replica is another database that never catches up with the primary
+    let replica_pool = miden_multisig_coordinator_store::establish_pool(
+        setup_test_db().await,
+        NonZeroUsize::MIN,
+        ConnConfig::default(),
+    )
+    .await
+    .expect("failed to initialize replica pool");
+
+    let store = setup_multisig_store(primary_db_url.clone()).await.with_read_pool(replica_pool);
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::MIN)
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![ApproverKey::Falcon(SecretKey::new().public_key())])
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    let engine = MultisigEngine::new(NetworkId::Testnet, store).with_trust_tx_summary(true);
+    let engine = start_offline_multisig_engine(temp_dir, engine).await;
+
+    let tx_request = pay_to_id_tx_request(multisig_addr.id(), alice_addr.id());
+
+    let tx_summary = empty_tx_summary(multisig_addr.id());
+
+    let propose_request = ProposeMultisigTxRequest::builder()
+        .address(multisig_addr)
+        .tx_request(tx_request)
+        .tx_summary(tx_summary)
+        .build()
+        .unwrap();
+
+    // Act
+    let ProposeMultisigTxResponseDissolved { tx_id, .. } =
+        engine.propose_multisig_tx(propose_request).await.unwrap().dissolve();
+
+    // Assert
+    let primary_store = setup_multisig_store(primary_db_url).await;
+
+    let tx = primary_store.get_multisig_tx_by_id(&tx_id).await.unwrap();
+    assert!(tx.is_some());
+}
+
+#[tokio::test]
+async fn listing_multisig_txs_skips_undeserializable_tx_and_reports_its_id() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let db_url = setup_test_db().await;
+    let store = setup_multisig_store(db_url.clone()).await;
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::MIN)
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![ApproverKey::Falcon(SecretKey::new().public_key())])
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    let engine = MultisigEngine::new(NetworkId::Testnet, store).with_trust_tx_summary(true);
+
+    // proposals are trusted, so no msg is ever sent to the runtime
+    let engine = start_offline_multisig_engine(temp_dir, engine).await;
+
+    let tx_summary = empty_tx_summary(multisig_addr.id());
+
+    let mut proposed_tx_ids = Vec::new();
+    for _ in 0..3 {
+        let tx_request = pay_to_id_tx_request(multisig_addr.id(), alice_addr.id());
+
+        let propose_request = ProposeMultisigTxRequest::builder()
+            .address(multisig_addr)
+            .tx_request(tx_request)
+            .tx_summary(tx_summary.clone())
+            .build()
+            .unwrap();
+
+        let ProposeMultisigTxResponseDissolved { tx_id, .. } =
+            engine.propose_multisig_tx(propose_request).await.unwrap().dissolve();
+
+        proposed_tx_ids.push(tx_id);
+    }
+
+    let corrupt_tx_id = proposed_tx_ids[1].clone();
+
+    diesel::sql_query(format!(
+        "UPDATE tx SET tx_summary = '\\x00'::bytea WHERE id = '{corrupt_tx_id}'"
+    ))
+    .execute(&mut PgConnection::establish(&db_url).expect("failed to connect to test database"))
+    .expect("failed to corrupt tx summary");
+
+    let list_tx_request = ListMultisigTxRequest::builder()
+        .multisig_account_id_address(multisig_addr)
+        .build();
+
+    // Act
+    let ListMultisigTxResponseDissolved { txs, corrupt_tx_ids, .. } =
+        engine.list_multisig_tx(list_tx_request).await.unwrap().dissolve();
+
+    // Assert
+    let listed_tx_ids: Vec<_> = txs.into_iter().map(|tx| tx.dissolve().id).collect();
+
+    assert_eq!(listed_tx_ids, [proposed_tx_ids[2].clone(), proposed_tx_ids[0].clone()]);
+    assert_eq!(corrupt_tx_ids, [corrupt_tx_id]);
+}
+
+#[tokio::test]
+async fn starting_multisig_client_runtime_fails_when_node_is_unreachable() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let engine = MultisigEngine::new(NetworkId::Testnet, store);
+
+    // nothing listens on this port, so the runtime fails to sync with the node
+    let config = MultisigClientRuntimeConfig::builder()
+        .node_url("http://127.0.0.1:1".parse().unwrap())
+        .store_path(temp_dir.join("store"))
+        .keystore_path(temp_dir.join("keystore"))
+        .timeout(Duration::from_secs(10))
+        .shutdown_drain_timeout(Duration::from_secs(10))
+        .build();
+
+    // Act
+    let started =
+        tokio::time::timeout(Duration::from_secs(30), engine.start_multisig_client_runtime(config))
+            .await
+            .expect("startup must fail fast rather than hang");
+
+    // Assert
+    assert!(started.is_err());
+}
+
+#[tokio::test]
+async fn proposal_referencing_more_unknown_notes_than_import_limit_is_rejected() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let (_, alice_account, alice_sk) = setup_regular_account_client(&temp_dir.join("alice")).await;
+
+    let engine =
+        MultisigEngine::new(NetworkId::Testnet, setup_multisig_store(setup_test_db().await).await);
+
+    let config = MultisigClientRuntimeConfig::builder()
+        .node_url("https://rpc.testnet.miden.io:443".parse().unwrap())
+        .store_path(temp_dir.join("multisig").join("store"))
+        .keystore_path(temp_dir.join("multisig").join("keystore"))
+        .timeout(Duration::from_secs(10))
+        .shutdown_drain_timeout(Duration::from_secs(10))
+        .note_import_limit(NonZeroUsize::MIN)
+        .build();
+
+    let engine = engine.start_multisig_client_runtime(config).await.unwrap();
+
+    let alice_addr = AccountIdAddress::new(alice_account.id(), AddressInterface::BasicWallet);
+
+    let create_account_request = CreateMultisigAccountRequest::builder()
+        .threshold(NonZeroU32::MIN)
+        .approvers(vec![alice_addr])
+        .pub_key_commits(vec![alice_sk.public_key()])
+        .build()
+        .unwrap();
+
+    let CreateMultisigAccountResponseDissolved { miden_account: multisig_account, .. } =
+        engine.create_multisig_account(create_account_request).await.unwrap().dissolve();
+
+    // neither note exists, so both are unknown to the runtime
+    let note_ids = vec![
+        NoteId::new(Word::default(), Word::default()),
+        NoteId::new(Word::from([ONE; 4]), Word::default()),
+    ];
+
+    let propose_request = ProposeMultisigTxRequest::builder()
+        .address(AccountIdAddress::new(multisig_account.id(), AddressInterface::BasicWallet))
+        .tx_request(TransactionRequestBuilder::new().build_consume_notes(note_ids).unwrap())
+        .build()
+        .unwrap();
+
+    // Act
+    let err = engine.propose_multisig_tx(propose_request).await.unwrap_err();
+
+    // Assert
+    assert_eq!(err.too_many_unknown_input_notes(), Some((2, NonZeroUsize::MIN)));
+}
+
+#[tokio::test]
+async fn pinging_runtime_succeeds_once_started() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let engine = MultisigEngine::new(NetworkId::Testnet, store);
+
+    // startup only returns once the runtime finished its initial sync
+    let engine = start_offline_multisig_engine(temp_dir, engine).await;
+
+    // Act
+    let ping = engine.ping_runtime().await;
+    let readiness = engine.check_readiness().await;
+
+    // Assert
+    assert!(ping.is_ok());
+    assert!(readiness.is_ok());
+}
+
+#[tokio::test]
+async fn runtime_syncing_periodically_keeps_handling_msgs() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let engine =
+        MultisigEngine::new(NetworkId::Testnet, setup_multisig_store(setup_test_db().await).await);
+
+    let config = MultisigClientRuntimeConfig::builder()
+        .node_url("https://rpc.testnet.miden.io:443".parse().unwrap())
+        .store_path(temp_dir.join("store"))
+        .keystore_path(temp_dir.join("keystore"))
+        .timeout(Duration::from_secs(10))
+        .shutdown_drain_timeout(Duration::from_secs(10))
+        .sync_interval(Duration::from_secs(1))
+        .build();
+
+    let engine = engine.start_multisig_client_runtime(config).await.unwrap();
+
+    // let several periodic syncs run while the runtime is idle
+    tokio::time::sleep(Duration::from_secs(5)).await;
+
+    // Act
+    let ping = tokio::time::timeout(Duration::from_secs(30), engine.ping_runtime())
+        .await
+        .expect("runtime must keep handling msgs between periodic syncs");
+
+    // Assert
+    assert!(ping.is_ok());
+}
+
+#[tokio::test]
+async fn proposals_on_accounts_owned_by_different_runtime_workers_proceed_concurrently() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let (mut ff_client, ff_account) =
+        setup_fungible_faucet_client(&temp_dir.join("ff"), "WRK", 8, 5_000_000).await;
+
+    let (_, alice_account, alice_sk) = setup_regular_account_client(&temp_dir.join("alice")).await;
+
+    let (_, bob_account, bob_sk) = setup_regular_account_client(&temp_dir.join("bob")).await;
+
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let engine = start_multisig_engine_with_workers(
+        &temp_dir.join("multisig"),
+        MultisigEngine::new(NetworkId::Testnet, store),
+        "https://rpc.testnet.miden.io:443",
+        NonZeroUsize::new(2).unwrap(),
+    )
+    .await;
+
+    let alice_addr = AccountIdAddress::new(alice_account.id(), AddressInterface::BasicWallet);
+    let bob_addr = AccountIdAddress::new(bob_account.id(), AddressInterface::BasicWallet);
+
+    // accounts are sharded by id, so create accounts until both workers own one
+    let mut multisig_accounts = [None, None];
+    while multisig_accounts.iter().any(Option::is_none) {
+        let create_account_request = CreateMultisigAccountRequest::builder()
+            .threshold(NonZeroU32::new(2).unwrap())
+            .approvers(vec![alice_addr, bob_addr])
+            .pub_key_commits(vec![alice_sk.public_key(), bob_sk.public_key()])
+            .build()
+            .unwrap();
+
+        let CreateMultisigAccountResponseDissolved { miden_account, .. } =
+            engine.create_multisig_account(create_account_request).await.unwrap().dissolve();
+
+        let worker = engine.runtime_worker_of(miden_account.id());
+        multisig_accounts[worker].get_or_insert(miden_account);
+    }
+
+    let [first_account, second_account] = multisig_accounts.map(Option::unwrap);
+
+    for multisig_account in [&first_account, &second_account] {
+        let asset = FungibleAsset::new(ff_account.id(), 100_000).unwrap();
+
+        let mint_request = TransactionRequestBuilder::new()
+            .build_mint_fungible_asset(
+                asset,
+                multisig_account.id(),
+                NoteType::Public,
+                ff_client.rng(),
+            )
+            .unwrap();
+
+        ff_client.sync_state().await.unwrap();
+        let tx_result = ff_client.new_transaction(ff_account.id(), mint_request).await.unwrap();
+
+        ff_client.submit_transaction(tx_result).await.unwrap();
+    }
+
+    let mut minted_note_ids = Vec::new();
+    let mut propose_requests = Vec::new();
+    for multisig_account in [&first_account, &second_account] {
+        let multisig_addr =
+            AccountIdAddress::new(multisig_account.id(), AddressInterface::BasicWallet);
+
+        let note_ids = wait_for_consumable_note_ids(&engine, Some(multisig_addr)).await;
+
+        minted_note_ids.extend(note_ids.iter().copied());
+
+        let propose_request = ProposeMultisigTxRequest::builder()
+            .address(multisig_addr)
+            .tx_request(TransactionRequestBuilder::new().build_consume_notes(note_ids).unwrap())
+            .build()
+            .unwrap();
+
+        propose_requests.push(propose_request);
+    }
+
+    let [first_request, second_request] = propose_requests.try_into().unwrap();
+
+    let second_addr = AccountIdAddress::new(second_account.id(), AddressInterface::BasicWallet);
+
+    // Act
+    let ((first_proposal, first_proposed_at), (second_listing, second_listed_at)) = tokio::join!(
+        async {
+            let proposal = engine.propose_multisig_tx(first_request).await;
+            (proposal, Instant::now())
+        },
+        async {
+            // queued once the first worker is busy executing the first proposal
+            tokio::time::sleep(Duration::from_millis(200)).await;
+
+            let get_notes_request =
+                GetConsumableNotesRequest::builder().address(second_addr).build();
+
+            let listing = engine.get_consumable_notes(get_notes_request).await;
+            (listing, Instant::now())
+        },
+    );
+
+    let second_proposal = engine.propose_multisig_tx(second_request).await;
+
+    // Assert
+    let ProposeMultisigTxResponseDissolved { tx_summary: first_tx_summary, .. } =
+        first_proposal.unwrap().dissolve();
+    let ProposeMultisigTxResponseDissolved { tx_summary: second_tx_summary, .. } =
+        second_proposal.unwrap().dissolve();
+
+    // a single worker would only have listed the notes after the proposal queued before them
+    assert!(second_listing.is_ok());
+    assert!(second_listed_at < first_proposed_at);
+
+    assert_eq!(first_tx_summary.account_delta().id(), first_account.id());
+    assert_eq!(second_tx_summary.account_delta().id(), second_account.id());
+
+    // both workers answer, and the consumable notes of all accounts are gathered from both
+    engine.ping_runtime().await.unwrap();
+
+    let consumable_note_ids: Vec<_> = engine
+        .get_consumable_notes(GetConsumableNotesRequest::builder().build())
+        .await
+        .unwrap()
+        .dissolve()
+        .notes
+        .into_iter()
+        .map(|(nr, _)| nr.id())
+        .collect();
+
+    assert!(!minted_note_ids.is_empty());
+    assert!(minted_note_ids.iter().all(|note_id| consumable_note_ids.contains(note_id)));
+}
+
+#[test]
+fn create_multisig_account_request_accepts_max_approvers() {
+    // Arrange
+    let (approvers, pub_key_commits) = make_approvers(MAX_APPROVERS);
+
+    // Act
+    let result = CreateMultisigAccountRequest::builder()
+        .threshold(NonZeroU32::new(2).unwrap())
+        .approvers(approvers)
+        .pub_key_commits(pub_key_commits)
+        .build();
+
+    // Assert
+    assert!(result.is_ok());
+}
+
+#[test]
+fn create_multisig_account_request_rejects_more_than_max_approvers() {
+    // Arrange
+    let (approvers, pub_key_commits) = make_approvers(MAX_APPROVERS + 1);
+
+    // Act
+    let result = CreateMultisigAccountRequest::builder()
+        .threshold(NonZeroU32::new(2).unwrap())
+        .approvers(approvers)
+        .pub_key_commits(pub_key_commits)
+        .build();
+
+    // Assert
+    let Err(CreateMultisigAccountRequestError::TooManyApprovers { count }) = result else {
+        panic!("request must be rejected for having too many approvers");
+    };
+
+    assert_eq!(count, MAX_APPROVERS + 1);
+}
+
+#[test]
+fn create_multisig_account_request_rejects_approvers_pub_key_commits_length_mismatch() {
+    // Arrange
+    let (approvers, mut pub_key_commits) = make_approvers(2);
+    pub_key_commits.pop();
+
+    // Act
+    let result = CreateMultisigAccountRequest::builder()
+        .threshold(NonZeroU32::new(1).unwrap())
+        .approvers(approvers)
+        .pub_key_commits(pub_key_commits)
+        .build();
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(CreateMultisigAccountRequestError::ApproversPubKeyCommitsLengthMismatch)
+    ));
+}
+
+#[test]
+fn create_multisig_account_request_rejects_threshold_exceeding_approvers() {
+    // Arrange
+    let (approvers, pub_key_commits) = make_approvers(2);
+
+    // Act
+    let result = CreateMultisigAccountRequest::builder()
+        .threshold(NonZeroU32::new(3).unwrap())
+        .approvers(approvers)
+        .pub_key_commits(pub_key_commits)
+        .build();
+
+    // Assert
+    assert!(matches!(result, Err(CreateMultisigAccountRequestError::ExcessThreshold)));
+}
+
+#[test]
+fn create_multisig_account_request_rejects_duplicate_approvers_and_pub_key_commits() {
+    // Arrange
+    let (approvers, pub_key_commits) = make_approvers(2);
+
+    let duplicate_approvers = vec![approvers[0]; 2];
+    let duplicate_pub_key_commits = vec![pub_key_commits[0]; 2];
+
+    let request = |approvers, pub_key_commits| {
+        CreateMultisigAccountRequest::builder()
+            .threshold(NonZeroU32::MIN)
+            .approvers(approvers)
+            .pub_key_commits(pub_key_commits)
+            .build()
+    };
+
+    // Act
+    let duplicate_approvers_result = request(duplicate_approvers, pub_key_commits.clone());
+    let duplicate_pub_key_commits_result = request(approvers, duplicate_pub_key_commits);
+
+    // Assert
+    assert!(matches!(
+        duplicate_approvers_result,
+        Err(CreateMultisigAccountRequestError::DuplicateApprover)
+    ));
+    assert!(matches!(
+        duplicate_pub_key_commits_result,
+        Err(CreateMultisigAccountRequestError::DuplicatePubKeyCommit)
+    ));
+}
+
+#[test]
+fn create_multisig_account_request_accepts_falcon_and_ecdsa_approver_keys() {
+    // Arrange
+    let approvers = [ACCOUNT_ID_SENDER, ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE]
+        .map(account_id_address);
+
+    let falcon_key = ApproverKey::Falcon(SecretKey::new().public_key());
+    let ecdsa_key =
+        ApproverKey::Ecdsa(EcdsaPubKey::from_bytes(&[0x02; EcdsaPubKey::SERIALIZED_SIZE]).unwrap());
+
+    // Act
+    let request = CreateMultisigAccountRequest::builder()
+        .threshold(NonZeroU32::new(2).unwrap())
+        .approvers(approvers.to_vec())
+        .pub_key_commits(vec![falcon_key, ecdsa_key])
+        .build()
+        .unwrap();
+
+    // Assert
+    let CreateMultisigAccountRequestDissolved { pub_key_commits, .. } = request.dissolve();
+
+    assert_eq!(pub_key_commits, [falcon_key, ecdsa_key]);
+}
+
+#[tokio::test]
+async fn multisig_account_created_with_unspecified_address_interface_keeps_it_in_every_address() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let engine = MultisigEngine::new(NetworkId::Testnet, store)
+        .with_trust_tx_summary(true)
+        .with_auto_process(false);
+
+    let engine = start_offline_multisig_engine(temp_dir, engine).await;
+
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+
+    let create_account_request = CreateMultisigAccountRequest::builder()
+        .threshold(NonZeroU32::MIN)
+        .approvers(vec![alice_addr])
+        .pub_key_commits(vec![SecretKey::new().public_key()])
+        .address_interface(AddressInterface::Unspecified)
+        .build()
+        .unwrap();
+
+    // Act
+    let CreateMultisigAccountResponseDissolved { miden_account, multisig_account: created } =
+        engine.create_multisig_account(create_account_request).await.unwrap().dissolve();
+
+    let multisig_addr = AccountIdAddress::new(miden_account.id(), AddressInterface::Unspecified);
+    let basic_wallet_addr =
+        AccountIdAddress::new(miden_account.id(), AddressInterface::BasicWallet);
+
+    let get_account = async |multisig_account_id_address| {
+        let get_account_request = GetMultisigAccountRequest::builder()
+            .multisig_account_id_address(multisig_account_id_address)
+            .build();
+
+        let GetMultisigAccountFullResponseDissolved { multisig_account } =
+            engine.get_multisig_account_full(get_account_request).await.unwrap().dissolve();
+
+        multisig_account
+    };
+
+    let fetched = get_account(multisig_addr).await.expect("multisig account must exist");
+    let fetched_by_basic_wallet_addr =
+        get_account(basic_wallet_addr).await.expect("multisig account must exist");
+
+    let tx_request = pay_to_id_tx_request(multisig_addr.id(), alice_addr.id());
+
+    let account_delta = AccountDelta::new(
+        multisig_addr.id(),
+        AccountStorageDelta::default(),
+        AccountVaultDelta::default(),
+        ONE,
+    )
+    .unwrap();
+
+    let tx_summary = tx_summary_of(account_delta);
+
+    let propose_request = ProposeMultisigTxRequest::builder()
+        .address(multisig_addr)
+        .tx_request(tx_request)
+        .tx_summary(tx_summary)
+        .build()
+        .unwrap();
+
+    engine.propose_multisig_tx(propose_request).await.unwrap();
+
+    let list_tx_request = ListMultisigTxRequest::builder()
+        .multisig_account_id_address(multisig_addr)
+        .build();
+
+    let ListMultisigTxResponseDissolved { txs, .. } =
+        engine.list_multisig_tx(list_tx_request).await.unwrap().dissolve();
+
+    // Assert
+    assert_eq!(created.address(), multisig_addr);
+    assert_eq!(fetched.address(), multisig_addr);
+    assert_eq!(fetched_by_basic_wallet_addr.address(), multisig_addr);
+
+    let [tx] = txs.try_into().expect("exactly one tx must have been proposed");
+    let MultisigTxDissolved { address: tx_addr, .. } = tx.dissolve();
+
+    assert_eq!(tx_addr, multisig_addr);
+    assert_eq!(
+        to_bech32(NetworkId::Testnet, tx_addr),
+        to_bech32(NetworkId::Testnet, created.address()),
+    );
+}
+
+#[tokio::test]
+async fn approver_is_reported_as_signed_only_after_adding_signature() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+    let bob_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE);
+
+    let alice_sk = SecretKey::new();
+    let bob_sk = SecretKey::new();
+
+    // a threshold of 2 keeps the tx pending after alice signs, so the runtime is never needed
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::new(2).unwrap())
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr, bob_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![
+            ApproverKey::Falcon(alice_sk.public_key()),
+            ApproverKey::Falcon(bob_sk.public_key()),
+        ])
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    let engine = MultisigEngine::new(NetworkId::Testnet, store).with_trust_tx_summary(true);
+    let engine = start_offline_multisig_engine(temp_dir, engine).await;
+
+    let tx_request = pay_to_id_tx_request(multisig_addr.id(), alice_addr.id());
+
+    let account_delta = AccountDelta::new(
+        multisig_addr.id(),
+        AccountStorageDelta::default(),
+        AccountVaultDelta::default(),
+        ONE,
+    )
+    .unwrap();
+
+    let tx_summary = tx_summary_of(account_delta);
+
+    let propose_request = ProposeMultisigTxRequest::builder()
+        .address(multisig_addr)
+        .tx_request(tx_request)
+        .tx_summary(tx_summary.clone())
+        .build()
+        .unwrap();
+
+    let ProposeMultisigTxResponseDissolved { tx_id, .. } =
+        engine.propose_multisig_tx(propose_request).await.unwrap().dissolve();
+
+    let has_signed = async |approver: AccountIdAddress| {
+        let request = HasApproverSignedRequest::builder()
+            .tx_id(tx_id.clone())
+            .approver(approver)
+            .build();
+
+        engine.has_approver_signed(request).await.unwrap().dissolve()
+    };
+
+    // Act
+    let signed_before = has_signed(alice_addr).await;
+
+    let add_sig_request = AddSignatureRequest::builder()
+        .tx_id(tx_id.clone())
+        .approver(alice_addr)
+        .signature(alice_sk.sign(tx_summary.to_commitment()))
+        .build();
+
+    engine.add_signature(add_sig_request).await.unwrap();
+
+    let alice_signed_after = has_signed(alice_addr).await;
+    let bob_signed_after = has_signed(bob_addr).await;
+    let stranger_signed_after =
+        has_signed(account_id_address(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET)).await;
+
+    // Assert
+    assert!(!signed_before);
+    assert!(alice_signed_after);
+    assert!(!bob_signed_after);
+    assert!(!stranger_signed_after);
+}
+
+#[tokio::test]
+async fn adding_signature_of_non_approver_fails_with_approver_not_authorized() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+    let stranger_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE);
+
+    let alice_sk = SecretKey::new();
+    let stranger_sk = SecretKey::new();
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::new(1).unwrap())
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![ApproverKey::Falcon(alice_sk.public_key())])
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    let engine = MultisigEngine::new(NetworkId::Testnet, store).with_trust_tx_summary(true);
+    let engine = start_offline_multisig_engine(temp_dir, engine).await;
+
+    let tx_request = pay_to_id_tx_request(multisig_addr.id(), alice_addr.id());
+
+    let account_delta = AccountDelta::new(
+        multisig_addr.id(),
+        AccountStorageDelta::default(),
+        AccountVaultDelta::default(),
+        ONE,
+    )
+    .unwrap();
+
+    let tx_summary = tx_summary_of(account_delta);
+
+    let propose_request = ProposeMultisigTxRequest::builder()
+        .address(multisig_addr)
+        .tx_request(tx_request)
+        .tx_summary(tx_summary.clone())
+        .build()
+        .unwrap();
+
+    let ProposeMultisigTxResponseDissolved { tx_id, .. } =
+        engine.propose_multisig_tx(propose_request).await.unwrap().dissolve();
+
+    let add_sig_request = AddSignatureRequest::builder()
+        .tx_id(tx_id.clone())
+        .approver(stranger_addr)
+        .signature(stranger_sk.sign(tx_summary.to_commitment()))
+        .build();
+
+    // Act
+    let err = engine.add_signature(add_sig_request).await.unwrap_err();
+
+    // Assert
+    let (approver, rejected_tx_id) = err
+        .approver_not_authorized()
+        .expect("error must report the unauthorized approver");
+
+    assert_eq!(approver, to_bech32(NetworkId::Testnet, stranger_addr));
+    assert_eq!(rejected_tx_id, &tx_id);
+
+    let has_signed_request =
+        HasApproverSignedRequest::builder().tx_id(tx_id).approver(stranger_addr).build();
+
+    let stranger_signed = engine.has_approver_signed(has_signed_request).await.unwrap().dissolve();
+
+    assert!(!stranger_signed);
+}
+
+#[tokio::test]
+async fn adding_signature_to_unknown_multisig_tx_fails_with_multisig_tx_not_found() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let engine = MultisigEngine::new(NetworkId::Testnet, store);
+    let engine = start_offline_multisig_engine(temp_dir, engine).await;
+
+    let approver_addr = account_id_address(ACCOUNT_ID_SENDER);
+
+    let unknown_tx_id: MultisigTxId = "550e8400-e29b-41d4-a716-446655440000".parse().unwrap();
+
+    let add_sig_request = AddSignatureRequest::builder()
+        .tx_id(unknown_tx_id.clone())
+        .approver(approver_addr)
+        .signature(SecretKey::new().sign(Word::default()))
+        .build();
+
+    // Act
+    let err = engine.add_signature(add_sig_request).await.unwrap_err();
+
+    // Assert
+    assert_eq!(err.multisig_tx_not_found(), Some(&unknown_tx_id));
+    assert!(err.approver_not_authorized().is_none());
+}
+
+#[tokio::test]
+async fn accepted_proposal_and_rejected_signature_are_listed_in_audit_log() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+    let stranger_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE);
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::MIN)
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![ApproverKey::Falcon(SecretKey::new().public_key())])
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    let engine = MultisigEngine::new(NetworkId::Testnet, store).with_trust_tx_summary(true);
+    let engine = start_offline_multisig_engine(temp_dir, engine).await;
+
+    let tx_request = pay_to_id_tx_request(multisig_addr.id(), alice_addr.id());
+
+    let account_delta = AccountDelta::new(
+        multisig_addr.id(),
+        AccountStorageDelta::default(),
+        AccountVaultDelta::default(),
+        ONE,
+    )
+    .unwrap();
+
+    let tx_summary = tx_summary_of(account_delta);
+
+    let propose_request = ProposeMultisigTxRequest::builder()
+        .address(multisig_addr)
+        .tx_request(tx_request)
+        .tx_summary(tx_summary.clone())
+        .build()
+        .unwrap();
+
+    let ProposeMultisigTxResponseDissolved { tx_id, .. } =
+        engine.propose_multisig_tx(propose_request).await.unwrap().dissolve();
+
+    let add_sig_request = AddSignatureRequest::builder()
+        .tx_id(tx_id.clone())
+        .approver(stranger_addr)
+        .signature(SecretKey::new().sign(tx_summary.to_commitment()))
+        .build();
+
+    engine.add_signature(add_sig_request).await.unwrap_err();
+
+    // Act
+    let mut audit_events = Vec::new();
+    let mut page_lens = Vec::new();
+    let mut cursor = None;
+
+    loop {
+        let list_request = ListAuditEventsRequest::builder()
+            .multisig_account_id_address(multisig_addr)
+            .maybe_after(cursor)
+            .limit(NonZeroU32::MIN)
+            .build();
+
+        let ListAuditEventsResponseDissolved { audit_events: page, next_cursor } =
+            engine.list_audit_events(list_request).await.unwrap().dissolve();
+
+        page_lens.push(page.len());
+        audit_events.extend(page.into_iter().map(AuditEvent::dissolve));
+
+        let Some(next_cursor) = next_cursor else { break };
+        cursor = Some(next_cursor);
+    }
+
+    // Assert
+    assert_eq!(page_lens, vec![1, 1, 0]);
+
+    let [rejected_signature, accepted_proposal] = audit_events.try_into().unwrap();
+
+    assert_eq!(rejected_signature.action, AuditAction::AddSignature);
+    assert_eq!(rejected_signature.outcome, AuditOutcome::Rejected);
+    assert_eq!(rejected_signature.tx_id, Some(tx_id.clone()));
+    assert_eq!(rejected_signature.actor, Some(stranger_addr));
+    assert!(rejected_signature.detail.is_some());
+
+    assert_eq!(accepted_proposal.action, AuditAction::ProposeTx);
+    assert_eq!(accepted_proposal.outcome, AuditOutcome::Accepted);
+    assert_eq!(accepted_proposal.tx_id, Some(tx_id));
+    assert_eq!(accepted_proposal.address, multisig_addr);
+    assert_eq!(accepted_proposal.detail, None);
+}
+
+#[tokio::test]
+async fn rejected_signatures_and_proposals_are_listed_in_audit_log_with_their_actors() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+    let stranger_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE);
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::MIN)
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![ApproverKey::Falcon(SecretKey::new().public_key())])
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    let engine = MultisigEngine::new(NetworkId::Testnet, store).with_trust_tx_summary(true);
+    let engine = start_offline_multisig_engine(temp_dir, engine).await;
+
+    let tx_request = pay_to_id_tx_request(multisig_addr.id(), alice_addr.id());
+
+    let tx_summary_of = |account_id| {
+        let account_delta = AccountDelta::new(
+            account_id,
+            AccountStorageDelta::default(),
+            AccountVaultDelta::default(),
+            ONE,
+        )
+        .unwrap();
+
+        tx_summary_of(account_delta)
+    };
+
+    let tx_summary = tx_summary_of(multisig_addr.id());
+
+    let propose_request = |tx_summary| {
+        ProposeMultisigTxRequest::builder()
+            .address(multisig_addr)
+            .tx_request(tx_request.clone())
+            .tx_summary(tx_summary)
+            .build()
+            .unwrap()
+    };
+
+    let ProposeMultisigTxResponseDissolved { tx_id, .. } = engine
+        .propose_multisig_tx(propose_request(tx_summary.clone()))
+        .await
+        .unwrap()
+        .dissolve();
+
+    // signed with another key than alice's
+    let signature = ApproverSignature::from(SecretKey::new().sign(tx_summary.to_commitment()));
+
+    let add_sig_request = AddSignatureRequest::builder()
+        .tx_id(tx_id.clone())
+        .approver(alice_addr)
+        .signature(signature.clone())
+        .build();
+
+    let add_sigs_request = AddSignaturesRequest::builder()
+        .tx_id(tx_id.clone())
+        .signatures(vec![(alice_addr, signature.clone()), (stranger_addr, signature)])
+        .build()
+        .unwrap();
+
+    // Act
+    let invalid_signature_err = engine.add_signature(add_sig_request).await.unwrap_err();
+    engine.add_signatures(add_sigs_request).await.unwrap_err();
+
+    engine
+        .propose_multisig_tx(propose_request(tx_summary_of(alice_addr.id())))
+        .await
+        .unwrap_err();
+
+    let list_request = ListAuditEventsRequest::builder()
+        .multisig_account_id_address(multisig_addr)
+        .build();
+
+    let ListAuditEventsResponseDissolved { audit_events, next_cursor } =
+        engine.list_audit_events(list_request).await.unwrap().dissolve();
+
+    // Assert
+    assert!(invalid_signature_err.is_invalid_signature());
+    assert_eq!(next_cursor, None);
+
+    let audit_events: Vec<_> = audit_events
+        .into_iter()
+        .map(AuditEvent::dissolve)
+        .map(|event| (event.action, event.outcome, event.tx_id, event.actor))
+        .collect();
+
+    let rejected_signature = |actor| {
+        (
+            AuditAction::AddSignature,
+            AuditOutcome::Rejected,
+            Some(tx_id.clone()),
+            Some(actor),
+        )
+    };
+
+    let [rejected_proposal, batch @ .., rejected_invalid_signature, accepted_proposal] =
+        audit_events.as_slice()
+    else {
+        panic!("unexpected audit events: {audit_events:?}");
+    };
+
+    assert_eq!(*rejected_proposal, (AuditAction::ProposeTx, AuditOutcome::Rejected, None, None));
+    assert_eq!(batch.len(), 2);
+    assert!(batch.contains(&rejected_signature(alice_addr)));
+    assert!(batch.contains(&rejected_signature(stranger_addr)));
+    assert_eq!(*rejected_invalid_signature, rejected_signature(alice_addr));
+    assert_eq!(
+        *accepted_proposal,
+        (AuditAction::ProposeTx, AuditOutcome::Accepted, Some(tx_id.clone()), None)
+    );
+}
+
+#[tokio::test]
+async fn proposal_beyond_max_pending_proposals_fails_until_one_meets_threshold() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+
+    let alice_sk = SecretKey::new();
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::MIN)
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![ApproverKey::Falcon(alice_sk.public_key())])
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    // with auto processing disabled, meeting the threshold never reaches the runtime
+    let engine = MultisigEngine::new(NetworkId::Testnet, store)
+        .with_trust_tx_summary(true)
+        .with_auto_process(false)
+        .with_max_pending_proposals(NonZeroU32::new(1));
+
+    let engine = start_offline_multisig_engine(temp_dir, engine).await;
+
+    let tx_request = pay_to_id_tx_request(multisig_addr.id(), alice_addr.id());
+
+    let account_delta = AccountDelta::new(
+        multisig_addr.id(),
+        AccountStorageDelta::default(),
+        AccountVaultDelta::default(),
+        ONE,
+    )
+    .unwrap();
+
+    let tx_summary = tx_summary_of(account_delta);
+
+    let propose = async || {
+        let request = ProposeMultisigTxRequest::builder()
+            .address(multisig_addr)
+            .tx_request(tx_request.clone())
+            .tx_summary(tx_summary.clone())
+            .build()
+            .unwrap();
+
+        engine.propose_multisig_tx(request).await
+    };
+
+    let ProposeMultisigTxResponseDissolved { tx_id, .. } = propose().await.unwrap().dissolve();
+
+    // Act
+    let err_at_max = propose().await.unwrap_err();
+
+    let add_sig_request = AddSignatureRequest::builder()
+        .tx_id(tx_id)
+        .approver(alice_addr)
+        .signature(alice_sk.sign(tx_summary.to_commitment()))
+        .build();
+
+    engine.add_signature(add_sig_request).await.unwrap();
+
+    let result_below_max = propose().await;
+
+    // Assert
+    assert_eq!(err_at_max.too_many_pending_proposals(), NonZeroU32::new(1));
+    assert!(result_below_max.is_ok());
+}
+
+#[tokio::test]
+async fn proposal_is_signed_up_to_threshold_against_in_memory_store_without_database() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    // clones share their state, so the store is read back from outside the engine
+    let store = InMemoryMultisigStore::new();
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+    let bob_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE);
+
+    let alice_sk = SecretKey::new();
+    let bob_sk = SecretKey::new();
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::new(2).unwrap())
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr, bob_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![
+            ApproverKey::Falcon(alice_sk.public_key()),
+            ApproverKey::Falcon(bob_sk.public_key()),
+        ])
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    // with auto processing disabled, meeting the threshold never reaches the runtime
+    let engine = MultisigEngine::new(NetworkId::Testnet, store.clone())
+        .with_trust_tx_summary(true)
+        .with_auto_process(false);
+
+    let engine = start_offline_multisig_engine(temp_dir, engine).await;
+
+    let tx_request = pay_to_id_tx_request(multisig_addr.id(), alice_addr.id());
+
+    let account_delta = AccountDelta::new(
+        multisig_addr.id(),
+        AccountStorageDelta::default(),
+        AccountVaultDelta::default(),
+        ONE,
+    )
+    .unwrap();
+
+    let tx_summary = tx_summary_of(account_delta);
+
+    let tx_status = async |tx_id: &MultisigTxId| {
+        let MultisigTxDissolved { status, .. } =
+            store.get_multisig_tx_by_id(tx_id).await.unwrap().unwrap().dissolve();
+
+        status
+    };
+
+    // Act
+    let propose_request = ProposeMultisigTxRequest::builder()
+        .address(multisig_addr)
+        .tx_request(tx_request)
+        .tx_summary(tx_summary.clone())
+        .build()
+        .unwrap();
+
+    let ProposeMultisigTxResponseDissolved { tx_id, .. } =
+        engine.propose_multisig_tx(propose_request).await.unwrap().dissolve();
+
+    let status_after_proposal = tx_status(&tx_id).await;
+
+    for (approver, sk) in [(alice_addr, &alice_sk), (bob_addr, &bob_sk)] {
+        let add_sig_request = AddSignatureRequest::builder()
+            .tx_id(tx_id.clone())
+            .approver(approver)
+            .signature(sk.sign(tx_summary.to_commitment()))
+            .build();
+
+        engine.add_signature(add_sig_request).await.unwrap();
+    }
+
+    let status_after_signatures = tx_status(&tx_id).await;
+
+    // Assert
+    assert!(matches!(status_after_proposal, MultisigTxStatus::Pending));
+    assert!(matches!(status_after_signatures, MultisigTxStatus::ReadyToExecute));
+}
+
+#[tokio::test]
+async fn reproposing_pending_or_unknown_multisig_tx_fails_with_typed_errors() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let store = InMemoryMultisigStore::new();
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::MIN)
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![ApproverKey::Falcon(SecretKey::new().public_key())])
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    let engine = MultisigEngine::new(NetworkId::Testnet, store).with_trust_tx_summary(true);
+    let engine = start_offline_multisig_engine(temp_dir, engine).await;
+
+    let tx_request = pay_to_id_tx_request(multisig_addr.id(), alice_addr.id());
+
+    let account_delta = AccountDelta::new(
+        multisig_addr.id(),
+        AccountStorageDelta::default(),
+        AccountVaultDelta::default(),
+        ONE,
+    )
+    .unwrap();
+
+    let tx_summary = tx_summary_of(account_delta);
+
+    let propose_request = ProposeMultisigTxRequest::builder()
+        .address(multisig_addr)
+        .tx_request(tx_request)
+        .tx_summary(tx_summary)
+        .build()
+        .unwrap();
+
+    let ProposeMultisigTxResponseDissolved { tx_id, .. } =
+        engine.propose_multisig_tx(propose_request).await.unwrap().dissolve();
+
+    let unknown_tx_id: MultisigTxId = "550e8400-e29b-41d4-a716-446655440000".parse().unwrap();
+
+    // Act
+    let repropose_request = ReproposeMultisigTxRequest::builder().tx_id(tx_id).build();
+    let pending_err = engine.repropose_multisig_tx(repropose_request).await.unwrap_err();
+
+    let repropose_request =
+        ReproposeMultisigTxRequest::builder().tx_id(unknown_tx_id.clone()).build();
+    let unknown_err = engine.repropose_multisig_tx(repropose_request).await.unwrap_err();
+
+    // Assert
+    assert_eq!(pending_err.multisig_tx_not_pending(), Some(MultisigTxStatus::Pending));
+    assert_eq!(unknown_err.multisig_tx_not_found(), Some(&unknown_tx_id));
+}
+
+#[tokio::test]
+async fn proposing_trusted_tx_summary_of_another_account_fails_with_tx_summary_mismatch() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let store = InMemoryMultisigStore::new();
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::MIN)
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![ApproverKey::Falcon(SecretKey::new().public_key())])
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    let engine = MultisigEngine::new(NetworkId::Testnet, store).with_trust_tx_summary(true);
+    let engine = start_offline_multisig_engine(temp_dir, engine).await;
+
+    let tx_request = pay_to_id_tx_request(multisig_addr.id(), alice_addr.id());
+
+    // the summary applies to the approver rather than to the multisig account
+    let account_delta = AccountDelta::new(
+        alice_addr.id(),
+        AccountStorageDelta::default(),
+        AccountVaultDelta::default(),
+        ONE,
+    )
+    .unwrap();
+
+    let tx_summary = tx_summary_of(account_delta);
+
+    let propose_request = ProposeMultisigTxRequest::builder()
+        .address(multisig_addr)
+        .tx_request(tx_request)
+        .tx_summary(tx_summary)
+        .build()
+        .unwrap();
+
+    // Act
+    let err = engine.propose_multisig_tx(propose_request).await.unwrap_err();
+
+    // Assert
+    assert!(err.is_tx_summary_mismatch());
+}
+
+#[tokio::test]
+async fn proposing_payment_for_unknown_account_fails_with_multisig_account_not_found() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let engine = MultisigEngine::new(NetworkId::Testnet, InMemoryMultisigStore::new());
+    let engine = start_offline_multisig_engine(temp_dir, engine).await;
+
+    let unknown_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+
+    let payment =
+        FungibleAsset::new(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET.try_into().unwrap(), 100).unwrap();
+
+    let propose_request = ProposePaymentRequest::builder()
+        .address(unknown_addr)
+        .recipient(account_id_address(ACCOUNT_ID_SENDER))
+        .asset(payment.into())
+        .note_type(NoteType::Public)
+        .build()
+        .unwrap();
+
+    // Act
+    let err = engine.propose_payment(propose_request).await.unwrap_err();
+
+    // Assert
+    assert_eq!(err.multisig_account_not_found(), Some(unknown_addr.id()));
+}
+
+#[tokio::test]
+async fn full_multisig_account_pairs_approvers_with_their_pub_key_commits_in_approver_order() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let store = InMemoryMultisigStore::new();
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+    let bob_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE);
+
+    let alice_key = ApproverKey::Falcon(SecretKey::new().public_key());
+    let bob_key = ApproverKey::Falcon(SecretKey::new().public_key());
+
+    // bob comes first, so that the approver order can't be mistaken for the id order
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::new(2).unwrap())
+        .aux(())
+        .build()
+        .with_approvers(vec![bob_addr, alice_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![bob_key, alice_key])
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    let engine = MultisigEngine::new(NetworkId::Testnet, store);
+    let engine = start_offline_multisig_engine(temp_dir, engine).await;
+
+    let get_request = GetMultisigAccountRequest::builder()
+        .multisig_account_id_address(multisig_addr)
+        .build();
+
+    // Act
+    let GetMultisigAccountFullResponseDissolved { multisig_account } =
+        engine.get_multisig_account_full(get_request).await.unwrap().dissolve();
+
+    // Assert
+    let multisig_account = multisig_account.expect("multisig account must exist");
+
+    assert_eq!(multisig_account.approvers(), [bob_addr, alice_addr]);
+    assert_eq!(multisig_account.pub_key_commits(), [bob_key, alice_key]);
+}
+
+#[tokio::test]
+async fn test_multisig_accounts_set_up_by_different_clients_share_their_id() {
+    // Arrange
+    let approvers: Vec<_> = (0..2).map(|_| SecretKey::new().public_key()).collect();
+
+    let mut account_ids = Vec::new();
+
+    // Act
+    for _ in 0..2 {
+        let temp_dir = TempDir::new().expect("failed to create temporary directory");
+        let (mut client, ..) = create_test_multisig_client(temp_dir.path()).await;
+
+        let account = setup_test_multisig_account(&mut client, approvers.clone(), 2).await;
+
+        account_ids.push(account.id());
+    }
+
+    // Assert
+    assert_eq!(account_ids[0], account_ids[1]);
+}
+
+#[tokio::test]
+async fn approver_order_lists_approvers_by_index() {
+    // Arrange
+    let temp_dir = TempDir::new().expect("failed to create temporary directory");
+    let temp_dir = temp_dir.path();
+
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let unknown_addr = account_id_address(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+    let bob_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE);
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::new(2).unwrap())
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr, bob_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![
+            ApproverKey::Falcon(SecretKey::new().public_key()),
+            ApproverKey::Falcon(SecretKey::new().public_key()),
+        ])
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    let engine = MultisigEngine::new(NetworkId::Testnet, store);
+    let engine = start_offline_multisig_engine(temp_dir, engine).await;
+
+    let approver_order = async |multisig_account_id_address| {
+        let request = GetMultisigAccountRequest::builder()
+            .multisig_account_id_address(multisig_account_id_address)
+            .build();
+
+        let GetMultisigApproverOrderResponseDissolved { approvers } =
+            engine.get_multisig_approver_order(request).await.unwrap().dissolve();
+
+        approvers.map(|approvers| {
+            approvers
+                .into_iter()
+                .map(|(index, approver)| {
+                    let MultisigApproverDissolved { address, .. } = approver.dissolve();
+                    (index, address)
+                })
+                .collect::<Vec<_>>()
+        })
+    };
+
+    // Act
+    let known = approver_order(multisig_addr).await;
+    let unknown = approver_order(unknown_addr).await;
+
+    // Assert
+    assert_eq!(known, Some(vec![(0, alice_addr), (1, bob_addr)]));
+    assert_eq!(unknown, None);
+}
+
+#[tokio::test]
+async fn proposal_memo_round_trips_through_store_and_overlong_memo_is_rejected() {
+    // Arrange
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::MIN)
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![ApproverKey::Falcon(SecretKey::new().public_key())])
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    let tx_request = pay_to_id_tx_request(multisig_addr.id(), alice_addr.id());
+
+    let tx_summary = empty_tx_summary(multisig_addr.id());
+
+    let memo = "Q3 vendor payment";
+
+    // Act
+    let overlong_memo_result = ProposeMultisigTxRequest::builder()
+        .address(multisig_addr)
+        .tx_request(tx_request.clone())
+        .memo("m".repeat(ProposeMultisigTxRequest::MAX_MEMO_LEN + 1))
+        .build();
+
+    let tx_id = store
+        .create_multisig_tx(
+            NetworkId::Testnet,
+            multisig_addr,
+            &tx_request,
+            &tx_summary,
+            None,
+            Some(memo),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let fetched_memo = store.get_multisig_tx_by_id(&tx_id).await.unwrap().unwrap().dissolve().memo;
+
+    let (listed_txs, _) = store
+        .get_txs_by_multisig_account_address_with_status_filter(
+            NetworkId::Testnet,
+            multisig_addr,
+            None::<MultisigTxStatus>,
+            TxSort::default(),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    assert!(overlong_memo_result.is_err());
+
+    assert_eq!(fetched_memo.as_deref(), Some(memo));
+
+    let listed_memos: Vec<_> = listed_txs
+        .into_iter()
+        .map(|(listed_tx_id, listed_tx)| (listed_tx_id, listed_tx.unwrap().dissolve().memo))
+        .collect();
+
+    assert_eq!(listed_memos, [(tx_id, Some(memo.to_owned()))]);
+}
+
+/// An [`EventSink`] collecting the emitted events in memory.
+#[derive(Default)]
+struct InMemoryEventSink(Mutex<Vec<CoordinatorEvent>>);
+
+#[async_trait::async_trait]
+impl EventSink for InMemoryEventSink {
+    async fn emit(&self, event: CoordinatorEvent) {
+        self.0.lock().unwrap().push(event);
+    }
+}
+
+/// How long a condition on the testnet is polled for before the test gives up on it.
+const TESTNET_POLL_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Polls `probe` every second until it returns a value, panicking with `what` it waited for
+/// once [`TESTNET_POLL_TIMEOUT`] elapsed.
+async fn poll_until<T>(what: &str, mut probe: impl AsyncFnMut() -> Option<T>) -> T {
+    let deadline = Instant::now() + TESTNET_POLL_TIMEOUT;
+
+    loop {
+        if let Some(value) = probe().await {
+            return value;
+        }
+
+        assert!(Instant::now() < deadline, "timed out waiting for {what}");
+
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+}
+
+/// Waits for the engine to list consumable notes, e.g. once the notes minted to a multisig
+/// account are committed, and returns their ids.
+async fn wait_for_consumable_note_ids(
+    engine: &MultisigEngine<Started>,
+    address: Option<AccountIdAddress>,
+) -> Vec<NoteId> {
+    poll_until("consumable notes", async || {
+        let request = GetConsumableNotesRequest::builder().maybe_address(address).build();
+
+        let note_ids: Vec<_> = engine
+            .get_consumable_notes(request)
+            .await
+            .unwrap()
+            .dissolve()
+            .notes
+            .into_iter()
+            .map(|(nr, _)| nr.id())
+            .collect();
+
+        (!note_ids.is_empty()).then_some(note_ids)
+    })
+    .await
+}
+
+/// Waits for the account of an executed tx to be committed on chain at the nonce the tx left it
+/// at.
+async fn wait_for_tx_commit(tx_result: &TransactionResult) {
+    let executed_tx = tx_result.executed_transaction();
+    let account_id = executed_tx.account_id();
+    let nonce = executed_tx.final_account().nonce().as_int();
+
+    let rpc_client = TonicRpcClient::new(&Endpoint::testnet(), 10_000);
+
+    poll_until("tx commit", async || match rpc_client.get_account_details(account_id).await {
+        Ok(FetchedAccount::Public(account, _)) => (account.nonce().as_int() >= nonce).then_some(()),
+        _ => None,
+    })
+    .await;
+}
+
+async fn setup_fungible_faucet_client(
+    temp_dir: &Path,
+    symbol: &str,
+    decimals: u8,
+    max_supply: u64,
+) -> (Client<FilesystemKeyStore<StdRng>>, Account) {
+    let (mut client, keystore) = setup_testnet_client(temp_dir).await;
+
+    let mut init_seed = [0u8; 32];
+    client.rng().fill_bytes(&mut init_seed);
+
+    let symbol = TokenSymbol::new(symbol).unwrap();
+    let max_supply = Felt::new(max_supply);
+
+    let sk = SecretKey::with_rng(client.rng());
+
+    let (account, seed) = AccountBuilder::new(init_seed)
+        .account_type(AccountType::FungibleFaucet)
+        .storage_mode(miden_client::account::AccountStorageMode::Public)
+        .with_auth_component(AuthRpoFalcon512::new(sk.public_key()))
+        .with_component(BasicFungibleFaucet::new(symbol, decimals, max_supply).unwrap())
+        .build()
+        .unwrap();
+
+    client.add_account(&account, Some(seed), false).await.unwrap();
+    keystore.add_key(&AuthSecretKey::RpoFalcon512(sk)).unwrap();
+
+    (client, account)
+}
+
+async fn setup_regular_account_client(
+    temp_dir: &Path,
+) -> (Client<FilesystemKeyStore<StdRng>>, Account, SecretKey) {
+    let (mut client, keystore) = setup_testnet_client(temp_dir).await;
+
+    let mut init_seed = [0u8; 32];
+    client.rng().fill_bytes(&mut init_seed);
+
+    let sk = SecretKey::with_rng(client.rng());
+
+    let (account, seed) = AccountBuilder::new(init_seed)
+        .account_type(AccountType::RegularAccountUpdatableCode)
+        .storage_mode(AccountStorageMode::Public)
+        .with_auth_component(AuthRpoFalcon512::new(sk.public_key()))
+        .with_component(BasicWallet)
+        .build()
+        .unwrap();
+
+    client.add_account(&account, Some(seed), false).await.unwrap();
+    keystore.add_key(&AuthSecretKey::RpoFalcon512(sk.clone())).unwrap();
+
+    (client, account, sk)
+}
+
+async fn setup_testnet_client(
+    temp_dir: &Path,
+) -> (Client<FilesystemKeyStore<StdRng>>, FilesystemKeyStore<StdRng>) {
+    let keystore =
+        FilesystemKeyStore::new(temp_dir.join("keystore")).expect("failed to initialize keystore");
+
+    let client = ClientBuilder::new()
+        .rpc(Arc::new(TonicRpcClient::new(&Endpoint::testnet(), 10_000)))
+        .sqlite_store(temp_dir.join("store").as_os_str().to_str().unwrap())
+        .authenticator(keystore.clone().into())
+        .in_debug_mode(DebugMode::Enabled)
+        .build()
+        .await
+        .expect("failed to build miden client");
+
+    (client, keystore)
+}
+
+async fn start_testnet_multisig_engine(temp_dir: &Path) -> MultisigEngine<Started> {
+    start_testnet_multisig_engine_with_db_url(temp_dir, setup_test_db().await).await
+}
+
+async fn start_testnet_multisig_engine_with_db_url(
+    temp_dir: &Path,
+    db_url: String,
+) -> MultisigEngine<Started> {
+    start_testnet_multisig_engine_with_auto_process(temp_dir, db_url, true).await
+}
+
+async fn start_testnet_multisig_engine_with_auto_process(
+    temp_dir: &Path,
+    db_url: String,
+    auto_process: bool,
+) -> MultisigEngine<Started> {
+    let engine = MultisigEngine::new(NetworkId::Testnet, setup_multisig_store(db_url).await)
+        .with_auto_process(auto_process);
+
+    start_multisig_engine(temp_dir, engine, "https://rpc.testnet.miden.io:443").await
+}
+
+async fn start_multisig_engine(
+    temp_dir: &Path,
+    engine: MultisigEngine<Stopped>,
+    node_url: &str,
+) -> MultisigEngine<Started> {
+    start_multisig_engine_with_workers(temp_dir, engine, node_url, NonZeroUsize::MIN).await
+}
+
+async fn start_multisig_engine_with_workers(
+    temp_dir: &Path,
+    engine: MultisigEngine<Stopped>,
+    node_url: &str,
+    workers: NonZeroUsize,
+) -> MultisigEngine<Started> {
+    let config = MultisigClientRuntimeConfig::builder()
+        .node_url(node_url.parse().unwrap())
+        .store_path(temp_dir.join("store"))
+        .keystore_path(temp_dir.join("keystore"))
+        .timeout(Duration::from_secs(10))
+        .shutdown_drain_timeout(Duration::from_secs(10))
+        .workers(workers)
+        .build();
+
+    engine.start_multisig_client_runtime(config).await.unwrap()
+}
+
+/// Starts the engine against a mock node, so that it runs offline.
+async fn start_offline_multisig_engine(
+    temp_dir: &Path,
+    engine: MultisigEngine<Stopped>,
+) -> MultisigEngine<Started> {
+    // the node url is never connected to, the mock node serving every request instead
+    let config = MultisigClientRuntimeConfig::builder()
+        .node_url("http://localhost".parse().unwrap())
+        .store_path(temp_dir.join("store"))
+        .keystore_path(temp_dir.join("keystore"))
+        .timeout(Duration::from_secs(10))
+        .shutdown_drain_timeout(Duration::from_secs(10))
+        .rpc_client(SharedNodeRpcClient::new(create_mock_rpc_api().await))
+        .build();
 
-    test_db_url
+    engine.start_multisig_client_runtime(config).await.unwrap()
 }
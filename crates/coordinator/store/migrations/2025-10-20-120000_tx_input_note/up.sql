@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS tx_input_note (
+    tx_id UUID NOT NULL REFERENCES tx(id) ON DELETE CASCADE,
+
+    -- position of the note in the tx request's input notes
+    note_index BIGINT NOT NULL CHECK (note_index >= 0),
+
+    note_id BYTEA NOT NULL,
+
+    PRIMARY KEY (tx_id, note_index),
+    UNIQUE (tx_id, note_id)
+);
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+
+ALTER TABLE tx
+    DROP COLUMN IF EXISTS memo,
+    DROP COLUMN IF EXISTS title;
@@ -0,0 +1,3 @@
+ALTER TABLE tx
+    ADD COLUMN title TEXT CHECK (char_length(title) <= 128),
+    ADD COLUMN memo TEXT CHECK (char_length(memo) <= 1024);
@@ -0,0 +1,2 @@
+-- reason the tx failed to be processed, only set when status is 'failure'
+ALTER TABLE tx ADD COLUMN failure_reason TEXT;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+
+ALTER TABLE approver DROP COLUMN IF EXISTS scheme;
+DROP TYPE IF EXISTS approver_key_scheme;
@@ -0,0 +1,4 @@
+-- signature scheme of the approver public key, existing approvers are all falcon
+CREATE TYPE approver_key_scheme AS ENUM ('falcon', 'ecdsa');
+
+ALTER TABLE approver ADD COLUMN scheme approver_key_scheme NOT NULL DEFAULT 'falcon';
@@ -0,0 +1,3 @@
+-- vote weight of the approver within the multisig account, existing approvers all weigh 1
+ALTER TABLE multisig_account_approver_mapping
+    ADD COLUMN weight INTEGER NOT NULL DEFAULT 1 CHECK (weight >= 1);
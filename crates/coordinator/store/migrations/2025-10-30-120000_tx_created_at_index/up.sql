@@ -0,0 +1,3 @@
+-- backs the keyset pagination of the transactions of a multisig account, most recent first
+CREATE INDEX IF NOT EXISTS tx_multisig_account_address_created_at_id_idx
+    ON tx (multisig_account_address, created_at DESC, id DESC);
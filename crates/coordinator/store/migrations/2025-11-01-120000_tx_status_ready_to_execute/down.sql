@@ -0,0 +1,16 @@
+-- This file should undo anything in `up.sql`
+
+-- postgres can't drop a value from an enum type, so the type is recreated without it
+UPDATE tx SET status = 'pending' WHERE status = 'ready_to_execute';
+
+ALTER TABLE tx ALTER COLUMN status DROP DEFAULT;
+
+ALTER TYPE tx_status RENAME TO tx_status_old;
+
+CREATE TYPE tx_status AS ENUM ('pending', 'success', 'failure');
+
+ALTER TABLE tx ALTER COLUMN status TYPE tx_status USING status::text::tx_status;
+
+ALTER TABLE tx ALTER COLUMN status SET DEFAULT 'pending';
+
+DROP TYPE tx_status_old;
@@ -0,0 +1,2 @@
+-- transactions that met their threshold while automatic processing is disabled
+ALTER TYPE tx_status ADD VALUE IF NOT EXISTS 'ready_to_execute' AFTER 'pending';
@@ -0,0 +1,2 @@
+-- effect of the tx decoded from its summary, null for txs proposed before it was persisted
+ALTER TABLE tx ADD COLUMN tx_effect BYTEA;
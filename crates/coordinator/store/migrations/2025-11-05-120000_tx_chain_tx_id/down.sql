@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+
+ALTER TABLE tx DROP COLUMN IF EXISTS submission_block_num;
+ALTER TABLE tx DROP COLUMN IF EXISTS chain_tx_id;
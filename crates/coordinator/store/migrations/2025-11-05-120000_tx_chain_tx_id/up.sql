@@ -0,0 +1,4 @@
+-- id of the executed tx on chain and the block it was executed against, only set when status is
+-- 'success'
+ALTER TABLE tx ADD COLUMN chain_tx_id BYTEA;
+ALTER TABLE tx ADD COLUMN submission_block_num BIGINT;
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+
+ALTER TABLE multisig_account DROP COLUMN IF EXISTS archived_at;
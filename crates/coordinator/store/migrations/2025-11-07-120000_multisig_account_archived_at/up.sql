@@ -0,0 +1,2 @@
+-- when the multisig account was archived, archived accounts are hidden from default listings
+ALTER TABLE multisig_account ADD COLUMN archived_at TIMESTAMPTZ;
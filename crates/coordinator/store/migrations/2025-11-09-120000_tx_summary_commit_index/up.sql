@@ -0,0 +1,2 @@
+-- backs the lookup of transactions by the commitment of their summary, i.e. what approvers sign
+CREATE INDEX IF NOT EXISTS tx_tx_summary_commit_idx ON tx (tx_summary_commit);
@@ -0,0 +1,3 @@
+-- the on-chain nonce of the multisig account as last seen by the coordinator, to detect drift
+-- NULL if unknown, i.e. for accounts stored before the nonce was tracked
+ALTER TABLE multisig_account ADD COLUMN nonce INT8;
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+
+ALTER TABLE tx DROP COLUMN IF EXISTS updated_at;
@@ -0,0 +1,4 @@
+-- when the transaction last changed, i.e. was signed or changed status, existing transactions are
+-- considered unchanged since they were proposed
+ALTER TABLE tx ADD COLUMN updated_at TIMESTAMPTZ NOT NULL DEFAULT now();
+UPDATE tx SET updated_at = created_at;
@@ -0,0 +1,3 @@
+-- optional human-readable label of the approver, e.g. "Alice - CFO", purely coordinator metadata.
+-- The label is given per multisig account, so that accounts sharing an approver label it apart
+ALTER TABLE multisig_account_approver_mapping ADD COLUMN label TEXT;
@@ -0,0 +1,33 @@
+-- when the weight of the signatures collected on the transaction first met the threshold, existing
+-- executed transactions are considered to have met it with the signature that brought their signed
+-- weight up to the threshold, signatures collected beyond it being left out
+ALTER TABLE tx ADD COLUMN threshold_met_at TIMESTAMPTZ;
+UPDATE tx
+SET threshold_met_at = met.created_at
+FROM (
+    SELECT DISTINCT ON (tx_id) tx_id, created_at
+    FROM (
+        SELECT
+            signature.tx_id,
+            signature.created_at,
+            multisig_account.threshold,
+            sum(mapping.weight) OVER (
+                PARTITION BY signature.tx_id
+                ORDER BY signature.created_at, signature.approver_address
+            ) AS signed_weight
+        FROM signature
+        JOIN tx ON tx.id = signature.tx_id
+        JOIN multisig_account ON multisig_account.address = tx.multisig_account_address
+        JOIN multisig_account_approver_mapping AS mapping
+            ON mapping.multisig_account_address = tx.multisig_account_address
+            AND mapping.approver_address = signature.approver_address
+    ) AS signed
+    WHERE signed_weight >= threshold
+    ORDER BY tx_id, created_at, signed_weight
+) AS met
+WHERE tx.id = met.tx_id AND tx.status = 'success';
+-- a transaction whose signatures no longer meet the threshold, e.g. as it was raised since, is
+-- considered to have met it with its last signature
+UPDATE tx
+SET threshold_met_at = (SELECT max(signature.created_at) FROM signature WHERE signature.tx_id = tx.id)
+WHERE status = 'success' AND threshold_met_at IS NULL;
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+
+DROP TABLE IF EXISTS audit_log CASCADE;
+DROP TYPE IF EXISTS audit_action;
+DROP TYPE IF EXISTS audit_outcome;
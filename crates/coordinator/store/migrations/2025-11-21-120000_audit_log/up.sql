@@ -0,0 +1,26 @@
+-- durable record of notable accepted and rejected operations on multisig accounts, e.g. signatures
+-- of non-approvers, for security reviews
+CREATE TYPE audit_action AS ENUM ('propose_tx', 'repropose_tx', 'add_signature', 'execute_tx');
+CREATE TYPE audit_outcome AS ENUM ('accepted', 'rejected');
+
+CREATE TABLE IF NOT EXISTS audit_log (
+    id UUID DEFAULT gen_random_uuid() PRIMARY KEY,
+
+    -- bech32 account address
+    multisig_account_address TEXT NOT NULL REFERENCES multisig_account(address) ON DELETE CASCADE,
+
+    -- not referencing the tx, so that events outlive it
+    tx_id UUID,
+
+    -- bech32 account address of the approver, if known
+    actor TEXT,
+
+    action audit_action NOT NULL,
+    outcome audit_outcome NOT NULL,
+    detail TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- backs the keyset pagination of the audit events of a multisig account, most recent first
+CREATE INDEX IF NOT EXISTS audit_log_multisig_account_address_created_at_id_idx
+    ON audit_log (multisig_account_address, created_at DESC, id DESC);
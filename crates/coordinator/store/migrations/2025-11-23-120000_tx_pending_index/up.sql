@@ -0,0 +1,3 @@
+-- backs the count of the pending transactions of a multisig account, bounded by the engine
+CREATE INDEX IF NOT EXISTS tx_multisig_account_address_pending_idx
+    ON tx (multisig_account_address) WHERE status = 'pending';
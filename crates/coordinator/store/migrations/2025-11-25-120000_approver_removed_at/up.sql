@@ -0,0 +1,3 @@
+-- when the approver was removed from the multisig account, removed approvers keep their approver
+-- index as the on-chain configuration of the account still lists their public key at it
+ALTER TABLE multisig_account_approver_mapping ADD COLUMN removed_at TIMESTAMPTZ;
@@ -0,0 +1,2 @@
+-- transactions claimed by an execution, so that concurrent executions don't submit them twice
+ALTER TYPE tx_status ADD VALUE IF NOT EXISTS 'executing' AFTER 'ready_to_execute';
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+
+ALTER TABLE multisig_account DROP COLUMN IF EXISTS interface;
+DROP TYPE IF EXISTS address_interface;
@@ -0,0 +1,7 @@
+-- interface of the multisig account address, existing accounts all have the basic wallet one
+--
+-- the account is keyed by the address of its id with the basic wallet interface whatever its
+-- interface, so that it is found by its id whichever interface it is addressed with
+CREATE TYPE address_interface AS ENUM ('unspecified', 'basic_wallet');
+
+ALTER TABLE multisig_account ADD COLUMN interface address_interface NOT NULL DEFAULT 'basic_wallet';
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+
+DROP TRIGGER IF EXISTS tx_signed_commitment_immutable ON tx;
+DROP FUNCTION IF EXISTS tx_signed_commitment_immutable();
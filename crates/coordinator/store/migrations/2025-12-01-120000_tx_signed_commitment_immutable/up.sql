@@ -0,0 +1,19 @@
+-- signatures are made over the summary commitment of the transaction, so the commitment of a
+-- transaction holding signatures never changes, whichever statement writes it; its signatures
+-- are to be deleted first, e.g. when a failed transaction is re-proposed with a fresh summary
+CREATE FUNCTION tx_signed_commitment_immutable() RETURNS trigger AS $$
+BEGIN
+    IF EXISTS (SELECT 1 FROM signature WHERE signature.tx_id = OLD.id) THEN
+        RAISE EXCEPTION 'the summary commitment of tx % holds signatures', OLD.id
+            USING ERRCODE = 'check_violation', CONSTRAINT = 'tx_signed_commitment_immutable';
+    END IF;
+
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER tx_signed_commitment_immutable
+BEFORE UPDATE OF tx_summary_commit ON tx
+FOR EACH ROW
+WHEN (NEW.tx_summary_commit IS DISTINCT FROM OLD.tx_summary_commit)
+EXECUTE FUNCTION tx_signed_commitment_immutable();
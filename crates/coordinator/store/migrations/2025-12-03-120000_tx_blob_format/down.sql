@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+
+UPDATE tx SET tx_request = substring(tx_request FROM 2)
+WHERE substring(tx_request FROM 1 FOR 1) = '\x00'::bytea;
+
+UPDATE tx SET tx_summary = substring(tx_summary FROM 2)
+WHERE substring(tx_summary FROM 1 FOR 1) = '\x00'::bytea;
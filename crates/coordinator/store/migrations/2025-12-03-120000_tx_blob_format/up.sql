@@ -0,0 +1,9 @@
+-- every tx blob is prefixed with a format byte, 0 for raw and 1 for zstd compressed blobs
+--
+-- compressed blobs already carry their format byte followed by the zstd frame magic number, raw
+-- blobs written before the format byte was introduced are prefixed with it
+UPDATE tx SET tx_request = '\x00'::bytea || tx_request
+WHERE substring(tx_request FROM 1 FOR 5) <> '\x0128b52ffd'::bytea;
+
+UPDATE tx SET tx_summary = '\x00'::bytea || tx_summary
+WHERE substring(tx_summary FROM 1 FOR 5) <> '\x0128b52ffd'::bytea;
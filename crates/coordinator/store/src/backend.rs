@@ -0,0 +1,721 @@
+//! The persistence operations the multisig engine is built on, abstracted over their backend.
+
+use core::num::NonZeroU32;
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use futures::stream::BoxStream;
+use miden_client::{
+    Word,
+    account::{AccountIdAddress, NetworkId},
+    transaction::TransactionRequest,
+};
+use miden_multisig_coordinator_domain::{
+    account::{MultisigAccount, MultisigApprover, WithApprovers, WithPubKeyCommits},
+    audit::{AuditAction, AuditEvent, AuditOutcome, AuditSubject},
+    cursor::Cursor,
+    key::ApproverSignature,
+    tx::{MultisigTx, MultisigTxId, MultisigTxStats, MultisigTxStatus, TrendGranularity, TxSort},
+};
+use miden_objects::{
+    block::BlockNumber,
+    crypto::dsa::rpo_falcon512::PublicKey,
+    transaction::{TransactionId, TransactionSummary},
+};
+
+use crate::{MultisigStore, error::Result};
+
+/// The persistence operations of the multisig coordinator, as relied upon by the multisig engine.
+///
+/// [`MultisigStore`] implements them on PostgreSQL. Other implementations, e.g. an in-memory one
+/// in tests, let the engine run without a database. Every operation is documented on the
+/// [`MultisigStore`] method of the same name, which other implementations are expected to behave
+/// like, including the errors they return.
+#[async_trait::async_trait]
+pub trait MultisigStoreBackend: Send + Sync {
+    /// Returns a view of the backend whose reads observe every preceding write.
+    ///
+    /// See [`MultisigStore::primary`].
+    fn primary(&self) -> Arc<dyn MultisigStoreBackend>;
+
+    /// See [`MultisigStore::create_multisig_account`].
+    async fn create_multisig_account(
+        &self,
+        multisig_account: MultisigAccount<WithApprovers, WithPubKeyCommits, ()>,
+    ) -> Result<MultisigAccount<WithApprovers, WithPubKeyCommits>>;
+
+    /// See [`MultisigStore::create_multisig_tx`].
+    async fn create_multisig_tx(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+        tx_request: &TransactionRequest,
+        tx_summary: &TransactionSummary,
+        title: Option<&str>,
+        memo: Option<&str>,
+        max_pending_txs: Option<NonZeroU32>,
+    ) -> Result<MultisigTxId>;
+
+    /// See [`MultisigStore::add_multisig_tx_signature`].
+    async fn add_multisig_tx_signature(
+        &self,
+        tx_id: &MultisigTxId,
+        network_id: NetworkId,
+        approver_account_id_address: AccountIdAddress,
+        signature: &ApproverSignature,
+    ) -> Result<Option<bool>>;
+
+    /// See [`MultisigStore::add_multisig_tx_signatures`].
+    async fn add_multisig_tx_signatures(
+        &self,
+        tx_id: &MultisigTxId,
+        network_id: NetworkId,
+        signatures: &[(AccountIdAddress, ApproverSignature)],
+    ) -> Result<Option<bool>>;
+
+    /// See [`MultisigStore::update_multisig_tx_status_by_id`].
+    async fn update_multisig_tx_status_by_id(
+        &self,
+        tx_id: &MultisigTxId,
+        new_status: MultisigTxStatus,
+    ) -> Result<()>;
+
+    /// See [`MultisigStore::transition_multisig_tx_status_by_id`].
+    async fn transition_multisig_tx_status_by_id(
+        &self,
+        tx_id: &MultisigTxId,
+        from: MultisigTxStatus,
+        to: MultisigTxStatus,
+    ) -> Result<bool>;
+
+    /// See [`MultisigStore::update_multisig_tx_status_to_success_by_id`].
+    async fn update_multisig_tx_status_to_success_by_id(
+        &self,
+        tx_id: &MultisigTxId,
+        chain_tx_id: TransactionId,
+        submission_block_num: BlockNumber,
+        account_nonce: u64,
+    ) -> Result<()>;
+
+    /// See [`MultisigStore::update_multisig_tx_status_to_failure_by_id`].
+    async fn update_multisig_tx_status_to_failure_by_id(
+        &self,
+        tx_id: &MultisigTxId,
+        failure_reason: &str,
+    ) -> Result<()>;
+
+    /// See [`MultisigStore::repropose_failed_multisig_tx`].
+    async fn repropose_failed_multisig_tx(
+        &self,
+        tx_id: &MultisigTxId,
+        tx_summary: &TransactionSummary,
+        max_pending_txs: Option<NonZeroU32>,
+    ) -> Result<()>;
+
+    /// See [`MultisigStore::archive_multisig_account`].
+    async fn archive_multisig_account(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<Option<MultisigAccount>>;
+
+    /// See [`MultisigStore::unarchive_multisig_account`].
+    async fn unarchive_multisig_account(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<Option<MultisigAccount>>;
+
+    /// See [`MultisigStore::remove_multisig_account_approver`].
+    async fn remove_multisig_account_approver(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+        approver_account_id_address: AccountIdAddress,
+    ) -> Result<Option<MultisigAccount<WithApprovers, WithPubKeyCommits>>>;
+
+    /// See [`MultisigStore::remove_multisig_account_approver_and_lower_threshold`].
+    async fn remove_multisig_account_approver_and_lower_threshold(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+        approver_account_id_address: AccountIdAddress,
+        threshold: NonZeroU32,
+    ) -> Result<Option<MultisigAccount<WithApprovers, WithPubKeyCommits>>>;
+
+    /// See [`MultisigStore::record_audit_event`].
+    async fn record_audit_event(
+        &self,
+        network_id: NetworkId,
+        subject: &AuditSubject,
+        actor: Option<AccountIdAddress>,
+        action: AuditAction,
+        outcome: AuditOutcome,
+        detail: Option<&str>,
+    ) -> Result<()>;
+
+    /// See [`MultisigStore::get_multisig_account`].
+    async fn get_multisig_account(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<Option<MultisigAccount>>;
+
+    /// See [`MultisigStore::get_account_summary`].
+    async fn get_account_summary(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<Option<(NonZeroU32, u32)>>;
+
+    /// See [`MultisigStore::try_get_multisig_account`].
+    async fn try_get_multisig_account(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<Option<MultisigAccount<WithApprovers, WithPubKeyCommits>>>;
+
+    /// See [`MultisigStore::get_all_multisig_accounts`].
+    async fn get_all_multisig_accounts(
+        &self,
+        include_archived: bool,
+    ) -> Result<Vec<MultisigAccount>>;
+
+    /// See [`MultisigStore::stream_multisig_accounts_paged`].
+    fn stream_multisig_accounts_paged(
+        &self,
+        include_archived: bool,
+        page_size: NonZeroU32,
+    ) -> BoxStream<'_, Result<MultisigAccount>>;
+
+    /// See [`MultisigStore::get_multisig_accounts_with_counts`].
+    async fn get_multisig_accounts_with_counts(
+        &self,
+        include_archived: bool,
+        after: Option<Cursor<String>>,
+        limit: Option<NonZeroU32>,
+    ) -> Result<(Vec<(MultisigAccount, u32, u64)>, Option<Cursor<String>>)>;
+
+    /// See [`MultisigStore::get_approvers_by_multisig_account_address`].
+    async fn get_approvers_by_multisig_account_address(
+        &self,
+        network_id: NetworkId,
+        multisig_account_id_address: AccountIdAddress,
+        label_contains: Option<&str>,
+        after: Option<Cursor<String>>,
+        limit: Option<NonZeroU32>,
+    ) -> Result<(Vec<MultisigApprover>, Option<Cursor<String>>)>;
+
+    /// See [`MultisigStore::get_indexed_approvers_by_multisig_account_address`].
+    async fn get_indexed_approvers_by_multisig_account_address(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<Option<Vec<(u32, MultisigApprover)>>>;
+
+    /// See [`MultisigStore::get_txs_by_multisig_account_address_with_status_filter`].
+    async fn get_txs_by_multisig_account_address_with_status_filter(
+        &self,
+        network_id: NetworkId,
+        address: AccountIdAddress,
+        tx_status_filter: Option<MultisigTxStatus>,
+        sort: TxSort,
+        after: Option<Cursor>,
+        limit: Option<NonZeroU32>,
+    ) -> Result<(Vec<(MultisigTxId, Result<MultisigTx>)>, Option<Cursor>)>;
+
+    /// See [`MultisigStore::get_pending_txs_for_approver`].
+    async fn get_pending_txs_for_approver(
+        &self,
+        network_id: NetworkId,
+        approver_address: AccountIdAddress,
+        after: Option<Cursor>,
+        limit: Option<NonZeroU32>,
+    ) -> Result<(Vec<(MultisigTxId, Result<MultisigTx>, bool)>, Option<Cursor>)>;
+
+    /// See [`MultisigStore::get_multisig_tx_by_id`].
+    async fn get_multisig_tx_by_id(&self, id: &MultisigTxId) -> Result<Option<MultisigTx>>;
+
+    /// See [`MultisigStore::get_multisig_account_address_by_tx_id`].
+    async fn get_multisig_account_address_by_tx_id(
+        &self,
+        id: &MultisigTxId,
+    ) -> Result<Option<AccountIdAddress>>;
+
+    /// See [`MultisigStore::get_multisig_tx_by_summary_commit`].
+    async fn get_multisig_tx_by_summary_commit(
+        &self,
+        tx_summary_commit: Word,
+    ) -> Result<Option<MultisigTx>>;
+
+    /// See [`MultisigStore::has_approver_signed`].
+    async fn has_approver_signed(
+        &self,
+        tx_id: &MultisigTxId,
+        network_id: NetworkId,
+        approver_account_id_address: AccountIdAddress,
+    ) -> Result<bool>;
+
+    /// See [`MultisigStore::is_approver_of_tx`].
+    async fn is_approver_of_tx(
+        &self,
+        tx_id: &MultisigTxId,
+        network_id: NetworkId,
+        approver_account_id_address: AccountIdAddress,
+    ) -> Result<Option<bool>>;
+
+    /// See [`MultisigStore::count_pending_multisig_txs_by_multisig_account_address`].
+    async fn count_pending_multisig_txs_by_multisig_account_address(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<u64>;
+
+    /// See [`MultisigStore::get_multisig_tx_stats_by_multisig_account_address`].
+    async fn get_multisig_tx_stats_by_multisig_account_address(
+        &self,
+        network_id: NetworkId,
+        multisig_account_id_address: AccountIdAddress,
+    ) -> Result<MultisigTxStats>;
+
+    /// See [`MultisigStore::get_tx_trend_by_multisig_account_address`].
+    async fn get_tx_trend_by_multisig_account_address(
+        &self,
+        network_id: NetworkId,
+        multisig_account_id_address: AccountIdAddress,
+        granularity: TrendGranularity,
+    ) -> Result<Vec<(DateTime<Utc>, u64)>>;
+
+    /// See [`MultisigStore::get_audit_events_by_multisig_account_address`].
+    async fn get_audit_events_by_multisig_account_address(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+        after: Option<Cursor>,
+        limit: Option<NonZeroU32>,
+    ) -> Result<(Vec<AuditEvent>, Option<Cursor>)>;
+
+    /// See [`MultisigStore::get_approver_pub_keys_in_order`].
+    async fn get_approver_pub_keys_in_order(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<Option<Vec<PublicKey>>>;
+
+    /// See [`MultisigStore::get_signatures_of_all_approvers_with_multisig_tx_by_tx_id`].
+    async fn get_signatures_of_all_approvers_with_multisig_tx_by_tx_id(
+        &self,
+        tx_id: &MultisigTxId,
+    ) -> Result<(Vec<Option<(ApproverSignature, DateTime<Utc>)>>, MultisigTx)>;
+
+    /// See [`MultisigStore::ping`].
+    async fn ping(&self) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl MultisigStoreBackend for MultisigStore {
+    fn primary(&self) -> Arc<dyn MultisigStoreBackend> {
+        Arc::new(MultisigStore::primary(self))
+    }
+
+    async fn create_multisig_account(
+        &self,
+        multisig_account: MultisigAccount<WithApprovers, WithPubKeyCommits, ()>,
+    ) -> Result<MultisigAccount<WithApprovers, WithPubKeyCommits>> {
+        MultisigStore::create_multisig_account(self, multisig_account).await
+    }
+
+    async fn create_multisig_tx(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+        tx_request: &TransactionRequest,
+        tx_summary: &TransactionSummary,
+        title: Option<&str>,
+        memo: Option<&str>,
+        max_pending_txs: Option<NonZeroU32>,
+    ) -> Result<MultisigTxId> {
+        MultisigStore::create_multisig_tx(
+            self,
+            network_id,
+            account_id_address,
+            tx_request,
+            tx_summary,
+            title,
+            memo,
+            max_pending_txs,
+        )
+        .await
+    }
+
+    async fn add_multisig_tx_signature(
+        &self,
+        tx_id: &MultisigTxId,
+        network_id: NetworkId,
+        approver_account_id_address: AccountIdAddress,
+        signature: &ApproverSignature,
+    ) -> Result<Option<bool>> {
+        MultisigStore::add_multisig_tx_signature(
+            self,
+            tx_id,
+            network_id,
+            approver_account_id_address,
+            signature,
+        )
+        .await
+    }
+
+    async fn add_multisig_tx_signatures(
+        &self,
+        tx_id: &MultisigTxId,
+        network_id: NetworkId,
+        signatures: &[(AccountIdAddress, ApproverSignature)],
+    ) -> Result<Option<bool>> {
+        MultisigStore::add_multisig_tx_signatures(self, tx_id, network_id, signatures).await
+    }
+
+    async fn update_multisig_tx_status_by_id(
+        &self,
+        tx_id: &MultisigTxId,
+        new_status: MultisigTxStatus,
+    ) -> Result<()> {
+        MultisigStore::update_multisig_tx_status_by_id(self, tx_id, new_status).await
+    }
+
+    async fn transition_multisig_tx_status_by_id(
+        &self,
+        tx_id: &MultisigTxId,
+        from: MultisigTxStatus,
+        to: MultisigTxStatus,
+    ) -> Result<bool> {
+        MultisigStore::transition_multisig_tx_status_by_id(self, tx_id, from, to).await
+    }
+
+    async fn update_multisig_tx_status_to_success_by_id(
+        &self,
+        tx_id: &MultisigTxId,
+        chain_tx_id: TransactionId,
+        submission_block_num: BlockNumber,
+        account_nonce: u64,
+    ) -> Result<()> {
+        MultisigStore::update_multisig_tx_status_to_success_by_id(
+            self,
+            tx_id,
+            chain_tx_id,
+            submission_block_num,
+            account_nonce,
+        )
+        .await
+    }
+
+    async fn update_multisig_tx_status_to_failure_by_id(
+        &self,
+        tx_id: &MultisigTxId,
+        failure_reason: &str,
+    ) -> Result<()> {
+        MultisigStore::update_multisig_tx_status_to_failure_by_id(self, tx_id, failure_reason).await
+    }
+
+    async fn repropose_failed_multisig_tx(
+        &self,
+        tx_id: &MultisigTxId,
+        tx_summary: &TransactionSummary,
+        max_pending_txs: Option<NonZeroU32>,
+    ) -> Result<()> {
+        MultisigStore::repropose_failed_multisig_tx(self, tx_id, tx_summary, max_pending_txs).await
+    }
+
+    async fn archive_multisig_account(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<Option<MultisigAccount>> {
+        MultisigStore::archive_multisig_account(self, network_id, account_id_address).await
+    }
+
+    async fn unarchive_multisig_account(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<Option<MultisigAccount>> {
+        MultisigStore::unarchive_multisig_account(self, network_id, account_id_address).await
+    }
+
+    async fn remove_multisig_account_approver(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+        approver_account_id_address: AccountIdAddress,
+    ) -> Result<Option<MultisigAccount<WithApprovers, WithPubKeyCommits>>> {
+        MultisigStore::remove_multisig_account_approver(
+            self,
+            network_id,
+            account_id_address,
+            approver_account_id_address,
+        )
+        .await
+    }
+
+    async fn remove_multisig_account_approver_and_lower_threshold(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+        approver_account_id_address: AccountIdAddress,
+        threshold: NonZeroU32,
+    ) -> Result<Option<MultisigAccount<WithApprovers, WithPubKeyCommits>>> {
+        MultisigStore::remove_multisig_account_approver_and_lower_threshold(
+            self,
+            network_id,
+            account_id_address,
+            approver_account_id_address,
+            threshold,
+        )
+        .await
+    }
+
+    async fn record_audit_event(
+        &self,
+        network_id: NetworkId,
+        subject: &AuditSubject,
+        actor: Option<AccountIdAddress>,
+        action: AuditAction,
+        outcome: AuditOutcome,
+        detail: Option<&str>,
+    ) -> Result<()> {
+        MultisigStore::record_audit_event(self, network_id, subject, actor, action, outcome, detail)
+            .await
+    }
+
+    async fn get_multisig_account(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<Option<MultisigAccount>> {
+        MultisigStore::get_multisig_account(self, network_id, account_id_address).await
+    }
+
+    async fn get_account_summary(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<Option<(NonZeroU32, u32)>> {
+        MultisigStore::get_account_summary(self, network_id, account_id_address).await
+    }
+
+    async fn try_get_multisig_account(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<Option<MultisigAccount<WithApprovers, WithPubKeyCommits>>> {
+        MultisigStore::try_get_multisig_account(self, network_id, account_id_address).await
+    }
+
+    async fn get_all_multisig_accounts(
+        &self,
+        include_archived: bool,
+    ) -> Result<Vec<MultisigAccount>> {
+        MultisigStore::get_all_multisig_accounts(self, include_archived).await
+    }
+
+    fn stream_multisig_accounts_paged(
+        &self,
+        include_archived: bool,
+        page_size: NonZeroU32,
+    ) -> BoxStream<'_, Result<MultisigAccount>> {
+        MultisigStore::stream_multisig_accounts_paged(self, include_archived, page_size)
+    }
+
+    async fn get_multisig_accounts_with_counts(
+        &self,
+        include_archived: bool,
+        after: Option<Cursor<String>>,
+        limit: Option<NonZeroU32>,
+    ) -> Result<(Vec<(MultisigAccount, u32, u64)>, Option<Cursor<String>>)> {
+        MultisigStore::get_multisig_accounts_with_counts(self, include_archived, after, limit).await
+    }
+
+    async fn get_approvers_by_multisig_account_address(
+        &self,
+        network_id: NetworkId,
+        multisig_account_id_address: AccountIdAddress,
+        label_contains: Option<&str>,
+        after: Option<Cursor<String>>,
+        limit: Option<NonZeroU32>,
+    ) -> Result<(Vec<MultisigApprover>, Option<Cursor<String>>)> {
+        MultisigStore::get_approvers_by_multisig_account_address(
+            self,
+            network_id,
+            multisig_account_id_address,
+            label_contains,
+            after,
+            limit,
+        )
+        .await
+    }
+
+    async fn get_indexed_approvers_by_multisig_account_address(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<Option<Vec<(u32, MultisigApprover)>>> {
+        MultisigStore::get_indexed_approvers_by_multisig_account_address(
+            self,
+            network_id,
+            account_id_address,
+        )
+        .await
+    }
+
+    async fn get_txs_by_multisig_account_address_with_status_filter(
+        &self,
+        network_id: NetworkId,
+        address: AccountIdAddress,
+        tx_status_filter: Option<MultisigTxStatus>,
+        sort: TxSort,
+        after: Option<Cursor>,
+        limit: Option<NonZeroU32>,
+    ) -> Result<(Vec<(MultisigTxId, Result<MultisigTx>)>, Option<Cursor>)> {
+        MultisigStore::get_txs_by_multisig_account_address_with_status_filter(
+            self,
+            network_id,
+            address,
+            tx_status_filter,
+            sort,
+            after,
+            limit,
+        )
+        .await
+    }
+
+    async fn get_pending_txs_for_approver(
+        &self,
+        network_id: NetworkId,
+        approver_address: AccountIdAddress,
+        after: Option<Cursor>,
+        limit: Option<NonZeroU32>,
+    ) -> Result<(Vec<(MultisigTxId, Result<MultisigTx>, bool)>, Option<Cursor>)> {
+        MultisigStore::get_pending_txs_for_approver(
+            self,
+            network_id,
+            approver_address,
+            after,
+            limit,
+        )
+        .await
+    }
+
+    async fn get_multisig_tx_by_id(&self, id: &MultisigTxId) -> Result<Option<MultisigTx>> {
+        MultisigStore::get_multisig_tx_by_id(self, id).await
+    }
+
+    async fn get_multisig_account_address_by_tx_id(
+        &self,
+        id: &MultisigTxId,
+    ) -> Result<Option<AccountIdAddress>> {
+        MultisigStore::get_multisig_account_address_by_tx_id(self, id).await
+    }
+
+    async fn get_multisig_tx_by_summary_commit(
+        &self,
+        tx_summary_commit: Word,
+    ) -> Result<Option<MultisigTx>> {
+        MultisigStore::get_multisig_tx_by_summary_commit(self, tx_summary_commit).await
+    }
+
+    async fn has_approver_signed(
+        &self,
+        tx_id: &MultisigTxId,
+        network_id: NetworkId,
+        approver_account_id_address: AccountIdAddress,
+    ) -> Result<bool> {
+        MultisigStore::has_approver_signed(self, tx_id, network_id, approver_account_id_address)
+            .await
+    }
+
+    async fn is_approver_of_tx(
+        &self,
+        tx_id: &MultisigTxId,
+        network_id: NetworkId,
+        approver_account_id_address: AccountIdAddress,
+    ) -> Result<Option<bool>> {
+        MultisigStore::is_approver_of_tx(self, tx_id, network_id, approver_account_id_address).await
+    }
+
+    async fn count_pending_multisig_txs_by_multisig_account_address(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<u64> {
+        MultisigStore::count_pending_multisig_txs_by_multisig_account_address(
+            self,
+            network_id,
+            account_id_address,
+        )
+        .await
+    }
+
+    async fn get_multisig_tx_stats_by_multisig_account_address(
+        &self,
+        network_id: NetworkId,
+        multisig_account_id_address: AccountIdAddress,
+    ) -> Result<MultisigTxStats> {
+        MultisigStore::get_multisig_tx_stats_by_multisig_account_address(
+            self,
+            network_id,
+            multisig_account_id_address,
+        )
+        .await
+    }
+
+    async fn get_tx_trend_by_multisig_account_address(
+        &self,
+        network_id: NetworkId,
+        multisig_account_id_address: AccountIdAddress,
+        granularity: TrendGranularity,
+    ) -> Result<Vec<(DateTime<Utc>, u64)>> {
+        MultisigStore::get_tx_trend_by_multisig_account_address(
+            self,
+            network_id,
+            multisig_account_id_address,
+            granularity,
+        )
+        .await
+    }
+
+    async fn get_audit_events_by_multisig_account_address(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+        after: Option<Cursor>,
+        limit: Option<NonZeroU32>,
+    ) -> Result<(Vec<AuditEvent>, Option<Cursor>)> {
+        MultisigStore::get_audit_events_by_multisig_account_address(
+            self,
+            network_id,
+            account_id_address,
+            after,
+            limit,
+        )
+        .await
+    }
+
+    async fn get_approver_pub_keys_in_order(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<Option<Vec<PublicKey>>> {
+        MultisigStore::get_approver_pub_keys_in_order(self, network_id, account_id_address).await
+    }
+
+    async fn get_signatures_of_all_approvers_with_multisig_tx_by_tx_id(
+        &self,
+        tx_id: &MultisigTxId,
+    ) -> Result<(Vec<Option<(ApproverSignature, DateTime<Utc>)>>, MultisigTx)> {
+        MultisigStore::get_signatures_of_all_approvers_with_multisig_tx_by_tx_id(self, tx_id).await
+    }
+
+    async fn ping(&self) -> Result<()> {
+        MultisigStore::ping(self).await
+    }
+}
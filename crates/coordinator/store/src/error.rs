@@ -1,6 +1,8 @@
+use core::num::NonZeroU32;
+
 use std::borrow::Cow;
 
-use crate::persistence::store::StoreError;
+use crate::persistence::store::{self, StoreError};
 
 pub type Result<T, E = MultisigStoreError> = core::result::Result<T, E>;
 
@@ -12,7 +14,14 @@ pub enum MultisigStoreError {
     /// This wraps errors from the underlying persistence layer, including
     /// connection issues, query failures, and transaction errors.
     #[error("database error: {0}")]
-    Store(#[from] StoreError),
+    Store(StoreError),
+
+    /// A database statement exceeded the configured statement timeout and was canceled.
+    ///
+    /// This is returned instead of [`MultisigStoreError::Store`] so that callers can tell slow
+    /// queries apart from other database errors.
+    #[error("statement timeout error")]
+    Timeout,
 
     /// A validation error occurred while processing input data.
     ///
@@ -21,6 +30,68 @@ pub enum MultisigStoreError {
     #[error("validation error: {0}")]
     Validation(Cow<'static, str>),
 
+    /// Removing an approver would leave a multisig account unable to meet its threshold.
+    ///
+    /// This is returned instead of removing the approver when the total weight of the remaining
+    /// approvers would be below the threshold, which would make the account permanently unusable.
+    #[error(
+        "unreachable threshold error: `{threshold}` exceeds remaining weight `{remaining_weight}`"
+    )]
+    UnreachableThreshold { threshold: u64, remaining_weight: u64 },
+
+    /// Removing an approver would raise the threshold of a multisig account.
+    ///
+    /// This is returned instead of removing the approver, as removing an approver can only keep or
+    /// lower the threshold.
+    #[error("threshold raised error: `{threshold}` exceeds current threshold `{current}`")]
+    ThresholdRaised { threshold: u64, current: u64 },
+
+    /// Removing an approver from a multisig account with transactions not executed yet.
+    ///
+    /// This is returned instead of removing the approver, as the signatures and thresholds of the
+    /// pending transactions were collected for the current approvers.
+    #[error("pending txs error: the multisig account has txs not executed yet")]
+    PendingTxs,
+
+    /// The multisig account to create is already persisted.
+    ///
+    /// This is returned when an account with the same id already exists, whatever the interface
+    /// of its address, as accounts are keyed by id.
+    #[error("multisig account already exists error")]
+    MultisigAccountAlreadyExists,
+
+    /// A signature does not verify against the key of the approver adding it.
+    ///
+    /// This is returned instead of storing the signature when it was not made with the approver's
+    /// key over the transaction summary commitment, e.g. when its scheme differs from the scheme
+    /// of the approver's key.
+    #[error("invalid signature error: the signature does not verify against the approver key")]
+    InvalidSignature,
+
+    /// A store operation would alter the summary commitment of a transaction which already
+    /// holds signatures.
+    ///
+    /// This is returned instead of storing the new commitment, as the collected signatures were
+    /// made over the stored one and would silently become invalid. The database refuses the
+    /// change whichever statement makes it, the signatures are to be deleted first.
+    #[error("commitment altered error: the signed commitment of a tx cannot change")]
+    CommitmentAltered,
+
+    /// A multisig account already has as many pending transactions as it is allowed to.
+    ///
+    /// This is returned instead of storing another pending transaction, the pending ones are
+    /// counted within the database transaction storing it so that concurrent proposals can't
+    /// exceed the maximum.
+    #[error("too many pending txs error: `{max}` txs already pending")]
+    TooManyPendingTxs { max: NonZeroU32 },
+
+    /// A query would retrieve more rows than it is allowed to at once.
+    ///
+    /// This is returned instead of loading an unbounded result, the caller is expected to
+    /// paginate instead.
+    #[error("result too large error: more than `{max}` rows, paginate instead")]
+    ResultTooLarge { max: NonZeroU32 },
+
     /// The requested resource was not found in the database.
     ///
     /// This is returned when querying for entities that don't exist,
@@ -56,6 +127,20 @@ pub enum MultisigStoreError {
     Other(Cow<'static, str>),
 }
 
+impl From<StoreError> for MultisigStoreError {
+    fn from(err: StoreError) -> Self {
+        if err.is_statement_timeout() {
+            return Self::Timeout;
+        }
+
+        if err.is_check_violation_of(store::TX_SIGNED_COMMITMENT_IMMUTABLE) {
+            return Self::CommitmentAltered;
+        }
+
+        Self::Store(err)
+    }
+}
+
 impl From<chrono::ParseError> for MultisigStoreError {
     fn from(err: chrono::ParseError) -> Self {
         MultisigStoreError::Serialization(err.to_string().into())
@@ -14,68 +14,104 @@
 //! # Main Components
 //!
 //! - [`MultisigStore`] - The primary interface for database operations
+//! - [`MultisigStoreBackend`] - The operations the engine relies on, abstracted over the database
 //! - [`DbPool`] - Connection pool type for managing database connections
 //! - [`DbConn`] - Individual database connection from the pool
+//! - [`ConnConfig`] - Statement timeout and slow query logging settings for pooled connections
+//! - [`PoolTlsConfig`] - TLS mode, CA certificate and client certificate of pooled connections
 //! - [`MultisigStoreError`] - Error types for store operations
 //!
 //! # Usage
 //!
 //! ```ignore
 //! // Establish a connection pool
-//! let pool = establish_pool(database_url, max_connections).await?;
+//! let conn_config = ConnConfig::builder()
+//!     .statement_timeout(Duration::from_secs(30))
+//!     .slow_query_threshold(Duration::from_millis(500))
+//!     .tls(PoolTlsConfig::builder().mode(TlsMode::Require).ca_cert_path("./ca.pem").build())
+//!     .build();
+//!
+//! let pool = establish_pool(database_url, max_connections, conn_config).await?;
 //!
 //! // Create the store
 //! let store = MultisigStore::new(pool);
 //!
 //! // Store operations
 //! let account = store.get_multisig_account(network_id, address).await?;
-//! let txs = store.get_txs_by_multisig_account_address_with_status_filter(
+//! let (txs, next_cursor) = store.get_txs_by_multisig_account_address_with_status_filter(
 //!     network_id,
 //!     address,
-//!     Some(MultisigTxStatus::Pending)
+//!     Some(MultisigTxStatus::Pending),
+//!     TxSort::default(),
+//!     None,
+//!     NonZeroU32::new(20),
 //! ).await?;
 //! ```
 
+mod backend;
 mod error;
 mod persistence;
 
 pub use self::{
+    backend::MultisigStoreBackend,
     error::MultisigStoreError,
-    persistence::pool::{DbConn, DbPool, establish_pool},
+    persistence::pool::{ConnConfig, DbConn, DbPool, PoolTlsConfig, TlsMode, establish_pool},
 };
 
-use core::num::NonZeroU32;
+use core::{num::NonZeroU32, time::Duration};
+
+use std::collections::{HashMap, HashSet};
 
+use chrono::{DateTime, Utc};
 use diesel_async::AsyncConnection;
-use futures::{Stream, StreamExt, TryStreamExt};
+use futures::{StreamExt, TryStreamExt, stream::BoxStream};
 use miden_client::{
     Word,
-    account::{AccountIdAddress, Address, NetworkId},
+    account::{AccountIdAddress, AddressInterface, NetworkId},
+    note::NoteId,
     transaction::TransactionRequest,
     utils::{Deserializable, Serializable},
 };
 use miden_multisig_coordinator_domain::{
     Timestamps,
-    account::{MultisigAccount, MultisigApprover, WithApprovers, WithPubKeyCommits},
-    tx::{MultisigTx, MultisigTxId, MultisigTxStats, MultisigTxStatus},
+    account::{
+        MultisigAccount, MultisigApprover, MultisigApproverDissolved, WithApprovers,
+        WithPubKeyCommits,
+    },
+    audit::{AuditAction, AuditEvent, AuditOutcome, AuditSubject},
+    cursor::Cursor,
+    key::{ApproverKey, ApproverSignature},
+    tx::{
+        MultisigTx, MultisigTxId, MultisigTxStats, MultisigTxStatus, TrendGranularity, TxEffect,
+        TxSort, TxSortField,
+    },
+};
+use miden_multisig_coordinator_utils::{
+    extract_network_id_account_id_address_pair, to_account_key, to_bech32,
+    verify_ecdsa_k256_signature,
 };
-use miden_multisig_coordinator_utils::extract_network_id_account_id_address_pair;
 use miden_objects::{
-    crypto::dsa::rpo_falcon512::{PublicKey, Signature},
-    transaction::TransactionSummary,
+    block::BlockNumber,
+    crypto::dsa::rpo_falcon512::PublicKey,
+    transaction::{TransactionId, TransactionSummary},
 };
 use oblux::U63;
+use uuid::Uuid;
 
 use self::{
     error::Result,
     persistence::{
+        blob,
         record::{
+            TxStatus,
             insert::{
-                NewApproverRecord, NewMultisigAccountRecord, NewSignatureRecord, NewTxRecord,
+                NewApproverRecord, NewAuditEventRecord, NewMultisigAccountRecord,
+                NewSignatureRecord, NewTxInputNoteRecord, NewTxRecord,
             },
             select::{
-                ApproverRecord, ApproverRecordDissolved, MultisigAccountRecord,
-                MultisigAccountRecordDissolved, TxRecord, TxRecordDissolved,
+                ApproverRecord, ApproverRecordDissolved, AuditEventRecord,
+                AuditEventRecordDissolved, MultisigAccountRecord, MultisigAccountRecordDissolved,
+                TxRecord, TxRecordDissolved,
             },
         },
         store::{self, StoreError},
@@ -86,14 +122,79 @@ use self::{
 ///
 /// `MultisigStore` provides high-level methods for interacting with the database,
 /// managing multisig accounts, transactions, signatures, and approvers.
+///
+/// Read-only queries can be routed to a separate connection pool, e.g. one connected to a read
+/// replica, see [`MultisigStore::with_read_pool`]. Writes, and reads that must observe the writes
+/// preceding them, always use the primary pool, see [`MultisigStore::primary`].
+///
+/// The serialized transaction requests and summaries can be stored compressed, see
+/// [`MultisigStore::with_tx_blob_compression`].
+///
+/// Retrieving all multisig accounts at once is capped, see
+/// [`MultisigStore::with_max_all_multisig_accounts`].
 pub struct MultisigStore {
     pool: DbPool,
+    read_pool: DbPool,
+    compress_tx_blobs: bool,
+    max_all_multisig_accounts: NonZeroU32,
 }
 
 impl MultisigStore {
+    /// The default most multisig accounts retrieved at once by
+    /// [`get_all_multisig_accounts`](Self::get_all_multisig_accounts).
+    pub const DEFAULT_MAX_ALL_MULTISIG_ACCOUNTS: NonZeroU32 = NonZeroU32::new(10_000).unwrap();
+
     /// Creates a new `MultisigStore` instance with the given connection pool.
+    ///
+    /// The pool serves both reads and writes, and transaction blobs are stored uncompressed.
     pub fn new(pool: DbPool) -> Self {
-        MultisigStore { pool }
+        MultisigStore {
+            read_pool: pool.clone(),
+            pool,
+            compress_tx_blobs: false,
+            max_all_multisig_accounts: Self::DEFAULT_MAX_ALL_MULTISIG_ACCOUNTS,
+        }
+    }
+
+    /// Routes read-only queries to the given connection pool instead of the primary pool.
+    ///
+    /// Reads served by a replica may lag behind the primary, so this is only used for listing
+    /// and lookup queries.
+    pub fn with_read_pool(self, read_pool: DbPool) -> Self {
+        MultisigStore { read_pool, ..self }
+    }
+
+    /// Returns a view of the store whose read-only queries are also served by the primary pool.
+    ///
+    /// This is meant for reads gating a write, e.g. checking the status of a transaction before
+    /// processing it, which must not act on a replica lagging behind the primary. Both pools are
+    /// shared with `self`.
+    pub fn primary(&self) -> Self {
+        MultisigStore {
+            pool: self.pool.clone(),
+            read_pool: self.pool.clone(),
+            compress_tx_blobs: self.compress_tx_blobs,
+            max_all_multisig_accounts: self.max_all_multisig_accounts,
+        }
+    }
+
+    /// Sets whether the serialized transaction requests and summaries of new and reproposed
+    /// transactions are zstd compressed before being stored.
+    ///
+    /// Stored transactions are read back regardless of this setting, whether they were written
+    /// compressed or not, so it can be toggled without migrating existing rows.
+    pub fn with_tx_blob_compression(self, enabled: bool) -> Self {
+        MultisigStore { compress_tx_blobs: enabled, ..self }
+    }
+
+    /// Sets the most multisig accounts
+    /// [`get_all_multisig_accounts`](Self::get_all_multisig_accounts) retrieves at once,
+    /// [`DEFAULT_MAX_ALL_MULTISIG_ACCOUNTS`](Self::DEFAULT_MAX_ALL_MULTISIG_ACCOUNTS) by default.
+    ///
+    /// Beyond it, accounts are to be retrieved with
+    /// [`stream_multisig_accounts_paged`](Self::stream_multisig_accounts_paged) instead.
+    pub fn with_max_all_multisig_accounts(self, max: NonZeroU32) -> Self {
+        MultisigStore { max_all_multisig_accounts: max, ..self }
     }
 }
 
@@ -101,11 +202,15 @@ impl MultisigStore {
     /// Creates a new multisig account in the database.
     ///
     /// This method stores the account details along with all associated approvers
-    /// and their public key commitments in a single database transaction.
+    /// and their public key commitments in a single database transaction, which is run again if
+    /// it is aborted by a conflict with a concurrent transaction.
     ///
     /// # Arguments
     ///
     /// * `multisig_account` - A fully configured multisig account with approvers and public key commitments.
+    ///   The approver weights are stored alongside the approvers, as are their labels. Labels are
+    ///   given per account, so an approver shared with another account keeps the label it has
+    ///   there.
     ///
     /// # Returns
     ///
@@ -115,8 +220,9 @@ impl MultisigStore {
     ///
     /// Returns an error if:
     /// - The database transaction fails
-    /// - An account with the same address already exists
-    /// - Any approver data is invalid
+    /// - An account with the same id already exists, in which case
+    ///   [`MultisigStoreError::MultisigAccountAlreadyExists`] is returned
+    /// - Any approver data is invalid, e.g. an approver appears more than once
     #[tracing::instrument(
         skip_all,
         fields(
@@ -131,37 +237,53 @@ impl MultisigStore {
         &self,
         multisig_account: MultisigAccount<WithApprovers, WithPubKeyCommits, ()>,
     ) -> Result<MultisigAccount<WithApprovers, WithPubKeyCommits>> {
-        self.get_conn()
-            .await?
-            .transaction(|conn| {
+        let nonce = multisig_account
+            .nonce()
+            .map(i64::try_from)
+            .transpose()
+            .map_err(|_| MultisigStoreError::InvalidValue)?;
+
+        let conn = &mut self.get_conn().await?;
+
+        store::retry_transient(async || {
+            // the account is consumed by the transaction, so every attempt gets its own copy
+            let multisig_account = multisig_account.clone();
+
+            conn.transaction(|conn| {
                 Box::pin(async move {
-                    let multisig_account_address = Address::AccountId(multisig_account.address())
-                        .to_bech32(multisig_account.network_id());
+                    let multisig_account_address =
+                        to_account_key(multisig_account.network_id(), multisig_account.address());
 
                     let new_multisig_account = NewMultisigAccountRecord::builder()
                         .address(&multisig_account_address)
                         .kind(multisig_account.kind().into())
                         .threshold(multisig_account.threshold().get().into())
+                        .maybe_nonce(nonce)
+                        .interface(multisig_account.address().interface().into())
                         .build();
 
                     let timestamps = store::save_new_multisig_account(conn, new_multisig_account)
                         .await
                         .map(|t| Timestamps::builder().created_at(t).updated_at(t).build())?;
 
-                    for (idx, (&approver_account_id_address, &pub_key_commit)) in multisig_account
-                        .approvers()
-                        .iter()
-                        .zip(multisig_account.pub_key_commits())
-                        .enumerate()
+                    for (idx, (((&approver_account_id_address, &pub_key_commit), weight), label)) in
+                        multisig_account
+                            .approvers()
+                            .iter()
+                            .zip(multisig_account.pub_key_commits())
+                            .zip(multisig_account.approver_weights())
+                            .zip(multisig_account.approver_labels())
+                            .enumerate()
                     {
-                        let approver_address = Address::AccountId(approver_account_id_address)
-                            .to_bech32(multisig_account.network_id());
+                        let approver_address =
+                            to_bech32(multisig_account.network_id(), approver_account_id_address);
 
-                        let pub_key_commit_bz = Word::from(pub_key_commit).as_bytes();
+                        let pub_key_commit_bz = pub_key_commit.to_bytes();
 
                         let new_approver = NewApproverRecord::builder()
                             .address(&approver_address)
                             .pub_key_commit(&pub_key_commit_bz)
+                            .scheme(pub_key_commit.scheme().into())
                             .build();
 
                         store::upsert_approver(conn, new_approver).await?;
@@ -172,6 +294,8 @@ impl MultisigStore {
                             &multisig_account_address,
                             &approver_address,
                             idx as u32,
+                            weight.get(),
+                            label.as_deref(),
                         )
                         .await?;
                     }
@@ -180,13 +304,27 @@ impl MultisigStore {
                 })
             })
             .await
-            .map_err(MultisigStoreError::Store)
+        })
+        .await
+        .map_err(|err| {
+            // the account is keyed by id, so this also holds for another address interface
+            if err.is_unique_violation_of(store::MULTISIG_ACCOUNT_PKEY) {
+                return MultisigStoreError::MultisigAccountAlreadyExists;
+            }
+
+            MultisigStoreError::from(err)
+        })
     }
 
     /// Creates a new multisig transaction proposal.
     ///
     /// This method stores a transaction proposal that requires multiple signatures
     /// before it can be executed. The transaction is initially created with a "pending" status.
+    /// An optional `title` and `memo` may be attached to give approvers human-readable context.
+    ///
+    /// If `max_pending_txs` is given, the account row is locked and its pending transactions
+    /// counted within the same database transaction, so that concurrent proposals can't exceed
+    /// it.
     ///
     /// # Returns
     ///
@@ -196,6 +334,8 @@ impl MultisigStore {
     ///
     /// Returns an error if:
     /// - The multisig account doesn't exist
+    /// - The multisig account already has `max_pending_txs` pending transactions, in which case
+    ///   [`MultisigStoreError::TooManyPendingTxs`] is returned and nothing is stored
     /// - Serialization of transaction data fails
     /// - The database operation fails
     #[tracing::instrument(
@@ -208,30 +348,79 @@ impl MultisigStore {
         account_id_address: AccountIdAddress,
         tx_request: &TransactionRequest,
         tx_summary: &TransactionSummary,
+        title: Option<&str>,
+        memo: Option<&str>,
+        max_pending_txs: Option<NonZeroU32>,
     ) -> Result<MultisigTxId> {
-        let multisig_account_address = Address::AccountId(account_id_address).to_bech32(network_id);
+        let multisig_account_address = to_account_key(network_id, account_id_address);
 
-        let tx_request_bz = tx_request.to_bytes();
-        let tx_summary_bz = tx_summary.to_bytes();
+        let tx_request_bz = self.encode_tx_blob(tx_request.to_bytes())?;
+        let tx_summary_bz = self.encode_tx_blob(tx_summary.to_bytes())?;
         let tx_summary_commit_bz = tx_summary.to_commitment().as_bytes();
+        let tx_effect_bz = TxEffect::from(tx_summary).to_bytes();
+
+        let input_note_ids_bz: Vec<_> =
+            tx_request.get_input_note_ids().iter().map(Serializable::to_bytes).collect();
 
         let new_tx = NewTxRecord::builder()
             .multisig_account_address(&multisig_account_address)
             .tx_request(&tx_request_bz)
             .tx_summary(&tx_summary_bz)
             .tx_summary_commit(&tx_summary_commit_bz)
+            .tx_effect(&tx_effect_bz)
+            .maybe_title(title)
+            .maybe_memo(memo)
             .build();
 
-        store::save_new_tx(&mut self.get_conn().await?, new_tx)
+        let address = &multisig_account_address;
+
+        let tx_id = self
+            .get_conn()
+            .await?
+            .transaction(|conn| {
+                Box::pin(async move {
+                    if let Some(max) = max_pending_txs {
+                        if has_max_pending_txs(conn, address, max).await? {
+                            return Ok(None);
+                        }
+                    }
+
+                    let tx_id = store::save_new_tx(conn, new_tx).await?;
+
+                    // casting idx to i64 is safe as input notes length cannot exceed i64::MAX
+                    let new_tx_input_notes = input_note_ids_bz
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, note_id_bz)| {
+                            NewTxInputNoteRecord::builder()
+                                .tx_id(tx_id)
+                                .note_index(idx as i64)
+                                .note_id(note_id_bz)
+                                .build()
+                        })
+                        .collect();
+
+                    store::save_new_tx_input_notes(conn, new_tx_input_notes).await?;
+
+                    Ok(Some(tx_id.into()))
+                })
+            })
             .await
-            .map(From::from)
-            .map_err(From::from)
+            .map_err(MultisigStoreError::from)?;
+
+        // only none when capped, which requires a maximum
+        tx_id.ok_or_else(|| MultisigStoreError::TooManyPendingTxs {
+            max: max_pending_txs.expect("only capped with a maximum"),
+        })
     }
 
     /// Adds a signature from an approver to a multisig transaction.
     ///
     /// This method validates that the approver is authorized to sign the transaction,
-    /// stores the signature, and checks if the signature threshold has been met.
+    /// stores the signature, and checks if the signature threshold has been met, i.e. whether the
+    /// weights of the approvers who signed sum up to the threshold. If the database transaction is
+    /// aborted by a concurrent one, e.g. another approver signing simultaneously, it is retried.
+    /// The time the threshold is first met is recorded along with the transaction.
     ///
     /// # Returns
     ///
@@ -243,8 +432,10 @@ impl MultisigStore {
     ///
     /// Returns an error if:
     /// - The transaction doesn't exist
+    /// - The signature doesn't verify against the approver's key, e.g. because its scheme doesn't
+    ///   match the approver's key scheme, in which case [`MultisigStoreError::InvalidSignature`]
+    ///   is returned and nothing is stored
     /// - The database transaction fails
-    /// - Signature serialization fails
     #[tracing::instrument(
         skip_all,
         fields(
@@ -258,14 +449,14 @@ impl MultisigStore {
         tx_id: &MultisigTxId,
         network_id: NetworkId,
         approver_account_id_address: AccountIdAddress,
-        signature: &Signature,
+        signature: &ApproverSignature,
     ) -> Result<Option<bool>> {
-        self.get_conn()
-            .await?
-            .transaction(|conn| {
+        let conn = &mut self.get_conn().await?;
+
+        let addition = store::retry_transient(async || {
+            conn.transaction(|conn| {
                 Box::pin(async move {
-                    let approver_address =
-                        Address::AccountId(approver_account_id_address).to_bech32(network_id);
+                    let approver_address = to_bech32(network_id, approver_account_id_address);
 
                     if !store::validate_approver_address_by_tx_id(
                         conn,
@@ -274,7 +465,18 @@ impl MultisigStore {
                     )
                     .await?
                     {
-                        return Ok(None);
+                        return Ok(SignatureAddition::NotAuthorized);
+                    }
+
+                    if !is_approver_signature_valid(
+                        conn,
+                        tx_id.into(),
+                        &approver_address,
+                        signature,
+                    )
+                    .await?
+                    {
+                        return Ok(SignatureAddition::InvalidSignature);
                     }
 
                     let signature_bz = signature.to_bytes();
@@ -287,24 +489,142 @@ impl MultisigStore {
 
                     store::save_new_signature(conn, new_signature).await?;
 
-                    let (tx_record, signature_count) =
-                        store::fetch_tx_with_signature_count_by_id(conn, tx_id.into())
-                            .await?
-                            .ok_or(StoreError::other("tx not found"))?;
+                    let threshold = fetch_threshold_by_tx_id(conn, tx_id.into()).await?;
+
+                    let signed_weight =
+                        store::fetch_signed_weight_by_tx_id(conn, tx_id.into()).await?;
+
+                    if signed_weight >= threshold {
+                        store::set_threshold_met_at_by_tx_id(conn, tx_id.into()).await?;
+                    }
+
+                    Ok(SignatureAddition::Added {
+                        threshold_met: signed_weight >= threshold,
+                    })
+                })
+            })
+            .await
+        })
+        .await?;
+
+        match addition {
+            SignatureAddition::Added { threshold_met } => Ok(Some(threshold_met)),
+            SignatureAddition::NotAuthorized => Ok(None),
+            SignatureAddition::InvalidSignature => Err(MultisigStoreError::InvalidSignature),
+        }
+    }
+
+    /// Adds signatures from several approvers to a multisig transaction at once.
+    ///
+    /// All approvers are validated before anything is stored, and all signatures are stored in a
+    /// single database transaction. If any signature cannot be stored (e.g. the approver already
+    /// signed the transaction), none of them are. The threshold is met once the weights of the
+    /// approvers who signed sum up to it, the time it is first met is recorded along with the
+    /// transaction.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Some(true))` if the signatures were added and the threshold was crossed by this batch
+    /// - `Ok(Some(false))` if the signatures were added but the threshold was not crossed by this
+    ///   batch, either because more signatures are needed or because it had already been met
+    /// - `Ok(None)` if any of the approvers is not authorized to sign this transaction, in which
+    ///   case no signature is added
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The transaction doesn't exist
+    /// - Any signature doesn't verify against the approver's key, e.g. because its scheme doesn't
+    ///   match the approver's key scheme, in which case [`MultisigStoreError::InvalidSignature`]
+    ///   is returned and no signature is added
+    /// - The database transaction fails
+    #[tracing::instrument(
+        skip_all,
+        fields(%tx_id, %network_id, signature_count = signatures.len()),
+    )]
+    pub async fn add_multisig_tx_signatures(
+        &self,
+        tx_id: &MultisigTxId,
+        network_id: NetworkId,
+        signatures: &[(AccountIdAddress, ApproverSignature)],
+    ) -> Result<Option<bool>> {
+        let addition = self
+            .get_conn()
+            .await?
+            .transaction(|conn| {
+                Box::pin(async move {
+                    let approver_addresses: Vec<_> = signatures
+                        .iter()
+                        .map(|&(approver, _)| to_bech32(network_id, approver))
+                        .collect();
+
+                    for approver_address in &approver_addresses {
+                        if !store::validate_approver_address_by_tx_id(
+                            conn,
+                            tx_id.into(),
+                            approver_address,
+                        )
+                        .await?
+                        {
+                            return Ok(SignatureAddition::NotAuthorized);
+                        }
+                    }
 
-                    let TxRecordDissolved { multisig_account_address, .. } = tx_record.dissolve();
+                    // every signature is verified before any is stored, as the outcome commits
+                    for (approver_address, (_, signature)) in
+                        approver_addresses.iter().zip(signatures)
+                    {
+                        if !is_approver_signature_valid(
+                            conn,
+                            tx_id.into(),
+                            approver_address,
+                            signature,
+                        )
+                        .await?
+                        {
+                            return Ok(SignatureAddition::InvalidSignature);
+                        }
+                    }
+
+                    let prev_signed_weight =
+                        store::fetch_signed_weight_by_tx_id(conn, tx_id.into()).await?;
+
+                    for (approver_address, (_, signature)) in
+                        approver_addresses.iter().zip(signatures)
+                    {
+                        let signature_bz = signature.to_bytes();
+
+                        let new_signature = NewSignatureRecord::builder()
+                            .tx_id(tx_id.into())
+                            .approver_address(approver_address)
+                            .signature_bytes(&signature_bz)
+                            .build();
+
+                        store::save_new_signature(conn, new_signature).await?;
+                    }
+
+                    let threshold = fetch_threshold_by_tx_id(conn, tx_id.into()).await?;
 
-                    let MultisigAccountRecordDissolved { threshold, .. } =
-                        store::fetch_mutisig_account_by_address(conn, &multisig_account_address)
-                            .await?
-                            .map(MultisigAccountRecord::dissolve)
-                            .ok_or(StoreError::other("multisig account not found"))?;
+                    let signed_weight =
+                        store::fetch_signed_weight_by_tx_id(conn, tx_id.into()).await?;
 
-                    Ok(Some(signature_count.to_signed() >= threshold))
+                    if signed_weight >= threshold {
+                        store::set_threshold_met_at_by_tx_id(conn, tx_id.into()).await?;
+                    }
+
+                    Ok(SignatureAddition::Added {
+                        threshold_met: prev_signed_weight < threshold && signed_weight >= threshold,
+                    })
                 })
             })
             .await
-            .map_err(MultisigStoreError::Store)
+            .map_err(MultisigStoreError::from)?;
+
+        match addition {
+            SignatureAddition::Added { threshold_met } => Ok(Some(threshold_met)),
+            SignatureAddition::NotAuthorized => Ok(None),
+            SignatureAddition::InvalidSignature => Err(MultisigStoreError::InvalidSignature),
+        }
     }
 
     /// Updates the execution status of a multisig transaction.
@@ -332,176 +652,1154 @@ impl MultisigStore {
         Ok(())
     }
 
-    /// Retrieves a multisig account by its address.
+    /// Moves a multisig transaction from the `from` status to the `to` one, if it has the `from`
+    /// status.
     ///
-    /// This method fetches the basic account information (address, network, kind, threshold)
-    /// but does not include the approvers or public key commitments.
+    /// The status is checked and updated atomically, so among concurrent transitions from the
+    /// same status only one succeeds, e.g. a single execution claims a transaction ready to
+    /// execute by moving it to [`MultisigTxStatus::Executing`].
     ///
     /// # Returns
     ///
-    /// Returns `Some(account)` if found, or `None` if the account doesn't exist.
+    /// Returns `true` if the transaction had the `from` status and was moved to the `to` one,
+    /// `false` if it doesn't exist or has another status.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database update fails.
+    #[tracing::instrument(skip_all, fields(%tx_id, %from, %to))]
+    pub async fn transition_multisig_tx_status_by_id(
+        &self,
+        tx_id: &MultisigTxId,
+        from: MultisigTxStatus,
+        to: MultisigTxStatus,
+    ) -> Result<bool> {
+        let conn = &mut self.get_conn().await?;
+
+        store::update_status_from_by_tx_id(conn, tx_id.into(), from.into(), to.into())
+            .await
+            .map_err(From::from)
+    }
+
+    /// Marks a multisig transaction as successfully executed and records its on-chain id.
+    ///
+    /// The status, the id of the executed transaction and the block number it was executed
+    /// against are updated together, so a successful transaction always links to the chain. The
+    /// on-chain nonce of the multisig account after the execution is recorded in the same
+    /// database transaction, so that [`MultisigAccount::nonce`] keeps track of the chain.
     ///
     /// # Errors
     ///
     /// Returns an error if:
-    /// - The database query fails
-    /// - Stored data cannot be deserialized
+    /// - The transaction ID doesn't exist
+    /// - The nonce exceeds the range of the database column
+    /// - The database transaction fails
     #[tracing::instrument(
         skip_all,
-        fields(
-            %network_id,
-            account_id_address = %account_id_address.id().to_hex(),
-        )
+        fields(%tx_id, %chain_tx_id, %submission_block_num, %account_nonce),
     )]
-    pub async fn get_multisig_account(
+    pub async fn update_multisig_tx_status_to_success_by_id(
         &self,
-        network_id: NetworkId,
-        account_id_address: AccountIdAddress,
-    ) -> Result<Option<MultisigAccount>> {
-        let conn = &mut self.get_conn().await?;
+        tx_id: &MultisigTxId,
+        chain_tx_id: TransactionId,
+        submission_block_num: BlockNumber,
+        account_nonce: u64,
+    ) -> Result<()> {
+        let account_nonce =
+            i64::try_from(account_nonce).map_err(|_| MultisigStoreError::InvalidValue)?;
 
-        let address = Address::AccountId(account_id_address).to_bech32(network_id);
+        let conn = &mut self.get_conn().await?;
 
-        let Some(MultisigAccountRecordDissolved { kind, threshold, created_at, .. }) =
-            store::fetch_mutisig_account_by_address(conn, &address)
-                .await?
-                .map(MultisigAccountRecord::dissolve)
-        else {
-            return Ok(None);
-        };
+        let updated = store::retry_transient(async || {
+            conn.transaction(|conn| {
+                Box::pin(async move {
+                    if !store::update_status_to_success_with_chain_tx_by_tx_id(
+                        conn,
+                        tx_id.into(),
+                        &chain_tx_id.to_bytes(),
+                        submission_block_num.as_u32().into(),
+                    )
+                    .await?
+                    {
+                        return Ok(false);
+                    }
 
-        let threshold = threshold
-            .try_into()
-            .map(NonZeroU32::new)
-            .map_err(|_| MultisigStoreError::InvalidValue)?
-            .ok_or(MultisigStoreError::InvalidValue)?;
+                    store::update_nonce_by_tx_id(conn, tx_id.into(), account_nonce).await?;
 
-        let timestamps =
-            Timestamps::builder().created_at(created_at).updated_at(created_at).build();
+                    Ok(true)
+                })
+            })
+            .await
+        })
+        .await?;
 
-        let multisig_account = MultisigAccount::builder()
-            .address(account_id_address)
-            .network_id(network_id)
-            .kind(kind.into_inner())
-            .threshold(threshold)
-            .aux(timestamps)
-            .build();
+        if !updated {
+            return Err(MultisigStoreError::NotFound("tx id not found".into()));
+        }
 
-        Ok(Some(multisig_account))
+        Ok(())
     }
 
-    /// Retrieves all multisig accounts.
+    /// Marks a multisig transaction as failed and records why it failed.
+    ///
+    /// The status and the failure reason are updated together, so a failed transaction always
+    /// carries the reason it failed.
     ///
     /// # Errors
     ///
     /// Returns an error if:
-    /// - The database query fails
-    /// - Stored account data cannot be deserialized
-    #[tracing::instrument(skip_all)]
-    pub async fn get_all_multisig_accounts(&self) -> Result<Vec<MultisigAccount>> {
-        store::stream_multisig_accounts(&mut self.get_conn().await?)
-            .await?
-            .map_ok(make_multisig_account)
-            .map_err(From::from)
-            .map(Result::flatten)
-            .try_collect()
-            .await
+    /// - The transaction ID doesn't exist
+    /// - The database update fails
+    #[tracing::instrument(skip_all, fields(%tx_id))]
+    pub async fn update_multisig_tx_status_to_failure_by_id(
+        &self,
+        tx_id: &MultisigTxId,
+        failure_reason: &str,
+    ) -> Result<()> {
+        let conn = &mut self.get_conn().await?;
+
+        let updated = store::update_status_to_failure_with_reason_by_tx_id(
+            conn,
+            tx_id.into(),
+            failure_reason,
+        )
+        .await?;
+
+        if !updated {
+            return Err(MultisigStoreError::NotFound("tx id not found".into()));
+        }
+
+        Ok(())
     }
 
-    /// Retrieves all approvers for a multisig account address for the given network identified
-    /// by `network_id`.
+    /// Resets a failed multisig transaction to pending with a freshly computed summary.
+    ///
+    /// The summary, its commitment and the status are updated and the failure reason is cleared
+    /// in a single database transaction. All signatures collected so far are deleted, as they
+    /// were made over the previous summary commitment.
+    ///
+    /// The transaction becomes pending again, so it counts against `max_pending_txs` like a new
+    /// proposal, see [`create_multisig_tx`](Self::create_multisig_tx).
     ///
     /// # Errors
     ///
     /// Returns an error if:
-    /// - The database query fails
-    /// - Approver data cannot be deserialized
-    #[tracing::instrument(skip_all)]
-    pub async fn get_approvers_by_multisig_account_address(
+    /// - The transaction ID doesn't exist or the transaction is not failed
+    /// - The multisig account already has `max_pending_txs` pending transactions, in which case
+    ///   [`MultisigStoreError::TooManyPendingTxs`] is returned and nothing is changed
+    /// - The database transaction fails
+    #[tracing::instrument(skip_all, fields(%tx_id))]
+    pub async fn repropose_failed_multisig_tx(
         &self,
-        network_id: NetworkId,
-        multisig_account_id_address: AccountIdAddress,
-    ) -> Result<Vec<MultisigApprover>> {
-        let conn = &mut self.get_conn().await?;
-
-        let multisig_account_address =
-            Address::AccountId(multisig_account_id_address).to_bech32(network_id);
+        tx_id: &MultisigTxId,
+        tx_summary: &TransactionSummary,
+        max_pending_txs: Option<NonZeroU32>,
+    ) -> Result<()> {
+        let tx_id = Uuid::from(tx_id);
+        let tx_summary_bz = self.encode_tx_blob(tx_summary.to_bytes())?;
+        let tx_summary_commit_bz = tx_summary.to_commitment().as_bytes();
+        let tx_effect_bz = TxEffect::from(tx_summary).to_bytes();
 
-        store::stream_approvers_by_multisig_account_address(conn, &multisig_account_address)
+        let reproposal = self
+            .get_conn()
             .await?
-            .map_ok(make_multisig_approver)
-            .map_err(From::from)
-            .map(Result::flatten)
-            .try_collect()
+            .transaction(|conn| {
+                Box::pin(async move {
+                    if let Some(max) = max_pending_txs {
+                        let Some(address) =
+                            store::fetch_multisig_account_address_by_tx_id(conn, tx_id).await?
+                        else {
+                            return Ok(Reproposal::NotFound);
+                        };
+
+                        if has_max_pending_txs(conn, &address, max).await? {
+                            return Ok(Reproposal::TooManyPendingTxs { max });
+                        }
+                    }
+
+                    // the tx stays failed until reset, so its signatures are only ever deleted
+                    // along with the reset
+                    let status = store::fetch_tx_status_for_update_by_id(conn, tx_id).await?;
+
+                    if status.map(TxStatus::into_inner) != Some(MultisigTxStatus::Failure) {
+                        return Ok(Reproposal::NotFound);
+                    }
+
+                    // the commitment of a tx holding signatures cannot change
+                    store::delete_signatures_by_tx_id(conn, tx_id).await?;
+
+                    store::reset_failed_tx_to_pending_with_summary_by_tx_id(
+                        conn,
+                        tx_id,
+                        &tx_summary_bz,
+                        &tx_summary_commit_bz,
+                        &tx_effect_bz,
+                    )
+                    .await?;
+
+                    Ok(Reproposal::Reset)
+                })
+            })
             .await
+            .map_err(MultisigStoreError::from)?;
+
+        match reproposal {
+            Reproposal::Reset => Ok(()),
+            Reproposal::NotFound => {
+                Err(MultisigStoreError::NotFound("failed tx id not found".into()))
+            },
+            Reproposal::TooManyPendingTxs { max } => {
+                Err(MultisigStoreError::TooManyPendingTxs { max })
+            },
+        }
     }
 
-    /// Retrieves all transactions for a multisig account, optionally filtered by status.
+    /// Archives a multisig account, hiding it from the default
+    /// [`get_all_multisig_accounts`](Self::get_all_multisig_accounts) listing.
     ///
-    /// Fetches transactions associated with a specific account address,
-    /// with optional filtering by execution status (pending, success, failure).
+    /// Archived accounts keep their approvers and transactions, and are still retrieved by
+    /// address. Archiving an archived account refreshes its archival timestamp.
     ///
     /// # Returns
     ///
-    /// Returns a list of transactions matching the criteria.
+    /// Returns `Some(account)`, archived, if found, or `None` if the account doesn't exist.
     ///
     /// # Errors
     ///
     /// Returns an error if:
-    /// - The database query fails
-    /// - Transaction data cannot be deserialized
+    /// - The database update fails
+    /// - Stored data cannot be deserialized
     #[tracing::instrument(
         skip_all,
         fields(
             %network_id,
-            address = %address.id().to_hex(),
-        ),
+            account_id_address = %account_id_address.id().to_hex(),
+        )
     )]
-    pub async fn get_txs_by_multisig_account_address_with_status_filter<TSF>(
+    pub async fn archive_multisig_account(
         &self,
         network_id: NetworkId,
-        address: AccountIdAddress,
-        tx_status_filter: TSF, // TODO: add support to filter on multiple `tx_status_filter`
-    ) -> Result<Vec<MultisigTx>>
-    where
-        Option<MultisigTxStatus>: From<TSF>,
-    {
-        let conn = &mut self.get_conn().await?;
-
-        let address = Address::AccountId(address).to_bech32(network_id);
-
-        fn transform_into_multisig_tx(
-            stream: impl Stream<Item = Result<(TxRecord, U63), StoreError>>,
-        ) -> impl Stream<Item = Result<MultisigTx, MultisigStoreError>> {
-            stream
-                .map_err(MultisigStoreError::from)
-                .map_ok(|(tx_record, sigs_count)| make_multisig_tx(tx_record, sigs_count))
-                .map(Result::flatten)
-        }
-
-        match Option::<MultisigTxStatus>::from(tx_status_filter) {
-            Some(status) => {
-                store::stream_txs_with_signature_count_by_multisig_account_address_and_status(
-                    conn,
-                    &address,
-                    status.into(),
-                )
-                .await
-                .map(transform_into_multisig_tx)?
-                .try_collect()
-                .await
-            },
-            None => {
-                store::stream_txs_with_signature_count_by_multisig_account_address(conn, &address)
-                    .await
-                    .map(transform_into_multisig_tx)?
-                    .try_collect()
-                    .await
-            },
-        }
+        account_id_address: AccountIdAddress,
+    ) -> Result<Option<MultisigAccount>> {
+        self.update_multisig_account_archived_at(network_id, account_id_address, Some(Utc::now()))
+            .await
     }
 
-    /// Retrieves a specific multisig transaction by its ID.
+    /// Unarchives a multisig account, listing it again by default.
+    ///
+    /// Unarchiving an account which is not archived leaves it unchanged.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(account)`, unarchived, if found, or `None` if the account doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The database update fails
+    /// - Stored data cannot be deserialized
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            %network_id,
+            account_id_address = %account_id_address.id().to_hex(),
+        )
+    )]
+    pub async fn unarchive_multisig_account(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<Option<MultisigAccount>> {
+        self.update_multisig_account_archived_at(network_id, account_id_address, None)
+            .await
+    }
+
+    /// Removes an approver from a multisig account, keeping its threshold.
+    ///
+    /// The removal is refused if the total weight of the remaining approvers would fall below the
+    /// threshold, as the account could never process a transaction again. Use
+    /// [`remove_multisig_account_approver_and_lower_threshold`] to lower the threshold along with
+    /// the removal.
+    ///
+    /// The removal is refused as well while the account has transactions not executed yet, as
+    /// their signatures were collected for the current approvers. This also means no transaction
+    /// can meet a threshold lowered along with the removal without being noticed.
+    ///
+    /// The other approvers keep their index, as does the removed one, who can no longer sign nor
+    /// is listed as an approver. Transactions are thus executed against the unchanged on-chain
+    /// configuration of the account, the slot of the removed approver left without signature.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(account)`, without the approver, if found, or `None` if the account doesn't
+    /// exist or the approver is not one of its approvers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The account has transactions not executed yet, in which case
+    ///   [`MultisigStoreError::PendingTxs`] is returned and nothing is changed
+    /// - The remaining approver weight would be below the threshold, in which case
+    ///   [`MultisigStoreError::UnreachableThreshold`] is returned and nothing is changed
+    /// - The database transaction fails
+    /// - Stored data cannot be deserialized
+    ///
+    /// [`remove_multisig_account_approver_and_lower_threshold`]:
+    ///     Self::remove_multisig_account_approver_and_lower_threshold
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            %network_id,
+            account_id_address = %account_id_address.id().to_hex(),
+            approver_account_id_address = %approver_account_id_address.id().to_hex(),
+        )
+    )]
+    pub async fn remove_multisig_account_approver(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+        approver_account_id_address: AccountIdAddress,
+    ) -> Result<Option<MultisigAccount<WithApprovers, WithPubKeyCommits>>> {
+        self.remove_approver(network_id, account_id_address, approver_account_id_address, None)
+            .await
+    }
+
+    /// Removes an approver from a multisig account and lowers its threshold, atomically.
+    ///
+    /// This is how an approver is removed when the remaining approvers would not weigh enough to
+    /// meet the current threshold, e.g. from an account whose threshold equals its approver
+    /// count. The removal is refused if the total weight of the remaining approvers would still
+    /// fall below the new threshold. Approver indices and transactions not executed yet are
+    /// handled as described in [`remove_multisig_account_approver`].
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(account)`, without the approver and with the new threshold, if found, or
+    /// `None` if the account doesn't exist or the approver is not one of its approvers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The new threshold is above the current one, in which case
+    ///   [`MultisigStoreError::ThresholdRaised`] is returned and nothing is changed
+    /// - The account has transactions not executed yet, in which case
+    ///   [`MultisigStoreError::PendingTxs`] is returned and nothing is changed
+    /// - The remaining approver weight would be below the new threshold, in which case
+    ///   [`MultisigStoreError::UnreachableThreshold`] is returned and nothing is changed
+    /// - The database transaction fails
+    /// - Stored data cannot be deserialized
+    ///
+    /// [`remove_multisig_account_approver`]: Self::remove_multisig_account_approver
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            %network_id,
+            account_id_address = %account_id_address.id().to_hex(),
+            approver_account_id_address = %approver_account_id_address.id().to_hex(),
+            %threshold,
+        )
+    )]
+    pub async fn remove_multisig_account_approver_and_lower_threshold(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+        approver_account_id_address: AccountIdAddress,
+        threshold: NonZeroU32,
+    ) -> Result<Option<MultisigAccount<WithApprovers, WithPubKeyCommits>>> {
+        self.remove_approver(
+            network_id,
+            account_id_address,
+            approver_account_id_address,
+            Some(threshold),
+        )
+        .await
+    }
+
+    /// Records an event in the audit log of a multisig account.
+    ///
+    /// An event applying to a transaction is recorded in the audit log of the account of the
+    /// transaction. It isn't recorded if the transaction doesn't exist, as there is no account to
+    /// record it for.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The multisig account doesn't exist
+    /// - The database operation fails
+    #[tracing::instrument(skip_all, fields(%network_id, ?subject, %action, %outcome))]
+    pub async fn record_audit_event(
+        &self,
+        network_id: NetworkId,
+        subject: &AuditSubject,
+        actor: Option<AccountIdAddress>,
+        action: AuditAction,
+        outcome: AuditOutcome,
+        detail: Option<&str>,
+    ) -> Result<()> {
+        let conn = &mut self.get_conn().await?;
+
+        let (address, tx_id) = match subject {
+            &AuditSubject::Account(address) => (to_account_key(network_id, address), None),
+            AuditSubject::Tx(tx_id) => {
+                let Some(address) =
+                    store::fetch_multisig_account_address_by_tx_id(conn, tx_id.into()).await?
+                else {
+                    return Ok(());
+                };
+
+                (address, Some(tx_id.into()))
+            },
+        };
+
+        let actor = actor.map(|actor| to_bech32(network_id, actor));
+
+        let new_audit_event = NewAuditEventRecord::builder()
+            .multisig_account_address(&address)
+            .maybe_tx_id(tx_id)
+            .maybe_actor(actor.as_deref())
+            .action(action.into())
+            .outcome(outcome.into())
+            .maybe_detail(detail)
+            .build();
+
+        store::save_new_audit_event(conn, new_audit_event).await.map_err(From::from)
+    }
+
+    async fn update_multisig_account_archived_at(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+        archived_at: Option<DateTime<Utc>>,
+    ) -> Result<Option<MultisigAccount>> {
+        let conn = &mut self.get_conn().await?;
+
+        let address = to_account_key(network_id, account_id_address);
+
+        store::update_archived_at_by_address(conn, &address, archived_at)
+            .await?
+            .map(make_multisig_account)
+            .transpose()
+    }
+
+    async fn remove_approver(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+        approver_account_id_address: AccountIdAddress,
+        threshold: Option<NonZeroU32>,
+    ) -> Result<Option<MultisigAccount<WithApprovers, WithPubKeyCommits>>> {
+        let conn = &mut self.get_conn().await?;
+
+        let removal = store::retry_transient(async || {
+            conn.transaction(|conn| {
+                Box::pin(async move {
+                    let address = to_account_key(network_id, account_id_address);
+                    let approver_address = to_bech32(network_id, approver_account_id_address);
+
+                    // locking the account serializes concurrent removals, which could otherwise
+                    // each leave enough weight on their own but not together
+                    let Some(multisig_account_record) =
+                        store::fetch_mutisig_account_for_update_by_address(conn, &address).await?
+                    else {
+                        return Ok(ApproverRemoval::NotFound);
+                    };
+
+                    let MultisigAccountRecordDissolved { threshold: current_threshold, .. } =
+                        multisig_account_record.dissolve();
+
+                    let approvers =
+                        store::fetch_approver_weights_by_multisig_account_address(conn, &address)
+                            .await?;
+
+                    if !approvers.iter().any(|(address, ..)| *address == approver_address) {
+                        return Ok(ApproverRemoval::NotFound);
+                    }
+
+                    let threshold = threshold.map_or(current_threshold, |t| t.get().into());
+
+                    if threshold > current_threshold {
+                        return Ok(ApproverRemoval::ThresholdRaised {
+                            threshold: threshold.unsigned_abs(),
+                            current: current_threshold.unsigned_abs(),
+                        });
+                    }
+
+                    // the account row is locked, so no transaction can be proposed meanwhile
+                    if store::exists_unexecuted_tx_by_multisig_account_address(conn, &address)
+                        .await?
+                    {
+                        return Ok(ApproverRemoval::PendingTxs);
+                    }
+
+                    let remaining_weight: i64 = approvers
+                        .iter()
+                        .filter(|(address, ..)| *address != approver_address)
+                        .map(|&(_, _, weight)| i64::from(weight))
+                        .sum();
+
+                    if remaining_weight < threshold {
+                        return Ok(ApproverRemoval::UnreachableThreshold {
+                            threshold: threshold.unsigned_abs(),
+                            remaining_weight: remaining_weight.unsigned_abs(),
+                        });
+                    }
+
+                    store::update_multisig_account_approver_mapping_to_removed(
+                        conn,
+                        &address,
+                        &approver_address,
+                    )
+                    .await?;
+
+                    if threshold != current_threshold {
+                        store::update_threshold_by_address(conn, &address, threshold).await?;
+                    }
+
+                    Ok(ApproverRemoval::Removed)
+                })
+            })
+            .await
+        })
+        .await?;
+
+        match removal {
+            ApproverRemoval::Removed => {
+                // the removal is read back from the primary, a replica may not have caught up yet
+                self.primary().try_get_multisig_account(network_id, account_id_address).await
+            },
+            ApproverRemoval::NotFound => Ok(None),
+            ApproverRemoval::PendingTxs => Err(MultisigStoreError::PendingTxs),
+            ApproverRemoval::ThresholdRaised { threshold, current } => {
+                Err(MultisigStoreError::ThresholdRaised { threshold, current })
+            },
+            ApproverRemoval::UnreachableThreshold { threshold, remaining_weight } => {
+                Err(MultisigStoreError::UnreachableThreshold { threshold, remaining_weight })
+            },
+        }
+    }
+
+    /// Retrieves a multisig account by its address.
+    ///
+    /// This method fetches the basic account information (address, network, kind, threshold)
+    /// but does not include the approvers or public key commitments. Archived accounts are
+    /// retrieved too. Accounts are keyed by account id, so the interface of the given address is
+    /// ignored and the returned address has the interface the account was created with.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(account)` if found, or `None` if the account doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The database query fails
+    /// - Stored data cannot be deserialized
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            %network_id,
+            account_id_address = %account_id_address.id().to_hex(),
+        )
+    )]
+    pub async fn get_multisig_account(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<Option<MultisigAccount>> {
+        let conn = &mut self.get_read_conn().await?;
+
+        let address = to_account_key(network_id, account_id_address);
+
+        store::fetch_mutisig_account_by_address(conn, &address)
+            .await?
+            .map(make_multisig_account)
+            .transpose()
+    }
+
+    /// Retrieves the threshold and the number of approvers of a multisig account.
+    ///
+    /// This is a cheaper alternative to [`get_multisig_account`](Self::get_multisig_account)
+    /// followed by
+    /// [`get_approvers_by_multisig_account_address`](Self::get_approvers_by_multisig_account_address)
+    /// for callers only rendering e.g. "2 of 3", as the approvers are counted within a single
+    /// query instead of being loaded.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some((threshold, approver_count))` if found, or `None` if the account doesn't
+    /// exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The database query fails
+    /// - Stored data cannot be deserialized
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            %network_id,
+            account_id_address = %account_id_address.id().to_hex(),
+        )
+    )]
+    pub async fn get_account_summary(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<Option<(NonZeroU32, u32)>> {
+        let conn = &mut self.get_read_conn().await?;
+
+        let address = to_account_key(network_id, account_id_address);
+
+        let Some((threshold, approver_count)) =
+            store::fetch_multisig_account_threshold_with_approver_count_by_address(conn, &address)
+                .await?
+        else {
+            return Ok(None);
+        };
+
+        let threshold = threshold
+            .try_into()
+            .map(NonZeroU32::new)
+            .map_err(|_| MultisigStoreError::InvalidValue)?
+            .ok_or(MultisigStoreError::InvalidValue)?;
+
+        let approver_count =
+            approver_count.try_into().map_err(|_| MultisigStoreError::InvalidValue)?;
+
+        Ok(Some((threshold, approver_count)))
+    }
+
+    /// Retrieves a multisig account by its address together with its approvers and their public
+    /// key commitments.
+    ///
+    /// Unlike [`get_multisig_account`](Self::get_multisig_account) followed by
+    /// [`get_approvers_by_multisig_account_address`](Self::get_approvers_by_multisig_account_address),
+    /// this method loads everything using a single joined query, so the approvers always agree with
+    /// the account they belong to. Approvers and public key commitments are ordered by approver
+    /// index.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(account)` if found, or `None` if the account doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The database query fails
+    /// - Stored account or approver data cannot be deserialized
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            %network_id,
+            account_id_address = %account_id_address.id().to_hex(),
+        )
+    )]
+    pub async fn try_get_multisig_account(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<Option<MultisigAccount<WithApprovers, WithPubKeyCommits>>> {
+        let conn = &mut self.get_read_conn().await?;
+
+        let address = to_account_key(network_id, account_id_address);
+
+        let records =
+            store::fetch_multisig_account_with_approvers_by_address(conn, &address).await?;
+
+        let mut multisig_account_record = None;
+        let mut approvers = Vec::with_capacity(records.len());
+        let mut pub_key_commits = Vec::with_capacity(records.len());
+        let mut weights = Vec::with_capacity(records.len());
+        let mut labels = Vec::with_capacity(records.len());
+
+        for (account_record, approver_record, weight, label) in records {
+            multisig_account_record.get_or_insert(account_record);
+
+            let MultisigApproverDissolved { address, pub_key_commit, .. } =
+                make_multisig_approver(approver_record, None)?.dissolve();
+
+            let weight = u32::try_from(weight)
+                .ok()
+                .and_then(NonZeroU32::new)
+                .ok_or(MultisigStoreError::InvalidValue)?;
+
+            approvers.push(address);
+            pub_key_commits.push(pub_key_commit);
+            weights.push(weight);
+            labels.push(label);
+        }
+
+        let Some(multisig_account_record) = multisig_account_record else {
+            return Ok(None);
+        };
+
+        make_multisig_account(multisig_account_record)?
+            .with_weighted_approvers(approvers, weights)
+            .ok_or(MultisigStoreError::InvalidValue)?
+            .with_approver_labels(labels)
+            .ok_or(MultisigStoreError::InvalidValue)?
+            .with_pub_key_commits(pub_key_commits)
+            .ok_or(MultisigStoreError::InvalidValue)
+            .map(Some)
+    }
+
+    /// Retrieves all multisig accounts.
+    ///
+    /// Archived accounts are only retrieved if `include_archived` is set. At most
+    /// [`with_max_all_multisig_accounts`](Self::with_max_all_multisig_accounts) accounts are
+    /// retrieved at once, beyond which they are to be paged through with
+    /// [`stream_multisig_accounts_paged`](Self::stream_multisig_accounts_paged).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - There are more accounts than the maximum, see [`MultisigStoreError::ResultTooLarge`]
+    /// - The database query fails
+    /// - Stored account data cannot be deserialized
+    #[tracing::instrument(skip_all, fields(%include_archived))]
+    pub async fn get_all_multisig_accounts(
+        &self,
+        include_archived: bool,
+    ) -> Result<Vec<MultisigAccount>> {
+        let conn = &mut self.get_read_conn().await?;
+
+        let max = self.max_all_multisig_accounts;
+
+        // one more account than the maximum tells whether there are too many
+        let multisig_accounts: Vec<_> = store::stream_multisig_accounts(
+            conn,
+            include_archived,
+            None,
+            Some(i64::from(max.get()) + 1),
+        )
+        .await?
+        .map_ok(make_multisig_account)
+        .map_err(MultisigStoreError::from)
+        .map(Result::flatten)
+        .try_collect()
+        .await?;
+
+        if multisig_accounts.len() > max.get() as usize {
+            return Err(MultisigStoreError::ResultTooLarge { max });
+        }
+
+        Ok(multisig_accounts)
+    }
+
+    /// Streams all multisig accounts, in creation order, retrieving them `page_size` at a time.
+    ///
+    /// Unlike [`get_all_multisig_accounts`](Self::get_all_multisig_accounts), the number of
+    /// accounts is not capped, as at most a page of them is held at once. Each page is retrieved
+    /// with its own connection and starts right after the last account of the previous one, so
+    /// no account is skipped nor streamed twice when accounts are created while streaming, the
+    /// ones created after the stream started may be streamed or not. Archived accounts are only
+    /// streamed if `include_archived` is set.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields an error if:
+    /// - The database query fails
+    /// - Stored account data cannot be deserialized
+    pub fn stream_multisig_accounts_paged(
+        &self,
+        include_archived: bool,
+        page_size: NonZeroU32,
+    ) -> BoxStream<'_, Result<MultisigAccount>> {
+        let page_size = i64::from(page_size.get());
+
+        // the outer option tells whether there is a page left, the inner one is its cursor
+        futures::stream::try_unfold(Some(None), move |after| async move {
+            let Some(after) = after else {
+                return Ok(None);
+            };
+
+            let conn = &mut self.get_read_conn().await?;
+
+            let records: Vec<_> =
+                store::stream_multisig_accounts(conn, include_archived, after, Some(page_size))
+                    .await?
+                    .try_collect()
+                    .await?;
+
+            // a partial page is the last one
+            let next_after = (records.len() as i64 == page_size).then(|| {
+                records
+                    .last()
+                    .map(|record| Cursor::new(record.created_at(), record.address().to_string()))
+            });
+
+            let page = records.into_iter().map(make_multisig_account);
+
+            Ok(Some((futures::stream::iter(page), next_after)))
+        })
+        .try_flatten()
+        .boxed()
+    }
+
+    /// Retrieves a page of multisig accounts, in creation order, along with their approver count
+    /// and the number of their transactions not executed yet, i.e. pending or ready to execute.
+    ///
+    /// Pages are walked with keyset pagination: `after` is the cursor returned along with the
+    /// previous page, and `limit` bounds the page size. Without a `limit`, all the accounts after
+    /// the cursor are returned as a single page. Archived accounts are only retrieved if
+    /// `include_archived` is set.
+    ///
+    /// # Returns
+    ///
+    /// Returns `(account, approver_count, pending_tx_count)` entries, along with the cursor of the
+    /// next page if the page is full.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The database query fails
+    /// - Stored account data cannot be deserialized
+    #[tracing::instrument(skip_all, fields(%include_archived))]
+    pub async fn get_multisig_accounts_with_counts(
+        &self,
+        include_archived: bool,
+        after: Option<Cursor<String>>,
+        limit: Option<NonZeroU32>,
+    ) -> Result<(Vec<(MultisigAccount, u32, u64)>, Option<Cursor<String>>)> {
+        let conn = &mut self.get_read_conn().await?;
+
+        let records: Vec<_> = store::stream_multisig_accounts(
+            conn,
+            include_archived,
+            after,
+            limit.map(|limit| limit.get().into()),
+        )
+        .await?
+        .try_collect()
+        .await?;
+
+        let next_cursor = limit
+            .filter(|limit| usize::try_from(limit.get()).is_ok_and(|limit| limit == records.len()))
+            .and(records.last())
+            .map(|record| Cursor::new(record.created_at(), record.address().to_string()));
+
+        let multisig_accounts: Vec<_> =
+            records.into_iter().map(make_multisig_account).collect::<Result<_>>()?;
+
+        let addresses: Vec<_> = multisig_accounts
+            .iter()
+            .map(|multisig_account| {
+                to_account_key(multisig_account.network_id(), multisig_account.address())
+            })
+            .collect();
+
+        let approver_counts: HashMap<_, _> =
+            store::fetch_approver_counts_by_multisig_account_addresses(conn, &addresses)
+                .await?
+                .into_iter()
+                .collect();
+
+        let pending_tx_counts: HashMap<_, _> =
+            store::fetch_unexecuted_tx_counts_by_multisig_account_addresses(conn, &addresses)
+                .await?
+                .into_iter()
+                .collect();
+
+        let multisig_accounts = multisig_accounts
+            .into_iter()
+            .zip(&addresses)
+            .map(|(multisig_account, address)| {
+                let approver_count = approver_counts.get(address).copied().unwrap_or(0);
+                let pending_tx_count = pending_tx_counts.get(address).copied().unwrap_or(0);
+
+                let approver_count =
+                    approver_count.try_into().map_err(|_| MultisigStoreError::InvalidValue)?;
+                let pending_tx_count =
+                    pending_tx_count.try_into().map_err(|_| MultisigStoreError::InvalidValue)?;
+
+                Ok((multisig_account, approver_count, pending_tx_count))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok((multisig_accounts, next_cursor))
+    }
+
+    /// Retrieves the approvers for a multisig account address for the given network identified
+    /// by `network_id`, in approver index order.
+    ///
+    /// Pages are walked with keyset pagination: `after` is the cursor returned along with the
+    /// previous page, and `limit` bounds the page size. Without a `limit`, all the approvers after
+    /// the cursor are returned as a single page. If `label_contains` is given, only the approvers
+    /// whose label contains it, ignoring case, are retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns the approvers, along with the cursor of the next page if the page is full.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The database query fails
+    /// - Approver data cannot be deserialized
+    #[tracing::instrument(skip_all)]
+    pub async fn get_approvers_by_multisig_account_address(
+        &self,
+        network_id: NetworkId,
+        multisig_account_id_address: AccountIdAddress,
+        label_contains: Option<&str>,
+        after: Option<Cursor<String>>,
+        limit: Option<NonZeroU32>,
+    ) -> Result<(Vec<MultisigApprover>, Option<Cursor<String>>)> {
+        let conn = &mut self.get_read_conn().await?;
+
+        let multisig_account_address = to_account_key(network_id, multisig_account_id_address);
+
+        let rows: Vec<_> = store::stream_approvers_by_multisig_account_address(
+            conn,
+            &multisig_account_address,
+            false,
+            label_contains,
+            after,
+            limit.map(|limit| limit.get().into()),
+        )
+        .await?
+        .try_collect()
+        .await?;
+
+        // approver indexes are never negative
+        let next_cursor = limit
+            .filter(|limit| usize::try_from(limit.get()).is_ok_and(|limit| limit == rows.len()))
+            .and(rows.last())
+            .map(|(approver_record, approver_index, _)| {
+                let approver_index = u64::try_from(*approver_index).unwrap_or_default();
+
+                Cursor::with_count(approver_index, approver_record.address().to_string())
+            });
+
+        let approvers = rows
+            .into_iter()
+            .map(|(approver_record, _, label)| make_multisig_approver(approver_record, label))
+            .collect::<Result<_>>()?;
+
+        Ok((approvers, next_cursor))
+    }
+
+    /// Retrieves the approvers of a multisig account along with their approver index, i.e. the
+    /// slot of their signature among the signatures the account's transactions are executed with.
+    /// Removed approvers are left out, their slot is not reused.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(approvers)` in approver index order if the account exists, `None` otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The database query fails
+    /// - Approver data cannot be deserialized, or an approver index is out of range
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            %network_id,
+            account_id_address = %account_id_address.id().to_hex(),
+        ),
+    )]
+    pub async fn get_indexed_approvers_by_multisig_account_address(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<Option<Vec<(u32, MultisigApprover)>>> {
+        let conn = &mut self.get_read_conn().await?;
+
+        let address = to_account_key(network_id, account_id_address);
+
+        if store::fetch_mutisig_account_by_address(conn, &address).await?.is_none() {
+            return Ok(None);
+        }
+
+        store::stream_approvers_by_multisig_account_address(conn, &address, false, None, None, None)
+            .await?
+            .map_ok(|(approver_record, approver_index, label)| -> Result<_> {
+                let approver_index =
+                    u32::try_from(approver_index).map_err(|_| MultisigStoreError::InvalidValue)?;
+
+                Ok((approver_index, make_multisig_approver(approver_record, label)?))
+            })
+            .map_err(From::from)
+            .map(Result::flatten)
+            .try_collect()
+            .await
+            .map(Some)
+    }
+
+    /// Retrieves a page of transactions for a multisig account, optionally filtered by status.
+    ///
+    /// Fetches transactions associated with a specific account address in the `sort` order, i.e.
+    /// most recent first by default, with optional filtering by execution status (pending,
+    /// success, failure).
+    ///
+    /// Pages are walked with keyset pagination: `after` is the cursor returned along with the
+    /// previous page, and `limit` bounds the page size. Without a `limit`, all the transactions
+    /// after the cursor are returned as a single page. The cursor is only valid for the `sort`
+    /// order it was returned for.
+    ///
+    /// # Returns
+    ///
+    /// Returns the transactions matching the criteria, each paired with its ID and deserialized
+    /// separately so that a single undeserializable transaction doesn't fail the whole page,
+    /// along with the cursor of the next page if the page is full.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            %network_id,
+            address = %address.id().to_hex(),
+        ),
+    )]
+    pub async fn get_txs_by_multisig_account_address_with_status_filter<TSF>(
+        &self,
+        network_id: NetworkId,
+        address: AccountIdAddress,
+        tx_status_filter: TSF, // TODO: add support to filter on multiple `tx_status_filter`
+        sort: TxSort,
+        after: Option<Cursor>,
+        limit: Option<NonZeroU32>,
+    ) -> Result<(Vec<(MultisigTxId, Result<MultisigTx>)>, Option<Cursor>)>
+    where
+        Option<MultisigTxStatus>: From<TSF>,
+    {
+        let conn = &mut self.get_read_conn().await?;
+
+        let address = to_account_key(network_id, address);
+
+        // an unknown account has no txs to list
+        let Some(MultisigAccountRecordDissolved { threshold, interface, .. }) =
+            store::fetch_mutisig_account_by_address(conn, &address)
+                .await?
+                .map(MultisigAccountRecord::dissolve)
+        else {
+            return Ok((Vec::new(), None));
+        };
+
+        let interface = interface.into_inner();
+
+        let txs = store::fetch_txs_with_signature_count_by_multisig_account_address(
+            conn,
+            &address,
+            Option::<MultisigTxStatus>::from(tx_status_filter).map(From::from),
+            sort,
+            after,
+            limit.map(|limit| limit.get().into()),
+        )
+        .await?;
+
+        // the cursor carries the sort key of the last tx, as it may change before the next page
+        let next_cursor = limit
+            .filter(|limit| usize::try_from(limit.get()).is_ok_and(|limit| limit == txs.len()))
+            .and(txs.last())
+            .map(|(tx_record, signature_count)| match sort.field() {
+                TxSortField::CreatedAt => Cursor::new(tx_record.created_at(), tx_record.id()),
+                TxSortField::UpdatedAt => Cursor::new(tx_record.updated_at(), tx_record.id()),
+                TxSortField::SignatureCount => {
+                    Cursor::with_count(signature_count.get(), tx_record.id())
+                },
+            });
+
+        let txs =
+            make_multisig_txs_with_input_note_ids(conn, txs, |_| (threshold, interface)).await?;
+
+        Ok((txs, next_cursor))
+    }
+
+    /// Retrieves a page of the pending transactions of every multisig account not archived that an
+    /// approver belongs to, most recent first.
+    ///
+    /// This is the feed of the proposals awaiting the approver's signature across all of their
+    /// accounts. Pages are walked with keyset pagination: `after` is the cursor returned along
+    /// with the previous page, and `limit` bounds the page size. Without a `limit`, all the
+    /// transactions after the cursor are returned as a single page.
+    ///
+    /// # Returns
+    ///
+    /// Returns the pending transactions, each paired with its ID and deserialized separately so
+    /// that a single undeserializable transaction doesn't fail the whole page, along with whether
+    /// the approver has already signed it, and the cursor of the next page if the page is full.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            %network_id,
+            approver_address = %approver_address.id().to_hex(),
+        ),
+    )]
+    pub async fn get_pending_txs_for_approver(
+        &self,
+        network_id: NetworkId,
+        approver_address: AccountIdAddress,
+        after: Option<Cursor>,
+        limit: Option<NonZeroU32>,
+    ) -> Result<(Vec<(MultisigTxId, Result<MultisigTx>, bool)>, Option<Cursor>)> {
+        let conn = &mut self.get_read_conn().await?;
+
+        let approver_address = to_bech32(network_id, approver_address);
+
+        let txs = store::fetch_pending_txs_with_signature_count_by_approver_address(
+            conn,
+            &approver_address,
+            after,
+            limit.map(|limit| limit.get().into()),
+        )
+        .await?;
+
+        let next_cursor = limit
+            .filter(|limit| usize::try_from(limit.get()).is_ok_and(|limit| limit == txs.len()))
+            .and(txs.last())
+            .map(|(tx_record, _)| Cursor::new(tx_record.created_at(), tx_record.id()));
+
+        let tx_ids: Vec<_> = txs.iter().map(|(tx_record, _)| tx_record.id()).collect();
+        let signed_tx_ids: HashSet<_> =
+            store::fetch_signed_tx_ids_by_approver_address(conn, &approver_address, &tx_ids)
+                .await?
+                .into_iter()
+                .collect();
+
+        let mut multisig_account_addresses: Vec<_> = txs
+            .iter()
+            .map(|(tx_record, _)| tx_record.multisig_account_address().to_owned())
+            .collect();
+        multisig_account_addresses.sort_unstable();
+        multisig_account_addresses.dedup();
+
+        let accounts: HashMap<_, _> =
+            store::fetch_thresholds_and_interfaces_by_multisig_account_addresses(
+                conn,
+                &multisig_account_addresses,
+            )
+            .await?
+            .into_iter()
+            .map(|(address, threshold, interface)| (address, (threshold, interface.into_inner())))
+            .collect();
+
+        // a tx always belongs to a known account, a missing threshold fails that tx alone
+        let txs = make_multisig_txs_with_input_note_ids(conn, txs, |tx_record| {
+            accounts
+                .get(tx_record.multisig_account_address())
+                .copied()
+                .unwrap_or((0, AddressInterface::BasicWallet))
+        })
+        .await?
+        .into_iter()
+        .map(|(tx_id, tx)| {
+            let signed = signed_tx_ids.contains(&Uuid::from(&tx_id));
+            (tx_id, tx, signed)
+        })
+        .collect();
+
+        Ok((txs, next_cursor))
+    }
+
+    /// Retrieves a specific multisig transaction by its ID.
     ///
     /// # Returns
     ///
@@ -514,16 +1812,212 @@ impl MultisigStore {
     /// - Transaction data cannot be deserialized
     #[tracing::instrument(skip_all, fields(%id))]
     pub async fn get_multisig_tx_by_id(&self, id: &MultisigTxId) -> Result<Option<MultisigTx>> {
-        store::fetch_tx_with_signature_count_by_id(&mut self.get_conn().await?, id.into())
-            .await?
-            .map(|(tx_record, sigs_count)| make_multisig_tx(tx_record, sigs_count))
-            .transpose()
+        let conn = &mut self.get_read_conn().await?;
+
+        let Some((tx_record, sigs_count)) =
+            store::fetch_tx_with_signature_count_by_id(conn, id.into()).await?
+        else {
+            return Ok(None);
+        };
+
+        let input_note_ids = store::fetch_input_note_ids_by_tx_id(conn, id.into()).await?;
+
+        let account = fetch_tx_account_by_multisig_account_address(
+            conn,
+            tx_record.multisig_account_address(),
+        )
+        .await?;
+
+        make_multisig_tx(tx_record, sigs_count, input_note_ids, account).map(Some)
+    }
+
+    /// Retrieves the address of the multisig account a transaction belongs to.
+    ///
+    /// This is a cheaper alternative to [`get_multisig_tx_by_id`](Self::get_multisig_tx_by_id)
+    /// for callers only interested in the account, as the transaction data is not loaded.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(address)` if found, or `None` if the transaction doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The database query fails
+    /// - The stored address cannot be parsed
+    #[tracing::instrument(skip_all, fields(%id))]
+    pub async fn get_multisig_account_address_by_tx_id(
+        &self,
+        id: &MultisigTxId,
+    ) -> Result<Option<AccountIdAddress>> {
+        let conn = &mut self.get_read_conn().await?;
+
+        let Some(address) = store::fetch_multisig_account_address_by_tx_id(conn, id.into()).await?
+        else {
+            return Ok(None);
+        };
+
+        let (_, interface) = fetch_tx_account_by_multisig_account_address(conn, &address).await?;
+
+        extract_network_id_account_id_address_pair(&address)
+            .map(|(_, address)| Some(AccountIdAddress::new(address.id(), interface)))
+            .map_err(|e| MultisigStoreError::Other(e.to_string().into()))
+    }
+
+    /// Retrieves a multisig transaction by the commitment of its summary, i.e. the message its
+    /// approvers sign.
+    ///
+    /// This lets approvers signing offline, who only know what they sign, find the transaction
+    /// they are signing.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(transaction)` if found, the most recent one if several transactions share
+    /// the commitment, or `None` if no transaction has this commitment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The database query fails
+    /// - Transaction data cannot be deserialized
+    #[tracing::instrument(skip_all)]
+    pub async fn get_multisig_tx_by_summary_commit(
+        &self,
+        tx_summary_commit: Word,
+    ) -> Result<Option<MultisigTx>> {
+        let conn = &mut self.get_read_conn().await?;
+
+        let tx_summary_commit_bz = tx_summary_commit.as_bytes();
+
+        let Some((tx_record, sigs_count)) =
+            store::fetch_tx_with_signature_count_by_summary_commit(conn, &tx_summary_commit_bz)
+                .await?
+        else {
+            return Ok(None);
+        };
+
+        let input_note_ids = store::fetch_input_note_ids_by_tx_id(conn, tx_record.id()).await?;
+
+        let account = fetch_tx_account_by_multisig_account_address(
+            conn,
+            tx_record.multisig_account_address(),
+        )
+        .await?;
+
+        make_multisig_tx(tx_record, sigs_count, input_note_ids, account).map(Some)
+    }
+
+    /// Checks whether an approver has signed a multisig transaction.
+    ///
+    /// This is a cheap alternative to
+    /// [`get_signatures_of_all_approvers_with_multisig_tx_by_tx_id`](Self::get_signatures_of_all_approvers_with_multisig_tx_by_tx_id)
+    /// for callers only interested in a single approver, e.g. to tell whether the approver can
+    /// still sign, as the signature is looked up with an `EXISTS` query instead of being loaded.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the approver signed the transaction, `false` otherwise, including when
+    /// the transaction doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            %tx_id,
+            %network_id,
+            approver_account_id_address = %approver_account_id_address.id().to_hex(),
+        ),
+    )]
+    pub async fn has_approver_signed(
+        &self,
+        tx_id: &MultisigTxId,
+        network_id: NetworkId,
+        approver_account_id_address: AccountIdAddress,
+    ) -> Result<bool> {
+        let conn = &mut self.get_read_conn().await?;
+
+        let approver_address = to_bech32(network_id, approver_account_id_address);
+
+        store::exists_signature_by_tx_id_and_approver_address(conn, tx_id.into(), &approver_address)
+            .await
+            .map_err(From::from)
+    }
+
+    /// Checks whether an account is an approver of the multisig account of a transaction.
+    ///
+    /// This is a cheap read-only check, e.g. to reject the signature of a non-approver before
+    /// opening the write transaction adding it. Adding a signature still validates the approver
+    /// on its own.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Some(true))` if the account is an approver of the transaction's multisig account
+    /// - `Ok(Some(false))` if it is not
+    /// - `Ok(None)` if the transaction doesn't exist
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            %tx_id,
+            %network_id,
+            approver_account_id_address = %approver_account_id_address.id().to_hex(),
+        ),
+    )]
+    pub async fn is_approver_of_tx(
+        &self,
+        tx_id: &MultisigTxId,
+        network_id: NetworkId,
+        approver_account_id_address: AccountIdAddress,
+    ) -> Result<Option<bool>> {
+        let conn = &mut self.get_read_conn().await?;
+
+        if !store::exists_tx_by_id(conn, tx_id.into()).await? {
+            return Ok(None);
+        }
+
+        let approver_address = to_bech32(network_id, approver_account_id_address);
+
+        store::validate_approver_address_by_tx_id(conn, tx_id.into(), &approver_address)
+            .await
+            .map(Some)
+            .map_err(From::from)
+    }
+
+    /// Counts the pending transactions of a multisig account, i.e. the proposals still awaiting
+    /// signatures.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    #[tracing::instrument(
+        skip_all,
+        fields(%network_id, account_id_address = %account_id_address.id().to_hex()),
+    )]
+    pub async fn count_pending_multisig_txs_by_multisig_account_address(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<u64> {
+        let conn = &mut self.get_read_conn().await?;
+
+        let address = to_account_key(network_id, account_id_address);
+
+        store::count_pending_txs_by_multisig_account_address(conn, &address)
+            .await
+            .map_err(From::from)
     }
 
     /// Retrieves aggregated transaction statistics for a multisig account.
     ///
     /// Computes and returns summary statistics (e.g., counts by status) for all
-    /// transactions associated with the provided multisig account address.
+    /// transactions associated with the provided multisig account address, along with the
+    /// number of those transactions signed by each approver, and the average and 95th percentile
+    /// of the time the executed ones took to meet their threshold once proposed.
     ///
     /// # Returns
     ///
@@ -531,24 +2025,212 @@ impl MultisigStore {
     ///
     /// # Errors
     ///
-    /// Returns an error if the database query fails.
+    /// Returns an error if:
+    /// - The database query fails
+    /// - An approver address cannot be parsed
     #[tracing::instrument(skip(self))]
     pub async fn get_multisig_tx_stats_by_multisig_account_address(
         &self,
         network_id: NetworkId,
         multisig_account_id_address: AccountIdAddress,
     ) -> Result<MultisigTxStats> {
-        let conn = &mut self.get_conn().await?;
-        let address = Address::AccountId(multisig_account_id_address).to_bech32(network_id);
+        let address = to_account_key(network_id, multisig_account_id_address);
+
+        let ((total, last_month, total_success), approver_signed_counts, time_to_threshold) = self
+            .get_read_conn()
+            .await?
+            .transaction(|conn| {
+                Box::pin(async move {
+                    let counts =
+                        store::fetch_tx_stats_by_multisig_account_address(conn, &address).await?;
+
+                    let approver_signed_counts =
+                        store::fetch_approver_signed_counts_by_multisig_account_address(
+                            conn, &address,
+                        )
+                        .await?;
 
-        store::fetch_tx_stats_by_multisig_account_address(conn, &address)
+                    let time_to_threshold =
+                        store::fetch_time_to_threshold_stats_by_multisig_account_address(
+                            conn, &address,
+                        )
+                        .await?;
+
+                    Ok((counts, approver_signed_counts, time_to_threshold))
+                })
+            })
             .await
-            .map_err(From::from)
+            .map_err(MultisigStoreError::from)?;
+
+        let approver_signed_counts = approver_signed_counts
+            .into_iter()
+            .map(|(approver_address, count)| {
+                extract_network_id_account_id_address_pair(&approver_address)
+                    .map(|(_, approver_address)| (approver_address, count))
+                    .map_err(|e| MultisigStoreError::Other(e.to_string().into()))
+            })
+            .collect::<Result<_>>()?;
+
+        // a negative duration can only come from clock skew, it is left out rather than clamped
+        let (avg_time_to_threshold, p95_time_to_threshold) = time_to_threshold;
+        let to_duration =
+            |secs: Option<f64>| secs.and_then(|secs| Duration::try_from_secs_f64(secs).ok());
+
+        let tx_stats = MultisigTxStats::builder()
+            .total(total)
+            .last_month(last_month)
+            .total_success(total_success)
+            .approver_signed_counts(approver_signed_counts)
+            .maybe_avg_time_to_threshold(to_duration(avg_time_to_threshold))
+            .maybe_p95_time_to_threshold(to_duration(p95_time_to_threshold))
+            .build();
+
+        Ok(tx_stats)
+    }
+
+    /// Retrieves the number of transactions of a multisig account proposed in each day or week,
+    /// e.g. to chart the activity of the account.
+    ///
+    /// # Returns
+    ///
+    /// Returns the `(bucket_start, tx_count)` of every bucket holding at least one transaction,
+    /// oldest first. Buckets start at midnight UTC, on Mondays for weeks.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    #[tracing::instrument(skip(self))]
+    pub async fn get_tx_trend_by_multisig_account_address(
+        &self,
+        network_id: NetworkId,
+        multisig_account_id_address: AccountIdAddress,
+        granularity: TrendGranularity,
+    ) -> Result<Vec<(DateTime<Utc>, u64)>> {
+        let conn = &mut self.get_read_conn().await?;
+
+        let address = to_account_key(network_id, multisig_account_id_address);
+
+        // the canonical string form of a granularity is the matching `date_trunc` field
+        store::fetch_tx_counts_by_multisig_account_address_bucketed(
+            conn,
+            &address,
+            granularity.into(),
+        )
+        .await
+        .map_err(From::from)
+    }
+
+    /// Retrieves the most recent signatures added to any transaction of a multisig account.
+    ///
+    /// Signatures are joined with their transactions and approvers in a single query, so only
+    /// the `limit` most recent signing events are loaded.
+    ///
+    /// # Returns
+    ///
+    /// Returns up to `limit` `(tx_id, approver_address, signed_at)` entries, most recent first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The database query fails
+    /// - An approver address cannot be parsed
+    #[tracing::instrument(skip(self))]
+    pub async fn get_recent_signatures_by_account(
+        &self,
+        network_id: NetworkId,
+        multisig_account_id_address: AccountIdAddress,
+        limit: NonZeroU32,
+    ) -> Result<Vec<(MultisigTxId, AccountIdAddress, DateTime<Utc>)>> {
+        let conn = &mut self.get_read_conn().await?;
+
+        let address = to_account_key(network_id, multisig_account_id_address);
+
+        store::fetch_recent_signatures_by_multisig_account_address(
+            conn,
+            &address,
+            limit.get().into(),
+        )
+        .await?
+        .into_iter()
+        .map(|(tx_id, approver_address, signed_at)| {
+            extract_network_id_account_id_address_pair(&approver_address)
+                .map(|(_, approver_address)| (tx_id.into(), approver_address, signed_at))
+                .map_err(|e| MultisigStoreError::Other(e.to_string().into()))
+        })
+        .collect()
+    }
+
+    /// Retrieves a page of the audit log of a multisig account, most recent first.
+    ///
+    /// Pages are walked with keyset pagination: `after` is the cursor returned along with the
+    /// previous page, and `limit` bounds the page size. Without a `limit`, all the events after
+    /// the cursor are returned as a single page.
+    ///
+    /// # Returns
+    ///
+    /// Returns the audit events, along with the cursor of the next page if the page is full.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The database query fails
+    /// - A stored address cannot be parsed
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            %network_id,
+            account_id_address = %account_id_address.id().to_hex(),
+        )
+    )]
+    pub async fn get_audit_events_by_multisig_account_address(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+        after: Option<Cursor>,
+        limit: Option<NonZeroU32>,
+    ) -> Result<(Vec<AuditEvent>, Option<Cursor>)> {
+        let conn = &mut self.get_read_conn().await?;
+
+        let address = to_account_key(network_id, account_id_address);
+
+        // an unknown account has no events to list
+        let Some(MultisigAccountRecordDissolved { interface, .. }) =
+            store::fetch_mutisig_account_by_address(conn, &address)
+                .await?
+                .map(MultisigAccountRecord::dissolve)
+        else {
+            return Ok((Vec::new(), None));
+        };
+
+        let interface = interface.into_inner();
+
+        let audit_event_records = store::fetch_audit_events_by_multisig_account_address(
+            conn,
+            &address,
+            after,
+            limit.map(|limit| limit.get().into()),
+        )
+        .await?;
+
+        let next_cursor = limit
+            .filter(|limit| {
+                usize::try_from(limit.get()).is_ok_and(|limit| limit == audit_event_records.len())
+            })
+            .and(audit_event_records.last())
+            .map(|record| Cursor::new(record.created_at(), record.id()));
+
+        let audit_events = audit_event_records
+            .into_iter()
+            .map(|record| make_audit_event(record, interface))
+            .collect::<Result<_>>()?;
+
+        Ok((audit_events, next_cursor))
     }
 
     /// Retrieves an approver by their account address.
     ///
-    /// This method looks up an approver's information including their public key commitment.
+    /// This method looks up an approver's information including their public key commitment. The
+    /// approver is not looked up through a multisig account, so it carries no label.
     ///
     /// # Returns
     ///
@@ -571,76 +2253,332 @@ impl MultisigStore {
         network_id: NetworkId,
         approver_account_id_address: AccountIdAddress,
     ) -> Result<Option<MultisigApprover>> {
-        let address = Address::AccountId(approver_account_id_address).to_bech32(network_id);
-        store::fetch_approver_by_approver_address(&mut self.get_conn().await?, &address)
+        let address = to_bech32(network_id, approver_account_id_address);
+        store::fetch_approver_by_approver_address(&mut self.get_read_conn().await?, &address)
             .await?
-            .map(make_multisig_approver)
+            .map(|approver_record| make_multisig_approver(approver_record, None))
             .transpose()
     }
 
+    /// Retrieves the public keys of the approvers of a multisig account, in approver index order,
+    /// as held by the storage of the account, i.e. the commitments of the stored approver keys.
+    ///
+    /// This is a fallback to reading the account storage when executing a transaction, e.g. of a
+    /// private account. Removed approvers are included, as they keep their slot on chain.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(pub_keys)` if the account has approvers
+    /// * `None` if the account doesn't exist
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The database query fails
+    /// - A stored approver key cannot be deserialized
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            %network_id,
+            account_id_address = %account_id_address.id().to_hex(),
+        )
+    )]
+    pub async fn get_approver_pub_keys_in_order(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<Option<Vec<PublicKey>>> {
+        let conn = &mut self.get_read_conn().await?;
+
+        let address = to_account_key(network_id, account_id_address);
+
+        let pub_keys: Vec<_> = store::stream_approvers_by_multisig_account_address(
+            conn, &address, true, None, None, None,
+        )
+        .await?
+        .map_ok(|(approver_record, ..)| -> Result<_> {
+            let ApproverRecordDissolved { pub_key_commit, scheme, .. } = approver_record.dissolve();
+
+            // the storage of the account holds the commitment of an approver key as its key
+            ApproverKey::from_bytes(scheme.into_inner(), &pub_key_commit)
+                .map(|approver_key| PublicKey::new(approver_key.commitment()))
+                .ok_or(MultisigStoreError::InvalidValue)
+        })
+        .map_err(From::from)
+        .map(Result::flatten)
+        .try_collect()
+        .await?;
+
+        Ok(Some(pub_keys).filter(|pub_keys| !pub_keys.is_empty()))
+    }
+
     /// Retrieves all signatures for a transaction along with the transaction details.
     ///
     /// This method fetches signatures from all approvers for a specific transaction,
-    /// ordered by the approver index. Approvers who haven't signed yet will have `None`
-    /// in their respective position(s).
+    /// ordered by the approver index. Each signature is paired with the time it was added.
+    /// Approvers who haven't signed yet will have `None` in their respective position(s).
     ///
     /// # Returns
     ///
     /// Returns a tuple of:
-    /// - A list of optional signatures (one per approver, in order)
+    /// - A list of optional signatures with their creation timestamps (one per approver, in order)
     /// - The transaction details
     ///
     /// # Errors
     ///
     /// Returns an error if:
     /// - The transaction doesn't exist
-    /// - Signature data cannot be deserialized
+    /// - Signature data cannot be deserialized according to the approver's key scheme
     /// - The database query fails
     #[tracing::instrument(skip_all, fields(%tx_id))]
     pub async fn get_signatures_of_all_approvers_with_multisig_tx_by_tx_id(
         &self,
         tx_id: &MultisigTxId,
-    ) -> Result<(Vec<Option<Signature>>, MultisigTx)> {
-        let (signatures, tx_record) =
+    ) -> Result<(Vec<Option<(ApproverSignature, DateTime<Utc>)>>, MultisigTx)> {
+        // the signatures are read right after being added to process the tx, so a lagging read
+        // replica must not be used
+        let conn = &mut self.get_conn().await?;
+
+        let (signatures, created_ats, tx_record) =
             store::fetch_all_signature_bytes_with_tx_by_tx_id_in_order_of_approvers(
-                &mut self.get_conn().await?,
+                conn,
                 tx_id.into(),
             )
             .await?;
 
+        let input_note_ids = store::fetch_input_note_ids_by_tx_id(conn, tx_id.into()).await?;
+
+        // approvers are streamed in the same approver index order the signatures are aggregated in
+        let schemes: Vec<_> = store::stream_approvers_by_multisig_account_address(
+            conn,
+            tx_record.multisig_account_address(),
+            true,
+            None,
+            None,
+            None,
+        )
+        .await?
+        .map_ok(|(approver_record, ..)| approver_record.scheme())
+        .try_collect()
+        .await?;
+
+        let slots = zip_signatures_with_created_ats(signatures, created_ats)?;
+
+        if slots.len() != schemes.len() {
+            return Err(MultisigStoreError::InvalidValue);
+        }
+
         let mut sigs_count = 0i64;
 
-        let signatures = signatures
+        let signatures = slots
             .into_iter()
-            .inspect(|s| {
+            .zip(schemes)
+            .inspect(|(s, _)| {
                 if s.is_some() {
                     sigs_count += 1
                 }
             })
-            .map(|s| s.as_deref().map(Deserializable::read_from_bytes).transpose())
-            .map(|s| s.map_err(|_| MultisigStoreError::InvalidValue))
+            .map(|(s, scheme)| {
+                s.map(|(signature, created_at)| {
+                    ApproverSignature::from_bytes(scheme, &signature).map(|sig| (sig, created_at))
+                })
+                .map(|s| s.ok_or(MultisigStoreError::InvalidValue))
+                .transpose()
+            })
             .collect::<Result<_, _>>()?;
 
         // unwrap is safe because sigs_count is non-negative
         let sigs_count = U63::from_signed(sigs_count).unwrap();
 
-        Ok((signatures, make_multisig_tx(tx_record, sigs_count)?))
+        let account = fetch_tx_account_by_multisig_account_address(
+            conn,
+            tx_record.multisig_account_address(),
+        )
+        .await?;
+
+        Ok((signatures, make_multisig_tx(tx_record, sigs_count, input_note_ids, account)?))
+    }
+
+    /// Checks that the primary database is reachable by running a trivial query on it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no connection can be acquired or the query fails.
+    #[tracing::instrument(skip_all)]
+    pub async fn ping(&self) -> Result<()> {
+        store::ping(&mut self.get_conn().await?).await.map_err(From::from)
     }
 
     async fn get_conn(&self) -> Result<DbConn> {
         self.pool.get().await.map_err(|_| MultisigStoreError::Pool)
     }
+
+    async fn get_read_conn(&self) -> Result<DbConn> {
+        self.read_pool.get().await.map_err(|_| MultisigStoreError::Pool)
+    }
+
+    fn encode_tx_blob(&self, bz: Vec<u8>) -> Result<Vec<u8>> {
+        blob::encode(bz, self.compress_tx_blobs)
+            .map_err(|e| MultisigStoreError::Serialization(e.to_string().into()))
+    }
+}
+
+/// The outcome of removing an approver within a database transaction, turned into the result of
+/// the removal once the transaction is over.
+enum ApproverRemoval {
+    Removed,
+    NotFound,
+    PendingTxs,
+    ThresholdRaised { threshold: u64, current: u64 },
+    UnreachableThreshold { threshold: u64, remaining_weight: u64 },
+}
+
+/// The outcome of adding signatures within a database transaction, turned into the result of the
+/// addition once the transaction is over.
+enum SignatureAddition {
+    Added { threshold_met: bool },
+    NotAuthorized,
+    InvalidSignature,
+}
+
+/// The outcome of re-proposing a transaction within a database transaction, turned into the
+/// result of the re-proposal once the transaction is over.
+enum Reproposal {
+    Reset,
+    NotFound,
+    TooManyPendingTxs { max: NonZeroU32 },
+}
+
+/// Returns whether a multisig account already has `max` pending transactions.
+///
+/// The account row is locked until the end of the current transaction first, so that concurrent
+/// transactions adding a pending transaction to the account count them one after the other.
+async fn has_max_pending_txs(
+    conn: &mut DbConn,
+    multisig_account_address: &str,
+    max: NonZeroU32,
+) -> Result<bool, StoreError> {
+    store::fetch_mutisig_account_for_update_by_address(conn, multisig_account_address).await?;
+
+    let pending =
+        store::count_pending_txs_by_multisig_account_address(conn, multisig_account_address)
+            .await?;
+
+    Ok(pending >= u64::from(max.get()))
+}
+
+/// Pairs each approver slot's signature bytes with the time the signature was added.
+///
+/// Both lists are expected to be aggregated in the same approver order, so a slot either has
+/// both a signature and a timestamp or neither.
+fn zip_signatures_with_created_ats(
+    signatures: Vec<Option<Vec<u8>>>,
+    created_ats: Vec<Option<DateTime<Utc>>>,
+) -> Result<Vec<Option<(Vec<u8>, DateTime<Utc>)>>> {
+    if signatures.len() != created_ats.len() {
+        return Err(MultisigStoreError::InvalidValue);
+    }
+
+    signatures
+        .into_iter()
+        .zip(created_ats)
+        .map(|slot| match slot {
+            (Some(signature), Some(created_at)) => Ok(Some((signature, created_at))),
+            (None, None) => Ok(None),
+            _ => Err(MultisigStoreError::InvalidValue),
+        })
+        .collect()
+}
+
+/// Returns whether `signature` was made with the approver's key over the transaction summary
+/// commitment, dispatching on the scheme of the approver's key. A signature of another scheme
+/// than the approver's key is not valid.
+async fn is_approver_signature_valid(
+    conn: &mut DbConn,
+    tx_id: Uuid,
+    approver_address: &str,
+    signature: &ApproverSignature,
+) -> Result<bool, StoreError> {
+    let ApproverRecordDissolved { pub_key_commit, scheme, .. } =
+        store::fetch_approver_by_approver_address(conn, approver_address)
+            .await?
+            .map(ApproverRecord::dissolve)
+            .ok_or(StoreError::other("approver not found"))?;
+
+    let approver_key = ApproverKey::from_bytes(scheme.into_inner(), &pub_key_commit)
+        .ok_or(StoreError::other("invalid approver key"))?;
+
+    let TxRecordDissolved { tx_summary_commit, .. } =
+        store::fetch_tx_with_signature_count_by_id(conn, tx_id)
+            .await?
+            .map(|(tx_record, _)| tx_record.dissolve())
+            .ok_or(StoreError::other("tx not found"))?;
+
+    let tx_summary_commit = Word::read_from_bytes(&tx_summary_commit)
+        .map_err(|_| StoreError::other("invalid tx summary commitment"))?;
+
+    let valid = match (approver_key, signature) {
+        (ApproverKey::Falcon(pub_key_commit), ApproverSignature::Falcon(signature)) => {
+            pub_key_commit.verify(tx_summary_commit, signature)
+        },
+        (ApproverKey::Ecdsa(pub_key), ApproverSignature::Ecdsa(signature)) => {
+            verify_ecdsa_k256_signature(pub_key.as_bytes(), tx_summary_commit, signature.as_bytes())
+        },
+        _ => false,
+    };
+
+    Ok(valid)
+}
+
+/// Fetches the threshold of the multisig account the transaction belongs to, i.e. the total
+/// approver weight required to process it.
+async fn fetch_threshold_by_tx_id(conn: &mut DbConn, tx_id: Uuid) -> Result<i64, StoreError> {
+    let TxRecordDissolved { multisig_account_address, .. } =
+        store::fetch_tx_with_signature_count_by_id(conn, tx_id)
+            .await?
+            .map(|(tx_record, _)| tx_record.dissolve())
+            .ok_or(StoreError::other("tx not found"))?;
+
+    fetch_tx_account_by_multisig_account_address(conn, &multisig_account_address)
+        .await
+        .map(|(threshold, _)| threshold)
+}
+
+/// Fetches the threshold of a multisig account, i.e. the total approver weight required to
+/// process its transactions, along with the interface of its address, i.e. what its transactions
+/// are made with.
+async fn fetch_tx_account_by_multisig_account_address(
+    conn: &mut DbConn,
+    multisig_account_address: &str,
+) -> Result<(i64, AddressInterface), StoreError> {
+    let MultisigAccountRecordDissolved { threshold, interface, .. } =
+        store::fetch_mutisig_account_by_address(conn, multisig_account_address)
+            .await?
+            .map(MultisigAccountRecord::dissolve)
+            .ok_or(StoreError::other("multisig account not found"))?;
+
+    Ok((threshold, interface.into_inner()))
 }
 
 fn make_multisig_account(
     multisig_account_record: MultisigAccountRecord,
 ) -> Result<MultisigAccount> {
-    let MultisigAccountRecordDissolved { address, kind, threshold, created_at } =
-        multisig_account_record.dissolve();
+    let MultisigAccountRecordDissolved {
+        address,
+        kind,
+        threshold,
+        created_at,
+        archived_at,
+        nonce,
+        interface,
+    } = multisig_account_record.dissolve();
 
     let (network_id, account_id_address) = extract_network_id_account_id_address_pair(&address)
         .map_err(|e| MultisigStoreError::Other(e.to_string().into()))?;
 
+    // the account is keyed by its id, its address has the interface stored apart
+    let account_id_address = AccountIdAddress::new(account_id_address.id(), interface.into_inner());
+
     let threshold = threshold
         .try_into()
         .map(NonZeroU32::new)
@@ -654,13 +2592,51 @@ fn make_multisig_account(
         .network_id(network_id)
         .kind(kind.into_inner())
         .threshold(threshold)
+        .maybe_nonce(
+            nonce
+                .map(u64::try_from)
+                .transpose()
+                .map_err(|_| MultisigStoreError::InvalidValue)?,
+        )
+        .maybe_archived_at(archived_at)
         .aux(timestamps)
         .build();
 
     Ok(multisig_account)
 }
 
-fn make_multisig_tx(tx_record: TxRecord, signature_count: U63) -> Result<MultisigTx> {
+async fn make_multisig_txs_with_input_note_ids(
+    conn: &mut DbConn,
+    txs: Vec<(TxRecord, U63)>,
+    account_of: impl Fn(&TxRecord) -> (i64, AddressInterface),
+) -> Result<Vec<(MultisigTxId, Result<MultisigTx>)>> {
+    let tx_ids: Vec<_> = txs.iter().map(|(tx_record, _)| tx_record.id()).collect();
+
+    let mut input_note_ids: HashMap<_, Vec<_>> = HashMap::new();
+    for (tx_id, note_id) in store::fetch_input_note_ids_by_tx_ids(conn, &tx_ids).await? {
+        input_note_ids.entry(tx_id).or_default().push(note_id);
+    }
+
+    let txs = txs
+        .into_iter()
+        .map(|(tx_record, sigs_count)| {
+            let tx_id = tx_record.id();
+            let tx_input_note_ids = input_note_ids.remove(&tx_id).unwrap_or_default();
+            let account = account_of(&tx_record);
+            let tx = make_multisig_tx(tx_record, sigs_count, tx_input_note_ids, account);
+            (tx_id.into(), tx)
+        })
+        .collect();
+
+    Ok(txs)
+}
+
+fn make_multisig_tx(
+    tx_record: TxRecord,
+    signature_count: U63,
+    input_note_ids: Vec<Vec<u8>>,
+    (threshold, interface): (i64, AddressInterface),
+) -> Result<MultisigTx> {
     let TxRecordDissolved {
         id,
         multisig_account_address,
@@ -669,6 +2645,14 @@ fn make_multisig_tx(tx_record: TxRecord, signature_count: U63) -> Result<Multisi
         tx_summary,
         tx_summary_commit,
         created_at,
+        title,
+        memo,
+        failure_reason,
+        tx_effect,
+        chain_tx_id,
+        submission_block_num,
+        updated_at,
+        threshold_met_at: _,
     } = tx_record.dissolve();
 
     let (network_id, address) =
@@ -677,16 +2661,56 @@ fn make_multisig_tx(tx_record: TxRecord, signature_count: U63) -> Result<Multisi
         )
         .map_err(|e| MultisigStoreError::Other(e.to_string().into()))?;
 
-    let tx_request = TransactionRequest::read_from_bytes(&tx_request)
-        .map_err(|_| MultisigStoreError::InvalidValue)?;
+    let address = AccountIdAddress::new(address.id(), interface);
 
-    let tx_summary = TransactionSummary::read_from_bytes(&tx_summary)
-        .map_err(|_| MultisigStoreError::InvalidValue)?;
+    let tx_request = blob::decode(&tx_request)
+        .map_err(|e| MultisigStoreError::Serialization(e.to_string().into()))
+        .and_then(|bz| {
+            TransactionRequest::read_from_bytes(&bz).map_err(|_| MultisigStoreError::InvalidValue)
+        })?;
+
+    let tx_summary = blob::decode(&tx_summary)
+        .map_err(|e| MultisigStoreError::Serialization(e.to_string().into()))
+        .and_then(|bz| {
+            TransactionSummary::read_from_bytes(&bz).map_err(|_| MultisigStoreError::InvalidValue)
+        })?;
 
     let tx_summary_commit =
         Word::read_from_bytes(&tx_summary_commit).map_err(|_| MultisigStoreError::InvalidValue)?;
 
-    let timestamps = Timestamps::builder().created_at(created_at).updated_at(created_at).build();
+    // txs proposed before the effect was persisted have it decoded from their summary instead
+    let tx_effect = match tx_effect {
+        Some(tx_effect) => {
+            TxEffect::read_from_bytes(&tx_effect).map_err(|_| MultisigStoreError::InvalidValue)?
+        },
+        None => TxEffect::from(&tx_summary),
+    };
+
+    let chain_tx_id = chain_tx_id
+        .as_deref()
+        .map(TransactionId::read_from_bytes)
+        .transpose()
+        .map_err(|_| MultisigStoreError::InvalidValue)?;
+
+    let submission_block_num = submission_block_num
+        .map(u32::try_from)
+        .transpose()
+        .map_err(|_| MultisigStoreError::InvalidValue)?
+        .map(BlockNumber::from);
+
+    // txs proposed before their input notes were persisted have them read from their request
+    let input_note_ids = if input_note_ids.is_empty() {
+        tx_request.get_input_note_ids()
+    } else {
+        input_note_ids
+            .iter()
+            .map(Vec::as_slice)
+            .map(NoteId::read_from_bytes)
+            .collect::<Result<_, _>>()
+            .map_err(|_| MultisigStoreError::InvalidValue)?
+    };
+
+    let timestamps = Timestamps::builder().created_at(created_at).updated_at(updated_at).build();
 
     let signature_count = signature_count
         .get()
@@ -694,6 +2718,12 @@ fn make_multisig_tx(tx_record: TxRecord, signature_count: U63) -> Result<Multisi
         .map(NonZeroU32::new)
         .map_err(|_| MultisigStoreError::InvalidValue)?;
 
+    let threshold = threshold
+        .try_into()
+        .map(NonZeroU32::new)
+        .map_err(|_| MultisigStoreError::InvalidValue)?
+        .ok_or(MultisigStoreError::InvalidValue)?;
+
     let tx = MultisigTx::builder()
         .id(id.into())
         .address(address)
@@ -702,24 +2732,80 @@ fn make_multisig_tx(tx_record: TxRecord, signature_count: U63) -> Result<Multisi
         .tx_request(tx_request)
         .tx_summary(tx_summary)
         .tx_summary_commit(tx_summary_commit)
+        .tx_effect(tx_effect)
+        .input_note_ids(input_note_ids)
+        .maybe_title(title)
+        .maybe_memo(memo)
+        .maybe_failure_reason(failure_reason)
+        .maybe_chain_tx_id(chain_tx_id)
+        .maybe_submission_block_num(submission_block_num)
         .maybe_signature_count(signature_count)
+        .threshold(threshold)
         .aux(timestamps)
         .build();
 
     Ok(tx)
 }
 
-fn make_multisig_approver(approver_record: ApproverRecord) -> Result<MultisigApprover> {
-    let ApproverRecordDissolved { address, pub_key_commit, created_at } =
-        approver_record.dissolve();
+fn make_audit_event(
+    audit_event_record: AuditEventRecord,
+    interface: AddressInterface,
+) -> Result<AuditEvent> {
+    let AuditEventRecordDissolved {
+        id,
+        multisig_account_address,
+        tx_id,
+        actor,
+        action,
+        outcome,
+        detail,
+        created_at,
+    } = audit_event_record.dissolve();
+
+    let (network_id, address) =
+        extract_network_id_account_id_address_pair(&multisig_account_address)
+            .map_err(|e| MultisigStoreError::Other(e.to_string().into()))?;
+
+    let address = AccountIdAddress::new(address.id(), interface);
+
+    let actor = actor
+        .map(|actor| extract_network_id_account_id_address_pair(&actor).map(|(_, actor)| actor))
+        .transpose()
+        .map_err(|e| MultisigStoreError::Other(e.to_string().into()))?;
+
+    let audit_event = AuditEvent::builder()
+        .id(id)
+        .address(address)
+        .network_id(network_id)
+        .maybe_tx_id(tx_id.map(From::from))
+        .maybe_actor(actor)
+        .action(action.into_inner())
+        .outcome(outcome.into_inner())
+        .maybe_detail(detail)
+        .created_at(created_at)
+        .build();
+
+    Ok(audit_event)
+}
+
+fn make_multisig_approver(
+    approver_record: ApproverRecord,
+    label: Option<String>,
+) -> Result<MultisigApprover> {
+    let ApproverRecordDissolved {
+        address,
+        pub_key_commit,
+        created_at,
+        scheme,
+        ..
+    } = approver_record.dissolve();
 
     let (network_id, address) =
         miden_multisig_coordinator_utils::extract_network_id_account_id_address_pair(&address)
             .map_err(|e| MultisigStoreError::Other(e.to_string().into()))?;
 
-    let pub_key_commit = Word::read_from_bytes(&pub_key_commit)
-        .map(PublicKey::new)
-        .map_err(|_| MultisigStoreError::InvalidValue)?;
+    let pub_key_commit = ApproverKey::from_bytes(scheme.into_inner(), &pub_key_commit)
+        .ok_or(MultisigStoreError::InvalidValue)?;
 
     let timestamps = Timestamps::builder().created_at(created_at).updated_at(created_at).build();
 
@@ -727,8 +2813,152 @@ fn make_multisig_approver(approver_record: ApproverRecord) -> Result<MultisigApp
         .address(address)
         .network_id(network_id)
         .pub_key_commit(pub_key_commit)
+        .maybe_label(label)
         .aux(timestamps)
         .build();
 
     Ok(approver)
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, TimeZone, Utc};
+    use diesel::result::{DatabaseErrorKind, Error as DieselError};
+    use diesel_async::{
+        AsyncPgConnection,
+        pooled_connection::{AsyncDieselConnectionManager, deadpool::Pool},
+    };
+
+    use crate::{
+        DbPool, MultisigStore, MultisigStoreError,
+        persistence::store::{StoreError, retry_transient},
+    };
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(secs, 0).unwrap()
+    }
+
+    fn make_pool(max_size: usize) -> DbPool {
+        let manager = AsyncDieselConnectionManager::<AsyncPgConnection>::new("postgres://unused");
+        Pool::builder(manager).max_size(max_size).build().unwrap()
+    }
+
+    #[test]
+    fn reads_use_primary_pool_unless_read_pool_is_configured() {
+        // Arrange
+        let primary = make_pool(1);
+        let replica = make_pool(2);
+
+        // Act
+        let single = MultisigStore::new(primary.clone());
+        let replicated = MultisigStore::new(primary).with_read_pool(replica);
+
+        // Assert, using the max size to tell the pools apart
+        assert_eq!(single.read_pool.status().max_size, 1);
+        assert_eq!(replicated.pool.status().max_size, 1);
+        assert_eq!(replicated.read_pool.status().max_size, 2);
+    }
+
+    #[test]
+    fn zipping_signatures_with_created_ats_keeps_approver_slots() {
+        // Arrange
+        let signatures = vec![Some(vec![1]), None, Some(vec![3])];
+        let created_ats = vec![Some(at(1_000)), None, Some(at(3_000))];
+
+        // Act
+        let slots = super::zip_signatures_with_created_ats(signatures, created_ats).unwrap();
+
+        // Assert
+        assert_eq!(slots, vec![Some((vec![1], at(1_000))), None, Some((vec![3], at(3_000)))]);
+    }
+
+    #[test]
+    fn zipping_misaligned_signatures_with_created_ats_fails() {
+        // Act
+        let mismatched_len = super::zip_signatures_with_created_ats(vec![None, None], vec![None]);
+        let missing_created_at =
+            super::zip_signatures_with_created_ats(vec![Some(vec![1])], vec![None]);
+
+        // Assert
+        assert!(matches!(mismatched_len, Err(MultisigStoreError::InvalidValue)));
+        assert!(matches!(missing_created_at, Err(MultisigStoreError::InvalidValue)));
+    }
+
+    #[test]
+    fn statement_timeout_errors_map_to_timeout() {
+        // Arrange
+        let db_error = |message: &str| {
+            StoreError::Db(DieselError::DatabaseError(
+                DatabaseErrorKind::Unknown,
+                Box::new(message.to_string()),
+            ))
+        };
+
+        // Act
+        let timeout =
+            MultisigStoreError::from(db_error("canceling statement due to statement timeout"));
+        let other = MultisigStoreError::from(db_error("deadlock detected"));
+
+        // Assert
+        assert!(matches!(timeout, MultisigStoreError::Timeout));
+        assert!(matches!(other, MultisigStoreError::Store(_)));
+    }
+
+    #[test]
+    fn only_serialization_failures_and_deadlocks_are_transient() {
+        // Arrange
+        let db_error = |kind: DatabaseErrorKind, message: &str| {
+            StoreError::Db(DieselError::DatabaseError(kind, Box::new(message.to_string())))
+        };
+
+        let serialization_failure =
+            db_error(DatabaseErrorKind::SerializationFailure, "could not serialize access");
+        let deadlock = db_error(DatabaseErrorKind::Unknown, "deadlock detected");
+        let unique_violation = db_error(DatabaseErrorKind::UniqueViolation, "duplicate key");
+        let other = StoreError::other("approver not found");
+
+        // Act & Assert
+        assert!(serialization_failure.is_transient());
+        assert!(deadlock.is_transient());
+
+        assert!(!unique_violation.is_transient());
+        assert!(!other.is_transient());
+    }
+
+    #[test]
+    fn transient_failures_are_retried_while_other_errors_pass_through() {
+        // Arrange
+        let rt = tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap();
+
+        let serialization_failure = || {
+            StoreError::Db(DieselError::DatabaseError(
+                DatabaseErrorKind::SerializationFailure,
+                Box::new("could not serialize access".to_string()),
+            ))
+        };
+
+        let mut conflicting_attempts = 0;
+        let mut failing_attempts = 0;
+
+        // Act
+        let conflicting = rt.block_on(retry_transient(async || {
+            conflicting_attempts += 1;
+
+            if conflicting_attempts < 3 {
+                return Err(serialization_failure());
+            }
+
+            Ok(conflicting_attempts)
+        }));
+
+        let failing = rt.block_on(retry_transient(async || -> Result<(), _> {
+            failing_attempts += 1;
+            Err(StoreError::other("approver not found"))
+        }));
+
+        // Assert
+        assert_eq!(conflicting.unwrap(), 3);
+        assert!(matches!(failing, Err(StoreError::Other(_))));
+        assert_eq!(failing_attempts, 1);
+    }
+}
@@ -1,3 +1,4 @@
+pub mod blob;
 pub mod pool;
 pub mod record;
 pub mod store;
@@ -0,0 +1,89 @@
+//! Encoding of the serialized transaction blobs, i.e. `tx_request` and `tx_summary`.
+//!
+//! Every blob is prefixed with a format byte telling how the serialized bytes following it are
+//! stored: [`RAW_FORMAT`] as is, or [`ZSTD_FORMAT`] zstd compressed. Rows written before the
+//! format byte was introduced are prefixed with [`RAW_FORMAT`] by the `tx_blob_format`
+//! migration, so that a blob with any other format byte is refused rather than guessed at.
+
+use std::{borrow::Cow, io};
+
+/// The format byte prefixing blobs stored as is.
+const RAW_FORMAT: u8 = 0;
+
+/// The format byte prefixing zstd compressed blobs.
+const ZSTD_FORMAT: u8 = 1;
+
+/// Encodes a serialized blob for storage, compressing it if `compress` is `true`.
+pub fn encode(bz: Vec<u8>, compress: bool) -> io::Result<Vec<u8>> {
+    if !compress {
+        let mut encoded = Vec::with_capacity(bz.len() + 1);
+        encoded.push(RAW_FORMAT);
+        encoded.extend(bz);
+
+        return Ok(encoded);
+    }
+
+    let mut encoded = vec![ZSTD_FORMAT];
+    zstd::stream::copy_encode(bz.as_slice(), &mut encoded, zstd::DEFAULT_COMPRESSION_LEVEL)?;
+
+    Ok(encoded)
+}
+
+/// Decodes a stored blob back into its serialized form, whether it was compressed or not.
+///
+/// # Errors
+///
+/// Returns an [`io::ErrorKind::InvalidData`] error if the blob is empty or prefixed with an
+/// unknown format byte, or the error of decompressing a zstd compressed blob.
+pub fn decode(bz: &[u8]) -> io::Result<Cow<'_, [u8]>> {
+    match bz {
+        [RAW_FORMAT, raw @ ..] => Ok(Cow::Borrowed(raw)),
+        [ZSTD_FORMAT, frame @ ..] => zstd::stream::decode_all(frame).map(Cow::Owned),
+        [format, ..] => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("unknown blob format `{format}`"),
+        )),
+        [] => Err(io::Error::new(io::ErrorKind::InvalidData, "empty blob")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    #[test]
+    fn blobs_round_trip_whether_compressed_or_not() {
+        // Arrange
+        let bz = b"serialized transaction request".repeat(8);
+
+        // Act
+        let raw = super::encode(bz.clone(), false).unwrap();
+        let compressed = super::encode(bz.clone(), true).unwrap();
+
+        // Assert
+        assert_eq!(raw[0], super::RAW_FORMAT);
+        assert_eq!(compressed[0], super::ZSTD_FORMAT);
+        assert!(compressed.len() < raw.len());
+
+        assert_eq!(*super::decode(&raw).unwrap(), bz);
+        assert_eq!(*super::decode(&compressed).unwrap(), bz);
+    }
+
+    #[test]
+    fn empty_unknown_format_and_corrupt_blobs_fail_to_decode() {
+        // Arrange
+        let mut corrupt = super::encode(b"serialized transaction summary".to_vec(), true).unwrap();
+        corrupt.truncate(corrupt.len() / 2);
+
+        // Act
+        let empty = super::decode(&[]).unwrap_err();
+        let unknown_format = super::decode(&[2, 0x28, 0xb5, 0x2f, 0xfd]).unwrap_err();
+        let truncated = super::decode(&corrupt);
+
+        // Assert
+        assert_eq!(empty.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(unknown_format.kind(), io::ErrorKind::InvalidData);
+        assert!(unknown_format.to_string().contains("unknown blob format `2`"));
+        assert!(truncated.is_err());
+    }
+}
@@ -1,22 +1,32 @@
 mod error;
+mod slow_query;
 
 pub use self::error::PoolError;
 
-use core::num::NonZeroUsize;
+use core::{num::NonZeroUsize, time::Duration};
 
+use std::path::PathBuf;
+
+use bon::Builder;
 use diesel::ConnectionError;
 use diesel_async::{
-    AsyncPgConnection,
+    AsyncConnection, AsyncPgConnection, SimpleAsyncConnection,
     pooled_connection::{
         AsyncDieselConnectionManager, ManagerConfig,
         deadpool::{Object, Pool},
     },
 };
-use rustls::{ClientConfig, RootCertStore};
+use rustls::{
+    ClientConfig, RootCertStore,
+    pki_types::{CertificateDer, PrivateKeyDer, pem::PemObject},
+};
 use rustls_native_certs::CertificateResult;
 use tokio::task;
+use tokio_postgres::config::SslMode;
 use tokio_postgres_rustls::MakeRustlsConnect;
 
+use self::slow_query::SlowQueryLogger;
+
 /// A connection pool for managing PostgreSQL database connections.
 ///
 /// This is a type alias for a deadpool-managed connection pool that handles
@@ -31,9 +41,79 @@ pub type DbPool = Pool<AsyncPgConnection>;
 /// automatically returned to the pool for reuse.
 pub type DbConn = Object<AsyncPgConnection>;
 
+/// Settings applied to every connection of the pool when it is established.
+#[derive(Debug, Clone, Default, Builder)]
+pub struct ConnConfig {
+    /// The maximum duration of a single statement, after which the database cancels it.
+    ///
+    /// If `None`, the database default applies.
+    statement_timeout: Option<Duration>,
+
+    /// The duration above which queries are logged as slow, along with the span they ran in.
+    ///
+    /// If `None`, slow queries are not logged.
+    slow_query_threshold: Option<Duration>,
+
+    /// The TLS settings of the connections, e.g. for managed databases requiring verified TLS.
+    ///
+    /// If `None`, the `sslmode` of the connection URL applies and server certificates are verified
+    /// against the native root certificates.
+    tls: Option<PoolTlsConfig>,
+}
+
+/// TLS settings of the connections of the pool.
+#[derive(Debug, Clone, Default, Builder)]
+pub struct PoolTlsConfig {
+    /// Whether connections use TLS, overriding the `sslmode` of the connection URL.
+    ///
+    /// If `None`, the `sslmode` of the connection URL applies.
+    mode: Option<TlsMode>,
+
+    /// The path of a PEM file of CA certificates the server certificate is verified against, in
+    /// addition to the native root certificates.
+    #[builder(into)]
+    ca_cert_path: Option<PathBuf>,
+
+    /// The path of a PEM file of the client certificate chain presented to the server.
+    ///
+    /// It must be set along with `client_key`.
+    #[builder(into)]
+    client_cert: Option<PathBuf>,
+
+    /// The path of a PEM file of the private key of the client certificate.
+    ///
+    /// It must be set along with `client_cert`.
+    #[builder(into)]
+    client_key: Option<PathBuf>,
+}
+
+/// Whether connections of the pool use TLS, mirroring the PostgreSQL `sslmode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TlsMode {
+    /// Connections do not use TLS.
+    Disable,
+
+    /// Connections use TLS if the server supports it.
+    Prefer,
+
+    /// Connections fail unless the server supports TLS.
+    Require,
+}
+
+impl From<TlsMode> for SslMode {
+    fn from(mode: TlsMode) -> Self {
+        match mode {
+            TlsMode::Disable => Self::Disable,
+            TlsMode::Prefer => Self::Prefer,
+            TlsMode::Require => Self::Require,
+        }
+    }
+}
+
 /// Establishes a connection pool to the PostgreSQL database.
 ///
-/// Creates and configures a connection pool with the specified maximum size.
+/// Creates and configures a connection pool with the specified maximum size, applying the given
+/// [ConnConfig] to every connection.
 ///
 /// # Returns
 ///
@@ -45,26 +125,72 @@ pub type DbConn = Object<AsyncPgConnection>;
 /// - The connection URL is malformed
 /// - The pool configuration is invalid
 /// - Initial connection validation fails
+/// - The certificates or private key of the TLS settings cannot be read or are invalid
 #[tracing::instrument(skip(url))]
-pub async fn establish_pool<U>(url: U, max_size: NonZeroUsize) -> Result<DbPool, PoolError>
+pub async fn establish_pool<U>(
+    url: U,
+    max_size: NonZeroUsize,
+    conn_config: ConnConfig,
+) -> Result<DbPool, PoolError>
 where
     String: From<U>,
 {
-    let tls = task::spawn_blocking(make_rustls_config).await??;
+    let ConnConfig {
+        statement_timeout,
+        slow_query_threshold,
+        tls: tls_config,
+    } = conn_config;
+
+    let ssl_mode = ssl_mode_override(tls_config.as_ref());
+    let tls = task::spawn_blocking(move || make_rustls_config(tls_config.as_ref())).await??;
 
     let mut manager_config = ManagerConfig::default();
     manager_config.custom_setup = Box::new(move |url: &str| {
         let tls = tls.clone();
         let url = url.to_string();
         Box::pin(async move {
-            let (client, conn) = tokio_postgres::connect(&url, tls)
+            let mut pg_config = url
+                .parse::<tokio_postgres::Config>()
+                .map_err(|e| e.to_string())
+                .map_err(ConnectionError::InvalidConnectionUrl)?;
+
+            if let Some(ssl_mode) = ssl_mode {
+                pg_config.ssl_mode(ssl_mode);
+            }
+
+            let (client, conn) = pg_config
+                .connect(tls)
                 .await
                 .map_err(|e| e.to_string())
                 .map_err(ConnectionError::BadConnection)?;
 
             tokio::spawn(conn);
 
-            AsyncPgConnection::try_from(client).await
+            let mut conn = AsyncPgConnection::try_from(client).await?;
+
+            // diesel doesn't surface the SQLSTATE of database errors, so the ones telling a
+            // statement timeout or a deadlock apart are recognized by their message, which is
+            // only stable in the `C` locale, setting it may be refused to roles lacking the
+            // privilege, whose messages then follow the server locale
+            let _ = conn.batch_execute("SET lc_messages = 'C'").await.inspect_err(|e| {
+                tracing::warn!(
+                    "failed to set lc_messages, database errors may be misclassified: {e}"
+                )
+            });
+
+            if let Some(statement_timeout) = statement_timeout {
+                let query = format!("SET statement_timeout = {}", statement_timeout.as_millis());
+
+                conn.batch_execute(&query)
+                    .await
+                    .map_err(ConnectionError::CouldntSetupConfiguration)?;
+            }
+
+            if let Some(threshold) = slow_query_threshold {
+                conn.set_instrumentation(SlowQueryLogger::new(threshold));
+            }
+
+            Ok(conn)
         })
     });
 
@@ -74,7 +200,12 @@ where
     Pool::builder(manager).max_size(max_size.get()).build().map_err(From::from)
 }
 
-fn make_rustls_config() -> Result<MakeRustlsConnect, rustls::Error> {
+/// Returns the `sslmode` overriding the one of the connection URL, if the TLS settings set one.
+fn ssl_mode_override(tls_config: Option<&PoolTlsConfig>) -> Option<SslMode> {
+    tls_config.and_then(|tls_config| tls_config.mode).map(SslMode::from)
+}
+
+fn make_rustls_config(tls_config: Option<&PoolTlsConfig>) -> Result<MakeRustlsConnect, PoolError> {
     let mut cert_store = RootCertStore::empty();
     let CertificateResult { certs, .. } = rustls_native_certs::load_native_certs();
 
@@ -82,7 +213,103 @@ fn make_rustls_config() -> Result<MakeRustlsConnect, rustls::Error> {
         cert_store.add(cert)?;
     }
 
-    let config = ClientConfig::builder().with_root_certificates(cert_store).with_no_client_auth();
+    let Some(tls_config) = tls_config else {
+        let config =
+            ClientConfig::builder().with_root_certificates(cert_store).with_no_client_auth();
+
+        return Ok(MakeRustlsConnect::new(config));
+    };
+
+    if let Some(ca_cert_path) = &tls_config.ca_cert_path {
+        for cert in CertificateDer::pem_file_iter(ca_cert_path)? {
+            cert_store.add(cert?)?;
+        }
+    }
+
+    let builder = ClientConfig::builder().with_root_certificates(cert_store);
+
+    let config = match (&tls_config.client_cert, &tls_config.client_key) {
+        (Some(client_cert), Some(client_key)) => {
+            let cert_chain =
+                CertificateDer::pem_file_iter(client_cert)?.collect::<Result<Vec<_>, _>>()?;
+            let key = PrivateKeyDer::from_pem_file(client_key)?;
+
+            builder.with_client_auth_cert(cert_chain, key)?
+        },
+        (None, None) => builder.with_no_client_auth(),
+        _ => return Err(PoolError::IncompleteClientAuth),
+    };
 
     Ok(MakeRustlsConnect::new(config))
 }
+
+#[cfg(test)]
+mod tests {
+    use tokio_postgres::config::SslMode;
+
+    use super::{PoolError, PoolTlsConfig, TlsMode};
+
+    #[test]
+    fn ssl_mode_of_connection_url_applies_unless_tls_config_sets_mode() {
+        // Arrange
+        let without_mode = PoolTlsConfig::builder().ca_cert_path("ca.pem").build();
+        let with_mode = PoolTlsConfig::builder().mode(TlsMode::Require).build();
+
+        // Act
+        let ssl_modes = [None, Some(&without_mode), Some(&with_mode)].map(super::ssl_mode_override);
+
+        // Assert
+        assert_eq!(ssl_modes, [None, None, Some(SslMode::Require)]);
+    }
+
+    #[test]
+    fn rustls_config_is_made_without_tls_config() {
+        // Act
+        let tls = super::make_rustls_config(None);
+
+        // Assert
+        assert!(tls.is_ok());
+    }
+
+    #[test]
+    fn rustls_config_is_made_from_tls_config_without_certs() {
+        // Arrange
+        let tls_config = PoolTlsConfig::builder().mode(TlsMode::Require).build();
+
+        // Act
+        let tls = super::make_rustls_config(Some(&tls_config));
+
+        // Assert
+        assert!(tls.is_ok());
+    }
+
+    #[test]
+    fn making_rustls_config_with_client_cert_but_no_key_fails() {
+        // Arrange
+        let tls_config = PoolTlsConfig::builder()
+            .mode(TlsMode::Require)
+            .client_cert("client.crt")
+            .build();
+
+        // Act
+        let tls = super::make_rustls_config(Some(&tls_config));
+
+        // Assert
+        assert!(matches!(tls, Err(PoolError::IncompleteClientAuth)));
+    }
+
+    #[test]
+    fn making_rustls_config_with_missing_ca_cert_file_fails() {
+        // Arrange
+        let tls_config = PoolTlsConfig::builder()
+            .mode(TlsMode::Require)
+            .ca_cert_path("/nonexistent/ca.pem")
+            .build();
+
+        // Act
+        let tls = super::make_rustls_config(Some(&tls_config));
+
+        // Assert
+        assert!(matches!(tls, Err(PoolError::Pem(_))));
+    }
+}
@@ -1,4 +1,5 @@
 use diesel_async::pooled_connection::deadpool::BuildError;
+use rustls::pki_types::pem;
 use tokio::task::JoinError;
 
 #[derive(Debug, thiserror::Error)]
@@ -14,4 +15,10 @@ pub enum PoolError {
 
     #[error("tokio postgres error: {0}")]
     TokioPostgres(#[from] tokio_postgres::Error),
+
+    #[error("pem error: {0}")]
+    Pem(#[from] pem::Error),
+
+    #[error("client certificate and client key must be set together")]
+    IncompleteClientAuth,
 }
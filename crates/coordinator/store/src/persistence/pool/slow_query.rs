@@ -0,0 +1,47 @@
+use core::time::Duration;
+
+use std::time::Instant;
+
+use diesel::connection::{Instrumentation, InstrumentationEvent};
+use tracing::Span;
+
+/// Connection instrumentation logging every query that takes longer than a threshold.
+///
+/// The log event is emitted in the span the query ran in, i.e. the span of the store method
+/// issuing it, whose name is recorded explicitly for log formats not printing span names.
+pub struct SlowQueryLogger {
+    threshold: Duration,
+    started_at: Option<Instant>,
+}
+
+impl SlowQueryLogger {
+    pub fn new(threshold: Duration) -> Self {
+        Self { threshold, started_at: None }
+    }
+}
+
+impl Instrumentation for SlowQueryLogger {
+    fn on_connection_event(&mut self, event: InstrumentationEvent<'_>) {
+        match event {
+            InstrumentationEvent::StartQuery { .. } => self.started_at = Some(Instant::now()),
+            InstrumentationEvent::FinishQuery { query, .. } => {
+                let Some(elapsed) = self.started_at.take().map(|started_at| started_at.elapsed())
+                else {
+                    return;
+                };
+
+                if elapsed > self.threshold {
+                    let span = Span::current();
+
+                    tracing::warn!(
+                        span = span.metadata().map_or("none", |metadata| metadata.name()),
+                        ?elapsed,
+                        %query,
+                        "slow query",
+                    );
+                }
+            },
+            _ => {},
+        }
+    }
+}
@@ -12,21 +12,43 @@ use diesel::{
     pg::Pg,
     serialize::{self, IsNull, Output, ToSql},
 };
-use miden_client::account::AccountStorageMode;
-use miden_multisig_coordinator_domain::tx::MultisigTxStatus;
+use miden_client::account::{AccountStorageMode, AddressInterface};
+use miden_multisig_coordinator_domain::{
+    audit::{AuditAction, AuditOutcome},
+    key::ApproverKeyScheme,
+    tx::MultisigTxStatus,
+};
 
 use crate::persistence::schema::sql_types::{
-    AccountKind as AccountKindSql, TxStatus as TxStatusSql,
+    AccountKind as AccountKindSql, AddressInterface as AddressInterfaceSql,
+    ApproverKeyScheme as ApproverKeySchemeSql, AuditAction as AuditActionSql,
+    AuditOutcome as AuditOutcomeSql, TxStatus as TxStatusSql,
 };
 
 #[derive(Debug, AsExpression, FromSqlRow)]
 #[diesel(sql_type = AccountKindSql)]
 pub struct AccountKind(AccountStorageMode);
 
+#[derive(Debug, AsExpression, FromSqlRow)]
+#[diesel(sql_type = AddressInterfaceSql)]
+pub struct Interface(AddressInterface);
+
 #[derive(Debug, AsExpression, FromSqlRow)]
 #[diesel(sql_type = TxStatusSql)]
 pub struct TxStatus(MultisigTxStatus);
 
+#[derive(Debug, AsExpression, FromSqlRow)]
+#[diesel(sql_type = ApproverKeySchemeSql)]
+pub struct KeyScheme(ApproverKeyScheme);
+
+#[derive(Debug, AsExpression, FromSqlRow)]
+#[diesel(sql_type = AuditActionSql)]
+pub struct Action(AuditAction);
+
+#[derive(Debug, AsExpression, FromSqlRow)]
+#[diesel(sql_type = AuditOutcomeSql)]
+pub struct Outcome(AuditOutcome);
+
 impl AccountKind {
     const PUBLIC: &[u8] = b"public";
 
@@ -37,24 +59,76 @@ impl AccountKind {
     }
 }
 
+impl Interface {
+    const UNSPECIFIED: &[u8] = b"unspecified";
+
+    const BASIC_WALLET: &[u8] = b"basic_wallet";
+
+    pub fn into_inner(self) -> AddressInterface {
+        self.0
+    }
+}
+
 impl TxStatus {
     pub fn into_inner(self) -> MultisigTxStatus {
         self.0
     }
 }
 
+impl KeyScheme {
+    pub fn into_inner(self) -> ApproverKeyScheme {
+        self.0
+    }
+}
+
+impl Action {
+    pub fn into_inner(self) -> AuditAction {
+        self.0
+    }
+}
+
+impl Outcome {
+    pub fn into_inner(self) -> AuditOutcome {
+        self.0
+    }
+}
+
 impl From<AccountStorageMode> for AccountKind {
     fn from(mode: AccountStorageMode) -> Self {
         Self(mode)
     }
 }
 
+impl From<AddressInterface> for Interface {
+    fn from(interface: AddressInterface) -> Self {
+        Self(interface)
+    }
+}
+
 impl From<MultisigTxStatus> for TxStatus {
     fn from(status: MultisigTxStatus) -> Self {
         Self(status)
     }
 }
 
+impl From<ApproverKeyScheme> for KeyScheme {
+    fn from(scheme: ApproverKeyScheme) -> Self {
+        Self(scheme)
+    }
+}
+
+impl From<AuditAction> for Action {
+    fn from(action: AuditAction) -> Self {
+        Self(action)
+    }
+}
+
+impl From<AuditOutcome> for Outcome {
+    fn from(outcome: AuditOutcome) -> Self {
+        Self(outcome)
+    }
+}
+
 impl ToSql<AccountKindSql, Pg> for AccountKind {
     fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
         match self.0 {
@@ -78,6 +152,27 @@ impl FromSql<AccountKindSql, Pg> for AccountKind {
     }
 }
 
+impl ToSql<AddressInterfaceSql, Pg> for Interface {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        match self.0 {
+            AddressInterface::Unspecified => out.write_all(Self::UNSPECIFIED)?,
+            AddressInterface::BasicWallet => out.write_all(Self::BASIC_WALLET)?,
+        }
+
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<AddressInterfaceSql, Pg> for Interface {
+    fn from_sql(bz: <Pg as Backend>::RawValue<'_>) -> deserialize::Result<Self> {
+        match bz.as_bytes() {
+            Self::UNSPECIFIED => Ok(Self(AddressInterface::Unspecified)),
+            Self::BASIC_WALLET => Ok(Self(AddressInterface::BasicWallet)),
+            _ => Err("unrecognized enum variant for address interface".into()),
+        }
+    }
+}
+
 impl ToSql<TxStatusSql, Pg> for TxStatus {
     fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
         out.write_all(<&str>::from(&self.0).as_bytes())?;
@@ -94,3 +189,54 @@ impl FromSql<TxStatusSql, Pg> for TxStatus {
             .map_err(From::from)
     }
 }
+
+impl ToSql<ApproverKeySchemeSql, Pg> for KeyScheme {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(<&str>::from(&self.0).as_bytes())?;
+
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<ApproverKeySchemeSql, Pg> for KeyScheme {
+    fn from_sql(bz: <Pg as Backend>::RawValue<'_>) -> deserialize::Result<Self> {
+        str::from_utf8(bz.as_bytes())
+            .map(FromStr::from_str)?
+            .map(Self)
+            .map_err(From::from)
+    }
+}
+
+impl ToSql<AuditActionSql, Pg> for Action {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(<&str>::from(&self.0).as_bytes())?;
+
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<AuditActionSql, Pg> for Action {
+    fn from_sql(bz: <Pg as Backend>::RawValue<'_>) -> deserialize::Result<Self> {
+        str::from_utf8(bz.as_bytes())
+            .map(FromStr::from_str)?
+            .map(Self)
+            .map_err(From::from)
+    }
+}
+
+impl ToSql<AuditOutcomeSql, Pg> for Outcome {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(<&str>::from(&self.0).as_bytes())?;
+
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<AuditOutcomeSql, Pg> for Outcome {
+    fn from_sql(bz: <Pg as Backend>::RawValue<'_>) -> deserialize::Result<Self> {
+        str::from_utf8(bz.as_bytes())
+            .map(FromStr::from_str)?
+            .map(Self)
+            .map_err(From::from)
+    }
+}
@@ -2,7 +2,10 @@ use bon::Builder;
 use diesel::prelude::Insertable;
 use uuid::Uuid;
 
-use crate::persistence::{record::AccountKind, schema};
+use crate::persistence::{
+    record::{AccountKind, Action, Interface, KeyScheme, Outcome},
+    schema,
+};
 
 #[derive(Debug, Builder, Insertable)]
 #[diesel(table_name = schema::multisig_account)]
@@ -10,6 +13,8 @@ pub struct NewMultisigAccountRecord<'a> {
     address: &'a str,
     threshold: i64,
     kind: AccountKind,
+    nonce: Option<i64>,
+    interface: Interface,
 }
 
 #[derive(Debug, Builder, Insertable)]
@@ -17,6 +22,7 @@ pub struct NewMultisigAccountRecord<'a> {
 pub struct NewApproverRecord<'a> {
     address: &'a str,
     pub_key_commit: &'a [u8],
+    scheme: KeyScheme,
 }
 
 #[derive(Debug, Builder, Insertable)]
@@ -26,6 +32,9 @@ pub struct NewTxRecord<'a> {
     tx_request: &'a [u8],
     tx_summary: &'a [u8],
     tx_summary_commit: &'a [u8],
+    tx_effect: &'a [u8],
+    title: Option<&'a str>,
+    memo: Option<&'a str>,
 }
 
 #[derive(Debug, Builder, Insertable)]
@@ -35,3 +44,28 @@ pub struct NewSignatureRecord<'a> {
     approver_address: &'a str,
     signature_bytes: &'a [u8],
 }
+
+#[derive(Debug, Builder, Insertable)]
+#[diesel(table_name = schema::audit_log)]
+pub struct NewAuditEventRecord<'a> {
+    multisig_account_address: &'a str,
+    tx_id: Option<Uuid>,
+    actor: Option<&'a str>,
+    action: Action,
+    outcome: Outcome,
+    detail: Option<&'a str>,
+}
+
+#[derive(Debug, Builder, Insertable)]
+#[diesel(table_name = schema::tx_input_note)]
+pub struct NewTxInputNoteRecord<'a> {
+    tx_id: Uuid,
+    note_index: i64,
+    note_id: &'a [u8],
+}
+
+impl NewSignatureRecord<'_> {
+    pub fn tx_id(&self) -> Uuid {
+        self.tx_id
+    }
+}
@@ -1,9 +1,10 @@
 use chrono::{DateTime, Utc};
 use diesel::prelude::Queryable;
 use dissolve_derive::Dissolve;
+use miden_multisig_coordinator_domain::key::ApproverKeyScheme;
 use uuid::Uuid;
 
-use crate::persistence::record::{AccountKind, TxStatus};
+use crate::persistence::record::{AccountKind, Action, Interface, KeyScheme, Outcome, TxStatus};
 
 #[derive(Debug, Dissolve, Queryable)]
 pub struct MultisigAccountRecord {
@@ -11,6 +12,9 @@ pub struct MultisigAccountRecord {
     kind: AccountKind,
     threshold: i64,
     created_at: DateTime<Utc>,
+    archived_at: Option<DateTime<Utc>>,
+    nonce: Option<i64>,
+    interface: Interface,
 }
 
 #[derive(Debug, Dissolve, Queryable)]
@@ -18,6 +22,7 @@ pub struct ApproverRecord {
     address: String,
     pub_key_commit: Vec<u8>,
     created_at: DateTime<Utc>,
+    scheme: KeyScheme,
 }
 
 #[derive(Debug, Dissolve, Queryable)]
@@ -29,4 +34,72 @@ pub struct TxRecord {
     tx_summary: Vec<u8>,
     tx_summary_commit: Vec<u8>,
     created_at: DateTime<Utc>,
+    title: Option<String>,
+    memo: Option<String>,
+    failure_reason: Option<String>,
+    tx_effect: Option<Vec<u8>>,
+    chain_tx_id: Option<Vec<u8>>,
+    submission_block_num: Option<i64>,
+    updated_at: DateTime<Utc>,
+    threshold_met_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Dissolve, Queryable)]
+pub struct AuditEventRecord {
+    id: Uuid,
+    multisig_account_address: String,
+    tx_id: Option<Uuid>,
+    actor: Option<String>,
+    action: Action,
+    outcome: Outcome,
+    detail: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl MultisigAccountRecord {
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
+
+impl ApproverRecord {
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    pub fn scheme(&self) -> ApproverKeyScheme {
+        self.scheme.0
+    }
+}
+
+impl AuditEventRecord {
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+}
+
+impl TxRecord {
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    pub fn multisig_account_address(&self) -> &str {
+        &self.multisig_account_address
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        self.updated_at
+    }
 }
@@ -5,28 +5,69 @@ pub mod sql_types {
     #[diesel(postgres_type(name = "account_kind"))]
     pub struct AccountKind;
 
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "address_interface"))]
+    pub struct AddressInterface;
+
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "approver_key_scheme"))]
+    pub struct ApproverKeyScheme;
+
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "audit_action"))]
+    pub struct AuditAction;
+
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "audit_outcome"))]
+    pub struct AuditOutcome;
+
     #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
     #[diesel(postgres_type(name = "tx_status"))]
     pub struct TxStatus;
 }
 
 diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::ApproverKeyScheme;
+
     approver (address) {
         address -> Text,
         pub_key_commit -> Bytea,
         created_at -> Timestamptz,
+        scheme -> ApproverKeyScheme,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::AuditAction;
+    use super::sql_types::AuditOutcome;
+
+    audit_log (id) {
+        id -> Uuid,
+        multisig_account_address -> Text,
+        tx_id -> Nullable<Uuid>,
+        actor -> Nullable<Text>,
+        action -> AuditAction,
+        outcome -> AuditOutcome,
+        detail -> Nullable<Text>,
+        created_at -> Timestamptz,
     }
 }
 
 diesel::table! {
     use diesel::sql_types::*;
     use super::sql_types::AccountKind;
+    use super::sql_types::AddressInterface;
 
     multisig_account (address) {
         address -> Text,
         kind -> AccountKind,
         threshold -> Int8,
         created_at -> Timestamptz,
+        archived_at -> Nullable<Timestamptz>,
+        nonce -> Nullable<Int8>,
+        interface -> AddressInterface,
     }
 }
 
@@ -35,6 +76,9 @@ diesel::table! {
         multisig_account_address -> Text,
         approver_address -> Text,
         approver_index -> Int8,
+        weight -> Int4,
+        label -> Nullable<Text>,
+        removed_at -> Nullable<Timestamptz>,
     }
 }
 
@@ -59,19 +103,39 @@ diesel::table! {
         tx_summary -> Bytea,
         tx_summary_commit -> Bytea,
         created_at -> Timestamptz,
+        title -> Nullable<Text>,
+        memo -> Nullable<Text>,
+        failure_reason -> Nullable<Text>,
+        tx_effect -> Nullable<Bytea>,
+        chain_tx_id -> Nullable<Bytea>,
+        submission_block_num -> Nullable<Int8>,
+        updated_at -> Timestamptz,
+        threshold_met_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    tx_input_note (tx_id, note_index) {
+        tx_id -> Uuid,
+        note_index -> Int8,
+        note_id -> Bytea,
     }
 }
 
+diesel::joinable!(audit_log -> multisig_account (multisig_account_address));
 diesel::joinable!(multisig_account_approver_mapping -> approver (approver_address));
 diesel::joinable!(multisig_account_approver_mapping -> multisig_account (multisig_account_address));
 diesel::joinable!(signature -> approver (approver_address));
 diesel::joinable!(signature -> tx (tx_id));
 diesel::joinable!(tx -> multisig_account (multisig_account_address));
+diesel::joinable!(tx_input_note -> tx (tx_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     approver,
+    audit_log,
     multisig_account,
     multisig_account_approver_mapping,
     signature,
     tx,
+    tx_input_note,
 );
@@ -1,18 +1,22 @@
 mod error;
+mod retry;
 
-pub use self::error::StoreError;
+pub use self::{error::StoreError, retry::retry_transient};
 
 use chrono::{DateTime, Months, Utc};
 use diesel::{
     AggregateExpressionMethods, BoolExpressionMethods, ExpressionMethods, JoinOnDsl,
-    NullableExpressionMethods, QueryDsl, dsl,
+    NullableExpressionMethods, PgTextExpressionMethods, QueryDsl, dsl,
     result::OptionalExtension,
-    sql_types::{Bytea, Nullable},
+    sql_types::{self, BigInt, Bool, Bytea, Double, Nullable, Timestamptz},
     upsert,
 };
 use diesel_async::RunQueryDsl;
 use futures::{Stream, TryStreamExt};
-use miden_multisig_coordinator_domain::tx::{MultisigTxStats, MultisigTxStatus};
+use miden_multisig_coordinator_domain::{
+    cursor::Cursor,
+    tx::{MultisigTxStatus, SortDirection, TxSort, TxSortField},
+};
 use oblux::U63;
 use uuid::Uuid;
 
@@ -21,14 +25,26 @@ use crate::persistence::record::{TxStatus, select::ApproverRecord};
 use super::{
     pool::DbConn,
     record::{
-        insert::{NewApproverRecord, NewMultisigAccountRecord, NewSignatureRecord, NewTxRecord},
-        select::{MultisigAccountRecord, TxRecord},
+        Interface,
+        insert::{
+            NewApproverRecord, NewAuditEventRecord, NewMultisigAccountRecord, NewSignatureRecord,
+            NewTxInputNoteRecord, NewTxRecord,
+        },
+        select::{AuditEventRecord, MultisigAccountRecord, TxRecord},
     },
     schema,
 };
 
 use self::error::Result;
 
+/// The primary key constraint of the `multisig_account` table, violated when creating an account
+/// that already exists.
+pub const MULTISIG_ACCOUNT_PKEY: &str = "multisig_account_pkey";
+
+/// The check enforced by a trigger on the `tx` table, violated when changing the summary
+/// commitment of a transaction that holds signatures.
+pub const TX_SIGNED_COMMITMENT_IMMUTABLE: &str = "tx_signed_commitment_immutable";
+
 #[tracing::instrument(skip_all)]
 pub async fn fetch_mutisig_account_by_address(
     conn: &mut DbConn,
@@ -42,57 +58,297 @@ pub async fn fetch_mutisig_account_by_address(
         .map_err(From::from)
 }
 
+/// Fetches a multisig account and locks its row until the end of the current transaction, so
+/// that concurrent changes to its approvers are serialized.
+#[tracing::instrument(skip_all)]
+pub async fn fetch_mutisig_account_for_update_by_address(
+    conn: &mut DbConn,
+    address: &str,
+) -> Result<Option<MultisigAccountRecord>> {
+    schema::multisig_account::table
+        .filter(schema::multisig_account::address.eq(address))
+        .for_update()
+        .first(conn)
+        .await
+        .optional()
+        .map_err(From::from)
+}
+
+/// Fetches the address, index and weight of every approver of a multisig account, ordered by
+/// approver index. Removed approvers are left out.
+#[tracing::instrument(skip_all)]
+pub async fn fetch_approver_weights_by_multisig_account_address(
+    conn: &mut DbConn,
+    multisig_account_address: &str,
+) -> Result<Vec<(String, i64, i32)>> {
+    schema::multisig_account_approver_mapping::table
+        .filter(
+            schema::multisig_account_approver_mapping::multisig_account_address
+                .eq(multisig_account_address),
+        )
+        .filter(schema::multisig_account_approver_mapping::removed_at.is_null())
+        .order_by(schema::multisig_account_approver_mapping::approver_index.asc())
+        .select((
+            schema::multisig_account_approver_mapping::approver_address,
+            schema::multisig_account_approver_mapping::approver_index,
+            schema::multisig_account_approver_mapping::weight,
+        ))
+        .load(conn)
+        .await
+        .map_err(From::from)
+}
+
+#[tracing::instrument(skip_all)]
+pub async fn fetch_multisig_account_threshold_with_approver_count_by_address(
+    conn: &mut DbConn,
+    address: &str,
+) -> Result<Option<(i64, i64)>> {
+    schema::multisig_account::table
+        .left_join(
+            schema::multisig_account_approver_mapping::table.on(
+                schema::multisig_account_approver_mapping::multisig_account_address
+                    .eq(schema::multisig_account::address)
+                    .and(schema::multisig_account_approver_mapping::removed_at.is_null()),
+            ),
+        )
+        .filter(schema::multisig_account::address.eq(address))
+        .group_by((schema::multisig_account::address, schema::multisig_account::threshold))
+        .select((
+            schema::multisig_account::threshold,
+            dsl::count(schema::multisig_account_approver_mapping::approver_address.nullable()),
+        ))
+        .first(conn)
+        .await
+        .optional()
+        .map_err(From::from)
+}
+
+#[tracing::instrument(skip_all)]
+pub async fn fetch_multisig_account_with_approvers_by_address(
+    conn: &mut DbConn,
+    address: &str,
+) -> Result<Vec<(MultisigAccountRecord, ApproverRecord, i32, Option<String>)>> {
+    schema::multisig_account::table
+        .inner_join(
+            schema::multisig_account_approver_mapping::table
+                .on(schema::multisig_account_approver_mapping::multisig_account_address
+                    .eq(schema::multisig_account::address)),
+        )
+        .inner_join(
+            schema::approver::table.on(schema::approver::address
+                .eq(schema::multisig_account_approver_mapping::approver_address)),
+        )
+        .filter(schema::multisig_account::address.eq(address))
+        .filter(schema::multisig_account_approver_mapping::removed_at.is_null())
+        .order_by(schema::multisig_account_approver_mapping::approver_index.asc())
+        .select((
+            schema::multisig_account::all_columns,
+            schema::approver::all_columns,
+            schema::multisig_account_approver_mapping::weight,
+            schema::multisig_account_approver_mapping::label,
+        ))
+        .load(conn)
+        .await
+        .map_err(From::from)
+}
+
+/// Streams the multisig accounts in creation order, streaming at most `limit` ones if given.
+///
+/// Pages are walked with keyset pagination: given the `(created_at, address)` of the last
+/// account of the previous page as `after`, only the accounts ordered strictly after it are
+/// streamed.
 #[tracing::instrument(skip_all)]
 pub async fn stream_multisig_accounts(
     conn: &mut DbConn,
+    include_archived: bool,
+    after: Option<Cursor<String>>,
+    limit: Option<i64>,
 ) -> Result<impl Stream<Item = Result<MultisigAccountRecord>>> {
-    let stream = schema::multisig_account::table
+    let mut query = schema::multisig_account::table
+        .order_by((
+            schema::multisig_account::created_at.asc(),
+            schema::multisig_account::address.asc(),
+        ))
         .select(schema::multisig_account::all_columns)
-        .load_stream(conn)
-        .await?
-        .map_err(From::from);
+        .into_boxed();
+
+    if !include_archived {
+        query = query.filter(schema::multisig_account::archived_at.is_null());
+    }
+
+    if let Some(cursor) = after {
+        // a row comparison lets PostgreSQL use the `(created_at, address)` ordering as a single key
+        query = query.filter(
+            dsl::sql::<Bool>("(multisig_account.created_at, multisig_account.address) > (")
+                .bind::<Timestamptz, _>(cursor.timestamp())
+                .sql(", ")
+                .bind::<sql_types::Text, _>(cursor.id().clone())
+                .sql(")"),
+        );
+    }
+
+    if let Some(limit) = limit {
+        query = query.limit(limit);
+    }
+
+    let stream = query.load_stream(conn).await?.map_err(From::from);
 
     Ok(stream)
 }
 
+/// Fetches the transactions of a multisig account along with their signature counts, in the
+/// given sort order, optionally filtered by status.
+///
+/// Pages are walked with keyset pagination: given the `(sort key, id)` of the last transaction
+/// of the previous page as `after`, only the transactions ordered strictly after it are fetched,
+/// so PostgreSQL seeks to the cursor instead of scanning skipped rows. The cursor carries the
+/// sort key the transaction had when listed, so the next page neither skips nor repeats
+/// transactions when it changes or is deleted in between.
 #[tracing::instrument(skip_all)]
-pub async fn stream_txs_with_signature_count_by_multisig_account_address(
+pub async fn fetch_txs_with_signature_count_by_multisig_account_address(
     conn: &mut DbConn,
     multisig_account_address: &str,
-) -> Result<impl Stream<Item = Result<(TxRecord, U63)>>> {
-    let stream = schema::tx::table
+    tx_status: Option<TxStatus>,
+    sort: TxSort,
+    after: Option<Cursor>,
+    limit: Option<i64>,
+) -> Result<Vec<(TxRecord, U63)>> {
+    let signature_count = dsl::count(schema::signature::tx_id.nullable());
+
+    let mut query = schema::tx::table
         .left_join(schema::signature::table.on(schema::signature::tx_id.eq(schema::tx::id)))
         .filter(schema::tx::multisig_account_address.eq(multisig_account_address))
         .group_by(schema::tx::all_columns)
-        .select((schema::tx::all_columns, dsl::count(schema::signature::tx_id.nullable())))
-        .order_by(schema::tx::created_at.desc())
-        .load_stream::<(_, i64)>(conn)
-        .await?
-        .map_ok(|(txr, c)| (txr, U63::from_signed(c).unwrap())) // unwrap is safe because count >= 0
-        .map_err(From::from);
+        .select((schema::tx::all_columns, signature_count))
+        .into_boxed();
 
-    Ok(stream)
+    // only known columns are ever ordered by, the sort is never spliced into the query as text
+    query = match (sort.field(), sort.direction()) {
+        (TxSortField::CreatedAt, SortDirection::Asc) => {
+            query.order_by((schema::tx::created_at.asc(), schema::tx::id.asc()))
+        },
+        (TxSortField::CreatedAt, SortDirection::Desc) => {
+            query.order_by((schema::tx::created_at.desc(), schema::tx::id.desc()))
+        },
+        (TxSortField::UpdatedAt, SortDirection::Asc) => {
+            query.order_by((schema::tx::updated_at.asc(), schema::tx::id.asc()))
+        },
+        (TxSortField::UpdatedAt, SortDirection::Desc) => {
+            query.order_by((schema::tx::updated_at.desc(), schema::tx::id.desc()))
+        },
+        (TxSortField::SignatureCount, SortDirection::Asc) => {
+            query.order_by((signature_count.asc(), schema::tx::id.asc()))
+        },
+        (TxSortField::SignatureCount, SortDirection::Desc) => {
+            query.order_by((signature_count.desc(), schema::tx::id.desc()))
+        },
+    };
+
+    if let Some(tx_status) = tx_status {
+        query = query.filter(schema::tx::status.eq(tx_status));
+    }
+
+    if let Some(cursor) = after {
+        let cmp = match sort.direction() {
+            SortDirection::Asc => ") > (",
+            SortDirection::Desc => ") < (",
+        };
+
+        // a row comparison lets PostgreSQL use the `(<sort key>, id)` ordering as a single key
+        query = match sort.field() {
+            TxSortField::CreatedAt => query.filter(
+                dsl::sql::<Bool>("(tx.created_at, tx.id")
+                    .sql(cmp)
+                    .bind::<Timestamptz, _>(cursor.timestamp())
+                    .sql(", ")
+                    .bind::<sql_types::Uuid, _>(*cursor.id())
+                    .sql(")"),
+            ),
+            TxSortField::UpdatedAt => query.filter(
+                dsl::sql::<Bool>("(tx.updated_at, tx.id")
+                    .sql(cmp)
+                    .bind::<Timestamptz, _>(cursor.timestamp())
+                    .sql(", ")
+                    .bind::<sql_types::Uuid, _>(*cursor.id())
+                    .sql(")"),
+            ),
+            // the count is aggregated by the grouping, so it is filtered on once grouped
+            TxSortField::SignatureCount => query.having(
+                dsl::sql::<Bool>("(count(signature.tx_id), tx.id")
+                    .sql(cmp)
+                    .bind::<BigInt, _>(cursor.count())
+                    .sql(", ")
+                    .bind::<sql_types::Uuid, _>(*cursor.id())
+                    .sql(")"),
+            ),
+        };
+    }
+
+    if let Some(limit) = limit {
+        query = query.limit(limit);
+    }
+
+    let txs = query.load::<(TxRecord, i64)>(conn).await?;
+
+    // unwrap is safe because count >= 0
+    Ok(txs.into_iter().map(|(txr, c)| (txr, U63::from_signed(c).unwrap())).collect())
 }
 
+/// Fetches the pending transactions of every multisig account not archived that the given
+/// approver belongs to, along with their signature counts, most recent first.
+///
+/// Pages are walked with keyset pagination: given the `(created_at, id)` of the last transaction
+/// of the previous page as `after`, only the transactions created before it are fetched.
 #[tracing::instrument(skip_all)]
-pub async fn stream_txs_with_signature_count_by_multisig_account_address_and_status(
+pub async fn fetch_pending_txs_with_signature_count_by_approver_address(
     conn: &mut DbConn,
-    multisig_account_address: &str,
-    tx_status: TxStatus,
-) -> Result<impl Stream<Item = Result<(TxRecord, U63)>>> {
-    let stream = schema::tx::table
+    approver_address: &str,
+    after: Option<Cursor>,
+    limit: Option<i64>,
+) -> Result<Vec<(TxRecord, U63)>> {
+    let mut query = schema::tx::table
+        .inner_join(
+            schema::multisig_account_approver_mapping::table.on(
+                schema::multisig_account_approver_mapping::multisig_account_address
+                    .eq(schema::tx::multisig_account_address)
+                    .and(
+                        schema::multisig_account_approver_mapping::approver_address
+                            .eq(approver_address),
+                    )
+                    .and(schema::multisig_account_approver_mapping::removed_at.is_null()),
+            ),
+        )
+        .inner_join(
+            schema::multisig_account::table
+                .on(schema::multisig_account::address.eq(schema::tx::multisig_account_address)),
+        )
         .left_join(schema::signature::table.on(schema::signature::tx_id.eq(schema::tx::id)))
-        .filter(schema::tx::multisig_account_address.eq(multisig_account_address))
-        .filter(schema::tx::status.eq(tx_status))
+        .filter(schema::tx::status.eq(TxStatus::from(MultisigTxStatus::Pending)))
+        .filter(schema::multisig_account::archived_at.is_null())
         .group_by(schema::tx::all_columns)
         .select((schema::tx::all_columns, dsl::count(schema::signature::tx_id.nullable())))
-        .order_by(schema::tx::created_at.desc())
-        .load_stream::<(_, i64)>(conn)
-        .await?
-        .map_ok(|(txr, c)| (txr, U63::from_signed(c).unwrap())) // unwrap is safe because count >= 0
-        .map_err(From::from);
+        .order_by((schema::tx::created_at.desc(), schema::tx::id.desc()))
+        .into_boxed();
 
-    Ok(stream)
+    if let Some(cursor) = after {
+        // a row comparison lets PostgreSQL use the `(created_at, id)` ordering as a single key
+        query = query.filter(
+            dsl::sql::<Bool>("(tx.created_at, tx.id) < (")
+                .bind::<Timestamptz, _>(cursor.timestamp())
+                .sql(", ")
+                .bind::<sql_types::Uuid, _>(*cursor.id())
+                .sql(")"),
+        );
+    }
+
+    if let Some(limit) = limit {
+        query = query.limit(limit);
+    }
+
+    let txs = query.load::<(TxRecord, i64)>(conn).await?;
+
+    // unwrap is safe because count >= 0
+    Ok(txs.into_iter().map(|(txr, c)| (txr, U63::from_signed(c).unwrap())).collect())
 }
 
 #[tracing::instrument(skip_all)]
@@ -112,11 +368,33 @@ pub async fn fetch_tx_with_signature_count_by_id(
         .map_err(From::from)
 }
 
+/// Fetches the most recent tx whose summary commitment is the given one.
+///
+/// Identical proposals share their summary commitment, the most recent of them is the one
+/// approvers are most likely to be signing.
+#[tracing::instrument(skip_all)]
+pub async fn fetch_tx_with_signature_count_by_summary_commit(
+    conn: &mut DbConn,
+    tx_summary_commit: &[u8],
+) -> Result<Option<(TxRecord, U63)>> {
+    schema::tx::table
+        .left_join(schema::signature::table.on(schema::signature::tx_id.eq(schema::tx::id)))
+        .filter(schema::tx::tx_summary_commit.eq(tx_summary_commit))
+        .group_by(schema::tx::all_columns)
+        .select((schema::tx::all_columns, dsl::count(schema::signature::tx_id.nullable())))
+        .order_by((schema::tx::created_at.desc(), schema::tx::id.desc()))
+        .first::<(_, i64)>(conn)
+        .await
+        .map(|(txr, c)| (txr, U63::from_signed(c).unwrap())) // unwrap is safe because count >= 0
+        .optional()
+        .map_err(From::from)
+}
+
 #[tracing::instrument(skip_all)]
 pub async fn fetch_tx_stats_by_multisig_account_address(
     conn: &mut DbConn,
     multisig_account_address: &str,
-) -> Result<MultisigTxStats> {
+) -> Result<(u64, u64, u64)> {
     schema::tx::table
         .filter(schema::tx::multisig_account_address.eq(multisig_account_address))
         .select((
@@ -129,21 +407,202 @@ pub async fn fetch_tx_stats_by_multisig_account_address(
         .first::<(i64, i64, i64)>(conn)
         .await
         .map(|(total, last_month, total_success)| {
-            MultisigTxStats::builder()
-                .total(total as u64)
-                .last_month(last_month as u64)
-                .total_success(total_success as u64)
-                .build() // casting i64 to u64 is safe as count >= 0
+            (total as u64, last_month as u64, total_success as u64) // safe as count >= 0
+        })
+        .map_err(From::from)
+}
+
+/// Counts the pending transactions of the multisig account.
+#[tracing::instrument(skip_all)]
+pub async fn count_pending_txs_by_multisig_account_address(
+    conn: &mut DbConn,
+    multisig_account_address: &str,
+) -> Result<u64> {
+    schema::tx::table
+        .filter(schema::tx::multisig_account_address.eq(multisig_account_address))
+        .filter(schema::tx::status.eq(TxStatus::from(MultisigTxStatus::Pending)))
+        .count()
+        .get_result::<i64>(conn)
+        .await
+        .map(|count| count as u64) // safe as count >= 0
+        .map_err(From::from)
+}
+
+/// Computes the average and the 95th percentile, in seconds, of the time the executed
+/// transactions of the multisig account took from their proposal to meeting their threshold.
+/// Both are `None` if no executed transaction recorded when it met its threshold.
+#[tracing::instrument(skip_all)]
+pub async fn fetch_time_to_threshold_stats_by_multisig_account_address(
+    conn: &mut DbConn,
+    multisig_account_address: &str,
+) -> Result<(Option<f64>, Option<f64>)> {
+    schema::tx::table
+        .filter(schema::tx::multisig_account_address.eq(multisig_account_address))
+        .filter(schema::tx::status.eq(TxStatus::from(MultisigTxStatus::Success)))
+        .filter(schema::tx::threshold_met_at.is_not_null())
+        .select((
+            dsl::sql::<Nullable<Double>>(
+                "avg(extract(epoch FROM tx.threshold_met_at - tx.created_at)::float8)",
+            ),
+            dsl::sql::<Nullable<Double>>(
+                "percentile_cont(0.95) WITHIN GROUP \
+                 (ORDER BY extract(epoch FROM tx.threshold_met_at - tx.created_at)::float8)",
+            ),
+        ))
+        .first::<(Option<f64>, Option<f64>)>(conn)
+        .await
+        .map_err(From::from)
+}
+
+/// Counts the transactions of the multisig account proposed within each `date_trunc` bucket of
+/// the given field, e.g. `day`, in UTC, ordered by bucket start. Buckets without transactions are
+/// left out.
+#[tracing::instrument(skip_all)]
+pub async fn fetch_tx_counts_by_multisig_account_address_bucketed(
+    conn: &mut DbConn,
+    multisig_account_address: &str,
+    date_trunc_field: &'static str,
+) -> Result<Vec<(DateTime<Utc>, u64)>> {
+    // the field is a static string, the bucket expression is spelled out rather than bound so
+    // that postgres matches the selected bucket against the grouped one
+    let bucket = || {
+        dsl::sql::<Timestamptz>(&format!("date_trunc('{date_trunc_field}', tx.created_at, 'UTC')"))
+    };
+
+    schema::tx::table
+        .filter(schema::tx::multisig_account_address.eq(multisig_account_address))
+        .group_by(bucket())
+        .order_by(bucket().asc())
+        .select((bucket(), dsl::count(schema::tx::id)))
+        .load::<(DateTime<Utc>, i64)>(conn)
+        .await
+        .map(|buckets| {
+            // safe as count >= 0
+            buckets.into_iter().map(|(start, count)| (start, count as u64)).collect()
+        })
+        .map_err(From::from)
+}
+
+/// Counts, for every approver of the multisig account, how many of the account's transactions
+/// they signed, ordered by approver index. Approvers who never signed are counted as zero, removed
+/// approvers are left out.
+#[tracing::instrument(skip_all)]
+pub async fn fetch_approver_signed_counts_by_multisig_account_address(
+    conn: &mut DbConn,
+    multisig_account_address: &str,
+) -> Result<Vec<(String, u64)>> {
+    schema::multisig_account_approver_mapping::table
+        .left_join(
+            schema::signature::table.on(schema::signature::approver_address
+                .eq(schema::multisig_account_approver_mapping::approver_address)),
+        )
+        .left_join(
+            schema::tx::table.on(schema::tx::id.eq(schema::signature::tx_id).and(
+                schema::tx::multisig_account_address
+                    .eq(schema::multisig_account_approver_mapping::multisig_account_address),
+            )),
+        )
+        .filter(
+            schema::multisig_account_approver_mapping::multisig_account_address
+                .eq(multisig_account_address),
+        )
+        .filter(schema::multisig_account_approver_mapping::removed_at.is_null())
+        .group_by((
+            schema::multisig_account_approver_mapping::approver_address,
+            schema::multisig_account_approver_mapping::approver_index,
+        ))
+        .order_by(schema::multisig_account_approver_mapping::approver_index.asc())
+        .select((
+            schema::multisig_account_approver_mapping::approver_address,
+            dsl::count(schema::tx::id.nullable()),
+        ))
+        .load::<(String, i64)>(conn)
+        .await
+        .map(|counts| {
+            counts
+                .into_iter()
+                .map(|(approver_address, count)| (approver_address, count as u64)) // count >= 0
+                .collect()
         })
         .map_err(From::from)
 }
 
+#[tracing::instrument(skip_all)]
+pub async fn fetch_recent_signatures_by_multisig_account_address(
+    conn: &mut DbConn,
+    multisig_account_address: &str,
+    limit: i64,
+) -> Result<Vec<(Uuid, String, DateTime<Utc>)>> {
+    schema::signature::table
+        .inner_join(schema::tx::table)
+        .inner_join(schema::approver::table)
+        .filter(schema::tx::multisig_account_address.eq(multisig_account_address))
+        .order_by((schema::signature::created_at.desc(), schema::signature::tx_id.asc()))
+        .limit(limit)
+        .select((
+            schema::signature::tx_id,
+            schema::approver::address,
+            schema::signature::created_at,
+        ))
+        .load(conn)
+        .await
+        .map_err(From::from)
+}
+
+/// Fetches the audit events of a multisig account, most recent first.
+///
+/// Pages are walked with keyset pagination: given the `(created_at, id)` of the last event of the
+/// previous page as `after`, only the events recorded before it are fetched.
+#[tracing::instrument(skip_all)]
+pub async fn fetch_audit_events_by_multisig_account_address(
+    conn: &mut DbConn,
+    multisig_account_address: &str,
+    after: Option<Cursor>,
+    limit: Option<i64>,
+) -> Result<Vec<AuditEventRecord>> {
+    let mut query = schema::audit_log::table
+        .filter(schema::audit_log::multisig_account_address.eq(multisig_account_address))
+        .order_by((schema::audit_log::created_at.desc(), schema::audit_log::id.desc()))
+        .select(schema::audit_log::all_columns)
+        .into_boxed();
+
+    if let Some(cursor) = after {
+        // a row comparison lets PostgreSQL use the `(created_at, id)` ordering as a single key
+        query = query.filter(
+            dsl::sql::<Bool>("(audit_log.created_at, audit_log.id) < (")
+                .bind::<Timestamptz, _>(cursor.timestamp())
+                .sql(", ")
+                .bind::<sql_types::Uuid, _>(*cursor.id())
+                .sql(")"),
+        );
+    }
+
+    if let Some(limit) = limit {
+        query = query.limit(limit);
+    }
+
+    query.load(conn).await.map_err(From::from)
+}
+
+/// Streams the approvers of a multisig account along with their approver index, in approver
+/// index order, streaming at most `limit` ones if given.
+///
+/// Pages are walked with keyset pagination: given the `(approver_index, approver_address)` of
+/// the last approver of the previous page as `after`, only the approvers ordered strictly after
+/// it are streamed.
+///
+/// Removed approvers are streamed only if `include_removed` is set, e.g. to line up with the
+/// on-chain configuration of the account, in which they keep their slot.
 #[tracing::instrument(skip_all)]
 pub async fn stream_approvers_by_multisig_account_address(
     conn: &mut DbConn,
     multisig_account_address: &str,
-) -> Result<impl Stream<Item = Result<ApproverRecord>> + use<>> {
-    let stream = schema::multisig_account_approver_mapping::table
+    include_removed: bool,
+    label_contains: Option<&str>,
+    after: Option<Cursor<String>>,
+    limit: Option<i64>,
+) -> Result<impl Stream<Item = Result<(ApproverRecord, i64, Option<String>)>> + use<>> {
+    let mut query = schema::multisig_account_approver_mapping::table
         .inner_join(
             schema::approver::table.on(schema::approver::address
                 .eq(schema::multisig_account_approver_mapping::approver_address)),
@@ -152,9 +611,49 @@ pub async fn stream_approvers_by_multisig_account_address(
             schema::multisig_account_approver_mapping::multisig_account_address
                 .eq(multisig_account_address),
         )
-        .order_by(schema::multisig_account_approver_mapping::approver_index.asc())
-        .select(schema::approver::all_columns)
-        .load_stream::<ApproverRecord>(conn)
+        .order_by((
+            schema::multisig_account_approver_mapping::approver_index.asc(),
+            schema::multisig_account_approver_mapping::approver_address.asc(),
+        ))
+        .select((
+            schema::approver::all_columns,
+            schema::multisig_account_approver_mapping::approver_index,
+            schema::multisig_account_approver_mapping::label,
+        ))
+        .into_boxed();
+
+    if !include_removed {
+        query = query.filter(schema::multisig_account_approver_mapping::removed_at.is_null());
+    }
+
+    if let Some(label_contains) = label_contains {
+        query = query.filter(
+            schema::multisig_account_approver_mapping::label
+                .ilike(contains_pattern(label_contains)),
+        );
+    }
+
+    if let Some(cursor) = after {
+        // a row comparison lets PostgreSQL use the `(approver_index, approver_address)` ordering
+        // as a single key
+        query = query.filter(
+            dsl::sql::<Bool>(
+                "(multisig_account_approver_mapping.approver_index, \
+                 multisig_account_approver_mapping.approver_address) > (",
+            )
+            .bind::<BigInt, _>(cursor.count())
+            .sql(", ")
+            .bind::<sql_types::Text, _>(cursor.id().clone())
+            .sql(")"),
+        );
+    }
+
+    if let Some(limit) = limit {
+        query = query.limit(limit);
+    }
+
+    let stream = query
+        .load_stream::<(ApproverRecord, i64, Option<String>)>(conn)
         .await?
         .map_err(From::from);
 
@@ -179,12 +678,18 @@ pub async fn fetch_approver_by_approver_address(
 pub async fn fetch_all_signature_bytes_with_tx_by_tx_id_in_order_of_approvers(
     conn: &mut DbConn,
     tx_id: Uuid,
-) -> Result<(Vec<Option<Vec<u8>>>, TxRecord)> {
+) -> Result<(Vec<Option<Vec<u8>>>, Vec<Option<DateTime<Utc>>>, TxRecord)> {
     diesel::define_sql_function! {
         #[aggregate]
         fn array_agg(expr: Nullable<Bytea>) -> Array<Nullable<Bytea>>;
     }
 
+    diesel::define_sql_function! {
+        #[aggregate]
+        #[sql_name = "array_agg"]
+        fn array_agg_timestamptz(expr: Nullable<Timestamptz>) -> Array<Nullable<Timestamptz>>;
+    }
+
     schema::tx::table
         .filter(schema::tx::id.eq(tx_id))
         .inner_join(
@@ -201,6 +706,8 @@ pub async fn fetch_all_signature_bytes_with_tx_by_tx_id_in_order_of_approvers(
         .select((
             array_agg(schema::signature::signature_bytes.nullable())
                 .aggregate_order(schema::multisig_account_approver_mapping::approver_index.asc()),
+            array_agg_timestamptz(schema::signature::created_at.nullable())
+                .aggregate_order(schema::multisig_account_approver_mapping::approver_index.asc()),
             schema::tx::all_columns,
         ))
         .first(conn)
@@ -208,6 +715,136 @@ pub async fn fetch_all_signature_bytes_with_tx_by_tx_id_in_order_of_approvers(
         .map_err(From::from)
 }
 
+#[tracing::instrument(skip_all)]
+pub async fn fetch_input_note_ids_by_tx_id(conn: &mut DbConn, tx_id: Uuid) -> Result<Vec<Vec<u8>>> {
+    schema::tx_input_note::table
+        .filter(schema::tx_input_note::tx_id.eq(tx_id))
+        .order_by(schema::tx_input_note::note_index.asc())
+        .select(schema::tx_input_note::note_id)
+        .load(conn)
+        .await
+        .map_err(From::from)
+}
+
+/// Fetches the number of approvers of each of the given multisig accounts, accounts without
+/// approvers being left out. Removed approvers are not counted.
+#[tracing::instrument(skip_all)]
+pub async fn fetch_approver_counts_by_multisig_account_addresses(
+    conn: &mut DbConn,
+    multisig_account_addresses: &[String],
+) -> Result<Vec<(String, i64)>> {
+    schema::multisig_account_approver_mapping::table
+        .filter(
+            schema::multisig_account_approver_mapping::multisig_account_address
+                .eq_any(multisig_account_addresses),
+        )
+        .filter(schema::multisig_account_approver_mapping::removed_at.is_null())
+        .group_by(schema::multisig_account_approver_mapping::multisig_account_address)
+        .select((
+            schema::multisig_account_approver_mapping::multisig_account_address,
+            dsl::count_star(),
+        ))
+        .load(conn)
+        .await
+        .map_err(From::from)
+}
+
+/// Fetches the number of transactions not executed yet, i.e. pending, ready to execute or
+/// executing, of each of the given multisig accounts, accounts without such transactions being
+/// left out.
+#[tracing::instrument(skip_all)]
+pub async fn fetch_unexecuted_tx_counts_by_multisig_account_addresses(
+    conn: &mut DbConn,
+    multisig_account_addresses: &[String],
+) -> Result<Vec<(String, i64)>> {
+    let unexecuted_statuses = [
+        MultisigTxStatus::Pending,
+        MultisigTxStatus::ReadyToExecute,
+        MultisigTxStatus::Executing,
+    ];
+
+    schema::tx::table
+        .filter(schema::tx::multisig_account_address.eq_any(multisig_account_addresses))
+        .filter(schema::tx::status.eq_any(unexecuted_statuses.map(TxStatus::from)))
+        .group_by(schema::tx::multisig_account_address)
+        .select((schema::tx::multisig_account_address, dsl::count_star()))
+        .load(conn)
+        .await
+        .map_err(From::from)
+}
+
+/// Checks whether the multisig account has transactions not executed yet, i.e. pending, ready to
+/// execute or executing.
+#[tracing::instrument(skip_all)]
+pub async fn exists_unexecuted_tx_by_multisig_account_address(
+    conn: &mut DbConn,
+    multisig_account_address: &str,
+) -> Result<bool> {
+    let unexecuted_statuses = [
+        MultisigTxStatus::Pending,
+        MultisigTxStatus::ReadyToExecute,
+        MultisigTxStatus::Executing,
+    ];
+
+    diesel::select(dsl::exists(
+        schema::tx::table
+            .filter(schema::tx::multisig_account_address.eq(multisig_account_address))
+            .filter(schema::tx::status.eq_any(unexecuted_statuses.map(TxStatus::from))),
+    ))
+    .get_result(conn)
+    .await
+    .map_err(From::from)
+}
+
+/// Fetches the thresholds and address interfaces of each of the given multisig accounts, unknown
+/// accounts being left out.
+#[tracing::instrument(skip_all)]
+pub async fn fetch_thresholds_and_interfaces_by_multisig_account_addresses(
+    conn: &mut DbConn,
+    multisig_account_addresses: &[String],
+) -> Result<Vec<(String, i64, Interface)>> {
+    schema::multisig_account::table
+        .filter(schema::multisig_account::address.eq_any(multisig_account_addresses))
+        .select((
+            schema::multisig_account::address,
+            schema::multisig_account::threshold,
+            schema::multisig_account::interface,
+        ))
+        .load(conn)
+        .await
+        .map_err(From::from)
+}
+
+/// Fetches the ids of the given transactions the approver has signed.
+#[tracing::instrument(skip_all)]
+pub async fn fetch_signed_tx_ids_by_approver_address(
+    conn: &mut DbConn,
+    approver_address: &str,
+    tx_ids: &[Uuid],
+) -> Result<Vec<Uuid>> {
+    schema::signature::table
+        .filter(schema::signature::approver_address.eq(approver_address))
+        .filter(schema::signature::tx_id.eq_any(tx_ids))
+        .select(schema::signature::tx_id)
+        .load(conn)
+        .await
+        .map_err(From::from)
+}
+
+#[tracing::instrument(skip_all)]
+pub async fn fetch_input_note_ids_by_tx_ids(
+    conn: &mut DbConn,
+    tx_ids: &[Uuid],
+) -> Result<Vec<(Uuid, Vec<u8>)>> {
+    schema::tx_input_note::table
+        .filter(schema::tx_input_note::tx_id.eq_any(tx_ids))
+        .order_by((schema::tx_input_note::tx_id.asc(), schema::tx_input_note::note_index.asc()))
+        .select((schema::tx_input_note::tx_id, schema::tx_input_note::note_id))
+        .load(conn)
+        .await
+        .map_err(From::from)
+}
+
 #[tracing::instrument(skip_all)]
 pub async fn save_new_tx(conn: &mut DbConn, new_tx: NewTxRecord<'_>) -> Result<Uuid> {
     diesel::insert_into(schema::tx::table)
@@ -218,6 +855,90 @@ pub async fn save_new_tx(conn: &mut DbConn, new_tx: NewTxRecord<'_>) -> Result<U
         .map_err(From::from)
 }
 
+#[tracing::instrument(skip_all)]
+pub async fn save_new_audit_event(
+    conn: &mut DbConn,
+    new_audit_event: NewAuditEventRecord<'_>,
+) -> Result<()> {
+    diesel::insert_into(schema::audit_log::table)
+        .values(new_audit_event)
+        .execute(conn)
+        .await?;
+
+    Ok(())
+}
+
+#[tracing::instrument(skip_all)]
+pub async fn save_new_tx_input_notes(
+    conn: &mut DbConn,
+    new_tx_input_notes: Vec<NewTxInputNoteRecord<'_>>,
+) -> Result<()> {
+    if new_tx_input_notes.is_empty() {
+        return Ok(());
+    }
+
+    diesel::insert_into(schema::tx_input_note::table)
+        .values(new_tx_input_notes)
+        .execute(conn)
+        .await?;
+
+    Ok(())
+}
+
+/// Sets or clears, when `archived_at` is `None`, the archival timestamp of a multisig account,
+/// returning the updated account if it exists.
+#[tracing::instrument(skip_all)]
+pub async fn update_archived_at_by_address(
+    conn: &mut DbConn,
+    address: &str,
+    archived_at: Option<DateTime<Utc>>,
+) -> Result<Option<MultisigAccountRecord>> {
+    diesel::update(
+        schema::multisig_account::table.filter(schema::multisig_account::address.eq(address)),
+    )
+    .set(schema::multisig_account::archived_at.eq(archived_at))
+    .returning(schema::multisig_account::all_columns)
+    .get_result(conn)
+    .await
+    .optional()
+    .map_err(From::from)
+}
+
+/// Sets the on-chain nonce of the multisig account of a tx.
+#[tracing::instrument(skip_all)]
+pub async fn update_nonce_by_tx_id(conn: &mut DbConn, tx_id: Uuid, nonce: i64) -> Result<()> {
+    let multisig_account_address = schema::tx::table
+        .filter(schema::tx::id.eq(tx_id))
+        .select(schema::tx::multisig_account_address);
+
+    diesel::update(
+        schema::multisig_account::table
+            .filter(schema::multisig_account::address.eq_any(multisig_account_address)),
+    )
+    .set(schema::multisig_account::nonce.eq(nonce))
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Sets the threshold of a multisig account, returning whether the account exists.
+#[tracing::instrument(skip_all)]
+pub async fn update_threshold_by_address(
+    conn: &mut DbConn,
+    address: &str,
+    threshold: i64,
+) -> Result<bool> {
+    let affected = diesel::update(
+        schema::multisig_account::table.filter(schema::multisig_account::address.eq(address)),
+    )
+    .set(schema::multisig_account::threshold.eq(threshold))
+    .execute(conn)
+    .await?;
+
+    Ok(affected == 1)
+}
+
 #[tracing::instrument(skip_all)]
 pub async fn update_status_by_tx_id(
     conn: &mut DbConn,
@@ -225,7 +946,51 @@ pub async fn update_status_by_tx_id(
     new_status: TxStatus,
 ) -> Result<bool> {
     let affected = diesel::update(schema::tx::dsl::tx.filter(schema::tx::id.eq(tx_id)))
-        .set(schema::tx::status.eq(new_status))
+        .set((schema::tx::status.eq(new_status), schema::tx::updated_at.eq(dsl::now)))
+        .execute(conn)
+        .await?;
+
+    assert!(affected <= 1, "duplicate tx id must not exist");
+
+    Ok(affected == 1)
+}
+
+/// Moves the transaction from the `from` status to the `to` one, returning whether it had the
+/// `from` status. The check and the update are a single statement, so a concurrent transition
+/// from the same status is applied only once.
+#[tracing::instrument(skip_all)]
+pub async fn update_status_from_by_tx_id(
+    conn: &mut DbConn,
+    tx_id: Uuid,
+    from: TxStatus,
+    to: TxStatus,
+) -> Result<bool> {
+    let affected = diesel::update(
+        schema::tx::table.filter(schema::tx::id.eq(tx_id).and(schema::tx::status.eq(from))),
+    )
+    .set((schema::tx::status.eq(to), schema::tx::updated_at.eq(dsl::now)))
+    .execute(conn)
+    .await?;
+
+    assert!(affected <= 1, "duplicate tx id must not exist");
+
+    Ok(affected == 1)
+}
+
+#[tracing::instrument(skip_all)]
+pub async fn update_status_to_success_with_chain_tx_by_tx_id(
+    conn: &mut DbConn,
+    tx_id: Uuid,
+    chain_tx_id: &[u8],
+    submission_block_num: i64,
+) -> Result<bool> {
+    let affected = diesel::update(schema::tx::dsl::tx.filter(schema::tx::id.eq(tx_id)))
+        .set((
+            schema::tx::status.eq(TxStatus::from(MultisigTxStatus::Success)),
+            schema::tx::chain_tx_id.eq(chain_tx_id),
+            schema::tx::submission_block_num.eq(submission_block_num),
+            schema::tx::updated_at.eq(dsl::now),
+        ))
         .execute(conn)
         .await?;
 
@@ -234,6 +999,83 @@ pub async fn update_status_by_tx_id(
     Ok(affected == 1)
 }
 
+#[tracing::instrument(skip_all)]
+pub async fn update_status_to_failure_with_reason_by_tx_id(
+    conn: &mut DbConn,
+    tx_id: Uuid,
+    failure_reason: &str,
+) -> Result<bool> {
+    let affected = diesel::update(schema::tx::dsl::tx.filter(schema::tx::id.eq(tx_id)))
+        .set((
+            schema::tx::status.eq(TxStatus::from(MultisigTxStatus::Failure)),
+            schema::tx::failure_reason.eq(failure_reason),
+            schema::tx::updated_at.eq(dsl::now),
+        ))
+        .execute(conn)
+        .await?;
+
+    assert!(affected <= 1, "duplicate tx id must not exist");
+
+    Ok(affected == 1)
+}
+
+/// Fetches the status of a transaction and locks its row until the end of the current
+/// transaction, so that concurrent status changes are serialized.
+#[tracing::instrument(skip_all)]
+pub async fn fetch_tx_status_for_update_by_id(
+    conn: &mut DbConn,
+    tx_id: Uuid,
+) -> Result<Option<TxStatus>> {
+    schema::tx::table
+        .filter(schema::tx::id.eq(tx_id))
+        .select(schema::tx::status)
+        .for_update()
+        .first(conn)
+        .await
+        .optional()
+        .map_err(From::from)
+}
+
+#[tracing::instrument(skip_all)]
+pub async fn reset_failed_tx_to_pending_with_summary_by_tx_id(
+    conn: &mut DbConn,
+    tx_id: Uuid,
+    tx_summary: &[u8],
+    tx_summary_commit: &[u8],
+    tx_effect: &[u8],
+) -> Result<bool> {
+    let affected = diesel::update(
+        schema::tx::dsl::tx.filter(
+            schema::tx::id
+                .eq(tx_id)
+                .and(schema::tx::status.eq(TxStatus::from(MultisigTxStatus::Failure))),
+        ),
+    )
+    .set((
+        schema::tx::status.eq(TxStatus::from(MultisigTxStatus::Pending)),
+        schema::tx::tx_summary.eq(tx_summary),
+        schema::tx::tx_summary_commit.eq(tx_summary_commit),
+        schema::tx::tx_effect.eq(tx_effect),
+        schema::tx::failure_reason.eq(None::<String>),
+        schema::tx::threshold_met_at.eq(None::<DateTime<Utc>>),
+        schema::tx::updated_at.eq(dsl::now),
+    ))
+    .execute(conn)
+    .await?;
+
+    assert!(affected <= 1, "duplicate tx id must not exist");
+
+    Ok(affected == 1)
+}
+
+#[tracing::instrument(skip_all)]
+pub async fn delete_signatures_by_tx_id(conn: &mut DbConn, tx_id: Uuid) -> Result<usize> {
+    diesel::delete(schema::signature::table.filter(schema::signature::tx_id.eq(tx_id)))
+        .execute(conn)
+        .await
+        .map_err(From::from)
+}
+
 #[tracing::instrument(skip_all)]
 pub async fn validate_approver_address_by_tx_id(
     conn: &mut DbConn,
@@ -249,13 +1091,75 @@ pub async fn validate_approver_address_by_tx_id(
             .filter(schema::tx::id.eq(tx_id))
             .filter(
                 schema::multisig_account_approver_mapping::approver_address.eq(approver_address),
-            ),
+            )
+            .filter(schema::multisig_account_approver_mapping::removed_at.is_null()),
+    ))
+    .get_result(conn)
+    .await
+    .map_err(From::from)
+}
+
+#[tracing::instrument(skip_all)]
+pub async fn fetch_multisig_account_address_by_tx_id(
+    conn: &mut DbConn,
+    tx_id: Uuid,
+) -> Result<Option<String>> {
+    schema::tx::table
+        .filter(schema::tx::id.eq(tx_id))
+        .select(schema::tx::multisig_account_address)
+        .first(conn)
+        .await
+        .optional()
+        .map_err(From::from)
+}
+
+#[tracing::instrument(skip_all)]
+pub async fn exists_tx_by_id(conn: &mut DbConn, tx_id: Uuid) -> Result<bool> {
+    diesel::select(dsl::exists(schema::tx::table.filter(schema::tx::id.eq(tx_id))))
+        .get_result(conn)
+        .await
+        .map_err(From::from)
+}
+
+#[tracing::instrument(skip_all)]
+pub async fn exists_signature_by_tx_id_and_approver_address(
+    conn: &mut DbConn,
+    tx_id: Uuid,
+    approver_address: &str,
+) -> Result<bool> {
+    diesel::select(dsl::exists(
+        schema::signature::table
+            .filter(schema::signature::tx_id.eq(tx_id))
+            .filter(schema::signature::approver_address.eq(approver_address)),
     ))
     .get_result(conn)
     .await
     .map_err(From::from)
 }
 
+#[tracing::instrument(skip_all)]
+pub async fn fetch_signed_weight_by_tx_id(conn: &mut DbConn, tx_id: Uuid) -> Result<i64> {
+    schema::signature::table
+        .inner_join(schema::tx::table)
+        .inner_join(
+            schema::multisig_account_approver_mapping::table.on(
+                schema::multisig_account_approver_mapping::multisig_account_address
+                    .eq(schema::tx::multisig_account_address)
+                    .and(
+                        schema::multisig_account_approver_mapping::approver_address
+                            .eq(schema::signature::approver_address),
+                    )
+                    .and(schema::multisig_account_approver_mapping::removed_at.is_null()),
+            ),
+        )
+        .filter(schema::signature::tx_id.eq(tx_id))
+        .select(dsl::sum(schema::multisig_account_approver_mapping::weight))
+        .get_result::<Option<i64>>(conn)
+        .await
+        .map(Option::unwrap_or_default)
+        .map_err(From::from)
+}
+
 #[tracing::instrument(skip_all)]
 pub async fn save_new_multisig_account(
     conn: &mut DbConn,
@@ -269,16 +1173,38 @@ pub async fn save_new_multisig_account(
         .map_err(From::from)
 }
 
+/// Saves a new signature, bumping the `updated_at` of the signed transaction.
 #[tracing::instrument(skip_all)]
 pub async fn save_new_signature(
     conn: &mut DbConn,
     new_signature: NewSignatureRecord<'_>,
 ) -> Result<()> {
+    let tx_id = new_signature.tx_id();
+
     diesel::insert_into(schema::signature::table)
         .values(new_signature)
         .execute(conn)
         .await?;
 
+    diesel::update(schema::tx::table.filter(schema::tx::id.eq(tx_id)))
+        .set(schema::tx::updated_at.eq(dsl::now))
+        .execute(conn)
+        .await?;
+
+    Ok(())
+}
+
+/// Records that the signatures of the transaction met its threshold, unless they already did.
+#[tracing::instrument(skip_all)]
+pub async fn set_threshold_met_at_by_tx_id(conn: &mut DbConn, tx_id: Uuid) -> Result<()> {
+    diesel::update(
+        schema::tx::table
+            .filter(schema::tx::id.eq(tx_id).and(schema::tx::threshold_met_at.is_null())),
+    )
+    .set(schema::tx::threshold_met_at.eq(dsl::now))
+    .execute(conn)
+    .await?;
+
     Ok(())
 }
 
@@ -288,13 +1214,20 @@ pub async fn save_new_multisig_account_approver_mapping(
     multisig_account_address: &str,
     approver_address: &str,
     approver_index: u32,
+    weight: u32,
+    label: Option<&str>,
 ) -> Result<()> {
+    let weight =
+        i32::try_from(weight).map_err(|_| StoreError::other("approver weight too large"))?;
+
     diesel::insert_into(schema::multisig_account_approver_mapping::table)
         .values((
             schema::multisig_account_approver_mapping::multisig_account_address
                 .eq(multisig_account_address),
             schema::multisig_account_approver_mapping::approver_address.eq(approver_address),
             schema::multisig_account_approver_mapping::approver_index.eq(i64::from(approver_index)),
+            schema::multisig_account_approver_mapping::weight.eq(weight),
+            schema::multisig_account_approver_mapping::label.eq(label),
         ))
         .execute(conn)
         .await?;
@@ -302,17 +1235,58 @@ pub async fn save_new_multisig_account_approver_mapping(
     Ok(())
 }
 
+/// Marks the approver of a multisig account as removed. The approver keeps its approver index, so
+/// that the indices of the approvers keep matching the on-chain configuration of the account.
+#[tracing::instrument(skip_all)]
+pub async fn update_multisig_account_approver_mapping_to_removed(
+    conn: &mut DbConn,
+    multisig_account_address: &str,
+    approver_address: &str,
+) -> Result<()> {
+    diesel::update(
+        schema::multisig_account_approver_mapping::table
+            .filter(
+                schema::multisig_account_approver_mapping::multisig_account_address
+                    .eq(multisig_account_address),
+            )
+            .filter(
+                schema::multisig_account_approver_mapping::approver_address.eq(approver_address),
+            ),
+    )
+    .set(schema::multisig_account_approver_mapping::removed_at.eq(dsl::now))
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
 #[tracing::instrument(skip_all)]
 pub async fn upsert_approver(conn: &mut DbConn, new_approver: NewApproverRecord<'_>) -> Result<()> {
     diesel::insert_into(schema::approver::table)
         .values(new_approver)
         .on_conflict(schema::approver::address)
         .do_update()
-        .set(
+        .set((
             schema::approver::pub_key_commit.eq(upsert::excluded(schema::approver::pub_key_commit)),
-        )
+            schema::approver::scheme.eq(upsert::excluded(schema::approver::scheme)),
+        ))
         .execute(conn)
         .await?;
 
     Ok(())
 }
+
+#[tracing::instrument(skip_all)]
+pub async fn ping(conn: &mut DbConn) -> Result<()> {
+    diesel::sql_query("SELECT 1").execute(conn).await?;
+
+    Ok(())
+}
+
+/// Makes a `LIKE` pattern matching any text containing `substring`, escaping the wildcards it
+/// holds.
+fn contains_pattern(substring: &str) -> String {
+    let escaped = substring.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+
+    format!("%{escaped}%")
+}
@@ -1,17 +1,69 @@
 use std::borrow::Cow;
 
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+
+/// The message of the error raised by postgres when a statement exceeds `statement_timeout`, i.e.
+/// SQLSTATE `57014`, in the `C` locale every pooled connection requests.
+const STATEMENT_TIMEOUT_MESSAGE: &str = "canceling statement due to statement timeout";
+
+/// The message of the error raised by postgres when it aborts a transaction to break a deadlock,
+/// i.e. SQLSTATE `40P01`, in the `C` locale every pooled connection requests.
+const DEADLOCK_DETECTED_MESSAGE: &str = "deadlock detected";
+
 pub type Result<T, E = StoreError> = core::result::Result<T, E>;
 
 #[derive(Debug, thiserror::Error)]
 pub enum StoreError {
     #[error("db error: {0}")]
-    Db(#[from] diesel::result::Error),
+    Db(#[from] DieselError),
 
     #[error("other error: {0}")]
     Other(Cow<'static, str>),
 }
 
 impl StoreError {
+    /// Returns whether the database canceled the statement because it exceeded the statement
+    /// timeout.
+    pub fn is_statement_timeout(&self) -> bool {
+        matches!(
+            self,
+            Self::Db(DieselError::DatabaseError(_, info))
+                if info.message() == STATEMENT_TIMEOUT_MESSAGE
+        )
+    }
+
+    /// Returns whether the database refused to write a row because it violates the given unique
+    /// constraint, e.g. because the row already exists.
+    pub fn is_unique_violation_of(&self, constraint: &str) -> bool {
+        matches!(
+            self,
+            Self::Db(DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, info))
+                if info.constraint_name() == Some(constraint)
+        )
+    }
+
+    /// Returns whether the database refused to write a row because it violates the given check,
+    /// e.g. one enforced by a trigger.
+    pub fn is_check_violation_of(&self, constraint: &str) -> bool {
+        matches!(
+            self,
+            Self::Db(DieselError::DatabaseError(DatabaseErrorKind::CheckViolation, info))
+                if info.constraint_name() == Some(constraint)
+        )
+    }
+
+    /// Returns whether the database aborted the transaction because of a conflict with a
+    /// concurrent transaction, i.e. a serialization failure (SQLSTATE `40001`) or a deadlock
+    /// (SQLSTATE `40P01`), in which case running the transaction again may succeed.
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            Self::Db(DieselError::DatabaseError(kind, info))
+                if matches!(kind, DatabaseErrorKind::SerializationFailure)
+                    || info.message() == DEADLOCK_DETECTED_MESSAGE
+        )
+    }
+
     pub fn other<E>(err: E) -> Self
     where
         Cow<'static, str>: From<E>,
@@ -0,0 +1,39 @@
+use core::time::Duration;
+
+use rand::Rng;
+
+use super::StoreError;
+
+/// The maximum number of attempts of a database transaction aborted by a transient failure.
+const MAX_ATTEMPTS: u32 = 4;
+
+/// The backoff before the second attempt, doubled before every following attempt.
+const BASE_BACKOFF: Duration = Duration::from_millis(20);
+
+/// Runs a database transaction, running it again when it is aborted by a transient failure.
+///
+/// Concurrent writes to the same rows, e.g. two approvers signing a transaction simultaneously,
+/// may abort a transaction with a serialization failure or a deadlock, which succeeds when run
+/// again. Such failures are retried up to `MAX_ATTEMPTS` times, with an exponential backoff
+/// plus a random jitter so that the conflicting transactions don't collide again. Any other
+/// error, or the transient failure of the last attempt, is returned unchanged.
+pub async fn retry_transient<T>(
+    mut transaction: impl AsyncFnMut() -> Result<T, StoreError>,
+) -> Result<T, StoreError> {
+    let mut attempt = 1;
+
+    loop {
+        match transaction().await {
+            Err(err) if err.is_transient() && attempt < MAX_ATTEMPTS => {
+                let backoff = BASE_BACKOFF * 2u32.pow(attempt - 1);
+                let jitter = rand::rng().random_range(Duration::ZERO..=backoff);
+
+                tracing::warn!(%err, attempt, "retrying transaction aborted by transient failure");
+
+                tokio::time::sleep(backoff + jitter).await;
+                attempt += 1;
+            },
+            result => return result,
+        }
+    }
+}
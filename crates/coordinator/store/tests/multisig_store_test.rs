@@ -0,0 +1,1806 @@
+//! integration tests for miden-multisig-coordinator-store
+
+use core::num::NonZeroU32;
+
+use diesel::{Connection, PgConnection, RunQueryDsl};
+use futures::TryStreamExt;
+use miden_client::{
+    ONE, Word,
+    account::{AccountIdAddress, AccountStorageMode, AddressInterface, NetworkId},
+    crypto::SecretKey,
+    note::NoteId,
+    transaction::TransactionRequestBuilder,
+    utils::Serializable,
+};
+use miden_multisig_coordinator_domain::{
+    account::{MultisigAccount, MultisigApprover, MultisigApproverDissolved},
+    key::{ApproverKey, ApproverSignature, EcdsaPubKey, EcdsaSignature},
+    tx::{
+        MultisigTxDissolved, MultisigTxStatsDissolved, MultisigTxStatus, SortDirection,
+        TrendGranularity, TxSort, TxSortField,
+    },
+};
+use miden_multisig_coordinator_store::{MultisigStoreBackend, MultisigStoreError};
+use miden_multisig_coordinator_utils::to_bech32;
+use miden_multisig_test_utils::{
+    InMemoryMultisigStore, account_id_address, conformance, empty_tx_summary, pay_to_id_tx_request,
+    setup_multisig_store, setup_test_db, tx_summary_of,
+};
+use miden_objects::{
+    account::{AccountDelta, AccountStorageDelta, AccountVaultDelta},
+    crypto::dsa::rpo_falcon512::PublicKey,
+    testing::account_id::{
+        ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET, ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE, ACCOUNT_ID_SENDER,
+    },
+    transaction::{InputNotes, OutputNotes, TransactionSummary},
+};
+
+#[tokio::test]
+async fn falcon_and_ecdsa_approver_keys_round_trip_through_store() {
+    // Arrange
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+    let bob_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE);
+
+    let alice_key = ApproverKey::Falcon(SecretKey::new().public_key());
+
+    let mut bob_pub_key = [0x5a; EcdsaPubKey::SERIALIZED_SIZE];
+    bob_pub_key[0] = 0x03;
+    let bob_key = ApproverKey::Ecdsa(EcdsaPubKey::from_bytes(&bob_pub_key).unwrap());
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::new(2).unwrap())
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr, bob_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![alice_key, bob_key])
+        .unwrap();
+
+    // Act
+    let created = store.create_multisig_account(multisig_account).await.unwrap();
+
+    let (approvers, _) = store
+        .get_approvers_by_multisig_account_address(
+            NetworkId::Testnet,
+            multisig_addr,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(created.pub_key_commits(), &[alice_key, bob_key]);
+
+    let approver_keys: Vec<_> = approvers
+        .into_iter()
+        .map(|approver| {
+            let MultisigApproverDissolved { address, pub_key_commit, .. } = approver.dissolve();
+            (address, pub_key_commit)
+        })
+        .collect();
+
+    assert_eq!(approver_keys, vec![(alice_addr, alice_key), (bob_addr, bob_key)]);
+}
+
+#[tokio::test]
+async fn signatures_not_verifying_against_the_approver_key_are_rejected_as_invalid() {
+    // Arrange
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+    let bob_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE);
+
+    let (alice_sk, bob_sk) = (SecretKey::new(), SecretKey::new());
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::new(2).unwrap())
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr, bob_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![
+            ApproverKey::Falcon(alice_sk.public_key()),
+            ApproverKey::Falcon(bob_sk.public_key()),
+        ])
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    let tx_request = pay_to_id_tx_request(multisig_addr.id(), alice_addr.id());
+
+    let tx_summary = empty_tx_summary(multisig_addr.id());
+
+    let tx_summary_commit = tx_summary.to_commitment();
+
+    let tx_id = store
+        .create_multisig_tx(
+            NetworkId::Testnet,
+            multisig_addr,
+            &tx_request,
+            &tx_summary,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    let alice_signature = ApproverSignature::from(alice_sk.sign(tx_summary_commit));
+    let signed_by_other_key = ApproverSignature::from(bob_sk.sign(tx_summary_commit));
+    let of_other_scheme = ApproverSignature::from(
+        EcdsaSignature::from_bytes(&[1; EcdsaSignature::SERIALIZED_SIZE]).unwrap(),
+    );
+
+    // Act
+    let other_key_result = store
+        .add_multisig_tx_signature(&tx_id, NetworkId::Testnet, alice_addr, &signed_by_other_key)
+        .await;
+
+    let other_scheme_result = store
+        .add_multisig_tx_signature(&tx_id, NetworkId::Testnet, alice_addr, &of_other_scheme)
+        .await;
+
+    let batch_result = store
+        .add_multisig_tx_signatures(
+            &tx_id,
+            NetworkId::Testnet,
+            &[(alice_addr, alice_signature), (bob_addr, of_other_scheme)],
+        )
+        .await;
+
+    // Assert
+    assert!(matches!(other_key_result, Err(MultisigStoreError::InvalidSignature)));
+    assert!(matches!(other_scheme_result, Err(MultisigStoreError::InvalidSignature)));
+    assert!(matches!(batch_result, Err(MultisigStoreError::InvalidSignature)));
+
+    // the valid signature of the batch is not stored either
+    assert!(!store.has_approver_signed(&tx_id, NetworkId::Testnet, alice_addr).await.unwrap());
+}
+
+#[tokio::test]
+async fn listing_approvers_with_cursor_and_limit_pages_in_approver_index_order() {
+    // Arrange
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let approver_addrs = vec![
+        account_id_address(ACCOUNT_ID_SENDER),
+        account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE),
+        account_id_address(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET),
+    ];
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::new(2).unwrap())
+        .aux(())
+        .build()
+        .with_approvers(approver_addrs.clone())
+        .unwrap()
+        .with_pub_key_commits(
+            (0..3).map(|_| ApproverKey::Falcon(SecretKey::new().public_key())).collect(),
+        )
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    // Act
+    let (all, all_cursor) = store
+        .get_approvers_by_multisig_account_address(
+            NetworkId::Testnet,
+            multisig_addr,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    let mut pages = Vec::new();
+    let mut after = None;
+    loop {
+        let (approvers, next_cursor) = store
+            .get_approvers_by_multisig_account_address(
+                NetworkId::Testnet,
+                multisig_addr,
+                None,
+                after,
+                NonZeroU32::new(2),
+            )
+            .await
+            .unwrap();
+
+        pages.push(approvers.iter().map(MultisigApprover::address).collect::<Vec<_>>());
+
+        match next_cursor {
+            Some(next_cursor) => after = Some(next_cursor),
+            None => break,
+        }
+    }
+
+    // Assert
+    assert_eq!(all.iter().map(MultisigApprover::address).collect::<Vec<_>>(), approver_addrs);
+    assert!(all_cursor.is_none(), "a listing without a limit is a single page");
+    assert_eq!(pages, [approver_addrs[..2].to_vec(), approver_addrs[2..].to_vec()]);
+}
+
+#[tokio::test]
+async fn approver_pub_keys_are_derived_from_stored_key_commitments_in_approver_index_order() {
+    // Arrange
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let unknown_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE);
+    let approver_addrs = vec![
+        account_id_address(ACCOUNT_ID_SENDER),
+        account_id_address(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET),
+    ];
+
+    let mut ecdsa_pub_key = [0x5a; EcdsaPubKey::SERIALIZED_SIZE];
+    ecdsa_pub_key[0] = 0x03;
+
+    let approver_keys = vec![
+        ApproverKey::Falcon(SecretKey::new().public_key()),
+        ApproverKey::Ecdsa(EcdsaPubKey::from_bytes(&ecdsa_pub_key).unwrap()),
+    ];
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::new(2).unwrap())
+        .aux(())
+        .build()
+        .with_approvers(approver_addrs)
+        .unwrap()
+        .with_pub_key_commits(approver_keys.clone())
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    // Act
+    let pub_keys = store
+        .get_approver_pub_keys_in_order(NetworkId::Testnet, multisig_addr)
+        .await
+        .unwrap();
+
+    let unknown_pub_keys = store
+        .get_approver_pub_keys_in_order(NetworkId::Testnet, unknown_addr)
+        .await
+        .unwrap();
+
+    // Assert
+    let expected_pub_keys =
+        approver_keys.iter().map(|key| PublicKey::new(key.commitment())).collect();
+
+    assert_eq!(pub_keys, Some(expected_pub_keys));
+    assert_eq!(unknown_pub_keys, None);
+}
+
+#[tokio::test]
+async fn approver_labels_are_kept_per_multisig_account_and_filter_approver_listing() {
+    // Arrange
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let first_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let second_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE);
+    let approver_addrs = vec![
+        account_id_address(ACCOUNT_ID_SENDER),
+        account_id_address(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET),
+    ];
+    let pub_key_commits: Vec<_> =
+        (0..2).map(|_| ApproverKey::Falcon(SecretKey::new().public_key())).collect();
+
+    // the second account shares the approvers of the first one and labels them differently
+    let first_labels = vec![Some("Alice - CFO".to_owned()), None];
+    let second_labels = vec![Some("Alice - Treasury".to_owned()), Some("Bob - cfo".to_owned())];
+    let accounts = [(first_addr, first_labels.clone()), (second_addr, second_labels.clone())];
+
+    for (multisig_addr, labels) in accounts {
+        let multisig_account = MultisigAccount::builder()
+            .address(multisig_addr)
+            .network_id(NetworkId::Testnet)
+            .kind(AccountStorageMode::Public)
+            .threshold(NonZeroU32::new(2).unwrap())
+            .aux(())
+            .build()
+            .with_approvers(approver_addrs.clone())
+            .unwrap()
+            .with_approver_labels(labels)
+            .unwrap()
+            .with_pub_key_commits(pub_key_commits.clone())
+            .unwrap();
+
+        store.create_multisig_account(multisig_account).await.unwrap();
+    }
+
+    let list_labels = async |multisig_addr, label_contains| {
+        store
+            .get_approvers_by_multisig_account_address(
+                NetworkId::Testnet,
+                multisig_addr,
+                label_contains,
+                None,
+                None,
+            )
+            .await
+            .unwrap()
+            .0
+            .into_iter()
+            .map(|approver| approver.dissolve().label)
+            .collect::<Vec<_>>()
+    };
+
+    // Act
+    let first_listed = list_labels(first_addr, None).await;
+    let second_listed = list_labels(second_addr, None).await;
+    let second_cfo_listed = list_labels(second_addr, Some("CFO")).await;
+    let second_wildcard_listed = list_labels(second_addr, Some("%")).await;
+
+    let fetched = store
+        .try_get_multisig_account(NetworkId::Testnet, first_addr)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Assert
+    assert_eq!(first_listed, first_labels);
+    assert_eq!(second_listed, second_labels);
+    assert_eq!(fetched.approver_labels(), first_labels);
+
+    // the label filter ignores case and matches wildcards literally
+    assert_eq!(second_cfo_listed, [Some("Bob - cfo".to_owned())]);
+    assert!(second_wildcard_listed.is_empty());
+}
+
+#[tokio::test]
+async fn removing_approver_is_refused_unless_threshold_is_lowered_to_remaining_weight() {
+    // Arrange
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let approver_addrs = vec![
+        account_id_address(ACCOUNT_ID_SENDER),
+        account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE),
+        account_id_address(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET),
+    ];
+    let pub_key_commits: Vec<_> =
+        (0..3).map(|_| ApproverKey::Falcon(SecretKey::new().public_key())).collect();
+
+    // every approver has to sign, so none can be removed without lowering the threshold
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::new(3).unwrap())
+        .aux(())
+        .build()
+        .with_approvers(approver_addrs.clone())
+        .unwrap()
+        .with_pub_key_commits(pub_key_commits.clone())
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    // Act
+    let refused = store
+        .remove_multisig_account_approver(NetworkId::Testnet, multisig_addr, approver_addrs[1])
+        .await;
+
+    let removed = store
+        .remove_multisig_account_approver_and_lower_threshold(
+            NetworkId::Testnet,
+            multisig_addr,
+            approver_addrs[1],
+            NonZeroU32::new(2).unwrap(),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+    let not_an_approver = store
+        .remove_multisig_account_approver(NetworkId::Testnet, multisig_addr, approver_addrs[1])
+        .await
+        .unwrap();
+
+    let (approvers, _) = store
+        .get_approvers_by_multisig_account_address(
+            NetworkId::Testnet,
+            multisig_addr,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    let indexed_approvers = store
+        .get_indexed_approvers_by_multisig_account_address(NetworkId::Testnet, multisig_addr)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Assert
+    assert!(matches!(
+        refused,
+        Err(MultisigStoreError::UnreachableThreshold { threshold: 3, remaining_weight: 2 })
+    ));
+
+    assert_eq!(removed.threshold().get(), 2);
+    assert_eq!(removed.approvers(), [approver_addrs[0], approver_addrs[2]]);
+    assert_eq!(removed.pub_key_commits(), [pub_key_commits[0], pub_key_commits[2]]);
+    assert!(not_an_approver.is_none());
+
+    let listed: Vec<_> = approvers.iter().map(MultisigApprover::address).collect();
+    assert_eq!(listed, [approver_addrs[0], approver_addrs[2]]);
+
+    // the approver following the removed one keeps its index, as in the on-chain configuration
+    let indices: Vec<_> = indexed_approvers.iter().map(|&(idx, _)| idx).collect();
+    assert_eq!(indices, [0, 2]);
+}
+
+#[tokio::test]
+async fn removing_approver_is_refused_while_multisig_account_has_pending_txs() {
+    // Arrange
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+    let bob_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE);
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::new(1).unwrap())
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr, bob_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![
+            ApproverKey::Falcon(SecretKey::new().public_key()),
+            ApproverKey::Falcon(SecretKey::new().public_key()),
+        ])
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    let tx_request = pay_to_id_tx_request(multisig_addr.id(), alice_addr.id());
+
+    let tx_summary = empty_tx_summary(multisig_addr.id());
+
+    let tx_id = store
+        .create_multisig_tx(
+            NetworkId::Testnet,
+            multisig_addr,
+            &tx_request,
+            &tx_summary,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    // Act
+    let refused = store
+        .remove_multisig_account_approver(NetworkId::Testnet, multisig_addr, bob_addr)
+        .await;
+
+    store
+        .update_multisig_tx_status_to_failure_by_id(&tx_id, "rejected")
+        .await
+        .unwrap();
+
+    let removed = store
+        .remove_multisig_account_approver(NetworkId::Testnet, multisig_addr, bob_addr)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // Assert
+    assert!(matches!(refused, Err(MultisigStoreError::PendingTxs)));
+    assert_eq!(removed.approvers(), [alice_addr]);
+}
+
+#[tokio::test]
+async fn archived_multisig_account_is_hidden_from_default_listing_but_still_fetchable() {
+    // Arrange
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let archived_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let active_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE);
+    let approver_addrs = vec![
+        account_id_address(ACCOUNT_ID_SENDER),
+        account_id_address(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET),
+    ];
+
+    for multisig_addr in [archived_addr, active_addr] {
+        let multisig_account = MultisigAccount::builder()
+            .address(multisig_addr)
+            .network_id(NetworkId::Testnet)
+            .kind(AccountStorageMode::Public)
+            .threshold(NonZeroU32::MIN)
+            .aux(())
+            .build()
+            .with_approvers(approver_addrs.clone())
+            .unwrap()
+            .with_pub_key_commits(
+                (0..2).map(|_| ApproverKey::Falcon(SecretKey::new().public_key())).collect(),
+            )
+            .unwrap();
+
+        store.create_multisig_account(multisig_account).await.unwrap();
+    }
+
+    // Act
+    let archived = store
+        .archive_multisig_account(NetworkId::Testnet, archived_addr)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let listed = store.get_all_multisig_accounts(false).await.unwrap();
+    let listed_with_archived = store.get_all_multisig_accounts(true).await.unwrap();
+
+    let fetched = store
+        .get_multisig_account(NetworkId::Testnet, archived_addr)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let unarchived = store
+        .unarchive_multisig_account(NetworkId::Testnet, archived_addr)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let listed_after_unarchival = store.get_all_multisig_accounts(false).await.unwrap();
+
+    // Assert
+    let addresses = |accounts: &[MultisigAccount]| {
+        accounts.iter().map(MultisigAccount::address).collect::<Vec<_>>()
+    };
+
+    assert!(archived.archived_at().is_some());
+
+    assert_eq!(addresses(&listed), [active_addr]);
+    assert_eq!(listed_with_archived.len(), 2);
+    assert!(addresses(&listed_with_archived).contains(&archived_addr));
+
+    assert_eq!(fetched.address(), archived_addr);
+    assert_eq!(fetched.archived_at(), archived.archived_at());
+
+    assert!(unarchived.archived_at().is_none());
+    assert_eq!(listed_after_unarchival.len(), 2);
+}
+
+#[tokio::test]
+async fn listing_multisig_accounts_with_counts_pages_through_accounts_in_creation_order() {
+    // Arrange
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let first_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let second_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE);
+    let approver_addrs = vec![
+        account_id_address(ACCOUNT_ID_SENDER),
+        account_id_address(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET),
+    ];
+
+    for (multisig_addr, approver_count) in [(first_addr, 2), (second_addr, 1)] {
+        let multisig_account = MultisigAccount::builder()
+            .address(multisig_addr)
+            .network_id(NetworkId::Testnet)
+            .kind(AccountStorageMode::Public)
+            .threshold(NonZeroU32::MIN)
+            .aux(())
+            .build()
+            .with_approvers(approver_addrs[..approver_count].to_vec())
+            .unwrap()
+            .with_pub_key_commits(
+                (0..approver_count)
+                    .map(|_| ApproverKey::Falcon(SecretKey::new().public_key()))
+                    .collect(),
+            )
+            .unwrap();
+
+        store.create_multisig_account(multisig_account).await.unwrap();
+    }
+
+    let tx_request = pay_to_id_tx_request(second_addr.id(), approver_addrs[0].id());
+
+    let tx_summary = empty_tx_summary(second_addr.id());
+
+    for _ in 0..2 {
+        store
+            .create_multisig_tx(
+                NetworkId::Testnet,
+                second_addr,
+                &tx_request,
+                &tx_summary,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+    }
+
+    // Act
+    let (listed, _) = store.get_multisig_accounts_with_counts(false, None, None).await.unwrap();
+
+    let (first_page, after) = store
+        .get_multisig_accounts_with_counts(false, None, NonZeroU32::new(1))
+        .await
+        .unwrap();
+
+    // archiving a listed account doesn't shift the next page
+    store.archive_multisig_account(NetworkId::Testnet, first_addr).await.unwrap();
+
+    let (second_page, after) = store
+        .get_multisig_accounts_with_counts(false, after, NonZeroU32::new(1))
+        .await
+        .unwrap();
+
+    let (past_the_end, last_cursor) = store
+        .get_multisig_accounts_with_counts(false, after, NonZeroU32::new(1))
+        .await
+        .unwrap();
+
+    // Assert
+    let overviews = |listed: &[(MultisigAccount, u32, u64)]| {
+        listed
+            .iter()
+            .map(|(multisig_account, approver_count, pending_tx_count)| {
+                (multisig_account.address(), *approver_count, *pending_tx_count)
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(overviews(&listed), [(first_addr, 2, 0), (second_addr, 1, 2)]);
+    assert_eq!(overviews(&first_page), [(first_addr, 2, 0)]);
+    assert_eq!(overviews(&second_page), [(second_addr, 1, 2)]);
+    assert!(past_the_end.is_empty());
+    assert!(last_cursor.is_none());
+}
+
+#[tokio::test]
+async fn retrieving_all_multisig_accounts_beyond_max_fails_while_paging_streams_them_all() {
+    // Arrange
+    let max = NonZeroU32::new(2).unwrap();
+
+    let store = setup_multisig_store(setup_test_db().await)
+        .await
+        .with_max_all_multisig_accounts(max);
+
+    let multisig_addrs = [
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE,
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
+        ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET,
+    ]
+    .map(account_id_address);
+
+    let approver_addr = account_id_address(ACCOUNT_ID_SENDER);
+
+    for multisig_addr in multisig_addrs {
+        let multisig_account = MultisigAccount::builder()
+            .address(multisig_addr)
+            .network_id(NetworkId::Testnet)
+            .kind(AccountStorageMode::Public)
+            .threshold(NonZeroU32::MIN)
+            .aux(())
+            .build()
+            .with_approvers(vec![approver_addr])
+            .unwrap()
+            .with_pub_key_commits(vec![ApproverKey::Falcon(SecretKey::new().public_key())])
+            .unwrap();
+
+        store.create_multisig_account(multisig_account).await.unwrap();
+    }
+
+    // Act
+    let result = store.get_all_multisig_accounts(true).await;
+
+    let streamed: Vec<_> = store
+        .stream_multisig_accounts_paged(true, max)
+        .map_ok(|multisig_account| multisig_account.address())
+        .try_collect()
+        .await
+        .unwrap();
+
+    // Assert
+    assert!(matches!(result, Err(MultisigStoreError::ResultTooLarge { max: m }) if m == max));
+    assert_eq!(streamed, multisig_addrs);
+}
+
+#[tokio::test]
+async fn pending_txs_for_approver_span_their_accounts_with_their_signatures() {
+    // Arrange
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let shared_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let bob_only_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+    let bob_addr = account_id_address(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET);
+
+    let alice_sk = SecretKey::new();
+
+    let shared_account = MultisigAccount::builder()
+        .address(shared_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::new(2).unwrap())
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr, bob_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![
+            ApproverKey::Falcon(alice_sk.public_key()),
+            ApproverKey::Falcon(SecretKey::new().public_key()),
+        ])
+        .unwrap();
+
+    let bob_only_account = MultisigAccount::builder()
+        .address(bob_only_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::MIN)
+        .aux(())
+        .build()
+        .with_approvers(vec![bob_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![ApproverKey::Falcon(SecretKey::new().public_key())])
+        .unwrap();
+
+    store.create_multisig_account(shared_account).await.unwrap();
+    store.create_multisig_account(bob_only_account).await.unwrap();
+
+    let tx_request = pay_to_id_tx_request(shared_addr.id(), alice_addr.id());
+
+    let tx_summary_of = |multisig_addr: AccountIdAddress| empty_tx_summary(multisig_addr.id());
+
+    let propose = async |multisig_addr| {
+        let tx_summary = tx_summary_of(multisig_addr);
+        let network_id = NetworkId::Testnet;
+        store
+            .create_multisig_tx(
+                network_id,
+                multisig_addr,
+                &tx_request,
+                &tx_summary,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap()
+    };
+
+    let signed_tx_id = propose(shared_addr).await;
+    let failed_tx_id = propose(shared_addr).await;
+    let bob_only_tx_id = propose(bob_only_addr).await;
+    let unsigned_tx_id = propose(shared_addr).await;
+
+    let signature =
+        ApproverSignature::from(alice_sk.sign(tx_summary_of(shared_addr).to_commitment()));
+    store
+        .add_multisig_tx_signature(&signed_tx_id, NetworkId::Testnet, alice_addr, &signature)
+        .await
+        .unwrap()
+        .expect("approver must be permitted to sign");
+
+    store
+        .update_multisig_tx_status_to_failure_by_id(&failed_tx_id, "rejected")
+        .await
+        .unwrap();
+
+    let pending_txs = async |approver_addr, after, limit| {
+        let (txs, next_cursor) = store
+            .get_pending_txs_for_approver(NetworkId::Testnet, approver_addr, after, limit)
+            .await
+            .unwrap();
+
+        let txs: Vec<_> = txs
+            .into_iter()
+            .map(|(tx_id, tx, signed)| {
+                tx.unwrap();
+                (tx_id, signed)
+            })
+            .collect();
+
+        (txs, next_cursor)
+    };
+
+    // Act
+    let (alice_page, alice_cursor) = pending_txs(alice_addr, None, NonZeroU32::new(2)).await;
+    let (alice_next_page, alice_next_cursor) =
+        pending_txs(alice_addr, alice_cursor, NonZeroU32::new(2)).await;
+    let (bob_page, bob_cursor) = pending_txs(bob_addr, None, None).await;
+
+    // Assert
+    assert_eq!(alice_page, [(unsigned_tx_id.clone(), false), (signed_tx_id.clone(), true)]);
+    assert!(alice_cursor.is_some());
+    assert!(alice_next_page.is_empty());
+    assert_eq!(alice_next_cursor, None);
+
+    assert_eq!(
+        bob_page,
+        [(unsigned_tx_id, false), (bob_only_tx_id, false), (signed_tx_id, false)]
+    );
+    assert_eq!(bob_cursor, None);
+}
+
+#[tokio::test]
+async fn multisig_account_address_interface_round_trips_through_store() {
+    // Arrange
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    // the account is keyed by its id, its interface is stored apart
+    let unspecified_address =
+        |id: u128| AccountIdAddress::new(id.try_into().unwrap(), AddressInterface::Unspecified);
+
+    let multisig_addr = unspecified_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let approver_addr = unspecified_address(ACCOUNT_ID_SENDER);
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::MIN)
+        .aux(())
+        .build()
+        .with_approvers(vec![approver_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![ApproverKey::Falcon(SecretKey::new().public_key())])
+        .unwrap();
+
+    // Act
+    let created = store.create_multisig_account(multisig_account).await.unwrap();
+
+    let fetched = store
+        .get_multisig_account(NetworkId::Testnet, multisig_addr)
+        .await
+        .unwrap()
+        .expect("multisig account must be found by its exact address");
+
+    let basic_wallet_addr =
+        AccountIdAddress::new(multisig_addr.id(), AddressInterface::BasicWallet);
+
+    let fetched_by_basic_wallet_addr = store
+        .get_multisig_account(NetworkId::Testnet, basic_wallet_addr)
+        .await
+        .unwrap()
+        .expect("multisig account must be found by its id under any interface");
+
+    // Assert
+    assert_eq!(created.address(), multisig_addr);
+    assert_eq!(fetched.address(), multisig_addr);
+    assert_eq!(fetched.approvers(), [approver_addr]);
+    assert_eq!(fetched_by_basic_wallet_addr.address(), multisig_addr);
+
+    assert_eq!(
+        to_bech32(NetworkId::Testnet, fetched.address()),
+        to_bech32(NetworkId::Testnet, multisig_addr),
+    );
+}
+
+#[tokio::test]
+async fn creating_multisig_account_already_stored_under_another_interface_is_refused() {
+    // Arrange
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+
+    let account_id = ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE.try_into().unwrap();
+
+    let multisig_account = |address_interface| {
+        MultisigAccount::builder()
+            .address(AccountIdAddress::new(account_id, address_interface))
+            .network_id(NetworkId::Testnet)
+            .kind(AccountStorageMode::Public)
+            .threshold(NonZeroU32::MIN)
+            .aux(())
+            .build()
+            .with_approvers(vec![alice_addr])
+            .unwrap()
+            .with_pub_key_commits(vec![ApproverKey::Falcon(SecretKey::new().public_key())])
+            .unwrap()
+    };
+
+    store
+        .create_multisig_account(multisig_account(AddressInterface::Unspecified))
+        .await
+        .unwrap();
+
+    // Act
+    let result = store
+        .create_multisig_account(multisig_account(AddressInterface::BasicWallet))
+        .await;
+
+    // Assert
+    assert!(matches!(result, Err(MultisigStoreError::MultisigAccountAlreadyExists)));
+}
+
+#[tokio::test]
+async fn concurrent_proposals_never_exceed_max_pending_txs() {
+    // Arrange
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::MIN)
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![ApproverKey::Falcon(SecretKey::new().public_key())])
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    let tx_request = pay_to_id_tx_request(multisig_addr.id(), alice_addr.id());
+
+    let tx_summary = empty_tx_summary(multisig_addr.id());
+
+    let max = NonZeroU32::new(1);
+
+    let propose = || {
+        store.create_multisig_tx(
+            NetworkId::Testnet,
+            multisig_addr,
+            &tx_request,
+            &tx_summary,
+            None,
+            None,
+            max,
+        )
+    };
+
+    // Act
+    let results = futures::future::join_all((0..4).map(|_| propose())).await;
+
+    // Assert
+    let stored = results.iter().filter(|result| result.is_ok()).count();
+    let capped = results
+        .iter()
+        .filter(|result| matches!(result, Err(MultisigStoreError::TooManyPendingTxs { .. })))
+        .count();
+
+    assert_eq!(stored, 1);
+    assert_eq!(capped, 3);
+}
+
+#[tokio::test]
+async fn reproposal_beyond_max_pending_txs_fails_and_keeps_tx_failed() {
+    // Arrange
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::MIN)
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![ApproverKey::Falcon(SecretKey::new().public_key())])
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    let tx_request = pay_to_id_tx_request(multisig_addr.id(), alice_addr.id());
+
+    let tx_summary = empty_tx_summary(multisig_addr.id());
+
+    let max = NonZeroU32::new(1);
+
+    let propose = || {
+        store.create_multisig_tx(
+            NetworkId::Testnet,
+            multisig_addr,
+            &tx_request,
+            &tx_summary,
+            None,
+            None,
+            max,
+        )
+    };
+
+    let failed_tx_id = propose().await.unwrap();
+
+    store
+        .update_multisig_tx_status_to_failure_by_id(&failed_tx_id, "stale chain state")
+        .await
+        .unwrap();
+
+    propose().await.unwrap();
+
+    // Act
+    let result = store.repropose_failed_multisig_tx(&failed_tx_id, &tx_summary, max).await;
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(MultisigStoreError::TooManyPendingTxs { max: capped_at }) if capped_at.get() == 1,
+    ));
+
+    let MultisigTxDissolved { status, .. } =
+        store.get_multisig_tx_by_id(&failed_tx_id).await.unwrap().unwrap().dissolve();
+
+    assert!(matches!(status, MultisigTxStatus::Failure));
+}
+
+#[tokio::test]
+async fn multisig_store_passes_store_conformance_scenarios() {
+    conformance::run(async || setup_multisig_store(setup_test_db().await).await).await;
+}
+
+#[tokio::test]
+async fn in_memory_store_passes_store_conformance_scenarios() {
+    conformance::run(async || InMemoryMultisigStore::new()).await;
+}
+
+#[tokio::test]
+async fn input_note_ids_of_proposed_tx_round_trip_through_store() {
+    // Arrange
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::MIN)
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![ApproverKey::Falcon(SecretKey::new().public_key())])
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    let note_ids = vec![
+        NoteId::new(Word::default(), Word::default()),
+        NoteId::new(Word::from([ONE; 4]), Word::default()),
+    ];
+
+    let tx_request =
+        TransactionRequestBuilder::new().build_consume_notes(note_ids.clone()).unwrap();
+
+    let tx_summary = empty_tx_summary(multisig_addr.id());
+
+    // Act
+    let tx_id = store
+        .create_multisig_tx(
+            NetworkId::Testnet,
+            multisig_addr,
+            &tx_request,
+            &tx_summary,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    let fetched_tx = store.get_multisig_tx_by_id(&tx_id).await.unwrap().unwrap();
+
+    let (listed_txs, _) = store
+        .get_txs_by_multisig_account_address_with_status_filter(
+            NetworkId::Testnet,
+            multisig_addr,
+            None::<MultisigTxStatus>,
+            TxSort::default(),
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let MultisigTxDissolved {
+        input_note_ids: fetched_input_note_ids, ..
+    } = fetched_tx.dissolve();
+
+    assert_eq!(fetched_input_note_ids, note_ids);
+
+    let [(listed_tx_id, listed_tx)] = listed_txs.try_into().expect("exactly one tx must be listed");
+    let MultisigTxDissolved {
+        input_note_ids: listed_input_note_ids, ..
+    } = listed_tx.unwrap().dissolve();
+
+    assert_eq!(listed_tx_id, tx_id);
+    assert_eq!(listed_input_note_ids, note_ids);
+}
+
+#[tokio::test]
+async fn input_note_ids_of_tx_stored_without_input_note_rows_are_read_from_its_request() {
+    // Arrange
+    let db_url = setup_test_db().await;
+    let store = setup_multisig_store(db_url.clone()).await;
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::MIN)
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![ApproverKey::Falcon(SecretKey::new().public_key())])
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    let note_ids = vec![
+        NoteId::new(Word::default(), Word::default()),
+        NoteId::new(Word::from([ONE; 4]), Word::default()),
+    ];
+
+    let tx_request =
+        TransactionRequestBuilder::new().build_consume_notes(note_ids.clone()).unwrap();
+
+    let tx_summary = empty_tx_summary(multisig_addr.id());
+
+    let tx_id = store
+        .create_multisig_tx(
+            NetworkId::Testnet,
+            multisig_addr,
+            &tx_request,
+            &tx_summary,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    // as if the tx was proposed before its input notes were persisted
+    diesel::sql_query(format!("DELETE FROM tx_input_note WHERE tx_id = '{tx_id}'"))
+        .execute(&mut PgConnection::establish(&db_url).expect("failed to connect to test database"))
+        .expect("failed to delete input notes");
+
+    // Act
+    let MultisigTxDissolved { input_note_ids, .. } =
+        store.get_multisig_tx_by_id(&tx_id).await.unwrap().unwrap().dissolve();
+
+    // Assert
+    assert_eq!(input_note_ids, note_ids);
+}
+
+#[tokio::test]
+async fn compressed_and_uncompressed_tx_blobs_round_trip_through_store() {
+    // Arrange
+    let db_url = setup_test_db().await;
+    let legacy_store = setup_multisig_store(db_url.clone()).await;
+    let compressing_store =
+        setup_multisig_store(db_url.clone()).await.with_tx_blob_compression(true);
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::MIN)
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![ApproverKey::Falcon(SecretKey::new().public_key())])
+        .unwrap();
+
+    legacy_store.create_multisig_account(multisig_account).await.unwrap();
+
+    let tx_request = pay_to_id_tx_request(multisig_addr.id(), alice_addr.id());
+
+    let tx_summary = empty_tx_summary(multisig_addr.id());
+
+    // Act
+    let legacy_tx_id = legacy_store
+        .create_multisig_tx(
+            NetworkId::Testnet,
+            multisig_addr,
+            &tx_request,
+            &tx_summary,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    let compressed_tx_id = compressing_store
+        .create_multisig_tx(
+            NetworkId::Testnet,
+            multisig_addr,
+            &tx_request,
+            &tx_summary,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let compressed_rows = diesel::sql_query(format!(
+        "SELECT id FROM tx WHERE id = '{compressed_tx_id}' AND get_byte(tx_request, 0) = 1 \
+         AND get_byte(tx_summary, 0) = 1"
+    ))
+    .execute(&mut PgConnection::establish(&db_url).expect("failed to connect to test database"))
+    .expect("failed to query compressed tx");
+
+    assert_eq!(compressed_rows, 1);
+
+    // both stores read both rows, whichever way they were written
+    for store in [&legacy_store, &compressing_store] {
+        for tx_id in [&legacy_tx_id, &compressed_tx_id] {
+            let MultisigTxDissolved {
+                tx_request: stored_tx_request,
+                tx_summary: stored_tx_summary,
+                ..
+            } = store.get_multisig_tx_by_id(tx_id).await.unwrap().unwrap().dissolve();
+
+            assert_eq!(stored_tx_request.to_bytes(), tx_request.to_bytes());
+            assert_eq!(stored_tx_summary.to_commitment(), tx_summary.to_commitment());
+        }
+    }
+}
+
+#[tokio::test]
+async fn listing_multisig_txs_sorted_by_created_at_walks_history_in_either_direction() {
+    // Arrange
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::MIN)
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![ApproverKey::Falcon(SecretKey::new().public_key())])
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    let tx_request = pay_to_id_tx_request(multisig_addr.id(), alice_addr.id());
+
+    let tx_summary = empty_tx_summary(multisig_addr.id());
+
+    let mut proposed_tx_ids = Vec::new();
+    for _ in 0..3 {
+        let tx_id = store
+            .create_multisig_tx(
+                NetworkId::Testnet,
+                multisig_addr,
+                &tx_request,
+                &tx_summary,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        proposed_tx_ids.push(tx_id);
+    }
+
+    // Act
+    let list_tx_ids = async |direction| {
+        let sort = TxSort::builder().field(TxSortField::CreatedAt).direction(direction).build();
+
+        let mut listed_tx_ids = Vec::new();
+        let mut cursor = None;
+
+        loop {
+            let (txs, next_cursor) = store
+                .get_txs_by_multisig_account_address_with_status_filter(
+                    NetworkId::Testnet,
+                    multisig_addr,
+                    None::<MultisigTxStatus>,
+                    sort,
+                    cursor,
+                    NonZeroU32::new(2),
+                )
+                .await
+                .unwrap();
+
+            listed_tx_ids.extend(txs.into_iter().map(|(tx_id, _)| tx_id));
+
+            let Some(next_cursor) = next_cursor else { break };
+            cursor = Some(next_cursor);
+        }
+
+        listed_tx_ids
+    };
+
+    let ascending_tx_ids = list_tx_ids(SortDirection::Asc).await;
+    let descending_tx_ids = list_tx_ids(SortDirection::Desc).await;
+
+    // Assert
+    assert_eq!(ascending_tx_ids, proposed_tx_ids);
+
+    let expected_tx_ids: Vec<_> = proposed_tx_ids.into_iter().rev().collect();
+    assert_eq!(descending_tx_ids, expected_tx_ids);
+}
+
+#[tokio::test]
+async fn listing_multisig_txs_sorted_by_signature_count_skips_no_tx_signed_between_pages() {
+    // Arrange
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+
+    let alice_sk = SecretKey::new();
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::MIN)
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![ApproverKey::Falcon(alice_sk.public_key())])
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    let tx_request = pay_to_id_tx_request(multisig_addr.id(), alice_addr.id());
+
+    let tx_summary = empty_tx_summary(multisig_addr.id());
+
+    let mut proposed_tx_ids = Vec::new();
+    for _ in 0..3 {
+        let tx_id = store
+            .create_multisig_tx(
+                NetworkId::Testnet,
+                multisig_addr,
+                &tx_request,
+                &tx_summary,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        proposed_tx_ids.push(tx_id);
+    }
+
+    // unsigned txs sort by id, whose hyphenated form sorts as its bytes do
+    proposed_tx_ids.sort_by_key(ToString::to_string);
+
+    let sort = TxSort::builder()
+        .field(TxSortField::SignatureCount)
+        .direction(SortDirection::Asc)
+        .build();
+
+    let list_page = async |cursor| {
+        store
+            .get_txs_by_multisig_account_address_with_status_filter(
+                NetworkId::Testnet,
+                multisig_addr,
+                None::<MultisigTxStatus>,
+                sort,
+                cursor,
+                NonZeroU32::new(1),
+            )
+            .await
+            .unwrap()
+    };
+
+    let (first_page, cursor) = list_page(None).await;
+
+    // Act
+    let signature = ApproverSignature::from(alice_sk.sign(tx_summary.to_commitment()));
+    store
+        .add_multisig_tx_signature(&first_page[0].0, NetworkId::Testnet, alice_addr, &signature)
+        .await
+        .unwrap();
+
+    let (second_page, _) = list_page(cursor).await;
+
+    // Assert
+    assert_eq!(first_page[0].0, proposed_tx_ids[0]);
+    assert_eq!(second_page[0].0, proposed_tx_ids[1]);
+}
+
+#[tokio::test]
+async fn fetched_multisig_tx_tracks_remaining_signatures_until_threshold_is_met() {
+    // Arrange
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+    let bob_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE);
+
+    let alice_sk = SecretKey::new();
+    let bob_sk = SecretKey::new();
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::new(2).unwrap())
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr, bob_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![
+            ApproverKey::Falcon(alice_sk.public_key()),
+            ApproverKey::Falcon(bob_sk.public_key()),
+        ])
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    let tx_request = pay_to_id_tx_request(multisig_addr.id(), alice_addr.id());
+
+    let tx_summary = empty_tx_summary(multisig_addr.id());
+
+    let tx_id = store
+        .create_multisig_tx(
+            NetworkId::Testnet,
+            multisig_addr,
+            &tx_request,
+            &tx_summary,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    let fetch_tx = async || store.get_multisig_tx_by_id(&tx_id).await.unwrap().unwrap();
+
+    let add_signature = async |approver_addr: AccountIdAddress, sk: &SecretKey| {
+        let signature = ApproverSignature::from(sk.sign(tx_summary.to_commitment()));
+
+        store
+            .add_multisig_tx_signature(&tx_id, NetworkId::Testnet, approver_addr, &signature)
+            .await
+            .unwrap()
+            .expect("approver must be permitted to sign");
+    };
+
+    // Act
+    let unsigned_tx = fetch_tx().await;
+
+    add_signature(alice_addr, &alice_sk).await;
+    let half_signed_tx = fetch_tx().await;
+
+    add_signature(bob_addr, &bob_sk).await;
+    let signed_tx = fetch_tx().await;
+
+    // Assert
+    assert_eq!(unsigned_tx.remaining_signatures(), 2);
+    assert!(!unsigned_tx.is_threshold_met());
+
+    assert_eq!(half_signed_tx.remaining_signatures(), 1);
+    assert!(!half_signed_tx.is_threshold_met());
+
+    assert_eq!(signed_tx.remaining_signatures(), 0);
+    assert!(signed_tx.is_threshold_met());
+}
+
+#[tokio::test]
+async fn overwriting_commitment_of_partially_signed_multisig_tx_is_refused() {
+    // Arrange
+    let db_url = setup_test_db().await;
+    let store = setup_multisig_store(db_url.clone()).await;
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+    let bob_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE);
+
+    let alice_sk = SecretKey::new();
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::new(2).unwrap())
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr, bob_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![
+            ApproverKey::Falcon(alice_sk.public_key()),
+            ApproverKey::Falcon(SecretKey::new().public_key()),
+        ])
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    let tx_request = pay_to_id_tx_request(multisig_addr.id(), alice_addr.id());
+
+    // summaries differing only by their salt have distinct commitments
+    let tx_summary_with_salt = |salt: Word| {
+        TransactionSummary::new(
+            AccountDelta::new(
+                multisig_addr.id(),
+                AccountStorageDelta::default(),
+                AccountVaultDelta::default(),
+                ONE,
+            )
+            .unwrap(),
+            InputNotes::new(vec![]).unwrap(),
+            OutputNotes::new(vec![]).unwrap(),
+            salt,
+        )
+    };
+
+    let tx_summary = tx_summary_with_salt(Word::default());
+    let other_tx_summary = tx_summary_with_salt(Word::from([ONE; 4]));
+
+    let tx_id = store
+        .create_multisig_tx(
+            NetworkId::Testnet,
+            multisig_addr,
+            &tx_request,
+            &tx_summary,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap();
+
+    let signature = ApproverSignature::from(alice_sk.sign(tx_summary.to_commitment()));
+    store
+        .add_multisig_tx_signature(&tx_id, NetworkId::Testnet, alice_addr, &signature)
+        .await
+        .unwrap()
+        .expect("approver must be permitted to sign");
+
+    // Act
+    let reproposal = store.repropose_failed_multisig_tx(&tx_id, &other_tx_summary, None).await;
+
+    // the database refuses the change whichever statement makes it
+    let overwrite = diesel::sql_query(format!(
+        "UPDATE tx SET tx_summary_commit = '\\x00' WHERE id = '{tx_id}'"
+    ))
+    .execute(&mut PgConnection::establish(&db_url).expect("failed to connect to test database"));
+
+    // Assert
+    assert!(matches!(reproposal, Err(MultisigStoreError::NotFound(_))));
+    assert!(matches!(
+        overwrite,
+        Err(diesel::result::Error::DatabaseError(
+            diesel::result::DatabaseErrorKind::CheckViolation,
+            info,
+        )) if info.constraint_name() == Some("tx_signed_commitment_immutable")
+    ));
+
+    let MultisigTxDissolved { tx_summary_commit, .. } =
+        store.get_multisig_tx_by_id(&tx_id).await.unwrap().unwrap().dissolve();
+
+    assert_eq!(tx_summary_commit, tx_summary.to_commitment());
+    assert!(store.has_approver_signed(&tx_id, NetworkId::Testnet, alice_addr).await.unwrap());
+}
+
+#[tokio::test]
+async fn multisig_tx_stats_report_time_to_threshold_of_executed_txs_only() {
+    // Arrange
+    let store = setup_multisig_store(setup_test_db().await).await;
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+
+    let alice_sk = SecretKey::new();
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::new(1).unwrap())
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![ApproverKey::Falcon(alice_sk.public_key())])
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    let tx_request = pay_to_id_tx_request(multisig_addr.id(), alice_addr.id());
+
+    let tx_summary = empty_tx_summary(multisig_addr.id());
+
+    let propose_and_sign = async || {
+        let tx_id = store
+            .create_multisig_tx(
+                NetworkId::Testnet,
+                multisig_addr,
+                &tx_request,
+                &tx_summary,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        let signature = ApproverSignature::from(alice_sk.sign(tx_summary.to_commitment()));
+
+        store
+            .add_multisig_tx_signature(&tx_id, NetworkId::Testnet, alice_addr, &signature)
+            .await
+            .unwrap()
+            .expect("approver must be permitted to sign");
+
+        tx_id
+    };
+
+    let get_tx_stats = async || {
+        store
+            .get_multisig_tx_stats_by_multisig_account_address(NetworkId::Testnet, multisig_addr)
+            .await
+            .unwrap()
+            .dissolve()
+    };
+
+    // the threshold of this tx is met but it is never executed, so it is left out of the stats
+    propose_and_sign().await;
+    let executed_tx_id = propose_and_sign().await;
+
+    // Act
+    let MultisigTxStatsDissolved {
+        avg_time_to_threshold: avg_before_execution,
+        p95_time_to_threshold: p95_before_execution,
+        ..
+    } = get_tx_stats().await;
+
+    store
+        .update_multisig_tx_status_by_id(&executed_tx_id, MultisigTxStatus::Success)
+        .await
+        .unwrap();
+
+    let MultisigTxStatsDissolved {
+        avg_time_to_threshold,
+        p95_time_to_threshold,
+        ..
+    } = get_tx_stats().await;
+
+    // Assert
+    assert!(avg_before_execution.is_none());
+    assert!(p95_before_execution.is_none());
+
+    // the single executed tx is both the average and the 95th percentile
+    assert!(avg_time_to_threshold.is_some());
+    assert_eq!(avg_time_to_threshold, p95_time_to_threshold);
+}
+
+#[tokio::test]
+async fn tx_trend_counts_txs_proposed_in_each_day_or_week() {
+    // Arrange
+    let db_url = setup_test_db().await;
+    let store = setup_multisig_store(db_url.clone()).await;
+
+    let multisig_addr = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+    let alice_addr = account_id_address(ACCOUNT_ID_SENDER);
+
+    let multisig_account = MultisigAccount::builder()
+        .address(multisig_addr)
+        .network_id(NetworkId::Testnet)
+        .kind(AccountStorageMode::Public)
+        .threshold(NonZeroU32::MIN)
+        .aux(())
+        .build()
+        .with_approvers(vec![alice_addr])
+        .unwrap()
+        .with_pub_key_commits(vec![ApproverKey::Falcon(SecretKey::new().public_key())])
+        .unwrap();
+
+    store.create_multisig_account(multisig_account).await.unwrap();
+
+    let tx_request = pay_to_id_tx_request(multisig_addr.id(), alice_addr.id());
+
+    let tx_summary = empty_tx_summary(multisig_addr.id());
+
+    let mut conn = PgConnection::establish(&db_url).expect("failed to connect to test database");
+
+    // 2025-11-03 and 2025-11-10 are Mondays
+    for created_at in [
+        "2025-11-03 08:00:00+00",
+        "2025-11-03 23:59:59+00",
+        "2025-11-05 12:00:00+00",
+        "2025-11-10 00:00:00+00",
+    ] {
+        let tx_id = store
+            .create_multisig_tx(
+                NetworkId::Testnet,
+                multisig_addr,
+                &tx_request,
+                &tx_summary,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        diesel::sql_query(format!(
+            "UPDATE tx SET created_at = '{created_at}'::timestamptz WHERE id = '{tx_id}'"
+        ))
+        .execute(&mut conn)
+        .expect("failed to backdate tx");
+    }
+
+    let get_tx_trend = async |granularity| {
+        store
+            .get_tx_trend_by_multisig_account_address(
+                NetworkId::Testnet,
+                multisig_addr,
+                granularity,
+            )
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|(start, count)| (start.to_rfc3339(), count))
+            .collect::<Vec<_>>()
+    };
+
+    // Act
+    let daily_trend = get_tx_trend(TrendGranularity::Day).await;
+    let weekly_trend = get_tx_trend(TrendGranularity::Week).await;
+
+    // Assert
+    assert_eq!(
+        daily_trend,
+        [
+            ("2025-11-03T00:00:00+00:00".to_owned(), 2),
+            ("2025-11-05T00:00:00+00:00".to_owned(), 1),
+            ("2025-11-10T00:00:00+00:00".to_owned(), 1),
+        ]
+    );
+
+    assert_eq!(
+        weekly_trend,
+        [
+            ("2025-11-03T00:00:00+00:00".to_owned(), 3),
+            ("2025-11-10T00:00:00+00:00".to_owned(), 1),
+        ]
+    );
+}
@@ -1,11 +1,13 @@
 use miden_objects::{
     AddressError,
     account::NetworkId,
-    address::{AccountIdAddress, Address},
+    address::{AccountIdAddress, Address, AddressInterface},
 };
 
 /// Decodes the bech32 string then returns [`NetworkId`] and [`AccountIdAddress`] pair.
 ///
+/// This is the canonical address decoding, every other address decoding goes through it.
+///
 /// # Errors
 ///
 /// When the bech32 string does not correspond to [`AccountIdAddress`].
@@ -19,7 +21,60 @@ pub fn extract_network_id_account_id_address_pair(
     Err(AccountIdAddressError::InvalidAccountIdAddress)
 }
 
-/// Error that occurs while invoking [`extract_network_id_account_id_address_pair`].
+/// Decodes the bech32 string into an [`AccountIdAddress`], ensuring it belongs to the expected
+/// network.
+///
+/// # Errors
+///
+/// - When the bech32 string does not correspond to [`AccountIdAddress`]
+/// - When the address belongs to another network than `expected`
+pub fn decode_account_id_address(
+    expected: NetworkId,
+    bech32: &str,
+) -> Result<AccountIdAddress, AccountIdAddressError> {
+    let (provided, address) = extract_network_id_account_id_address_pair(bech32)?;
+
+    if provided != expected {
+        return Err(AccountIdAddressError::NetworkIdMismatch { expected, provided });
+    }
+
+    Ok(address)
+}
+
+/// Encodes the [`AccountIdAddress`] into its bech32 string on the given network.
+///
+/// This is the inverse of [`extract_network_id_account_id_address_pair`]. The address interface
+/// is part of the bech32 string, so an address decoded from a stored string re-encodes into that
+/// very string, whatever its interface.
+pub fn to_bech32(network_id: NetworkId, address: AccountIdAddress) -> String {
+    Address::AccountId(address).to_bech32(network_id)
+}
+
+/// Encodes the account id of the [`AccountIdAddress`] into the bech32 string multisig accounts are
+/// keyed by on the given network.
+///
+/// The key is the bech32 string of the account id with the basic wallet interface, whatever the
+/// interface of the address, so that an account is found by its id whichever interface it is
+/// addressed with. The interface of the account is kept apart from its key.
+pub fn to_account_key(network_id: NetworkId, address: AccountIdAddress) -> String {
+    to_bech32(network_id, AccountIdAddress::new(address.id(), AddressInterface::BasicWallet))
+}
+
+/// Canonicalizes a bech32 account address, e.g. an uppercase one, by decoding and re-encoding it.
+///
+/// Addresses are stored and compared in their canonical form, so equal addresses always have
+/// equal strings.
+///
+/// # Errors
+///
+/// When the bech32 string does not correspond to [`AccountIdAddress`].
+pub fn normalize_address(bech32: &str) -> Result<String, AccountIdAddressError> {
+    let (network_id, address) = extract_network_id_account_id_address_pair(bech32)?;
+
+    Ok(to_bech32(network_id, address))
+}
+
+/// Error that occurs while decoding a bech32 account address.
 #[derive(Debug, thiserror::Error)]
 pub enum AccountIdAddressError {
     /// Address error
@@ -29,4 +84,88 @@ pub enum AccountIdAddressError {
     /// When the bech32 string does not correspond to [`AccountIdAddress`]
     #[error("invalid account id address error")]
     InvalidAccountIdAddress,
+
+    /// When the address belongs to another network than the expected one
+    #[error(
+        "network id mismatch error: expected `{}`, provided `{}`",
+        expected.as_str(),
+        provided.as_str()
+    )]
+    NetworkIdMismatch { expected: NetworkId, provided: NetworkId },
+}
+
+#[cfg(test)]
+mod tests {
+    use miden_objects::{
+        account::NetworkId,
+        address::{AccountIdAddress, AddressInterface},
+        testing::account_id::ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
+    };
+
+    use super::AccountIdAddressError;
+
+    fn account_id_address() -> AccountIdAddress {
+        AccountIdAddress::new(
+            ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE.try_into().unwrap(),
+            AddressInterface::BasicWallet,
+        )
+    }
+
+    #[test]
+    fn address_round_trips_through_bech32() {
+        // Arrange
+        let address = account_id_address();
+
+        // Act
+        let bech32 = super::to_bech32(NetworkId::Testnet, address);
+        let decoded = super::decode_account_id_address(NetworkId::Testnet, &bech32).unwrap();
+
+        // Assert
+        assert_eq!(decoded, address);
+    }
+
+    #[test]
+    fn account_key_only_depends_on_account_id() {
+        // Arrange
+        let address = account_id_address();
+        let unspecified_address =
+            AccountIdAddress::new(address.id(), AddressInterface::Unspecified);
+
+        // Act
+        let key = super::to_account_key(NetworkId::Testnet, address);
+        let unspecified_key = super::to_account_key(NetworkId::Testnet, unspecified_address);
+
+        // Assert
+        assert_eq!(key, unspecified_key);
+        assert_eq!(key, super::to_bech32(NetworkId::Testnet, address));
+    }
+
+    #[test]
+    fn normalizing_uppercase_address_yields_canonical_address() {
+        // Arrange
+        let bech32 = super::to_bech32(NetworkId::Testnet, account_id_address());
+
+        // Act
+        let normalized = super::normalize_address(&bech32.to_uppercase()).unwrap();
+
+        // Assert
+        assert_eq!(normalized, bech32);
+    }
+
+    #[test]
+    fn decoding_address_of_another_network_fails() {
+        // Arrange
+        let bech32 = super::to_bech32(NetworkId::Mainnet, account_id_address());
+
+        // Act
+        let err = super::decode_account_id_address(NetworkId::Testnet, &bech32).unwrap_err();
+
+        // Assert
+        let AccountIdAddressError::NetworkIdMismatch { expected, provided } = err else {
+            panic!("decoding must fail with a network id mismatch");
+        };
+
+        assert_eq!(expected, NetworkId::Testnet);
+        assert_eq!(provided, NetworkId::Mainnet);
+    }
 }
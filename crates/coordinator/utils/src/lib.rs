@@ -4,6 +4,9 @@ mod address;
 mod signature;
 
 pub use self::{
-    address::{AccountIdAddressError, extract_network_id_account_id_address_pair},
-    signature::rpo_falcon512_signature_into_felt_vec,
+    address::{
+        AccountIdAddressError, decode_account_id_address,
+        extract_network_id_account_id_address_pair, normalize_address, to_account_key, to_bech32,
+    },
+    signature::verify_ecdsa_k256_signature,
 };
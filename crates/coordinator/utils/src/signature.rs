@@ -1,80 +1,50 @@
-use miden_crypto::{
-    Felt,
-    dsa::rpo_falcon512::{Polynomial, Signature},
-};
-use miden_objects::Hasher;
+use k256::ecdsa::{self, VerifyingKey, signature::hazmat::PrehashVerifier};
+use miden_crypto::Word;
 
-/// Turn RPO Falcon512 [`Signature`] into a `Vec<Felt>` what would have been obtained using
-/// [`TransactionAuthenticator::get_signature`](https://docs.rs/miden-client/0.11.11/miden_client/auth/trait.TransactionAuthenticator.html#tymethod.get_signature).
+/// Verifies a secp256k1 ECDSA `signature`, encoded as `r || s || v`, made with the compressed SEC1
+/// encoded `pub_key` over `message`.
 ///
-/// Slightly modified implementation of [`miden_tx::auth::signatures::get_falcon_signature`](https://docs.rs/miden-tx/0.11.5/miden_tx/auth/signatures/fn.get_falcon_signature.html).
-// TODO: deprecate this after miden-client v0.12 is available.
-pub fn rpo_falcon512_signature_into_felt_vec(sig: Signature) -> Vec<Felt> {
-    // The signature is composed of a nonce and a polynomial s2
-    // The nonce is represented as 8 field elements.
-    let nonce = sig.nonce();
-
-    let s2 = sig.sig_poly();
-
-    // We also need in the VM the expanded key corresponding to the public key that was provided
-    // via the operand stack
-    let h = &sig.pk_poly().0;
-
-    // Lastly, for the probabilistic product routine that is part of the verification procedure,
-    // we need to compute the product of the expanded key and the signature polynomial in
-    // the ring of polynomials with coefficients in the Miden field.
-    let pi = Polynomial::mul_modulo_p(h, s2);
-
-    // We now push the expanded key, the signature polynomial, and the product of the
-    // expanded key and the signature polynomial to the advice stack. We also push
-    // the challenge point at which the previous polynomials will be evaluated.
-    // Finally, we push the nonce needed for the hash-to-point algorithm.
-
-    let mut polynomials: Vec<Felt> =
-        h.coefficients.iter().map(|a| Felt::from(a.value() as u32)).collect();
-    polynomials.extend(s2.coefficients.iter().map(|a| Felt::from(a.value() as u32)));
-    polynomials.extend(pi.iter().map(|a| Felt::new(*a)));
-
-    let digest_polynomials = Hasher::hash_elements(&polynomials);
-    let challenge = (digest_polynomials[0], digest_polynomials[1]);
-
-    let mut result: Vec<Felt> = vec![challenge.0, challenge.1];
-    result.extend_from_slice(&polynomials);
-    result.extend_from_slice(&nonce.to_elements());
+/// The bytes of `message` are used as the prehash, as the message is already a commitment.
+pub fn verify_ecdsa_k256_signature(pub_key: &[u8], message: Word, signature: &[u8]) -> bool {
+    let Ok(verifying_key) = VerifyingKey::from_sec1_bytes(pub_key) else {
+        return false;
+    };
 
-    result.reverse();
+    // the trailing recovery id is not needed for verification
+    let Some(Ok(signature)) = signature.get(..64).map(ecdsa::Signature::from_slice) else {
+        return false;
+    };
 
-    result
+    verifying_key.verify_prehash(&message.as_bytes(), &signature).is_ok()
 }
 
 #[cfg(test)]
 mod tests {
-    use miden_crypto::{
-        dsa::rpo_falcon512::{SecretKey, Signature},
-        hash::rpo::Rpo256,
-    };
-    use miden_tx::auth::signatures;
-    use rand::SeedableRng;
-    use rand_chacha::ChaCha20Rng;
-
-    const RNG_SEED: u64 = 8086;
+    use k256::ecdsa::SigningKey;
+    use miden_crypto::hash::rpo::Rpo256;
 
     #[test]
-    fn turning_rpo_falcon512_signature_into_felt_vec_works() {
+    fn verifying_ecdsa_k256_signature_works() {
         // Arrange
-        let sk = SecretKey::new();
+        let signing_key = SigningKey::from_slice(&[7; 32]).unwrap();
+        let pub_key = signing_key.verifying_key().to_encoded_point(true);
         let msg = Rpo256::hash(b"miden will get multisig");
+        let other_msg = Rpo256::hash(b"miden will not get multisig");
 
-        let sig: Signature = sk.sign_with_rng(msg, &mut ChaCha20Rng::seed_from_u64(RNG_SEED));
+        let (sig, recovery_id) = signing_key.sign_prehash_recoverable(&msg.as_bytes()).unwrap();
+        let mut sig_bz = sig.to_bytes().to_vec();
+        sig_bz.push(recovery_id.to_byte());
 
         // Act
-        let felt_vec = super::rpo_falcon512_signature_into_felt_vec(sig);
+        let valid = super::verify_ecdsa_k256_signature(pub_key.as_bytes(), msg, &sig_bz);
+        let other_msg_valid =
+            super::verify_ecdsa_k256_signature(pub_key.as_bytes(), other_msg, &sig_bz);
+        let truncated_valid =
+            super::verify_ecdsa_k256_signature(pub_key.as_bytes(), msg, &sig_bz[..32]);
 
         // Assert
-        let expected_felt_vec =
-            signatures::get_falcon_signature(&sk, msg, &mut ChaCha20Rng::seed_from_u64(RNG_SEED))
-                .expect("valid secret key must be able to sign the message");
-
-        assert_eq!(felt_vec, expected_felt_vec);
+        assert!(valid);
+        assert!(!other_msg_valid);
+        assert!(!truncated_valid);
     }
 }
@@ -28,11 +28,14 @@ use miden_client::{
     auth::TransactionAuthenticator,
     builder::ClientBuilder,
     keystore::FilesystemKeyStore,
-    rpc::Endpoint,
+    rpc::{Endpoint, NodeRpcClient, TonicRpcClient, domain::account::FetchedAccount},
     transaction::{TransactionExecutorError, TransactionRequest, TransactionResult},
 };
 use miden_objects::{
-    Hasher, assembly::diagnostics::tracing::info, crypto::dsa::rpo_falcon512::PublicKey,
+    Hasher,
+    account::{AccountCode, AccountComponent},
+    assembly::diagnostics::tracing::info,
+    crypto::dsa::rpo_falcon512::{Polynomial, PublicKey, Signature},
     transaction::TransactionSummary,
 };
 use rand::{RngCore, rngs::StdRng};
@@ -46,14 +49,138 @@ pub enum MultisigClientError {
     #[error("multisig transaction proposal error: {0}")]
     TxProposalError(String),
 
+    /// An error occurred while communicating with the node, the request may succeed if retried.
+    #[error("node rpc error: {0}")]
+    RpcError(String),
+
     /// An error occurred while executing a transaction.
     #[error("multisig transaction execution error: {0}")]
     TxExecutionError(String),
+
+    /// The account storage does not hold a valid multisig configuration.
+    #[error("multisig account config error: {0}")]
+    AccountConfigError(String),
+
+    /// More approvers than [`MAX_APPROVERS`] were given.
+    #[error("too many approvers error: {count} approvers exceed the maximum of {MAX_APPROVERS}")]
+    TooManyApprovers { count: usize },
+
+    /// An error occurred while fetching an account from the node.
+    #[error("account import error: {0}")]
+    AccountImportError(String),
+
+    /// The auth component of the account is not the multisig auth component.
+    #[error("not multisig account error: `{0}` is not authenticated by the multisig component")]
+    NotMultisigAccount(AccountId),
+
+    /// Fewer signatures than the threshold were given to execute a transaction.
+    #[error("insufficient signatures error: {have} signatures are below the threshold of {need}")]
+    InsufficientSignatures { have: usize, need: u32 },
+
+    /// An error occurred while building a new multisig account or tracking it.
+    #[error("account setup error: {0}")]
+    AccountSetupError(String),
+}
+
+impl MultisigClientError {
+    /// Classifies an error of the client raised while proposing a transaction: failures to reach
+    /// the node are [`RpcError`](Self::RpcError)s, the others, e.g. the transaction failing to
+    /// execute, are [`TxProposalError`](Self::TxProposalError)s.
+    pub fn tx_proposal(err: ClientError) -> Self {
+        match err {
+            ClientError::RpcError(_) => Self::RpcError(err.to_string()),
+            err => Self::TxProposalError(err.to_string()),
+        }
+    }
+}
+
+/// The index of the storage slot holding `[threshold, num_approvers, 0, 0]`.
+pub const CONFIG_STORAGE_SLOT_INDEX: u8 = 0;
+
+/// The index of the storage map slot mapping `[approver_index, 0, 0, 0]` to the approver's public
+/// key.
+pub const APPROVER_PUB_KEYS_STORAGE_SLOT_INDEX: u8 = 1;
+
+/// The maximum number of approvers of a multisig account.
+///
+/// The auth procedure of the multisig account looks up the signature of every approver when
+/// authorizing a transaction, so its cost grows with the number of approvers. Above this limit,
+/// transactions risk exceeding the cycle limit of the VM.
+pub const MAX_APPROVERS: usize = 64;
+
+/// The configuration of a multisig account, as read from its storage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultisigConfig {
+    /// The number of signatures required to authorize a transaction.
+    pub threshold: u32,
+
+    /// The number of approvers of the account.
+    pub num_approvers: u32,
+
+    /// The public keys of the approvers, in approver index order.
+    pub approver_pub_keys: Vec<PublicKey>,
+}
+
+impl TryFrom<&Account> for MultisigConfig {
+    type Error = MultisigClientError;
+
+    /// Reads the threshold, the number of approvers and the approvers' public keys from the
+    /// storage of a multisig account.
+    ///
+    /// # Errors
+    ///
+    /// When the config storage slot or the public key of an approver is missing, or when the
+    /// threshold or the number of approvers does not fit in a `u32`.
+    fn try_from(account: &Account) -> Result<Self, Self::Error> {
+        let storage = account.storage();
+
+        let config = storage.get_item(CONFIG_STORAGE_SLOT_INDEX).map_err(|e| {
+            MultisigClientError::AccountConfigError(format!("missing config storage slot: {e}"))
+        })?;
+
+        let &[threshold, num_approvers, ..] = config.as_elements() else {
+            return Err(MultisigClientError::AccountConfigError(
+                "config storage slot is too short".to_string(),
+            ));
+        };
+
+        let [threshold, num_approvers] = [threshold, num_approvers].map(|felt| {
+            u32::try_from(felt.as_int()).map_err(|_| {
+                MultisigClientError::AccountConfigError(format!("{felt} does not fit in a u32"))
+            })
+        });
+
+        let (threshold, num_approvers) = (threshold?, num_approvers?);
+
+        let approver_pub_keys = (0..num_approvers)
+            .map(|i| {
+                let pub_key_index_word = Word::from([Felt::from(i), ZERO, ZERO, ZERO]);
+                storage
+                    .get_map_item(APPROVER_PUB_KEYS_STORAGE_SLOT_INDEX, pub_key_index_word)
+                    .map(PublicKey::new)
+                    .map_err(|e| {
+                        MultisigClientError::AccountConfigError(format!(
+                            "missing public key of approver {i}: {e}"
+                        ))
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            threshold,
+            num_approvers,
+            approver_pub_keys,
+        })
+    }
 }
 
 /// A client for interacting with multisig accounts.
 pub struct MultisigClient<AUTH: TransactionAuthenticator + Sync + 'static> {
     client: Client<AUTH>,
+
+    /// The RPC client the `client` reaches the node with, used to fetch accounts without
+    /// tracking them.
+    rpc_api: Arc<dyn NodeRpcClient + Send>,
 }
 
 impl<AUTH> MultisigClient<AUTH>
@@ -61,8 +188,10 @@ where
     AUTH: TransactionAuthenticator + Sync + 'static,
 {
     /// Construct a `MultisigClient`.
-    pub fn new(client: Client<AUTH>) -> Self {
-        Self { client }
+    ///
+    /// The `rpc_api` is expected to be the one the `client` was built with.
+    pub fn new(client: Client<AUTH>, rpc_api: Arc<dyn NodeRpcClient + Send>) -> Self {
+        Self { client, rpc_api }
     }
 }
 
@@ -91,8 +220,11 @@ impl MultisigClient<FilesystemKeyStore<StdRng>> {
             .map_err(anyhow::Error::msg)
             .with_context(|| format!("failed to parse node url: {node_url}"))?;
 
+        let rpc_api: Arc<dyn NodeRpcClient + Send> =
+            Arc::new(TonicRpcClient::new(&endpoint, timeout.as_millis() as u64));
+
         let mut client = ClientBuilder::new()
-            .tonic_rpc_client(&endpoint, Some(timeout.as_millis() as u64))
+            .rpc(rpc_api.clone())
             .authenticator(Arc::new(keystore))
             .sqlite_store(store_path.to_str().context("invalid store path")?)
             .build()
@@ -102,7 +234,7 @@ impl MultisigClient<FilesystemKeyStore<StdRng>> {
 
         client.ensure_genesis_in_place().await?;
 
-        Ok(Self { client })
+        Ok(Self { client, rpc_api })
     }
 }
 
@@ -122,26 +254,166 @@ impl<AUTH: TransactionAuthenticator + Sync + 'static> DerefMut for MultisigClien
 
 impl<AUTH: TransactionAuthenticator + Sync + 'static> MultisigClient<AUTH> {
     /// Sets up a new multisig account with the specified approvers and threshold.
-    pub async fn setup_account(&mut self, approvers: Vec<PublicKey>, threshold: u32) -> Account {
+    ///
+    /// # Errors
+    ///
+    /// When more than [`MAX_APPROVERS`] approvers are given, or with
+    /// [`MultisigClientError::AccountSetupError`] when the account cannot be built or tracked.
+    pub async fn setup_account(
+        &mut self,
+        approvers: Vec<PublicKey>,
+        threshold: u32,
+    ) -> Result<Account, MultisigClientError> {
+        let (multisig_account, seed) = self.build_account(approvers, threshold)?;
+
+        self.track_built_account(multisig_account, seed).await
+    }
+
+    /// Builds a new multisig account with the specified approvers and threshold, without tracking
+    /// it.
+    ///
+    /// The account is not on chain yet, so it is returned along with the seed it was built from,
+    /// which tracking it requires, see [`add_account`](Client::add_account).
+    ///
+    /// # Errors
+    ///
+    /// When more than [`MAX_APPROVERS`] approvers are given.
+    pub fn build_account(
+        &mut self,
+        approvers: Vec<PublicKey>,
+        threshold: u32,
+    ) -> Result<(Account, Word), MultisigClientError> {
         let mut init_seed = [0u8; 32];
         self.rng().fill_bytes(&mut init_seed);
 
-        let multisig_auth_component = AuthRpoFalcon512Multisig::new(threshold, approvers).unwrap();
-        let (multisig_account, seed) = AccountBuilder::new(init_seed)
-            .with_auth_component(multisig_auth_component)
-            .account_type(AccountType::RegularAccountImmutableCode)
-            .storage_mode(AccountStorageMode::Public)
-            .with_component(BasicWallet)
-            .build()
-            .unwrap();
+        build_account_with_seed(approvers, threshold, init_seed)
+    }
 
-        self.add_account(&multisig_account, Some(seed), false).await.unwrap();
+    /// Sets up a new multisig account with the specified approvers and threshold, derived from
+    /// the given `init_seed` rather than a random one.
+    ///
+    /// The same seed, approvers and threshold always yield the same account id, which lets tests
+    /// assert on fixed ids. This must only be used in tests, never in production: anyone knowing
+    /// the seed can predict the account id, and reusing a seed yields an account that already
+    /// exists. It is therefore only available with the `testing` feature.
+    ///
+    /// # Errors
+    ///
+    /// Like [`setup_account`](Self::setup_account).
+    #[cfg(any(test, feature = "testing"))]
+    pub async fn setup_account_with_seed(
+        &mut self,
+        approvers: Vec<PublicKey>,
+        threshold: u32,
+        init_seed: [u8; 32],
+    ) -> Result<Account, MultisigClientError> {
+        let (multisig_account, seed) = build_account_with_seed(approvers, threshold, init_seed)?;
 
-        multisig_account
+        self.track_built_account(multisig_account, seed).await
+    }
+
+    /// Tracks a multisig account built from `seed`, returning it.
+    async fn track_built_account(
+        &mut self,
+        multisig_account: Account,
+        seed: Word,
+    ) -> Result<Account, MultisigClientError> {
+        self.add_account(&multisig_account, Some(seed), false)
+            .await
+            .map_err(|e| MultisigClientError::AccountSetupError(e.to_string()))?;
+
+        Ok(multisig_account)
+    }
+
+    /// Fetches a public multisig account created elsewhere, e.g. with the CLI, from the node.
+    ///
+    /// The account is not tracked by the client, so that it can be validated before being
+    /// imported, see [`add_account`](Client::add_account).
+    ///
+    /// # Errors
+    ///
+    /// When the account cannot be fetched from the node, e.g. because it is private or doesn't
+    /// exist, or when its auth component is not the multisig auth component.
+    pub async fn fetch_multisig_account(
+        &self,
+        account_id: AccountId,
+    ) -> Result<Account, MultisigClientError> {
+        let fetched_account = self
+            .rpc_api
+            .get_account_details(account_id)
+            .await
+            .map_err(|e| MultisigClientError::AccountImportError(e.to_string()))?;
+
+        let account = match fetched_account {
+            FetchedAccount::Public(account, _) => Account::clone(&account),
+            FetchedAccount::Private(..) => {
+                return Err(MultisigClientError::AccountImportError(format!(
+                    "`{account_id}` is a private account"
+                )));
+            },
+        };
+
+        if !Self::has_multisig_auth_component(&account) {
+            return Err(MultisigClientError::NotMultisigAccount(account_id));
+        }
+
+        Ok(account)
     }
 }
 
 impl<AUTH: TransactionAuthenticator + Sync + 'static> MultisigClient<AUTH> {
+    /// Reads the threshold, the number of approvers and the approvers' public keys from the
+    /// storage of a multisig account, see [`MultisigConfig::try_from`].
+    ///
+    /// # Errors
+    ///
+    /// When the config storage slot or the public key of an approver is missing, or when the
+    /// threshold or the number of approvers does not fit in a `u32`.
+    pub fn account_config(account: &Account) -> Result<MultisigConfig, MultisigClientError> {
+        MultisigConfig::try_from(account)
+    }
+
+    /// Returns whether the account is authenticated by the multisig auth component.
+    ///
+    /// The auth procedure of an account is its first procedure. It is compared against the one of
+    /// the multisig auth component, which doesn't depend on the threshold nor on the approvers.
+    pub fn has_multisig_auth_component(account: &Account) -> bool {
+        let multisig_auth_component: AccountComponent =
+            AuthRpoFalcon512Multisig::new(1, vec![PublicKey::new(Word::default())])
+                .expect("a single approver with a threshold of 1 is a valid multisig config")
+                .into();
+
+        let multisig_code = AccountCode::from_components(
+            &[multisig_auth_component],
+            AccountType::RegularAccountImmutableCode,
+        )
+        .expect("the multisig auth component alone is valid account code");
+
+        let auth_procedure_root =
+            |code: &AccountCode| code.procedures().first().map(|procedure| *procedure.mast_root());
+
+        auth_procedure_root(account.code()) == auth_procedure_root(&multisig_code)
+    }
+
+    /// Reads the multisig configuration of a tracked account, see
+    /// [`account_config`](Self::account_config).
+    ///
+    /// # Errors
+    ///
+    /// When the account is not tracked by the client, or its storage does not hold a valid
+    /// multisig configuration.
+    pub async fn get_multisig_config(
+        &self,
+        account_id: AccountId,
+    ) -> Result<MultisigConfig, MultisigClientError> {
+        let account_record = self
+            .try_get_account(account_id)
+            .await
+            .map_err(|e| MultisigClientError::AccountConfigError(e.to_string()))?;
+
+        Self::account_config(&account_record.into())
+    }
+
     /// Propose a multisig transaction. This is expected to "dry-run" and only return
     /// `TransactionSummary`.
     pub async fn propose_multisig_transaction(
@@ -159,38 +431,215 @@ impl<AUTH: TransactionAuthenticator + Sync + 'static> MultisigClient<AUTH> {
             Err(ClientError::TransactionExecutorError(TransactionExecutorError::Unauthorized(
                 summary,
             ))) => Ok(*summary),
-            Err(e) => Err(MultisigClientError::TxProposalError(e.to_string())),
+            Err(e) => Err(MultisigClientError::tx_proposal(e)),
         }
     }
 
     /// Creates and executes a transaction specified by the request against the specified multisig
     /// account. It is expected to have at least `threshold` signatures from the approvers.
+    ///
+    /// # Errors
+    ///
+    /// [`MultisigClientError::InsufficientSignatures`] when fewer than `threshold` signatures are
+    /// given, before attempting to execute the transaction.
     pub async fn new_multisig_transaction(
         &mut self,
         account: Account,
+        transaction_request: TransactionRequest,
+        transaction_summary: TransactionSummary,
+        signatures: Vec<Option<SignatureFelts>>,
+    ) -> Result<TransactionResult, MultisigClientError> {
+        let MultisigConfig { threshold, approver_pub_keys, .. } = Self::account_config(&account)?;
+
+        self.new_multisig_transaction_with_pub_keys(
+            account.id(),
+            transaction_request,
+            transaction_summary,
+            signatures,
+            approver_pub_keys,
+            threshold,
+        )
+        .await
+    }
+
+    /// Creates and executes a transaction like
+    /// [`new_multisig_transaction`](Self::new_multisig_transaction), with the approvers' public
+    /// keys, in approver index order, and the threshold given rather than read from the account
+    /// storage.
+    pub async fn new_multisig_transaction_with_pub_keys(
+        &mut self,
+        account_id: AccountId,
         mut transaction_request: TransactionRequest,
         transaction_summary: TransactionSummary,
-        signatures: Vec<Option<Vec<Felt>>>,
+        signatures: Vec<Option<SignatureFelts>>,
+        approver_pub_keys: Vec<PublicKey>,
+        threshold: u32,
     ) -> Result<TransactionResult, MultisigClientError> {
-        // Add signatures to the advice provider
-        let advice_inputs = transaction_request.advice_map_mut();
-        let msg = transaction_summary.to_commitment();
-        let num_approvers: u32 =
-            account.storage().get_item(0).unwrap().as_elements()[1].try_into().unwrap();
-
-        for i in 0..num_approvers as usize {
-            let pub_key_index_word = Word::from([Felt::from(i as u32), ZERO, ZERO, ZERO]);
-            let pub_key = account.storage().get_map_item(1, pub_key_index_word).unwrap();
-            let sig_key = Hasher::merge(&[pub_key, msg]);
-            if let Some(signature) = signatures.get(i).and_then(|s| s.as_ref()) {
-                advice_inputs.extend(vec![(sig_key, signature.clone())]);
-            }
+        // the VM would fail the auth procedure anyway, only after a costly execution attempt
+        let have = signatures.iter().flatten().count();
+        if have < threshold as usize {
+            return Err(MultisigClientError::InsufficientSignatures { have, need: threshold });
         }
 
-        // TODO as sanity check we should verify that we have enough signatures
+        let pub_keys: Vec<_> = approver_pub_keys.into_iter().map(Word::from).collect();
+
+        // Add signatures to the advice provider
+        let signature_advice =
+            build_signature_advice(&pub_keys, transaction_summary.to_commitment(), &signatures)?;
+
+        transaction_request.advice_map_mut().extend(signature_advice);
 
-        self.new_transaction(account.id(), transaction_request)
+        self.new_transaction(account_id, transaction_request)
             .await
             .map_err(|e| MultisigClientError::TxExecutionError(e.to_string()))
     }
 }
+
+/// An approver signature encoded as the field elements the multisig account reads from the
+/// advice map, whatever its scheme.
+///
+/// Wrapping the felts keeps encoded signatures from being mixed up with other felt vectors on
+/// their way into the advice map, so they are only built by encoding a signature: an RPO
+/// Falcon512 one through [`From<Signature>`], encoded like
+/// [`TransactionAuthenticator::get_signature`] does, or a secp256k1 ECDSA one through
+/// [`from_ecdsa_k256`](Self::from_ecdsa_k256).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignatureFelts(Vec<Felt>);
+
+impl SignatureFelts {
+    /// Wraps felts already encoded like a signature, e.g. by a [`TransactionAuthenticator`].
+    pub(crate) fn from_felts(felts: Vec<Felt>) -> Self {
+        Self(felts)
+    }
+
+    /// Encodes a secp256k1 ECDSA `signature`, encoded as `r || s || v`.
+    ///
+    /// The signature bytes are packed into little-endian `u32` limbs, one per field element, with
+    /// the last limb padded with zeros. As the signature is recoverable, the public key is not
+    /// included.
+    // TODO: align with the on-chain ECDSA verification procedure once it is available.
+    pub fn from_ecdsa_k256(signature: &[u8; 65]) -> Self {
+        let felts = signature
+            .chunks(4)
+            .map(|chunk| {
+                let mut limb = [0u8; 4];
+                limb[..chunk.len()].copy_from_slice(chunk);
+                Felt::from(u32::from_le_bytes(limb))
+            })
+            .collect();
+
+        Self(felts)
+    }
+
+    /// Returns the encoded felts.
+    pub fn as_felts(&self) -> &[Felt] {
+        &self.0
+    }
+
+    /// Returns the encoded felts, consuming the signature.
+    pub fn into_felts(self) -> Vec<Felt> {
+        self.0
+    }
+}
+
+impl From<Signature> for SignatureFelts {
+    /// Slightly modified implementation of [`miden_tx::auth::signatures::get_falcon_signature`](https://docs.rs/miden-tx/0.11.5/miden_tx/auth/signatures/fn.get_falcon_signature.html).
+    // TODO: deprecate this after miden-client v0.12 is available.
+    fn from(sig: Signature) -> Self {
+        // The signature is composed of a nonce and a polynomial s2
+        // The nonce is represented as 8 field elements.
+        let nonce = sig.nonce();
+
+        let s2 = sig.sig_poly();
+
+        // We also need in the VM the expanded key corresponding to the public key that was
+        // provided via the operand stack
+        let h = &sig.pk_poly().0;
+
+        // Lastly, for the probabilistic product routine that is part of the verification
+        // procedure, we need to compute the product of the expanded key and the signature
+        // polynomial in the ring of polynomials with coefficients in the Miden field.
+        let pi = Polynomial::mul_modulo_p(h, s2);
+
+        // We now push the expanded key, the signature polynomial, and the product of the
+        // expanded key and the signature polynomial to the advice stack. We also push
+        // the challenge point at which the previous polynomials will be evaluated.
+        // Finally, we push the nonce needed for the hash-to-point algorithm.
+
+        let mut polynomials: Vec<Felt> =
+            h.coefficients.iter().map(|a| Felt::from(a.value() as u32)).collect();
+        polynomials.extend(s2.coefficients.iter().map(|a| Felt::from(a.value() as u32)));
+        polynomials.extend(pi.iter().map(|a| Felt::new(*a)));
+
+        let digest_polynomials = Hasher::hash_elements(&polynomials);
+        let challenge = (digest_polynomials[0], digest_polynomials[1]);
+
+        let mut result: Vec<Felt> = vec![challenge.0, challenge.1];
+        result.extend_from_slice(&polynomials);
+        result.extend_from_slice(&nonce.to_elements());
+
+        result.reverse();
+
+        Self(result)
+    }
+}
+
+/// Builds a multisig account with the specified approvers and threshold from the given
+/// `init_seed`, returning it along with the seed it was built from.
+fn build_account_with_seed(
+    approvers: Vec<PublicKey>,
+    threshold: u32,
+    init_seed: [u8; 32],
+) -> Result<(Account, Word), MultisigClientError> {
+    if approvers.len() > MAX_APPROVERS {
+        return Err(MultisigClientError::TooManyApprovers { count: approvers.len() });
+    }
+
+    let multisig_auth_component = AuthRpoFalcon512Multisig::new(threshold, approvers)
+        .map_err(|e| MultisigClientError::AccountSetupError(e.to_string()))?;
+
+    let (multisig_account, seed) = AccountBuilder::new(init_seed)
+        .with_auth_component(multisig_auth_component)
+        .account_type(AccountType::RegularAccountImmutableCode)
+        .storage_mode(AccountStorageMode::Public)
+        .with_component(BasicWallet)
+        .build()
+        .map_err(|e| MultisigClientError::AccountSetupError(e.to_string()))?;
+
+    Ok((multisig_account, seed))
+}
+
+/// Builds the advice map entries carrying the approvers' signatures over a transaction summary
+/// commitment `msg`.
+///
+/// `signatures` holds one slot per approver, in approver index order, the signature of the
+/// approver at index `i` is keyed by `hash(pub_keys[i], msg)`. Approvers without a signature are
+/// skipped.
+///
+/// # Errors
+///
+/// When the number of signature slots differs from the number of approver public keys.
+pub fn build_signature_advice(
+    pub_keys: &[Word],
+    msg: Word,
+    signatures: &[Option<SignatureFelts>],
+) -> Result<Vec<(Word, Vec<Felt>)>, MultisigClientError> {
+    if signatures.len() != pub_keys.len() {
+        return Err(MultisigClientError::TxExecutionError(format!(
+            "expected {} signature slots, one per approver, got {}",
+            pub_keys.len(),
+            signatures.len()
+        )));
+    }
+
+    let signature_advice = pub_keys
+        .iter()
+        .zip(signatures)
+        .filter_map(|(&pub_key, signature)| {
+            let signature = signature.as_ref()?;
+            Some((Hasher::merge(&[pub_key, msg]), signature.as_felts().to_vec()))
+        })
+        .collect();
+
+    Ok(signature_advice)
+}
@@ -2,23 +2,33 @@ use alloc::boxed::Box;
 
 use miden_client::{
     auth::SigningInputs,
+    crypto::SecretKey,
     note::NoteType,
+    rpc::RpcError,
     testing::{
         common::{TestClientKeyStore, insert_new_fungible_faucet, insert_new_wallet, mint_note},
         mock::MockRpcApi,
     },
     transaction::TransactionRequestBuilder,
 };
+use miden_tx::auth::signatures;
+use proptest::prelude::*;
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
 
 use super::*;
 
+const RNG_SEED: u64 = 8086;
+
 type TestMultisigClient = MultisigClient<TestClientKeyStore>;
 
 async fn setup_multisig_client() -> (TestMultisigClient, MockRpcApi, TestClientKeyStore) {
     let (client, mock_rpc_api, keystore) =
         miden_multisig_test_utils::create_test_client(std::env::temp_dir()).await;
 
-    (MultisigClient { client }, mock_rpc_api, keystore)
+    let rpc_api = Arc::new(mock_rpc_api.clone());
+
+    (MultisigClient { client, rpc_api }, mock_rpc_api, keystore)
 }
 
 #[tokio::test]
@@ -43,7 +53,8 @@ async fn multisig() {
             .unwrap();
     let pub_key_b = secret_key_b.public_key();
 
-    let multisig_account = coordinator_client.setup_account(vec![pub_key_a, pub_key_b], 2).await;
+    let multisig_account =
+        coordinator_client.setup_account(vec![pub_key_a, pub_key_b], 2).await.unwrap();
 
     // we insert the faucet to the coordinator client for convenience
     let (faucet_account, ..) = insert_new_fungible_faucet(
@@ -98,9 +109,240 @@ async fn multisig() {
             multisig_account,
             tx_request,
             tx_summary,
-            vec![Some(signature_a), Some(signature_b)],
+            vec![
+                Some(SignatureFelts::from_felts(signature_a)),
+                Some(SignatureFelts::from_felts(signature_b)),
+            ],
         )
         .await;
 
     assert!(tx_result.is_ok());
 }
+
+#[tokio::test]
+async fn executing_with_fewer_signatures_than_threshold_fails_before_execution() {
+    let (mut coordinator_client, ..) = setup_multisig_client().await;
+
+    let secret_keys: Vec<_> = (0..3).map(|_| SecretKey::new()).collect();
+    let approvers = secret_keys.iter().map(SecretKey::public_key).collect();
+    let multisig_account = coordinator_client.setup_account(approvers, 2).await.unwrap();
+
+    let tx_request = TransactionRequestBuilder::new().build().unwrap();
+    let tx_summary = coordinator_client
+        .propose_multisig_transaction(multisig_account.id(), tx_request.clone())
+        .await
+        .unwrap();
+
+    let signature = SignatureFelts::from(secret_keys[0].sign(tx_summary.to_commitment()));
+
+    let tx_result = coordinator_client
+        .new_multisig_transaction(
+            multisig_account,
+            tx_request,
+            tx_summary,
+            vec![Some(signature), None, None],
+        )
+        .await;
+
+    assert!(matches!(
+        tx_result,
+        Err(MultisigClientError::InsufficientSignatures { have: 1, need: 2 })
+    ));
+}
+
+#[tokio::test]
+async fn account_config_reads_threshold_and_approvers_from_storage() {
+    let (mut coordinator_client, ..) = setup_multisig_client().await;
+
+    let approvers: Vec<_> = (0..3).map(|_| SecretKey::new().public_key()).collect();
+    let multisig_account = coordinator_client.setup_account(approvers.clone(), 2).await.unwrap();
+
+    let config = TestMultisigClient::account_config(&multisig_account).unwrap();
+    let tracked_config =
+        coordinator_client.get_multisig_config(multisig_account.id()).await.unwrap();
+
+    let expected = MultisigConfig {
+        threshold: 2,
+        num_approvers: 3,
+        approver_pub_keys: approvers,
+    };
+
+    assert_eq!(config, expected);
+    assert_eq!(tracked_config, expected);
+}
+
+#[tokio::test]
+async fn only_multisig_accounts_have_the_multisig_auth_component() {
+    let (mut coordinator_client, _, keystore) = setup_multisig_client().await;
+
+    let approvers: Vec<_> = (0..2).map(|_| SecretKey::new().public_key()).collect();
+    let multisig_account = coordinator_client.setup_account(approvers, 1).await.unwrap();
+
+    let (wallet, ..) =
+        insert_new_wallet(coordinator_client.deref_mut(), AccountStorageMode::Public, &keystore)
+            .await
+            .unwrap();
+
+    assert!(TestMultisigClient::has_multisig_auth_component(&multisig_account));
+    assert!(!TestMultisigClient::has_multisig_auth_component(&wallet));
+}
+
+#[tokio::test]
+async fn setting_up_accounts_with_the_same_seed_yields_the_same_account_id() {
+    let (mut coordinator_client, ..) = setup_multisig_client().await;
+    let (mut other_client, ..) = setup_multisig_client().await;
+
+    let approvers: Vec<_> = (0..2).map(|_| SecretKey::new().public_key()).collect();
+    let init_seed = [7; 32];
+
+    let multisig_account = coordinator_client
+        .setup_account_with_seed(approvers.clone(), 2, init_seed)
+        .await
+        .unwrap();
+    let same_account = other_client
+        .setup_account_with_seed(approvers.clone(), 2, init_seed)
+        .await
+        .unwrap();
+    let random_account = other_client.setup_account(approvers, 2).await.unwrap();
+
+    assert_eq!(same_account.id(), multisig_account.id());
+    assert_ne!(random_account.id(), multisig_account.id());
+}
+
+#[tokio::test]
+async fn setting_up_account_with_max_approvers_succeeds() {
+    let (mut coordinator_client, ..) = setup_multisig_client().await;
+
+    let approvers: Vec<_> = (0..MAX_APPROVERS).map(|_| SecretKey::new().public_key()).collect();
+    let multisig_account = coordinator_client.setup_account(approvers, 2).await.unwrap();
+
+    let config = TestMultisigClient::account_config(&multisig_account).unwrap();
+
+    assert_eq!(config.num_approvers as usize, MAX_APPROVERS);
+}
+
+#[tokio::test]
+async fn setting_up_account_with_more_than_max_approvers_fails() {
+    let (mut coordinator_client, ..) = setup_multisig_client().await;
+
+    let approvers: Vec<_> = (0..=MAX_APPROVERS).map(|_| SecretKey::new().public_key()).collect();
+    let result = coordinator_client.setup_account(approvers, 2).await;
+
+    assert!(matches!(
+        result,
+        Err(MultisigClientError::TooManyApprovers { count }) if count == MAX_APPROVERS + 1
+    ));
+}
+
+#[test]
+fn proposal_errors_reaching_the_node_are_told_apart_from_other_errors() {
+    // Act
+    let rpc_error = MultisigClientError::tx_proposal(ClientError::RpcError(
+        RpcError::ExpectedDataMissing("block header".into()),
+    ));
+    let other_error =
+        MultisigClientError::tx_proposal(ClientError::ClientInitializationError("store".into()));
+
+    // Assert
+    assert!(matches!(rpc_error, MultisigClientError::RpcError(_)));
+    assert!(matches!(other_error, MultisigClientError::TxProposalError(_)));
+}
+
+#[test]
+fn falcon_signature_is_encoded_as_authenticator_felts_keyed_by_approver_in_advice() {
+    // Arrange
+    let sk = SecretKey::new();
+    let pub_key = Word::from(sk.public_key());
+    let msg = Hasher::hash(b"miden will get multisig");
+
+    let sig = sk.sign_with_rng(msg, &mut ChaCha20Rng::seed_from_u64(RNG_SEED));
+
+    // Act
+    let signature = SignatureFelts::from(sig);
+    let signature_advice =
+        build_signature_advice(&[pub_key], msg, &[Some(signature.clone())]).unwrap();
+
+    // Assert
+    let expected_felts =
+        signatures::get_falcon_signature(&sk, msg, &mut ChaCha20Rng::seed_from_u64(RNG_SEED))
+            .expect("valid secret key must be able to sign the message");
+
+    assert_eq!(signature.as_felts(), expected_felts);
+    assert_eq!(signature_advice, vec![(Hasher::merge(&[pub_key, msg]), expected_felts)]);
+}
+
+#[test]
+fn ecdsa_k256_signature_is_encoded_as_u32_limbs() {
+    // Arrange
+    let sig_bz: [u8; 65] = core::array::from_fn(|i| i as u8 + 1);
+
+    // Act
+    let signature = SignatureFelts::from_ecdsa_k256(&sig_bz);
+
+    // Assert
+    let felts = signature.as_felts();
+
+    assert_eq!(felts.len(), 17);
+    assert_eq!(felts[0], Felt::from(u32::from_le_bytes([1, 2, 3, 4])));
+    assert_eq!(felts[16], Felt::from(65u32));
+}
+
+fn arb_word() -> impl Strategy<Value = Word> {
+    any::<[u64; 4]>().prop_map(|elements| Word::from(elements.map(Felt::new)))
+}
+
+fn arb_signature() -> impl Strategy<Value = SignatureFelts> {
+    prop::collection::vec(any::<u64>().prop_map(Felt::new), 0..8)
+        .prop_map(SignatureFelts::from_felts)
+}
+
+/// Approver public keys along with one sparse signature slot per approver.
+fn arb_approver_signatures() -> impl Strategy<Value = (Vec<Word>, Vec<Option<SignatureFelts>>)> {
+    prop_oneof![Just(0), Just(1), Just(MAX_APPROVERS), 0..=MAX_APPROVERS].prop_flat_map(
+        |num_approvers| {
+            (
+                prop::collection::vec(arb_word(), num_approvers),
+                prop::collection::vec(prop::option::of(arb_signature()), num_approvers),
+            )
+        },
+    )
+}
+
+proptest! {
+    #[test]
+    fn signature_advice_holds_exactly_provided_signatures_keyed_by_approver(
+        (pub_keys, signatures) in arb_approver_signatures(),
+        msg in arb_word(),
+    ) {
+        let signature_advice = build_signature_advice(&pub_keys, msg, &signatures).unwrap();
+
+        let expected: Vec<_> = pub_keys
+            .iter()
+            .zip(&signatures)
+            .filter_map(|(&pub_key, signature)| {
+                let signature = signature.clone()?.into_felts();
+                Some((Hasher::merge(&[pub_key, msg]), signature))
+            })
+            .collect();
+
+        prop_assert_eq!(signature_advice, expected);
+    }
+
+    #[test]
+    fn signature_advice_fails_on_signature_count_mismatch(
+        (pub_keys, mut signatures) in arb_approver_signatures(),
+        msg in arb_word(),
+        extra_signatures in 1..4usize,
+        drop_signatures in any::<bool>(),
+    ) {
+        if drop_signatures && signatures.len() >= extra_signatures {
+            signatures.truncate(signatures.len() - extra_signatures);
+        } else {
+            signatures.extend(core::iter::repeat_n(None, extra_signatures));
+        }
+
+        let result = build_signature_advice(&pub_keys, msg, &signatures);
+
+        prop_assert!(matches!(result, Err(MultisigClientError::TxExecutionError(_))));
+    }
+}
@@ -0,0 +1,278 @@
+//! Scenarios every [`MultisigStoreBackend`] is expected to behave the same in.
+//!
+//! Each scenario starts from an empty backend and panics on the first behavior differing from
+//! the one of the PostgreSQL [`MultisigStore`](miden_multisig_coordinator_store::MultisigStore),
+//! so that running them against both it and [`InMemoryMultisigStore`](crate::InMemoryMultisigStore)
+//! keeps the latter from drifting away. [`run`] runs all of them.
+
+use core::num::NonZeroU32;
+
+use miden_client::{
+    account::{AccountIdAddress, AccountStorageMode, NetworkId},
+    crypto::SecretKey,
+    transaction::TransactionRequest,
+};
+use miden_multisig_coordinator_domain::{
+    account::{MultisigAccount, WithApprovers, WithPubKeyCommits},
+    key::{ApproverKey, ApproverSignature},
+    tx::{MultisigTxDissolved, MultisigTxId, MultisigTxStatus},
+};
+use miden_multisig_coordinator_store::{MultisigStoreBackend, MultisigStoreError};
+use miden_objects::{
+    testing::account_id::{
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE,
+        ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE, ACCOUNT_ID_SENDER,
+    },
+    transaction::TransactionSummary,
+};
+
+use crate::{account_id_address, empty_tx_summary, pay_to_id_tx_request};
+
+/// Runs every conformance scenario, each against a new empty backend.
+pub async fn run<S>(new_store: impl AsyncFn() -> S)
+where
+    S: MultisigStoreBackend,
+{
+    multisig_account_is_created_once_and_archived_reversibly(&new_store().await).await;
+    signatures_are_accepted_from_approvers_until_threshold_is_met(&new_store().await).await;
+    pending_txs_are_capped_and_only_failed_ones_are_reproposed(&new_store().await).await;
+    pending_txs_of_archived_accounts_are_left_out_of_approver_feed(&new_store().await).await;
+}
+
+/// Creating an account twice is refused, and archiving only hides it from the default listing.
+pub async fn multisig_account_is_created_once_and_archived_reversibly(
+    store: &dyn MultisigStoreBackend,
+) {
+    let fixture = Fixture::new(NonZeroU32::MIN);
+
+    store.create_multisig_account(fixture.multisig_account()).await.unwrap();
+
+    let recreated = store.create_multisig_account(fixture.multisig_account()).await;
+
+    assert!(matches!(recreated, Err(MultisigStoreError::MultisigAccountAlreadyExists)));
+
+    let indexed_approvers = store
+        .get_indexed_approvers_by_multisig_account_address(NetworkId::Testnet, fixture.multisig)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let indices = indexed_approvers.iter().map(|(index, _)| *index).collect::<Vec<_>>();
+
+    assert_eq!(indices, [0, 1]);
+
+    let listed = async |include_archived| {
+        store.get_all_multisig_accounts(include_archived).await.unwrap().len()
+    };
+
+    store
+        .archive_multisig_account(NetworkId::Testnet, fixture.multisig)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(listed(false).await, 0);
+    assert_eq!(listed(true).await, 1);
+
+    let fetched = store.get_multisig_account(NetworkId::Testnet, fixture.multisig).await.unwrap();
+
+    assert!(fetched.is_some());
+
+    store
+        .unarchive_multisig_account(NetworkId::Testnet, fixture.multisig)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(listed(false).await, 1);
+}
+
+/// Only valid signatures of approvers are stored, and the one meeting the threshold says so.
+pub async fn signatures_are_accepted_from_approvers_until_threshold_is_met(
+    store: &dyn MultisigStoreBackend,
+) {
+    let fixture = Fixture::new(NonZeroU32::new(2).unwrap());
+
+    store.create_multisig_account(fixture.multisig_account()).await.unwrap();
+
+    let tx_id = fixture.propose(store, None).await.unwrap();
+
+    let sign = async |approver, sk: &SecretKey| {
+        let signature = ApproverSignature::Falcon(sk.sign(fixture.tx_summary.to_commitment()));
+
+        store
+            .add_multisig_tx_signature(&tx_id, NetworkId::Testnet, approver, &signature)
+            .await
+    };
+
+    let by_non_approver = sign(fixture.multisig, &fixture.alice_sk).await.unwrap();
+    let with_wrong_key = sign(fixture.alice, &fixture.bob_sk).await;
+    let by_alice = sign(fixture.alice, &fixture.alice_sk).await.unwrap();
+    let by_bob = sign(fixture.bob, &fixture.bob_sk).await.unwrap();
+
+    assert_eq!(by_non_approver, None);
+    assert!(matches!(with_wrong_key, Err(MultisigStoreError::InvalidSignature)));
+    assert_eq!(by_alice, Some(false));
+    assert_eq!(by_bob, Some(true));
+
+    for approver in [fixture.alice, fixture.bob] {
+        let signed = store.has_approver_signed(&tx_id, NetworkId::Testnet, approver).await.unwrap();
+
+        assert!(signed);
+    }
+}
+
+/// Proposals beyond the max pending txs are refused, as are reproposals of txs not failed, and
+/// reproposing a failed tx clears its signatures.
+pub async fn pending_txs_are_capped_and_only_failed_ones_are_reproposed(
+    store: &dyn MultisigStoreBackend,
+) {
+    let fixture = Fixture::new(NonZeroU32::MIN);
+    let max = NonZeroU32::new(1);
+
+    store.create_multisig_account(fixture.multisig_account()).await.unwrap();
+
+    let tx_id = fixture.propose(store, max).await.unwrap();
+    let capped = fixture.propose(store, max).await;
+
+    assert!(matches!(
+        capped,
+        Err(MultisigStoreError::TooManyPendingTxs { max: capped_at }) if capped_at.get() == 1,
+    ));
+
+    let removal = store
+        .remove_multisig_account_approver(NetworkId::Testnet, fixture.multisig, fixture.bob)
+        .await;
+
+    assert!(matches!(removal, Err(MultisigStoreError::PendingTxs)));
+
+    let reproposal_of_pending =
+        store.repropose_failed_multisig_tx(&tx_id, &fixture.tx_summary, None).await;
+
+    assert!(matches!(reproposal_of_pending, Err(MultisigStoreError::NotFound(_))));
+
+    let signature =
+        ApproverSignature::Falcon(fixture.alice_sk.sign(fixture.tx_summary.to_commitment()));
+
+    store
+        .add_multisig_tx_signature(&tx_id, NetworkId::Testnet, fixture.alice, &signature)
+        .await
+        .unwrap();
+
+    store
+        .update_multisig_tx_status_to_failure_by_id(&tx_id, "stale chain state")
+        .await
+        .unwrap();
+
+    store
+        .repropose_failed_multisig_tx(&tx_id, &fixture.tx_summary, max)
+        .await
+        .unwrap();
+
+    let MultisigTxDissolved { status, .. } =
+        store.get_multisig_tx_by_id(&tx_id).await.unwrap().unwrap().dissolve();
+
+    let signed = store
+        .has_approver_signed(&tx_id, NetworkId::Testnet, fixture.alice)
+        .await
+        .unwrap();
+
+    assert!(matches!(status, MultisigTxStatus::Pending));
+    assert!(!signed);
+}
+
+/// The pending txs of an approver only span the accounts not archived.
+pub async fn pending_txs_of_archived_accounts_are_left_out_of_approver_feed(
+    store: &dyn MultisigStoreBackend,
+) {
+    let fixture = Fixture::new(NonZeroU32::MIN);
+
+    store.create_multisig_account(fixture.multisig_account()).await.unwrap();
+
+    let tx_id = fixture.propose(store, None).await.unwrap();
+
+    let pending_tx_ids = async || {
+        let (txs, _) = store
+            .get_pending_txs_for_approver(NetworkId::Testnet, fixture.alice, None, None)
+            .await
+            .unwrap();
+
+        txs.into_iter().map(|(tx_id, ..)| tx_id).collect::<Vec<_>>()
+    };
+
+    assert_eq!(pending_tx_ids().await, [tx_id]);
+
+    store
+        .archive_multisig_account(NetworkId::Testnet, fixture.multisig)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(pending_tx_ids().await.is_empty());
+}
+
+/// A multisig account of two approvers, along with a tx to propose on it.
+struct Fixture {
+    multisig: AccountIdAddress,
+    alice: AccountIdAddress,
+    bob: AccountIdAddress,
+    alice_sk: SecretKey,
+    bob_sk: SecretKey,
+    threshold: NonZeroU32,
+    tx_request: TransactionRequest,
+    tx_summary: TransactionSummary,
+}
+
+impl Fixture {
+    fn new(threshold: NonZeroU32) -> Self {
+        let multisig = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_UPDATABLE_CODE);
+        let alice = account_id_address(ACCOUNT_ID_SENDER);
+        let bob = account_id_address(ACCOUNT_ID_REGULAR_PUBLIC_ACCOUNT_IMMUTABLE_CODE);
+
+        Self {
+            multisig,
+            alice,
+            bob,
+            alice_sk: SecretKey::new(),
+            bob_sk: SecretKey::new(),
+            threshold,
+            tx_request: pay_to_id_tx_request(multisig.id(), alice.id()),
+            tx_summary: empty_tx_summary(multisig.id()),
+        }
+    }
+
+    fn multisig_account(&self) -> MultisigAccount<WithApprovers, WithPubKeyCommits, ()> {
+        MultisigAccount::builder()
+            .address(self.multisig)
+            .network_id(NetworkId::Testnet)
+            .kind(AccountStorageMode::Public)
+            .threshold(self.threshold)
+            .aux(())
+            .build()
+            .with_approvers(vec![self.alice, self.bob])
+            .unwrap()
+            .with_pub_key_commits(vec![
+                ApproverKey::Falcon(self.alice_sk.public_key()),
+                ApproverKey::Falcon(self.bob_sk.public_key()),
+            ])
+            .unwrap()
+    }
+
+    async fn propose(
+        &self,
+        store: &dyn MultisigStoreBackend,
+        max_pending_txs: Option<NonZeroU32>,
+    ) -> Result<MultisigTxId, MultisigStoreError> {
+        store
+            .create_multisig_tx(
+                NetworkId::Testnet,
+                self.multisig,
+                &self.tx_request,
+                &self.tx_summary,
+                None,
+                None,
+                max_pending_txs,
+            )
+            .await
+    }
+}
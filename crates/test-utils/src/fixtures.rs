@@ -0,0 +1,59 @@
+//! Accounts and txs the coordinator tests build their scenarios from.
+
+use miden_client::{
+    ONE, Word,
+    account::{AccountIdAddress, AddressInterface},
+    asset::FungibleAsset,
+    crypto::RpoRandomCoin,
+    note::NoteType,
+    transaction::{PaymentNoteDescription, TransactionRequest, TransactionRequestBuilder},
+};
+use miden_objects::{
+    account::{AccountDelta, AccountId, AccountStorageDelta, AccountVaultDelta},
+    testing::account_id::ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET,
+    transaction::{InputNotes, OutputNotes, TransactionSummary},
+};
+
+/// Addresses the account of `id`, e.g. one of the [`miden_objects::testing::account_id`]
+/// constants, as a basic wallet.
+pub fn account_id_address(id: u128) -> AccountIdAddress {
+    AccountIdAddress::new(id.try_into().unwrap(), AddressInterface::BasicWallet)
+}
+
+/// Builds a tx request of `sender` paying 100 units of the test faucet's asset to `target` in a
+/// public note.
+pub fn pay_to_id_tx_request(sender: AccountId, target: AccountId) -> TransactionRequest {
+    let payment =
+        FungibleAsset::new(ACCOUNT_ID_PUBLIC_FUNGIBLE_FAUCET.try_into().unwrap(), 100).unwrap();
+
+    TransactionRequestBuilder::new()
+        .build_pay_to_id(
+            PaymentNoteDescription::new(vec![payment.into()], sender, target),
+            NoteType::Public,
+            &mut RpoRandomCoin::new(Word::default()),
+        )
+        .unwrap()
+}
+
+/// Summarizes a tx applying `account_delta`, without consuming nor creating any note.
+pub fn tx_summary_of(account_delta: AccountDelta) -> TransactionSummary {
+    TransactionSummary::new(
+        account_delta,
+        InputNotes::new(vec![]).unwrap(),
+        OutputNotes::new(vec![]).unwrap(),
+        Word::default(),
+    )
+}
+
+/// Summarizes a tx of the account `account_id` changing nothing but its nonce.
+pub fn empty_tx_summary(account_id: AccountId) -> TransactionSummary {
+    let account_delta = AccountDelta::new(
+        account_id,
+        AccountStorageDelta::default(),
+        AccountVaultDelta::default(),
+        ONE,
+    )
+    .unwrap();
+
+    tx_summary_of(account_delta)
+}
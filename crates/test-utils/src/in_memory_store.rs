@@ -0,0 +1,1468 @@
+//! An in-memory multisig store backend, running the multisig engine without a database.
+
+use core::{cmp::Ordering, num::NonZeroU32, time::Duration};
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use chrono::{DateTime, Datelike, Months, SubsecRound, TimeDelta, Utc};
+use futures::{StreamExt, stream::BoxStream};
+use miden_client::{
+    Word,
+    account::{AccountIdAddress, AccountStorageMode, NetworkId},
+    transaction::TransactionRequest,
+};
+use miden_multisig_coordinator_domain::{
+    Timestamps,
+    account::{MultisigAccount, MultisigApprover, WithApprovers, WithPubKeyCommits},
+    audit::{AuditAction, AuditEvent, AuditOutcome, AuditSubject},
+    cursor::Cursor,
+    key::{ApproverKey, ApproverSignature},
+    tx::{
+        MultisigTx, MultisigTxId, MultisigTxStats, MultisigTxStatus, SortDirection,
+        TrendGranularity, TxEffect, TxSort, TxSortField,
+    },
+};
+use miden_multisig_coordinator_store::{MultisigStore, MultisigStoreBackend, MultisigStoreError};
+use miden_multisig_coordinator_utils::{to_account_key, to_bech32, verify_ecdsa_k256_signature};
+use miden_objects::{
+    block::BlockNumber,
+    crypto::dsa::rpo_falcon512::PublicKey,
+    transaction::{TransactionId, TransactionSummary},
+};
+use uuid::Uuid;
+
+type Result<T, E = MultisigStoreError> = core::result::Result<T, E>;
+
+/// A [`MultisigStoreBackend`] keeping everything in memory, so that the multisig engine can be
+/// exercised in tests without a PostgreSQL container.
+///
+/// It behaves like `MultisigStore`, e.g. it verifies signatures against the approver keys and
+/// meets thresholds by approver weight, except that nothing outlives the store. There is no read
+/// replica, every read observes the preceding writes, and clones share the same state.
+///
+/// Ids are assigned sequentially and timestamps are truncated to microseconds, as stored by the
+/// database, so that listings and cursors behave the same.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryMultisigStore {
+    state: Arc<Mutex<State>>,
+    max_all_multisig_accounts: Option<NonZeroU32>,
+}
+
+impl InMemoryMultisigStore {
+    /// Creates a new, empty `InMemoryMultisigStore`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the most multisig accounts `get_all_multisig_accounts` retrieves at once, see
+    /// `MultisigStore::with_max_all_multisig_accounts`.
+    ///
+    /// Defaults to `MultisigStore::DEFAULT_MAX_ALL_MULTISIG_ACCOUNTS`.
+    pub fn with_max_all_multisig_accounts(self, max: NonZeroU32) -> Self {
+        Self {
+            max_all_multisig_accounts: Some(max),
+            ..self
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        // a panicking test poisons the state of its own store only
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    /// The multisig accounts, in creation order.
+    accounts: Vec<AccountEntry>,
+    /// The approvers of all accounts, keyed by bech32 address.
+    approvers: HashMap<String, ApproverEntry>,
+    /// The multisig transactions, in creation order.
+    txs: Vec<TxEntry>,
+    /// The audit events of all accounts, along the bech32 address of their account, in recording
+    /// order.
+    audit_events: Vec<(String, AuditEvent)>,
+    last_id: u128,
+}
+
+#[derive(Debug)]
+struct AccountEntry {
+    bech32: String,
+    address: AccountIdAddress,
+    network_id: NetworkId,
+    kind: AccountStorageMode,
+    threshold: NonZeroU32,
+    nonce: Option<u64>,
+    created_at: DateTime<Utc>,
+    archived_at: Option<DateTime<Utc>>,
+    /// The bech32 addresses and weights of the approvers, in approver index order, removed ones
+    /// included as they keep their index.
+    approvers: Vec<(String, NonZeroU32)>,
+    /// The bech32 addresses of the removed approvers.
+    removed_approvers: HashSet<String>,
+    /// The labels the account gives its approvers, keyed by bech32 approver address.
+    approver_labels: HashMap<String, String>,
+}
+
+#[derive(Debug)]
+struct ApproverEntry {
+    address: AccountIdAddress,
+    network_id: NetworkId,
+    pub_key_commit: ApproverKey,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(Debug)]
+struct TxEntry {
+    id: Uuid,
+    account: String,
+    status: MultisigTxStatus,
+    tx_request: TransactionRequest,
+    tx_summary: TransactionSummary,
+    title: Option<String>,
+    memo: Option<String>,
+    failure_reason: Option<String>,
+    chain_tx_id: Option<TransactionId>,
+    submission_block_num: Option<BlockNumber>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    threshold_met_at: Option<DateTime<Utc>>,
+    /// The signatures, keyed by bech32 approver address, in the order they were added.
+    signatures: Vec<(String, ApproverSignature, DateTime<Utc>)>,
+}
+
+impl AccountEntry {
+    fn to_multisig_account(&self) -> MultisigAccount {
+        let timestamps = Timestamps::builder()
+            .created_at(self.created_at)
+            .updated_at(self.created_at)
+            .build();
+
+        MultisigAccount::builder()
+            .address(self.address)
+            .network_id(self.network_id)
+            .kind(self.kind)
+            .threshold(self.threshold)
+            .maybe_nonce(self.nonce)
+            .maybe_archived_at(self.archived_at)
+            .aux(timestamps)
+            .build()
+    }
+
+    /// Returns the approvers who weren't removed along with their approver index, in approver
+    /// index order.
+    fn current_approvers(&self) -> impl Iterator<Item = (u32, &String, NonZeroU32)> {
+        (0..)
+            .zip(&self.approvers)
+            .filter(|(_, (address, _))| !self.removed_approvers.contains(address))
+            .map(|(idx, (address, weight))| (idx, address, *weight))
+    }
+
+    fn approver_label(&self, approver_address: &str) -> Option<String> {
+        self.approver_labels.get(approver_address).cloned()
+    }
+}
+
+impl ApproverEntry {
+    fn to_multisig_approver(&self, label: Option<String>) -> MultisigApprover {
+        let timestamps = Timestamps::builder()
+            .created_at(self.created_at)
+            .updated_at(self.created_at)
+            .build();
+
+        MultisigApprover::builder()
+            .address(self.address)
+            .network_id(self.network_id)
+            .pub_key_commit(self.pub_key_commit)
+            .maybe_label(label)
+            .aux(timestamps)
+            .build()
+    }
+}
+
+impl TxEntry {
+    fn has_signed(&self, approver_address: &str) -> bool {
+        self.signatures.iter().any(|(address, ..)| address == approver_address)
+    }
+}
+
+impl State {
+    fn next_id(&mut self) -> Uuid {
+        self.last_id += 1;
+        Uuid::from_u128(self.last_id)
+    }
+
+    fn account(&self, address: &str) -> Option<&AccountEntry> {
+        self.accounts.iter().find(|account| account.bech32 == address)
+    }
+
+    fn account_mut(&mut self, address: &str) -> Option<&mut AccountEntry> {
+        self.accounts.iter_mut().find(|account| account.bech32 == address)
+    }
+
+    fn tx(&self, tx_id: &MultisigTxId) -> Option<&TxEntry> {
+        let tx_id = Uuid::from(tx_id);
+        self.txs.iter().find(|tx| tx.id == tx_id)
+    }
+
+    fn tx_mut(&mut self, tx_id: &MultisigTxId) -> Option<&mut TxEntry> {
+        let tx_id = Uuid::from(tx_id);
+        self.txs.iter_mut().find(|tx| tx.id == tx_id)
+    }
+
+    fn has_max_pending_txs(&self, address: &str, max: NonZeroU32) -> bool {
+        let pending = self
+            .txs
+            .iter()
+            .filter(|tx| tx.account == address && tx.status == MultisigTxStatus::Pending)
+            .count();
+
+        pending >= max.get() as usize
+    }
+
+    fn tx_account(&self, tx: &TxEntry) -> &AccountEntry {
+        self.account(&tx.account).expect("a tx is stored along its multisig account")
+    }
+
+    fn is_approver_of_tx(&self, tx: &TxEntry, approver_address: &str) -> bool {
+        self.tx_account(tx)
+            .current_approvers()
+            .any(|(_, address, _)| address == approver_address)
+    }
+
+    /// Returns the total weight of the current approvers who signed the transaction.
+    fn signed_weight(&self, tx: &TxEntry) -> u64 {
+        self.tx_account(tx)
+            .current_approvers()
+            .filter(|(_, address, _)| tx.has_signed(address))
+            .map(|(.., weight)| u64::from(weight.get()))
+            .sum()
+    }
+
+    /// Checks that the approver may add `signature` to the transaction, i.e. that it was made with
+    /// the approver's key over the transaction summary commitment and that the approver didn't
+    /// sign already.
+    fn validate_signature(
+        &self,
+        tx: &TxEntry,
+        approver_address: &str,
+        signature: &ApproverSignature,
+    ) -> Result<()> {
+        let approver = self
+            .approvers
+            .get(approver_address)
+            .ok_or(MultisigStoreError::Other("approver not found".into()))?;
+
+        let tx_summary_commit = tx.tx_summary.to_commitment();
+
+        let valid = match (approver.pub_key_commit, signature) {
+            (ApproverKey::Falcon(pub_key_commit), ApproverSignature::Falcon(signature)) => {
+                pub_key_commit.verify(tx_summary_commit, signature)
+            },
+            (ApproverKey::Ecdsa(pub_key), ApproverSignature::Ecdsa(signature)) => {
+                verify_ecdsa_k256_signature(
+                    pub_key.as_bytes(),
+                    tx_summary_commit,
+                    signature.as_bytes(),
+                )
+            },
+            _ => false,
+        };
+
+        if !valid {
+            return Err(MultisigStoreError::InvalidSignature);
+        }
+
+        if tx.has_signed(approver_address) {
+            return Err(MultisigStoreError::Other("approver already signed the tx".into()));
+        }
+
+        Ok(())
+    }
+
+    /// Adds the validated signatures to the transaction and returns the signed weight before and
+    /// after, recording when the threshold is first met.
+    fn add_signatures(
+        &mut self,
+        tx_id: &MultisigTxId,
+        signatures: Vec<(String, ApproverSignature)>,
+    ) -> (u64, u64, u64) {
+        let now = now();
+
+        let tx = self.tx(tx_id).expect("the tx was validated");
+        let threshold = u64::from(self.tx_account(tx).threshold.get());
+        let prev_signed_weight = self.signed_weight(tx);
+
+        let tx = self.tx_mut(tx_id).expect("the tx was validated");
+        tx.signatures
+            .extend(signatures.into_iter().map(|(address, sig)| (address, sig, now)));
+        tx.updated_at = now;
+
+        let tx = self.tx(tx_id).expect("the tx was validated");
+        let signed_weight = self.signed_weight(tx);
+
+        if signed_weight >= threshold {
+            let tx = self.tx_mut(tx_id).expect("the tx was validated");
+            tx.threshold_met_at.get_or_insert(now);
+        }
+
+        (threshold, prev_signed_weight, signed_weight)
+    }
+
+    fn to_multisig_tx(&self, tx: &TxEntry, signature_count: usize) -> Result<MultisigTx> {
+        let account = self.tx_account(tx);
+
+        let signature_count = u32::try_from(signature_count)
+            .map(NonZeroU32::new)
+            .map_err(|_| MultisigStoreError::InvalidValue)?;
+
+        let timestamps = Timestamps::builder()
+            .created_at(tx.created_at)
+            .updated_at(tx.updated_at)
+            .build();
+
+        let multisig_tx = MultisigTx::builder()
+            .id(tx.id.into())
+            .address(account.address)
+            .network_id(account.network_id)
+            .status(tx.status)
+            .tx_request(tx.tx_request.clone())
+            .tx_summary(tx.tx_summary.clone())
+            .tx_summary_commit(tx.tx_summary.to_commitment())
+            .tx_effect(TxEffect::from(&tx.tx_summary))
+            .input_note_ids(tx.tx_request.get_input_note_ids())
+            .maybe_title(tx.title.clone())
+            .maybe_memo(tx.memo.clone())
+            .maybe_failure_reason(tx.failure_reason.clone())
+            .maybe_chain_tx_id(tx.chain_tx_id)
+            .maybe_submission_block_num(tx.submission_block_num)
+            .maybe_signature_count(signature_count)
+            .threshold(account.threshold)
+            .aux(timestamps)
+            .build();
+
+        Ok(multisig_tx)
+    }
+
+    fn try_get_multisig_account(
+        &self,
+        address: &str,
+    ) -> Result<Option<MultisigAccount<WithApprovers, WithPubKeyCommits>>> {
+        let Some(account) = self.account(address) else {
+            return Ok(None);
+        };
+
+        let approvers: Vec<_> = account
+            .current_approvers()
+            .map(|(_, address, weight)| {
+                (&self.approvers[address], weight, account.approver_label(address))
+            })
+            .collect();
+
+        account
+            .to_multisig_account()
+            .with_weighted_approvers(
+                approvers.iter().map(|(approver, ..)| approver.address).collect(),
+                approvers.iter().map(|&(_, weight, _)| weight).collect(),
+            )
+            .ok_or(MultisigStoreError::InvalidValue)?
+            .with_approver_labels(approvers.iter().map(|(.., label)| label.clone()).collect())
+            .ok_or(MultisigStoreError::InvalidValue)?
+            .with_pub_key_commits(approvers.iter().map(|(a, ..)| a.pub_key_commit).collect())
+            .ok_or(MultisigStoreError::InvalidValue)
+            .map(Some)
+    }
+
+    /// Returns the accounts in creation order, as listed by the store.
+    fn accounts_in_creation_order(&self, include_archived: bool) -> Vec<&AccountEntry> {
+        let mut accounts: Vec<_> = self
+            .accounts
+            .iter()
+            .filter(|account| include_archived || account.archived_at.is_none())
+            .collect();
+
+        accounts.sort_by(|a, b| (a.created_at, &a.bech32).cmp(&(b.created_at, &b.bech32)));
+        accounts
+    }
+
+    fn remove_approver(
+        &mut self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+        approver_account_id_address: AccountIdAddress,
+        threshold: Option<NonZeroU32>,
+    ) -> Result<Option<MultisigAccount<WithApprovers, WithPubKeyCommits>>> {
+        let address = to_account_key(network_id, account_id_address);
+        let approver_address = to_bech32(network_id, approver_account_id_address);
+
+        let has_pending_txs = self.txs.iter().any(|tx| {
+            tx.account == address
+                && matches!(
+                    tx.status,
+                    MultisigTxStatus::Pending
+                        | MultisigTxStatus::ReadyToExecute
+                        | MultisigTxStatus::Executing
+                )
+        });
+
+        let Some(account) = self.account_mut(&address) else {
+            return Ok(None);
+        };
+
+        if !account.current_approvers().any(|(_, address, _)| *address == approver_address) {
+            return Ok(None);
+        }
+
+        let threshold = threshold.unwrap_or(account.threshold);
+
+        if threshold > account.threshold {
+            return Err(MultisigStoreError::ThresholdRaised {
+                threshold: threshold.get().into(),
+                current: account.threshold.get().into(),
+            });
+        }
+
+        if has_pending_txs {
+            return Err(MultisigStoreError::PendingTxs);
+        }
+
+        let remaining_weight: u64 = account
+            .current_approvers()
+            .filter(|(_, address, _)| **address != approver_address)
+            .map(|(.., weight)| u64::from(weight.get()))
+            .sum();
+
+        if remaining_weight < u64::from(threshold.get()) {
+            return Err(MultisigStoreError::UnreachableThreshold {
+                threshold: threshold.get().into(),
+                remaining_weight,
+            });
+        }
+
+        account.removed_approvers.insert(approver_address);
+        account.threshold = threshold;
+
+        self.try_get_multisig_account(&address)
+    }
+}
+
+#[async_trait::async_trait]
+impl MultisigStoreBackend for InMemoryMultisigStore {
+    fn primary(&self) -> Arc<dyn MultisigStoreBackend> {
+        Arc::new(self.clone())
+    }
+
+    async fn create_multisig_account(
+        &self,
+        multisig_account: MultisigAccount<WithApprovers, WithPubKeyCommits, ()>,
+    ) -> Result<MultisigAccount<WithApprovers, WithPubKeyCommits>> {
+        // the nonce is bounded by the database column of the store
+        multisig_account
+            .nonce()
+            .map(i64::try_from)
+            .transpose()
+            .map_err(|_| MultisigStoreError::InvalidValue)?;
+
+        let network_id = multisig_account.network_id();
+        let address = to_account_key(network_id, multisig_account.address());
+
+        let mut state = self.state();
+
+        if state.account(&address).is_some() {
+            return Err(MultisigStoreError::MultisigAccountAlreadyExists);
+        }
+
+        let now = now();
+
+        let approvers = multisig_account
+            .approvers()
+            .iter()
+            .zip(multisig_account.pub_key_commits())
+            .zip(multisig_account.approver_weights())
+            .zip(multisig_account.approver_labels());
+
+        let mut approver_weights = Vec::with_capacity(multisig_account.approvers().len());
+        let mut approver_labels = HashMap::new();
+
+        for (((&approver, &pub_key_commit), &weight), label) in approvers {
+            let approver_address = to_bech32(network_id, approver);
+
+            let prev = state.approvers.remove(&approver_address);
+
+            let approver_entry = ApproverEntry {
+                address: approver,
+                network_id,
+                pub_key_commit,
+                created_at: prev.map_or(now, |prev| prev.created_at),
+            };
+
+            state.approvers.insert(approver_address.clone(), approver_entry);
+
+            if let Some(label) = label {
+                approver_labels.insert(approver_address.clone(), label.clone());
+            }
+
+            approver_weights.push((approver_address, weight));
+        }
+
+        state.accounts.push(AccountEntry {
+            bech32: address,
+            address: multisig_account.address(),
+            network_id,
+            kind: multisig_account.kind(),
+            threshold: multisig_account.threshold(),
+            nonce: multisig_account.nonce(),
+            created_at: now,
+            archived_at: None,
+            approvers: approver_weights,
+            removed_approvers: HashSet::new(),
+            approver_labels,
+        });
+
+        let timestamps = Timestamps::builder().created_at(now).updated_at(now).build();
+
+        Ok(multisig_account.with_aux(timestamps).0)
+    }
+
+    async fn create_multisig_tx(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+        tx_request: &TransactionRequest,
+        tx_summary: &TransactionSummary,
+        title: Option<&str>,
+        memo: Option<&str>,
+        max_pending_txs: Option<NonZeroU32>,
+    ) -> Result<MultisigTxId> {
+        let address = to_account_key(network_id, account_id_address);
+
+        let mut state = self.state();
+
+        if state.account(&address).is_none() {
+            return Err(MultisigStoreError::NotFound("multisig account not found".into()));
+        }
+
+        if let Some(max) = max_pending_txs {
+            if state.has_max_pending_txs(&address, max) {
+                return Err(MultisigStoreError::TooManyPendingTxs { max });
+            }
+        }
+
+        let id = state.next_id();
+        let now = now();
+
+        state.txs.push(TxEntry {
+            id,
+            account: address,
+            status: MultisigTxStatus::Pending,
+            tx_request: tx_request.clone(),
+            tx_summary: tx_summary.clone(),
+            title: title.map(ToOwned::to_owned),
+            memo: memo.map(ToOwned::to_owned),
+            failure_reason: None,
+            chain_tx_id: None,
+            submission_block_num: None,
+            created_at: now,
+            updated_at: now,
+            threshold_met_at: None,
+            signatures: Vec::new(),
+        });
+
+        Ok(id.into())
+    }
+
+    async fn add_multisig_tx_signature(
+        &self,
+        tx_id: &MultisigTxId,
+        network_id: NetworkId,
+        approver_account_id_address: AccountIdAddress,
+        signature: &ApproverSignature,
+    ) -> Result<Option<bool>> {
+        let approver_address = to_bech32(network_id, approver_account_id_address);
+
+        let mut state = self.state();
+
+        let Some(tx) = state.tx(tx_id).filter(|tx| state.is_approver_of_tx(tx, &approver_address))
+        else {
+            return Ok(None);
+        };
+
+        state.validate_signature(tx, &approver_address, signature)?;
+
+        let (threshold, _, signed_weight) =
+            state.add_signatures(tx_id, vec![(approver_address, signature.clone())]);
+
+        Ok(Some(signed_weight >= threshold))
+    }
+
+    async fn add_multisig_tx_signatures(
+        &self,
+        tx_id: &MultisigTxId,
+        network_id: NetworkId,
+        signatures: &[(AccountIdAddress, ApproverSignature)],
+    ) -> Result<Option<bool>> {
+        let signatures: Vec<_> = signatures
+            .iter()
+            .map(|(approver, signature)| (to_bech32(network_id, *approver), signature.clone()))
+            .collect();
+
+        let mut state = self.state();
+
+        let Some(tx) = state.tx(tx_id) else {
+            return Ok(None);
+        };
+
+        if !signatures.iter().all(|(approver, _)| state.is_approver_of_tx(tx, approver)) {
+            return Ok(None);
+        }
+
+        for (approver_address, signature) in &signatures {
+            state.validate_signature(tx, approver_address, signature)?;
+        }
+
+        // signatures are stored all or nothing, as within a database transaction
+        if has_duplicates(signatures.iter().map(|(approver, _)| approver)) {
+            return Err(MultisigStoreError::Other("approver already signed the tx".into()));
+        }
+
+        let (threshold, prev_signed_weight, signed_weight) =
+            state.add_signatures(tx_id, signatures);
+
+        Ok(Some(prev_signed_weight < threshold && signed_weight >= threshold))
+    }
+
+    async fn update_multisig_tx_status_by_id(
+        &self,
+        tx_id: &MultisigTxId,
+        new_status: MultisigTxStatus,
+    ) -> Result<()> {
+        let mut state = self.state();
+
+        let tx = state
+            .tx_mut(tx_id)
+            .ok_or(MultisigStoreError::NotFound("tx id not found".into()))?;
+
+        tx.status = new_status;
+        tx.updated_at = now();
+
+        Ok(())
+    }
+
+    async fn transition_multisig_tx_status_by_id(
+        &self,
+        tx_id: &MultisigTxId,
+        from: MultisigTxStatus,
+        to: MultisigTxStatus,
+    ) -> Result<bool> {
+        let mut state = self.state();
+
+        let Some(tx) = state.tx_mut(tx_id).filter(|tx| tx.status == from) else {
+            return Ok(false);
+        };
+
+        tx.status = to;
+        tx.updated_at = now();
+
+        Ok(true)
+    }
+
+    async fn update_multisig_tx_status_to_success_by_id(
+        &self,
+        tx_id: &MultisigTxId,
+        chain_tx_id: TransactionId,
+        submission_block_num: BlockNumber,
+        account_nonce: u64,
+    ) -> Result<()> {
+        // the nonce is bounded by the database column of the store
+        i64::try_from(account_nonce).map_err(|_| MultisigStoreError::InvalidValue)?;
+
+        let mut state = self.state();
+
+        let tx = state
+            .tx_mut(tx_id)
+            .ok_or(MultisigStoreError::NotFound("tx id not found".into()))?;
+
+        tx.status = MultisigTxStatus::Success;
+        tx.chain_tx_id = Some(chain_tx_id);
+        tx.submission_block_num = Some(submission_block_num);
+        tx.updated_at = now();
+
+        let account = tx.account.clone();
+        state
+            .account_mut(&account)
+            .expect("a tx is stored along its multisig account")
+            .nonce = Some(account_nonce);
+
+        Ok(())
+    }
+
+    async fn update_multisig_tx_status_to_failure_by_id(
+        &self,
+        tx_id: &MultisigTxId,
+        failure_reason: &str,
+    ) -> Result<()> {
+        let mut state = self.state();
+
+        let tx = state
+            .tx_mut(tx_id)
+            .ok_or(MultisigStoreError::NotFound("tx id not found".into()))?;
+
+        tx.status = MultisigTxStatus::Failure;
+        tx.failure_reason = Some(failure_reason.to_owned());
+        tx.updated_at = now();
+
+        Ok(())
+    }
+
+    async fn repropose_failed_multisig_tx(
+        &self,
+        tx_id: &MultisigTxId,
+        tx_summary: &TransactionSummary,
+        max_pending_txs: Option<NonZeroU32>,
+    ) -> Result<()> {
+        let mut state = self.state();
+
+        let Some(address) = state.tx(tx_id).map(|tx| tx.account.clone()) else {
+            return Err(MultisigStoreError::NotFound("failed tx id not found".into()));
+        };
+
+        if let Some(max) = max_pending_txs.filter(|&max| state.has_max_pending_txs(&address, max)) {
+            return Err(MultisigStoreError::TooManyPendingTxs { max });
+        }
+
+        let Some(tx) = state.tx_mut(tx_id) else {
+            return Err(MultisigStoreError::NotFound("failed tx id not found".into()));
+        };
+
+        if tx.status != MultisigTxStatus::Failure {
+            return Err(MultisigStoreError::NotFound("failed tx id not found".into()));
+        }
+
+        tx.status = MultisigTxStatus::Pending;
+        tx.tx_summary = tx_summary.clone();
+        tx.failure_reason = None;
+        tx.threshold_met_at = None;
+        tx.signatures.clear();
+        tx.updated_at = now();
+
+        Ok(())
+    }
+
+    async fn archive_multisig_account(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<Option<MultisigAccount>> {
+        let mut state = self.state();
+
+        let account = state.account_mut(&to_account_key(network_id, account_id_address));
+
+        Ok(account.map(|account| {
+            account.archived_at = Some(now());
+            account.to_multisig_account()
+        }))
+    }
+
+    async fn unarchive_multisig_account(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<Option<MultisigAccount>> {
+        let mut state = self.state();
+
+        let account = state.account_mut(&to_account_key(network_id, account_id_address));
+
+        Ok(account.map(|account| {
+            account.archived_at = None;
+            account.to_multisig_account()
+        }))
+    }
+
+    async fn remove_multisig_account_approver(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+        approver_account_id_address: AccountIdAddress,
+    ) -> Result<Option<MultisigAccount<WithApprovers, WithPubKeyCommits>>> {
+        self.state().remove_approver(
+            network_id,
+            account_id_address,
+            approver_account_id_address,
+            None,
+        )
+    }
+
+    async fn remove_multisig_account_approver_and_lower_threshold(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+        approver_account_id_address: AccountIdAddress,
+        threshold: NonZeroU32,
+    ) -> Result<Option<MultisigAccount<WithApprovers, WithPubKeyCommits>>> {
+        self.state().remove_approver(
+            network_id,
+            account_id_address,
+            approver_account_id_address,
+            Some(threshold),
+        )
+    }
+
+    async fn record_audit_event(
+        &self,
+        network_id: NetworkId,
+        subject: &AuditSubject,
+        actor: Option<AccountIdAddress>,
+        action: AuditAction,
+        outcome: AuditOutcome,
+        detail: Option<&str>,
+    ) -> Result<()> {
+        let mut state = self.state();
+
+        let (address, tx_id) = match subject {
+            &AuditSubject::Account(address) => (to_account_key(network_id, address), None),
+            AuditSubject::Tx(tx_id) => {
+                let Some(tx) = state.tx(tx_id) else {
+                    return Ok(());
+                };
+
+                (tx.account.clone(), Some(tx_id.clone()))
+            },
+        };
+
+        let account = state
+            .account(&address)
+            .ok_or(MultisigStoreError::NotFound("multisig account not found".into()))?;
+
+        let (account_id_address, network_id) = (account.address, account.network_id);
+
+        let audit_event = AuditEvent::builder()
+            .id(state.next_id())
+            .address(account_id_address)
+            .network_id(network_id)
+            .maybe_tx_id(tx_id)
+            .maybe_actor(actor)
+            .action(action)
+            .outcome(outcome)
+            .maybe_detail(detail.map(ToOwned::to_owned))
+            .created_at(now())
+            .build();
+
+        state.audit_events.push((address, audit_event));
+
+        Ok(())
+    }
+
+    async fn get_multisig_account(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<Option<MultisigAccount>> {
+        let state = self.state();
+
+        let account = state.account(&to_account_key(network_id, account_id_address));
+
+        Ok(account.map(AccountEntry::to_multisig_account))
+    }
+
+    async fn get_account_summary(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<Option<(NonZeroU32, u32)>> {
+        let state = self.state();
+
+        let Some(account) = state.account(&to_account_key(network_id, account_id_address)) else {
+            return Ok(None);
+        };
+
+        let approver_count = account
+            .current_approvers()
+            .count()
+            .try_into()
+            .map_err(|_| MultisigStoreError::InvalidValue)?;
+
+        Ok(Some((account.threshold, approver_count)))
+    }
+
+    async fn try_get_multisig_account(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<Option<MultisigAccount<WithApprovers, WithPubKeyCommits>>> {
+        self.state()
+            .try_get_multisig_account(&to_account_key(network_id, account_id_address))
+    }
+
+    async fn get_all_multisig_accounts(
+        &self,
+        include_archived: bool,
+    ) -> Result<Vec<MultisigAccount>> {
+        let state = self.state();
+
+        let accounts = state.accounts_in_creation_order(include_archived);
+
+        let max = self
+            .max_all_multisig_accounts
+            .unwrap_or(MultisigStore::DEFAULT_MAX_ALL_MULTISIG_ACCOUNTS);
+
+        if accounts.len() > max.get() as usize {
+            return Err(MultisigStoreError::ResultTooLarge { max });
+        }
+
+        Ok(accounts.into_iter().map(AccountEntry::to_multisig_account).collect())
+    }
+
+    fn stream_multisig_accounts_paged(
+        &self,
+        include_archived: bool,
+        _page_size: NonZeroU32,
+    ) -> BoxStream<'_, Result<MultisigAccount>> {
+        let state = self.state();
+
+        // the accounts are in memory already, so they are streamed at once
+        let accounts: Vec<_> = state
+            .accounts_in_creation_order(include_archived)
+            .into_iter()
+            .map(AccountEntry::to_multisig_account)
+            .map(Ok)
+            .collect();
+
+        futures::stream::iter(accounts).boxed()
+    }
+
+    async fn get_multisig_accounts_with_counts(
+        &self,
+        include_archived: bool,
+        after: Option<Cursor<String>>,
+        limit: Option<NonZeroU32>,
+    ) -> Result<(Vec<(MultisigAccount, u32, u64)>, Option<Cursor<String>>)> {
+        let state = self.state();
+
+        let accounts: Vec<_> = state
+            .accounts_in_creation_order(include_archived)
+            .into_iter()
+            .filter(|account| {
+                after.as_ref().is_none_or(|cursor| {
+                    (account.created_at, &account.bech32) > (cursor.timestamp(), cursor.id())
+                })
+            })
+            .take(limit.map_or(usize::MAX, |limit| limit.get() as usize))
+            .collect();
+
+        let next_cursor = limit
+            .filter(|limit| limit.get() as usize == accounts.len())
+            .and(accounts.last())
+            .map(|account| Cursor::new(account.created_at, account.bech32.clone()));
+
+        let accounts = accounts
+            .into_iter()
+            .map(|account| {
+                let approver_count = account
+                    .current_approvers()
+                    .count()
+                    .try_into()
+                    .map_err(|_| MultisigStoreError::InvalidValue)?;
+
+                let pending_tx_count = state
+                    .txs
+                    .iter()
+                    .filter(|tx| tx.account == account.bech32)
+                    .filter(|tx| {
+                        matches!(
+                            tx.status,
+                            MultisigTxStatus::Pending
+                                | MultisigTxStatus::ReadyToExecute
+                                | MultisigTxStatus::Executing
+                        )
+                    })
+                    .count() as u64;
+
+                Ok((account.to_multisig_account(), approver_count, pending_tx_count))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok((accounts, next_cursor))
+    }
+
+    async fn get_approvers_by_multisig_account_address(
+        &self,
+        network_id: NetworkId,
+        multisig_account_id_address: AccountIdAddress,
+        label_contains: Option<&str>,
+        after: Option<Cursor<String>>,
+        limit: Option<NonZeroU32>,
+    ) -> Result<(Vec<MultisigApprover>, Option<Cursor<String>>)> {
+        let state = self.state();
+
+        let Some(account) = state.account(&to_account_key(network_id, multisig_account_id_address))
+        else {
+            return Ok((Vec::new(), None));
+        };
+
+        let label_contains = label_contains.map(str::to_lowercase);
+
+        let approvers: Vec<_> = account
+            .current_approvers()
+            .filter(|(idx, address, _)| {
+                after.as_ref().is_none_or(|cursor| {
+                    (i64::from(*idx), *address) > (cursor.count(), cursor.id())
+                })
+            })
+            .map(|(idx, address, _)| (idx, address, account.approver_label(address)))
+            .filter(|(_, _, label)| match &label_contains {
+                Some(label_contains) => label
+                    .as_ref()
+                    .is_some_and(|label| label.to_lowercase().contains(label_contains)),
+                None => true,
+            })
+            .take(limit.map_or(usize::MAX, |limit| limit.get() as usize))
+            .collect();
+
+        let next_cursor = limit
+            .filter(|limit| limit.get() as usize == approvers.len())
+            .and(approvers.last())
+            .map(|(idx, address, _)| Cursor::with_count(u64::from(*idx), (*address).clone()));
+
+        let approvers = approvers
+            .into_iter()
+            .map(|(_, address, label)| state.approvers[address].to_multisig_approver(label))
+            .collect();
+
+        Ok((approvers, next_cursor))
+    }
+
+    async fn get_indexed_approvers_by_multisig_account_address(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<Option<Vec<(u32, MultisigApprover)>>> {
+        let state = self.state();
+
+        let Some(account) = state.account(&to_account_key(network_id, account_id_address)) else {
+            return Ok(None);
+        };
+
+        let approvers = account
+            .current_approvers()
+            .map(|(idx, address, _)| {
+                let approver =
+                    state.approvers[address].to_multisig_approver(account.approver_label(address));
+
+                (idx, approver)
+            })
+            .collect();
+
+        Ok(Some(approvers))
+    }
+
+    async fn get_txs_by_multisig_account_address_with_status_filter(
+        &self,
+        network_id: NetworkId,
+        address: AccountIdAddress,
+        tx_status_filter: Option<MultisigTxStatus>,
+        sort: TxSort,
+        after: Option<Cursor>,
+        limit: Option<NonZeroU32>,
+    ) -> Result<(Vec<(MultisigTxId, Result<MultisigTx>)>, Option<Cursor>)> {
+        let address = to_account_key(network_id, address);
+
+        let state = self.state();
+
+        // an unknown account has no txs to list
+        if state.account(&address).is_none() {
+            return Ok((Vec::new(), None));
+        }
+
+        // transactions sorting equal on the field are ordered by id, as by the store
+        let cmp = |a: &TxEntry, b: &TxEntry| {
+            let ordering = match sort.field() {
+                TxSortField::CreatedAt => a.created_at.cmp(&b.created_at),
+                TxSortField::UpdatedAt => a.updated_at.cmp(&b.updated_at),
+                TxSortField::SignatureCount => a.signatures.len().cmp(&b.signatures.len()),
+            };
+
+            ordering.then(a.id.cmp(&b.id))
+        };
+
+        let past_cursor = match sort.direction() {
+            SortDirection::Asc => Ordering::Greater,
+            SortDirection::Desc => Ordering::Less,
+        };
+
+        // the cursor carries the sort key of the last tx of the previous page
+        let is_after_cursor = |tx: &TxEntry| {
+            let Some(cursor) = after else {
+                return true;
+            };
+
+            let ordering = match sort.field() {
+                TxSortField::CreatedAt => {
+                    (tx.created_at, tx.id).cmp(&(cursor.timestamp(), *cursor.id()))
+                },
+                TxSortField::UpdatedAt => {
+                    (tx.updated_at, tx.id).cmp(&(cursor.timestamp(), *cursor.id()))
+                },
+                TxSortField::SignatureCount => {
+                    (tx.signatures.len() as i64, tx.id).cmp(&(cursor.count(), *cursor.id()))
+                },
+            };
+
+            ordering == past_cursor
+        };
+
+        let mut txs: Vec<_> = state
+            .txs
+            .iter()
+            .filter(|tx| tx.account == address)
+            .filter(|tx| tx_status_filter.is_none_or(|status| tx.status == status))
+            .filter(|tx| is_after_cursor(tx))
+            .collect();
+
+        txs.sort_by(|a, b| match sort.direction() {
+            SortDirection::Asc => cmp(a, b),
+            SortDirection::Desc => cmp(b, a),
+        });
+
+        if let Some(limit) = limit {
+            txs.truncate(limit.get() as usize);
+        }
+
+        let next_cursor = limit
+            .filter(|limit| limit.get() as usize == txs.len())
+            .and(txs.last())
+            .map(|tx| match sort.field() {
+                TxSortField::CreatedAt => Cursor::new(tx.created_at, tx.id),
+                TxSortField::UpdatedAt => Cursor::new(tx.updated_at, tx.id),
+                TxSortField::SignatureCount => {
+                    Cursor::with_count(tx.signatures.len() as u64, tx.id)
+                },
+            });
+
+        let txs = txs
+            .into_iter()
+            .map(|tx| (tx.id.into(), state.to_multisig_tx(tx, tx.signatures.len())))
+            .collect();
+
+        Ok((txs, next_cursor))
+    }
+
+    async fn get_pending_txs_for_approver(
+        &self,
+        network_id: NetworkId,
+        approver_address: AccountIdAddress,
+        after: Option<Cursor>,
+        limit: Option<NonZeroU32>,
+    ) -> Result<(Vec<(MultisigTxId, Result<MultisigTx>, bool)>, Option<Cursor>)> {
+        let approver_address = to_bech32(network_id, approver_address);
+
+        let state = self.state();
+
+        let mut txs: Vec<_> = state
+            .txs
+            .iter()
+            .filter(|tx| tx.status == MultisigTxStatus::Pending)
+            .filter(|tx| state.tx_account(tx).archived_at.is_none())
+            .filter(|tx| state.is_approver_of_tx(tx, &approver_address))
+            .filter(|tx| {
+                after.is_none_or(|cursor| {
+                    (tx.created_at, tx.id) < (cursor.timestamp(), *cursor.id())
+                })
+            })
+            .collect();
+
+        // most recent first, transactions created at once being ordered by id as by the store
+        txs.sort_by(|a, b| (b.created_at, b.id).cmp(&(a.created_at, a.id)));
+
+        if let Some(limit) = limit {
+            txs.truncate(limit.get() as usize);
+        }
+
+        let next_cursor = limit
+            .filter(|limit| limit.get() as usize == txs.len())
+            .and(txs.last())
+            .map(|tx| Cursor::new(tx.created_at, tx.id));
+
+        let txs = txs
+            .into_iter()
+            .map(|tx| {
+                let multisig_tx = state.to_multisig_tx(tx, tx.signatures.len());
+                (tx.id.into(), multisig_tx, tx.has_signed(&approver_address))
+            })
+            .collect();
+
+        Ok((txs, next_cursor))
+    }
+
+    async fn get_multisig_tx_by_id(&self, id: &MultisigTxId) -> Result<Option<MultisigTx>> {
+        let state = self.state();
+
+        state.tx(id).map(|tx| state.to_multisig_tx(tx, tx.signatures.len())).transpose()
+    }
+
+    async fn get_multisig_account_address_by_tx_id(
+        &self,
+        id: &MultisigTxId,
+    ) -> Result<Option<AccountIdAddress>> {
+        let state = self.state();
+
+        Ok(state.tx(id).map(|tx| state.tx_account(tx).address))
+    }
+
+    async fn get_multisig_tx_by_summary_commit(
+        &self,
+        tx_summary_commit: Word,
+    ) -> Result<Option<MultisigTx>> {
+        let state = self.state();
+
+        // the most recent tx wins if several share the commitment
+        state
+            .txs
+            .iter()
+            .filter(|tx| tx.tx_summary.to_commitment() == tx_summary_commit)
+            .max_by_key(|tx| (tx.created_at, tx.id))
+            .map(|tx| state.to_multisig_tx(tx, tx.signatures.len()))
+            .transpose()
+    }
+
+    async fn has_approver_signed(
+        &self,
+        tx_id: &MultisigTxId,
+        network_id: NetworkId,
+        approver_account_id_address: AccountIdAddress,
+    ) -> Result<bool> {
+        let approver_address = to_bech32(network_id, approver_account_id_address);
+
+        Ok(self.state().tx(tx_id).is_some_and(|tx| tx.has_signed(&approver_address)))
+    }
+
+    async fn is_approver_of_tx(
+        &self,
+        tx_id: &MultisigTxId,
+        network_id: NetworkId,
+        approver_account_id_address: AccountIdAddress,
+    ) -> Result<Option<bool>> {
+        let approver_address = to_bech32(network_id, approver_account_id_address);
+
+        let state = self.state();
+
+        Ok(state.tx(tx_id).map(|tx| state.is_approver_of_tx(tx, &approver_address)))
+    }
+
+    async fn count_pending_multisig_txs_by_multisig_account_address(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<u64> {
+        let address = to_account_key(network_id, account_id_address);
+
+        let state = self.state();
+
+        let pending = state
+            .txs
+            .iter()
+            .filter(|tx| tx.account == address && tx.status == MultisigTxStatus::Pending)
+            .count();
+
+        Ok(pending as u64)
+    }
+
+    async fn get_multisig_tx_stats_by_multisig_account_address(
+        &self,
+        network_id: NetworkId,
+        multisig_account_id_address: AccountIdAddress,
+    ) -> Result<MultisigTxStats> {
+        let address = to_account_key(network_id, multisig_account_id_address);
+
+        let state = self.state();
+
+        let txs: Vec<_> = state.txs.iter().filter(|tx| tx.account == address).collect();
+
+        let month_ago = Utc::now() - Months::new(1);
+        let is_success = |tx: &&&TxEntry| tx.status == MultisigTxStatus::Success;
+
+        let approver_signed_counts = state
+            .account(&address)
+            .into_iter()
+            .flat_map(AccountEntry::current_approvers)
+            .map(|(_, approver_address, _)| {
+                let signed_count = txs.iter().filter(|tx| tx.has_signed(approver_address)).count();
+                (state.approvers[approver_address].address, signed_count as u64)
+            })
+            .collect();
+
+        let mut times_to_threshold: Vec<_> = txs
+            .iter()
+            .filter(is_success)
+            .filter_map(|tx| tx.threshold_met_at.map(|met_at| met_at - tx.created_at))
+            .map(secs)
+            .collect();
+
+        times_to_threshold.sort_by(f64::total_cmp);
+
+        // a negative duration can only come from clock skew, it is left out rather than clamped
+        let to_duration =
+            |secs: Option<f64>| secs.and_then(|secs| Duration::try_from_secs_f64(secs).ok());
+
+        let avg_time_to_threshold = (!times_to_threshold.is_empty())
+            .then(|| times_to_threshold.iter().sum::<f64>() / times_to_threshold.len() as f64);
+
+        let tx_stats = MultisigTxStats::builder()
+            .total(txs.len() as u64)
+            .last_month(txs.iter().filter(|tx| tx.created_at >= month_ago).count() as u64)
+            .total_success(txs.iter().filter(is_success).count() as u64)
+            .approver_signed_counts(approver_signed_counts)
+            .maybe_avg_time_to_threshold(to_duration(avg_time_to_threshold))
+            .maybe_p95_time_to_threshold(to_duration(percentile_cont(&times_to_threshold, 0.95)))
+            .build();
+
+        Ok(tx_stats)
+    }
+
+    async fn get_tx_trend_by_multisig_account_address(
+        &self,
+        network_id: NetworkId,
+        multisig_account_id_address: AccountIdAddress,
+        granularity: TrendGranularity,
+    ) -> Result<Vec<(DateTime<Utc>, u64)>> {
+        let address = to_account_key(network_id, multisig_account_id_address);
+
+        let state = self.state();
+
+        let mut buckets = BTreeMap::new();
+
+        for tx in state.txs.iter().filter(|tx| tx.account == address) {
+            let day = tx.created_at.date_naive();
+
+            let start = match granularity {
+                TrendGranularity::Day => day,
+                TrendGranularity::Week => {
+                    day - TimeDelta::days(day.weekday().num_days_from_monday().into())
+                },
+            };
+
+            let start = start.and_hms_opt(0, 0, 0).expect("midnight is valid").and_utc();
+
+            *buckets.entry(start).or_insert(0) += 1;
+        }
+
+        Ok(buckets.into_iter().collect())
+    }
+
+    async fn get_audit_events_by_multisig_account_address(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+        after: Option<Cursor>,
+        limit: Option<NonZeroU32>,
+    ) -> Result<(Vec<AuditEvent>, Option<Cursor>)> {
+        let address = to_account_key(network_id, account_id_address);
+
+        let state = self.state();
+
+        let mut audit_events: Vec<_> = state
+            .audit_events
+            .iter()
+            .filter(|(account, _)| *account == address)
+            .map(|(_, event)| event)
+            .filter(|event| {
+                after.is_none_or(|cursor| {
+                    (event.created_at(), event.id()) < (cursor.timestamp(), *cursor.id())
+                })
+            })
+            .cloned()
+            .collect();
+
+        // most recent first
+        audit_events.sort_by_key(|event| core::cmp::Reverse((event.created_at(), event.id())));
+
+        if let Some(limit) = limit {
+            audit_events.truncate(limit.get() as usize);
+        }
+
+        let next_cursor = limit
+            .filter(|limit| limit.get() as usize == audit_events.len())
+            .and(audit_events.last())
+            .map(|event| Cursor::new(event.created_at(), event.id()));
+
+        Ok((audit_events, next_cursor))
+    }
+
+    async fn get_approver_pub_keys_in_order(
+        &self,
+        network_id: NetworkId,
+        account_id_address: AccountIdAddress,
+    ) -> Result<Option<Vec<PublicKey>>> {
+        let state = self.state();
+
+        let Some(account) = state.account(&to_account_key(network_id, account_id_address)) else {
+            return Ok(None);
+        };
+
+        let pub_keys: Vec<_> = account
+            .approvers
+            .iter()
+            .map(|(address, _)| {
+                PublicKey::new(state.approvers[address].pub_key_commit.commitment())
+            })
+            .collect();
+
+        Ok(pub_keys.filter(|pub_keys| !pub_keys.is_empty()))
+    }
+
+    async fn get_signatures_of_all_approvers_with_multisig_tx_by_tx_id(
+        &self,
+        tx_id: &MultisigTxId,
+    ) -> Result<(Vec<Option<(ApproverSignature, DateTime<Utc>)>>, MultisigTx)> {
+        let state = self.state();
+
+        let tx = state.tx(tx_id).ok_or(MultisigStoreError::NotFound("tx id not found".into()))?;
+
+        let signatures: Vec<_> = state
+            .tx_account(tx)
+            .approvers
+            .iter()
+            .map(|(approver_address, _)| {
+                tx.signatures
+                    .iter()
+                    .find(|(address, ..)| address == approver_address)
+                    .map(|(_, signature, created_at)| (signature.clone(), *created_at))
+            })
+            .collect();
+
+        // only the signatures of the current approvers are counted, as by the store
+        let signature_count = signatures.iter().flatten().count();
+
+        Ok((signatures, state.to_multisig_tx(tx, signature_count)?))
+    }
+
+    async fn ping(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Returns the current time at the microsecond precision of the database timestamps.
+fn now() -> DateTime<Utc> {
+    Utc::now().trunc_subsecs(6)
+}
+
+fn secs(time_delta: TimeDelta) -> f64 {
+    time_delta.num_microseconds().map_or(f64::NAN, |micros| micros as f64 / 1e6)
+}
+
+/// Returns the continuous percentile of sorted values, interpolating between the closest ranks
+/// like `percentile_cont` of PostgreSQL, or `None` if there is no value.
+fn percentile_cont(sorted: &[f64], percentile: f64) -> Option<f64> {
+    let last = sorted.len().checked_sub(1)?;
+    let rank = percentile * last as f64;
+    let (lower, upper) = (sorted[rank.floor() as usize], sorted[rank.ceil() as usize]);
+
+    Some(lower + (upper - lower) * rank.fract())
+}
+
+fn has_duplicates<T: Eq + core::hash::Hash>(items: impl IntoIterator<Item = T>) -> bool {
+    let mut seen = HashSet::new();
+    items.into_iter().any(|item| !seen.insert(item))
+}
@@ -1,15 +1,38 @@
 //! Test utilities for Miden multisig components.
 //!
-//! This crate provides helpers to set up a ready-to-use `MockClient` and a prebuilt mock
-//! chain for integration and end-to-end tests across this workspace.
+//! This crate provides helpers to set up a ready-to-use `MockClient` or `MultisigClient` and a
+//! prebuilt mock chain for integration and end-to-end tests across this workspace, along with
+//! multisig accounts whose ids are the same across runs.
 //!
 //! The APIs are thin wrappers around `miden-client` testing facilities while exposing
 //! a stable interface for this repository's tests.
+//!
+//! It also provides [`InMemoryMultisigStore`], a multisig store backend running the multisig
+//! engine without a database, and the [`conformance`] scenarios every multisig store backend is
+//! expected to pass.
+//!
+//! Tests running against PostgreSQL get a database of their own from [`setup_test_db`], and
+//! fixtures such as [`account_id_address`] and [`empty_tx_summary`] build the accounts and txs
+//! of their scenarios.
+
+pub mod conformance;
+
+mod fixtures;
+mod in_memory_store;
+mod postgres;
+
+pub use self::{
+    fixtures::{account_id_address, empty_tx_summary, pay_to_id_tx_request, tx_summary_of},
+    in_memory_store::InMemoryMultisigStore,
+    postgres::{setup_multisig_store, setup_test_db},
+};
 
 use std::{path::Path, sync::Arc};
 
 use miden_client::{
     DebugMode, Felt,
+    account::Account,
+    auth::TransactionAuthenticator,
     builder::ClientBuilder,
     crypto::RpoRandomCoin,
     keystore::FilesystemKeyStore,
@@ -22,6 +45,8 @@ use miden_client::{
     },
     transaction::OutputNote,
 };
+use miden_multisig_client::MultisigClient;
+use miden_objects::crypto::dsa::rpo_falcon512::PublicKey;
 use miden_testing::{MockChain, MockChainBuilder};
 use rand::{Rng, rngs::StdRng};
 
@@ -49,6 +74,53 @@ where
     (client, rpc_api, keystore)
 }
 
+/// The seed the multisig accounts of tests are set up from, see [`setup_test_multisig_account`].
+pub const TEST_MULTISIG_ACCOUNT_SEED: [u8; 32] = [7; 32];
+
+/// Create a ready-to-use `MultisigClient` on top of [`create_test_client`].
+///
+/// Returns the client, the associated `MockRpcApi`, and the `FilesystemKeyStore`.
+pub async fn create_test_multisig_client<P>(
+    keystore_path: P,
+) -> (
+    MultisigClient<FilesystemKeyStore<StdRng>>,
+    MockRpcApi,
+    FilesystemKeyStore<StdRng>,
+)
+where
+    P: AsRef<Path>,
+{
+    let (client, rpc_api, keystore) = Box::pin(create_test_client(keystore_path)).await;
+    let client = MultisigClient::new(client, Arc::new(rpc_api.clone()));
+
+    (client, rpc_api, keystore)
+}
+
+/// Set up a multisig account from [`TEST_MULTISIG_ACCOUNT_SEED`] rather than a random seed.
+///
+/// The same approvers and threshold always yield the same account id, so that tests can assert
+/// on it across runs.
+pub async fn setup_test_multisig_account<AUTH>(
+    client: &mut MultisigClient<AUTH>,
+    approvers: Vec<PublicKey>,
+    threshold: u32,
+) -> Account
+where
+    AUTH: TransactionAuthenticator + Sync + 'static,
+{
+    client
+        .setup_account_with_seed(approvers, threshold, TEST_MULTISIG_ACCOUNT_SEED)
+        .await
+        .unwrap()
+}
+
+/// Create a `MockRpcApi` serving the prebuilt mock chain.
+///
+/// This lets components connecting to a node, e.g. the multisig client runtime, run offline.
+pub async fn create_mock_rpc_api() -> MockRpcApi {
+    MockRpcApi::new(Box::pin(create_prebuilt_mock_chain()).await)
+}
+
 async fn create_test_client_builder<P>(
     keystore_path: P,
 ) -> (ClientBuilder<TestClientKeyStore>, MockRpcApi, FilesystemKeyStore<StdRng>)
@@ -65,7 +137,7 @@ where
 
     let keystore = FilesystemKeyStore::new(keystore_path.as_ref().into()).unwrap();
 
-    let rpc_api = MockRpcApi::new(Box::pin(create_prebuilt_mock_chain()).await);
+    let rpc_api = create_mock_rpc_api().await;
     let arc_rpc_api = Arc::new(rpc_api.clone());
 
     let builder = ClientBuilder::new()